@@ -170,3 +170,33 @@ fn layout_many_text_elements(count: usize) {
 
     engine.compute(&root, 80, 24);
 }
+
+fn create_paragraphs(count: usize) -> Element {
+    let mut root = Element::root();
+    root.style.flex_direction = FlexDirection::Column;
+
+    for i in 0..count {
+        root.add_child(Element::text(format!(
+            "Paragraph {i}: the quick brown fox jumps over the lazy dog while the \
+             layout engine wraps this sentence across several terminal lines."
+        )));
+    }
+    root
+}
+
+#[divan::bench(args = [20, 100])]
+fn layout_wrapped_paragraphs_cold(bencher: divan::Bencher, count: usize) {
+    let tree = create_paragraphs(count);
+    bencher.bench_local(|| {
+        let mut engine = LayoutEngine::new();
+        engine.compute(&tree, 40, 24);
+    });
+}
+
+#[divan::bench(args = [20, 100])]
+fn layout_wrapped_paragraphs_cached(bencher: divan::Bencher, count: usize) {
+    let tree = create_paragraphs(count);
+    let mut engine = LayoutEngine::new();
+    engine.compute(&tree, 40, 24);
+    bencher.bench_local(|| engine.compute(&tree, 40, 24));
+}
@@ -231,3 +231,55 @@ fn render_full_screen(size: (u16, u16)) {
 
     divan::black_box(render_to_string(&root, size.0));
 }
+
+/// Frame pair where only a spinner cell changes between renders
+fn spinner_frames() -> (Output, Output) {
+    let style = Style::default();
+    let mut previous = Output::new(120, 40);
+    let mut next = Output::new(120, 40);
+    for y in 0..40 {
+        let line = format!("Row {y:02}: mostly static dashboard content that does not change");
+        previous.write(0, y, &line, &style);
+        next.write(0, y, &line, &style);
+    }
+    previous.write(118, 0, "⠋", &style);
+    next.write(118, 0, "⠙", &style);
+    (previous, next)
+}
+
+#[divan::bench]
+fn frame_write_line_diff(bencher: divan::Bencher) {
+    let (previous, next) = spinner_frames();
+    let previous = previous.render();
+    bencher.bench_local(|| {
+        // Line-level diffing rewrites every changed line in full
+        let next = next.render();
+        let bytes: usize = next
+            .split("\r\n")
+            .zip(previous.split("\r\n"))
+            .filter(|(new, old)| new != old)
+            .map(|(new, _)| new.len())
+            .sum();
+        divan::black_box(bytes)
+    });
+}
+
+#[divan::bench]
+fn frame_write_cell_diff(bencher: divan::Bencher) {
+    let (previous, next) = spinner_frames();
+    bencher.bench_local(|| divan::black_box(next.diff(&previous).len()));
+}
+
+#[divan::bench(args = [50, 200])]
+fn layout_full_rebuild(rows: usize) {
+    let bench = rnk::testing::RenderBench::new(120, 40).iterations(10);
+    divan::black_box(bench.layout_full(|frame| rnk::testing::gen_bench_tree(rows, 4, frame)));
+}
+
+#[divan::bench(args = [50, 200])]
+fn layout_incremental(rows: usize) {
+    let bench = rnk::testing::RenderBench::new(120, 40).iterations(10);
+    divan::black_box(
+        bench.layout_incremental(|frame| rnk::testing::gen_bench_tree(rows, 4, frame)),
+    );
+}
@@ -2,19 +2,31 @@
 
 use std::fmt;
 
+use crate::icon_set::{IconSet, icon_set, substitute};
+
 /// An icon with optional color
+///
+/// Displaying an icon uses the current [`icon_set`], so Nerd Font glyphs
+/// degrade to unicode or ascii substitutes when the set says so.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct Icon {
     /// The icon character(s)
     pub glyph: &'static str,
     /// Optional hex color (e.g., "#ff0000")
     pub color: Option<String>,
+    /// Text shown instead of the glyph outside [`IconSet::NerdFont`]
+    pub fallback: Option<String>,
 }
 
 impl Icon {
     /// Create a new icon from a glyph
     pub fn new(glyph: &'static str) -> Self {
-        Self { glyph, color: None }
+        Self {
+            glyph,
+            color: None,
+            fallback: None,
+        }
     }
 
     /// Set the icon color (hex format)
@@ -23,11 +35,51 @@ impl Icon {
         self
     }
 
+    /// Get the default color for this glyph, if it is a known file icon
+    ///
+    /// Uses the same colors as the `icons::file::*_colored()` accessors.
+    pub fn default_color(&self) -> Option<&'static str> {
+        if self.glyph.is_empty() {
+            return None;
+        }
+        crate::icons::file::DEFAULT_COLORS
+            .iter()
+            .find(|(glyph, _)| *glyph == self.glyph)
+            .map(|(_, color)| *color)
+    }
+
+    /// Color the icon with its [`default_color`](Self::default_color),
+    /// unless a color is already set
+    pub fn with_default_color(mut self) -> Self {
+        if self.color.is_none() {
+            self.color = self.default_color().map(str::to_string);
+        }
+        self
+    }
+
+    /// Show `text` instead of the glyph when Nerd Fonts are not in use
+    ///
+    /// Overrides the built-in substitute for both [`IconSet::Unicode`] and
+    /// [`IconSet::Ascii`].
+    pub fn fallback(mut self, text: impl Into<String>) -> Self {
+        self.fallback = Some(text.into());
+        self
+    }
+
     /// Get the glyph string
     pub fn glyph(&self) -> &'static str {
         self.glyph
     }
 
+    /// Get what the icon renders as in `set`
+    pub fn glyph_for(&self, set: IconSet) -> &str {
+        match (set, &self.fallback) {
+            (IconSet::NerdFont, _) => self.glyph,
+            (_, Some(fallback)) => fallback,
+            (_, None) => substitute(self.glyph, set),
+        }
+    }
+
     /// Check if icon has a color
     pub fn has_color(&self) -> bool {
         self.color.is_some()
@@ -41,7 +93,7 @@ impl Icon {
 
 impl fmt::Display for Icon {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.glyph)
+        write!(f, "{}", self.glyph_for(icon_set()))
     }
 }
 
@@ -74,4 +126,26 @@ mod tests {
         let icon = Icon::new("");
         assert_eq!(format!("{}", icon), "");
     }
+
+    #[test]
+    fn test_icon_glyph_for_icon_set() {
+        let icon = Icon::new("\u{f07b}");
+        assert_eq!(icon.glyph_for(IconSet::NerdFont), "\u{f07b}");
+        assert_eq!(icon.glyph_for(IconSet::Ascii), "*");
+
+        let icon = icon.fallback("[dir]");
+        assert_eq!(icon.glyph_for(IconSet::NerdFont), "\u{f07b}");
+        assert_eq!(icon.glyph_for(IconSet::Unicode), "[dir]");
+        assert_eq!(icon.glyph_for(IconSet::Ascii), "[dir]");
+    }
+
+    #[test]
+    fn test_icon_default_color() {
+        let icon = Icon::new("x");
+        assert_eq!(icon.default_color(), None);
+        assert!(!icon.with_default_color().has_color());
+
+        let icon = Icon::new(crate::icons::file::rust()).colored("#000000");
+        assert_eq!(icon.with_default_color().get_color(), Some("#000000"));
+    }
 }
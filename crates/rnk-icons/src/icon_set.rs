@@ -0,0 +1,179 @@
+//! Icon sets and fallbacks for terminals without Nerd Fonts
+//!
+//! Whether a Nerd Font is installed can't be detected reliably, so the set
+//! is an explicit, process-wide setting that defaults to [`IconSet::NerdFont`].
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::icons::{git, misc, ui};
+
+/// Which glyphs icons render with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IconSet {
+    /// Nerd Font glyphs as-is
+    #[default]
+    NerdFont,
+    /// Plain unicode symbols that common terminal fonts include
+    Unicode,
+    /// ASCII only
+    Ascii,
+}
+
+static ICON_SET: AtomicU8 = AtomicU8::new(0);
+
+/// Set the icon set used when displaying an [`Icon`](crate::Icon)
+pub fn set_icon_set(set: IconSet) {
+    let value = match set {
+        IconSet::NerdFont => 0,
+        IconSet::Unicode => 1,
+        IconSet::Ascii => 2,
+    };
+    ICON_SET.store(value, Ordering::Relaxed);
+}
+
+/// The icon set used when displaying an [`Icon`](crate::Icon)
+pub fn icon_set() -> IconSet {
+    match ICON_SET.load(Ordering::Relaxed) {
+        1 => IconSet::Unicode,
+        2 => IconSet::Ascii,
+        _ => IconSet::NerdFont,
+    }
+}
+
+/// Nerd Font glyph, unicode substitute, ascii substitute
+const FALLBACKS: &[(&str, &str, &str)] = &[
+    // UI
+    (ui::folder(), "▸", "+"),
+    (ui::folder_open(), "▾", "-"),
+    (ui::folder_empty(), "▹", "+"),
+    (ui::arrow_right(), "→", ">"),
+    (ui::arrow_left(), "←", "<"),
+    (ui::arrow_up(), "↑", "^"),
+    (ui::arrow_down(), "↓", "v"),
+    (ui::chevron_right(), "›", ">"),
+    (ui::chevron_left(), "‹", "<"),
+    (ui::chevron_up(), "▴", "^"),
+    (ui::chevron_down(), "▾", "v"),
+    (ui::check(), "✓", "v"),
+    (ui::cross(), "✗", "x"),
+    (ui::plus(), "+", "+"),
+    (ui::minus(), "−", "-"),
+    (ui::undo(), "↶", "<"),
+    (ui::redo(), "↷", ">"),
+    (ui::refresh(), "↻", "@"),
+    (ui::info(), "ℹ", "i"),
+    (ui::warning(), "⚠", "!"),
+    (ui::error(), "✖", "x"),
+    (ui::success(), "✔", "v"),
+    (ui::question(), "?", "?"),
+    (ui::menu(), "☰", "="),
+    (ui::close(), "✕", "x"),
+    (ui::settings(), "⚙", "*"),
+    (ui::home(), "⌂", "~"),
+    (ui::star(), "☆", "*"),
+    (ui::star_filled(), "★", "*"),
+    (ui::heart(), "♡", "<3"),
+    (ui::heart_filled(), "♥", "<3"),
+    (ui::mail(), "✉", "@"),
+    (ui::terminal(), "❯", ">"),
+    (ui::spinner(), "◌", "o"),
+    (ui::tree_branch(), "├", "|"),
+    (ui::tree_last(), "└", "`"),
+    (ui::tree_vertical(), "│", "|"),
+    (ui::line_horizontal(), "─", "-"),
+    (ui::corner_tl(), "┌", "+"),
+    (ui::corner_tr(), "┐", "+"),
+    (ui::corner_br(), "┘", "+"),
+    // Git
+    (git::branch(), "⎇", "*"),
+    (git::commit(), "●", "o"),
+    (git::added(), "✚", "+"),
+    (git::modified(), "●", "~"),
+    (git::deleted(), "✖", "-"),
+    (git::renamed(), "➜", ">"),
+    (git::untracked(), "?", "?"),
+    (git::ignored(), "◌", "!"),
+    (git::conflict(), "≠", "!"),
+    (git::staged(), "✓", "+"),
+    (git::unstaged(), "✗", "x"),
+    (git::ahead(), "↑", "^"),
+    (git::behind(), "↓", "v"),
+    // Shapes
+    (misc::bullet(), "•", "*"),
+    (misc::circle(), "○", "o"),
+    (misc::circle_filled(), "●", "*"),
+    (misc::square(), "□", "[ ]"),
+    (misc::square_filled(), "■", "[#]"),
+    (misc::diamond(), "◇", "<>"),
+    (misc::diamond_filled(), "◆", "<>"),
+    (misc::triangle_right(), "▶", ">"),
+    (misc::triangle_left(), "◀", "<"),
+    (misc::triangle_up(), "▲", "^"),
+    (misc::triangle_down(), "▼", "v"),
+    (misc::powerline_right(), "▶", ">"),
+    (misc::powerline_left(), "◀", "<"),
+    (misc::powerline_right_thin(), "›", ">"),
+    (misc::powerline_left_thin(), "‹", "<"),
+    (misc::powerline_round_right(), "▶", ">"),
+    (misc::powerline_round_left(), "◀", "<"),
+];
+
+/// Whether `c` is in a Private Use Area, where Nerd Font glyphs live
+fn is_private_use(c: char) -> bool {
+    matches!(c, '\u{E000}'..='\u{F8FF}' | '\u{F0000}'..='\u{FFFFD}' | '\u{100000}'..='\u{10FFFD}')
+}
+
+/// The glyph to show for `glyph` in `set`
+///
+/// Known icons map to a matching symbol. Other Nerd Font glyphs become a
+/// generic bullet (`•` or `*`), while glyphs the set can already display
+/// are kept.
+///
+/// # Example
+///
+/// ```
+/// use rnk_icons::{IconSet, icons, substitute};
+///
+/// assert_eq!(substitute(icons::misc::triangle_right(), IconSet::Ascii), ">");
+/// assert_eq!(substitute("\u{e7a8}", IconSet::Unicode), "•");
+/// ```
+pub fn substitute(glyph: &'static str, set: IconSet) -> &'static str {
+    if set == IconSet::NerdFont || glyph.is_empty() {
+        return glyph;
+    }
+
+    if let Some(&(_, unicode, ascii)) = FALLBACKS.iter().find(|(nerd, _, _)| *nerd == glyph) {
+        return if set == IconSet::Unicode {
+            unicode
+        } else {
+            ascii
+        };
+    }
+
+    match set {
+        IconSet::Unicode if !glyph.chars().any(is_private_use) => glyph,
+        IconSet::Unicode => "•",
+        _ if glyph.is_ascii() => glyph,
+        _ => "*",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_substitute_known_and_unknown_glyphs() {
+        let nerd = "\u{e7a8}";
+        assert_eq!(substitute(nerd, IconSet::NerdFont), nerd);
+        assert_eq!(substitute(nerd, IconSet::Unicode), "•");
+        assert_eq!(substitute(nerd, IconSet::Ascii), "*");
+
+        // Symbols a plain font has are kept in unicode mode only
+        assert_eq!(substitute(misc::circle(), IconSet::Unicode), "○");
+        assert_eq!(substitute(misc::circle(), IconSet::Ascii), "o");
+        assert_eq!(substitute(ui::corner_tl(), IconSet::Ascii), "+");
+        assert_eq!(substitute("✓", IconSet::Unicode), "✓");
+        assert_eq!(substitute(">", IconSet::Ascii), ">");
+    }
+}
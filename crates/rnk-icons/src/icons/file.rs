@@ -1,4 +1,10 @@
 //! File type icons (Nerd Font)
+//!
+//! Each icon has an uncolored accessor returning the glyph and, for icons
+//! with a well-known brand color, a `*_colored()` accessor returning an
+//! [`Icon`] already colored with it.
+
+use crate::Icon;
 
 /// Rust file icon
 pub const fn rust() -> &'static str {
@@ -205,6 +211,63 @@ pub const fn default() -> &'static str {
     ""
 }
 
+/// Generate the `*_colored()` accessors and the default color table
+macro_rules! colored_icons {
+    ($($name:ident, $colored:ident => $color:literal;)+) => {
+        $(
+            #[doc = concat!("[`", stringify!($name), "`] icon in its default color (`", $color, "`)")]
+            pub fn $colored() -> Icon {
+                Icon::new($name()).colored($color)
+            }
+        )+
+
+        /// Default colors keyed by glyph
+        pub(crate) const DEFAULT_COLORS: &[(&str, &str)] = &[$(($name(), $color)),+];
+
+        /// Every `*_colored()` accessor, in `DEFAULT_COLORS` order
+        #[cfg(test)]
+        const COLORED: &[fn() -> Icon] = &[$($colored),+];
+    };
+}
+
+colored_icons! {
+    rust, rust_colored => "#DEA584";
+    python, python_colored => "#3776AB";
+    javascript, javascript_colored => "#F1E05A";
+    typescript, typescript_colored => "#3178C6";
+    go, go_colored => "#00ADD8";
+    c, c_colored => "#599EFF";
+    cpp, cpp_colored => "#F34B7D";
+    java, java_colored => "#B07219";
+    ruby, ruby_colored => "#CC342D";
+    php, php_colored => "#777BB4";
+    swift, swift_colored => "#F05138";
+    kotlin, kotlin_colored => "#A97BFF";
+    lua, lua_colored => "#51A0CF";
+    vim, vim_colored => "#019833";
+    shell, shell_colored => "#89E051";
+    html, html_colored => "#E34C26";
+    css, css_colored => "#563D7C";
+    sass, sass_colored => "#C6538C";
+    json, json_colored => "#CBCB41";
+    yaml, yaml_colored => "#CB171E";
+    toml, toml_colored => "#9C4221";
+    xml, xml_colored => "#0060AC";
+    markdown, markdown_colored => "#519ABA";
+    pdf, pdf_colored => "#B30B00";
+    image, image_colored => "#A074C4";
+    video, video_colored => "#FD971F";
+    audio, audio_colored => "#00AFFF";
+    archive, archive_colored => "#ECA517";
+    database, database_colored => "#DAD8D8";
+    docker, docker_colored => "#2496ED";
+    makefile, makefile_colored => "#6D8086";
+    license, license_colored => "#D0BF41";
+    gitfile, gitfile_colored => "#F05032";
+    npm, npm_colored => "#CB3837";
+    cargo, cargo_colored => "#DEA584";
+}
+
 /// Get icon for a file extension
 pub fn for_extension(ext: &str) -> &'static str {
     match ext.to_lowercase().as_str() {
@@ -291,4 +354,21 @@ mod tests {
         assert_eq!(for_filename("main.rs"), rust());
         assert_eq!(for_filename("Dockerfile"), docker());
     }
+
+    #[test]
+    fn test_colored_accessors() {
+        assert_eq!(rust_colored().glyph(), rust());
+        assert_eq!(rust_colored().get_color(), Some("#DEA584"));
+        assert_eq!(go_colored().get_color(), Some("#00ADD8"));
+    }
+
+    #[test]
+    fn test_colored_accessors_match_default_colors() {
+        assert_eq!(COLORED.len(), DEFAULT_COLORS.len());
+        for (colored, &(glyph, color)) in COLORED.iter().zip(DEFAULT_COLORS) {
+            let icon = colored();
+            assert_eq!(icon.glyph(), glyph);
+            assert_eq!(icon.get_color(), Some(color));
+        }
+    }
 }
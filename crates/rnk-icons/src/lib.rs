@@ -17,15 +17,39 @@
 //!
 //! // With color (requires rnk or rnk-style)
 //! let icon = Icon::new(icons::file::rust()).colored("#DEA584");
+//!
+//! // Or with the file type's default color
+//! let icon = icons::file::rust_colored();
+//! assert_eq!(icon.get_color(), Some("#DEA584"));
+//! ```
+//!
+//! # Without Nerd Fonts
+//!
+//! Nerd Font glyphs show up as boxes when the terminal font lacks them.
+//! Choose a plainer set and icons fall back to unicode or ascii symbols:
+//!
+//! ```rust
+//! use rnk_icons::{Icon, IconSet, icons, set_icon_set};
+//!
+//! set_icon_set(IconSet::Ascii);
+//! let expand = Icon::new(icons::misc::triangle_right());
+//! assert_eq!(expand.to_string(), ">");
+//!
+//! let folder = Icon::new(icons::ui::folder()).fallback("[dir]");
+//! assert_eq!(folder.to_string(), "[dir]");
+//! # set_icon_set(IconSet::NerdFont);
 //! ```
 
 mod icon;
+mod icon_set;
 pub mod icons;
 
 pub use icon::Icon;
+pub use icon_set::{IconSet, icon_set, set_icon_set, substitute};
 
 /// Prelude for convenient imports
 pub mod prelude {
     pub use crate::icon::Icon;
+    pub use crate::icon_set::{IconSet, set_icon_set};
     pub use crate::icons;
 }
@@ -21,7 +21,9 @@ fn main() {
     for item in &items {
         println!(
             "  {{ label: {:?}, value: {:?}, selected: {} }}",
-            item.label, item.value, item.selected
+            item.label(),
+            item.value().unwrap(),
+            item.is_selected()
         );
     }
     println!();
@@ -63,11 +65,11 @@ fn main() {
     for (i, item) in items.iter().enumerate() {
         let is_highlighted = i == highlighted_idx;
         let prefix = if is_highlighted { "❯ " } else { "  " };
-        let checkbox = if item.selected { "◉ " } else { "◯ " };
+        let checkbox = if item.is_selected() { "◉ " } else { "◯ " };
 
         let (color_start, color_end) = if is_highlighted {
             ("\x1b[1;36m", "\x1b[0m") // Bold cyan
-        } else if item.selected {
+        } else if item.is_selected() {
             ("\x1b[32m", "\x1b[0m") // Green
         } else {
             ("", "")
@@ -75,7 +77,11 @@ fn main() {
 
         println!(
             "  {}{}{}{}{}",
-            color_start, prefix, checkbox, item.label, color_end
+            color_start,
+            prefix,
+            checkbox,
+            item.label(),
+            color_end
         );
     }
     println!();
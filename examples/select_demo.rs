@@ -21,7 +21,8 @@ fn main() {
     for item in &items {
         println!(
             "  SelectItem {{ label: {:?}, value: {:?} }}",
-            item.label, item.value
+            item.label(),
+            item.value().unwrap()
         );
     }
     println!();
@@ -65,7 +66,7 @@ fn main() {
         let prefix = if is_highlighted { "❯ " } else { "  " };
         let color_start = if is_highlighted { "\x1b[1;36m" } else { "" };
         let color_end = if is_highlighted { "\x1b[0m" } else { "" };
-        println!("  {}{}{}{}", color_start, prefix, item.label, color_end);
+        println!("  {}{}{}{}", color_start, prefix, item.label(), color_end);
     }
     println!();
 
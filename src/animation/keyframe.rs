@@ -276,10 +276,17 @@ impl AnimationInstance {
     }
 
     /// Update the animation with elapsed time (call each frame)
+    ///
+    /// With [reduced motion](super::prefers_reduced_motion) on, the first
+    /// tick skips to the end; see [`skip_to_end`](Self::skip_to_end).
     pub fn tick(&mut self, delta: Duration) {
         if self.state != AnimationState::Running {
             return;
         }
+        if super::prefers_reduced_motion() {
+            self.skip_to_end();
+            return;
+        }
 
         self.elapsed += delta;
 
@@ -347,6 +354,26 @@ impl AnimationInstance {
             .interpolate(self.config.from, self.config.to, effective_t);
     }
 
+    /// Jump to the end state without animating
+    ///
+    /// A finite animation completes with its final fill value. An infinite
+    /// one has no end, so it holds its start value and pauses.
+    pub fn skip_to_end(&mut self) {
+        if self.config.iterations == 0 {
+            self.value = self.config.from;
+            self.state = AnimationState::Paused;
+            return;
+        }
+
+        self.elapsed = self.config.delay + self.config.duration * self.config.iterations;
+        self.current_iteration = self.config.iterations;
+        self.state = AnimationState::Completed;
+        self.value = match self.config.fill_mode {
+            FillMode::Forwards | FillMode::Both => self.config.to,
+            _ => self.config.from,
+        };
+    }
+
     /// Get the current value
     pub fn get(&self) -> f32 {
         self.value
@@ -537,6 +564,28 @@ mod tests {
         assert!(instance.is_completed());
     }
 
+    #[test]
+    fn test_skip_to_end() {
+        let mut instance = Animation::new()
+            .from(0.0)
+            .to(100.0)
+            .duration(100.ms())
+            .iterations(2)
+            .fill_mode(FillMode::Forwards)
+            .start();
+        instance.play();
+        instance.skip_to_end();
+        assert!(instance.is_completed());
+        assert_eq!(instance.get(), 100.0);
+        assert_eq!(instance.progress(), 1.0);
+
+        let mut pulse = Animation::pulse(100.ms()).start();
+        pulse.play();
+        pulse.skip_to_end();
+        assert_eq!(pulse.get(), 1.0);
+        assert!(!pulse.is_running());
+    }
+
     #[test]
     fn test_animation_infinite() {
         let anim = Animation::new()
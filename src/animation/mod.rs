@@ -4,10 +4,14 @@
 
 mod easing;
 mod keyframe;
+mod reduced_motion;
 mod spring;
 
 pub use easing::Easing;
 pub use keyframe::{
     Animation, AnimationDirection, AnimationInstance, AnimationState, DurationExt, FillMode,
 };
+#[cfg(test)]
+pub(crate) use reduced_motion::with_reduced_motion;
+pub use reduced_motion::{prefers_reduced_motion, set_reduced_motion};
 pub use spring::{Spring, SpringColor, SpringValue, SpringValue2D};
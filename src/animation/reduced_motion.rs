@@ -0,0 +1,74 @@
+//! Global reduced-motion preference
+//!
+//! When reduced motion is on, spinners show a static glyph, indeterminate
+//! progress bars stop sweeping, and transitions and finite keyframe
+//! animations jump straight to their end state. Besides accessibility, this
+//! makes snapshots of animated components deterministic.
+//!
+//! The preference is read once from `RNK_REDUCED_MOTION` (any value other
+//! than empty, `0` or `false` turns it on) and can be overridden with
+//! [`set_reduced_motion`].
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static REDUCED_MOTION: AtomicBool = AtomicBool::new(false);
+static REDUCED_MOTION_CHECKED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(test)]
+thread_local! {
+    static TEST_OVERRIDE: std::cell::Cell<Option<bool>> = const { std::cell::Cell::new(None) };
+}
+
+/// Check whether animations should be reduced
+pub fn prefers_reduced_motion() -> bool {
+    #[cfg(test)]
+    if let Some(reduced) = TEST_OVERRIDE.with(std::cell::Cell::get) {
+        return reduced;
+    }
+    if !REDUCED_MOTION_CHECKED.load(Ordering::SeqCst) {
+        let reduced =
+            env_requests_reduced_motion(std::env::var("RNK_REDUCED_MOTION").ok().as_deref());
+        REDUCED_MOTION.store(reduced, Ordering::SeqCst);
+        REDUCED_MOTION_CHECKED.store(true, Ordering::SeqCst);
+    }
+    REDUCED_MOTION.load(Ordering::SeqCst)
+}
+
+/// Force reduced motion on or off, overriding the environment
+pub fn set_reduced_motion(reduced: bool) {
+    REDUCED_MOTION.store(reduced, Ordering::SeqCst);
+    REDUCED_MOTION_CHECKED.store(true, Ordering::SeqCst);
+}
+
+/// Run `f` with reduced motion forced on or off for the current thread only,
+/// so tests don't race on the global preference
+#[cfg(test)]
+pub(crate) fn with_reduced_motion<R>(reduced: bool, f: impl FnOnce() -> R) -> R {
+    let previous = TEST_OVERRIDE.with(|cell| cell.replace(Some(reduced)));
+    let result = f();
+    TEST_OVERRIDE.with(|cell| cell.set(previous));
+    result
+}
+
+/// Decide whether an `RNK_REDUCED_MOTION` value turns reduced motion on
+fn env_requests_reduced_motion(value: Option<&str>) -> bool {
+    value.is_some_and(|value| {
+        let value = value.trim();
+        !value.is_empty() && value != "0" && !value.eq_ignore_ascii_case("false")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_values() {
+        assert!(!env_requests_reduced_motion(None));
+        assert!(!env_requests_reduced_motion(Some("")));
+        assert!(!env_requests_reduced_motion(Some("0")));
+        assert!(!env_requests_reduced_motion(Some("FALSE")));
+        assert!(env_requests_reduced_motion(Some("1")));
+        assert!(env_requests_reduced_motion(Some("true")));
+    }
+}
@@ -93,11 +93,39 @@ pub enum TerminalCmd {
     DisableBracketedPaste,
 }
 
+/// Which end of a throttle window fires the command.
+///
+/// See [`Cmd::throttle`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Edge {
+    /// Fire the first command immediately, drop the rest of the window.
+    #[default]
+    Leading,
+    /// Fire the latest command when the window ends.
+    Trailing,
+    /// Fire the first command immediately and the latest one when the
+    /// window ends, if another arrived in between.
+    Both,
+}
+
+impl Edge {
+    /// Whether the first command of a window fires immediately.
+    pub fn fires_leading(self) -> bool {
+        matches!(self, Edge::Leading | Edge::Both)
+    }
+
+    /// Whether the latest command fires at the end of the window.
+    pub fn fires_trailing(self) -> bool {
+        matches!(self, Edge::Trailing | Edge::Both)
+    }
+}
+
 /// Unified command type.
 ///
 /// - `Cmd<()>`: side-effect commands used by runtime/hooks.
 /// - `Cmd<M>`: typed commands that produce messages of type `M`.
 #[derive(Default)]
+#[non_exhaustive]
 pub enum Cmd<M = ()>
 where
     M: Send + 'static,
@@ -112,6 +140,9 @@ where
     /// Execute multiple commands sequentially.
     Sequence(Vec<Cmd<M>>),
 
+    /// Execute multiple commands with at most `limit` running at once.
+    BatchLimited { limit: usize, cmds: Vec<Cmd<M>> },
+
     /// Execute an async task that produces a message.
     Perform {
         future: Pin<Box<dyn Future<Output = M> + Send + 'static>>,
@@ -141,6 +172,14 @@ where
         msg_fn: Box<dyn FnOnce(ExecResult) -> M + Send + 'static>,
     },
 
+    /// Run a command at most once per window for a key.
+    Throttle {
+        key: String,
+        duration: Duration,
+        edge: Edge,
+        cmd: Box<Cmd<M>>,
+    },
+
     /// Terminal control command.
     Terminal(TerminalCmd),
 }
@@ -168,6 +207,26 @@ where
         }
     }
 
+    /// Create a batch command that runs at most `limit` commands at a time.
+    ///
+    /// The remaining commands are queued and started as earlier ones
+    /// complete, so each command's message is delivered as soon as it
+    /// finishes. A `limit` of zero is treated as one.
+    pub fn batch_limited(limit: usize, cmds: impl IntoIterator<Item = Cmd<M>>) -> Self {
+        let limit = limit.max(1);
+        let mut cmds: Vec<Cmd<M>> = cmds
+            .into_iter()
+            .filter(|cmd| !matches!(cmd, Cmd::None))
+            .collect();
+
+        match cmds.len() {
+            0 => Cmd::None,
+            1 => cmds.pop().unwrap(),
+            n if n <= limit => Cmd::Batch(cmds),
+            _ => Cmd::BatchLimited { limit, cmds },
+        }
+    }
+
     /// Create a sequence command that executes multiple commands in order.
     pub fn sequence(cmds: impl IntoIterator<Item = Cmd<M>>) -> Self {
         let mut cmds: Vec<Cmd<M>> = cmds
@@ -243,6 +302,23 @@ where
         Cmd::exec(config, msg_fn)
     }
 
+    /// Run `cmd` at most once per `duration` for commands sharing `key`.
+    ///
+    /// The executor opens a window for `key` on the first command. With
+    /// [`Edge::Leading`] that command runs immediately and the rest of the
+    /// window's commands are dropped; with [`Edge::Trailing`] the latest
+    /// command runs when the window ends; [`Edge::Both`] does both.
+    /// Unlike a debounce, a steady stream of commands still fires once per
+    /// window instead of waiting for a pause.
+    pub fn throttle(key: impl Into<String>, duration: Duration, edge: Edge, cmd: Cmd<M>) -> Self {
+        Cmd::Throttle {
+            key: key.into(),
+            duration,
+            edge,
+            cmd: Box::new(cmd),
+        }
+    }
+
     /// Clear the terminal screen.
     pub fn clear_screen() -> Self {
         Cmd::Terminal(TerminalCmd::ClearScreen)
@@ -319,10 +395,27 @@ where
     }
 
     /// Map command messages to a different type.
+    ///
+    /// Lifts a child component's command into its parent's message type:
+    ///
+    /// ```
+    /// use rnk::cmd::Cmd;
+    ///
+    /// enum ChildMsg {
+    ///     Loaded(String),
+    /// }
+    /// enum ParentMsg {
+    ///     Child(ChildMsg),
+    /// }
+    ///
+    /// let child = Cmd::perform(|| async { ChildMsg::Loaded("data".into()) });
+    /// let parent: Cmd<ParentMsg> = child.map(ParentMsg::Child);
+    /// # let _ = parent;
+    /// ```
     pub fn map<N, F>(self, f: F) -> Cmd<N>
     where
         N: Send + 'static,
-        F: FnOnce(M) -> N + Send + 'static + Clone,
+        F: FnOnce(M) -> N + Send + Clone + 'static,
     {
         match self {
             Cmd::None => Cmd::None,
@@ -330,6 +423,10 @@ where
             Cmd::Sequence(cmds) => {
                 Cmd::Sequence(cmds.into_iter().map(|c| c.map(f.clone())).collect())
             }
+            Cmd::BatchLimited { limit, cmds } => Cmd::BatchLimited {
+                limit,
+                cmds: cmds.into_iter().map(|c| c.map(f.clone())).collect(),
+            },
             Cmd::Perform { future } => Cmd::Perform {
                 future: Box::pin(async move {
                     let msg = future.await;
@@ -352,11 +449,37 @@ where
                 config,
                 msg_fn: Box::new(move |r| f(msg_fn(r))),
             },
+            Cmd::Throttle {
+                key,
+                duration,
+                edge,
+                cmd,
+            } => Cmd::Throttle {
+                key,
+                duration,
+                edge,
+                cmd: Box::new(cmd.map(f)),
+            },
             Cmd::Terminal(tc) => Cmd::Terminal(tc),
         }
     }
 }
 
+impl<T, E> Cmd<Result<T, E>>
+where
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    /// Map the error of a fallible command, leaving successes untouched.
+    pub fn map_err<E2, F>(self, f: F) -> Cmd<Result<T, E2>>
+    where
+        E2: Send + 'static,
+        F: FnOnce(E) -> E2 + Send + Clone + 'static,
+    {
+        self.map(move |result| result.map_err(f))
+    }
+}
+
 impl<M> std::fmt::Debug for Cmd<M>
 where
     M: Send + 'static,
@@ -366,6 +489,11 @@ where
             Cmd::None => write!(f, "Cmd::None"),
             Cmd::Batch(cmds) => f.debug_tuple("Cmd::Batch").field(cmds).finish(),
             Cmd::Sequence(cmds) => f.debug_tuple("Cmd::Sequence").field(cmds).finish(),
+            Cmd::BatchLimited { limit, cmds } => f
+                .debug_struct("Cmd::BatchLimited")
+                .field("limit", limit)
+                .field("cmds", cmds)
+                .finish(),
             Cmd::Perform { .. } => write!(f, "Cmd::Perform {{ ... }}"),
             Cmd::Sleep { duration, then } => f
                 .debug_struct("Cmd::Sleep")
@@ -383,6 +511,18 @@ where
             Cmd::Exec { config, .. } => {
                 f.debug_struct("Cmd::Exec").field("config", config).finish()
             }
+            Cmd::Throttle {
+                key,
+                duration,
+                edge,
+                cmd,
+            } => f
+                .debug_struct("Cmd::Throttle")
+                .field("key", key)
+                .field("duration", duration)
+                .field("edge", edge)
+                .field("cmd", cmd)
+                .finish(),
             Cmd::Terminal(tc) => write!(f, "Cmd::Terminal({:?})", tc),
         }
     }
@@ -422,6 +562,21 @@ mod tests {
         assert!(matches!(seq, Cmd::Sequence(_)));
     }
 
+    #[test]
+    fn test_cmd_batch_limited_normalizes() {
+        let none: Cmd = Cmd::batch_limited(2, vec![Cmd::None]);
+        assert!(none.is_none());
+
+        let fits: Cmd = Cmd::batch_limited(3, vec![Cmd::clear_screen(), Cmd::hide_cursor()]);
+        assert!(matches!(fits, Cmd::Batch(_)));
+
+        let queued: Cmd = Cmd::batch_limited(
+            0,
+            vec![Cmd::clear_screen(), Cmd::hide_cursor(), Cmd::show_cursor()],
+        );
+        assert!(matches!(queued, Cmd::BatchLimited { limit: 1, ref cmds } if cmds.len() == 3));
+    }
+
     #[test]
     fn test_cmd_perform_tick_every_exec() {
         let perform: Cmd<TestMsg> = Cmd::perform(|| async { TestMsg::Loaded("ok".into()) });
@@ -464,6 +619,77 @@ mod tests {
         assert!(matches!(parent_cmd, Cmd::Batch(_)));
     }
 
+    #[test]
+    fn test_cmd_throttle_maps_inner_command() {
+        let cmd: Cmd<TestMsg> = Cmd::throttle(
+            "fetch",
+            Duration::from_millis(100),
+            Edge::Trailing,
+            Cmd::perform(|| async { TestMsg::Tick(1) }),
+        );
+        let mapped: Cmd<String> = cmd.map(|msg| format!("{:?}", msg));
+
+        let Cmd::Throttle { key, edge, cmd, .. } = mapped else {
+            panic!("expected Cmd::Throttle");
+        };
+        assert_eq!(key, "fetch");
+        assert_eq!(edge, Edge::Trailing);
+        assert!(edge.fires_trailing() && !edge.fires_leading());
+        assert!(Edge::Both.fires_leading() && Edge::Both.fires_trailing());
+        let Cmd::Perform { future } = *cmd else {
+            panic!("expected Cmd::Perform");
+        };
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        assert_eq!(rt.block_on(future), "Tick(1)");
+    }
+
+    #[test]
+    fn test_cmd_map_transforms_perform_output() {
+        let cmd: Cmd<usize> =
+            Cmd::perform(|| async { TestMsg::Loaded("data".into()) }).map(|msg| match msg {
+                TestMsg::Loaded(s) => s.len(),
+                TestMsg::Tick(n) => n as usize,
+            });
+
+        let Cmd::Perform { future } = cmd else {
+            panic!("expected Cmd::Perform");
+        };
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        assert_eq!(rt.block_on(future), 4);
+    }
+
+    #[test]
+    fn test_cmd_map_accepts_fn_once() {
+        let source = String::from("child");
+        // Moves `source` out, so the closure is only FnOnce
+        let cmd: Cmd<(String, usize)> =
+            Cmd::perform(|| async { 3 }).map(move |n: usize| (source, n));
+
+        let Cmd::Perform { future } = cmd else {
+            panic!("expected Cmd::Perform");
+        };
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        assert_eq!(rt.block_on(future), ("child".to_string(), 3));
+    }
+
+    #[test]
+    fn test_cmd_map_err_only_touches_errors() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let run = |cmd: Cmd<Result<u32, String>>| match cmd {
+            Cmd::Perform { future } => rt.block_on(future),
+            other => panic!("expected Cmd::Perform, got {:?}", other),
+        };
+
+        let failed: Cmd<Result<u32, &str>> = Cmd::perform(|| async { Err("boom") });
+        assert_eq!(
+            run(failed.map_err(|e| format!("load failed: {e}"))),
+            Err("load failed: boom".to_string())
+        );
+
+        let ok: Cmd<Result<u32, &str>> = Cmd::perform(|| async { Ok(7) });
+        assert_eq!(run(ok.map_err(|e| e.to_string())), Ok(7));
+    }
+
     #[test]
     fn test_terminal_cmd_variants_exist() {
         assert!(matches!(
@@ -2,14 +2,15 @@
 //!
 //! The executor is responsible for:
 //! - Managing a Tokio runtime for async tasks
-//! - Executing commands (Perform, Sleep, Batch, Sequence, Tick, Every, Exec)
+//! - Executing commands (Perform, Sleep, Batch, BatchLimited, Sequence, Tick, Every, Exec, Throttle)
 //! - Notifying the render loop when tasks complete
 //! - Supporting graceful shutdown
 //! - Queueing Exec requests for the event loop to handle
 
 use super::{Cmd, ExecRequest};
 use crate::renderer::registry::queue_exec_request;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
 use std::time::Instant;
 use tokio::sync::mpsc;
 
@@ -63,6 +64,20 @@ impl CmdRenderNotifier {
 pub struct CmdExecutor {
     runtime: Option<Arc<tokio::runtime::Runtime>>,
     render_handle: CmdRenderNotifier,
+    throttles: Arc<Mutex<HashMap<String, ThrottleWindow>>>,
+}
+
+/// An open throttle window for one key
+struct ThrottleWindow {
+    /// Latest command waiting for the trailing edge
+    pending: Option<PendingCmd>,
+}
+
+/// A deferred command with the completion it must signal
+struct PendingCmd {
+    cmd: Cmd,
+    completion: Option<tokio::sync::oneshot::Sender<()>>,
+    notify_render: bool,
 }
 
 impl CmdExecutor {
@@ -90,6 +105,7 @@ impl CmdExecutor {
         Self {
             runtime,
             render_handle: CmdRenderNotifier::new(render_tx),
+            throttles: Arc::default(),
         }
     }
 
@@ -140,6 +156,7 @@ impl CmdExecutor {
             return;
         };
         let render_handle = self.render_handle.clone();
+        let throttles = Arc::clone(&self.throttles);
 
         /// Signal completion or optionally request render
         macro_rules! finish {
@@ -169,11 +186,13 @@ impl CmdExecutor {
                         for cmd in cmds {
                             let rt = Arc::clone(&runtime_clone);
                             let rh = render_handle_clone.clone();
+                            let th = Arc::clone(&throttles);
 
                             let handle = tokio::spawn(async move {
                                 let temp = CmdExecutor {
                                     runtime: Some(rt),
                                     render_handle: rh,
+                                    throttles: th,
                                 };
                                 let (tx, rx) = tokio::sync::oneshot::channel();
                                 temp.execute_cmd(cmd, Some(tx), false);
@@ -199,6 +218,54 @@ impl CmdExecutor {
                 }
             }
 
+            Cmd::BatchLimited { limit, cmds } => {
+                if cmds.is_empty() {
+                    finish!(completion, notify_render, render_handle);
+                    return;
+                }
+
+                let runtime_clone = Arc::clone(runtime);
+                let render_handle_clone = render_handle.clone();
+                let permits = Arc::new(tokio::sync::Semaphore::new(limit.max(1)));
+
+                runtime.spawn(async move {
+                    let mut handles = Vec::with_capacity(cmds.len());
+
+                    for cmd in cmds {
+                        // Queue here until a running command frees its slot
+                        let Ok(permit) = Arc::clone(&permits).acquire_owned().await else {
+                            break;
+                        };
+                        let rt = Arc::clone(&runtime_clone);
+                        let rh = render_handle_clone.clone();
+                        let th = Arc::clone(&throttles);
+
+                        let handle = tokio::spawn(async move {
+                            let temp = CmdExecutor {
+                                runtime: Some(rt),
+                                render_handle: rh.clone(),
+                                throttles: th,
+                            };
+                            let (tx, rx) = tokio::sync::oneshot::channel();
+                            temp.execute_cmd(cmd, Some(tx), false);
+                            let _ = rx.await;
+                            drop(permit);
+                            // Deliver each result as it lands, not after the whole batch
+                            if notify_render {
+                                rh.request();
+                            }
+                        });
+                        handles.push(handle);
+                    }
+
+                    for handle in handles {
+                        let _ = handle.await;
+                    }
+
+                    finish!(completion, false, render_handle_clone);
+                });
+            }
+
             Cmd::Sequence(cmds) => {
                 if cmds.is_empty() {
                     finish!(completion, notify_render, render_handle);
@@ -213,6 +280,7 @@ impl CmdExecutor {
                         let temp = CmdExecutor {
                             runtime: Some(Arc::clone(&runtime_clone)),
                             render_handle: render_handle_clone.clone(),
+                            throttles: Arc::clone(&throttles),
                         };
                         let (tx, rx) = tokio::sync::oneshot::channel();
                         temp.execute_cmd(cmd, Some(tx), false);
@@ -245,6 +313,7 @@ impl CmdExecutor {
                             let temp = CmdExecutor {
                                 runtime: Some(runtime_clone),
                                 render_handle: render_handle_clone.clone(),
+                                throttles,
                             };
                             if completion.is_some() {
                                 let (tx, rx) = tokio::sync::oneshot::channel();
@@ -315,6 +384,71 @@ impl CmdExecutor {
                 }
             }
 
+            Cmd::Throttle {
+                key,
+                duration,
+                edge,
+                cmd,
+            } => {
+                let mut windows = throttles.lock().unwrap_or_else(PoisonError::into_inner);
+
+                if let Some(window) = windows.get_mut(&key) {
+                    // Inside an open window: only the latest trailing command survives
+                    let dropped = if edge.fires_trailing() {
+                        window
+                            .pending
+                            .replace(PendingCmd {
+                                cmd: *cmd,
+                                completion,
+                                notify_render,
+                            })
+                            .and_then(|pending| pending.completion)
+                    } else {
+                        completion
+                    };
+                    drop(windows);
+                    finish!(dropped, false, render_handle);
+                    return;
+                }
+
+                let (leading, pending) = if edge.fires_leading() {
+                    (Some((*cmd, completion)), None)
+                } else {
+                    let pending = PendingCmd {
+                        cmd: *cmd,
+                        completion,
+                        notify_render,
+                    };
+                    (None, Some(pending))
+                };
+                windows.insert(key.clone(), ThrottleWindow { pending });
+                drop(windows);
+
+                let runtime_clone = Arc::clone(runtime);
+                let render_handle_clone = render_handle.clone();
+                let window_throttles = Arc::clone(&throttles);
+                runtime.spawn(async move {
+                    tokio::time::sleep(duration).await;
+                    let pending = window_throttles
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .remove(&key)
+                        .and_then(|window| window.pending);
+                    if let Some(pending) = pending {
+                        let temp = CmdExecutor {
+                            runtime: Some(runtime_clone),
+                            render_handle: render_handle_clone,
+                            throttles: window_throttles,
+                        };
+                        temp.execute_cmd(pending.cmd, pending.completion, pending.notify_render);
+                    }
+                });
+
+                if let Some((cmd, completion)) = leading {
+                    self.execute_cmd(cmd, completion, notify_render);
+                }
+            }
+
             Cmd::Terminal(tc) => {
                 crate::renderer::registry::queue_terminal_cmd(tc);
                 finish!(completion, notify_render, render_handle);
@@ -403,6 +537,7 @@ pub fn run_exec_process(config: &super::ExecConfig) -> super::ExecResult {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cmd::Edge;
     use std::sync::Arc;
     use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
     use std::time::Duration;
@@ -420,6 +555,7 @@ mod tests {
         let executor = CmdExecutor {
             runtime: None,
             render_handle: CmdRenderNotifier::new(tx),
+            throttles: Arc::default(),
         };
 
         executor.execute(Cmd::perform(|| async {}));
@@ -1124,4 +1260,182 @@ mod tests {
 
         assert_eq!(counter.load(Ordering::SeqCst), 3);
     }
+
+    // ==================== BatchLimited Tests ====================
+
+    #[tokio::test]
+    async fn test_batch_limited_caps_concurrency() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let executor = CmdExecutor::new(tx);
+
+        let running = Arc::new(AtomicU32::new(0));
+        let peak = Arc::new(AtomicU32::new(0));
+        let done = Arc::new(AtomicU32::new(0));
+
+        let cmds = (0..6).map(|_| {
+            let running = Arc::clone(&running);
+            let peak = Arc::clone(&peak);
+            let done = Arc::clone(&done);
+            Cmd::perform(move || async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                done.fetch_add(1, Ordering::SeqCst);
+            })
+        });
+
+        let cmd = Cmd::batch_limited(2, cmds);
+        assert!(matches!(cmd, Cmd::BatchLimited { limit: 2, .. }));
+        executor.execute(cmd);
+
+        // One render per completed command
+        for _ in 0..6 {
+            tokio::time::timeout(Duration::from_secs(2), rx.recv())
+                .await
+                .expect("timeout")
+                .expect("channel closed");
+        }
+
+        assert_eq!(done.load(Ordering::SeqCst), 6);
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_batch_limited_completes_inside_sequence() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let executor = CmdExecutor::new(tx);
+
+        let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let batch = (0..3).map(|i| {
+            let order = Arc::clone(&order);
+            Cmd::perform(move || async move {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                order.lock().unwrap().push(i);
+            })
+        });
+        let last = Arc::clone(&order);
+
+        executor.execute(Cmd::sequence(vec![
+            Cmd::batch_limited(1, batch),
+            Cmd::perform(move || async move {
+                last.lock().unwrap().push(99);
+            }),
+        ]));
+
+        tokio::time::timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("channel closed");
+
+        assert_eq!(*order.lock().unwrap(), vec![0, 1, 2, 99]);
+    }
+
+    // ==================== Throttle Tests ====================
+
+    fn record(log: &Arc<std::sync::Mutex<Vec<u32>>>, value: u32) -> Cmd {
+        let log = Arc::clone(log);
+        Cmd::perform(move || async move {
+            log.lock().unwrap().push(value);
+        })
+    }
+
+    fn throttle_burst(executor: &CmdExecutor, edge: Edge) -> Arc<std::sync::Mutex<Vec<u32>>> {
+        let log = Arc::new(std::sync::Mutex::new(Vec::new()));
+        for i in 1..=3 {
+            executor.execute(Cmd::throttle(
+                "scroll",
+                Duration::from_millis(50),
+                edge,
+                record(&log, i),
+            ));
+        }
+        log
+    }
+
+    #[tokio::test]
+    async fn test_throttle_leading_fires_first_only() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let executor = CmdExecutor::new(tx);
+
+        let log = throttle_burst(&executor, Edge::Leading);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(*log.lock().unwrap(), vec![1]);
+
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        assert_eq!(*log.lock().unwrap(), vec![1]);
+
+        // A new window opens once the previous one has ended
+        executor.execute(Cmd::throttle(
+            "scroll",
+            Duration::from_millis(50),
+            Edge::Leading,
+            record(&log, 4),
+        ));
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(*log.lock().unwrap(), vec![1, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_throttle_trailing_fires_latest_at_window_end() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let executor = CmdExecutor::new(tx);
+
+        let log = throttle_burst(&executor, Edge::Trailing);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(log.lock().unwrap().is_empty());
+
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        assert_eq!(*log.lock().unwrap(), vec![3]);
+    }
+
+    #[tokio::test]
+    async fn test_throttle_both_edges_and_separate_keys() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let executor = CmdExecutor::new(tx);
+
+        let log = throttle_burst(&executor, Edge::Both);
+        executor.execute(Cmd::throttle(
+            "other",
+            Duration::from_millis(50),
+            Edge::Both,
+            record(&log, 10),
+        ));
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        let mut fired = log.lock().unwrap().clone();
+        fired.sort_unstable();
+        assert_eq!(fired, vec![1, 10]);
+
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        assert_eq!(log.lock().unwrap().last(), Some(&3));
+        assert_eq!(log.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_throttled_out_command_completes_inside_sequence() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let executor = CmdExecutor::new(tx);
+
+        let log = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let throttled = |value| {
+            Cmd::throttle(
+                "seq",
+                Duration::from_secs(5),
+                Edge::Leading,
+                record(&log, value),
+            )
+        };
+        executor.execute(Cmd::sequence(vec![
+            throttled(1),
+            throttled(2),
+            record(&log, 99),
+        ]));
+
+        tokio::time::timeout(Duration::from_secs(2), rx.recv())
+            .await
+            .expect("timeout")
+            .expect("channel closed");
+
+        assert_eq!(*log.lock().unwrap(), vec![1, 99]);
+    }
 }
@@ -9,10 +9,12 @@
 //! - [`Cmd::none`](crate::cmd::Cmd::none)
 //! - [`Cmd::perform`](crate::cmd::Cmd::perform)
 //! - [`Cmd::batch`](crate::cmd::Cmd::batch)
+//! - [`Cmd::batch_limited`](crate::cmd::Cmd::batch_limited)
 //! - [`Cmd::sequence`](crate::cmd::Cmd::sequence)
 //! - [`Cmd::sleep`](crate::cmd::Cmd::sleep)
 //! - [`Cmd::tick`](crate::cmd::Cmd::tick)
 //! - [`Cmd::every`](crate::cmd::Cmd::every)
+//! - [`Cmd::throttle`](crate::cmd::Cmd::throttle)
 //! - [`Cmd::exec`](crate::cmd::Cmd::exec)
 
 mod core;
@@ -20,7 +22,7 @@ mod exec;
 mod executor;
 mod tasks;
 
-pub use core::{AppMsg, BoxedMsg, Cmd, TerminalCmd};
+pub use core::{AppMsg, BoxedMsg, Cmd, Edge, TerminalCmd};
 pub use exec::{ExecConfig, ExecResult};
 pub use executor::{CmdExecutor, CmdRenderNotifier, run_exec_process};
 pub use tasks::{HttpRequest, HttpResponse, ProcessOutput};
@@ -2,7 +2,7 @@
 
 use crate::core::{Color, Element};
 
-use super::display::Text;
+use super::display::{Span, Text};
 use super::theme::{ComponentState, ComponentVariant, Theme, get_theme};
 
 /// Semantic role for button-like actions.
@@ -82,6 +82,145 @@ impl ActionShape {
             Self::Padded => format!("[ {} ]{}", label, hint),
         }
     }
+
+    /// Byte offset of the label within a formatted string.
+    pub(crate) fn label_offset(self) -> usize {
+        match self {
+            Self::Brackets | Self::Angles | Self::Parens => 1,
+            Self::Plain => 0,
+            Self::Padded => 2,
+        }
+    }
+}
+
+/// A label with an optional access key, written with a leading `&`.
+///
+/// `"&Save"` displays as `Save` with `S` as its access key, `"Save &As"`
+/// marks `A`, and `&&` produces a literal ampersand.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Mnemonic {
+    label: String,
+    index: Option<usize>,
+}
+
+impl Mnemonic {
+    /// Parse a label, stripping the `&` marker.
+    pub fn parse(raw: &str) -> Self {
+        let mut label = String::with_capacity(raw.len());
+        let mut index = None;
+        let mut chars = raw.chars().peekable();
+        let mut count = 0;
+
+        while let Some(ch) = chars.next() {
+            if ch == '&' {
+                match chars.peek() {
+                    Some('&') => {
+                        chars.next();
+                    }
+                    Some(next) if index.is_none() && !next.is_whitespace() => {
+                        index = Some(count);
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+            label.push(ch);
+            count += 1;
+        }
+
+        Self { label, index }
+    }
+
+    /// The label with the marker removed.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Character index of the access key within the label.
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    /// Use the first letter or digit of the label as the access key when
+    /// none is marked.
+    pub fn or_first_letter(mut self) -> Self {
+        if self.index.is_none() {
+            self.index = self.label.chars().position(char::is_alphanumeric);
+        }
+        self
+    }
+
+    /// The access key, lowercased.
+    pub fn key(&self) -> Option<char> {
+        self.index
+            .and_then(|i| self.label.chars().nth(i))
+            .map(|c| c.to_lowercase().next().unwrap_or(c))
+    }
+
+    /// Check whether typed input is this access key (case-insensitive).
+    pub fn matches(&self, input: &str) -> bool {
+        self.key().is_some_and(|key| input_char(input) == Some(key))
+    }
+
+    /// Split the label into spans with the access key underlined.
+    pub fn spans(&self) -> Vec<Span> {
+        underline_char(&self.label, 0, self.index)
+    }
+}
+
+/// Lowercased character for single-character input.
+pub(crate) fn input_char(input: &str) -> Option<char> {
+    let mut chars = input.chars();
+    let ch = chars.next()?;
+    if chars.next().is_some() {
+        return None;
+    }
+    ch.to_lowercase().next()
+}
+
+/// Split `text` into spans, underlining the character at `label_start + index`.
+pub(crate) fn underline_char(text: &str, label_start: usize, index: Option<usize>) -> Vec<Span> {
+    let Some(index) = index else {
+        return vec![Span::new(text)];
+    };
+    let Some((start, ch)) = text[label_start..].char_indices().nth(index) else {
+        return vec![Span::new(text)];
+    };
+    let start = label_start + start;
+    let end = start + ch.len_utf8();
+
+    let mut spans = Vec::with_capacity(3);
+    if start > 0 {
+        spans.push(Span::new(&text[..start]));
+    }
+    spans.push(Span::new(&text[start..end]).underline());
+    if end < text.len() {
+        spans.push(Span::new(&text[end..]));
+    }
+    spans
+}
+
+/// Pick the next item for an access key, cycling among ambiguous matches.
+///
+/// Returns the chosen index and whether it was the only match.
+pub(crate) fn next_mnemonic_match(
+    keys: impl IntoIterator<Item = Option<char>>,
+    current: usize,
+    pressed: char,
+) -> Option<(usize, bool)> {
+    let matches: Vec<usize> = keys
+        .into_iter()
+        .enumerate()
+        .filter(|(_, key)| *key == Some(pressed))
+        .map(|(i, _)| i)
+        .collect();
+
+    let next = matches
+        .iter()
+        .copied()
+        .find(|&i| i > current)
+        .or_else(|| matches.first().copied())?;
+    Some((next, matches.len() == 1))
 }
 
 /// Resolved terminal style for an action.
@@ -106,6 +245,7 @@ pub struct ActionButton {
     shape: ActionShape,
     hint: Option<char>,
     show_hint: bool,
+    mnemonic: Option<usize>,
 }
 
 impl ActionButton {
@@ -118,6 +258,7 @@ impl ActionButton {
             shape: ActionShape::Brackets,
             hint: None,
             show_hint: false,
+            mnemonic: None,
         }
     }
 
@@ -151,6 +292,12 @@ impl ActionButton {
         self
     }
 
+    /// Underline the label character at this index as the access key.
+    pub fn mnemonic(mut self, index: Option<usize>) -> Self {
+        self.mnemonic = index;
+        self
+    }
+
     /// Resolve this action against the current theme.
     pub fn style(&self) -> ActionStyle {
         self.style_with_theme(&get_theme())
@@ -176,7 +323,16 @@ impl ActionButton {
     /// Convert to a styled text node with a specific theme.
     pub fn into_text_with_theme(self, theme: &Theme) -> Text {
         let style = self.style_with_theme(theme);
-        let mut text = Text::new(self.formatted_label()).color(style.fg);
+        let label = self.formatted_label();
+        let mut text = match self.mnemonic {
+            Some(index) => Text::spans(underline_char(
+                &label,
+                self.shape.label_offset(),
+                Some(index),
+            )),
+            None => Text::new(label),
+        }
+        .color(style.fg);
         if let Some(bg) = style.bg {
             text = text.background(bg);
         }
@@ -238,4 +394,40 @@ mod tests {
         assert_eq!(style.bg, Some(theme.error));
         assert!(style.bold);
     }
+
+    #[test]
+    fn test_mnemonic_parse() {
+        let save = Mnemonic::parse("&Save");
+        assert_eq!(save.label(), "Save");
+        assert_eq!(save.index(), Some(0));
+        assert_eq!(save.key(), Some('s'));
+        assert!(save.matches("S"));
+        assert!(!save.matches("a"));
+
+        let save_as = Mnemonic::parse("Save &As && Exit");
+        assert_eq!(save_as.label(), "Save As & Exit");
+        assert_eq!(save_as.key(), Some('a'));
+
+        let plain = Mnemonic::parse("Plain & simple");
+        assert_eq!(plain.label(), "Plain & simple");
+        assert_eq!(plain.key(), None);
+    }
+
+    #[test]
+    fn test_mnemonic_spans_underline_key() {
+        let spans = Mnemonic::parse("Save &As").spans();
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[1].content, "A");
+        assert!(spans[1].style.underline);
+        assert!(!spans[0].style.underline);
+    }
+
+    #[test]
+    fn test_next_mnemonic_match_cycles() {
+        let keys = [Some('s'), Some('c'), Some('s'), None];
+        assert_eq!(next_mnemonic_match(keys, 0, 's'), Some((2, false)));
+        assert_eq!(next_mnemonic_match(keys, 2, 's'), Some((0, false)));
+        assert_eq!(next_mnemonic_match(keys, 2, 'c'), Some((1, true)));
+        assert_eq!(next_mnemonic_match(keys, 0, 'x'), None);
+    }
 }
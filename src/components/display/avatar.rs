@@ -16,6 +16,7 @@
 //!             Avatar::new("John Doe").into_element(),
 //!             Avatar::new("AB").size(AvatarSize::Large).into_element(),
 //!             Avatar::initials("CD").color(Color::Cyan).into_element(),
+//!             Avatar::from_name("Jane Doe").into_element(),
 //!         ])
 //!         .into_element()
 //! }
@@ -33,12 +34,18 @@ pub enum AvatarSize {
     Large,
 }
 
+/// Minimum WCAG contrast ratio between text and generated backgrounds
+const MIN_CONTRAST: f64 = 4.5;
+
 /// An avatar component for user representation
 #[derive(Debug, Clone)]
 pub struct Avatar {
     initials: String,
-    color: Color,
+    /// Text color; picked from the background when unset
+    color: Option<Color>,
     background: Color,
+    /// Name hash used to derive the background at render time
+    background_seed: Option<u32>,
     size: AvatarSize,
 }
 
@@ -49,8 +56,24 @@ impl Avatar {
         let initials = Self::extract_initials(&name);
         Self {
             initials,
-            color: Color::White,
+            color: None,
             background: Color::Blue,
+            background_seed: None,
+            size: AvatarSize::Medium,
+        }
+    }
+
+    /// Create an avatar from a name with a stable, name-derived background
+    ///
+    /// The background is recomputed against the final text color so it keeps
+    /// sufficient contrast even after calling [`Avatar::color`].
+    pub fn from_name(name: impl AsRef<str>) -> Self {
+        let name = name.as_ref();
+        Self {
+            initials: Self::initials_from_name(name),
+            color: None,
+            background: Color::Blue,
+            background_seed: Some(Self::hash_name(name)),
             size: AvatarSize::Medium,
         }
     }
@@ -59,21 +82,26 @@ impl Avatar {
     pub fn initials(initials: impl Into<String>) -> Self {
         Self {
             initials: initials.into(),
-            color: Color::White,
+            color: None,
             background: Color::Blue,
+            background_seed: None,
             size: AvatarSize::Medium,
         }
     }
 
     /// Set the text color
     pub fn color(mut self, color: Color) -> Self {
-        self.color = color;
+        self.color = Some(color);
         self
     }
 
     /// Set the background color
+    ///
+    /// Without an explicit [`Avatar::color`], the text color is picked with
+    /// [`Color::contrast_text`] so it stays readable.
     pub fn background(mut self, color: Color) -> Self {
         self.background = color;
+        self.background_seed = None;
         self
     }
 
@@ -92,6 +120,85 @@ impl Avatar {
             .to_uppercase()
     }
 
+    /// Generate up to two uppercase initials from a display name
+    ///
+    /// Multi-word names use the first letter of the first and last word
+    /// (`"Jane Q. Doe"` → `JD`). Single words use their first two letters
+    /// (`"alice"` → `AL`), and one-letter names yield a single initial.
+    pub fn initials_from_name(name: &str) -> String {
+        let words: Vec<&str> = name
+            .split_whitespace()
+            .filter(|word| word.chars().any(char::is_alphanumeric))
+            .collect();
+
+        let first_letter = |word: &str| word.chars().find(|c| c.is_alphanumeric());
+
+        let initials: String = match words.as_slice() {
+            [] => return "?".to_string(),
+            [word] => word
+                .chars()
+                .filter(|c| c.is_alphanumeric())
+                .take(2)
+                .collect(),
+            [first, .., last] => first_letter(first)
+                .into_iter()
+                .chain(first_letter(last))
+                .collect(),
+        };
+
+        initials.to_uppercase()
+    }
+
+    /// Derive a stable background color for a name
+    ///
+    /// The same name always maps to the same hue. The lightness is adjusted
+    /// until the result reaches a 4.5:1 contrast ratio against `foreground`.
+    pub fn color_for_name(name: &str, foreground: Color) -> Color {
+        Self::color_for_seed(Self::hash_name(name), foreground)
+    }
+
+    fn color_for_seed(seed: u32, foreground: Color) -> Color {
+        let hue = (seed % 360) as f64;
+        let saturation = 0.45 + ((seed >> 9) % 20) as f64 / 100.0;
+        // Move away from the foreground's luminance: darker behind light text,
+        // lighter behind dark text.
+        let darken = foreground.relative_luminance() > 0.18;
+
+        let mut lightness: f64 = if darken { 0.45 } else { 0.65 };
+        loop {
+            let candidate = Color::from_hsl(hue, saturation, lightness);
+            if candidate.contrast_ratio(&foreground) >= MIN_CONTRAST
+                || !(0.05..=0.95).contains(&lightness)
+            {
+                return candidate;
+            }
+            lightness += if darken { -0.05 } else { 0.05 };
+        }
+    }
+
+    /// FNV-1a hash, stable across platforms and Rust versions
+    fn hash_name(name: &str) -> u32 {
+        name.trim()
+            .to_lowercase()
+            .bytes()
+            .fold(0x811c_9dc5, |hash: u32, byte| {
+                (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+            })
+    }
+
+    /// Resolve the text and background colors
+    fn colors(&self) -> (Color, Color) {
+        match (self.background_seed, self.color) {
+            // Generated backgrounds are chosen to contrast with the text
+            (Some(seed), color) => {
+                let color = color.unwrap_or(Color::White);
+                (color, Self::color_for_seed(seed, color))
+            }
+            (None, Some(color)) => (color, self.background),
+            (None, None) => (self.background.contrast_text(), self.background),
+        }
+    }
+
     /// Convert to Element
     pub fn into_element(self) -> Element {
         let (left, right) = match self.size {
@@ -101,10 +208,11 @@ impl Avatar {
         };
 
         let content = format!("{}{}{}", left, self.initials, right);
+        let (color, background) = self.colors();
 
         Text::new(content)
-            .color(self.color)
-            .background(self.background)
+            .color(color)
+            .background(background)
             .bold()
             .into_element()
     }
@@ -138,6 +246,59 @@ mod tests {
         assert_eq!(av.initials, "XY");
     }
 
+    #[test]
+    fn test_initials_from_name() {
+        assert_eq!(Avatar::initials_from_name("Jane Doe"), "JD");
+        assert_eq!(Avatar::initials_from_name("jane mary doe"), "JD");
+        assert_eq!(Avatar::initials_from_name("alice"), "AL");
+        assert_eq!(Avatar::initials_from_name("X"), "X");
+        assert_eq!(Avatar::initials_from_name("  "), "?");
+        assert_eq!(Avatar::initials_from_name("Émile Zola"), "ÉZ");
+    }
+
+    #[test]
+    fn test_color_for_name_is_stable() {
+        let a = Avatar::color_for_name("Jane Doe", Color::White);
+        let b = Avatar::color_for_name("Jane Doe", Color::White);
+        let c = Avatar::color_for_name("John Smith", Color::White);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_color_for_name_has_contrast() {
+        for name in ["Jane Doe", "Alice", "Bob", "Zoe Quinn", "李雷"] {
+            for fg in [Color::White, Color::Black, Color::BrightWhite] {
+                let bg = Avatar::color_for_name(name, fg);
+                assert!(
+                    bg.contrast_ratio(&fg) >= MIN_CONTRAST,
+                    "{name} on {fg:?} produced {bg:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_name_background_follows_color() {
+        let av = Avatar::from_name("Jane Doe").color(Color::Black);
+        assert_eq!(av.initials, "JD");
+        assert!(av.background_seed.is_some());
+
+        let av = av.background(Color::Red);
+        assert_eq!(av.background_seed, None);
+    }
+
+    #[test]
+    fn test_avatar_background_picks_readable_text() {
+        assert_eq!(Avatar::new("Ann").colors(), (Color::White, Color::Blue));
+
+        let av = Avatar::new("Ann").background(Color::BrightCyan);
+        assert_eq!(av.colors(), (Color::Black, Color::BrightCyan));
+
+        let av = av.color(Color::Magenta);
+        assert_eq!(av.colors().0, Color::Magenta);
+    }
+
     #[test]
     fn test_avatar_into_element() {
         let _ = Avatar::new("Test User").into_element();
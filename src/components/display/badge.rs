@@ -23,7 +23,7 @@
 
 use crate::components::capsule::CapsuleElementBuilder;
 use crate::components::capsule_variant::CapsuleVariant;
-use crate::core::Element;
+use crate::core::{Color, Element};
 
 /// Badge variant for different styles.
 pub type BadgeVariant = CapsuleVariant;
@@ -34,6 +34,8 @@ pub struct Badge {
     text: String,
     variant: BadgeVariant,
     pill: bool,
+    color: Option<Color>,
+    background: Option<Color>,
 }
 
 impl Badge {
@@ -43,6 +45,8 @@ impl Badge {
             text: text.into(),
             variant: BadgeVariant::Default,
             pill: false,
+            color: None,
+            background: None,
         }
     }
 
@@ -58,9 +62,35 @@ impl Badge {
         self
     }
 
+    /// Set the text color, overriding the variant
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the background color, overriding the variant
+    ///
+    /// Without an explicit [`Badge::color`], the text color is picked with
+    /// [`Color::contrast_text`] so it stays readable.
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Resolve the text and background colors
+    fn colors(&self) -> (Color, Color) {
+        let (fg, bg) = self.variant.badge_colors();
+        match (self.color, self.background) {
+            (Some(fg), Some(bg)) => (fg, bg),
+            (None, Some(bg)) => (bg.contrast_text(), bg),
+            (Some(fg), None) => (fg, bg),
+            (None, None) => (fg, bg),
+        }
+    }
+
     /// Convert to Element
     pub fn into_element(self) -> Element {
-        let (fg, bg) = self.variant.badge_colors();
+        let (fg, bg) = self.colors();
 
         if self.pill {
             CapsuleElementBuilder::new(self.text, fg, bg).into_element()
@@ -94,6 +124,20 @@ mod tests {
         assert_eq!(badge.variant, BadgeVariant::Success);
     }
 
+    #[test]
+    fn test_badge_background_picks_readable_text() {
+        let light = Badge::new("x").background(Color::Rgb(250, 240, 200));
+        assert_eq!(light.colors(), (Color::Black, Color::Rgb(250, 240, 200)));
+
+        let dark = Badge::new("x").background(Color::Rgb(20, 20, 60));
+        assert_eq!(dark.colors().0, Color::White);
+
+        let explicit = Badge::new("x")
+            .background(Color::Rgb(20, 20, 60))
+            .color(Color::Yellow);
+        assert_eq!(explicit.colors().0, Color::Yellow);
+    }
+
     #[test]
     fn test_badge_into_element() {
         let badge = Badge::new("Test").variant(BadgeVariant::Primary);
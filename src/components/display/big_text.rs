@@ -0,0 +1,277 @@
+//! BigText component for figlet-style banner text
+//!
+//! Renders short strings in a bundled block font several rows tall, for
+//! splash screens and hero headers.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use rnk::prelude::*;
+//! use rnk::components::{BigFont, BigText, Gradient};
+//!
+//! fn splash() -> Element {
+//!     BigText::new("rnk")
+//!         .font(BigFont::Block)
+//!         .gradient(Gradient::sunset())
+//!         .into_element()
+//! }
+//! ```
+
+use crate::components::{Gradient, Line, Span, Text};
+use crate::core::{Color, Element, Style};
+
+/// Bundled fonts for [`BigText`]
+///
+/// Both draw the same 3×5 pixel glyphs, covering ASCII letters (case
+/// folded), digits and common punctuation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BigFont {
+    /// Full blocks, two columns per pixel: 5 rows, 6 columns per glyph
+    #[default]
+    Block,
+    /// Half blocks, two pixels per cell: 3 rows, 3 columns per glyph
+    Small,
+}
+
+impl BigFont {
+    /// Rows of text one line of this font takes
+    pub fn height(self) -> usize {
+        match self {
+            BigFont::Block => GLYPH_ROWS,
+            BigFont::Small => GLYPH_ROWS.div_ceil(2),
+        }
+    }
+
+    /// Render one glyph's rows
+    fn render(self, glyph: &[&str; GLYPH_ROWS]) -> Vec<String> {
+        let pixel = |row: usize, col: usize| glyph[row].as_bytes()[col] == b'#';
+        match self {
+            BigFont::Block => glyph
+                .iter()
+                .map(|row| {
+                    row.chars()
+                        .map(|c| if c == '#' { "██" } else { "  " })
+                        .collect()
+                })
+                .collect(),
+            BigFont::Small => (0..GLYPH_ROWS)
+                .step_by(2)
+                .map(|row| {
+                    (0..GLYPH_COLS)
+                        .map(|col| {
+                            let top = pixel(row, col);
+                            let bottom = row + 1 < GLYPH_ROWS && pixel(row + 1, col);
+                            match (top, bottom) {
+                                (true, true) => '█',
+                                (true, false) => '▀',
+                                (false, true) => '▄',
+                                (false, false) => ' ',
+                            }
+                        })
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Blank columns between glyphs
+    fn gap(self) -> usize {
+        match self {
+            BigFont::Block => 2,
+            BigFont::Small => 1,
+        }
+    }
+}
+
+const GLYPH_ROWS: usize = 5;
+const GLYPH_COLS: usize = 3;
+
+const BLANK: [&str; GLYPH_ROWS] = ["...", "...", "...", "...", "..."];
+
+/// 3×5 pixel glyphs, `#` for a filled pixel
+fn glyph(c: char) -> Option<[&'static str; GLYPH_ROWS]> {
+    Some(match c.to_ascii_uppercase() {
+        'A' => [".#.", "#.#", "###", "#.#", "#.#"],
+        'B' => ["##.", "#.#", "##.", "#.#", "##."],
+        'C' => [".##", "#..", "#..", "#..", ".##"],
+        'D' => ["##.", "#.#", "#.#", "#.#", "##."],
+        'E' => ["###", "#..", "##.", "#..", "###"],
+        'F' => ["###", "#..", "##.", "#..", "#.."],
+        'G' => [".##", "#..", "#.#", "#.#", ".##"],
+        'H' => ["#.#", "#.#", "###", "#.#", "#.#"],
+        'I' => ["###", ".#.", ".#.", ".#.", "###"],
+        'J' => ["..#", "..#", "..#", "#.#", ".#."],
+        'K' => ["#.#", "#.#", "##.", "#.#", "#.#"],
+        'L' => ["#..", "#..", "#..", "#..", "###"],
+        'M' => ["#.#", "###", "#.#", "#.#", "#.#"],
+        'N' => ["##.", "#.#", "#.#", "#.#", "#.#"],
+        'O' => [".#.", "#.#", "#.#", "#.#", ".#."],
+        'P' => ["##.", "#.#", "##.", "#..", "#.."],
+        'Q' => [".#.", "#.#", "#.#", "##.", ".##"],
+        'R' => ["##.", "#.#", "##.", "#.#", "#.#"],
+        'S' => [".##", "#..", ".#.", "..#", "##."],
+        'T' => ["###", ".#.", ".#.", ".#.", ".#."],
+        'U' => ["#.#", "#.#", "#.#", "#.#", "###"],
+        'V' => ["#.#", "#.#", "#.#", "#.#", ".#."],
+        'W' => ["#.#", "#.#", "#.#", "###", "#.#"],
+        'X' => ["#.#", "#.#", ".#.", "#.#", "#.#"],
+        'Y' => ["#.#", "#.#", ".#.", ".#.", ".#."],
+        'Z' => ["###", "..#", ".#.", "#..", "###"],
+        '0' => ["###", "#.#", "#.#", "#.#", "###"],
+        '1' => [".#.", "##.", ".#.", ".#.", "###"],
+        '2' => ["##.", "..#", ".#.", "#..", "###"],
+        '3' => ["##.", "..#", ".#.", "..#", "##."],
+        '4' => ["#.#", "#.#", "###", "..#", "..#"],
+        '5' => ["###", "#..", "##.", "..#", "##."],
+        '6' => [".##", "#..", "###", "#.#", "###"],
+        '7' => ["###", "..#", ".#.", ".#.", ".#."],
+        '8' => ["###", "#.#", "###", "#.#", "###"],
+        '9' => ["###", "#.#", "###", "..#", "##."],
+        ' ' => BLANK,
+        '!' => [".#.", ".#.", ".#.", "...", ".#."],
+        '?' => ["##.", "..#", ".#.", "...", ".#."],
+        '.' => ["...", "...", "...", "...", ".#."],
+        ',' => ["...", "...", "...", ".#.", "#.."],
+        ':' => ["...", ".#.", "...", ".#.", "..."],
+        '-' => ["...", "...", "###", "...", "..."],
+        '+' => ["...", ".#.", "###", ".#.", "..."],
+        '_' => ["...", "...", "...", "...", "###"],
+        '\'' => [".#.", ".#.", "...", "...", "..."],
+        '/' => ["..#", "..#", ".#.", "#..", "#.."],
+        _ => return None,
+    })
+}
+
+/// Large banner text drawn with a block font
+///
+/// Each line of the text becomes [`BigFont::height`] rows. Characters the
+/// font has no glyph for are drawn as a space.
+#[derive(Debug, Clone)]
+pub struct BigText {
+    text: String,
+    font: BigFont,
+    color: Option<Color>,
+    gradient: Option<Gradient>,
+}
+
+impl BigText {
+    /// Create big text
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            font: BigFont::default(),
+            color: None,
+            gradient: None,
+        }
+    }
+
+    /// Set the font
+    pub fn font(mut self, font: BigFont) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Set a single color
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Fill the glyphs with a gradient running left to right
+    pub fn gradient(mut self, gradient: Gradient) -> Self {
+        self.gradient = Some(gradient);
+        self
+    }
+
+    /// Rendered rows, without styling
+    pub fn rows(&self) -> Vec<String> {
+        let mut rows = Vec::new();
+        for line in self.text.lines() {
+            let mut line_rows = vec![String::new(); self.font.height()];
+            for (index, c) in line.chars().enumerate() {
+                let glyph = glyph(c).unwrap_or(BLANK);
+                for (row, part) in line_rows.iter_mut().zip(self.font.render(&glyph)) {
+                    if index > 0 {
+                        row.push_str(&" ".repeat(self.font.gap()));
+                    }
+                    row.push_str(&part);
+                }
+            }
+            rows.extend(line_rows);
+        }
+        rows
+    }
+
+    /// Convert to Element
+    pub fn into_element(self) -> Element {
+        let rows = self.rows();
+        let width = rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let lines = rows
+            .into_iter()
+            .map(|row| match &self.gradient {
+                Some(gradient) => Line::from_spans(
+                    row.chars()
+                        .enumerate()
+                        .map(|(col, c)| {
+                            let position = if width > 1 {
+                                col as f32 / (width - 1) as f32
+                            } else {
+                                0.0
+                            };
+                            Span::styled(
+                                c.to_string(),
+                                Style::new().fg(gradient.color_at(position)),
+                            )
+                        })
+                        .collect(),
+                ),
+                None => Line::from_spans(vec![Span::new(row)]),
+            })
+            .collect();
+
+        let mut text = Text::from_lines(lines);
+        if let Some(color) = self.color {
+            text = text.color(color);
+        }
+        text.into_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_big_text_fonts_and_fallback() {
+        let block = BigText::new("Hi").rows();
+        assert_eq!(block.len(), 5);
+        assert_eq!(block[0], "██  ██  ██████");
+        assert_eq!(block[2], "██████    ██  ");
+
+        let small = BigText::new("h~i").font(BigFont::Small).rows();
+        assert_eq!(small, vec!["█ █     ▀█▀", "█▀█      █ ", "▀ ▀     ▀▀▀"]);
+    }
+
+    #[test]
+    fn test_big_text_lays_out_all_rows() {
+        let element = BigText::new("OK")
+            .font(BigFont::Small)
+            .gradient(Gradient::from_two(
+                Color::Rgb(255, 0, 0),
+                Color::Rgb(0, 0, 255),
+            ))
+            .into_element();
+        let plain = crate::testing::TestRenderer::new(20, 3).render_to_plain(&element);
+        let lines: Vec<&str> = plain.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["▄▀▄ █ █", "█ █ █▀▄", " ▀  ▀ ▀"]);
+
+        let spans = element.spans.as_ref().unwrap();
+        assert_eq!(spans[0].spans[0].style.color, Some(Color::Rgb(255, 0, 0)));
+        assert_eq!(spans[0].spans[6].style.color, Some(Color::Rgb(0, 0, 255)));
+    }
+}
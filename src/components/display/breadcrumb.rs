@@ -15,10 +15,32 @@
 //!         .into_element()
 //! }
 //! ```
+//!
+//! Paths can be shortened to fit a header or status bar:
+//!
+//! ```rust,ignore
+//! // "/home/me/Projects/rnk/src" is shown as "~ / P / r / src"
+//! Breadcrumb::from_path(&cwd)
+//!     .abbreviate(AbbreviateMode::ShortenMiddle)
+//!     .into_element()
+//! ```
 
 use crate::components::{Box as RnkBox, Text};
 use crate::core::{Color, Element, FlexDirection};
 
+/// How a breadcrumb shortens its items
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AbbreviateMode {
+    /// Show every item in full
+    #[default]
+    None,
+    /// Replace a leading home directory with `~`
+    HomeTilde,
+    /// `HomeTilde`, and shorten every item but the last to its first
+    /// letter, like fish's prompt (`~/P/r/src`)
+    ShortenMiddle,
+}
+
 /// A breadcrumb component for navigation paths
 #[derive(Debug, Clone)]
 pub struct Breadcrumb {
@@ -26,6 +48,8 @@ pub struct Breadcrumb {
     separator: String,
     active_color: Color,
     inactive_color: Color,
+    abbreviate: AbbreviateMode,
+    home: Option<String>,
 }
 
 impl Breadcrumb {
@@ -36,9 +60,16 @@ impl Breadcrumb {
             separator: " / ".to_string(),
             active_color: Color::White,
             inactive_color: Color::BrightBlack,
+            abbreviate: AbbreviateMode::None,
+            home: None,
         }
     }
 
+    /// Create a breadcrumb with one item per segment of `path`
+    pub fn from_path(path: &str) -> Self {
+        Self::new().items(path_segments(path))
+    }
+
     /// Set the breadcrumb items
     pub fn items(mut self, items: Vec<impl Into<String>>) -> Self {
         self.items = items.into_iter().map(|s| s.into()).collect();
@@ -63,12 +94,49 @@ impl Breadcrumb {
         self
     }
 
+    /// Set how items are shortened (default: [`AbbreviateMode::None`])
+    pub fn abbreviate(mut self, mode: AbbreviateMode) -> Self {
+        self.abbreviate = mode;
+        self
+    }
+
+    /// Set the home directory replaced by `~` (default: `$HOME`)
+    pub fn home(mut self, home: impl Into<String>) -> Self {
+        self.home = Some(home.into());
+        self
+    }
+
+    /// Items as they are displayed, after abbreviation
+    fn display_items(&self) -> Vec<String> {
+        if self.abbreviate == AbbreviateMode::None {
+            return self.items.clone();
+        }
+
+        let home = self.home.clone().or_else(home_dir).unwrap_or_default();
+        let home = path_segments(&home);
+        let mut items = self.items.clone();
+        let under_home = !home.is_empty() && items.starts_with(&home);
+        if under_home {
+            items.splice(..home.len(), ["~".to_string()]);
+        }
+
+        if self.abbreviate == AbbreviateMode::ShortenMiddle {
+            let skip = usize::from(under_home);
+            let last_idx = items.len().saturating_sub(1);
+            for item in items.iter_mut().take(last_idx).skip(skip) {
+                *item = shorten(item);
+            }
+        }
+        items
+    }
+
     /// Convert to Element
     pub fn into_element(self) -> Element {
         let mut children = Vec::new();
-        let last_idx = self.items.len().saturating_sub(1);
+        let items = self.display_items();
+        let last_idx = items.len().saturating_sub(1);
 
-        for (i, item) in self.items.iter().enumerate() {
+        for (i, item) in items.iter().enumerate() {
             let is_last = i == last_idx;
 
             // Add item
@@ -108,11 +176,33 @@ impl Default for Breadcrumb {
 
 /// Create a breadcrumb from a path string
 pub fn breadcrumb_from_path(path: &str) -> Element {
-    let items: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
-    Breadcrumb::new()
-        .items(items)
-        .separator(" / ")
-        .into_element()
+    Breadcrumb::from_path(path).separator(" / ").into_element()
+}
+
+/// Non-empty segments of a `/` or `\` separated path
+fn path_segments(path: &str) -> Vec<String> {
+    path.split(['/', '\\'])
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// The user's home directory, from the environment
+fn home_dir() -> Option<String> {
+    std::env::var("HOME")
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .ok()
+        .filter(|home| !home.is_empty())
+}
+
+/// First letter of a path segment, keeping the dot of hidden ones
+fn shorten(segment: &str) -> String {
+    let (dot, rest) = match segment.strip_prefix('.') {
+        Some(rest) => (".", rest),
+        None => ("", segment),
+    };
+    let first: String = rest.chars().take(1).collect();
+    format!("{dot}{first}")
 }
 
 #[cfg(test)]
@@ -147,4 +237,35 @@ mod tests {
     fn test_breadcrumb_from_path() {
         let _ = breadcrumb_from_path("/home/user/documents");
     }
+
+    #[test]
+    fn test_breadcrumb_abbreviate() {
+        let path = "/home/me/Projects/.config/rnk/src";
+        let items = |mode| {
+            Breadcrumb::from_path(path)
+                .home("/home/me/")
+                .abbreviate(mode)
+                .display_items()
+        };
+
+        assert_eq!(
+            items(AbbreviateMode::None),
+            vec!["home", "me", "Projects", ".config", "rnk", "src"]
+        );
+        assert_eq!(
+            items(AbbreviateMode::HomeTilde),
+            vec!["~", "Projects", ".config", "rnk", "src"]
+        );
+        assert_eq!(
+            items(AbbreviateMode::ShortenMiddle),
+            vec!["~", "P", ".c", "r", "src"]
+        );
+
+        // Outside home only the middle is shortened; a sibling directory
+        // whose name merely starts with the home path doesn't match
+        let outside = Breadcrumb::from_path("/home/meg/work")
+            .home("/home/me")
+            .abbreviate(AbbreviateMode::ShortenMiddle);
+        assert_eq!(outside.display_items(), vec!["h", "m", "work"]);
+    }
 }
@@ -16,6 +16,10 @@ pub struct Calendar {
     selected_day: Option<u32>,
     /// Highlighted dates
     highlighted: Vec<u32>,
+    /// Keyboard-focused date (day of month)
+    focused_day: Option<u32>,
+    /// Dates that cannot be selected
+    disabled: Vec<u32>,
     /// First day of week (0 = Sunday, 1 = Monday)
     first_day_of_week: u8,
     /// Show week numbers
@@ -42,6 +46,8 @@ impl Calendar {
             month: month.clamp(1, 12),
             selected_day: None,
             highlighted: Vec::new(),
+            focused_day: None,
+            disabled: Vec::new(),
             first_day_of_week: 0, // Sunday
             show_week_numbers: false,
             header_color: Color::Cyan,
@@ -65,6 +71,18 @@ impl Calendar {
         self
     }
 
+    /// Set the keyboard-focused day, drawn inverted
+    pub fn focused(mut self, day: u32) -> Self {
+        self.focused_day = Some(day);
+        self
+    }
+
+    /// Set days that cannot be selected, drawn dimmed
+    pub fn disabled(mut self, days: Vec<u32>) -> Self {
+        self.disabled = days;
+        self
+    }
+
     /// Set first day of week (0 = Sunday, 1 = Monday)
     pub fn first_day_of_week(mut self, day: u8) -> Self {
         self.first_day_of_week = day % 7;
@@ -289,13 +307,18 @@ impl Calendar {
         let text = format!("{:2}", day);
         let mut text_elem = Text::new(text);
 
-        if is_selected {
+        if self.disabled.contains(&day) {
+            text_elem = text_elem.dim();
+        } else if is_selected {
             text_elem = text_elem.color(self.selected_color).bold();
         } else if is_today {
             text_elem = text_elem.color(self.today_color);
         } else if is_highlighted {
             text_elem = text_elem.color(self.highlighted_color);
         }
+        if self.focused_day == Some(day) {
+            text_elem = text_elem.inverse();
+        }
 
         RnkBox::new()
             .width(3)
@@ -440,6 +463,18 @@ mod tests {
         assert_eq!(cal.highlighted, vec![1, 10, 20]);
     }
 
+    #[test]
+    fn test_calendar_focused_and_disabled() {
+        let cal = Calendar::new(2024, 6).focused(12).disabled(vec![13, 14]);
+        assert_eq!(cal.focused_day, Some(12));
+        assert_eq!(cal.disabled, vec![13, 14]);
+
+        let focused = cal.build_day_cell(12);
+        assert!(focused.children.get(0).unwrap().style.inverse);
+        let disabled = cal.build_day_cell(13);
+        assert!(disabled.children.get(0).unwrap().style.dim);
+    }
+
     #[test]
     fn test_calendar_monday_first() {
         let cal = Calendar::new(2024, 6).monday_first();
@@ -27,6 +27,8 @@ pub struct Chip {
     selected: bool,
     disabled: bool,
     icon: Option<String>,
+    color: Option<Color>,
+    background: Option<Color>,
 }
 
 impl Chip {
@@ -37,6 +39,8 @@ impl Chip {
             selected: false,
             disabled: false,
             icon: None,
+            color: None,
+            background: None,
         }
     }
 
@@ -58,15 +62,41 @@ impl Chip {
         self
     }
 
+    /// Set the text color
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the background color (ignored while disabled)
+    ///
+    /// Without an explicit [`Chip::color`], the text color is picked with
+    /// [`Color::contrast_text`] so it stays readable.
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Resolve the text and background colors
+    fn colors(&self) -> (Color, Color) {
+        if self.disabled {
+            return (Color::BrightBlack, Color::Ansi256(238));
+        }
+        let bg = self.background.unwrap_or(if self.selected {
+            Color::Blue
+        } else {
+            Color::Ansi256(240)
+        });
+        let fg = self.color.unwrap_or_else(|| match self.background {
+            Some(bg) => bg.contrast_text(),
+            None => Color::White,
+        });
+        (fg, bg)
+    }
+
     /// Convert to Element
     pub fn into_element(self) -> Element {
-        let (fg, bg) = if self.disabled {
-            (Color::BrightBlack, Color::Ansi256(238))
-        } else if self.selected {
-            (Color::White, Color::Blue)
-        } else {
-            (Color::White, Color::Ansi256(240))
-        };
+        let (fg, bg) = self.colors();
 
         let mut builder = CapsuleElementBuilder::new(self.label, fg, bg).prefix(if self.selected {
             "●"
@@ -111,6 +141,20 @@ mod tests {
         assert!(c.disabled);
     }
 
+    #[test]
+    fn test_chip_background_picks_readable_text() {
+        let chip = Chip::new("Tag").background(Color::BrightYellow);
+        assert_eq!(chip.colors(), (Color::Black, Color::BrightYellow));
+
+        let chip = Chip::new("Tag").selected(true);
+        assert_eq!(chip.colors(), (Color::White, Color::Blue));
+
+        let chip = Chip::new("Tag")
+            .background(Color::BrightYellow)
+            .disabled(true);
+        assert_eq!(chip.colors().1, Color::Ansi256(238));
+    }
+
     #[test]
     fn test_chip_into_element() {
         let _ = Chip::new("Test").into_element();
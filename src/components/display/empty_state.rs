@@ -16,7 +16,8 @@
 //!             .icon("📭")
 //!             .title("No items")
 //!             .description("Add some items to get started")
-//!             .into_element()
+//!             .action("Press n to create")
+//!             .centered(80, 20)
 //!     } else {
 //!         // Render items...
 //!     }
@@ -25,6 +26,10 @@
 
 use crate::components::{Box as RnkBox, Text};
 use crate::core::{AlignItems, Color, Element, FlexDirection, JustifyContent};
+use crate::layout::center;
+
+/// Padding around the content block, in cells
+const PADDING: u16 = 2;
 
 /// An empty state component
 #[derive(Debug, Clone)]
@@ -32,6 +37,8 @@ pub struct EmptyState {
     icon: Option<String>,
     title: String,
     description: Option<String>,
+    action: Option<String>,
+    max_width: Option<u16>,
 }
 
 impl EmptyState {
@@ -41,6 +48,8 @@ impl EmptyState {
             icon: None,
             title: "No data".to_string(),
             description: None,
+            action: None,
+            max_width: None,
         }
     }
 
@@ -62,6 +71,28 @@ impl EmptyState {
         self
     }
 
+    /// Set a call-to-action hint shown below the description
+    pub fn action(mut self, action: impl Into<String>) -> Self {
+        self.action = Some(action.into());
+        self
+    }
+
+    /// Set the maximum width of the content block; longer descriptions wrap
+    pub fn max_width(mut self, width: u16) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Convert to an element centered within a `width` × `height` area
+    ///
+    /// Unless a smaller [`max_width`](Self::max_width) is set, the
+    /// description wraps to fit inside the area.
+    pub fn centered(mut self, width: u16, height: u16) -> Element {
+        let available = width.saturating_sub(PADDING * 2).max(1);
+        self.max_width = Some(self.max_width.map_or(available, |w| w.min(available)));
+        center(width, height, self.into_element())
+    }
+
     /// Convert to Element
     pub fn into_element(self) -> Element {
         let mut children = Vec::new();
@@ -79,16 +110,38 @@ impl EmptyState {
                 .into_element(),
         );
 
-        // Description
+        // Description, one element per wrapped line so each line centers
         if let Some(desc) = self.description {
-            children.push(Text::new(desc).color(Color::BrightBlack).into_element());
+            let lines: Vec<String> = match self.max_width {
+                Some(width) => textwrap::wrap(&desc, width.max(1) as usize)
+                    .into_iter()
+                    .map(|line| line.into_owned())
+                    .collect(),
+                None => vec![desc],
+            };
+            children.push(
+                RnkBox::new()
+                    .flex_direction(FlexDirection::Column)
+                    .align_items(AlignItems::Center)
+                    .children(
+                        lines
+                            .into_iter()
+                            .map(|line| Text::new(line).color(Color::BrightBlack).into_element()),
+                    )
+                    .into_element(),
+            );
+        }
+
+        // Call to action
+        if let Some(action) = self.action {
+            children.push(Text::new(action).color(Color::Cyan).into_element());
         }
 
         RnkBox::new()
             .flex_direction(FlexDirection::Column)
             .justify_content(JustifyContent::Center)
             .align_items(AlignItems::Center)
-            .padding(2)
+            .padding(PADDING)
             .gap(0.5)
             .children(children)
             .into_element()
@@ -104,6 +157,7 @@ impl Default for EmptyState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::TestRenderer;
 
     #[test]
     fn test_empty_state_creation() {
@@ -122,6 +176,29 @@ mod tests {
         assert_eq!(es.description, Some("Nothing here".to_string()));
     }
 
+    #[test]
+    fn test_empty_state_centered() {
+        let plain = TestRenderer::new(40, 12).render_to_plain(
+            &EmptyState::new()
+                .title("No items")
+                .description("Create your first item to see it listed here")
+                .action("Press n to create")
+                .centered(40, 12),
+        );
+        let lines: Vec<&str> = plain.lines().collect();
+
+        let title_row = lines.iter().position(|l| l.contains("No items")).unwrap();
+        assert!(title_row > 0, "block should be vertically centered");
+        let title = lines[title_row];
+        let indent = title.len() - title.trim_start().len();
+        assert!(indent > 10, "title should be horizontally centered");
+
+        // Description wrapped within the area minus padding
+        assert!(lines.iter().all(|l| l.trim_end().chars().count() <= 40));
+        assert!(plain.contains("Create your first item"));
+        assert!(plain.contains("Press n to create"));
+    }
+
     #[test]
     fn test_empty_state_into_element() {
         let _ = EmptyState::new().into_element();
@@ -2,109 +2,107 @@
 //!
 //! Provides gradient text rendering for terminal UI.
 
-use crate::core::Color;
+use crate::core::{BlendSpace, Color};
 
 /// A color gradient for text
 #[derive(Debug, Clone)]
 pub struct Gradient {
     /// Colors in the gradient
     colors: Vec<Color>,
+    /// Color space used between stops
+    space: BlendSpace,
 }
 
 impl Gradient {
     /// Create a new gradient from colors
     pub fn new(colors: Vec<Color>) -> Self {
-        Self { colors }
+        Self {
+            colors,
+            space: BlendSpace::default(),
+        }
     }
 
     /// Create a gradient from two colors
     pub fn from_two(start: Color, end: Color) -> Self {
-        Self {
-            colors: vec![start, end],
-        }
+        Self::new(vec![start, end])
+    }
+
+    /// Set the color space used to interpolate between stops
+    ///
+    /// Defaults to [`BlendSpace::Srgb`]; use [`BlendSpace::Linear`] to avoid
+    /// dark bands between saturated stops.
+    pub fn blend_space(mut self, space: BlendSpace) -> Self {
+        self.space = space;
+        self
     }
 
     /// Create a rainbow gradient
     pub fn rainbow() -> Self {
-        Self {
-            colors: vec![
-                Color::Red,
-                Color::Yellow,
-                Color::Green,
-                Color::Cyan,
-                Color::Blue,
-                Color::Magenta,
-            ],
-        }
+        Self::new(vec![
+            Color::Red,
+            Color::Yellow,
+            Color::Green,
+            Color::Cyan,
+            Color::Blue,
+            Color::Magenta,
+        ])
     }
 
     /// Create a warm gradient (red to yellow)
     pub fn warm() -> Self {
-        Self {
-            colors: vec![
-                Color::Rgb(255, 0, 0),
-                Color::Rgb(255, 128, 0),
-                Color::Rgb(255, 255, 0),
-            ],
-        }
+        Self::new(vec![
+            Color::Rgb(255, 0, 0),
+            Color::Rgb(255, 128, 0),
+            Color::Rgb(255, 255, 0),
+        ])
     }
 
     /// Create a cool gradient (cyan to blue to purple)
     pub fn cool() -> Self {
-        Self {
-            colors: vec![
-                Color::Rgb(0, 255, 255),
-                Color::Rgb(0, 128, 255),
-                Color::Rgb(128, 0, 255),
-            ],
-        }
+        Self::new(vec![
+            Color::Rgb(0, 255, 255),
+            Color::Rgb(0, 128, 255),
+            Color::Rgb(128, 0, 255),
+        ])
     }
 
     /// Create a pastel rainbow gradient
     pub fn pastel() -> Self {
-        Self {
-            colors: vec![
-                Color::Rgb(255, 179, 186), // Pink
-                Color::Rgb(255, 223, 186), // Peach
-                Color::Rgb(255, 255, 186), // Yellow
-                Color::Rgb(186, 255, 201), // Mint
-                Color::Rgb(186, 225, 255), // Sky
-                Color::Rgb(218, 186, 255), // Lavender
-            ],
-        }
+        Self::new(vec![
+            Color::Rgb(255, 179, 186), // Pink
+            Color::Rgb(255, 223, 186), // Peach
+            Color::Rgb(255, 255, 186), // Yellow
+            Color::Rgb(186, 255, 201), // Mint
+            Color::Rgb(186, 225, 255), // Sky
+            Color::Rgb(218, 186, 255), // Lavender
+        ])
     }
 
     /// Create a sunset gradient
     pub fn sunset() -> Self {
-        Self {
-            colors: vec![
-                Color::Rgb(255, 94, 77),  // Coral
-                Color::Rgb(255, 154, 0),  // Orange
-                Color::Rgb(255, 206, 84), // Gold
-            ],
-        }
+        Self::new(vec![
+            Color::Rgb(255, 94, 77),  // Coral
+            Color::Rgb(255, 154, 0),  // Orange
+            Color::Rgb(255, 206, 84), // Gold
+        ])
     }
 
     /// Create an ocean gradient
     pub fn ocean() -> Self {
-        Self {
-            colors: vec![
-                Color::Rgb(0, 105, 148),   // Deep blue
-                Color::Rgb(0, 168, 198),   // Teal
-                Color::Rgb(127, 219, 255), // Light blue
-            ],
-        }
+        Self::new(vec![
+            Color::Rgb(0, 105, 148),   // Deep blue
+            Color::Rgb(0, 168, 198),   // Teal
+            Color::Rgb(127, 219, 255), // Light blue
+        ])
     }
 
     /// Create a forest gradient
     pub fn forest() -> Self {
-        Self {
-            colors: vec![
-                Color::Rgb(34, 139, 34),   // Forest green
-                Color::Rgb(50, 205, 50),   // Lime green
-                Color::Rgb(144, 238, 144), // Light green
-            ],
-        }
+        Self::new(vec![
+            Color::Rgb(34, 139, 34),   // Forest green
+            Color::Rgb(50, 205, 50),   // Lime green
+            Color::Rgb(144, 238, 144), // Light green
+        ])
     }
 
     /// Get the color at a specific position (0.0 to 1.0)
@@ -126,7 +124,18 @@ impl Gradient {
         let start_color = &self.colors[segment_index];
         let end_color = &self.colors[segment_index + 1];
 
-        interpolate_color(start_color, end_color, segment_position)
+        match self.space {
+            BlendSpace::Srgb => interpolate_color(start_color, end_color, segment_position),
+            BlendSpace::Linear => {
+                let (r1, g1, b1) = start_color.to_rgb();
+                let (r2, g2, b2) = end_color.to_rgb();
+                Color::Rgb(r1, g1, b1).blend_in(
+                    &Color::Rgb(r2, g2, b2),
+                    segment_position,
+                    BlendSpace::Linear,
+                )
+            }
+        }
     }
 
     /// Apply gradient to text, returning a vector of (char, color) pairs
@@ -201,8 +210,8 @@ impl Default for Gradient {
 
 /// Interpolate between two colors
 fn interpolate_color(start: &Color, end: &Color, t: f32) -> Color {
-    let (r1, g1, b1) = color_to_rgb(start);
-    let (r2, g2, b2) = color_to_rgb(end);
+    let (r1, g1, b1) = start.to_rgb();
+    let (r2, g2, b2) = end.to_rgb();
 
     let r = lerp(r1 as f32, r2 as f32, t) as u8;
     let g = lerp(g1 as f32, g2 as f32, t) as u8;
@@ -216,73 +225,6 @@ fn lerp(a: f32, b: f32, t: f32) -> f32 {
     a + (b - a) * t
 }
 
-/// Convert a Color to RGB values
-fn color_to_rgb(color: &Color) -> (u8, u8, u8) {
-    match color {
-        Color::Rgb(r, g, b) => (*r, *g, *b),
-        Color::Black => (0, 0, 0),
-        Color::Red => (205, 0, 0),
-        Color::Green => (0, 205, 0),
-        Color::Yellow => (205, 205, 0),
-        Color::Blue => (0, 0, 238),
-        Color::Magenta => (205, 0, 205),
-        Color::Cyan => (0, 205, 205),
-        Color::White => (229, 229, 229),
-        Color::BrightBlack => (127, 127, 127),
-        Color::BrightRed => (255, 0, 0),
-        Color::BrightGreen => (0, 255, 0),
-        Color::BrightYellow => (255, 255, 0),
-        Color::BrightBlue => (92, 92, 255),
-        Color::BrightMagenta => (255, 0, 255),
-        Color::BrightCyan => (0, 255, 255),
-        Color::BrightWhite => (255, 255, 255),
-        Color::Ansi256(code) => ansi256_to_rgb(*code),
-        Color::Reset => (255, 255, 255),
-    }
-}
-
-/// Convert ANSI 256 color code to RGB
-fn ansi256_to_rgb(code: u8) -> (u8, u8, u8) {
-    match code {
-        0..=15 => {
-            // Standard colors
-            let standard = [
-                (0, 0, 0),
-                (128, 0, 0),
-                (0, 128, 0),
-                (128, 128, 0),
-                (0, 0, 128),
-                (128, 0, 128),
-                (0, 128, 128),
-                (192, 192, 192),
-                (128, 128, 128),
-                (255, 0, 0),
-                (0, 255, 0),
-                (255, 255, 0),
-                (0, 0, 255),
-                (255, 0, 255),
-                (0, 255, 255),
-                (255, 255, 255),
-            ];
-            standard[code as usize]
-        }
-        16..=231 => {
-            // 6x6x6 color cube
-            let code = code - 16;
-            let r = (code / 36) % 6;
-            let g = (code / 6) % 6;
-            let b = code % 6;
-            let to_rgb = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
-            (to_rgb(r), to_rgb(g), to_rgb(b))
-        }
-        232..=255 => {
-            // Grayscale
-            let gray = 8 + (code - 232) * 10;
-            (gray, gray, gray)
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -409,24 +351,6 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_color_to_rgb() {
-        assert_eq!(color_to_rgb(&Color::Black), (0, 0, 0));
-        assert_eq!(color_to_rgb(&Color::Rgb(100, 150, 200)), (100, 150, 200));
-    }
-
-    #[test]
-    fn test_ansi256_to_rgb() {
-        // Black
-        assert_eq!(ansi256_to_rgb(0), (0, 0, 0));
-        // White
-        assert_eq!(ansi256_to_rgb(15), (255, 255, 255));
-        // Grayscale
-        let (r, g, b) = ansi256_to_rgb(240);
-        assert_eq!(r, g);
-        assert_eq!(g, b);
-    }
-
     #[test]
     fn test_empty_gradient() {
         let g = Gradient::new(vec![]);
@@ -441,4 +365,18 @@ mod tests {
         assert_eq!(g.color_at(0.5), Color::Red);
         assert_eq!(g.color_at(1.0), Color::Red);
     }
+
+    #[test]
+    fn test_linear_blend_space_brightens_midpoint() {
+        let srgb = Gradient::from_two(Color::Rgb(255, 0, 0), Color::Rgb(0, 255, 0));
+        let linear = srgb.clone().blend_space(BlendSpace::Linear);
+
+        assert_eq!(srgb.color_at(0.5), Color::Rgb(127, 127, 0));
+        assert_eq!(linear.color_at(0.5), Color::Rgb(188, 188, 0));
+        assert_eq!(linear.color_at(0.0), Color::Rgb(255, 0, 0));
+        assert_eq!(linear.color_at(1.0), Color::Rgb(0, 255, 0));
+        assert!(
+            linear.color_at(0.5).relative_luminance() > srgb.color_at(0.5).relative_luminance()
+        );
+    }
 }
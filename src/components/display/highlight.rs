@@ -19,9 +19,21 @@
 //!         .into_element()
 //! }
 //! ```
+//!
+//! Search matches can be marked inside a larger string:
+//!
+//! ```
+//! use rnk::components::{Highlight, HighlightVariant};
+//!
+//! let line = Highlight::matches("foo bar foo", "foo", HighlightVariant::Warning);
+//! assert_eq!(line.spans.len(), 3); // "foo", " bar ", "foo"
+//! ```
+
+use std::ops::Range;
 
 use crate::components::capsule::CapsuleElementBuilder;
 use crate::components::capsule_variant::CapsuleVariant;
+use crate::components::{Line, Span};
 use crate::core::Element;
 
 /// Highlight variant.
@@ -49,6 +61,78 @@ impl Highlight {
         self
     }
 
+    /// Mark every occurrence of `query` in `text` (case-sensitive)
+    ///
+    /// Matched runs use the variant's highlight colors and the rest of the
+    /// text is left unstyled. An empty query produces a single plain span.
+    pub fn matches(text: &str, query: &str, variant: HighlightVariant) -> Line {
+        Self::matches_with_case(text, query, variant, false)
+    }
+
+    /// Mark every occurrence of `query` in `text`, ignoring case
+    pub fn matches_ignore_case(text: &str, query: &str, variant: HighlightVariant) -> Line {
+        Self::matches_with_case(text, query, variant, true)
+    }
+
+    fn matches_with_case(
+        text: &str,
+        query: &str,
+        variant: HighlightVariant,
+        ignore_case: bool,
+    ) -> Line {
+        let (fg, bg) = variant.highlight_colors();
+        let mut spans = Vec::new();
+        let mut last = 0;
+
+        for range in Self::find_matches(text, query, ignore_case) {
+            if range.start > last {
+                spans.push(Span::new(&text[last..range.start]));
+            }
+            spans.push(Span::new(&text[range.clone()]).fg(fg).bg(bg));
+            last = range.end;
+        }
+        if last < text.len() || spans.is_empty() {
+            spans.push(Span::new(&text[last..]));
+        }
+
+        Line::from_spans(spans)
+    }
+
+    /// Find the byte ranges of all non-overlapping occurrences of `query`
+    ///
+    /// Ranges always fall on `char` boundaries of `text`, including when
+    /// `ignore_case` folds characters whose lowercase form has a different
+    /// byte length. An empty query yields no matches.
+    pub fn find_matches(text: &str, query: &str, ignore_case: bool) -> Vec<Range<usize>> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+        if !ignore_case {
+            return text
+                .match_indices(query)
+                .map(|(start, m)| start..start + m.len())
+                .collect();
+        }
+
+        let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+        let mut matches = Vec::new();
+        let mut start = 0;
+
+        while start < text.len() {
+            match match_len_ignore_case(&text[start..], &query) {
+                Some(len) => {
+                    matches.push(start..start + len);
+                    start += len;
+                }
+                None => {
+                    start += text[start..].chars().next().map_or(1, char::len_utf8);
+                }
+            }
+        }
+
+        matches
+    }
+
     /// Convert to Element
     pub fn into_element(self) -> Element {
         let (fg, bg) = self.variant.highlight_colors();
@@ -57,6 +141,27 @@ impl Highlight {
     }
 }
 
+/// Byte length of the prefix of `haystack` that case-insensitively equals
+/// the (already lowercased) `needle`, if any
+fn match_len_ignore_case(haystack: &str, needle: &[char]) -> Option<usize> {
+    let mut needle = needle.iter();
+    let mut pending = needle.len();
+
+    for (offset, ch) in haystack.char_indices() {
+        for lower in ch.to_lowercase() {
+            if needle.next() != Some(&lower) {
+                return None;
+            }
+            pending -= 1;
+        }
+        if pending == 0 {
+            return Some(offset + ch.len_utf8());
+        }
+    }
+
+    None
+}
+
 impl Default for Highlight {
     fn default() -> Self {
         Self::new("")
@@ -73,6 +178,49 @@ mod tests {
         assert_eq!(h.text, "Test");
     }
 
+    fn span_texts(line: &Line) -> Vec<&str> {
+        line.spans.iter().map(|s| s.content.as_str()).collect()
+    }
+
+    #[test]
+    fn test_matches_marks_all_occurrences() {
+        let line = Highlight::matches("foo bar foo", "foo", HighlightVariant::Primary);
+        assert_eq!(span_texts(&line), vec!["foo", " bar ", "foo"]);
+        assert!(line.spans[0].style.background_color.is_some());
+        assert!(line.spans[1].style.background_color.is_none());
+    }
+
+    #[test]
+    fn test_matches_empty_query_and_no_match() {
+        let line = Highlight::matches("hello", "", HighlightVariant::Primary);
+        assert_eq!(span_texts(&line), vec!["hello"]);
+
+        let line = Highlight::matches("hello", "xyz", HighlightVariant::Primary);
+        assert_eq!(span_texts(&line), vec!["hello"]);
+
+        let line = Highlight::matches("", "x", HighlightVariant::Primary);
+        assert_eq!(span_texts(&line), vec![""]);
+    }
+
+    #[test]
+    fn test_matches_non_overlapping() {
+        assert_eq!(
+            Highlight::find_matches("aaaa", "aa", false),
+            vec![0..2, 2..4]
+        );
+        assert_eq!(Highlight::find_matches("aaa", "aa", true), vec![0..2]);
+    }
+
+    #[test]
+    fn test_matches_ignore_case_multibyte() {
+        let line =
+            Highlight::matches_ignore_case("Ünïcode ünÏ 日本", "ÜNÏ", HighlightVariant::Info);
+        assert_eq!(span_texts(&line), vec!["Ünï", "code ", "ünÏ", " 日本"]);
+
+        let line = Highlight::matches("日本語と日本", "日本", HighlightVariant::Info);
+        assert_eq!(span_texts(&line), vec!["日本", "語と", "日本"]);
+    }
+
     #[test]
     fn test_highlight_variants() {
         let _ = Highlight::new("P")
@@ -3,6 +3,7 @@
 //! Provides clickable hyperlinks in supported terminals using OSC 8 escape sequences.
 //! Falls back gracefully in unsupported terminals.
 
+use crate::core::Element;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Global flag for hyperlink support detection
@@ -153,6 +154,28 @@ impl Hyperlink {
         }
     }
 
+    /// Make everything an element draws a clickable link to `url`
+    ///
+    /// Works for any element (an icon plus label, a table cell, a whole
+    /// box): each rendered row of the element is wrapped in an OSC 8 link.
+    /// Where hyperlinks are not supported the element is returned unchanged.
+    ///
+    /// ```ignore
+    /// Hyperlink::wrap(
+    ///     Box::new().child(icon).child(Text::new("Docs").into_element()).into_element(),
+    ///     "https://docs.rs/rnk",
+    /// )
+    /// ```
+    pub fn wrap(element: Element, url: impl Into<String>) -> Element {
+        link_element(element, url.into(), supports_hyperlinks(), false)
+    }
+
+    /// Like [`wrap`](Self::wrap), but underline the element's text where
+    /// hyperlinks are not supported, so it still reads as a link
+    pub fn wrap_underlined(element: Element, url: impl Into<String>) -> Element {
+        link_element(element, url.into(), supports_hyperlinks(), true)
+    }
+
     /// Render with custom fallback format
     pub fn render_with_fallback<F>(&self, fallback: F) -> String
     where
@@ -166,6 +189,29 @@ impl Hyperlink {
     }
 }
 
+/// Link an element, or fall back to leaving it (optionally underlined) as is
+fn link_element(mut element: Element, url: String, supported: bool, underline: bool) -> Element {
+    if supported {
+        element.style.hyperlink = Some(url);
+    } else if underline {
+        underline_tree(&mut element);
+    }
+    element
+}
+
+/// Underline every piece of text in an element tree
+fn underline_tree(element: &mut Element) {
+    element.style.underline = true;
+    for line in element.spans.iter_mut().flatten() {
+        for span in &mut line.spans {
+            span.style.underline = true;
+        }
+    }
+    for child in element.children.iter_mut() {
+        underline_tree(child);
+    }
+}
+
 /// Builder for creating styled hyperlinks
 #[derive(Debug, Clone)]
 pub struct HyperlinkBuilder {
@@ -218,6 +264,20 @@ impl HyperlinkBuilder {
 
     /// Render the styled hyperlink
     pub fn render(&self) -> String {
+        self.styled(&self.hyperlink.render())
+    }
+
+    /// Render the styled hyperlink as OSC 8, ignoring detection
+    pub fn render_osc8(&self) -> String {
+        self.styled(&self.hyperlink.render_osc8())
+    }
+
+    /// Render only the styled display text, without a link
+    pub fn render_text(&self) -> String {
+        self.styled(&self.hyperlink.text)
+    }
+
+    fn styled(&self, body: &str) -> String {
         let mut result = String::new();
 
         // Apply styles
@@ -231,8 +291,7 @@ impl HyperlinkBuilder {
             result.push_str(&color.to_ansi_fg());
         }
 
-        // Add the hyperlink
-        result.push_str(&self.hyperlink.render());
+        result.push_str(body);
 
         // Reset styles
         result.push_str("\x1b[0m");
@@ -340,6 +399,18 @@ mod tests {
         assert!(rendered.ends_with("\x1b[0m"));
     }
 
+    #[test]
+    fn test_hyperlink_builder_forced_and_text_only() {
+        let _guard = test_lock().lock().unwrap();
+        let builder = HyperlinkBuilder::new("https://example.com", "Example").no_color();
+
+        assert_eq!(
+            builder.render_osc8(),
+            "\x1b[4m\x1b]8;;https://example.com\x1b\\Example\x1b]8;;\x1b\\\x1b[0m"
+        );
+        assert_eq!(builder.render_text(), "\x1b[4mExample\x1b[0m");
+    }
+
     #[test]
     fn test_set_hyperlinks_supported() {
         let _guard = test_lock().lock().unwrap();
@@ -366,4 +437,42 @@ mod tests {
         // Reset
         HYPERLINKS_CHECKED.store(false, Ordering::SeqCst);
     }
+
+    #[test]
+    fn test_wrap_links_every_row_of_an_element() {
+        use crate::components::{Box as RnkBox, Text};
+        use crate::layout::LayoutEngine;
+        use crate::renderer::Output;
+        use crate::renderer::tree_renderer::render_element_tree;
+
+        let child = RnkBox::new()
+            .flex_direction(crate::core::FlexDirection::Column)
+            .child(Text::new("* Docs").into_element())
+            .child(Text::new("  more").into_element())
+            .into_element();
+        let element = link_element(child.clone(), "https://x.io".into(), true, false);
+
+        let mut engine = LayoutEngine::new();
+        engine.compute(&element, 8, 2);
+        let mut output = Output::new(8, 2);
+        render_element_tree(&element, &engine, &mut output, 0.0, 0.0);
+        let open = "\x1b]8;;https://x.io\x1b\\";
+        let close = "\x1b]8;;\x1b\\";
+        assert_eq!(
+            output.render(),
+            format!("{open}* Docs{close}\r\n  {open}more{close}")
+        );
+
+        // Unsupported terminals get the element back, underlined on request
+        let plain = link_element(child.clone(), "https://x.io".into(), false, false);
+        assert!(plain.style.hyperlink.is_none());
+        assert!(!plain.children.iter().next().unwrap().style.underline);
+        let underlined = link_element(child, "https://x.io".into(), false, true);
+        assert!(
+            underlined
+                .children
+                .iter()
+                .all(|child| child.style.underline)
+        );
+    }
 }
@@ -20,6 +20,20 @@
 //!         .into_element()
 //! }
 //! ```
+//!
+//! For footers that must fit a narrow terminal, [`KeyHints`] drops the
+//! lowest-priority hints first:
+//!
+//! ```rust,ignore
+//! KeyHints::new(vec![
+//!     KeyHint::new("q", "Quit").priority(10),
+//!     KeyHint::new("?", "Help").priority(5),
+//!     KeyHint::new("Tab", "Switch pane"),
+//! ])
+//! .render_within(width)
+//! ```
+
+use unicode_width::UnicodeWidthStr;
 
 use crate::components::{Box as RnkBox, Text};
 use crate::core::{Color, Element, FlexDirection};
@@ -31,6 +45,7 @@ pub struct KeyHint {
     description: String,
     key_color: Color,
     desc_color: Color,
+    priority: i32,
 }
 
 impl KeyHint {
@@ -41,9 +56,23 @@ impl KeyHint {
             description: description.into(),
             key_color: Color::Yellow,
             desc_color: Color::BrightBlack,
+            priority: 0,
         }
     }
 
+    /// Set the priority used by [`KeyHints::render_within`]
+    ///
+    /// Higher priorities are kept when space runs out. Defaults to 0.
+    pub fn priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Display width in columns (key, space, description)
+    pub fn width(&self) -> usize {
+        self.key.width() + 1 + self.description.width()
+    }
+
     /// Set the key color
     pub fn key_color(mut self, color: Color) -> Self {
         self.key_color = color;
@@ -58,9 +87,13 @@ impl KeyHint {
 
     /// Convert to Element
     pub fn into_element(self) -> Element {
+        self.render(0.5)
+    }
+
+    fn render(self, gap: f32) -> Element {
         RnkBox::new()
             .flex_direction(FlexDirection::Row)
-            .gap(0.5)
+            .gap(gap)
             .children(vec![
                 Text::new(&self.key)
                     .color(self.key_color)
@@ -80,6 +113,109 @@ impl Default for KeyHint {
     }
 }
 
+/// A row of key hints that adapts to the available width
+#[derive(Debug, Clone)]
+pub struct KeyHints {
+    hints: Vec<KeyHint>,
+    gap: usize,
+    overflow_symbol: String,
+}
+
+impl KeyHints {
+    /// Create a row of hints
+    pub fn new(hints: Vec<KeyHint>) -> Self {
+        Self {
+            hints,
+            gap: 2,
+            overflow_symbol: "…".to_string(),
+        }
+    }
+
+    /// Set the number of columns between hints (default: 2)
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set the symbol shown when hints were dropped (default: `…`)
+    pub fn overflow_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.overflow_symbol = symbol.into();
+        self
+    }
+
+    /// Indices of the hints that fit in `width`, in display order
+    ///
+    /// Hints are taken greedily from highest to lowest priority (ties keep
+    /// their order), skipping any that no longer fit. The second value is
+    /// `true` when some hints were dropped, in which case room for the
+    /// overflow symbol has been reserved.
+    pub fn fit(&self, width: usize) -> (Vec<usize>, bool) {
+        let all: Vec<usize> = (0..self.hints.len()).collect();
+        if self.row_width(&all, false) <= width {
+            return (all, false);
+        }
+
+        let mut order = all;
+        order.sort_by_key(|&index| std::cmp::Reverse(self.hints[index].priority));
+
+        let mut kept = Vec::new();
+        for index in order {
+            kept.push(index);
+            if self.row_width(&kept, true) > width {
+                kept.pop();
+            }
+        }
+        kept.sort_unstable();
+        (kept, true)
+    }
+
+    /// Render only the hints that fit in `width` columns
+    pub fn render_within(self, width: usize) -> Element {
+        let (kept, dropped) = self.fit(width);
+        self.render(&kept, dropped)
+    }
+
+    /// Render every hint
+    pub fn into_element(self) -> Element {
+        let all: Vec<usize> = (0..self.hints.len()).collect();
+        self.render(&all, false)
+    }
+
+    fn row_width(&self, indices: &[usize], overflow: bool) -> usize {
+        let items = indices.len() + overflow as usize;
+        let content: usize = indices.iter().map(|&i| self.hints[i].width()).sum();
+        let symbol = if overflow {
+            self.overflow_symbol.width()
+        } else {
+            0
+        };
+        content + symbol + self.gap * items.saturating_sub(1)
+    }
+
+    fn render(self, indices: &[usize], dropped: bool) -> Element {
+        let mut children: Vec<Element> = self
+            .hints
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| indices.contains(index))
+            // A whole-column gap keeps the row as wide as `row_width` says
+            .map(|(_, hint)| hint.render(1.0))
+            .collect();
+        if dropped {
+            children.push(
+                Text::new(self.overflow_symbol)
+                    .color(Color::BrightBlack)
+                    .into_element(),
+            );
+        }
+        RnkBox::new()
+            .flex_direction(FlexDirection::Row)
+            .gap(self.gap as f32)
+            .children(children)
+            .into_element()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,4 +231,31 @@ mod tests {
     fn test_key_hint_into_element() {
         let _ = KeyHint::new("Enter", "Select").into_element();
     }
+
+    fn footer() -> KeyHints {
+        KeyHints::new(vec![
+            KeyHint::new("q", "Quit").priority(10),
+            KeyHint::new("Tab", "Switch"),
+            KeyHint::new("?", "Help").priority(5),
+            KeyHint::new("/", "Find").priority(5),
+        ])
+    }
+
+    #[test]
+    fn test_key_hints_fit_by_priority() {
+        let hints = footer();
+        // q Quit(6) Tab Switch(10) ? Help(6) / Find(6) + 3 gaps of 2
+        assert_eq!(hints.fit(34), (vec![0, 1, 2, 3], false));
+        // Drops "Tab Switch", keeps the rest plus the overflow symbol
+        assert_eq!(hints.fit(30), (vec![0, 2, 3], true));
+        assert_eq!(hints.fit(17), (vec![0, 2], true));
+        assert_eq!(hints.fit(3), (vec![], true));
+    }
+
+    #[test]
+    fn test_key_hints_render_within() {
+        let element = footer().render_within(20);
+        let plain = crate::testing::TestRenderer::new(20, 1).render_to_plain(&element);
+        assert_eq!(plain.trim_end(), "q Quit  ? Help  …");
+    }
 }
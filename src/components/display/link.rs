@@ -1,6 +1,9 @@
 //! Link component for styled hyperlinks
 //!
-//! Displays styled links with optional icons.
+//! Displays styled links with optional icons. A link marked
+//! [`focused`](Link::focused) is activated with Enter, which calls its
+//! [`on_activate`](Link::on_activate) handler or opens the URL in the
+//! system browser.
 //!
 //! # Example
 //!
@@ -17,19 +20,50 @@
 //!         ])
 //!         .into_element()
 //! }
+//!
+//! fn focusable_link() -> Element {
+//!     let focus = use_focus(UseFocusOptions::new());
+//!     Link::new("Changelog", "https://example.com/changelog")
+//!         .focused(focus.is_focused)
+//!         .into_element()
+//! }
 //! ```
 
+use std::fmt;
+use std::process::{Command, Stdio};
+use std::rc::Rc;
+
 use crate::components::Text;
-use crate::core::{Color, Element};
+use crate::components::theme::get_theme;
+use crate::core::{Color, Element, Style};
+use crate::hooks::{Key, use_input};
+
+type ActivateHandler = Rc<dyn Fn(&str)>;
 
 /// A link component
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Link {
     text: String,
     url: String,
     icon: Option<String>,
     color: Color,
     underline: bool,
+    focused: bool,
+    on_activate: Option<ActivateHandler>,
+}
+
+impl fmt::Debug for Link {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Link")
+            .field("text", &self.text)
+            .field("url", &self.url)
+            .field("icon", &self.icon)
+            .field("color", &self.color)
+            .field("underline", &self.underline)
+            .field("focused", &self.focused)
+            .field("on_activate", &self.on_activate.is_some())
+            .finish()
+    }
 }
 
 impl Link {
@@ -41,6 +75,8 @@ impl Link {
             icon: None,
             color: Color::Cyan,
             underline: true,
+            focused: false,
+            on_activate: None,
         }
     }
 
@@ -62,13 +98,55 @@ impl Link {
         self
     }
 
+    /// Mark the link as the keyboard focus target
+    ///
+    /// Pass the `is_focused` flag from [`use_focus`](crate::hooks::use_focus())
+    /// so the link takes part in Tab navigation. A focused link is drawn
+    /// with the theme's focus emphasis and listens for Enter.
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Call `handler` with the URL instead of opening it in the browser
+    pub fn on_activate<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&str) + 'static,
+    {
+        self.on_activate = Some(Rc::new(handler));
+        self
+    }
+
     /// Get the URL
     pub fn url(&self) -> &str {
         &self.url
     }
 
+    /// Activate the link as if Enter was pressed on it
+    ///
+    /// Runs the [`on_activate`](Self::on_activate) handler, or opens the URL
+    /// with [`open_url`] when there is none. Failures to launch the browser
+    /// are ignored.
+    pub fn activate(&self) {
+        match &self.on_activate {
+            Some(handler) => handler(&self.url),
+            None => {
+                let _ = open_url(&self.url);
+            }
+        }
+    }
+
     /// Convert to Element
     pub fn into_element(self) -> Element {
+        // Registered whether or not the link is focused so the hook order
+        // stays stable as focus moves
+        let link = self.clone();
+        use_input(move |input, key| {
+            if link.focused && handle_link_input(input, key) {
+                link.activate();
+            }
+        });
+
         let mut content = String::new();
 
         if let Some(icon) = &self.icon {
@@ -78,14 +156,81 @@ impl Link {
 
         content.push_str(&self.text);
 
-        let mut text = Text::new(content).color(self.color);
+        let style = Style {
+            color: Some(self.color),
+            ..Style::new()
+        };
+        let style = get_theme().focus_style().label_style(style, self.focused);
+
+        let mut text = Text::new(content).color(style.color.unwrap_or(self.color));
+        if style.bold {
+            text = text.bold();
+        }
         if self.underline {
             text = text.underline();
         }
+        if self.focused {
+            text = text.inverse();
+        }
         text.into_element()
     }
 }
 
+/// Whether a key press activates a focused [`Link`]
+pub fn handle_link_input(_input: &str, key: &Key) -> bool {
+    key.return_key
+}
+
+/// Open `url` with the operating system's default handler
+///
+/// Uses `open` on macOS, the `url.dll` protocol handler on Windows and
+/// `xdg-open` elsewhere. The URL is passed as a single argument and never
+/// through a shell. Only `http`, `https` and `mailto` URLs are opened;
+/// anything else (file paths, `file:` or custom schemes the launcher could
+/// hand to a local program) fails with [`InvalidInput`]. The launcher runs
+/// in the background with its output discarded.
+///
+/// [`InvalidInput`]: std::io::ErrorKind::InvalidInput
+pub fn open_url(url: &str) -> std::io::Result<()> {
+    if !has_allowed_scheme(url) {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "only http, https and mailto URLs can be opened",
+        ));
+    }
+    let mut command = if cfg!(target_os = "macos") {
+        let mut command = Command::new("open");
+        command.arg(url);
+        command
+    } else if cfg!(windows) {
+        let mut command = Command::new("rundll32");
+        command.args(["url.dll,FileProtocolHandler", url]);
+        command
+    } else {
+        let mut command = Command::new("xdg-open");
+        command.arg(url);
+        command
+    };
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+}
+
+/// Schemes [`open_url`] hands to the system launcher
+const ALLOWED_SCHEMES: [&str; 3] = ["http", "https", "mailto"];
+
+fn has_allowed_scheme(url: &str) -> bool {
+    url.split_once(':').is_some_and(|(scheme, rest)| {
+        ALLOWED_SCHEMES
+            .iter()
+            .any(|allowed| scheme.eq_ignore_ascii_case(allowed))
+            && !rest.is_empty()
+    })
+}
+
 impl Default for Link {
     fn default() -> Self {
         Self::new("", "")
@@ -96,6 +241,24 @@ impl Default for Link {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_open_url_rejects_unsupported_schemes() {
+        for url in [
+            "--help",
+            "file:///etc/passwd",
+            "C:\\Windows\\System32\\calc.exe",
+            "/usr/bin/xterm",
+            "javascript:alert(1)",
+            "https:",
+        ] {
+            let err = open_url(url).unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput, "{url}");
+        }
+        assert!(has_allowed_scheme("https://docs.rs"));
+        assert!(has_allowed_scheme("HTTP://example.com"));
+        assert!(has_allowed_scheme("mailto:dev@example.com"));
+    }
+
     #[test]
     fn test_link_creation() {
         let l = Link::new("Text", "https://example.com");
@@ -119,4 +282,63 @@ mod tests {
             .no_underline()
             .into_element();
     }
+
+    #[test]
+    fn test_link_activation() {
+        use std::cell::RefCell;
+
+        let enter = Key {
+            return_key: true,
+            ..Default::default()
+        };
+        assert!(handle_link_input("", &enter));
+        assert!(!handle_link_input(" ", &Key::default()));
+
+        let opened = Rc::new(RefCell::new(Vec::new()));
+        let sink = opened.clone();
+        let link = Link::new("Docs", "https://docs.rs/rnk")
+            .focused(true)
+            .on_activate(move |url| sink.borrow_mut().push(url.to_string()));
+        link.activate();
+        assert_eq!(*opened.borrow(), vec!["https://docs.rs/rnk".to_string()]);
+    }
+
+    #[test]
+    fn test_link_keeps_hook_order_when_focus_moves() {
+        use crate::hooks::{KeyCodeKind, use_signal};
+        use crate::testing::HookHarness;
+        use std::cell::RefCell;
+
+        let opened = Rc::new(RefCell::new(0));
+        let sink = opened.clone();
+        let mut harness = HookHarness::with_props(false, move |focused: &bool| {
+            let sink = sink.clone();
+            Link::new("Docs", "https://docs.rs")
+                .focused(*focused)
+                .on_activate(move |_| *sink.borrow_mut() += 1)
+                .into_element();
+            use_signal(|| 0).get()
+        });
+
+        harness.send_key(KeyCodeKind::Enter);
+        assert_eq!(*opened.borrow(), 0);
+
+        harness.rerender(true);
+        harness.send_key(KeyCodeKind::Enter);
+        assert_eq!(*opened.borrow(), 1);
+
+        harness.rerender(false);
+        harness.send_key(KeyCodeKind::Enter);
+        assert_eq!(*opened.borrow(), 1);
+    }
+
+    #[test]
+    fn test_focused_link_is_emphasized() {
+        let rest = Link::new("Docs", "https://docs.rs").into_element();
+        let focused = Link::new("Docs", "https://docs.rs")
+            .focused(true)
+            .into_element();
+        assert!(!rest.style.inverse);
+        assert!(focused.style.inverse);
+    }
 }
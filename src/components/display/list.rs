@@ -1,27 +1,38 @@
 //! List component for displaying selectable lists
 //!
 //! Provides a scrollable, selectable list widget similar to Ratatui's List.
+//!
+//! Items can be grouped under [`ListItem::header`] rows. Headers are not
+//! selectable, and when a section's header scrolls out of view the windowed
+//! list pins it to the top row (see [`List::sticky_headers`]).
+//!
+//! [`ListItem::disabled`] items are dimmed and [`ListItem::separator`] rows
+//! draw a divider line; like headers, both are skipped by navigation.
 
 use crate::components::navigation::SelectionState;
 use crate::components::{Box as RnkBox, Line, Span, Text};
 use crate::core::{Color, Element, Style};
+use crate::layout::measure::measure_text_width;
 
 /// List item with content and optional styling
 #[derive(Debug, Clone)]
 pub struct ListItem {
     /// The content of the item (can be rich text)
-    pub content: Line,
+    content: Line,
     /// Custom style for this item
-    pub style: Option<Style>,
+    style: Option<Style>,
+    /// Whether this is a non-selectable section header
+    header: bool,
+    /// Whether this item is shown dimmed and cannot be selected
+    disabled: bool,
+    /// Whether this is a divider line rather than an item
+    separator: bool,
 }
 
 impl ListItem {
     /// Create a new list item from a string
     pub fn new(content: impl Into<String>) -> Self {
-        Self {
-            content: Line::raw(content),
-            style: None,
-        }
+        Self::from_line(Line::raw(content))
     }
 
     /// Create a list item from a Line (rich text)
@@ -29,22 +40,78 @@ impl ListItem {
         Self {
             content: line,
             style: None,
+            header: false,
+            disabled: false,
+            separator: false,
         }
     }
 
     /// Create a list item from spans
     pub fn from_spans(spans: Vec<Span>) -> Self {
+        Self::from_line(Line::from_spans(spans))
+    }
+
+    /// Create a section header
+    ///
+    /// Headers group the items that follow them, are skipped by
+    /// [`ListState`] navigation, and stay pinned at the top of a windowed
+    /// list while their section is scrolled.
+    pub fn header(content: impl Into<String>) -> Self {
         Self {
-            content: Line::from_spans(spans),
-            style: None,
+            header: true,
+            ..Self::new(content)
+        }
+    }
+
+    /// Create a separator row, drawn as a divider across the list
+    pub fn separator() -> Self {
+        Self {
+            separator: true,
+            ..Self::new("")
         }
     }
 
+    /// Set whether this item is disabled (dimmed and skipped by navigation)
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
     /// Set custom style for this item
     pub fn style(mut self, style: Style) -> Self {
         self.style = Some(style);
         self
     }
+
+    /// Get the content of the item
+    pub fn content(&self) -> &Line {
+        &self.content
+    }
+
+    /// Get the custom style of the item
+    pub fn get_style(&self) -> Option<&Style> {
+        self.style.as_ref()
+    }
+
+    /// Check if this is a section header
+    pub fn is_header(&self) -> bool {
+        self.header
+    }
+
+    /// Check if this item is disabled
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Check if this is a separator row
+    pub fn is_separator(&self) -> bool {
+        self.separator
+    }
+
+    /// Check if this item can be selected
+    pub fn is_selectable(&self) -> bool {
+        !self.header && !self.disabled && !self.separator
+    }
 }
 
 impl<T: Into<String>> From<T> for ListItem {
@@ -54,12 +121,17 @@ impl<T: Into<String>> From<T> for ListItem {
 }
 
 /// List state for tracking selection and scroll
+///
+/// After [`set_items`](Self::set_items), the [`SelectionState`] navigation
+/// methods skip headers, separators and disabled items.
 #[derive(Debug, Clone, Default)]
 pub struct ListState {
     /// Currently selected index (None if nothing selected)
     pub selected: Option<usize>,
     /// Scroll offset
     pub offset: usize,
+    /// Which items can be selected; empty means all of them
+    selectable: Vec<bool>,
 }
 
 impl ListState {
@@ -72,11 +144,69 @@ impl ListState {
     pub fn with_selected(selected: Option<usize>) -> Self {
         Self {
             selected,
-            offset: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Record which items can be selected, so navigation skips the rest
+    pub fn set_items(&mut self, items: &[ListItem]) {
+        self.selectable = items.iter().map(ListItem::is_selectable).collect();
+    }
+
+    /// Select the next selectable item, skipping headers, separators and
+    /// disabled items
+    pub fn select_next_item(&mut self, items: &[ListItem]) {
+        self.set_items(items);
+        self.select_next(items.len());
+    }
+
+    /// Select the previous selectable item, skipping headers, separators
+    /// and disabled items
+    pub fn select_previous_item(&mut self, items: &[ListItem]) {
+        self.set_items(items);
+        self.select_previous(items.len());
+    }
+
+    /// Select the first selectable item
+    pub fn select_first_item(&mut self, items: &[ListItem]) {
+        self.set_items(items);
+        self.select_first(items.len());
+    }
+
+    /// Select the last selectable item
+    pub fn select_last_item(&mut self, items: &[ListItem]) {
+        self.set_items(items);
+        self.select_last(items.len());
+    }
+
+    /// Adjust the scroll offset to keep the selection visible below any
+    /// pinned section header
+    pub fn scroll_to_selected_item(&mut self, items: &[ListItem], viewport_height: usize) {
+        let Some(selected) = self.selected else {
+            return;
+        };
+        let viewport_height = viewport_height.max(1);
+        self.scroll_to_selected(viewport_height);
+
+        // A pinned header takes the top row, leaving one row less for items
+        while self.offset < selected
+            && pinned_header(items, self.offset).is_some()
+            && selected >= self.offset + viewport_height.saturating_sub(1)
+        {
+            self.offset += 1;
         }
     }
 }
 
+/// Header to pin above the window starting at `offset`, if its section
+/// has been scrolled past.
+fn pinned_header(items: &[ListItem], offset: usize) -> Option<usize> {
+    if offset == 0 || items.get(offset).is_none_or(ListItem::is_header) {
+        return None;
+    }
+    items[..offset].iter().rposition(ListItem::is_header)
+}
+
 impl SelectionState for ListState {
     fn selected(&self) -> Option<usize> {
         self.selected
@@ -93,6 +223,10 @@ impl SelectionState for ListState {
     fn set_offset(&mut self, offset: usize) {
         self.offset = offset;
     }
+
+    fn is_selectable(&self, index: usize) -> bool {
+        self.selectable.get(index).copied().unwrap_or(true)
+    }
 }
 
 /// List component builder
@@ -106,6 +240,8 @@ pub struct List {
     highlight_symbol: Option<String>,
     /// Whether to show selection
     show_selection: bool,
+    /// Whether to pin the current section header while scrolling
+    sticky_headers: bool,
     /// Key for reconciliation
     key: Option<String>,
 }
@@ -118,6 +254,7 @@ impl List {
             highlight_style: Style::new(),
             highlight_symbol: None,
             show_selection: true,
+            sticky_headers: true,
             key: None,
         }
     }
@@ -130,10 +267,7 @@ impl List {
     {
         Self {
             items: items.into_iter().map(|i| i.into()).collect(),
-            highlight_style: Style::new(),
-            highlight_symbol: None,
-            show_selection: true,
-            key: None,
+            ..Self::new()
         }
     }
 
@@ -183,6 +317,13 @@ impl List {
         self
     }
 
+    /// Set whether section headers stay pinned at the top while scrolling
+    /// (default: true; only applies with a viewport height)
+    pub fn sticky_headers(mut self, sticky: bool) -> Self {
+        self.sticky_headers = sticky;
+        self
+    }
+
     /// Set key for reconciliation
     pub fn key(mut self, key: impl Into<String>) -> Self {
         self.key = Some(key.into());
@@ -210,6 +351,14 @@ impl List {
         let offset = state.offset;
         let height = viewport_height.unwrap_or(self.items.len());
         let symbol_width = self.highlight_symbol.as_ref().map(|s| s.len()).unwrap_or(0);
+        let separator_width = self.separator_width(symbol_width);
+
+        let pinned = if self.sticky_headers && viewport_height.is_some() && height > 1 {
+            pinned_header(&self.items, offset)
+        } else {
+            None
+        };
+        let item_rows = if pinned.is_some() { height - 1 } else { height };
 
         // Get visible items
         let visible_items: Vec<_> = self
@@ -217,18 +366,32 @@ impl List {
             .iter()
             .enumerate()
             .skip(offset)
-            .take(height)
+            .take(item_rows)
             .collect();
 
         // Build list element
         let mut container = RnkBox::new().flex_direction(crate::core::FlexDirection::Column);
 
-        if let Some(key) = self.key {
+        if let Some(key) = self.key.clone() {
             container = container.key(key);
         }
 
+        if let Some(header) = pinned {
+            container = container.child(self.render_header(&self.items[header]));
+        }
+
         for (idx, item) in visible_items {
-            let is_selected = self.show_selection && selected == Some(idx);
+            if item.is_header() {
+                container = container.child(self.render_header(item));
+                continue;
+            }
+            if item.is_separator() {
+                let line = "─".repeat(separator_width);
+                container = container.child(Text::new(line).dim().into_element());
+                continue;
+            }
+
+            let is_selected = self.show_selection && selected == Some(idx) && !item.is_disabled();
 
             // Build the item content
             let mut spans = Vec::new();
@@ -250,7 +413,9 @@ impl List {
             let line = Line::from_spans(spans);
             let mut text = Text::line(line);
 
-            if is_selected {
+            if item.is_disabled() {
+                text = text.dim();
+            } else if is_selected {
                 if let Some(color) = self.highlight_style.color {
                     text = text.color(color);
                 }
@@ -275,6 +440,38 @@ impl List {
         container.into_element()
     }
 
+    /// Width of a separator row: the widest item row, at least one cell
+    fn separator_width(&self, symbol_width: usize) -> usize {
+        self.items
+            .iter()
+            .filter(|item| !item.is_separator())
+            .map(|item| {
+                let content: usize = item
+                    .content
+                    .spans
+                    .iter()
+                    .map(|span| measure_text_width(&span.content))
+                    .sum();
+                if item.is_header() {
+                    content
+                } else {
+                    content + symbol_width
+                }
+            })
+            .max()
+            .unwrap_or(0)
+            .max(1)
+    }
+
+    /// Render a section header row
+    fn render_header(&self, item: &ListItem) -> Element {
+        let mut text = Text::line(item.content.clone()).bold();
+        if let Some(color) = item.style.as_ref().and_then(|style| style.color) {
+            text = text.color(color);
+        }
+        text.into_element()
+    }
+
     /// Convert to element (no selection)
     pub fn into_element(self) -> Element {
         self.render(&ListState::new())
@@ -294,7 +491,7 @@ mod tests {
     #[test]
     fn test_list_item_creation() {
         let item = ListItem::new("Test item");
-        assert_eq!(item.content.spans[0].content, "Test item");
+        assert_eq!(item.content().spans[0].content, "Test item");
     }
 
     #[test]
@@ -331,10 +528,141 @@ mod tests {
         assert_eq!(state.selected, Some(0));
     }
 
+    fn grouped_items() -> Vec<ListItem> {
+        vec![
+            ListItem::header("Files"),
+            ListItem::new("open"),
+            ListItem::new("save"),
+            ListItem::new("close"),
+            ListItem::header("Edit"),
+            ListItem::new("undo"),
+            ListItem::new("redo"),
+        ]
+    }
+
+    #[test]
+    fn test_navigation_skips_headers() {
+        let items = grouped_items();
+        let mut state = ListState::new();
+
+        state.select_first_item(&items);
+        assert_eq!(state.selected, Some(1));
+
+        state.selected = Some(3);
+        state.select_next_item(&items);
+        assert_eq!(state.selected, Some(5));
+
+        state.select_previous_item(&items);
+        assert_eq!(state.selected, Some(3));
+
+        state.selected = Some(1);
+        state.select_previous_item(&items);
+        assert_eq!(state.selected, Some(1));
+
+        state.select_last_item(&items);
+        assert_eq!(state.selected, Some(6));
+    }
+
+    #[test]
+    fn test_stock_navigation_skips_headers() {
+        use crate::components::navigation::{NavigationConfig, NavigationResult};
+        use crate::hooks::Key;
+
+        let items = grouped_items();
+        let mut state = ListState::new();
+        state.set_items(&items);
+
+        state.select_next(items.len());
+        assert_eq!(state.selected, Some(1));
+
+        state.selected = Some(3);
+        state.select_next(items.len());
+        assert_eq!(state.selected, Some(5));
+        state.select_previous(items.len());
+        assert_eq!(state.selected, Some(3));
+
+        state.select_first(items.len());
+        assert_eq!(state.selected, Some(1));
+
+        let up = Key {
+            up_arrow: true,
+            ..Key::default()
+        };
+        let result = state.handle_navigation(items.len(), "", up, &NavigationConfig::new());
+        assert_eq!(result, NavigationResult::Moved(1));
+        assert_eq!(state.selected, Some(1));
+
+        let end = Key {
+            end: true,
+            ..Key::default()
+        };
+        state.handle_navigation(items.len(), "", end, &NavigationConfig::new());
+        assert_eq!(state.selected, Some(6));
+    }
+
+    #[test]
+    fn test_sticky_header_pinned_when_section_scrolled() {
+        let items = grouped_items();
+        let state = ListState {
+            selected: Some(2),
+            offset: 2,
+            ..ListState::default()
+        };
+        let element = List::from_items(items.clone()).render_with_height(&state, Some(3));
+        let output = crate::testing::TestRenderer::new(20, 3).render_to_plain(&element);
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["Files", "save", "close"]);
+
+        // A header at the top of the window is not duplicated
+        let state = ListState {
+            selected: Some(5),
+            offset: 4,
+            ..ListState::default()
+        };
+        let element = List::from_items(items).render_with_height(&state, Some(3));
+        let output = crate::testing::TestRenderer::new(20, 3).render_to_plain(&element);
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["Edit", "undo", "redo"]);
+    }
+
+    #[test]
+    fn test_scroll_to_selected_item_accounts_for_pinned_header() {
+        let items = grouped_items();
+        let mut state = ListState::with_selected(Some(3));
+        state.scroll_to_selected_item(&items, 3);
+        // Offset 1 would pin "Files" and push "close" out of the window
+        assert_eq!(state.offset, 2);
+    }
+
     #[test]
     fn test_scroll_to_selected() {
         let mut state = ListState::with_selected(Some(15));
         state.scroll_to_selected(10);
         assert_eq!(state.offset, 6); // 15 - (10 - 1) = 6
     }
+
+    #[test]
+    fn test_disabled_and_separator_items() {
+        let items = vec![
+            ListItem::new("cut"),
+            ListItem::new("copy").disabled(true),
+            ListItem::separator(),
+            ListItem::new("paste"),
+        ];
+        assert!(!items[1].is_selectable());
+        assert!(!items[2].is_selectable());
+
+        let mut state = ListState::with_selected(Some(0));
+        state.select_next_item(&items);
+        assert_eq!(state.selected, Some(3));
+        state.select_previous_item(&items);
+        assert_eq!(state.selected, Some(0));
+
+        let element = List::from_items(items)
+            .highlight_symbol("> ")
+            .render(&state);
+        let output = crate::testing::TestRenderer::new(10, 4).render_to_plain(&element);
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["> cut", "  copy", "───────", "  paste"]);
+    }
 }
@@ -1,10 +1,18 @@
 //! Markdown rendering component
 //!
 //! Renders basic Markdown text with terminal styling.
+//!
+//! Inline and reference-style links become clickable OSC 8 hyperlinks on
+//! terminals that support them, and `text (url)` elsewhere.
+
+use std::collections::HashMap;
 
-use crate::components::{Box as RnkBox, Text};
+use crate::components::{Box as RnkBox, HyperlinkBuilder, Text, supports_hyperlinks};
 use crate::core::{Color, Element, FlexDirection};
 
+/// Reference link definitions (`[label]: url`), keyed by lowercase label
+type LinkDefinitions = HashMap<String, String>;
+
 /// Markdown rendering component
 #[derive(Debug, Clone)]
 pub struct Markdown {
@@ -20,6 +28,10 @@ pub struct Markdown {
     quote_color: Color,
     /// Maximum width
     width: Option<u16>,
+    /// Force OSC 8 hyperlinks on or off (`None` = detect)
+    hyperlinks: Option<bool>,
+    /// Append `(url)` to link text when hyperlinks are unavailable
+    show_link_urls: bool,
     /// Key for reconciliation
     key: Option<String>,
 }
@@ -34,6 +46,8 @@ impl Markdown {
             link_color: Color::Blue,
             quote_color: Color::BrightBlack,
             width: None,
+            hyperlinks: None,
+            show_link_urls: true,
             key: None,
         }
     }
@@ -68,6 +82,20 @@ impl Markdown {
         self
     }
 
+    /// Force clickable OSC 8 links on or off instead of detecting support
+    pub fn hyperlinks(mut self, enabled: bool) -> Self {
+        self.hyperlinks = Some(enabled);
+        self
+    }
+
+    /// Show `(url)` after link text when hyperlinks are unavailable (default: true)
+    ///
+    /// When disabled, links fall back to underlined text only.
+    pub fn show_link_urls(mut self, show: bool) -> Self {
+        self.show_link_urls = show;
+        self
+    }
+
     /// Set key
     pub fn key(mut self, key: impl Into<String>) -> Self {
         self.key = Some(key.into());
@@ -93,6 +121,7 @@ impl Markdown {
         let mut elements = Vec::new();
         let mut in_code_block = false;
         let mut code_block_content = Vec::new();
+        let definitions = self.link_definitions();
 
         for line in self.content.lines() {
             // Code block handling
@@ -114,8 +143,13 @@ impl Markdown {
                 continue;
             }
 
+            // Reference definitions are not rendered
+            if parse_link_definition(line).is_some() {
+                continue;
+            }
+
             // Parse line
-            elements.push(self.parse_line(line));
+            elements.push(self.parse_line(line, &definitions));
         }
 
         // Handle unclosed code block
@@ -126,7 +160,22 @@ impl Markdown {
         elements
     }
 
-    fn parse_line(&self, line: &str) -> Element {
+    /// Collect `[label]: url` definitions outside code blocks
+    fn link_definitions(&self) -> LinkDefinitions {
+        let mut definitions = LinkDefinitions::new();
+        let mut in_code_block = false;
+        for line in self.content.lines() {
+            if line.starts_with("```") {
+                in_code_block = !in_code_block;
+            } else if !in_code_block && let Some((label, url)) = parse_link_definition(line) {
+                // The first definition of a label wins
+                definitions.entry(label).or_insert(url);
+            }
+        }
+        definitions
+    }
+
+    fn parse_line(&self, line: &str, definitions: &LinkDefinitions) -> Element {
         let trimmed = line.trim();
 
         // Empty line
@@ -151,16 +200,16 @@ impl Markdown {
 
         // Unordered list
         if trimmed.starts_with("- ") || trimmed.starts_with("* ") || trimmed.starts_with("+ ") {
-            return self.render_list_item(&trimmed[2..], false, 0);
+            return self.render_list_item(&trimmed[2..], false, definitions);
         }
 
         // Ordered list
         if let Some(rest) = self.parse_ordered_list(trimmed) {
-            return self.render_list_item(rest, true, 0);
+            return self.render_list_item(rest, true, definitions);
         }
 
         // Regular paragraph with inline formatting
-        self.render_inline(trimmed)
+        self.render_inline(trimmed, definitions)
     }
 
     fn parse_heading(&self, line: &str) -> Option<Element> {
@@ -236,9 +285,14 @@ impl Markdown {
             .into_element()
     }
 
-    fn render_list_item(&self, text: &str, ordered: bool, _indent: usize) -> Element {
+    fn render_list_item(
+        &self,
+        text: &str,
+        ordered: bool,
+        definitions: &LinkDefinitions,
+    ) -> Element {
         let bullet = if ordered { "  " } else { "• " };
-        self.render_inline(&format!("{}{}", bullet, text))
+        self.render_inline(&format!("{}{}", bullet, text), definitions)
     }
 
     fn render_hr(&self) -> Element {
@@ -247,7 +301,18 @@ impl Markdown {
         Text::new(line).dim().into_element()
     }
 
-    fn render_inline(&self, text: &str) -> Element {
+    fn render_link(&self, text: &str, url: &str) -> String {
+        let link = HyperlinkBuilder::new(url, text).color(self.link_color);
+        if self.hyperlinks.unwrap_or_else(supports_hyperlinks) {
+            link.render_osc8()
+        } else if self.show_link_urls && text != url {
+            format!("{} ({})", link.render_text(), url)
+        } else {
+            link.render_text()
+        }
+    }
+
+    fn render_inline(&self, text: &str, definitions: &LinkDefinitions) -> Element {
         // Simple inline parsing - handles **bold**, *italic*, `code`, [links](url)
         let mut result = String::new();
         let mut chars = text.chars().peekable();
@@ -260,7 +325,7 @@ impl Markdown {
                 '`' => {
                     // Inline code
                     if !current_text.is_empty() {
-                        segments.push((current_text.clone(), current_style));
+                        segments.push((current_text.clone(), current_style.clone()));
                         current_text.clear();
                     }
                     let mut code = String::new();
@@ -278,7 +343,7 @@ impl Markdown {
                         // Bold
                         chars.next();
                         if !current_text.is_empty() {
-                            segments.push((current_text.clone(), current_style));
+                            segments.push((current_text.clone(), current_style.clone()));
                             current_text.clear();
                         }
                         let mut bold_text = String::new();
@@ -298,7 +363,7 @@ impl Markdown {
                     } else {
                         // Italic
                         if !current_text.is_empty() {
-                            segments.push((current_text.clone(), current_style));
+                            segments.push((current_text.clone(), current_style.clone()));
                             current_text.clear();
                         }
                         let mut italic_text = String::new();
@@ -315,7 +380,7 @@ impl Markdown {
                 '[' => {
                     // Link
                     if !current_text.is_empty() {
-                        segments.push((current_text.clone(), current_style));
+                        segments.push((current_text.clone(), current_style.clone()));
                         current_text.clear();
                     }
                     let mut link_text = String::new();
@@ -326,18 +391,42 @@ impl Markdown {
                         }
                         link_text.push(chars.next().unwrap());
                     }
-                    // Skip (url)
-                    if chars.peek() == Some(&'(') {
-                        chars.next();
-                        while let Some(&next) = chars.peek() {
-                            if next == ')' {
-                                chars.next();
-                                break;
+                    let url = match chars.peek() {
+                        // Inline: [text](url)
+                        Some('(') => {
+                            chars.next();
+                            let mut url = String::new();
+                            while let Some(&next) = chars.peek() {
+                                if next == ')' {
+                                    chars.next();
+                                    break;
+                                }
+                                url.push(chars.next().unwrap());
                             }
+                            Some(url.trim().to_string())
+                        }
+                        // Full or collapsed reference: [text][ref], [text][]
+                        Some('[') => {
                             chars.next();
+                            let mut label = String::new();
+                            while let Some(&next) = chars.peek() {
+                                if next == ']' {
+                                    chars.next();
+                                    break;
+                                }
+                                label.push(chars.next().unwrap());
+                            }
+                            let label = if label.is_empty() { &link_text } else { &label };
+                            definitions.get(&label.to_lowercase()).cloned()
                         }
+                        // Shortcut reference: [text]
+                        _ => definitions.get(&link_text.to_lowercase()).cloned(),
+                    };
+                    match url {
+                        Some(url) => segments.push((link_text, TextStyle::Link(url))),
+                        // Unresolved references stay literal
+                        None => segments.push((format!("[{}]", link_text), TextStyle::Normal)),
                     }
-                    segments.push((link_text, TextStyle::Link));
                 }
                 _ => {
                     current_text.push(c);
@@ -356,7 +445,7 @@ impl Markdown {
                 TextStyle::Bold => result.push_str(&format!("\x1b[1m{}\x1b[0m", text)),
                 TextStyle::Italic => result.push_str(&format!("\x1b[3m{}\x1b[0m", text)),
                 TextStyle::Code => result.push_str(&format!("\x1b[33m{}\x1b[0m", text)),
-                TextStyle::Link => result.push_str(&format!("\x1b[34m\x1b[4m{}\x1b[0m", text)),
+                TextStyle::Link(url) => result.push_str(&self.render_link(text, url)),
             }
         }
 
@@ -364,13 +453,25 @@ impl Markdown {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 enum TextStyle {
     Normal,
     Bold,
     Italic,
     Code,
-    Link,
+    Link(String),
+}
+
+/// Parse a reference definition line: `[label]: url "optional title"`
+fn parse_link_definition(line: &str) -> Option<(String, String)> {
+    let rest = line.trim().strip_prefix('[')?;
+    let (label, rest) = rest.split_once("]:")?;
+    let url = rest.split_whitespace().next()?;
+    let url = url.trim_start_matches('<').trim_end_matches('>');
+    if label.trim().is_empty() || url.is_empty() {
+        return None;
+    }
+    Some((label.trim().to_lowercase(), url.to_string()))
 }
 
 impl Default for Markdown {
@@ -424,4 +525,50 @@ mod tests {
         let md = Markdown::new("> This is a quote");
         let _ = md.into_element();
     }
+
+    fn render_plain(md: Markdown) -> String {
+        crate::render_to_string_raw(&md.into_element(), 80)
+    }
+
+    #[test]
+    fn test_inline_link_osc8() {
+        let out = render_plain(Markdown::new("see [docs](https://example.com)").hyperlinks(true));
+        // The link's color codes are drawn inside it
+        let (_, linked) = out.split_once("\x1b]8;;https://example.com\x1b\\").unwrap();
+        let (linked, _) = linked.split_once("\x1b]8;;\x1b\\").unwrap();
+        assert!(linked.contains("docs"));
+    }
+
+    #[test]
+    fn test_link_fallback_shows_url() {
+        let md = Markdown::new("see [docs](https://example.com)").hyperlinks(false);
+        let out = render_plain(md);
+        assert!(!out.contains("\x1b]8"));
+        assert!(out.contains("docs\x1b[0m (https://example.com)"));
+
+        let md = Markdown::new("see [docs](https://example.com)")
+            .hyperlinks(false)
+            .show_link_urls(false);
+        assert!(!render_plain(md).contains("example.com"));
+    }
+
+    #[test]
+    fn test_reference_links_resolve() {
+        let md = Markdown::new(
+            "[Full][site], [Site][] and [site]\n\n[site]: <https://example.com> \"Title\"",
+        )
+        .hyperlinks(true);
+        let out = render_plain(md);
+        assert_eq!(out.matches("\x1b]8;;https://example.com\x1b\\").count(), 3);
+        assert!(!out.contains("[site]:"));
+    }
+
+    #[test]
+    fn test_unresolved_reference_is_literal() {
+        let md = Markdown::new("- [ ] todo and [missing][nope]").hyperlinks(true);
+        let out = render_plain(md);
+        assert!(out.contains("[ ] todo"));
+        assert!(out.contains("[missing]"));
+        assert!(!out.contains("\x1b]8"));
+    }
 }
@@ -1,9 +1,24 @@
 //! Message component for chat-style interfaces
 //!
 //! Provides styled message components for different roles (user, assistant, system, tool).
+//!
+//! For streamed LLM responses, a [`MessageState`] accumulates tokens and
+//! reasoning as they arrive; [`Message::from_state`] shows the partial text
+//! with a blinking cursor while streaming and renders the final content as
+//! markdown once finished.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use crate::components::{Box, Text};
+use crate::animation::prefers_reduced_motion;
+use crate::components::{Box, CodeEditor, Language, Markdown, Text};
 use crate::core::{Color, Element, FlexDirection};
+use crate::renderer::frame_rate::AnimationActivity;
+
+/// Cursor shown at the end of a streaming message
+const STREAMING_CURSOR: &str = "▌";
+/// How long the streaming cursor stays on (and off) per blink
+const CURSOR_BLINK_MS: u128 = 530;
 
 /// Message role in a chat interface
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -41,6 +56,9 @@ pub struct Message {
     role: MessageRole,
     content: String,
     prefix: Option<String>,
+    /// Streaming cursor, `Some(visible)` while the message is streaming
+    cursor: Option<bool>,
+    markdown: bool,
 }
 
 impl Message {
@@ -50,6 +68,21 @@ impl Message {
             role,
             content: content.into(),
             prefix: None,
+            cursor: None,
+            markdown: false,
+        }
+    }
+
+    /// Create a message showing the text streamed into `state` so far
+    ///
+    /// While streaming, the text ends in a blinking cursor; once finished
+    /// it is rendered as markdown.
+    pub fn from_state(role: MessageRole, state: &MessageState) -> Self {
+        let message = Self::new(role, state.content());
+        if state.is_streaming() {
+            message.streaming(state.cursor_visible())
+        } else {
+            message.markdown(true)
         }
     }
 
@@ -89,6 +122,18 @@ impl Message {
         self
     }
 
+    /// Show the message as still streaming, with the cursor on or off
+    pub fn streaming(mut self, cursor_visible: bool) -> Self {
+        self.cursor = Some(cursor_visible);
+        self
+    }
+
+    /// Render the content as markdown (not while streaming)
+    pub fn markdown(mut self, markdown: bool) -> Self {
+        self.markdown = markdown;
+        self
+    }
+
     /// Get the default prefix for this role
     fn default_prefix(&self) -> &str {
         match self.role {
@@ -143,25 +188,84 @@ impl Message {
         }
 
         // Add content
-        container = container.child(Text::new(&self.content).color(content_color).into_element());
+        let content = match self.cursor {
+            Some(true) => Text::new(format!("{}{}", self.content, STREAMING_CURSOR)),
+            // Keep the width steady while the cursor blinks off
+            Some(false) => Text::new(format!("{} ", self.content)),
+            None if self.markdown => {
+                return container
+                    .child(Markdown::new(&self.content).into_element())
+                    .into_element();
+            }
+            None => Text::new(&self.content),
+        };
+        container = container.child(content.color(content_color).into_element());
 
         container.into_element()
     }
 }
 
+/// Progress of a tool call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToolCallStatus {
+    /// Waiting to run
+    Pending,
+    /// Currently running
+    Running,
+    /// Finished successfully
+    Success,
+    /// Failed
+    Error,
+}
+
+impl ToolCallStatus {
+    fn badge(self) -> (&'static str, Color) {
+        match self {
+            ToolCallStatus::Pending => ("○ pending", Color::Ansi256(245)),
+            ToolCallStatus::Running => ("◐ running", Color::Yellow),
+            ToolCallStatus::Success => ("✓ done", Color::Green),
+            ToolCallStatus::Error => ("✗ error", Color::Red),
+        }
+    }
+}
+
+/// Longest argument summary shown in a collapsed tool call
+const ARGS_SUMMARY_WIDTH: usize = 60;
+
 /// Tool call message with name and arguments
 ///
+/// With a [`status`](Self::status) it shows a status badge, and when
+/// [`expanded`](Self::expanded) it lists the JSON
+/// [`arguments`](Self::arguments) pretty-printed and highlighted, followed
+/// by the [`result`](Self::result). Toggle expansion from the keyboard with
+/// [`handle_tool_call_input`] while the call is focused.
+///
 /// # Example
 ///
 /// ```ignore
-/// use rnk::components::ToolCall;
+/// use rnk::components::{ToolCall, ToolCallStatus};
 ///
 /// let tool = ToolCall::new("read_file", "path=/tmp/test.txt");
 /// rnk::println(tool.into_element());
+///
+/// ToolCall::new("search", "")
+///     .arguments(r#"{"query": "rnk", "limit": 5}"#)
+///     .status(ToolCallStatus::Success)
+///     .duration(Duration::from_millis(420))
+///     .result("5 matches")
+///     .expanded(expanded.get())
+///     .focused(focus.is_focused)
+///     .into_element()
 /// ```
 pub struct ToolCall {
     name: String,
     args: String,
+    arguments: Option<String>,
+    status: Option<ToolCallStatus>,
+    result: Option<String>,
+    duration: Option<Duration>,
+    expanded: bool,
+    focused: bool,
 }
 
 impl ToolCall {
@@ -170,26 +274,259 @@ impl ToolCall {
         Self {
             name: name.into(),
             args: args.into(),
+            arguments: None,
+            status: None,
+            result: None,
+            duration: None,
+            expanded: false,
+            focused: false,
         }
     }
 
+    /// Set the call's arguments as JSON
+    ///
+    /// Collapsed, they are summarized on one line; expanded, they are
+    /// pretty-printed with syntax highlighting.
+    pub fn arguments(mut self, json: impl Into<String>) -> Self {
+        self.arguments = Some(json.into());
+        self
+    }
+
+    /// Show a status badge
+    pub fn status(mut self, status: ToolCallStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Set the output of the call, shown when expanded
+    pub fn result(mut self, result: impl Into<String>) -> Self {
+        self.result = Some(result.into());
+        self
+    }
+
+    /// Show how long the call took
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Show the full arguments and result
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Highlight the call as the keyboard target
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
     /// Convert to an Element
     pub fn into_element(self) -> Element {
-        Box::new()
+        let expandable = self.arguments.is_some() || self.result.is_some();
+        let marker = match (expandable, self.expanded) {
+            (false, _) => "● ",
+            (true, false) => "▸ ",
+            (true, true) => "▾ ",
+        };
+        let summary = match &self.arguments {
+            Some(json) if !self.expanded => summarize_json(json),
+            Some(_) => String::new(),
+            None => self.args.clone(),
+        };
+
+        let mut name = Text::new(&self.name).color(Color::Magenta).bold();
+        if self.focused {
+            name = name.underline();
+        }
+        let mut header = Box::new()
             .flex_direction(FlexDirection::Row)
-            .child(Text::new("● ").color(Color::Magenta).into_element())
+            .child(Text::new(marker).color(Color::Magenta).into_element())
+            .child(name.into_element())
             .child(
-                Text::new(&self.name)
+                Text::new(format!("({})", summary))
                     .color(Color::Magenta)
-                    .bold()
                     .into_element(),
-            )
-            .child(
-                Text::new(format!("({})", self.args))
-                    .color(Color::Magenta)
+            );
+        if let Some(status) = self.status {
+            let (label, color) = status.badge();
+            header = header.child(Text::new(format!(" {label}")).color(color).into_element());
+        }
+        if let Some(duration) = self.duration {
+            header = header.child(
+                Text::new(format!(" {}", format_tool_duration(duration)))
+                    .color(Color::Ansi256(245))
+                    .into_element(),
+            );
+        }
+
+        if !self.expanded || !expandable {
+            return header.into_element();
+        }
+
+        let mut container = Box::new()
+            .flex_direction(FlexDirection::Column)
+            .child(header.into_element());
+        if let Some(json) = &self.arguments {
+            container = container.child(
+                Box::new()
+                    .padding_left(4.0)
+                    .child(
+                        CodeEditor::new(pretty_json(json))
+                            .language(Language::Json)
+                            .show_line_numbers(false)
+                            .into_element(),
+                    )
                     .into_element(),
-            )
-            .into_element()
+            );
+        }
+        if let Some(result) = &self.result {
+            let color = if self.status == Some(ToolCallStatus::Error) {
+                Color::Red
+            } else {
+                Color::Ansi256(245)
+            };
+            container = container.child(
+                Box::new()
+                    .flex_direction(FlexDirection::Row)
+                    .child(Text::new("  ⎿ ").color(Color::Ansi256(245)).into_element())
+                    .child(Text::new(result).color(color).into_element())
+                    .into_element(),
+            );
+        }
+        container.into_element()
+    }
+}
+
+/// Expand or collapse a focused [`ToolCall`]
+///
+/// Enter and Space toggle, Right expands and Left collapses. Returns true
+/// when the key was handled.
+pub fn handle_tool_call_input(expanded: &mut bool, _input: &str, key: &crate::hooks::Key) -> bool {
+    if key.return_key || key.space {
+        *expanded = !*expanded;
+    } else if key.right_arrow {
+        *expanded = true;
+    } else if key.left_arrow {
+        *expanded = false;
+    } else {
+        return false;
+    }
+    true
+}
+
+/// Format a tool call duration compactly (`420ms`, `1.5s`, `2m 05s`)
+fn format_tool_duration(duration: Duration) -> String {
+    let millis = duration.as_millis();
+    if millis < 1000 {
+        format!("{millis}ms")
+    } else if millis < 60_000 {
+        format!("{:.1}s", duration.as_secs_f64())
+    } else {
+        let secs = duration.as_secs();
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    }
+}
+
+/// Split JSON into tokens, keeping strings (with their escapes) whole
+fn json_tokens(json: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = json.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '"' => {
+                let mut token = String::from('"');
+                while let Some(c) = chars.next() {
+                    token.push(c);
+                    match c {
+                        '\\' => token.extend(chars.next()),
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+                tokens.push(token);
+            }
+            '{' | '}' | '[' | ']' | ',' | ':' => tokens.push(c.to_string()),
+            _ => {
+                let mut token = String::from(c);
+                while let Some(&next) = chars.peek() {
+                    if next.is_whitespace() || "{}[],:\"".contains(next) {
+                        break;
+                    }
+                    token.push(next);
+                    chars.next();
+                }
+                tokens.push(token);
+            }
+        }
+    }
+    tokens
+}
+
+/// Re-indent JSON with two spaces per level
+///
+/// This only reformats whitespace, so text that isn't JSON comes back
+/// mostly as-is.
+fn pretty_json(json: &str) -> String {
+    let tokens = json_tokens(json);
+    let mut out = String::new();
+    let mut depth = 0usize;
+    let newline = |out: &mut String, depth: usize| {
+        out.push('\n');
+        out.push_str(&"  ".repeat(depth));
+    };
+
+    for (index, token) in tokens.iter().enumerate() {
+        let next = tokens.get(index + 1).map(String::as_str);
+        match token.as_str() {
+            "{" | "[" => {
+                out.push_str(token);
+                // Keep empty objects and arrays on one line
+                if !matches!(next, Some("}" | "]")) {
+                    depth += 1;
+                    newline(&mut out, depth);
+                }
+            }
+            "}" | "]" => {
+                let previous = index.checked_sub(1).map(|i| tokens[i].as_str());
+                if !matches!(previous, Some("{" | "[")) {
+                    depth = depth.saturating_sub(1);
+                    newline(&mut out, depth);
+                }
+                out.push_str(token);
+            }
+            "," => {
+                out.push(',');
+                newline(&mut out, depth);
+            }
+            ":" => out.push_str(": "),
+            _ => out.push_str(token),
+        }
+    }
+    out
+}
+
+/// One-line summary of JSON arguments, cut to fit a header
+fn summarize_json(json: &str) -> String {
+    let compact: String = json_tokens(json)
+        .iter()
+        .map(|token| match token.as_str() {
+            "," => ", ",
+            ":" => ": ",
+            token => token,
+        })
+        .collect();
+    let compact = compact
+        .strip_prefix('{')
+        .and_then(|inner| inner.strip_suffix('}'))
+        .unwrap_or(&compact);
+    if compact.chars().count() > ARGS_SUMMARY_WIDTH {
+        let cut: String = compact.chars().take(ARGS_SUMMARY_WIDTH - 1).collect();
+        format!("{cut}…")
+    } else {
+        compact.to_string()
     }
 }
 
@@ -206,6 +543,7 @@ impl ToolCall {
 pub struct ThinkingBlock {
     content: String,
     max_lines: usize,
+    collapsed: bool,
 }
 
 impl ThinkingBlock {
@@ -214,19 +552,44 @@ impl ThinkingBlock {
         Self {
             content: content.into(),
             max_lines: 5,
+            collapsed: false,
         }
     }
 
+    /// Create a thinking block for the reasoning streamed into `state`
+    ///
+    /// It is collapsed unless [`MessageState::toggle_thinking`] expanded it,
+    /// and shows every line when expanded.
+    pub fn from_state(state: &MessageState) -> Self {
+        Self::new(state.thinking())
+            .collapsed(!state.is_thinking_expanded())
+            .max_lines(usize::MAX)
+    }
+
     /// Set the maximum number of lines to display
     pub fn max_lines(mut self, max_lines: usize) -> Self {
         self.max_lines = max_lines;
         self
     }
 
+    /// Show only the header with a line count
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
     /// Convert to an Element
     pub fn into_element(self) -> Element {
+        let total = self.content.lines().count();
+        if self.collapsed {
+            let noun = if total == 1 { "line" } else { "lines" };
+            return Text::new(format!("▸ Thinking ({total} {noun})"))
+                .color(Color::Magenta)
+                .into_element();
+        }
+
         let lines: Vec<&str> = self.content.lines().take(self.max_lines).collect();
-        let has_more = self.content.lines().count() > self.max_lines;
+        let has_more = total > self.max_lines;
 
         let mut container = Box::new().flex_direction(FlexDirection::Column).child(
             Text::new("● Thinking...")
@@ -257,6 +620,125 @@ impl ThinkingBlock {
     }
 }
 
+/// Accumulates a message as it is streamed in
+///
+/// Append tokens as they arrive and call [`finish`](Self::finish) when the
+/// response is complete. Render it with [`Message::from_state`] and
+/// [`ThinkingBlock::from_state`].
+///
+/// # Example
+///
+/// ```ignore
+/// let state = use_signal(MessageState::new);
+///
+/// // In the stream handler
+/// state.update(|s| s.push_token(&token));
+/// state.update(|s| s.finish());
+///
+/// // In the component
+/// Message::from_state(MessageRole::Assistant, &state.get()).into_element()
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MessageState {
+    content: String,
+    thinking: String,
+    thinking_expanded: bool,
+    /// When streaming started, `None` once finished
+    streaming_since: Option<Instant>,
+    /// Keeps frames coming while the cursor blinks
+    activity: Arc<AnimationActivity>,
+}
+
+impl MessageState {
+    /// Create an empty, finished message
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an empty message that is streaming
+    pub fn streaming() -> Self {
+        let state = Self {
+            streaming_since: Some(Instant::now()),
+            ..Self::default()
+        };
+        state.sync_activity();
+        state
+    }
+
+    /// Append a streamed token to the content, marking the message as streaming
+    pub fn push_token(&mut self, token: &str) {
+        self.streaming_since.get_or_insert_with(Instant::now);
+        self.content.push_str(token);
+        self.sync_activity();
+    }
+
+    /// Append streamed reasoning for the thinking block
+    pub fn push_thinking(&mut self, token: &str) {
+        self.streaming_since.get_or_insert_with(Instant::now);
+        self.thinking.push_str(token);
+        self.sync_activity();
+    }
+
+    /// Mark the message as complete
+    pub fn finish(&mut self) {
+        self.streaming_since = None;
+        self.sync_activity();
+    }
+
+    /// Clear the content and reasoning
+    pub fn clear(&mut self) {
+        *self = Self {
+            activity: self.activity.clone(),
+            ..Self::default()
+        };
+        self.sync_activity();
+    }
+
+    /// Whether tokens are still arriving
+    pub fn is_streaming(&self) -> bool {
+        self.streaming_since.is_some()
+    }
+
+    /// Text streamed so far
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// Reasoning streamed so far
+    pub fn thinking(&self) -> &str {
+        &self.thinking
+    }
+
+    /// Whether the thinking block shows its content
+    pub fn is_thinking_expanded(&self) -> bool {
+        self.thinking_expanded
+    }
+
+    /// Expand or collapse the thinking block
+    pub fn toggle_thinking(&mut self) {
+        self.thinking_expanded = !self.thinking_expanded;
+    }
+
+    /// Whether the streaming cursor is in the "on" phase of its blink
+    ///
+    /// The cursor stays on when reduced motion is preferred.
+    pub fn cursor_visible(&self) -> bool {
+        match self.streaming_since {
+            Some(since) if !prefers_reduced_motion() => {
+                (since.elapsed().as_millis() / CURSOR_BLINK_MS).is_multiple_of(2)
+            }
+            _ => true,
+        }
+    }
+
+    /// Register the blinking cursor as an animation while streaming, so the
+    /// app keeps rendering frames for it
+    fn sync_activity(&self) {
+        self.activity
+            .set(self.is_streaming() && !prefers_reduced_motion());
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -290,4 +772,108 @@ mod tests {
         let element = thinking.into_element();
         assert!(!element.children.is_empty());
     }
+
+    fn plain(element: Element) -> String {
+        crate::testing::TestRenderer::new(30, 4)
+            .render_to_plain(&element)
+            .lines()
+            .map(str::trim_end)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_message_state_streams_then_renders_markdown() {
+        let mut state = MessageState::new();
+        assert!(!state.is_streaming());
+
+        state.push_token("Hello ");
+        state.push_token("**world**");
+        assert!(state.is_streaming());
+        assert_eq!(state.content(), "Hello **world**");
+
+        let streaming = Message::assistant(state.content()).streaming(true);
+        assert_eq!(plain(streaming.into_element()), "● Hello **world**▌");
+
+        state.finish();
+        assert!(!state.is_streaming());
+        assert!(state.cursor_visible());
+        let done = Message::from_state(MessageRole::Assistant, &state);
+        assert_eq!(plain(done.into_element()), "● Hello world");
+    }
+
+    #[test]
+    fn test_streaming_cursor_registers_as_animation() {
+        let mut state = MessageState::new();
+        assert!(!state.activity.is_active());
+
+        state.push_token("Hi");
+        assert_eq!(state.activity.is_active(), !prefers_reduced_motion());
+
+        state.finish();
+        assert!(!state.activity.is_active());
+    }
+
+    #[test]
+    fn test_thinking_block_collapses() {
+        let mut state = MessageState::streaming();
+        state.push_thinking("step one\nstep two");
+
+        let collapsed = ThinkingBlock::from_state(&state).into_element();
+        assert_eq!(plain(collapsed), "▸ Thinking (2 lines)");
+
+        state.toggle_thinking();
+        let expanded = ThinkingBlock::from_state(&state).into_element();
+        assert_eq!(plain(expanded), "● Thinking...\n  step one\n  step two");
+    }
+
+    #[test]
+    fn test_pretty_json() {
+        let json = r#"{"query":"a, \"b\"","opts":{"limit":5,"tags":[]},"ok":true}"#;
+        assert_eq!(
+            pretty_json(json),
+            "{\n  \"query\": \"a, \\\"b\\\"\",\n  \"opts\": {\n    \"limit\": 5,\n    \"tags\": []\n  },\n  \"ok\": true\n}"
+        );
+        assert_eq!(
+            summarize_json(r#"{"path": "/tmp", "tags": [1,2]}"#),
+            r#""path": "/tmp", "tags": [1, 2]"#
+        );
+        let summary = summarize_json(json);
+        assert_eq!(summary.chars().count(), ARGS_SUMMARY_WIDTH);
+        assert!(summary.ends_with("\"ok\"…"));
+    }
+
+    #[test]
+    fn test_tool_call_status_and_expansion() {
+        let call = || {
+            ToolCall::new("search", "")
+                .arguments(r#"{"q": "rnk"}"#)
+                .status(ToolCallStatus::Success)
+                .duration(Duration::from_millis(1500))
+                .result("3 matches")
+        };
+        let plain = |element: Element| {
+            crate::testing::TestRenderer::new(40, 4)
+                .render_to_plain(&element)
+                .lines()
+                .map(str::trim_end)
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        assert_eq!(
+            plain(call().into_element()),
+            "▸ search(\"q\": \"rnk\") ✓ done 1.5s"
+        );
+
+        let mut expanded = false;
+        let enter = crate::hooks::Key {
+            return_key: true,
+            ..Default::default()
+        };
+        assert!(handle_tool_call_input(&mut expanded, "", &enter));
+        assert_eq!(
+            plain(call().expanded(expanded).into_element()),
+            "▾ search() ✓ done 1.5s\n    {\n      \"q\": \"rnk\"\n    }"
+        );
+    }
 }
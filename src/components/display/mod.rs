@@ -2,6 +2,7 @@ mod accordion;
 mod avatar;
 mod badge;
 mod barchart;
+mod big_text;
 mod breadcrumb;
 mod calendar;
 pub(crate) mod capsule_variant;
@@ -35,7 +36,8 @@ pub use accordion::{Accordion, AccordionItem};
 pub use avatar::{Avatar, AvatarSize};
 pub use badge::{Badge, BadgeVariant};
 pub use barchart::{Bar, BarChart, BarChartOrientation};
-pub use breadcrumb::{Breadcrumb, breadcrumb_from_path};
+pub use big_text::{BigFont, BigText};
+pub use breadcrumb::{AbbreviateMode, Breadcrumb, breadcrumb_from_path};
 pub use calendar::Calendar;
 pub use capsule_variant::CapsuleVariant;
 pub use card::Card;
@@ -45,20 +47,23 @@ pub use empty_state::EmptyState;
 pub use gradient::Gradient;
 pub use highlight::{Highlight, HighlightVariant};
 pub use hyperlink::{Hyperlink, HyperlinkBuilder, set_hyperlinks_supported, supports_hyperlinks};
-pub use key_hint::KeyHint;
+pub use key_hint::{KeyHint, KeyHints};
 pub use line_chart::{LineChart, Series};
-pub use link::Link;
+pub use link::{Link, handle_link_input, open_url};
 pub use list::{List, ListItem, ListState};
 pub use markdown::Markdown;
-pub use message::{Message, MessageRole, ThinkingBlock, ToolCall};
+pub use message::{
+    Message, MessageRole, MessageState, ThinkingBlock, ToolCall, ToolCallStatus,
+    handle_tool_call_input,
+};
 pub use newline::Newline;
 pub use progress::{Gauge, Progress, ProgressSymbols};
 pub use quote::{Quote, QuoteStyle};
 pub use rating::{Rating, RatingStyle, RatingSymbols};
 pub use skeleton::{Skeleton, SkeletonVariant};
 pub use sparkline::Sparkline;
-pub use stat::{Stat, Trend};
-pub use static_output::Static;
+pub use stat::{Stat, StatFormat, Trend};
+pub use static_output::{Static, StaticState, use_static_state};
 pub use tag::Tag;
 pub use text::{Line, Span, Text};
 pub use timer::{
@@ -15,6 +15,8 @@ impl Newline {
     }
 
     /// Create multiple newlines
+    ///
+    /// The element renders `count` blank rows.
     pub fn count(mut self, count: u16) -> Self {
         self.count = count;
         self
@@ -28,6 +30,7 @@ impl Newline {
         element.style.flex_basis = crate::core::Dimension::Points(0.0);
         element.style.width = crate::core::Dimension::Percent(100.0);
         element.style.height = crate::core::Dimension::Points(self.count as f32);
+        element.style.flex_shrink = 0.0;
         element
     }
 }
@@ -47,4 +50,21 @@ mod tests {
         let element = Newline::new().count(3).into_element();
         assert_eq!(element.text_content, Some("\n\n\n".to_string()));
     }
+
+    #[test]
+    fn test_newline_count_does_not_shrink() {
+        use crate::components::{Box, Text};
+        use crate::core::FlexDirection;
+
+        let element = Box::new()
+            .flex_direction(FlexDirection::Column)
+            .height(3)
+            .child(Text::new("a").into_element())
+            .child(Newline::new().count(2).into_element())
+            .child(Text::new("b").into_element())
+            .into_element();
+        let output = crate::testing::TestRenderer::new(4, 4).render_to_plain(&element);
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["a", "", "", "b"]);
+    }
 }
@@ -2,11 +2,12 @@
 //!
 //! Provides progress bars and gauges for showing completion status.
 
-use crate::components::{Box as RnkBox, Line, Span, Text};
+use crate::components::{Box as RnkBox, Line, Span, Text, get_theme};
 use crate::core::{Color, Element};
 
 /// Progress bar style
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct ProgressSymbols {
     /// Character for filled portion
     pub filled: char,
@@ -18,6 +19,10 @@ pub struct ProgressSymbols {
     pub bracket_left: Option<char>,
     /// Right bracket
     pub bracket_right: Option<char>,
+    /// Glyphs for a partially filled last cell, from least to most filled
+    ///
+    /// Empty means the fill is rounded to whole cells.
+    pub partials: Vec<char>,
 }
 
 impl Default for ProgressSymbols {
@@ -28,6 +33,7 @@ impl Default for ProgressSymbols {
             head: None,
             bracket_left: Some('['),
             bracket_right: Some(']'),
+            partials: Vec::new(),
         }
     }
 }
@@ -46,6 +52,7 @@ impl ProgressSymbols {
             head: Some('╸'),
             bracket_left: None,
             bracket_right: None,
+            partials: Vec::new(),
         }
     }
 
@@ -57,6 +64,7 @@ impl ProgressSymbols {
             head: None,
             bracket_left: Some('⟨'),
             bracket_right: Some('⟩'),
+            partials: Vec::new(),
         }
     }
 
@@ -68,6 +76,7 @@ impl ProgressSymbols {
             head: Some('>'),
             bracket_left: Some('['),
             bracket_right: Some(']'),
+            partials: Vec::new(),
         }
     }
 
@@ -79,8 +88,35 @@ impl ProgressSymbols {
             head: Some('●'),
             bracket_left: None,
             bracket_right: None,
+            partials: Vec::new(),
         }
     }
+
+    /// Smooth style with eighth-cell precision
+    ///
+    /// The last filled cell uses a partial block (`▏▎▍▌▋▊▉`), so small
+    /// changes in progress stay visible.
+    pub fn smooth() -> Self {
+        Self {
+            filled: '█',
+            empty: ' ',
+            head: None,
+            bracket_left: Some('▕'),
+            bracket_right: Some('▏'),
+            partials: vec!['▏', '▎', '▍', '▌', '▋', '▊', '▉'],
+        }
+    }
+
+    /// Check whether every glyph is plain ASCII
+    pub fn is_ascii(&self) -> bool {
+        [self.filled, self.empty]
+            .into_iter()
+            .chain(self.head)
+            .chain(self.bracket_left)
+            .chain(self.bracket_right)
+            .chain(self.partials.iter().copied())
+            .all(|c| c.is_ascii())
+    }
 }
 
 /// Progress bar component
@@ -100,6 +136,10 @@ pub struct Progress {
     show_percent: bool,
     /// Custom label
     label: Option<String>,
+    /// Animation tick for the indeterminate mode
+    indeterminate: Option<u64>,
+    /// Replace non-ASCII symbols with the ASCII style
+    ascii_only: bool,
     /// Key for reconciliation
     key: Option<String>,
 }
@@ -115,10 +155,49 @@ impl Progress {
             empty_color: None,
             show_percent: false,
             label: None,
+            indeterminate: None,
+            ascii_only: false,
             key: None,
         }
     }
 
+    /// Show an indeterminate bar for work of unknown length
+    ///
+    /// Instead of a fill, a highlight block sweeps back and forth across the
+    /// bar; `tick` picks its position, so advance it from a timer or
+    /// animation frame counter. No percentage is shown in this mode. With
+    /// [reduced motion](crate::animation::prefers_reduced_motion) on, the
+    /// block stays centered whatever the tick.
+    pub fn indeterminate(mut self, tick: u64) -> Self {
+        self.indeterminate = Some(tick);
+        self
+    }
+
+    /// Highlight block of an indeterminate bar as `(start, len)` in cells
+    ///
+    /// The block is a quarter of the bar wide (at least one cell) and bounces
+    /// between the ends, moving one cell per tick.
+    pub fn indeterminate_block(bar_width: usize, tick: u64) -> (usize, usize) {
+        let len = (bar_width / 4).max(1).min(bar_width);
+        let travel = (bar_width - len) as u64;
+        if travel == 0 {
+            return (0, len);
+        }
+        let phase = tick % (travel * 2);
+        let start = if phase > travel {
+            travel * 2 - phase
+        } else {
+            phase
+        };
+        (start as usize, len)
+    }
+
+    /// Static highlight block for reduced motion, centered in the bar
+    fn centered_block(bar_width: usize) -> (usize, usize) {
+        let (_, len) = Self::indeterminate_block(bar_width, 0);
+        ((bar_width - len) / 2, len)
+    }
+
     /// Set progress (0.0 to 1.0)
     pub fn progress(mut self, progress: f32) -> Self {
         self.progress = progress.clamp(0.0, 1.0);
@@ -147,6 +226,15 @@ impl Progress {
         self
     }
 
+    /// Restrict output to ASCII for limited terminals
+    ///
+    /// Symbols containing non-ASCII glyphs fall back to
+    /// [`ProgressSymbols::ascii`] (`#`/`-`).
+    pub fn ascii_only(mut self, ascii_only: bool) -> Self {
+        self.ascii_only = ascii_only;
+        self
+    }
+
     /// Set filled color
     pub fn filled_color(mut self, color: Color) -> Self {
         self.filled_color = Some(color);
@@ -178,7 +266,10 @@ impl Progress {
     }
 
     /// Convert to element
-    pub fn into_element(self) -> Element {
+    pub fn into_element(mut self) -> Element {
+        if self.ascii_only && !self.symbols.is_ascii() {
+            self.symbols = ProgressSymbols::ascii();
+        }
         let mut spans = Vec::new();
 
         // Left bracket
@@ -191,6 +282,113 @@ impl Progress {
             + self.symbols.bracket_right.is_some() as u16;
         let bar_width = self.width.saturating_sub(bracket_width) as usize;
 
+        if let Some(tick) = self.indeterminate {
+            self.push_indeterminate(&mut spans, bar_width, tick);
+        } else {
+            self.push_determinate(&mut spans, bar_width);
+        }
+
+        // Right bracket
+        if let Some(bracket) = self.symbols.bracket_right {
+            spans.push(Span::new(bracket.to_string()));
+        }
+
+        // Percentage or label
+        if self.show_percent && self.indeterminate.is_none() {
+            let percent = format!(" {:3.0}%", self.progress * 100.0);
+            spans.push(Span::new(percent));
+        }
+
+        if let Some(label) = self.label {
+            spans.push(Span::new(format!(" {}", label)));
+        }
+
+        let text = Text::line(Line::from_spans(spans));
+        let mut container = RnkBox::new().child(text.into_element());
+
+        if let Some(key) = self.key {
+            container = container.key(key);
+        }
+
+        container.into_element()
+    }
+
+    fn empty_span(&self, width: usize) -> Span {
+        let empty_str: String = std::iter::repeat_n(self.symbols.empty, width).collect();
+        let span = Span::new(empty_str);
+        match self.empty_color {
+            Some(color) => span.color(color),
+            None => span.dim(),
+        }
+    }
+
+    fn push_indeterminate(&self, spans: &mut Vec<Span>, bar_width: usize, tick: u64) {
+        if bar_width == 0 {
+            return;
+        }
+        let (start, len) = if crate::animation::prefers_reduced_motion() {
+            Self::centered_block(bar_width)
+        } else {
+            Self::indeterminate_block(bar_width, tick)
+        };
+        let color = self.filled_color.unwrap_or_else(|| get_theme().primary);
+
+        if start > 0 {
+            spans.push(self.empty_span(start));
+        }
+        let block: String = std::iter::repeat_n(self.symbols.filled, len).collect();
+        spans.push(Span::new(block).color(color));
+        let rest = bar_width - start - len;
+        if rest > 0 {
+            spans.push(self.empty_span(rest));
+        }
+    }
+
+    /// Whole cells and partial glyph index for a smooth fill
+    ///
+    /// `steps` is the number of partial glyphs; the fraction of the last cell
+    /// is rounded to the nearest of `steps + 1` levels.
+    fn smooth_fill(progress: f32, bar_width: usize, steps: usize) -> (usize, Option<usize>) {
+        let cells = progress as f64 * bar_width as f64;
+        let mut full = cells.floor() as usize;
+        let mut level = ((cells - full as f64) * (steps + 1) as f64).round() as usize;
+        if level > steps {
+            full += 1;
+            level = 0;
+        }
+        let full = full.min(bar_width);
+        let partial = (level > 0 && full < bar_width).then(|| level - 1);
+        (full, partial)
+    }
+
+    fn push_smooth(&self, spans: &mut Vec<Span>, bar_width: usize) {
+        let partials = &self.symbols.partials;
+        let (full, partial) = Self::smooth_fill(self.progress, bar_width, partials.len());
+
+        let mut filled: String = std::iter::repeat_n(self.symbols.filled, full).collect();
+        if let Some(index) = partial {
+            filled.push(partials[index]);
+        }
+        if !filled.is_empty() {
+            let mut filled_span = Span::new(filled);
+            if let Some(color) = self.filled_color {
+                filled_span = filled_span.color(color);
+            }
+            spans.push(filled_span);
+        }
+
+        let empty_width = bar_width - full - partial.is_some() as usize;
+        if empty_width > 0 {
+            spans.push(self.empty_span(empty_width));
+        }
+    }
+
+    fn push_determinate(&self, spans: &mut Vec<Span>, bar_width: usize) {
+        if !self.symbols.partials.is_empty() {
+            self.push_smooth(spans, bar_width);
+            return;
+        }
+
         let filled_width = (self.progress * bar_width as f32).round() as usize;
         let empty_width = bar_width.saturating_sub(filled_width);
 
@@ -224,39 +422,8 @@ impl Progress {
 
         // Empty portion
         if empty_width > 0 {
-            let empty_str: String = std::iter::repeat_n(self.symbols.empty, empty_width).collect();
-            let mut empty_span = Span::new(empty_str);
-            if let Some(color) = self.empty_color {
-                empty_span = empty_span.color(color);
-            } else {
-                empty_span = empty_span.dim();
-            }
-            spans.push(empty_span);
-        }
-
-        // Right bracket
-        if let Some(bracket) = self.symbols.bracket_right {
-            spans.push(Span::new(bracket.to_string()));
-        }
-
-        // Percentage or label
-        if self.show_percent {
-            let percent = format!(" {:3.0}%", self.progress * 100.0);
-            spans.push(Span::new(percent));
-        }
-
-        if let Some(label) = self.label {
-            spans.push(Span::new(format!(" {}", label)));
+            spans.push(self.empty_span(empty_width));
         }
-
-        let text = Text::line(Line::from_spans(spans));
-        let mut container = RnkBox::new().child(text.into_element());
-
-        if let Some(key) = self.key {
-            container = container.key(key);
-        }
-
-        container.into_element()
     }
 }
 
@@ -275,17 +442,29 @@ pub struct Gauge {
     label: Option<String>,
     /// Color
     color: Option<Color>,
+    /// Color bands as `(start, color)` pairs, sorted by start
+    thresholds: Vec<(f32, Color)>,
+    /// Target position (0.0 to 1.0)
+    target: Option<f32>,
+    /// Glyph drawn at the target position
+    target_symbol: char,
     /// Key for reconciliation
     key: Option<String>,
 }
 
 impl Gauge {
+    /// Bar width in cells
+    const WIDTH: usize = 10;
+
     /// Create a new gauge
     pub fn new() -> Self {
         Self {
             progress: 0.0,
             label: None,
             color: None,
+            thresholds: Vec::new(),
+            target: None,
+            target_symbol: '│',
             key: None,
         }
     }
@@ -308,35 +487,109 @@ impl Gauge {
         self
     }
 
+    /// Color the filled portion by threshold bands
+    ///
+    /// Each `(start, color)` pair colors the part of the bar from `start`
+    /// (0.0 to 1.0) up to the next band. Cells below the first band use
+    /// [`Gauge::color`]. An empty list keeps the single-color bar.
+    ///
+    /// ```
+    /// use rnk::components::Gauge;
+    /// use rnk::core::Color;
+    ///
+    /// let gauge = Gauge::new().progress(0.95).thresholds(vec![
+    ///     (0.0, Color::Green),
+    ///     (0.7, Color::Yellow),
+    ///     (0.9, Color::Red),
+    /// ]);
+    /// ```
+    pub fn thresholds(mut self, thresholds: Vec<(f32, Color)>) -> Self {
+        let mut thresholds: Vec<(f32, Color)> = thresholds
+            .into_iter()
+            .filter(|(start, _)| !start.is_nan())
+            .map(|(start, color)| (start.clamp(0.0, 1.0), color))
+            .collect();
+        thresholds.sort_by(|a, b| a.0.total_cmp(&b.0));
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// Mark a target position (0.0 to 1.0) on the bar
+    pub fn target(mut self, target: f32) -> Self {
+        self.target = Some(target.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Set the glyph drawn at the target position
+    pub fn target_symbol(mut self, symbol: char) -> Self {
+        self.target_symbol = symbol;
+        self
+    }
+
     /// Set key
     pub fn key(mut self, key: impl Into<String>) -> Self {
         self.key = Some(key.into());
         self
     }
 
+    /// Color for a position on the bar, taking threshold bands into account
+    fn color_at(&self, position: f32) -> Option<Color> {
+        self.thresholds
+            .iter()
+            .rev()
+            .find(|(start, _)| *start <= position)
+            .map(|(_, color)| *color)
+            .or(self.color)
+    }
+
     /// Convert to element
     pub fn into_element(self) -> Element {
         // Simple gauge representation: [████░░░░] 75%
-        let width = 10usize;
+        let width = Self::WIDTH;
         let filled = (self.progress * width as f32).round() as usize;
-        let empty = width - filled;
-
-        let mut spans = Vec::new();
-
-        let filled_str: String = std::iter::repeat_n('█', filled).collect();
-        let empty_str: String = std::iter::repeat_n('░', empty).collect();
-
-        let mut filled_span = Span::new(filled_str);
-        if let Some(color) = self.color {
-            filled_span = filled_span.color(color);
+        let target_cell = self
+            .target
+            .map(|target| ((target * width as f32).round() as usize).min(width - 1));
+
+        // (glyph, color, dim) per cell, merged into spans below
+        let cells: Vec<(char, Option<Color>, bool)> = (0..width)
+            .map(|i| {
+                let color = self.color_at(i as f32 / width as f32);
+                if target_cell == Some(i) {
+                    (self.target_symbol, color.filter(|_| i < filled), false)
+                } else if i < filled {
+                    ('█', color, false)
+                } else {
+                    ('░', None, true)
+                }
+            })
+            .collect();
+
+        let mut spans: Vec<Span> = Vec::new();
+        let mut run = String::new();
+        for (i, &(ch, color, dim)) in cells.iter().enumerate() {
+            run.push(ch);
+            if cells.get(i + 1).map(|next| (next.1, next.2)) != Some((color, dim)) {
+                let mut span = Span::new(std::mem::take(&mut run));
+                if let Some(color) = color {
+                    span = span.color(color);
+                }
+                if dim {
+                    span = span.dim();
+                }
+                spans.push(span);
+            }
         }
-        spans.push(filled_span);
-        spans.push(Span::new(empty_str).dim());
 
         // Percentage
         let percent = format!(" {:3.0}%", self.progress * 100.0);
         let mut percent_span = Span::new(percent);
-        if let Some(color) = self.color {
+        let percent_color = if self.thresholds.is_empty() {
+            self.color
+        } else {
+            self.color_at(self.progress)
+        };
+        if let Some(color) = percent_color {
             percent_span = percent_span.color(color);
         }
         spans.push(percent_span.bold());
@@ -398,4 +651,152 @@ mod tests {
         assert!((gauge.progress - 0.75).abs() < 0.01);
         assert_eq!(gauge.label, Some("CPU".to_string()));
     }
+
+    fn gauge_spans(gauge: Gauge) -> Vec<Span> {
+        let element = gauge.into_element();
+        let text = element.children.get(0).unwrap();
+        text.spans.as_ref().unwrap()[0].spans.clone()
+    }
+
+    #[test]
+    fn test_gauge_without_thresholds_is_single_color() {
+        let spans = gauge_spans(Gauge::new().progress(0.5).color(Color::Cyan));
+
+        assert_eq!(spans[0].content, "█████");
+        assert_eq!(spans[0].style.color, Some(Color::Cyan));
+        assert_eq!(spans[1].content, "░░░░░");
+        assert!(spans[1].style.dim);
+    }
+
+    #[test]
+    fn test_gauge_thresholds_color_bands() {
+        let spans = gauge_spans(Gauge::new().progress(1.0).thresholds(vec![
+            (0.9, Color::Red),
+            (0.0, Color::Green),
+            (0.7, Color::Yellow),
+        ]));
+
+        assert_eq!(spans[0].content, "███████");
+        assert_eq!(spans[0].style.color, Some(Color::Green));
+        assert_eq!(spans[1].content, "██");
+        assert_eq!(spans[1].style.color, Some(Color::Yellow));
+        assert_eq!(spans[2].content, "█");
+        assert_eq!(spans[2].style.color, Some(Color::Red));
+        assert_eq!(spans[3].style.color, Some(Color::Red));
+    }
+
+    #[test]
+    fn test_gauge_target_marker() {
+        let spans = gauge_spans(Gauge::new().progress(0.3).target(0.8));
+        let bar: String = spans[..spans.len() - 1]
+            .iter()
+            .map(|span| span.content.as_str())
+            .collect();
+
+        assert_eq!(bar, "███░░░░░│░");
+    }
+
+    fn progress_bar(progress: Progress) -> String {
+        let element = progress.into_element();
+        let text = element.children.get(0).unwrap();
+        text.spans.as_ref().unwrap()[0]
+            .spans
+            .iter()
+            .map(|span| span.content.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_indeterminate_block_bounces() {
+        let starts: Vec<usize> = (0..10)
+            .map(|tick| Progress::indeterminate_block(8, tick).0)
+            .collect();
+
+        assert_eq!(Progress::indeterminate_block(8, 0).1, 2);
+        assert_eq!(starts, vec![0, 1, 2, 3, 4, 5, 6, 5, 4, 3]);
+        assert_eq!(Progress::indeterminate_block(3, 5), (1, 1));
+        assert_eq!(Progress::indeterminate_block(1, 7), (0, 1));
+        assert_eq!(Progress::indeterminate_block(0, 7), (0, 0));
+    }
+
+    #[test]
+    fn test_indeterminate_progress_renders_sweeping_block() {
+        let bar = |tick| {
+            progress_bar(
+                Progress::new()
+                    .width(10)
+                    .show_percent(true)
+                    .indeterminate(tick),
+            )
+        };
+
+        assert_eq!(bar(0), "[██░░░░░░]");
+        assert_eq!(bar(3), "[░░░██░░░]");
+        assert_eq!(bar(6), "[░░░░░░██]");
+        assert_eq!(bar(9), "[░░░██░░░]");
+    }
+
+    #[test]
+    fn test_indeterminate_progress_holds_centered_block_with_reduced_motion() {
+        let bar = |tick| {
+            crate::animation::with_reduced_motion(true, || {
+                progress_bar(Progress::new().width(10).indeterminate(tick))
+            })
+        };
+
+        assert_eq!(bar(0), "[░░░██░░░]");
+        assert_eq!(bar(6), "[░░░██░░░]");
+    }
+
+    #[test]
+    fn test_indeterminate_uses_theme_primary_by_default() {
+        let element = Progress::new().width(6).indeterminate(1).into_element();
+        let text = element.children.get(0).unwrap();
+        let spans = &text.spans.as_ref().unwrap()[0].spans;
+        let block = spans.iter().find(|span| span.content == "█").unwrap();
+
+        assert_eq!(block.style.color, Some(get_theme().primary));
+        assert!(spans[1].style.dim);
+    }
+
+    #[test]
+    fn test_smooth_fill_uses_partial_last_cell() {
+        assert_eq!(Progress::smooth_fill(0.53, 10, 7), (5, Some(1)));
+        assert_eq!(Progress::smooth_fill(0.0, 10, 7), (0, None));
+        assert_eq!(Progress::smooth_fill(1.0, 10, 7), (10, None));
+        // Close to the next cell rounds up to a whole cell
+        assert_eq!(Progress::smooth_fill(0.299, 10, 7), (3, None));
+        assert_eq!(Progress::smooth_fill(0.05, 10, 7), (0, Some(3)));
+    }
+
+    #[test]
+    fn test_smooth_progress_renders_eighth_blocks() {
+        let bar = |value| {
+            progress_bar(
+                Progress::new()
+                    .width(10)
+                    .symbols(ProgressSymbols::smooth())
+                    .progress(value),
+            )
+        };
+
+        assert_eq!(bar(0.55), "▕████▍   ▏");
+        assert_eq!(bar(0.5), "▕████    ▏");
+        assert_eq!(bar(1.0), "▕████████▏");
+    }
+
+    #[test]
+    fn test_ascii_only_degrades_symbols() {
+        assert!(ProgressSymbols::ascii().is_ascii());
+        assert!(!ProgressSymbols::smooth().is_ascii());
+
+        let bar = progress_bar(
+            Progress::new()
+                .width(10)
+                .symbols(ProgressSymbols::smooth())
+                .ascii_only(true)
+                .progress(0.5),
+        );
+        assert_eq!(bar, "[###>----]");
+    }
 }
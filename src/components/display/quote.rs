@@ -11,13 +11,31 @@
 //! fn app() -> Element {
 //!     Quote::new("The only way to do great work is to love what you do.")
 //!         .author("Steve Jobs")
+//!         .width(40)
 //!         .into_element()
 //! }
 //! ```
+//!
+//! Block quotes wrap to the configured width and repeat the accent bar on
+//! every visual line. Nested quotes stack one bar per level, and take the
+//! bar glyph, colors and width of their parent unless they set their own:
+//!
+//! ```rust,ignore
+//! Quote::new("Outer remark")
+//!     .nested(Quote::new("Inner reply").author("Bob"))
+//!     .width(30)
+//!     .into_element();
+//! ```
 
 use crate::components::{Box as RnkBox, Text};
 use crate::core::{Color, Element, FlexDirection};
 
+/// Default bar glyph of the block style
+const DEFAULT_BAR: char = '▌';
+
+/// Default bar colors, one per nesting level (cycled when nesting deeper)
+const DEFAULT_LEVEL_COLORS: [Color; 4] = [Color::Cyan, Color::Magenta, Color::Yellow, Color::Green];
+
 /// A quote component
 #[derive(Debug, Clone)]
 pub struct Quote {
@@ -25,6 +43,10 @@ pub struct Quote {
     author: Option<String>,
     source: Option<String>,
     style: QuoteStyle,
+    bar: Option<char>,
+    level_colors: Option<Vec<Color>>,
+    width: Option<u16>,
+    nested: Vec<Quote>,
 }
 
 /// Quote display style
@@ -44,6 +66,10 @@ impl Quote {
             author: None,
             source: None,
             style: QuoteStyle::Block,
+            bar: None,
+            level_colors: None,
+            width: None,
+            nested: Vec::new(),
         }
     }
 
@@ -65,6 +91,32 @@ impl Quote {
         self
     }
 
+    /// Set the left bar glyph used by the block style
+    pub fn bar(mut self, bar: char) -> Self {
+        self.bar = Some(bar);
+        self
+    }
+
+    /// Set bar colors per nesting level (cycled for deeper levels)
+    pub fn level_colors(mut self, colors: Vec<Color>) -> Self {
+        if !colors.is_empty() {
+            self.level_colors = Some(colors);
+        }
+        self
+    }
+
+    /// Set the available width, including the bars, for wrapping
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Add a quote nested inside this one (rendered below the text)
+    pub fn nested(mut self, quote: Quote) -> Self {
+        self.nested.push(quote);
+        self
+    }
+
     /// Convert to Element
     pub fn into_element(self) -> Element {
         match self.style {
@@ -75,44 +127,109 @@ impl Quote {
     }
 
     fn render_block(self) -> Element {
-        let mut children = Vec::new();
+        let mut rows = Vec::new();
+        self.push_block_rows(0, &[self.level_bar(0)], &mut rows);
 
-        // Quote text with bar
-        children.push(
-            RnkBox::new()
-                .flex_direction(FlexDirection::Row)
-                .children(vec![
-                    Text::new("│ ").color(Color::Cyan).into_element(),
-                    Text::new(&self.text)
-                        .color(Color::White)
-                        .italic()
-                        .into_element(),
-                ])
-                .into_element(),
-        );
+        RnkBox::new()
+            .flex_direction(FlexDirection::Column)
+            .padding_y(0.5)
+            .children(rows)
+            .into_element()
+    }
 
-        // Attribution
-        if self.author.is_some() || self.source.is_some() {
-            let mut attr = String::from("  — ");
-            if let Some(author) = &self.author {
-                attr.push_str(author);
-            }
-            if let Some(source) = &self.source {
-                if self.author.is_some() {
-                    attr.push_str(", ");
-                }
-                attr.push_str(source);
+    /// This quote's bar glyph and color at nesting level `depth`
+    fn level_bar(&self, depth: usize) -> (char, Color) {
+        let colors = self
+            .level_colors
+            .as_deref()
+            .unwrap_or(&DEFAULT_LEVEL_COLORS);
+        (
+            self.bar.unwrap_or(DEFAULT_BAR),
+            colors[depth % colors.len()],
+        )
+    }
+
+    /// Append one row per visual line, prefixed by one bar per nesting level
+    ///
+    /// `bars` holds the glyph and color of every enclosing level plus this
+    /// one, so outer levels keep their own look next to a restyled child.
+    fn push_block_rows(&self, depth: usize, bars: &[(char, Color)], rows: &mut Vec<Element>) {
+        // Each level occupies the bar glyph plus a separating space
+        let prefix_width = bars.len() * 2;
+        let text_width = self
+            .width
+            .map(|w| (w as usize).saturating_sub(prefix_width).max(1));
+
+        for line in wrap_lines(&self.text, text_width) {
+            rows.push(self.bar_row(
+                bars,
+                Text::new(line).color(Color::White).italic().into_element(),
+            ));
+        }
+
+        for child in &self.nested {
+            // Inherit only what the child leaves unset
+            let child = Quote {
+                bar: child.bar.or(self.bar),
+                level_colors: child
+                    .level_colors
+                    .clone()
+                    .or_else(|| self.level_colors.clone()),
+                width: child.width.or(self.width),
+                ..child.clone()
+            };
+            let mut child_bars = bars.to_vec();
+            child_bars.push(child.level_bar(depth + 1));
+            child.push_block_rows(depth + 1, &child_bars, rows);
+        }
+
+        if let Some(attr) = self.attribution() {
+            for line in wrap_lines(&attr, text_width) {
+                rows.push(
+                    self.bar_row(
+                        bars,
+                        Text::new(line)
+                            .color(Color::BrightBlack)
+                            .dim()
+                            .into_element(),
+                    ),
+                );
             }
-            children.push(Text::new(attr).color(Color::BrightBlack).into_element());
         }
+    }
+
+    fn bar_row(&self, bars: &[(char, Color)], content: Element) -> Element {
+        let mut children: Vec<Element> = bars
+            .iter()
+            .map(|(bar, color)| Text::new(format!("{} ", bar)).color(*color).into_element())
+            .collect();
+        children.push(content);
 
         RnkBox::new()
-            .flex_direction(FlexDirection::Column)
-            .padding_y(0.5)
+            .flex_direction(FlexDirection::Row)
             .children(children)
             .into_element()
     }
 
+    /// Attribution line (`— Author, Source`) if either is set
+    fn attribution(&self) -> Option<String> {
+        if self.author.is_none() && self.source.is_none() {
+            return None;
+        }
+
+        let mut attr = String::from("— ");
+        if let Some(author) = &self.author {
+            attr.push_str(author);
+        }
+        if let Some(source) = &self.source {
+            if self.author.is_some() {
+                attr.push_str(", ");
+            }
+            attr.push_str(source);
+        }
+        Some(attr)
+    }
+
     fn render_inline(self) -> Element {
         let mut content = format!("\"{}\"", self.text);
         if let Some(author) = &self.author {
@@ -164,6 +281,17 @@ impl Quote {
     }
 }
 
+/// Word-wrap text to `width` columns, keeping explicit line breaks
+fn wrap_lines(text: &str, width: Option<usize>) -> Vec<String> {
+    match width {
+        Some(width) => textwrap::wrap(text, width)
+            .into_iter()
+            .map(|line| line.into_owned())
+            .collect(),
+        None => text.lines().map(str::to_string).collect(),
+    }
+}
+
 impl Default for Quote {
     fn default() -> Self {
         Self::new("")
@@ -173,6 +301,7 @@ impl Default for Quote {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::TestRenderer;
 
     #[test]
     fn test_quote_creation() {
@@ -186,6 +315,73 @@ mod tests {
         assert_eq!(q.author, Some("Author".to_string()));
     }
 
+    #[test]
+    fn test_wrap_lines() {
+        assert_eq!(
+            wrap_lines("one two three four", Some(9)),
+            vec!["one two", "three", "four"]
+        );
+        assert_eq!(wrap_lines("a\nb", None), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_block_bar_on_every_wrapped_line() {
+        let plain = TestRenderer::new(40, 10).render_to_plain(
+            &Quote::new("the quick brown fox jumps over the lazy dog")
+                .width(14)
+                .into_element(),
+        );
+        let lines: Vec<&str> = plain.lines().filter(|l| !l.trim().is_empty()).collect();
+        assert!(lines.len() > 2);
+        assert!(lines.iter().all(|line| line.starts_with("▌ ")));
+        assert!(
+            lines
+                .iter()
+                .all(|line| line.trim_end().chars().count() <= 14)
+        );
+    }
+
+    #[test]
+    fn test_nested_quotes_stack_bars() {
+        let plain = TestRenderer::new(40, 10).render_to_plain(
+            &Quote::new("outer")
+                .nested(Quote::new("inner").author("Bob"))
+                .bar('|')
+                .into_element(),
+        );
+        assert!(plain.contains("| outer"));
+        assert!(plain.contains("| | inner"));
+        assert!(plain.contains("| | — Bob"));
+    }
+
+    #[test]
+    fn test_nested_quote_keeps_its_own_style() {
+        let plain = TestRenderer::new(40, 10).render_to_plain(
+            &Quote::new("outer")
+                .bar('|')
+                .width(30)
+                .nested(Quote::new("one two three four").bar('>').width(12))
+                .into_element(),
+        );
+        assert!(plain.contains("| outer"));
+        // The child's own bar and width, below the parent's bar
+        assert!(plain.contains("| > one two"));
+        assert!(plain.contains("| > three"));
+
+        let element = Quote::new("outer")
+            .level_colors(vec![Color::Red])
+            .nested(Quote::new("inner").level_colors(vec![Color::Blue, Color::Green]))
+            .into_element();
+        let inner_row = element.children.get(1).unwrap();
+        let bar_colors: Vec<Option<Color>> = inner_row
+            .children
+            .iter()
+            .take(2)
+            .map(|bar| bar.style.color)
+            .collect();
+        assert_eq!(bar_colors, [Some(Color::Red), Some(Color::Green)]);
+    }
+
     #[test]
     fn test_quote_styles() {
         let _ = Quote::new("Test").style(QuoteStyle::Block).into_element();
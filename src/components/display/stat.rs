@@ -16,6 +16,10 @@
 //!             Stat::new("Users", "1,234").trend_up("12%").into_element(),
 //!             Stat::new("Revenue", "$5,678").trend_down("3%").into_element(),
 //!             Stat::new("Orders", "89").into_element(),
+//!             Stat::number("Visits", 1_234_567.0)
+//!                 .format(StatFormat::compact())
+//!                 .trend(0.042, false)
+//!                 .into_element(),
 //!         ])
 //!         .into_element()
 //! }
@@ -32,12 +36,123 @@ pub enum Trend {
     Neutral,
 }
 
+/// How a numeric stat value is displayed
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum StatFormat {
+    /// Show the value as given
+    #[default]
+    Plain,
+    /// Abbreviate large numbers: `1.2K`, `3.4M`, `5.6B`, `7.8T`
+    Compact { decimals: usize },
+    /// Thousands separators: `1,234.5`
+    Number { decimals: usize },
+    /// Currency symbol plus thousands separators: `$1,234.50`
+    Currency { symbol: String, decimals: usize },
+    /// A fraction shown as a percentage: `0.123` → `12.3%`
+    Percent { decimals: usize },
+}
+
+impl StatFormat {
+    /// Abbreviated numbers with one decimal
+    pub fn compact() -> Self {
+        Self::Compact { decimals: 1 }
+    }
+
+    /// Grouped numbers with a fixed number of decimals
+    pub fn number(decimals: usize) -> Self {
+        Self::Number { decimals }
+    }
+
+    /// Currency with two decimals
+    pub fn currency(symbol: impl Into<String>) -> Self {
+        Self::Currency {
+            symbol: symbol.into(),
+            decimals: 2,
+        }
+    }
+
+    /// Percentage with a fixed number of decimals
+    pub fn percent(decimals: usize) -> Self {
+        Self::Percent { decimals }
+    }
+
+    /// Format a number
+    pub fn apply(&self, value: f64) -> String {
+        let sign = if value < 0.0 { "-" } else { "" };
+        let abs = value.abs();
+        match self {
+            Self::Plain => trim_zeros(format!("{:.2}", value)),
+            Self::Compact { decimals } => {
+                const UNITS: [(f64, &str); 5] =
+                    [(1.0, ""), (1e3, "K"), (1e6, "M"), (1e9, "B"), (1e12, "T")];
+                // Pick the unit after rounding, so 999_950 is "1M", not "1000K"
+                let mut formatted = String::new();
+                for (scale, unit) in UNITS {
+                    let scaled = format!("{:.*}", decimals, abs / scale);
+                    formatted = format!("{}{}", trim_zeros(scaled.clone()), unit);
+                    if scaled.parse::<f64>().is_ok_and(|rounded| rounded < 1000.0) {
+                        break;
+                    }
+                }
+                format!("{}{}", sign, formatted)
+            }
+            Self::Number { decimals } => format!("{}{}", sign, group_thousands(abs, *decimals)),
+            Self::Currency { symbol, decimals } => {
+                format!("{}{}{}", sign, symbol, group_thousands(abs, *decimals))
+            }
+            Self::Percent { decimals } => format!("{}{:.*}%", sign, decimals, abs * 100.0),
+        }
+    }
+}
+
+/// Drop trailing zeros after the decimal point
+fn trim_zeros(text: String) -> String {
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        text
+    }
+}
+
+/// Format a non-negative number with comma thousands separators
+fn group_thousands(value: f64, decimals: usize) -> String {
+    let text = format!("{:.*}", decimals, value);
+    let (int_part, frac_part) = match text.split_once('.') {
+        Some((int_part, frac)) => (int_part, Some(frac)),
+        None => (text.as_str(), None),
+    };
+
+    let mut grouped = String::with_capacity(text.len() + int_part.len() / 3);
+    for (i, ch) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(ch);
+    }
+    if let Some(frac) = frac_part {
+        grouped.push('.');
+        grouped.push_str(frac);
+    }
+    grouped
+}
+
+/// Trend change, either preformatted text or a number formatted with the
+/// stat's [`StatFormat`]
+#[derive(Debug, Clone)]
+enum TrendDelta {
+    Text(String),
+    Number(f64),
+}
+
 /// A stat component for displaying metrics
 #[derive(Debug, Clone)]
 pub struct Stat {
     label: String,
     value: String,
-    trend: Option<(Trend, String)>,
+    number: Option<f64>,
+    format: StatFormat,
+    trend: Option<(Trend, TrendDelta)>,
+    invert_trend: bool,
     help_text: Option<String>,
 }
 
@@ -47,29 +162,81 @@ impl Stat {
         Self {
             label: label.into(),
             value: value.into(),
+            number: None,
+            format: StatFormat::Plain,
             trend: None,
+            invert_trend: false,
             help_text: None,
         }
     }
 
+    /// Create a stat with a numeric value, displayed through [`format`](Self::format)
+    pub fn number(label: impl Into<String>, value: f64) -> Self {
+        Self {
+            number: Some(value),
+            ..Self::new(label, value.to_string())
+        }
+    }
+
+    /// Set how the value and numeric trend are formatted
+    ///
+    /// String values that parse as numbers (commas allowed) are formatted
+    /// too; anything else is shown as given.
+    pub fn format(mut self, format: StatFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Add a numeric trend
+    ///
+    /// The direction comes from the sign of `delta` and the magnitude is
+    /// shown with the stat's format. Increases are green and decreases red;
+    /// pass `invert` for metrics where going down is good (latency, errors).
+    pub fn trend(mut self, delta: f64, invert: bool) -> Self {
+        let direction = if delta > 0.0 {
+            Trend::Up
+        } else if delta < 0.0 {
+            Trend::Down
+        } else {
+            Trend::Neutral
+        };
+        self.trend = Some((direction, TrendDelta::Number(delta.abs())));
+        self.invert_trend = invert;
+        self
+    }
+
     /// Add an upward trend
     pub fn trend_up(mut self, change: impl Into<String>) -> Self {
-        self.trend = Some((Trend::Up, change.into()));
+        self.trend = Some((Trend::Up, TrendDelta::Text(change.into())));
         self
     }
 
     /// Add a downward trend
     pub fn trend_down(mut self, change: impl Into<String>) -> Self {
-        self.trend = Some((Trend::Down, change.into()));
+        self.trend = Some((Trend::Down, TrendDelta::Text(change.into())));
         self
     }
 
     /// Add a neutral trend
     pub fn trend_neutral(mut self, change: impl Into<String>) -> Self {
-        self.trend = Some((Trend::Neutral, change.into()));
+        self.trend = Some((Trend::Neutral, TrendDelta::Text(change.into())));
         self
     }
 
+    /// The value as it will be displayed
+    pub fn display_value(&self) -> String {
+        if self.format == StatFormat::Plain {
+            return self.value.clone();
+        }
+        let number = self
+            .number
+            .or_else(|| self.value.trim().replace(',', "").parse().ok());
+        match number {
+            Some(number) => self.format.apply(number),
+            None => self.value.clone(),
+        }
+    }
+
     /// Add help text
     pub fn help(mut self, text: impl Into<String>) -> Self {
         self.help_text = Some(text.into());
@@ -89,18 +256,27 @@ impl Stat {
 
         // Value with optional trend
         let mut value_children = vec![
-            Text::new(&self.value)
+            Text::new(self.display_value())
                 .color(Color::White)
                 .bold()
                 .into_element(),
         ];
 
-        if let Some((trend, change)) = &self.trend {
+        if let Some((trend, delta)) = &self.trend {
+            let (good, bad) = if self.invert_trend {
+                (Color::Red, Color::Green)
+            } else {
+                (Color::Green, Color::Red)
+            };
             let (icon, color) = match trend {
-                Trend::Up => ("↑", Color::Green),
-                Trend::Down => ("↓", Color::Red),
+                Trend::Up => ("↑", good),
+                Trend::Down => ("↓", bad),
                 Trend::Neutral => ("→", Color::Yellow),
             };
+            let change = match delta {
+                TrendDelta::Text(text) => text.clone(),
+                TrendDelta::Number(number) => self.format.apply(*number),
+            };
             value_children.push(
                 Text::new(format!(" {} {}", icon, change))
                     .color(color)
@@ -161,4 +337,47 @@ mod tests {
         let _ = Stat::new("Test", "123").trend_up("5%").into_element();
         let _ = Stat::new("Test", "123").trend_down("3%").into_element();
     }
+
+    #[test]
+    fn test_stat_format_apply() {
+        assert_eq!(StatFormat::compact().apply(1_234.0), "1.2K");
+        assert_eq!(StatFormat::compact().apply(3_400_000.0), "3.4M");
+        assert_eq!(StatFormat::compact().apply(2_000_000_000.0), "2B");
+        assert_eq!(StatFormat::compact().apply(-950.0), "-950");
+        assert_eq!(StatFormat::compact().apply(999_950.0), "1M");
+        assert_eq!(StatFormat::compact().apply(999.96), "1K");
+        assert_eq!(StatFormat::compact().apply(2e15), "2000T");
+        assert_eq!(StatFormat::number(1).apply(1_234_567.25), "1,234,567.2");
+        assert_eq!(StatFormat::currency("$").apply(-1234.5), "-$1,234.50");
+        assert_eq!(StatFormat::percent(1).apply(0.123), "12.3%");
+        assert_eq!(StatFormat::Plain.apply(12.50), "12.5");
+    }
+
+    #[test]
+    fn test_stat_display_value_formats_numbers() {
+        let s = Stat::number("Visits", 1_234_567.0).format(StatFormat::compact());
+        assert_eq!(s.display_value(), "1.2M");
+
+        let s = Stat::new("Revenue", "5,678").format(StatFormat::currency("$"));
+        assert_eq!(s.display_value(), "$5,678.00");
+
+        let s = Stat::new("Status", "ok").format(StatFormat::compact());
+        assert_eq!(s.display_value(), "ok");
+    }
+
+    #[test]
+    fn test_stat_numeric_trend_colors() {
+        use crate::testing::TestRenderer;
+
+        let s = Stat::number("Latency", 120.0).trend(-15.0, true);
+        assert!(matches!(s.trend, Some((Trend::Down, _))));
+
+        let element = s.into_element();
+        let output = TestRenderer::new(30, 3).render_to_plain(&element);
+        assert!(output.contains("120 ↓ 15"));
+
+        let value_row = element.children.get(1).unwrap();
+        let trend = value_row.children.get(1).unwrap();
+        assert_eq!(trend.style.color, Some(Color::Green));
+    }
 }
@@ -1,7 +1,9 @@
 //! Static component - renders content once and persists it
 
-use crate::core::{Element, ElementType, Style};
-use crate::hooks::use_signal;
+use std::sync::{Arc, Mutex};
+
+use crate::core::{Element, ElementType, FlexDirection, Style};
+use crate::hooks::{use_effect, use_ref, use_signal};
 
 /// Static component that renders items only once.
 ///
@@ -84,6 +86,130 @@ where
     }
 }
 
+/// Append-only log of elements printed once above the live frame
+///
+/// Like Ink's `<Static>`: each pushed element is written to the terminal
+/// history on the next frame and never re-rendered, while the rest of the UI
+/// keeps redrawing below it. The state is cheap to clone and can be pushed to
+/// from input handlers or background threads.
+///
+/// # Example
+///
+/// ```ignore
+/// fn app() -> Element {
+///     let log = use_static_state();
+///     let progress = use_signal(|| 0.0);
+///
+///     use_input({
+///         let log = log.clone();
+///         move |_, key| {
+///             if key.return_key {
+///                 log.push(Text::new("✓ step finished"));
+///             }
+///         }
+///     });
+///
+///     Box::new()
+///         .flex_direction(FlexDirection::Column)
+///         .child(log.view())
+///         .child(Progress::new().progress(progress.get()).into_element())
+///         .into_element()
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct StaticState {
+    inner: Arc<Mutex<StaticQueue>>,
+}
+
+#[derive(Default)]
+struct StaticQueue {
+    /// Items not yet committed as printed
+    pending: Vec<Element>,
+    /// Number of pending items included in the last `view()`
+    viewed: usize,
+    /// Number of items already handed to the renderer
+    printed: usize,
+}
+
+impl StaticState {
+    /// Create an empty state
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an item; it is printed on the next frame and requests a render
+    pub fn push(&self, item: impl Into<Element>) {
+        if let Ok(mut queue) = self.inner.lock() {
+            queue.pending.push(item.into());
+        }
+        crate::renderer::request_render();
+    }
+
+    /// Number of items waiting to be printed
+    pub fn pending_len(&self) -> usize {
+        self.inner
+            .lock()
+            .map(|queue| queue.pending.len())
+            .unwrap_or(0)
+    }
+
+    /// Number of items already printed
+    pub fn printed_len(&self) -> usize {
+        self.inner.lock().map(|queue| queue.printed).unwrap_or(0)
+    }
+
+    /// Render the items that have not been printed yet
+    ///
+    /// The returned element is marked static, so the app prints it above the
+    /// live region. Rendering has no side effects: the items stay pending
+    /// until [`commit`](Self::commit) runs, which [`use_static_state`] does
+    /// after each render, so a render that is thrown away loses nothing.
+    pub fn view(&self) -> Element {
+        let items = match self.inner.lock() {
+            Ok(mut queue) => {
+                queue.viewed = queue.pending.len();
+                queue.pending.clone()
+            }
+            Err(_) => Vec::new(),
+        };
+
+        let mut element = Element::new(ElementType::Box);
+        element.style.flex_direction = FlexDirection::Column;
+        element.style.is_static = true;
+        for item in items {
+            element.add_child(item);
+        }
+        element
+    }
+
+    /// Mark the items shown by the last [`view`](Self::view) as printed
+    ///
+    /// Later views only contain items pushed after them.
+    pub fn commit(&self) {
+        if let Ok(mut queue) = self.inner.lock() {
+            let viewed = std::mem::take(&mut queue.viewed);
+            queue.pending.drain(..viewed);
+            queue.printed += viewed;
+        }
+    }
+}
+
+/// Hook returning a [`StaticState`] that persists across renders
+///
+/// Commits the items viewed during a render once that render's effects run.
+pub fn use_static_state() -> StaticState {
+    let state = use_ref(StaticState::new).get();
+    let committed = state.clone();
+    use_effect(
+        move || {
+            committed.commit();
+            None
+        },
+        (),
+    );
+    state
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,4 +254,60 @@ mod tests {
         // Only new items should be rendered
         assert_eq!(element2.children.len(), 2);
     }
+
+    #[test]
+    fn test_static_state_views_each_item_once() {
+        let state = StaticState::new();
+        state.push(Text::new("first"));
+        state.push(Text::new("second"));
+        assert_eq!(state.pending_len(), 2);
+
+        let first = state.view();
+        assert!(first.style.is_static);
+        assert_eq!(first.children.len(), 2);
+        assert_eq!(state.printed_len(), 0);
+
+        // Viewing again before the commit shows the same items
+        assert_eq!(state.view().children.len(), 2);
+        state.clone().push(Text::new("third"));
+        state.commit();
+        assert_eq!(state.printed_len(), 2);
+        assert_eq!(state.pending_len(), 1);
+
+        assert_eq!(state.view().children.len(), 1);
+        state.commit();
+        assert_eq!(state.printed_len(), 3);
+        assert!(state.view().children.is_empty());
+    }
+
+    #[test]
+    fn test_use_static_state_persists_across_renders() {
+        let ctx = Rc::new(RefCell::new(HookContext::new()));
+
+        let state = with_hooks(ctx.clone(), use_static_state);
+        state.push(Text::new("kept"));
+
+        let again = with_hooks(ctx.clone(), use_static_state);
+        assert_eq!(again.pending_len(), 1);
+    }
+
+    #[test]
+    fn test_use_static_state_commits_after_render() {
+        let ctx = Rc::new(RefCell::new(HookContext::new()));
+        let render = || {
+            with_hooks(ctx.clone(), || {
+                let state = use_static_state();
+                (state.clone(), state.view())
+            })
+        };
+
+        let (state, _) = render();
+        state.push(Text::new("line"));
+
+        // with_hooks runs the effects, committing what the render viewed
+        let (_, frame) = render();
+        assert_eq!(frame.children.len(), 1);
+        assert_eq!(state.printed_len(), 1);
+        assert!(render().1.children.is_empty());
+    }
 }
@@ -17,7 +17,7 @@
 //! ])
 //! ```
 
-use crate::core::{Color, Element, ElementType, Style, TextWrap};
+use crate::core::{Color, Element, ElementType, Style, TextOverflow, TextWrap};
 
 /// Generate chainable style setter methods for a type with a `style: Style` field.
 /// Each method takes `mut self`, sets the style field, and returns `self`.
@@ -290,6 +290,10 @@ impl Text {
     text_style_setters!(bool strikethrough => strikethrough, "Set strikethrough");
     text_style_setters!(bool dim => dim, "Set dim (less bright)");
     text_style_setters!(bool inverse => inverse, "Set inverse (swap foreground and background)");
+    text_style_setters!(
+        bool double_width => double_width,
+        "Draw as a double-width line (see [`Style::double_width`])"
+    );
 
     /// Alias for background
     pub fn bg(self, color: Color) -> Self {
@@ -302,6 +306,15 @@ impl Text {
         self
     }
 
+    /// Set how text that overflows its layout width is shown
+    ///
+    /// [`TextOverflow::Ellipsis`] keeps each line on one row and truncates it
+    /// with `…` at the width the layout finally gives this text.
+    pub fn text_overflow(mut self, overflow: TextOverflow) -> Self {
+        self.style.text_overflow = overflow;
+        self
+    }
+
     // === Convenience methods ===
 
     /// Apply error style (red color)
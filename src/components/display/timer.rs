@@ -1,6 +1,22 @@
 //! Timer and Stopwatch components
 //!
 //! Provides countdown timer and stopwatch functionality for TUI applications.
+//!
+//! Both states can advance from the wall clock (`start`/`tick`) or from an
+//! injected clock reading (`start_at`/`tick_at`), which keeps them
+//! deterministic in tests and lets apps drive them from their own time source.
+//!
+//! ```
+//! use std::time::Duration;
+//! use rnk::components::TimerState;
+//!
+//! let mut timer = TimerState::from_secs(2);
+//! timer.start_at(Duration::ZERO);
+//! assert!(!timer.tick_at(Duration::from_secs(1)));
+//! assert!(timer.tick_at(Duration::from_secs(2))); // fires exactly once
+//! assert!(!timer.tick_at(Duration::from_secs(3)));
+//! assert!(timer.is_complete());
+//! ```
 
 use std::time::{Duration, Instant};
 
@@ -17,6 +33,10 @@ pub struct TimerState {
     start_instant: Option<Instant>,
     /// Time remaining when paused
     paused_remaining: Duration,
+    /// Injected clock reading when the timer was last started/resumed
+    clock_start: Option<Duration>,
+    /// Whether the most recent tick completed the countdown
+    just_completed: bool,
 }
 
 impl TimerState {
@@ -28,6 +48,8 @@ impl TimerState {
             running: false,
             start_instant: None,
             paused_remaining: duration,
+            clock_start: None,
+            just_completed: false,
         }
     }
 
@@ -49,15 +71,30 @@ impl TimerState {
         }
     }
 
+    /// Start or resume the timer using an injected clock reading
+    pub fn start_at(&mut self, now: Duration) {
+        if !self.running && self.paused_remaining > Duration::ZERO {
+            self.running = true;
+            self.clock_start = Some(now);
+        }
+    }
+
     /// Pause the timer
     pub fn pause(&mut self) {
         if self.running {
             self.running = false;
             self.paused_remaining = self.remaining;
             self.start_instant = None;
+            self.clock_start = None;
         }
     }
 
+    /// Advance to `now` and pause, using an injected clock reading
+    pub fn pause_at(&mut self, now: Duration) {
+        self.tick_at(now);
+        self.pause();
+    }
+
     /// Toggle between running and paused
     pub fn toggle(&mut self) {
         if self.running {
@@ -73,20 +110,42 @@ impl TimerState {
         self.remaining = self.duration;
         self.paused_remaining = self.duration;
         self.start_instant = None;
+        self.clock_start = None;
+        self.just_completed = false;
     }
 
     /// Update the timer state (call this each frame)
     pub fn tick(&mut self) {
-        if self.running {
-            if let Some(start) = self.start_instant {
-                let elapsed = start.elapsed();
-                self.remaining = self.paused_remaining.saturating_sub(elapsed);
-
-                if self.remaining == Duration::ZERO {
-                    self.running = false;
-                    self.start_instant = None;
-                }
-            }
+        self.just_completed = false;
+        if self.running
+            && let Some(start) = self.start_instant
+        {
+            self.advance(start.elapsed());
+        }
+    }
+
+    /// Update the timer state from an injected clock reading
+    ///
+    /// Returns `true` only on the tick where the countdown reaches zero,
+    /// so callers can fire a completion `Cmd` exactly once.
+    pub fn tick_at(&mut self, now: Duration) -> bool {
+        self.just_completed = false;
+        if self.running
+            && let Some(start) = self.clock_start
+        {
+            self.advance(now.saturating_sub(start));
+        }
+        self.just_completed
+    }
+
+    fn advance(&mut self, elapsed: Duration) {
+        self.remaining = self.paused_remaining.saturating_sub(elapsed);
+
+        if self.remaining == Duration::ZERO {
+            self.running = false;
+            self.start_instant = None;
+            self.clock_start = None;
+            self.just_completed = true;
         }
     }
 
@@ -95,6 +154,18 @@ impl TimerState {
         self.remaining == Duration::ZERO
     }
 
+    /// Check if the countdown has completed (alias for [`is_finished`](Self::is_finished))
+    pub fn is_complete(&self) -> bool {
+        self.is_finished()
+    }
+
+    /// Whether the most recent tick completed the countdown
+    ///
+    /// This is a one-shot signal: it resets on the next tick.
+    pub fn just_completed(&self) -> bool {
+        self.just_completed
+    }
+
     /// Get the progress as a fraction (0.0 to 1.0)
     pub fn progress(&self) -> f64 {
         if self.duration.is_zero() {
@@ -143,6 +214,8 @@ pub struct StopwatchState {
     accumulated: Duration,
     /// Lap times
     laps: Vec<Duration>,
+    /// Injected clock reading when the stopwatch was last started/resumed
+    clock_start: Option<Duration>,
 }
 
 impl Default for StopwatchState {
@@ -160,6 +233,7 @@ impl StopwatchState {
             start_instant: None,
             accumulated: Duration::ZERO,
             laps: Vec::new(),
+            clock_start: None,
         }
     }
 
@@ -171,15 +245,30 @@ impl StopwatchState {
         }
     }
 
+    /// Start or resume the stopwatch using an injected clock reading
+    pub fn start_at(&mut self, now: Duration) {
+        if !self.running {
+            self.running = true;
+            self.clock_start = Some(now);
+        }
+    }
+
     /// Pause the stopwatch
     pub fn pause(&mut self) {
         if self.running {
             self.running = false;
             self.accumulated = self.elapsed;
             self.start_instant = None;
+            self.clock_start = None;
         }
     }
 
+    /// Advance to `now` and pause, using an injected clock reading
+    pub fn pause_at(&mut self, now: Duration) {
+        self.tick_at(now);
+        self.pause();
+    }
+
     /// Toggle between running and paused
     pub fn toggle(&mut self) {
         if self.running {
@@ -195,6 +284,7 @@ impl StopwatchState {
         self.elapsed = Duration::ZERO;
         self.accumulated = Duration::ZERO;
         self.start_instant = None;
+        self.clock_start = None;
         self.laps.clear();
     }
 
@@ -205,6 +295,16 @@ impl StopwatchState {
         }
     }
 
+    /// Record a lap and return its split (time since the previous lap)
+    ///
+    /// Returns `None` if the stopwatch has not started yet.
+    pub fn record_lap(&mut self) -> Option<Duration> {
+        let previous = self.last_lap().unwrap_or(Duration::ZERO);
+        let count = self.laps.len();
+        self.lap();
+        (self.laps.len() > count).then(|| self.elapsed.saturating_sub(previous))
+    }
+
     /// Get all lap times
     pub fn laps(&self) -> &[Duration] {
         &self.laps
@@ -226,12 +326,38 @@ impl StopwatchState {
         splits
     }
 
+    /// Format each lap as `Lap N  <split>  <total>` using the precise formatter
+    pub fn format_laps(&self) -> Vec<String> {
+        self.splits()
+            .into_iter()
+            .zip(&self.laps)
+            .enumerate()
+            .map(|(i, (split, total))| {
+                format!(
+                    "Lap {}  {}  {}",
+                    i + 1,
+                    format_duration_precise(split),
+                    format_duration_precise(*total)
+                )
+            })
+            .collect()
+    }
+
     /// Update the stopwatch state (call this each frame)
     pub fn tick(&mut self) {
-        if self.running {
-            if let Some(start) = self.start_instant {
-                self.elapsed = self.accumulated + start.elapsed();
-            }
+        if self.running
+            && let Some(start) = self.start_instant
+        {
+            self.elapsed = self.accumulated + start.elapsed();
+        }
+    }
+
+    /// Update the stopwatch state from an injected clock reading
+    pub fn tick_at(&mut self, now: Duration) {
+        if self.running
+            && let Some(start) = self.clock_start
+        {
+            self.elapsed = self.accumulated + now.saturating_sub(start);
         }
     }
 
@@ -478,6 +604,62 @@ mod tests {
         assert_eq!(sw.format_stopwatch(), "02:05.45");
     }
 
+    #[test]
+    fn test_timer_tick_at_completes_once() {
+        let mut timer = TimerState::from_secs(10);
+        timer.start_at(Duration::from_secs(100));
+
+        assert!(!timer.tick_at(Duration::from_secs(104)));
+        assert_eq!(timer.remaining, Duration::from_secs(6));
+        assert!(!timer.is_complete());
+
+        assert!(timer.tick_at(Duration::from_secs(111)));
+        assert!(timer.just_completed());
+        assert!(timer.is_complete());
+        assert!(!timer.running);
+
+        assert!(!timer.tick_at(Duration::from_secs(112)));
+        assert!(!timer.just_completed());
+        assert!(timer.is_complete());
+    }
+
+    #[test]
+    fn test_timer_pause_at_resume() {
+        let mut timer = TimerState::from_secs(10);
+        timer.start_at(Duration::ZERO);
+        timer.pause_at(Duration::from_secs(3));
+        assert_eq!(timer.remaining, Duration::from_secs(7));
+
+        // Time passing while paused does not count
+        timer.start_at(Duration::from_secs(50));
+        timer.tick_at(Duration::from_secs(52));
+        assert_eq!(timer.remaining, Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_stopwatch_tick_at_and_record_lap() {
+        let mut sw = StopwatchState::new();
+        assert_eq!(sw.record_lap(), None);
+
+        sw.start_at(Duration::from_secs(5));
+        sw.tick_at(Duration::from_secs(15));
+        assert_eq!(sw.record_lap(), Some(Duration::from_secs(10)));
+
+        sw.pause_at(Duration::from_millis(27_500));
+        sw.start_at(Duration::from_secs(100));
+        sw.tick_at(Duration::from_secs(101));
+        assert_eq!(sw.elapsed, Duration::from_millis(23_500));
+        assert_eq!(sw.record_lap(), Some(Duration::from_millis(13_500)));
+
+        assert_eq!(
+            sw.format_laps(),
+            vec![
+                "Lap 1  00:10.000  00:10.000".to_string(),
+                "Lap 2  00:13.500  00:23.500".to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn test_format_duration_helpers() {
         let d = Duration::from_secs(3661);
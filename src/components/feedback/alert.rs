@@ -23,8 +23,15 @@
 //! ```
 
 use crate::components::status::{StatusLevel, impl_status_level_from, status_style};
-use crate::components::{Box as RnkBox, Text};
+use crate::components::{Box as RnkBox, InteractionOutcome, Text};
 use crate::core::{BorderStyle, Color, Element, FlexDirection};
+use crate::hooks::{Key, Mouse};
+
+/// Dismiss button glyph drawn in the top-right corner
+const DISMISS_SYMBOL: &str = "×";
+
+/// Columns taken by the border and horizontal padding on each side
+const FRAME_WIDTH: u16 = 2;
 
 /// Alert severity level
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -45,6 +52,7 @@ pub struct Alert {
     level: AlertLevel,
     title: Option<String>,
     dismissible: bool,
+    max_width: Option<u16>,
 }
 
 impl Alert {
@@ -55,6 +63,7 @@ impl Alert {
             level: AlertLevel::Info,
             title: None,
             dismissible: false,
+            max_width: None,
         }
     }
 
@@ -90,64 +99,113 @@ impl Alert {
         self
     }
 
-    /// Make the alert dismissible
-    pub fn dismissible(mut self) -> Self {
-        self.dismissible = true;
+    /// Show a `×` dismiss button in the top-right corner
+    pub fn dismissible(mut self, dismissible: bool) -> Self {
+        self.dismissible = dismissible;
+        self
+    }
+
+    /// Fix the alert's total width and wrap the body to fit inside it
+    pub fn max_width(mut self, width: u16) -> Self {
+        self.max_width = Some(width);
         self
     }
 
+    /// Whether the alert shows a dismiss button
+    pub fn is_dismissible(&self) -> bool {
+        self.dismissible
+    }
+
+    /// Position of the `×` button relative to the alert's top-left corner
+    /// when the alert is `width` columns wide
+    ///
+    /// Returns `None` for alerts that are not dismissible.
+    pub fn dismiss_button_offset(&self, width: u16) -> Option<(u16, u16)> {
+        if !self.dismissible || width <= 2 * FRAME_WIDTH {
+            return None;
+        }
+        Some((width - FRAME_WIDTH - 1, 1))
+    }
+
+    /// Check whether `mouse` is a left click on the `×` button of an alert
+    /// drawn at `origin` (column, row) and `width` columns wide
+    pub fn is_dismiss_click(&self, mouse: &Mouse, origin: (u16, u16), width: u16) -> bool {
+        mouse.is_left_click()
+            && self.dismiss_button_offset(width).is_some_and(|(x, y)| {
+                mouse.x == origin.0.saturating_add(x) && mouse.y == origin.1.saturating_add(y)
+            })
+    }
+
+    /// Body lines, wrapped when a max width is set
+    fn body_lines(&self, icon_width: u16) -> Vec<String> {
+        let Some(max_width) = self.max_width else {
+            return vec![self.message.clone()];
+        };
+        let dismiss_width = if self.dismissible { 2 } else { 0 };
+        let width = max_width
+            .saturating_sub(2 * FRAME_WIDTH + icon_width + dismiss_width)
+            .max(1);
+        textwrap::wrap(&self.message, width as usize)
+            .into_iter()
+            .map(|line| line.into_owned())
+            .collect()
+    }
+
     /// Convert to Element
     pub fn into_element(self) -> Element {
         let style = status_style(self.level.into());
-        let icon = style.icon;
+        let icon = format!("{} ", style.icon);
         let color = style.fg;
         let bg = style.bg;
 
-        let mut children = Vec::new();
-
-        // Icon and title/message row
-        let mut header_children = vec![
-            Text::new(format!("{} ", icon))
-                .color(color)
-                .bold()
-                .into_element(),
-        ];
-
+        // Title and wrapped body stacked to the right of the icon
+        let mut content = RnkBox::new()
+            .flex_direction(FlexDirection::Column)
+            .flex_grow(1.0);
         if let Some(title) = &self.title {
-            header_children.push(Text::new(title).color(color).bold().into_element());
-        } else {
-            header_children.push(Text::new(&self.message).color(Color::White).into_element());
+            content = content.child(Text::new(title).color(color).bold().into_element());
         }
-
-        if self.dismissible {
-            header_children.push(Text::new(" [x]").color(Color::BrightBlack).into_element());
+        let icon_width = unicode_width::UnicodeWidthStr::width(icon.as_str()) as u16;
+        for line in self.body_lines(icon_width) {
+            content = content.child(Text::new(line).color(Color::White).into_element());
         }
 
-        children.push(
-            RnkBox::new()
-                .flex_direction(FlexDirection::Row)
-                .children(header_children)
-                .into_element(),
-        );
-
-        // Message (if title is set)
-        if self.title.is_some() {
-            children.push(
-                Text::new(format!("  {}", self.message))
-                    .color(Color::White)
+        let mut row = RnkBox::new()
+            .flex_direction(FlexDirection::Row)
+            .child(Text::new(icon).color(color).bold().into_element())
+            .child(content.into_element());
+        if self.dismissible {
+            row = row.child(
+                Text::new(format!(" {}", DISMISS_SYMBOL))
+                    .color(Color::BrightBlack)
                     .into_element(),
             );
         }
 
-        RnkBox::new()
+        let mut container = RnkBox::new()
             .flex_direction(FlexDirection::Column)
             .padding_x(1.0)
-            .padding_y(0.5)
             .background(bg)
             .border_style(BorderStyle::Round)
             .border_color(color)
-            .children(children)
-            .into_element()
+            .child(row.into_element());
+        if let Some(width) = self.max_width {
+            container = container.width(width);
+        }
+        container.into_element()
+    }
+}
+
+/// Handle a key press against a dismissible alert
+///
+/// `Esc` or `x` dismisses the alert, reported as
+/// [`InteractionOutcome::Cancelled`]. Alerts that are not dismissible ignore
+/// every key.
+pub fn handle_alert_input(alert: &Alert, input: &str, key: &Key) -> InteractionOutcome<()> {
+    if alert.dismissible && (key.escape || (!key.ctrl && !key.alt && input == "x")) {
+        InteractionOutcome::Cancelled
+    } else {
+        InteractionOutcome::Ignored
     }
 }
 
@@ -175,6 +233,67 @@ mod tests {
         let _ = Alert::error("Error").into_element();
     }
 
+    #[test]
+    fn test_alert_wraps_body_with_title_and_dismiss() {
+        let alert = Alert::warning("disk usage is above ninety percent on /var")
+            .title("Low space")
+            .dismissible(true)
+            .max_width(24);
+
+        let output =
+            crate::testing::TestRenderer::new(24, 6).render_to_plain(&alert.clone().into_element());
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert!(lines[1].contains("Low space"));
+        // Dismiss button sits where dismiss_button_offset says it does
+        let (x, y) = alert.dismiss_button_offset(24).unwrap();
+        let row: Vec<char> = lines[y as usize].chars().collect();
+        assert_eq!(row[x as usize], '×');
+        // Body wraps inside the frame instead of overflowing
+        assert!(lines[2].contains("disk usage"));
+        assert!(
+            lines
+                .iter()
+                .all(|line| crate::testing::display_width(line) <= 24)
+        );
+        assert!(lines[3].contains("ninety") || lines[3].contains("percent"));
+    }
+
+    #[test]
+    fn test_alert_dismiss_keys_and_click() {
+        use crate::hooks::{MouseAction, MouseButton};
+
+        let alert = Alert::info("Saved").dismissible(true);
+        let esc = Key {
+            escape: true,
+            ..Key::default()
+        };
+        assert_eq!(
+            handle_alert_input(&alert, "", &esc),
+            InteractionOutcome::Cancelled
+        );
+        assert_eq!(
+            handle_alert_input(&alert, "x", &Key::default()),
+            InteractionOutcome::Cancelled
+        );
+        assert_eq!(
+            handle_alert_input(&Alert::info("Saved"), "", &esc),
+            InteractionOutcome::Ignored
+        );
+
+        let click = |x, y| Mouse {
+            x,
+            y,
+            action: MouseAction::Press(MouseButton::Left),
+            ctrl: false,
+            shift: false,
+            alt: false,
+        };
+        assert!(alert.is_dismiss_click(&click(15, 3), (0, 2), 18));
+        assert!(!alert.is_dismiss_click(&click(14, 3), (0, 2), 18));
+        assert!(!Alert::info("Saved").is_dismiss_click(&click(15, 3), (0, 2), 18));
+    }
+
     #[test]
     fn test_alert_with_title() {
         let a = Alert::error("Details").title("Error!");
@@ -27,9 +27,34 @@
 //!         .into_element()
 //! }
 //! ```
+//!
+//! # Live inspection
+//!
+//! Feed the frame's VNode snapshot and computed layouts to show the real
+//! element tree with each node's position and size:
+//!
+//! ```rust,ignore
+//! let (vnode, _) = engine.compute_element_incremental(&root, prev.as_ref(), 80, 24);
+//! let layouts = engine.get_all_vnode_layouts();
+//!
+//! DevTools::new()
+//!     .visible(true)
+//!     .inspect(&vnode, &layouts)
+//!     .frame_stats(controller.stats().clone())
+//!     .into_element()
+//! ```
+
+use std::collections::{HashMap, HashSet};
 
 use crate::components::{Box as RnkBox, Text};
-use crate::core::{BorderStyle, Color, Element, FlexDirection, JustifyContent};
+use crate::core::{
+    BorderStyle, Color, Element, FlexDirection, JustifyContent, NodeKey, VNode, VNodeType,
+};
+use crate::layout::Layout;
+use crate::renderer::FrameRateStats;
+
+/// Maximum characters of text content shown for a text node
+const TEXT_PREVIEW_LEN: usize = 16;
 
 /// DevTools panel for debugging rnk applications
 #[derive(Debug, Clone)]
@@ -37,6 +62,24 @@ pub struct DevTools {
     visible: bool,
     active_tab: DevToolsTab,
     width: u16,
+    snapshot: Option<Vec<InspectedNode>>,
+    collapsed: HashSet<NodeKey>,
+    selected: Option<NodeKey>,
+    frame_stats: Option<FrameRateStats>,
+}
+
+/// A flattened node from an inspected VNode tree
+#[derive(Debug, Clone)]
+struct InspectedNode {
+    key: NodeKey,
+    depth: usize,
+    label: String,
+    /// Absolute layout (parent offsets accumulated)
+    layout: Option<Layout>,
+    /// Keys of all ancestors, used to hide collapsed subtrees
+    ancestors: Vec<NodeKey>,
+    has_children: bool,
+    style_summary: String,
 }
 
 /// DevTools tabs
@@ -56,6 +99,10 @@ impl DevTools {
             visible: false,
             active_tab: DevToolsTab::Tree,
             width: 40,
+            snapshot: None,
+            collapsed: HashSet::new(),
+            selected: None,
+            frame_stats: None,
         }
     }
 
@@ -77,6 +124,50 @@ impl DevTools {
         self
     }
 
+    /// Inspect a VNode snapshot with the layouts computed for it
+    ///
+    /// Pass the snapshot returned by `LayoutEngine::compute_element_incremental`
+    /// and `LayoutEngine::get_all_vnode_layouts` each frame. Positions are shown
+    /// in absolute terminal cells.
+    pub fn inspect(mut self, root: &VNode, layouts: &HashMap<NodeKey, Layout>) -> Self {
+        let mut nodes = Vec::new();
+        flatten_vnode(root, layouts, 0, (0.0, 0.0), &mut Vec::new(), &mut nodes);
+        self.snapshot = Some(nodes);
+        self
+    }
+
+    /// Collapse the subtrees under the given nodes in the tree tab
+    pub fn collapsed(mut self, keys: impl IntoIterator<Item = NodeKey>) -> Self {
+        self.collapsed = keys.into_iter().collect();
+        self
+    }
+
+    /// Select a node; its details are shown in the layout tab
+    pub fn selected(mut self, key: Option<NodeKey>) -> Self {
+        self.selected = key;
+        self
+    }
+
+    /// Set frame statistics shown in the performance tab
+    pub fn frame_stats(mut self, stats: FrameRateStats) -> Self {
+        self.frame_stats = Some(stats);
+        self
+    }
+
+    /// Keys of the tree rows currently visible (collapsed subtrees hidden)
+    ///
+    /// Useful for moving the selection with arrow keys.
+    pub fn visible_keys(&self) -> Vec<NodeKey> {
+        self.visible_nodes().map(|node| node.key).collect()
+    }
+
+    fn visible_nodes(&self) -> impl Iterator<Item = &InspectedNode> {
+        self.snapshot
+            .iter()
+            .flatten()
+            .filter(|node| !node.ancestors.iter().any(|a| self.collapsed.contains(a)))
+    }
+
     /// Convert to Element
     pub fn into_element(self) -> Element {
         if !self.visible {
@@ -157,40 +248,58 @@ impl DevTools {
     }
 
     fn render_tree_tab(&self) -> Element {
+        let mut children = vec![
+            Text::new("Component Tree")
+                .color(Color::White)
+                .bold()
+                .into_element(),
+            Text::new("").into_element(),
+        ];
+
+        if self.snapshot.is_none() {
+            children.push(
+                Text::new("No snapshot (use DevTools::inspect)")
+                    .color(Color::BrightBlack)
+                    .into_element(),
+            );
+        }
+        for node in self.visible_nodes() {
+            children.push(self.render_tree_node(node));
+        }
+
         RnkBox::new()
             .flex_direction(FlexDirection::Column)
             .padding(1)
             .flex_grow(1.0)
-            .children(vec![
-                Text::new("Component Tree")
-                    .color(Color::White)
-                    .bold()
-                    .into_element(),
-                Text::new("").into_element(),
-                self.render_tree_node("App", 0, true),
-                self.render_tree_node("Box", 1, false),
-                self.render_tree_node("Text", 2, false),
-                self.render_tree_node("Box", 2, false),
-                self.render_tree_node("Text", 3, false),
-            ])
+            .children(children)
             .into_element()
     }
 
-    fn render_tree_node(&self, name: &str, depth: usize, selected: bool) -> Element {
-        let indent = "  ".repeat(depth);
-        let prefix = if depth == 0 { "▼" } else { "├─" };
+    fn render_tree_node(&self, node: &InspectedNode) -> Element {
+        let indent = "  ".repeat(node.depth);
+        let marker = match (node.has_children, self.collapsed.contains(&node.key)) {
+            (false, _) => "•",
+            (true, true) => "▶",
+            (true, false) => "▼",
+        };
+        let selected = self.selected == Some(node.key);
 
         RnkBox::new()
+            .flex_direction(FlexDirection::Row)
+            .gap(1.0)
             .background(if selected {
                 Color::Ansi256(238)
             } else {
                 Color::Reset
             })
-            .child(
-                Text::new(format!("{}{} {}", indent, prefix, name))
+            .children(vec![
+                Text::new(format!("{}{} {}", indent, marker, node.label))
                     .color(if selected { Color::Cyan } else { Color::White })
                     .into_element(),
-            )
+                Text::new(format_layout(node.layout))
+                    .color(Color::BrightBlack)
+                    .into_element(),
+            ])
             .into_element()
     }
 
@@ -228,22 +337,44 @@ impl DevTools {
     }
 
     fn render_layout_tab(&self) -> Element {
+        let mut children = vec![
+            Text::new("Layout Info")
+                .color(Color::White)
+                .bold()
+                .into_element(),
+            Text::new("").into_element(),
+        ];
+
+        let selected = self
+            .selected
+            .and_then(|key| self.snapshot.iter().flatten().find(|n| n.key == key));
+        match selected {
+            Some(node) => {
+                children.push(self.render_layout_item("Node", &node.label));
+                if let Some(layout) = node.layout {
+                    children.push(self.render_layout_item(
+                        "Position",
+                        &format!("x: {}, y: {}", layout.x, layout.y),
+                    ));
+                    children.push(self.render_layout_item(
+                        "Size",
+                        &format!("w: {}, h: {}", layout.width, layout.height),
+                    ));
+                }
+                children.push(self.render_layout_item("Style", &node.style_summary));
+            }
+            None => children.push(
+                Text::new("No node selected")
+                    .color(Color::BrightBlack)
+                    .into_element(),
+            ),
+        }
+
         RnkBox::new()
             .flex_direction(FlexDirection::Column)
             .padding(1)
             .flex_grow(1.0)
-            .children(vec![
-                Text::new("Layout Info")
-                    .color(Color::White)
-                    .bold()
-                    .into_element(),
-                Text::new("").into_element(),
-                self.render_layout_item("Position", "x: 0, y: 0"),
-                self.render_layout_item("Size", "w: 80, h: 24"),
-                self.render_layout_item("Padding", "1, 1, 1, 1"),
-                self.render_layout_item("Margin", "0, 0, 0, 0"),
-                self.render_layout_item("Flex", "row, grow: 1.0"),
-            ])
+            .children(children)
             .into_element()
     }
 
@@ -261,22 +392,63 @@ impl DevTools {
     }
 
     fn render_performance_tab(&self) -> Element {
+        let mut children = vec![
+            Text::new("Performance")
+                .color(Color::White)
+                .bold()
+                .into_element(),
+            Text::new("").into_element(),
+        ];
+
+        match &self.frame_stats {
+            Some(stats) => {
+                let frame_color = if stats.avg_frame_time_ms > 16.7 {
+                    Color::Yellow
+                } else {
+                    Color::Green
+                };
+                children.extend([
+                    self.render_perf_item("FPS", &format!("{:.0}", stats.current_fps), frame_color),
+                    self.render_perf_item(
+                        "Frame Time",
+                        &format!("{:.1}ms", stats.avg_frame_time_ms),
+                        frame_color,
+                    ),
+                    self.render_perf_item(
+                        "Min / Max",
+                        &format!(
+                            "{:.1} / {:.1}ms",
+                            stats.min_frame_time_ms, stats.max_frame_time_ms
+                        ),
+                        Color::White,
+                    ),
+                    self.render_perf_item("Frames", &stats.total_frames.to_string(), Color::White),
+                    self.render_perf_item(
+                        "Dropped",
+                        &stats.dropped_frames.to_string(),
+                        if stats.dropped_frames > 0 {
+                            Color::Red
+                        } else {
+                            Color::Green
+                        },
+                    ),
+                ]);
+            }
+            None => children.push(
+                Text::new("No frame stats (use DevTools::frame_stats)")
+                    .color(Color::BrightBlack)
+                    .into_element(),
+            ),
+        }
+        if let Some(nodes) = &self.snapshot {
+            children.push(self.render_perf_item("Nodes", &nodes.len().to_string(), Color::White));
+        }
+
         RnkBox::new()
             .flex_direction(FlexDirection::Column)
             .padding(1)
             .flex_grow(1.0)
-            .children(vec![
-                Text::new("Performance")
-                    .color(Color::White)
-                    .bold()
-                    .into_element(),
-                Text::new("").into_element(),
-                self.render_perf_item("FPS", "60", Color::Green),
-                self.render_perf_item("Frame Time", "16.7ms", Color::Green),
-                self.render_perf_item("Render Count", "42", Color::White),
-                self.render_perf_item("Layout Time", "0.5ms", Color::Green),
-                self.render_perf_item("Hooks", "5", Color::White),
-            ])
+            .children(children)
             .into_element()
     }
 
@@ -292,6 +464,74 @@ impl DevTools {
     }
 }
 
+/// Flatten a VNode tree depth-first, accumulating absolute positions
+fn flatten_vnode(
+    vnode: &VNode,
+    layouts: &HashMap<NodeKey, Layout>,
+    depth: usize,
+    origin: (f32, f32),
+    ancestors: &mut Vec<NodeKey>,
+    out: &mut Vec<InspectedNode>,
+) {
+    let layout = layouts.get(&vnode.key).map(|l| Layout {
+        x: origin.0 + l.x,
+        y: origin.1 + l.y,
+        ..*l
+    });
+    let child_origin = layout.map_or(origin, |l| (l.x, l.y));
+
+    out.push(InspectedNode {
+        key: vnode.key,
+        depth,
+        label: node_label(vnode),
+        layout,
+        ancestors: ancestors.clone(),
+        has_children: !vnode.children.is_empty(),
+        style_summary: style_summary(vnode),
+    });
+
+    ancestors.push(vnode.key);
+    for child in &vnode.children {
+        flatten_vnode(child, layouts, depth + 1, child_origin, ancestors, out);
+    }
+    ancestors.pop();
+}
+
+fn node_label(vnode: &VNode) -> String {
+    match &vnode.node_type {
+        VNodeType::Root => "Root".to_string(),
+        VNodeType::Box => match &vnode.props.key {
+            Some(key) => format!("Box#{}", key),
+            None => "Box".to_string(),
+        },
+        VNodeType::Text(text) => {
+            let preview: String = text.chars().take(TEXT_PREVIEW_LEN).collect();
+            let ellipsis = if text.chars().count() > TEXT_PREVIEW_LEN {
+                "…"
+            } else {
+                ""
+            };
+            format!("Text \"{}{}\"", preview.replace('\n', "⏎"), ellipsis)
+        }
+        VNodeType::Component(_) => "Component".to_string(),
+    }
+}
+
+fn style_summary(vnode: &VNode) -> String {
+    let style = &vnode.props.style;
+    format!(
+        "{:?}, grow: {}, shrink: {}",
+        style.flex_direction, style.flex_grow, style.flex_shrink
+    )
+}
+
+fn format_layout(layout: Option<Layout>) -> String {
+    match layout {
+        Some(l) => format!("{},{} {}×{}", l.x, l.y, l.width, l.height),
+        None => "-".to_string(),
+    }
+}
+
 impl Default for DevTools {
     fn default() -> Self {
         Self::new()
@@ -301,6 +541,7 @@ impl Default for DevTools {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::TestRenderer;
 
     #[test]
     fn test_devtools_creation() {
@@ -327,6 +568,84 @@ mod tests {
         let _ = devtools.into_element();
     }
 
+    fn sample_snapshot() -> (VNode, HashMap<NodeKey, Layout>) {
+        use crate::layout::LayoutEngine;
+
+        let root = RnkBox::new()
+            .flex_direction(FlexDirection::Column)
+            .padding(1)
+            .child(Text::new("hello").into_element())
+            .child(
+                RnkBox::new()
+                    .key("inner")
+                    .child(Text::new("world").into_element())
+                    .into_element(),
+            )
+            .into_element();
+
+        let mut engine = LayoutEngine::new();
+        let (vnode, _) = engine.compute_element_incremental(&root, None, 30, 10);
+        let layouts = engine.get_all_vnode_layouts();
+        (vnode, layouts)
+    }
+
+    #[test]
+    fn test_devtools_inspect_shows_layouts() {
+        let (vnode, layouts) = sample_snapshot();
+        let devtools = DevTools::new()
+            .visible(true)
+            .width(60)
+            .inspect(&vnode, &layouts);
+        assert_eq!(devtools.visible_keys().len(), vnode.node_count());
+
+        let plain = TestRenderer::new(60, 20).render_to_plain(&devtools.into_element());
+        assert!(plain.contains("Text \"hello\""));
+        assert!(plain.contains("Box#inner"));
+        // Nested text sits inside the padded container: absolute x = 1, y = 2
+        assert!(plain.contains("Text \"world\" 1,2 5×1"), "{plain}");
+    }
+
+    #[test]
+    fn test_devtools_collapse_hides_subtree() {
+        let (vnode, layouts) = sample_snapshot();
+        let inner = vnode.children[1].key;
+        let devtools = DevTools::new().inspect(&vnode, &layouts).collapsed([inner]);
+
+        assert_eq!(devtools.visible_keys().len(), vnode.node_count() - 1);
+        assert!(devtools.visible_keys().contains(&inner));
+    }
+
+    #[test]
+    fn test_devtools_layout_and_perf_tabs() {
+        let (vnode, layouts) = sample_snapshot();
+        let inner = vnode.children[1].key;
+        let stats = FrameRateStats {
+            current_fps: 30.0,
+            avg_frame_time_ms: 12.5,
+            total_frames: 99,
+            ..Default::default()
+        };
+
+        let layout_tab = DevTools::new()
+            .visible(true)
+            .width(60)
+            .tab(DevToolsTab::Layout)
+            .inspect(&vnode, &layouts)
+            .selected(Some(inner));
+        let plain = TestRenderer::new(60, 20).render_to_plain(&layout_tab.into_element());
+        assert!(plain.contains("Box#inner"));
+        assert!(plain.contains("x: 1, y: 2"));
+
+        let perf_tab = DevTools::new()
+            .visible(true)
+            .width(60)
+            .tab(DevToolsTab::Performance)
+            .frame_stats(stats);
+        let plain = TestRenderer::new(60, 20).render_to_plain(&perf_tab.into_element());
+        assert!(plain.contains("12.5ms"));
+        assert!(plain.contains("99"));
+    }
+
     #[test]
     fn test_devtools_hidden() {
         let devtools = DevTools::new().visible(false);
@@ -1,10 +1,16 @@
 //! Help component for displaying keyboard shortcuts
 //!
 //! Provides a component to display keybindings and help text,
-//! similar to Bubbles' help component.
+//! similar to Bubbles' help component. Large keymaps can be balanced across
+//! columns with [`Help::columns`] and split into pages when they are taller
+//! than the terminal; [`handle_help_input`] moves between pages.
 
-use crate::components::{Box as RnkBox, Text};
+use crate::components::{Box as RnkBox, Line, Span, Text};
 use crate::core::{Color, Element, FlexDirection};
+use crate::layout::measure::measure_text_width;
+
+/// Spaces between columns of bindings
+const COLUMN_GAP: usize = 3;
 
 /// A single key binding with its description
 #[derive(Debug, Clone)]
@@ -135,10 +141,24 @@ pub struct Help {
     style: HelpStyle,
     /// Maximum width (for wrapping in single-line mode)
     max_width: Option<usize>,
+    /// Maximum height in lines before paging
+    max_height: Option<usize>,
+    /// Balance bindings across as many columns as fit the width
+    auto_columns: bool,
+    /// Page shown when the bindings don't fit the height
+    page: usize,
     /// Whether the help is visible
     visible: bool,
 }
 
+/// How bindings are arranged in the column grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct GridLayout {
+    columns: usize,
+    rows_per_page: usize,
+    pages: usize,
+}
+
 impl Help {
     /// Create a new Help component with bindings
     pub fn new(bindings: Vec<KeyBinding>) -> Self {
@@ -147,6 +167,9 @@ impl Help {
             mode: HelpMode::default(),
             style: HelpStyle::default(),
             max_width: None,
+            max_height: None,
+            auto_columns: false,
+            page: 0,
             visible: true,
         }
     }
@@ -207,6 +230,42 @@ impl Help {
         self
     }
 
+    /// Set the maximum height in lines
+    ///
+    /// Bindings that don't fit are split into pages, with the last line
+    /// showing the page number. Applies to the multi-line modes and to
+    /// [`columns`](Self::columns).
+    pub fn max_height(mut self, height: usize) -> Self {
+        self.max_height = Some(height);
+        self
+    }
+
+    /// Balance bindings across as many columns as fit the width
+    ///
+    /// The width is [`max_width`](Self::max_width), or the terminal width
+    /// when unset. Bindings fill the columns top to bottom, and when they
+    /// are still taller than [`max_height`](Self::max_height) (or the
+    /// terminal) they are split into pages.
+    pub fn columns(mut self, auto: bool) -> Self {
+        self.auto_columns = auto;
+        self
+    }
+
+    /// Set the page to show, clamped to the last page
+    pub fn page(mut self, page: usize) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Number of pages the bindings are split into
+    pub fn page_count(&self) -> usize {
+        if self.uses_grid() {
+            self.grid_layout().pages
+        } else {
+            1
+        }
+    }
+
     /// Set visibility
     pub fn visible(mut self, visible: bool) -> Self {
         self.visible = visible;
@@ -239,6 +298,10 @@ impl Help {
             return RnkBox::new().into_element();
         }
 
+        if self.uses_grid() {
+            return self.render_grid();
+        }
+
         match self.mode {
             HelpMode::SingleLine => self.render_single_line(),
             HelpMode::MultiLine => self.render_multi_line(),
@@ -247,79 +310,188 @@ impl Help {
     }
 
     fn render_single_line(self) -> Element {
-        let mut parts = Vec::new();
+        let mut spans = Vec::new();
 
         for (i, binding) in self.bindings.iter().enumerate() {
             if i > 0 {
-                parts.push(self.style.binding_separator.clone());
+                spans.push(Span::new(self.style.binding_separator.clone()));
             }
-            parts.push(binding.key.clone());
-            parts.push(self.style.separator.clone());
-            parts.push(binding.description.clone());
+            spans.extend(self.binding_spans(binding, 0));
         }
 
-        let text = parts.join("");
-        let mut text_elem = Text::new(&text);
+        Text::line(Line::from_spans(spans)).into_element()
+    }
+
+    fn render_multi_line(self) -> Element {
+        let mut container = RnkBox::new().flex_direction(FlexDirection::Column);
 
-        if self.style.description_dim {
-            text_elem = text_elem.dim();
+        for binding in &self.bindings {
+            let spans = self.binding_spans(binding, 0);
+            container = container.child(Text::line(Line::from_spans(spans)).into_element());
         }
 
-        text_elem.into_element()
+        container.into_element()
     }
 
-    fn render_multi_line(self) -> Element {
+    fn render_two_column(self) -> Element {
         let mut container = RnkBox::new().flex_direction(FlexDirection::Column);
 
+        // Find the maximum key width for alignment
+        let max_key_width = self.key_width();
+
         for binding in &self.bindings {
-            let line = format!(
-                "{}{}{}",
-                binding.key, self.style.separator, binding.description
-            );
+            let spans = self.binding_spans(binding, max_key_width);
+            container = container.child(Text::line(Line::from_spans(spans)).into_element());
+        }
 
-            let mut text = Text::new(&line);
+        container.into_element()
+    }
 
-            if self.style.description_dim {
-                text = text.dim();
-            }
+    /// Styled key, separator and description of one binding, with the key
+    /// padded to `key_width`
+    fn binding_spans(&self, binding: &KeyBinding, key_width: usize) -> Vec<Span> {
+        let mut key = Span::new(binding.key.clone());
+        if let Some(color) = self.style.key_color {
+            key = key.color(color);
+        }
+        if self.style.key_bold {
+            key = key.bold();
+        }
 
-            container = container.child(text.into_element());
+        let padding = key_width.saturating_sub(measure_text_width(&binding.key));
+        let separator = Span::new(format!("{}{}", " ".repeat(padding), self.style.separator));
+
+        let mut description = Span::new(binding.description.clone());
+        if let Some(color) = self.style.description_color {
+            description = description.color(color);
+        }
+        if self.style.description_dim {
+            description = description.dim();
         }
 
-        container.into_element()
+        vec![key, separator, description]
     }
 
-    fn render_two_column(self) -> Element {
-        let mut container = RnkBox::new().flex_direction(FlexDirection::Column);
+    fn uses_grid(&self) -> bool {
+        self.auto_columns
+            || (self.mode != HelpMode::SingleLine
+                && self
+                    .max_height
+                    .is_some_and(|height| self.bindings.len() > height))
+    }
 
-        // Find the maximum key width for alignment
-        let max_key_width = self
-            .bindings
+    fn key_width(&self) -> usize {
+        self.bindings
             .iter()
-            .map(|b| b.key.chars().count())
+            .map(|b| measure_text_width(&b.key))
             .max()
-            .unwrap_or(0);
-
-        for binding in &self.bindings {
-            let padded_key = format!("{:width$}", binding.key, width = max_key_width);
-            let line = format!(
-                "{}{}{}",
-                padded_key, self.style.separator, binding.description
-            );
+            .unwrap_or(0)
+    }
+
+    fn grid_layout(&self) -> GridLayout {
+        let count = self.bindings.len().max(1);
+        let columns = if self.auto_columns {
+            let cell_width = self.key_width()
+                + measure_text_width(&self.style.separator)
+                + self
+                    .bindings
+                    .iter()
+                    .map(|b| measure_text_width(&b.description))
+                    .max()
+                    .unwrap_or(0);
+            let width = self
+                .max_width
+                .unwrap_or_else(|| crate::hooks::get_terminal_size().0 as usize);
+            ((width + COLUMN_GAP) / (cell_width + COLUMN_GAP)).clamp(1, count)
+        } else {
+            1
+        };
+
+        let rows = count.div_ceil(columns);
+        let height = self
+            .max_height
+            .unwrap_or_else(|| crate::hooks::get_terminal_size().1 as usize);
+        // Paging costs a line for the page indicator
+        let rows_per_page = if rows > height {
+            height.saturating_sub(1).max(1)
+        } else {
+            rows
+        };
+
+        GridLayout {
+            columns,
+            rows_per_page,
+            pages: rows.div_ceil(rows_per_page),
+        }
+    }
 
-            let mut text = Text::new(&line);
+    fn render_grid(self) -> Element {
+        let GridLayout {
+            columns,
+            rows_per_page,
+            pages,
+        } = self.grid_layout();
+        let page = self.page.min(pages - 1);
+        let per_page = columns * rows_per_page;
+        let start = page * per_page;
+        let bindings = &self.bindings[start..(start + per_page).min(self.bindings.len())];
+
+        // The last page may be short, so balance it on its own
+        let rows = bindings.len().div_ceil(columns);
+        let key_width = self.key_width();
+        let cells: Vec<Vec<Span>> = bindings
+            .iter()
+            .map(|binding| self.binding_spans(binding, key_width))
+            .collect();
+        let cell_width = |cell: &[Span]| cell.iter().map(Span::width).sum::<usize>();
+        let column_widths: Vec<usize> = cells
+            .chunks(rows)
+            .map(|column| column.iter().map(|c| cell_width(c)).max().unwrap_or(0))
+            .collect();
 
-            if self.style.description_dim {
-                text = text.dim();
+        let mut container = RnkBox::new().flex_direction(FlexDirection::Column);
+        for row in 0..rows {
+            let mut spans = Vec::new();
+            for (column, width) in column_widths.iter().enumerate() {
+                let Some(cell) = cells.get(column * rows + row) else {
+                    break;
+                };
+                if column > 0 {
+                    spans.push(Span::new(" ".repeat(COLUMN_GAP)));
+                }
+                spans.extend(cell.iter().cloned());
+                if cells.get((column + 1) * rows + row).is_some() {
+                    spans.push(Span::new(" ".repeat(width - cell_width(cell))));
+                }
             }
+            container = container.child(Text::line(Line::from_spans(spans)).into_element());
+        }
 
-            container = container.child(text.into_element());
+        if pages > 1 {
+            let indicator = format!("Page {}/{} · PgUp/PgDn", page + 1, pages);
+            container = container.child(Text::new(indicator).dim().into_element());
         }
 
         container.into_element()
     }
 }
 
+/// Move between help pages with PageUp/PageDown
+///
+/// `page_count` is usually [`Help::page_count`]. Returns true when the key
+/// was handled.
+pub fn handle_help_input(page: &mut usize, page_count: usize, key: &crate::hooks::Key) -> bool {
+    if key.page_up {
+        *page = page.saturating_sub(1);
+        true
+    } else if key.page_down {
+        *page = (*page + 1).min(page_count.saturating_sub(1));
+        true
+    } else {
+        false
+    }
+}
+
 impl Default for Help {
     fn default() -> Self {
         Self::new(Vec::new())
@@ -452,4 +624,91 @@ mod tests {
         assert!(help.is_empty());
         assert_eq!(help.len(), 0);
     }
+
+    fn grid_lines(help: Help) -> Vec<String> {
+        let output =
+            crate::testing::TestRenderer::new(40, 10).render_to_plain(&help.into_element());
+        output
+            .lines()
+            .map(|line| line.trim_end().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_help_columns_balance_across_width() {
+        let help = Help::from_tuples([
+            ("a", "Add"),
+            ("d", "Delete"),
+            ("e", "Edit"),
+            ("q", "Quit"),
+            ("?", "Help"),
+        ])
+        .columns(true)
+        .max_width(30)
+        .max_height(10);
+
+        assert_eq!(help.page_count(), 1);
+        assert_eq!(
+            grid_lines(help),
+            vec!["a Add      e Edit   ? Help", "d Delete   q Quit"]
+        );
+    }
+
+    #[test]
+    fn test_help_grid_keeps_binding_styles() {
+        let help = Help::from_tuples([("a", "Add"), ("d", "Delete")])
+            .style(HelpStyle::new().key_color(Color::Green))
+            .columns(true)
+            .max_width(30);
+        let element = help.into_element();
+        let row = element.children.get(0).unwrap();
+        let spans = &row.spans.as_ref().unwrap()[0].spans;
+
+        let key = spans.iter().find(|span| span.content == "a").unwrap();
+        assert_eq!(key.style.color, Some(Color::Green));
+        assert!(key.style.bold);
+        assert!(!key.style.dim);
+
+        let description = spans.iter().find(|span| span.content == "Add").unwrap();
+        assert_eq!(description.style.color, Some(Color::BrightBlack));
+        assert!(description.style.dim);
+    }
+
+    #[test]
+    fn test_help_max_height_only_pages_when_needed() {
+        let help = Help::from_tuples([("a", "Add"), ("d", "Delete")])
+            .multi_line()
+            .max_height(5);
+        assert!(!help.uses_grid());
+        assert_eq!(help.page_count(), 1);
+        assert_eq!(grid_lines(help), vec!["a Add", "d Delete"]);
+    }
+
+    #[test]
+    fn test_help_pages_when_too_tall() {
+        let help = Help::from_tuples((1..=5).map(|i| (format!("F{i}"), format!("Action {i}"))))
+            .multi_line()
+            .max_height(3);
+        assert_eq!(help.page_count(), 3);
+
+        let mut page = 0;
+        let key = crate::hooks::Key {
+            page_down: true,
+            ..Default::default()
+        };
+        assert!(handle_help_input(&mut page, help.page_count(), &key));
+        assert!(handle_help_input(&mut page, help.page_count(), &key));
+        assert!(handle_help_input(&mut page, help.page_count(), &key));
+        assert_eq!(page, 2);
+
+        assert_eq!(
+            grid_lines(help.clone().page(1)),
+            vec!["F3 Action 3", "F4 Action 4", "Page 2/3 · PgUp/PgDn"]
+        );
+        // Out of range pages show the last one
+        assert_eq!(
+            grid_lines(help.page(9)),
+            vec!["F5 Action 5", "Page 3/3 · PgUp/PgDn"]
+        );
+    }
 }
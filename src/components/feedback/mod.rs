@@ -10,13 +10,17 @@ mod status_bar;
 mod stepper;
 mod tooltip;
 
-pub use alert::{Alert, AlertLevel};
+pub use alert::{Alert, AlertLevel, handle_alert_input};
 pub use cursor::{Cursor, CursorShape, CursorState, CursorStyle};
 pub use devtools::{DevTools, DevToolsTab};
 pub use help::{
-    Help, HelpMode, HelpStyle, KeyBinding, editor_help, navigation_help, vim_navigation_help,
+    Help, HelpMode, HelpStyle, KeyBinding, editor_help, handle_help_input, navigation_help,
+    vim_navigation_help,
+};
+pub use modal::{
+    Dialog, DialogState, Modal, ModalAlign, ModalStack, ModalStackState, handle_modal_stack_input,
+    handle_modal_stack_input_with_mode,
 };
-pub use modal::{Dialog, DialogState, Modal, ModalAlign};
 pub use notification::{
     Notification, NotificationBorder, NotificationItem, NotificationLevel, NotificationPosition,
     NotificationState, NotificationStyle, Toast,
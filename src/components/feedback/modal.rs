@@ -3,8 +3,15 @@
 //! Provides a centered overlay that can be used for dialogs, confirmations,
 //! and other modal interactions.
 
-use crate::components::{ActionButton, ActionRole, ActionShape, ActionState, Text, get_theme};
-use crate::core::{AlignItems, BorderStyle, Color, Element, FlexDirection, JustifyContent};
+use crate::components::{
+    ActionButton, ActionRole, ActionShape, ActionState, InteractionMode, InteractionOutcome, Text,
+    get_theme,
+};
+use crate::core::{
+    AccessibilityProps, AccessibilityRole, AlignItems, Backdrop, BorderStyle, Color, Dimension,
+    Element, FlexDirection, JustifyContent,
+};
+use crate::hooks::use_focus_manager;
 
 /// Modal alignment options
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
@@ -507,6 +514,254 @@ impl DialogState {
     }
 }
 
+/// Stack of open modals, topmost last
+///
+/// Only the top modal should receive input: run
+/// [`handle_modal_stack_input`] first, then dispatch on [`top`](Self::top)
+/// and fall back to the base UI when the stack is empty. Register each
+/// modal's focusable widgets under [`focus_scope`](Self::focus_scope) so
+/// Tab stays trapped inside the top modal.
+///
+/// # Example
+///
+/// ```ignore
+/// use_input(move |input, key| {
+///     let mut s = stack.get();
+///     if handle_modal_stack_input(&mut s, input, key) {
+///         stack.set(s);
+///         return;
+///     }
+///     match s.top() {
+///         Some("confirm-close") => { /* confirm dialog keys */ }
+///         Some("settings") => { /* settings keys */ }
+///         _ => { /* base UI keys */ }
+///     }
+/// });
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModalStackState {
+    /// Open modal ids, bottom first
+    stack: Vec<String>,
+}
+
+impl ModalStackState {
+    /// Create an empty stack
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open a modal on top of the stack
+    ///
+    /// A modal that is already open moves to the top.
+    pub fn push_modal(&mut self, id: impl Into<String>) {
+        let id = id.into();
+        self.stack.retain(|open| *open != id);
+        self.stack.push(id);
+    }
+
+    /// Close the top modal, returning its id
+    pub fn pop_modal(&mut self) -> Option<String> {
+        self.stack.pop()
+    }
+
+    /// Close a modal and every modal opened above it
+    ///
+    /// Returns false if the modal was not open.
+    pub fn close_modal(&mut self, id: &str) -> bool {
+        match self.stack.iter().position(|open| open == id) {
+            Some(index) => {
+                self.stack.truncate(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Close every modal
+    pub fn clear(&mut self) {
+        self.stack.clear();
+    }
+
+    /// Id of the topmost modal
+    pub fn top(&self) -> Option<&str> {
+        self.stack.last().map(String::as_str)
+    }
+
+    /// Open modal ids, bottom first
+    pub fn ids(&self) -> &[String] {
+        &self.stack
+    }
+
+    /// Number of open modals
+    pub fn depth(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Check whether no modal is open
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+
+    /// Check whether a modal is open anywhere in the stack
+    pub fn is_open(&self, id: &str) -> bool {
+        self.stack.iter().any(|open| open == id)
+    }
+
+    /// Check whether a modal is the topmost one, and so receives input
+    pub fn is_top(&self, id: &str) -> bool {
+        self.top() == Some(id)
+    }
+
+    /// Focus scope for a modal's focusable widgets
+    ///
+    /// Pass it to [`ScopedFocusOptions::new`](crate::hooks::ScopedFocusOptions::new).
+    pub fn focus_scope(id: &str) -> String {
+        format!("modal:{}", id)
+    }
+
+    /// Focus scope of the topmost modal
+    pub fn top_focus_scope(&self) -> Option<String> {
+        self.top().map(Self::focus_scope)
+    }
+}
+
+/// Handle modal stack keys
+pub fn handle_modal_stack_input(
+    state: &mut ModalStackState,
+    input: &str,
+    key: &crate::hooks::Key,
+) -> bool {
+    handle_modal_stack_input_with_mode(state, input, key, InteractionMode::Enabled).is_handled()
+}
+
+/// Handle modal stack keys with explicit disabled/read-only behavior.
+///
+/// Escape pops the top modal and reports its id as `Changed`. Tab and
+/// Shift+Tab move focus within the top modal's [focus
+/// scope](ModalStackState::focus_scope) so it never leaves the modal. Other
+/// keys are ignored and should be routed to the top modal by the caller.
+pub fn handle_modal_stack_input_with_mode(
+    state: &mut ModalStackState,
+    _input: &str,
+    key: &crate::hooks::Key,
+    mode: InteractionMode,
+) -> InteractionOutcome<String> {
+    if !mode.is_enabled() {
+        return InteractionOutcome::Ignored;
+    }
+    let Some(scope) = state.top_focus_scope() else {
+        return InteractionOutcome::Ignored;
+    };
+
+    if key.escape {
+        return match state.pop_modal() {
+            Some(id) => InteractionOutcome::Changed(id),
+            None => InteractionOutcome::Ignored,
+        };
+    }
+    if key.back_tab || (key.tab && key.shift) {
+        use_focus_manager().focus_previous_in_scope(&scope);
+        return InteractionOutcome::Handled;
+    }
+    if key.tab {
+        use_focus_manager().focus_next_in_scope(&scope);
+        return InteractionOutcome::Handled;
+    }
+    InteractionOutcome::Ignored
+}
+
+/// Renders a base view with the open modals layered over it
+///
+/// Each open modal gets a full-size layer with a [`Backdrop`] that dims
+/// everything beneath it, so deeper modals sit on progressively darker
+/// content. Layers are drawn in stack order; ids without content are
+/// skipped.
+///
+/// # Example
+///
+/// ```ignore
+/// ModalStack::new(&stack.get())
+///     .base(editor_view())
+///     .layer("settings", Modal::new().title("Settings").child(form).into_element())
+///     .layer("confirm-close", Dialog::new().message("Discard changes?").into_element())
+///     .into_element()
+/// ```
+pub struct ModalStack<'a> {
+    /// Stack state
+    state: &'a ModalStackState,
+    /// Content beneath every modal
+    base: Option<Element>,
+    /// Modal content by id
+    layers: Vec<(String, Element)>,
+    /// Scrim drawn under each modal
+    backdrop: Backdrop,
+}
+
+impl<'a> ModalStack<'a> {
+    /// Create a modal stack view for a state
+    pub fn new(state: &'a ModalStackState) -> Self {
+        Self {
+            state,
+            base: None,
+            layers: Vec::new(),
+            backdrop: Backdrop::default(),
+        }
+    }
+
+    /// Set the content beneath every modal
+    pub fn base(mut self, base: Element) -> Self {
+        self.base = Some(base);
+        self
+    }
+
+    /// Provide the content for a modal id
+    pub fn layer(mut self, id: impl Into<String>, content: Element) -> Self {
+        self.layers.push((id.into(), content));
+        self
+    }
+
+    /// Set the scrim drawn under each modal
+    pub fn backdrop(mut self, backdrop: Backdrop) -> Self {
+        self.backdrop = backdrop;
+        self
+    }
+
+    /// Convert to Element
+    pub fn into_element(mut self) -> Element {
+        use crate::components::Box;
+
+        let mut root = Box::new()
+            .flex_direction(FlexDirection::Column)
+            .width(Dimension::Percent(100.0))
+            .height(Dimension::Percent(100.0));
+        if let Some(base) = self.base.take() {
+            root = root.child(base);
+        }
+
+        for id in self.state.ids() {
+            let Some(index) = self.layers.iter().position(|(layer, _)| layer == id) else {
+                continue;
+            };
+            let (_, content) = self.layers.swap_remove(index);
+            let layer = Box::new()
+                .position_absolute()
+                .top(0.0)
+                .left(0.0)
+                .right(0.0)
+                .bottom(0.0)
+                .flex_direction(FlexDirection::Column)
+                .backdrop(self.backdrop)
+                .key(format!("modal-layer:{}", id))
+                .child(content)
+                .into_element()
+                .with_accessibility(AccessibilityProps::new(AccessibilityRole::Dialog).label(id));
+            root = root.child(layer);
+        }
+
+        root.into_element()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -609,4 +864,102 @@ mod tests {
         assert_eq!(button_row.style.gap, 3.0);
         assert_eq!(confirm.text_content.as_deref(), Some("OK"));
     }
+
+    #[test]
+    fn test_modal_stack_push_pop_and_close() {
+        let mut stack = ModalStackState::new();
+        assert!(stack.is_empty());
+        assert_eq!(stack.top_focus_scope(), None);
+
+        stack.push_modal("settings");
+        stack.push_modal("confirm");
+        assert_eq!(stack.depth(), 2);
+        assert!(stack.is_top("confirm"));
+        assert!(stack.is_open("settings") && !stack.is_top("settings"));
+        assert_eq!(stack.top_focus_scope().as_deref(), Some("modal:confirm"));
+
+        // Re-opening moves a modal to the top
+        stack.push_modal("settings");
+        assert_eq!(stack.ids(), ["confirm", "settings"]);
+
+        assert_eq!(stack.pop_modal().as_deref(), Some("settings"));
+        stack.push_modal("nested");
+        stack.push_modal("deeper");
+        assert!(stack.close_modal("nested"));
+        assert_eq!(stack.ids(), ["confirm"]);
+        assert!(!stack.close_modal("nested"));
+    }
+
+    #[test]
+    fn test_modal_stack_escape_pops_top_only() {
+        use crate::hooks::Key;
+
+        let mut stack = ModalStackState::new();
+        let escape: Key = "Escape".parse().unwrap();
+        let letter: Key = "x".parse().unwrap();
+        assert!(!handle_modal_stack_input(&mut stack, "", &escape));
+
+        stack.push_modal("settings");
+        stack.push_modal("confirm");
+        assert!(!handle_modal_stack_input(&mut stack, "x", &letter));
+        assert_eq!(
+            handle_modal_stack_input_with_mode(&mut stack, "", &escape, InteractionMode::Enabled),
+            InteractionOutcome::Changed("confirm".to_string())
+        );
+        assert_eq!(stack.top(), Some("settings"));
+        assert_eq!(
+            handle_modal_stack_input_with_mode(&mut stack, "", &escape, InteractionMode::ReadOnly),
+            InteractionOutcome::Ignored
+        );
+
+        let tab: Key = "Tab".parse().unwrap();
+        assert!(handle_modal_stack_input(&mut stack, "", &tab));
+        assert_eq!(stack.depth(), 1);
+    }
+
+    #[test]
+    fn test_modal_stack_layers_dim_progressively() {
+        use crate::components::Box;
+        use crate::layout::LayoutEngine;
+        use crate::renderer::Output;
+        use crate::renderer::tree_renderer::render_element_tree;
+
+        let render = |stack: &ModalStackState| {
+            let base = Text::new("base content")
+                .background(Color::Rgb(200, 200, 200))
+                .into_element();
+            let first = Box::new()
+                .padding_top(1.0)
+                .child(Text::new("first").into_element())
+                .into_element();
+            let element = ModalStack::new(stack)
+                .base(base)
+                .layer("first", first)
+                .layer("second", Text::new("second").into_element())
+                .into_element();
+            let mut engine = LayoutEngine::new();
+            engine.compute(&element, 20, 3);
+            let mut output = Output::new(20, 3);
+            render_element_tree(&element, &engine, &mut output, 0.0, 0.0);
+            output
+        };
+        let base_bg = |output: &Output| output.cell_at(10, 0).unwrap().bg;
+
+        let mut stack = ModalStackState::new();
+        assert_eq!(base_bg(&render(&stack)), Some(Color::Rgb(200, 200, 200)));
+
+        stack.push_modal("first");
+        let one = render(&stack);
+        assert!(one.cell_at(10, 0).unwrap().dim);
+        assert_eq!(one.cell_at(0, 1).unwrap().ch, 'f');
+        assert!(!one.cell_at(0, 1).unwrap().dim);
+
+        stack.push_modal("second");
+        let two = render(&stack);
+        assert_eq!(two.cell_at(0, 0).unwrap().ch, 's');
+        assert!(!two.cell_at(0, 0).unwrap().dim);
+        // The base darkens further and the lower modal is dimmed too
+        assert_ne!(base_bg(&one), base_bg(&two));
+        assert!(two.cell_at(0, 1).unwrap().dim);
+    }
 }
@@ -16,9 +16,20 @@
 //!     popover.into_element()
 //! }
 //! ```
+//!
+//! To float the content next to an element rendered elsewhere, anchor the
+//! popover to that element's id instead of giving it a trigger:
+//!
+//! ```rust,ignore
+//! let button = Text::new("Options").into_element();
+//! let popover = Popover::default()
+//!     .anchor(button.id)
+//!     .content("Rename, Delete")
+//!     .open(true);
+//! ```
 
 use crate::components::{Box, Text};
-use crate::core::{Color, Element, FlexDirection};
+use crate::core::{Anchor, AnchorPlacement, Color, Element, ElementId, FlexDirection};
 
 /// Position for the popover relative to the trigger
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -34,6 +45,17 @@ pub enum PopoverPosition {
     Right,
 }
 
+impl From<PopoverPosition> for AnchorPlacement {
+    fn from(position: PopoverPosition) -> Self {
+        match position {
+            PopoverPosition::Top => AnchorPlacement::Top,
+            PopoverPosition::Bottom => AnchorPlacement::Bottom,
+            PopoverPosition::Left => AnchorPlacement::Left,
+            PopoverPosition::Right => AnchorPlacement::Right,
+        }
+    }
+}
+
 /// Arrow style for the popover
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PopoverArrow {
@@ -216,6 +238,8 @@ pub struct Popover {
     open: bool,
     /// Style configuration
     style: PopoverStyle,
+    /// Element the content is positioned against, replacing the trigger
+    anchor: Option<ElementId>,
 }
 
 impl Popover {
@@ -227,6 +251,7 @@ impl Popover {
             position: PopoverPosition::Bottom,
             open: false,
             style: PopoverStyle::default(),
+            anchor: None,
         }
     }
 
@@ -254,6 +279,15 @@ impl Popover {
         self
     }
 
+    /// Position the content next to an already rendered element
+    ///
+    /// The anchor element acts as the trigger, so only the content is
+    /// rendered, floating over the rest of the UI while open.
+    pub fn anchor(mut self, element_id: ElementId) -> Self {
+        self.anchor = Some(element_id);
+        self
+    }
+
     /// Render the popover content as a string
     fn render_content(&self) -> String {
         let mut result = String::new();
@@ -323,8 +357,31 @@ impl Popover {
         result
     }
 
+    /// Render the content text with the configured colors
+    fn content_element(&self) -> Element {
+        let mut content_element = Text::new(self.render_content());
+
+        if let Some(fg) = self.style.foreground {
+            content_element = content_element.color(fg);
+        }
+        if let Some(bg) = self.style.background {
+            content_element = content_element.background(bg);
+        }
+
+        content_element.into_element()
+    }
+
     /// Convert to Element
     pub fn into_element(self) -> Element {
+        if let Some(target) = self.anchor {
+            let mut overlay = Box::new();
+            if self.open {
+                let anchor = Anchor::new(target).placement(self.position.into());
+                overlay = overlay.anchor(anchor).child(self.content_element());
+            }
+            return overlay.into_element();
+        }
+
         let direction = match self.position {
             PopoverPosition::Top => FlexDirection::ColumnReverse,
             PopoverPosition::Bottom => FlexDirection::Column,
@@ -339,17 +396,7 @@ impl Popover {
 
         // Add content if open
         if self.open {
-            let content_text = self.render_content();
-            let mut content_element = Text::new(content_text);
-
-            if let Some(fg) = self.style.foreground {
-                content_element = content_element.color(fg);
-            }
-            if let Some(bg) = self.style.background {
-                content_element = content_element.background(bg);
-            }
-
-            container = container.child(content_element.into_element());
+            container = container.child(self.content_element());
         }
 
         container.into_element()
@@ -464,4 +511,38 @@ mod tests {
         assert_eq!(style.padding, 2);
         assert_eq!(style.max_width, Some(50));
     }
+
+    #[test]
+    fn test_popover_anchor_floats_below_target() {
+        use crate::testing::TestRenderer;
+
+        let target = Text::new("Options").into_element();
+        let popover = Popover::default()
+            .anchor(target.id)
+            .content("Rename")
+            .style(PopoverStyle::minimal())
+            .open(true);
+
+        let root = Box::new()
+            .flex_direction(FlexDirection::Column)
+            .child(Text::new("header").into_element())
+            .child(Box::new().padding_left(4.0).child(target).into_element())
+            .child(popover.into_element())
+            .child(Text::new("-".repeat(12)).into_element())
+            .into_element();
+
+        let output = TestRenderer::new(20, 4).render_to_plain(&root);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[1].trim_end(), "    Options");
+        // Drawn over the footer, left-aligned with the anchor
+        assert_eq!(lines[2].trim_end(), "----Rename--");
+    }
+
+    #[test]
+    fn test_popover_anchor_closed_renders_nothing() {
+        let target = Text::new("x").into_element();
+        let element = Popover::default().anchor(target.id).into_element();
+        assert!(element.children.is_empty());
+        assert!(element.style.anchor.is_none());
+    }
 }
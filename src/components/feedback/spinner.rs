@@ -1,6 +1,8 @@
 //! Spinner component for loading animations
 //!
 //! Provides a customizable loading spinner with optional cancellation support.
+//! With [reduced motion](crate::animation::prefers_reduced_motion) on, the
+//! spinner shows its first frame without cycling.
 
 use std::io::{self, Write};
 use std::sync::Arc;
@@ -122,9 +124,9 @@ impl SpinnerBuilder {
         let running_clone = running.clone();
         let cancelled_clone = cancelled.clone();
 
-        let handle = std::thread::spawn(move || {
-            let mut i = 0;
+        let mut frames = self.frame_sequence();
 
+        let handle = std::thread::spawn(move || {
             // Enable raw mode for key detection if cancellable
             if self.cancellable {
                 let _ = terminal::enable_raw_mode();
@@ -155,10 +157,11 @@ impl SpinnerBuilder {
 
                 print!(
                     "\x1b[2K\r\x1b[33m{} {}{}\x1b[0m",
-                    self.frames[i], self.message, cancel_hint
+                    frames.next().unwrap_or_default(),
+                    self.message,
+                    cancel_hint
                 );
                 let _ = io::stdout().flush();
-                i = (i + 1) % self.frames.len();
             }
 
             if self.cancellable {
@@ -176,6 +179,17 @@ impl SpinnerBuilder {
             handle: Some(handle),
         }
     }
+
+    /// Frames in display order, cycling, or the first frame held still
+    /// with reduced motion on
+    fn frame_sequence(&self) -> impl Iterator<Item = &'static str> + Send + use<> {
+        let frames = if crate::animation::prefers_reduced_motion() {
+            self.frames.iter().take(1).copied().collect()
+        } else {
+            self.frames.clone()
+        };
+        frames.into_iter().cycle()
+    }
 }
 
 #[cfg(test)]
@@ -198,4 +212,22 @@ mod tests {
         assert!(!spinner.is_cancelled());
         spinner.stop();
     }
+
+    #[test]
+    fn test_spinner_frames_cycle() {
+        let builder = Spinner::builder().frames(vec!["a", "b"]);
+        let frames: Vec<_> = crate::animation::with_reduced_motion(false, || {
+            builder.frame_sequence().take(3).collect()
+        });
+        assert_eq!(frames, vec!["a", "b", "a"]);
+    }
+
+    #[test]
+    fn test_spinner_holds_first_frame_with_reduced_motion() {
+        let builder = Spinner::builder().frames(vec!["a", "b"]);
+        let frames: Vec<_> = crate::animation::with_reduced_motion(true, || {
+            builder.frame_sequence().take(3).collect()
+        });
+        assert_eq!(frames, vec!["a", "a", "a"]);
+    }
 }
@@ -17,7 +17,7 @@
 //! ```
 
 use crate::components::{Box as RnkBox, Text};
-use crate::core::{Color, Element, FlexDirection};
+use crate::core::{Anchor, AnchorPlacement, Color, Element, ElementId, FlexDirection};
 
 /// Tooltip position
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -36,6 +36,7 @@ pub struct Tooltip {
     content: String,
     position: TooltipPosition,
     visible: bool,
+    anchor: Option<ElementId>,
 }
 
 impl Tooltip {
@@ -46,6 +47,7 @@ impl Tooltip {
             content: String::new(),
             position: TooltipPosition::Right,
             visible: true,
+            anchor: None,
         }
     }
 
@@ -67,19 +69,28 @@ impl Tooltip {
         self
     }
 
+    /// Float the tooltip next to an already rendered element
+    ///
+    /// The anchor element replaces the label, so only the tooltip bubble is
+    /// rendered, over the rest of the UI.
+    pub fn anchor(mut self, element_id: ElementId) -> Self {
+        self.anchor = Some(element_id);
+        self
+    }
+
     /// Convert to Element
     pub fn into_element(self) -> Element {
+        if let Some(target) = self.anchor {
+            return self.anchored_element(target);
+        }
+
         let label_elem = Text::new(&self.label).color(Color::White).into_element();
 
         if !self.visible || self.content.is_empty() {
             return label_elem;
         }
 
-        let tooltip_elem = RnkBox::new()
-            .padding_x(1.0)
-            .background(Color::Ansi256(240))
-            .child(Text::new(&self.content).color(Color::White).into_element())
-            .into_element();
+        let tooltip_elem = self.bubble();
 
         let (direction, children) = match self.position {
             TooltipPosition::Top => (FlexDirection::Column, vec![tooltip_elem, label_elem]),
@@ -101,6 +112,34 @@ impl Tooltip {
     }
 }
 
+impl Tooltip {
+    fn bubble(&self) -> Element {
+        RnkBox::new()
+            .padding_x(1.0)
+            .background(Color::Ansi256(240))
+            .child(Text::new(&self.content).color(Color::White).into_element())
+            .into_element()
+    }
+
+    fn anchored_element(self, target: ElementId) -> Element {
+        if !self.visible || self.content.is_empty() {
+            return RnkBox::new().into_element();
+        }
+
+        // Side placements keep the same one-cell gap as the inline layout
+        let (placement, gap) = match self.position {
+            TooltipPosition::Top => (AnchorPlacement::Top, 0),
+            TooltipPosition::Bottom => (AnchorPlacement::Bottom, 0),
+            TooltipPosition::Left => (AnchorPlacement::Left, 1),
+            TooltipPosition::Right => (AnchorPlacement::Right, 1),
+        };
+        RnkBox::new()
+            .anchor(Anchor::new(target).placement(placement).gap(gap))
+            .child(self.bubble())
+            .into_element()
+    }
+}
+
 impl Default for Tooltip {
     fn default() -> Self {
         Self::new("")
@@ -142,4 +181,30 @@ mod tests {
             .position(TooltipPosition::Right)
             .into_element();
     }
+
+    #[test]
+    fn test_tooltip_anchor_right_flips_left_at_edge() {
+        use crate::testing::TestRenderer;
+
+        let near_edge = Text::new("Save").into_element();
+        let tooltip = Tooltip::default().content("Ctrl+S").anchor(near_edge.id);
+
+        let root = RnkBox::new()
+            .flex_direction(FlexDirection::Column)
+            .child(
+                RnkBox::new()
+                    .padding_left(14.0)
+                    .child(near_edge)
+                    .into_element(),
+            )
+            .child(tooltip.into_element())
+            .into_element();
+
+        // No room on the right of "Save" in 20 columns, so it flips left
+        let output = TestRenderer::new(20, 1).render_to_plain(&root);
+        assert_eq!(
+            output.lines().next().unwrap().trim_end(),
+            "      Ctrl+S  Save"
+        );
+    }
 }
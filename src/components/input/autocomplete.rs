@@ -0,0 +1,551 @@
+//! Autocomplete component - text input with a dropdown of filtered suggestions
+//!
+//! `AutocompleteState` owns the query (a [`TextInputState`]), the current
+//! suggestions, and the highlighted entry. Suggestions come from a provider
+//! closure passed to [`handle_autocomplete_input`], so they can be computed
+//! from any source (static lists, history, file names, ...).
+//!
+//! # Example
+//!
+//! ```
+//! use rnk::components::{
+//!     Autocomplete, AutocompleteState, filter_suggestions, handle_autocomplete_input,
+//! };
+//! use rnk::hooks::Key;
+//!
+//! let fruits = ["apple", "apricot", "banana"];
+//! let provider = |query: &str| filter_suggestions(&fruits, query);
+//!
+//! let mut state = AutocompleteState::new();
+//! handle_autocomplete_input(&mut state, "a", &Key::default(), &provider);
+//! handle_autocomplete_input(&mut state, "p", &Key::default(), &provider);
+//! assert_eq!(state.suggestions(), ["apple", "apricot"]);
+//!
+//! let tab = Key { tab: true, ..Key::default() };
+//! handle_autocomplete_input(&mut state, "", &tab, &provider);
+//! assert_eq!(state.value(), "apple");
+//!
+//! let element = Autocomplete::new().state(state).into_element();
+//! ```
+
+use crate::components::{
+    Box, InteractionMode, InteractionOutcome, Text, TextInputOptions, TextInputState,
+    handle_text_input,
+};
+use crate::core::{Anchor, BorderStyle, Color, Element, FlexDirection};
+
+/// Autocomplete state: query, filtered suggestions, and highlighted index
+#[derive(Debug, Clone, Default)]
+pub struct AutocompleteState {
+    /// The text field
+    input: TextInputState,
+    /// Suggestions for the current query
+    suggestions: Vec<String>,
+    /// Highlighted suggestion, if any
+    highlighted: Option<usize>,
+    /// Whether the suggestion list is shown
+    open: bool,
+}
+
+impl AutocompleteState {
+    /// Create an empty state
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current query text
+    pub fn value(&self) -> &str {
+        self.input.value()
+    }
+
+    /// The underlying text field state
+    pub fn input(&self) -> &TextInputState {
+        &self.input
+    }
+
+    /// Suggestions for the current query
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+
+    /// Index of the highlighted suggestion
+    pub fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    /// The highlighted suggestion text
+    pub fn highlighted_suggestion(&self) -> Option<&str> {
+        self.highlighted
+            .and_then(|index| self.suggestions.get(index))
+            .map(String::as_str)
+    }
+
+    /// Whether the suggestion list is shown
+    pub fn is_open(&self) -> bool {
+        self.open && !self.suggestions.is_empty()
+    }
+
+    /// Replace the query without refreshing suggestions
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        self.input.set_value(value);
+    }
+
+    /// Recompute suggestions for the current query and show the list
+    pub fn refresh(&mut self, provider: &dyn Fn(&str) -> Vec<String>) {
+        self.suggestions = provider(self.input.value());
+        self.highlighted = None;
+        self.open = !self.suggestions.is_empty();
+    }
+
+    /// Highlight the next suggestion, wrapping to the first
+    pub fn highlight_next(&mut self) {
+        let len = self.suggestions.len();
+        if len == 0 {
+            return;
+        }
+        self.highlighted = Some(match self.highlighted {
+            Some(index) if index + 1 < len => index + 1,
+            _ => 0,
+        });
+    }
+
+    /// Highlight the previous suggestion, wrapping to the last
+    pub fn highlight_previous(&mut self) {
+        let len = self.suggestions.len();
+        if len == 0 {
+            return;
+        }
+        self.highlighted = Some(match self.highlighted {
+            Some(index) if index > 0 => index - 1,
+            _ => len - 1,
+        });
+    }
+
+    /// Accept the highlighted suggestion (or the first one) into the field
+    ///
+    /// Returns the accepted text and closes the list.
+    pub fn accept(&mut self) -> Option<String> {
+        if !self.is_open() {
+            return None;
+        }
+        let accepted = self.suggestions.get(self.highlighted.unwrap_or(0))?.clone();
+        self.input.set_value(accepted.clone());
+        self.dismiss();
+        Some(accepted)
+    }
+
+    /// Hide the suggestion list, keeping the query
+    pub fn dismiss(&mut self) {
+        self.open = false;
+        self.highlighted = None;
+    }
+}
+
+/// Case-insensitive suggestion filter: prefix matches first, then substrings
+///
+/// An empty query yields no suggestions.
+pub fn filter_suggestions<S: AsRef<str>>(candidates: &[S], query: &str) -> Vec<String> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let query = query.to_lowercase();
+    let (mut prefix, mut contains) = (Vec::new(), Vec::new());
+    for candidate in candidates {
+        let candidate = candidate.as_ref();
+        let lower = candidate.to_lowercase();
+        if lower.starts_with(&query) {
+            prefix.push(candidate.to_string());
+        } else if lower.contains(&query) {
+            contains.push(candidate.to_string());
+        }
+    }
+    prefix.extend(contains);
+    prefix
+}
+
+/// Autocomplete style
+#[derive(Debug, Clone)]
+pub struct AutocompleteStyle {
+    /// Field text color
+    pub text_color: Color,
+    /// Placeholder color
+    pub placeholder_color: Color,
+    /// Cursor color
+    pub cursor_color: Color,
+    /// Suggestion list border color
+    pub border_color: Color,
+    /// Suggestion text color
+    pub item_color: Color,
+    /// Highlighted suggestion foreground
+    pub highlighted_fg: Color,
+    /// Highlighted suggestion background
+    pub highlighted_bg: Color,
+    /// Maximum number of visible suggestions
+    pub max_visible: usize,
+}
+
+impl Default for AutocompleteStyle {
+    fn default() -> Self {
+        Self {
+            text_color: Color::White,
+            placeholder_color: Color::BrightBlack,
+            cursor_color: Color::Yellow,
+            border_color: Color::BrightBlack,
+            item_color: Color::White,
+            highlighted_fg: Color::Black,
+            highlighted_bg: Color::Cyan,
+            max_visible: 6,
+        }
+    }
+}
+
+impl AutocompleteStyle {
+    /// Create a new style
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set border color
+    pub fn border_color(mut self, color: Color) -> Self {
+        self.border_color = color;
+        self
+    }
+
+    /// Set highlighted suggestion colors
+    pub fn highlighted(mut self, fg: Color, bg: Color) -> Self {
+        self.highlighted_fg = fg;
+        self.highlighted_bg = bg;
+        self
+    }
+
+    /// Set maximum visible suggestions
+    pub fn max_visible(mut self, max: usize) -> Self {
+        self.max_visible = max.max(1);
+        self
+    }
+}
+
+/// Text input with a suggestion dropdown
+///
+/// The list is an overlay anchored below the field, so it covers whatever
+/// follows instead of pushing it down, and flips above the field when there
+/// is no room below it on screen.
+#[derive(Debug, Clone, Default)]
+pub struct Autocomplete {
+    state: AutocompleteState,
+    style: AutocompleteStyle,
+    placeholder: Option<String>,
+    focused: bool,
+}
+
+impl Autocomplete {
+    /// Create a new autocomplete
+    pub fn new() -> Self {
+        Self {
+            focused: true,
+            ..Self::default()
+        }
+    }
+
+    /// Set the state
+    pub fn state(mut self, state: AutocompleteState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Set the style
+    pub fn style(mut self, style: AutocompleteStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set placeholder text
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set whether the field shows its cursor
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    fn render_field(&self) -> Element {
+        let value = self.state.input.value();
+        if value.is_empty()
+            && let Some(placeholder) = &self.placeholder
+        {
+            return Text::new(placeholder)
+                .color(self.style.placeholder_color)
+                .dim()
+                .into_element();
+        }
+        if !self.focused {
+            return Text::new(value).color(self.style.text_color).into_element();
+        }
+
+        let chars: Vec<char> = value.chars().collect();
+        let cursor = self.state.input.cursor().min(chars.len());
+        let before: String = chars[..cursor].iter().collect();
+        let at = chars.get(cursor).copied().unwrap_or(' ');
+        let after: String = chars.iter().skip(cursor + 1).collect();
+
+        Box::new()
+            .flex_direction(FlexDirection::Row)
+            .child(
+                Text::new(before)
+                    .color(self.style.text_color)
+                    .into_element(),
+            )
+            .child(
+                Text::new(at.to_string())
+                    .color(Color::Black)
+                    .background(self.style.cursor_color)
+                    .into_element(),
+            )
+            .child(Text::new(after).color(self.style.text_color).into_element())
+            .into_element()
+    }
+
+    fn render_list(&self) -> Element {
+        let max_visible = self.style.max_visible;
+        let highlighted = self.state.highlighted;
+        // Keep the highlighted entry inside the visible window
+        let start = highlighted
+            .map(|index| (index + 1).saturating_sub(max_visible))
+            .unwrap_or(0);
+
+        let mut list = Box::new()
+            .flex_direction(FlexDirection::Column)
+            .border_style(BorderStyle::Single)
+            .border_color(self.style.border_color);
+        for (index, suggestion) in self
+            .state
+            .suggestions
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(max_visible)
+        {
+            let item = if highlighted == Some(index) {
+                Text::new(suggestion)
+                    .color(self.style.highlighted_fg)
+                    .background(self.style.highlighted_bg)
+            } else {
+                Text::new(suggestion).color(self.style.item_color)
+            };
+            list = list.child(item.into_element());
+        }
+        list.into_element()
+    }
+
+    /// Convert to element
+    pub fn into_element(self) -> Element {
+        let field = self.render_field();
+        let container = Box::new().flex_direction(FlexDirection::Column);
+        if !self.state.is_open() {
+            return container.child(field).into_element();
+        }
+
+        let list = Box::new()
+            .anchor(Anchor::new(field.id))
+            .child(self.render_list())
+            .into_element();
+        container.child(field).child(list).into_element()
+    }
+}
+
+/// Handle autocomplete editing, navigation, accept, and dismiss.
+///
+/// - Typing edits the query and refreshes suggestions from `provider`
+///   (`Changed` with the new query).
+/// - Up/Down move the highlight while the list is open; Down reopens it.
+/// - Tab accepts the highlighted (or first) suggestion; Enter accepts the
+///   highlighted suggestion, or submits the query when nothing is highlighted.
+/// - Escape dismisses the list, or cancels when it is already closed.
+pub fn handle_autocomplete_input(
+    state: &mut AutocompleteState,
+    input: &str,
+    key: &crate::hooks::Key,
+    provider: &dyn Fn(&str) -> Vec<String>,
+) -> InteractionOutcome<String> {
+    if key.escape {
+        if state.is_open() {
+            state.dismiss();
+            return InteractionOutcome::Handled;
+        }
+        return InteractionOutcome::Cancelled;
+    }
+
+    if key.down_arrow {
+        if !state.is_open() {
+            state.refresh(provider);
+        }
+        if state.is_open() {
+            state.highlight_next();
+            return InteractionOutcome::Handled;
+        }
+        return InteractionOutcome::Ignored;
+    }
+
+    if key.up_arrow {
+        if state.is_open() {
+            state.highlight_previous();
+            return InteractionOutcome::Handled;
+        }
+        return InteractionOutcome::Ignored;
+    }
+
+    if key.tab {
+        return match state.accept() {
+            Some(value) => InteractionOutcome::Changed(value),
+            None => InteractionOutcome::Ignored,
+        };
+    }
+
+    if key.return_key {
+        if state.highlighted.is_some()
+            && let Some(value) = state.accept()
+        {
+            return InteractionOutcome::Changed(value);
+        }
+        state.dismiss();
+        return InteractionOutcome::Submitted(state.value().to_string());
+    }
+
+    let options = TextInputOptions {
+        mode: InteractionMode::Enabled,
+        ..TextInputOptions::default()
+    };
+    let outcome = handle_text_input(&mut state.input, input, key, &options);
+    if outcome.is_changed() {
+        state.refresh(provider);
+    }
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::Key;
+
+    const LANGUAGES: [&str; 5] = ["rust", "ruby", "python", "go", "typescript"];
+
+    fn provider(query: &str) -> Vec<String> {
+        filter_suggestions(&LANGUAGES, query)
+    }
+
+    fn typed(text: &str) -> AutocompleteState {
+        let mut state = AutocompleteState::new();
+        for ch in text.chars() {
+            handle_autocomplete_input(&mut state, &ch.to_string(), &Key::default(), &provider);
+        }
+        state
+    }
+
+    #[test]
+    fn test_filter_suggestions_prefers_prefix() {
+        assert_eq!(
+            filter_suggestions(&LANGUAGES, "t"),
+            ["typescript", "rust", "python"]
+        );
+        assert_eq!(filter_suggestions(&LANGUAGES, "RU"), ["rust", "ruby"]);
+        assert!(filter_suggestions(&LANGUAGES, "").is_empty());
+    }
+
+    #[test]
+    fn test_typing_refreshes_suggestions() {
+        let state = typed("ru");
+        assert_eq!(state.value(), "ru");
+        assert_eq!(state.suggestions(), ["rust", "ruby"]);
+        assert!(state.is_open());
+        assert_eq!(state.highlighted(), None);
+    }
+
+    #[test]
+    fn test_arrow_navigation_wraps() {
+        let mut state = typed("ru");
+        let down = Key {
+            down_arrow: true,
+            ..Key::default()
+        };
+        let up = Key {
+            up_arrow: true,
+            ..Key::default()
+        };
+
+        handle_autocomplete_input(&mut state, "", &down, &provider);
+        assert_eq!(state.highlighted_suggestion(), Some("rust"));
+        handle_autocomplete_input(&mut state, "", &down, &provider);
+        handle_autocomplete_input(&mut state, "", &down, &provider);
+        assert_eq!(state.highlighted_suggestion(), Some("rust"));
+        handle_autocomplete_input(&mut state, "", &up, &provider);
+        assert_eq!(state.highlighted_suggestion(), Some("ruby"));
+    }
+
+    #[test]
+    fn test_enter_accepts_highlight_or_submits_query() {
+        let enter = Key {
+            return_key: true,
+            ..Key::default()
+        };
+        let mut state = typed("ru");
+        assert_eq!(
+            handle_autocomplete_input(&mut state, "", &enter, &provider),
+            InteractionOutcome::Submitted("ru".to_string())
+        );
+
+        let mut state = typed("ru");
+        state.highlight_previous();
+        assert_eq!(
+            handle_autocomplete_input(&mut state, "", &enter, &provider),
+            InteractionOutcome::Changed("ruby".to_string())
+        );
+        assert_eq!(state.value(), "ruby");
+        assert!(!state.is_open());
+    }
+
+    #[test]
+    fn test_escape_dismisses_then_cancels() {
+        let escape = Key {
+            escape: true,
+            ..Key::default()
+        };
+        let mut state = typed("py");
+        assert_eq!(
+            handle_autocomplete_input(&mut state, "", &escape, &provider),
+            InteractionOutcome::Handled
+        );
+        assert!(!state.is_open());
+        assert_eq!(state.value(), "py");
+        assert_eq!(
+            handle_autocomplete_input(&mut state, "", &escape, &provider),
+            InteractionOutcome::Cancelled
+        );
+    }
+
+    #[test]
+    fn test_list_renders_below_and_flips_above() {
+        use crate::testing::TestRenderer;
+
+        let state = typed("ru");
+        let renderer = TestRenderer::new(20, 8);
+
+        let below =
+            renderer.render_to_plain(&Autocomplete::new().state(state.clone()).into_element());
+        let lines: Vec<&str> = below.lines().collect();
+        assert!(lines[0].starts_with("ru"));
+        assert!(lines[2].contains("rust"));
+
+        // A field on the last row has no room below, so the list flips
+        let cramped = Box::new()
+            .padding_top(7.0)
+            .child(Autocomplete::new().state(state).into_element())
+            .into_element();
+        let above = renderer.render_to_plain(&cramped);
+        let lines: Vec<&str> = above.lines().collect();
+        assert!(lines[4].contains("rust"));
+        assert!(lines[7].starts_with("ru"));
+    }
+}
@@ -2,7 +2,7 @@
 //!
 //! A simple code display/editor component for terminal UIs.
 
-use crate::components::{Box as RnkBox, Text};
+use crate::components::{Box as RnkBox, Text, TextAreaState};
 use crate::core::{Color, Element, FlexDirection};
 
 /// Syntax highlighting language
@@ -35,8 +35,8 @@ pub struct CodeEditor {
     start_line: usize,
     /// Highlighted line (1-indexed)
     highlighted_line: Option<usize>,
-    /// Cursor position (line, column) - 1-indexed
-    cursor: Option<(usize, usize)>,
+    /// Cursor positions (line, column) - 1-indexed
+    cursors: Vec<(usize, usize)>,
     /// Line number color
     line_number_color: Color,
     /// Highlighted line background
@@ -62,7 +62,7 @@ impl CodeEditor {
             show_line_numbers: true,
             start_line: 1,
             highlighted_line: None,
-            cursor: None,
+            cursors: Vec::new(),
             line_number_color: Color::BrightBlack,
             highlight_color: Color::Ansi256(236),
             keyword_color: Color::Magenta,
@@ -97,9 +97,24 @@ impl CodeEditor {
         self
     }
 
+    /// Create an editor showing a textarea's content and all of its cursors
+    pub fn from_state(state: &TextAreaState) -> Self {
+        let cursors = state
+            .cursors()
+            .into_iter()
+            .map(|pos| (pos.row + 1, pos.col + 1));
+        Self::new(state.content()).cursors(cursors)
+    }
+
     /// Set cursor position (line, column) - 1-indexed
     pub fn cursor(mut self, line: usize, column: usize) -> Self {
-        self.cursor = Some((line, column));
+        self.cursors = vec![(line, column)];
+        self
+    }
+
+    /// Set several cursor positions (line, column) - 1-indexed
+    pub fn cursors(mut self, cursors: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        self.cursors = cursors.into_iter().collect();
         self
     }
 
@@ -150,7 +165,7 @@ impl CodeEditor {
         for (i, line) in lines.iter().enumerate() {
             let line_num = self.start_line + i;
             let is_highlighted = self.highlighted_line == Some(line_num);
-            let has_cursor = self.cursor.map(|(l, _)| l == line_num).unwrap_or(false);
+            let has_cursor = self.cursors.iter().any(|&(l, _)| l == line_num);
 
             let mut row_children = Vec::new();
 
@@ -474,6 +489,8 @@ impl Default for CodeEditor {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::components::TextAreaPosition;
+    use crate::testing::TestRenderer;
 
     #[test]
     fn test_code_editor_creation() {
@@ -508,7 +525,7 @@ mod tests {
     #[test]
     fn test_cursor() {
         let editor = CodeEditor::new("code").cursor(1, 5).highlighted_line(1);
-        assert_eq!(editor.cursor, Some((1, 5)));
+        assert_eq!(editor.cursors, vec![(1, 5)]);
         assert_eq!(editor.highlighted_line, Some(1));
     }
 
@@ -518,4 +535,17 @@ mod tests {
         let editor = CodeEditor::new(json).language(Language::Json);
         let _ = editor.into_element();
     }
+
+    #[test]
+    fn test_from_state_marks_every_cursor_line() {
+        let mut state = TextAreaState::with_content("a\nb\nc");
+        state.add_cursor(TextAreaPosition::new(2, 0));
+
+        let editor = CodeEditor::from_state(&state);
+        assert_eq!(editor.cursors, vec![(1, 1), (3, 1)]);
+
+        let output = TestRenderer::new(20, 3).render_to_plain(&editor.into_element());
+        let marked = output.lines().filter(|l| l.contains('◂')).count();
+        assert_eq!(marked, 2);
+    }
 }
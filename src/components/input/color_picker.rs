@@ -16,6 +16,7 @@
 //! }
 //! ```
 
+use crate::components::theme::ThemeBuilder;
 use crate::components::{Box, InteractionMode, InteractionOutcome, Text};
 use crate::core::{AccessibilityProps, AccessibilityRole, Color, Element, FlexDirection};
 
@@ -28,6 +29,12 @@ pub struct ColorPalette {
     pub name: String,
 }
 
+/// Semantic hues used by [`ColorPalette::from_base`]: success, warning, error, info
+const SEMANTIC_HUES: [f64; 4] = [130.0, 40.0, 0.0, 205.0];
+
+/// Lightness steps for the tint/shade ramp, lightest first
+const RAMP_LIGHTNESS: [f64; 5] = [0.85, 0.7, 0.55, 0.4, 0.25];
+
 impl ColorPalette {
     /// Create a new palette
     pub fn new(name: impl Into<String>, colors: Vec<Color>) -> Self {
@@ -129,6 +136,70 @@ impl ColorPalette {
             ],
         )
     }
+
+    /// Generate a palette from a single seed color in HSL space
+    ///
+    /// The result is deterministic and laid out by role, so it can feed
+    /// [`theme_builder`](Self::theme_builder) directly:
+    ///
+    /// | Index | Color |
+    /// |-------|-------|
+    /// | 0 | base (primary) |
+    /// | 1 | complement (secondary) |
+    /// | 2–5 | success, warning, error, info at the base's saturation/lightness |
+    /// | 6–7 | analogous hues (−30°, +30°) |
+    /// | 8–9 | triadic hues (+120°, +240°) |
+    /// | 10–14 | tints to shades of the base hue, lightest first |
+    pub fn from_base(base: Color) -> Self {
+        let (hue, saturation, lightness) = base.to_hsl();
+        // Keep semantic colors recognizable even for gray or extreme seeds
+        let accent_saturation = saturation.max(0.45);
+        let accent_lightness = lightness.clamp(0.35, 0.65);
+
+        let mut colors = vec![
+            Color::from_hsl(hue, saturation, lightness),
+            Color::from_hsl(hue + 180.0, saturation, lightness),
+        ];
+        colors.extend(
+            SEMANTIC_HUES
+                .iter()
+                .map(|&h| Color::from_hsl(h, accent_saturation, accent_lightness)),
+        );
+        colors.extend(
+            [-30.0, 30.0, 120.0, 240.0]
+                .iter()
+                .map(|offset| Color::from_hsl(hue + offset, saturation, lightness)),
+        );
+        colors.extend(
+            RAMP_LIGHTNESS
+                .iter()
+                .map(|&l| Color::from_hsl(hue, saturation, l)),
+        );
+
+        Self::new("Generated", colors)
+    }
+
+    /// Start a [`ThemeBuilder`] whose semantic colors come from this palette
+    ///
+    /// The first six colors become primary, secondary, success, warning,
+    /// error and info, in that order. Missing entries keep the builder's
+    /// defaults.
+    pub fn theme_builder(&self, name: impl Into<String>) -> ThemeBuilder {
+        let setters: [fn(ThemeBuilder, Color) -> ThemeBuilder; 6] = [
+            ThemeBuilder::primary,
+            ThemeBuilder::secondary,
+            ThemeBuilder::success,
+            ThemeBuilder::warning,
+            ThemeBuilder::error,
+            ThemeBuilder::info,
+        ];
+        setters
+            .iter()
+            .zip(&self.colors)
+            .fold(ThemeBuilder::new(name), |builder, (set, &color)| {
+                set(builder, color)
+            })
+    }
 }
 
 impl Default for ColorPalette {
@@ -480,6 +551,42 @@ mod tests {
         assert_eq!(palette.colors.len(), 16);
     }
 
+    #[test]
+    fn test_color_palette_from_base_layout() {
+        let base = Color::Rgb(30, 144, 255);
+        let palette = ColorPalette::from_base(base);
+
+        assert_eq!(palette.colors.len(), 15);
+        assert_eq!(palette.colors[0], base);
+
+        let (hue, _, _) = base.to_hsl();
+        let (complement, _, _) = palette.colors[1].to_hsl();
+        assert!(((complement - hue).rem_euclid(360.0) - 180.0).abs() < 1.0);
+
+        // Tint/shade ramp keeps the base hue and gets darker
+        let ramp: Vec<f64> = palette.colors[10..].iter().map(|c| c.to_hsl().2).collect();
+        assert!(ramp.windows(2).all(|w| w[0] > w[1]));
+    }
+
+    #[test]
+    fn test_color_palette_from_base_is_deterministic() {
+        let a = ColorPalette::from_base(Color::Magenta);
+        let b = ColorPalette::from_base(Color::Magenta);
+        assert_eq!(a.colors, b.colors);
+    }
+
+    #[test]
+    fn test_color_palette_theme_builder() {
+        let palette = ColorPalette::from_base(Color::Rgb(200, 80, 40));
+        let theme = palette.theme_builder("seeded").build();
+
+        assert_eq!(theme.name, "seeded");
+        assert_eq!(theme.primary, palette.colors[0]);
+        assert_eq!(theme.secondary, palette.colors[1]);
+        assert_eq!(theme.success, palette.colors[2]);
+        assert_eq!(theme.info, palette.colors[5]);
+    }
+
     #[test]
     fn test_color_palette_grayscale() {
         let palette = ColorPalette::grayscale();
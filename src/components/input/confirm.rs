@@ -7,6 +7,7 @@
 //! - Yes/No confirmation
 //! - Customizable button labels
 //! - Keyboard navigation (Tab, Enter, Y/N)
+//! - Access keys from `&`-marked labels (`"&Save"`), underlined when rendered
 //! - Customizable styling
 //!
 //! # Example
@@ -35,11 +36,12 @@
 //! }
 //! ```
 
+use crate::components::action::input_char;
 use crate::components::{
-    ActionButton, ActionRole, ActionShape, ActionState, Box as RnkBox, InteractionMode,
-    InteractionOutcome, Text, Theme, get_theme,
+    ActionButton, ActionRole, ActionShape, ActionState, Box as RnkBox, FocusStyle, InteractionMode,
+    InteractionOutcome, Mnemonic, Text, Theme, get_theme,
 };
-use crate::core::{AccessibilityProps, AccessibilityRole, Color, Element, FlexDirection};
+use crate::core::{AccessibilityProps, AccessibilityRole, Color, Element, FlexDirection, Style};
 
 /// Confirm dialog state
 #[derive(Debug, Clone)]
@@ -68,10 +70,9 @@ impl ConfirmState {
     /// Create with default set to Yes
     pub fn default_yes(prompt: impl Into<String>) -> Self {
         Self {
-            prompt: prompt.into(),
             focused_yes: true,
-            result: None,
             default: true,
+            ..Self::new(prompt)
         }
     }
 
@@ -256,12 +257,28 @@ impl ConfirmStyle {
     }
 
     /// Set both labels
+    ///
+    /// Each label's access key is the character marked with `&` (`"&Save"`),
+    /// or else its first letter; it is underlined when rendered. Pass the
+    /// style to [`handle_confirm_input_with_style`] so the keys are bound.
     pub fn labels(mut self, yes: impl Into<String>, no: impl Into<String>) -> Self {
         self.yes_label = yes.into();
         self.no_label = no.into();
         self
     }
 
+    /// Get the access keys for Yes and No, derived from the labels
+    ///
+    /// With the default labels these are `y` and `n`. When both buttons
+    /// share a key, pressing it cycles focus instead of answering.
+    pub fn access_keys(&self) -> (char, char) {
+        let key = |label: &str| Mnemonic::parse(label).or_first_letter().key();
+        (
+            key(&self.yes_label).unwrap_or('y'),
+            key(&self.no_label).unwrap_or('n'),
+        )
+    }
+
     /// Set separator
     pub fn separator(mut self, sep: impl Into<String>) -> Self {
         self.separator = sep.into();
@@ -305,17 +322,24 @@ impl ConfirmStyle {
     }
 
     /// Format a button label
-    fn format_button(&self, label: &str, hint: Option<char>) -> String {
-        ActionShape::from(self.button_style).format_label(label, hint, self.show_hints)
+    fn format_button(&self, label: &str, key: char) -> String {
+        let (label, _) = button_label(label, key);
+        ActionShape::from(self.button_style).format_label(
+            &label,
+            Some(key.to_uppercase().next().unwrap_or(key)),
+            self.show_hints,
+        )
     }
 
-    fn action_text(&self, label: &str, hint: char, role: ActionRole, state: ActionState) -> Text {
+    fn action_text(&self, label: &str, key: char, role: ActionRole, state: ActionState) -> Text {
+        let (label, index) = button_label(label, key);
         let mut text = ActionButton::new(label)
             .role(role)
             .state(state)
             .shape(self.button_style.into())
-            .hint(Some(hint))
+            .hint(Some(key.to_uppercase().next().unwrap_or(key)))
             .show_hint(self.show_hints)
+            .mnemonic(index)
             .into_text();
 
         if state == ActionState::Focused {
@@ -354,6 +378,21 @@ impl ConfirmStyle {
     }
 }
 
+/// A button's displayed label and where its access key `key` sits in it,
+/// preferring the `&`-marked character.
+fn button_label(label: &str, key: char) -> (String, Option<usize>) {
+    let mnemonic = Mnemonic::parse(label);
+    let index = if mnemonic.key() == Some(key) {
+        mnemonic.index()
+    } else {
+        mnemonic
+            .label()
+            .chars()
+            .position(|c| c.to_lowercase().next() == Some(key))
+    };
+    (mnemonic.label().to_string(), index)
+}
+
 /// Confirm dialog component
 #[derive(Debug, Clone)]
 pub struct Confirm<'a> {
@@ -363,6 +402,8 @@ pub struct Confirm<'a> {
     style: ConfirmStyle,
     /// Whether the dialog is focused
     focused: bool,
+    /// Focus ring drawn around the dialog
+    focus_ring: Option<FocusStyle>,
 }
 
 impl<'a> Confirm<'a> {
@@ -372,7 +413,47 @@ impl<'a> Confirm<'a> {
             state,
             style: ConfirmStyle::from_theme(&get_theme()),
             focused: true,
+            focus_ring: None,
+        }
+    }
+
+    /// Draw a focus ring around the dialog, e.g. `get_theme().focus_style()`
+    ///
+    /// The focused button label also takes the ring's bold/brighten emphasis.
+    pub fn focus_ring(mut self, focus: FocusStyle) -> Self {
+        self.focus_ring = Some(focus);
+        self
+    }
+
+    /// Apply the focus ring's label emphasis to a focused button
+    fn emphasize(&self, text: Text, state: ActionState) -> Element {
+        let mut element = text.into_element();
+        let Some(ring) = &self.focus_ring else {
+            return element;
+        };
+        if state != ActionState::Focused {
+            return element;
         }
+        let label = ring.label_style(
+            Style {
+                color: self.style.focused_color.or(element.style.color),
+                ..Style::new()
+            },
+            true,
+        );
+        // Override span colors too; `Text::color` keeps colors already set
+        let spans = element.spans.iter_mut().flatten();
+        for style in spans
+            .flat_map(|line| line.spans.iter_mut())
+            .map(|span| &mut span.style)
+            .chain(std::iter::once(&mut element.style))
+        {
+            if label.color.is_some() {
+                style.color = label.color;
+            }
+            style.bold |= label.bold;
+        }
+        element
     }
 
     /// Set the style
@@ -401,8 +482,10 @@ impl<'a> Confirm<'a> {
 
     /// Render as string (for non-TUI usage)
     pub fn render(&self) -> String {
-        let yes_btn = self.style.format_button(&self.style.yes_label, Some('Y'));
-        let no_btn = self.style.format_button(&self.style.no_label, Some('N'));
+        let (yes_label, no_label) = (&self.style.yes_label, &self.style.no_label);
+        let (yes_key, no_key) = self.style.access_keys();
+        let yes_btn = self.style.format_button(yes_label, yes_key);
+        let no_btn = self.style.format_button(no_label, no_key);
 
         format!(
             "{} {}{}{}",
@@ -423,16 +506,18 @@ impl<'a> Confirm<'a> {
 
         // Buttons row
         let mut buttons = RnkBox::new().flex_direction(FlexDirection::Row);
+        let (yes_label, no_label) = (&self.style.yes_label, &self.style.no_label);
+        let (yes_key, no_key) = self.style.access_keys();
 
         let yes_state = if self.focused && self.state.is_yes_focused() {
             ActionState::Focused
         } else {
             ActionState::Rest
         };
-        let yes_text =
-            self.style
-                .action_text(&self.style.yes_label, 'Y', ActionRole::Primary, yes_state);
-        buttons = buttons.child(yes_text.into_element());
+        let yes_text = self
+            .style
+            .action_text(yes_label, yes_key, ActionRole::Primary, yes_state);
+        buttons = buttons.child(self.emphasize(yes_text, yes_state));
 
         // Separator
         buttons = buttons.child(Text::new(&self.style.separator).into_element());
@@ -442,19 +527,24 @@ impl<'a> Confirm<'a> {
         } else {
             ActionState::Rest
         };
-        let no_text =
-            self.style
-                .action_text(&self.style.no_label, 'N', ActionRole::Secondary, no_state);
-        buttons = buttons.child(no_text.into_element());
+        let no_text = self
+            .style
+            .action_text(no_label, no_key, ActionRole::Secondary, no_state);
+        buttons = buttons.child(self.emphasize(no_text, no_state));
 
         container = container.child(buttons.into_element());
 
-        container.into_element().with_accessibility(
+        let element = match &self.focus_ring {
+            Some(ring) => ring.wrap(container.into_element(), self.focused),
+            None => container.into_element(),
+        };
+        element.with_accessibility(
             AccessibilityProps::new(AccessibilityRole::Dialog)
                 .label(self.state.prompt.clone())
                 .description(format!(
                     "{} or {}",
-                    self.style.yes_label, self.style.no_label
+                    Mnemonic::parse(yes_label).label(),
+                    Mnemonic::parse(no_label).label()
                 ))
                 .focusable(self.focused),
         )
@@ -462,6 +552,9 @@ impl<'a> Confirm<'a> {
 }
 
 /// Handle confirm dialog input
+///
+/// Answers with `y` and `n`; use [`handle_confirm_input_with_style`] when
+/// the dialog has custom labels.
 pub fn handle_confirm_input(
     state: &mut ConfirmState,
     input: &str,
@@ -476,6 +569,30 @@ pub fn handle_confirm_input_with_mode(
     input: &str,
     key: &crate::hooks::Key,
     mode: InteractionMode,
+) -> InteractionOutcome<bool> {
+    confirm_input(state, input, key, mode, ('y', 'n'))
+}
+
+/// Handle confirm dialog input with the access keys of `style`'s labels
+///
+/// Pass the same style given to [`Confirm::style`] so the underlined keys
+/// are the ones that answer.
+pub fn handle_confirm_input_with_style(
+    state: &mut ConfirmState,
+    style: &ConfirmStyle,
+    input: &str,
+    key: &crate::hooks::Key,
+    mode: InteractionMode,
+) -> InteractionOutcome<bool> {
+    confirm_input(state, input, key, mode, style.access_keys())
+}
+
+fn confirm_input(
+    state: &mut ConfirmState,
+    input: &str,
+    key: &crate::hooks::Key,
+    mode: InteractionMode,
+    (yes_key, no_key): (char, char),
 ) -> InteractionOutcome<bool> {
     if mode.is_disabled() {
         return InteractionOutcome::Ignored;
@@ -492,7 +609,15 @@ pub fn handle_confirm_input_with_mode(
         return InteractionOutcome::Handled;
     }
 
-    if key.escape || input.eq_ignore_ascii_case("n") {
+    let pressed = input_char(input);
+
+    // Shared access key: cycle focus between the two buttons
+    if yes_key == no_key && pressed == Some(yes_key) {
+        state.toggle_focus();
+        return InteractionOutcome::Handled;
+    }
+
+    if key.escape || pressed == Some(no_key) {
         if mode.is_read_only() {
             return InteractionOutcome::Cancelled;
         }
@@ -509,8 +634,8 @@ pub fn handle_confirm_input_with_mode(
         state.submit();
         return InteractionOutcome::Submitted(state.result().unwrap_or(false));
     }
-    // Y for yes
-    if input.eq_ignore_ascii_case("y") {
+    // Y (or the Yes access key) for yes
+    if pressed == Some(yes_key) {
         state.confirm();
         return InteractionOutcome::Submitted(true);
     }
@@ -664,4 +789,76 @@ mod tests {
         assert_eq!(outcome, InteractionOutcome::Submitted(true));
         assert!(state.is_confirmed());
     }
+
+    #[test]
+    fn test_confirm_mnemonic_labels() {
+        let mut state = ConfirmState::new("Unsaved changes");
+        let style = ConfirmStyle::default().labels("Sa&ve", "Discard");
+        assert_eq!(style.access_keys(), ('v', 'd'));
+        assert_eq!(ConfirmStyle::default().access_keys(), ('y', 'n'));
+
+        let rendered = Confirm::new(&state).style(style.clone()).render();
+        assert!(rendered.contains("[Save](V)"), "{rendered}");
+        assert!(rendered.contains("[Discard](D)"), "{rendered}");
+
+        let key = crate::hooks::Key::default();
+        let enabled = InteractionMode::Enabled;
+        let outcome = handle_confirm_input_with_style(&mut state, &style, "y", &key, enabled);
+        assert_eq!(outcome, InteractionOutcome::Ignored);
+        let outcome = handle_confirm_input_with_style(&mut state, &style, "D", &key, enabled);
+        assert_eq!(outcome, InteractionOutcome::Cancelled);
+        assert!(state.is_cancelled());
+    }
+
+    #[test]
+    fn test_confirm_style_label_mnemonics_are_underlined() {
+        let state = ConfirmState::new("Unsaved changes");
+        let style = ConfirmStyle::default().labels("&Save", "&Discard");
+        assert_eq!(style.access_keys(), ('s', 'd'));
+
+        let element = Confirm::new(&state).style(style).into_element();
+        let buttons = element.children.get(1).unwrap();
+        let underlined: Vec<String> = [0, 2]
+            .into_iter()
+            .flat_map(|i| buttons.children.get(i).unwrap().spans.clone().unwrap())
+            .flat_map(|line| line.spans)
+            .filter(|span| span.style.underline)
+            .map(|span| span.content)
+            .collect();
+        assert_eq!(underlined, vec!["S", "D"]);
+    }
+
+    #[test]
+    fn test_confirm_shared_access_key_cycles() {
+        let mut state = ConfirmState::new("Continue?");
+        let style = ConfirmStyle::default().labels("&Save", "&Skip");
+        let key = crate::hooks::Key::default();
+        let press = |state: &mut ConfirmState| {
+            handle_confirm_input_with_style(state, &style, "s", &key, InteractionMode::Enabled)
+        };
+
+        assert_eq!(press(&mut state), InteractionOutcome::Handled);
+        assert!(state.is_yes_focused());
+        assert_eq!(press(&mut state), InteractionOutcome::Handled);
+        assert!(state.is_no_focused());
+        assert!(!state.is_answered());
+    }
+
+    #[test]
+    fn test_confirm_focus_ring_brightens_focused_button() {
+        let state = ConfirmState::new("Delete?");
+        let ring = Theme::dark().focus_style().brighten(true);
+        let element = Confirm::new(&state)
+            .style(ConfirmStyle::default().focused_color(Color::Cyan))
+            .focus_ring(ring)
+            .into_element();
+
+        assert_eq!(element.style.border_style, ring.focused_border);
+        let dialog = element.children.get(0).unwrap();
+        let buttons = dialog.children.get(1).unwrap();
+        // "No" is focused by default
+        let no = buttons.children.get(2).unwrap();
+        assert_eq!(no.style.color, Some(Color::BrightCyan));
+        assert!(no.style.bold);
+    }
 }
@@ -9,8 +9,10 @@
 //! use rnk::components::ContextMenu;
 //!
 //! fn app() -> Element {
+//!     // Access keys default to the first letter; `&` picks another one,
+//!     // so pressing `t`, `c` or `p` activates the item
 //!     let items = vec![
-//!         MenuItem::new("cut", "Cut").shortcut("Ctrl+X"),
+//!         MenuItem::new("cut", "Cu&t").shortcut("Ctrl+X"),
 //!         MenuItem::new("copy", "Copy").shortcut("Ctrl+C"),
 //!         MenuItem::separator(),
 //!         MenuItem::new("paste", "Paste").shortcut("Ctrl+V"),
@@ -20,8 +22,10 @@
 //! }
 //! ```
 
-use crate::components::{Box, Text};
+use crate::components::action::{input_char, next_mnemonic_match, underline_char};
+use crate::components::{Box, InteractionOutcome, Mnemonic, Text};
 use crate::core::{Color, Element, FlexDirection};
+use crate::hooks::Key;
 
 /// A menu item
 #[derive(Debug, Clone)]
@@ -30,7 +34,7 @@ pub enum MenuItem {
     Action {
         /// Unique ID
         id: String,
-        /// Display label
+        /// Display label; an `&` marks the access key
         label: String,
         /// Optional keyboard shortcut
         shortcut: Option<String>,
@@ -43,7 +47,7 @@ pub enum MenuItem {
     Separator,
     /// A submenu
     Submenu {
-        /// Display label
+        /// Display label; an `&` marks the access key
         label: String,
         /// Submenu items
         items: Vec<MenuItem>,
@@ -52,6 +56,10 @@ pub enum MenuItem {
 
 impl MenuItem {
     /// Create a new action item
+    ///
+    /// The access key is the label's first letter, unless an `&` marks the
+    /// following character instead (`"Save &As"`); use `&&` for a literal
+    /// ampersand.
     pub fn new(id: impl Into<String>, label: impl Into<String>) -> Self {
         Self::Action {
             id: id.into(),
@@ -68,6 +76,8 @@ impl MenuItem {
     }
 
     /// Create a submenu
+    ///
+    /// Its access key is picked as for [`MenuItem::new`].
     pub fn submenu(label: impl Into<String>, items: Vec<MenuItem>) -> Self {
         Self::Submenu {
             label: label.into(),
@@ -128,13 +138,29 @@ impl MenuItem {
         }
     }
 
-    /// Get the label
+    /// Get the label as given, including any `&` marker
     pub fn label(&self) -> Option<&str> {
         match self {
             Self::Action { label, .. } | Self::Submenu { label, .. } => Some(label),
             Self::Separator => None,
         }
     }
+
+    /// Get the access key (lowercase), if the label has a letter or digit
+    pub fn mnemonic(&self) -> Option<char> {
+        self.parsed_label().and_then(|mnemonic| mnemonic.key())
+    }
+
+    /// The label with its `&` marker stripped and access key resolved
+    fn parsed_label(&self) -> Option<Mnemonic> {
+        self.label()
+            .map(|label| Mnemonic::parse(label).or_first_letter())
+    }
+
+    /// Check if this is a disabled action
+    fn is_disabled(&self) -> bool {
+        matches!(self, Self::Action { disabled: true, .. })
+    }
 }
 
 /// Context menu state
@@ -209,6 +235,79 @@ impl ContextMenuState {
             }
         }
     }
+
+    /// Jump to the item with the given access key
+    ///
+    /// Repeated presses cycle through items sharing the key. Returns the
+    /// selected index and whether it was the only match.
+    pub fn select_mnemonic(&mut self, items: &[MenuItem], key: char) -> Option<(usize, bool)> {
+        let key = key.to_lowercase().next().unwrap_or(key);
+        let keys = items
+            .iter()
+            .map(|item| item.mnemonic().filter(|_| !item.is_disabled()));
+        let (index, unique) = next_mnemonic_match(keys, self.selected, key)?;
+        self.selected = index;
+        Some((index, unique))
+    }
+}
+
+/// Handle context menu input: arrows, Enter, Escape and access keys
+///
+/// An access key (bare letter or Alt+letter) that matches a single action
+/// activates it; ambiguous keys cycle the selection. Submitted values are
+/// the activated item's id.
+pub fn handle_context_menu_input(
+    state: &mut ContextMenuState,
+    items: &[MenuItem],
+    input: &str,
+    key: &Key,
+) -> InteractionOutcome<String> {
+    if !state.open {
+        return InteractionOutcome::Ignored;
+    }
+
+    if key.escape {
+        state.close();
+        return InteractionOutcome::Cancelled;
+    }
+    if key.up_arrow {
+        state.select_prev(items);
+        return InteractionOutcome::Handled;
+    }
+    if key.down_arrow {
+        state.select_next(items);
+        return InteractionOutcome::Handled;
+    }
+    if key.return_key {
+        return activate(state, items);
+    }
+
+    if let Some(pressed) = input_char(input)
+        && let Some((_, unique)) = state.select_mnemonic(items, pressed)
+    {
+        if unique {
+            return activate(state, items);
+        }
+        return InteractionOutcome::Handled;
+    }
+
+    InteractionOutcome::Ignored
+}
+
+fn activate(state: &mut ContextMenuState, items: &[MenuItem]) -> InteractionOutcome<String> {
+    match items.get(state.selected) {
+        Some(MenuItem::Action {
+            id,
+            disabled: false,
+            ..
+        }) => {
+            let id = id.clone();
+            state.close();
+            InteractionOutcome::Submitted(id)
+        }
+        Some(MenuItem::Submenu { .. }) => InteractionOutcome::Handled,
+        _ => InteractionOutcome::Ignored,
+    }
 }
 
 /// Context menu style
@@ -319,6 +418,7 @@ impl ContextMenu {
     /// Render a menu item
     fn render_item(&self, item: &MenuItem, is_selected: bool, index: usize) -> Element {
         let padding = " ".repeat(self.style.padding);
+        let mnemonic = item.parsed_label().unwrap_or_else(|| Mnemonic::parse(""));
 
         match item {
             MenuItem::Separator => {
@@ -328,7 +428,6 @@ impl ContextMenu {
                     .into_element()
             }
             MenuItem::Action {
-                label,
                 shortcut,
                 disabled,
                 icon,
@@ -352,7 +451,8 @@ impl ContextMenu {
                 }
 
                 // Label
-                line.push_str(label);
+                let label_start = line.len();
+                line.push_str(mnemonic.label());
 
                 // Shortcut (right-aligned)
                 if let Some(shortcut) = shortcut {
@@ -368,8 +468,10 @@ impl ContextMenu {
                 line.push_str(&padding);
 
                 // Truncate if needed
+                let mut visible_end = line.len();
                 if line.len() > self.style.width {
                     line.truncate(self.style.width - 3);
+                    visible_end = line.len();
                     line.push_str("...");
                 }
 
@@ -386,9 +488,12 @@ impl ContextMenu {
                     (self.style.text_color, self.style.background)
                 };
 
-                Text::new(line).color(fg).background(bg).into_element()
+                label_text(&line, label_start, visible_end, mnemonic.index())
+                    .color(fg)
+                    .background(bg)
+                    .into_element()
             }
-            MenuItem::Submenu { label, .. } => {
+            MenuItem::Submenu { .. } => {
                 let mut line = String::new();
 
                 // Selection indicator
@@ -399,7 +504,9 @@ impl ContextMenu {
                 }
 
                 line.push_str(&padding);
-                line.push_str(label);
+                let label_start = line.len();
+                line.push_str(mnemonic.label());
+                let visible_end = line.len();
 
                 // Submenu arrow (right-aligned)
                 let arrow = "▶";
@@ -416,7 +523,10 @@ impl ContextMenu {
                     (self.style.text_color, self.style.background)
                 };
 
-                Text::new(line).color(fg).background(bg).into_element()
+                label_text(&line, label_start, visible_end, mnemonic.index())
+                    .color(fg)
+                    .background(bg)
+                    .into_element()
             }
         }
     }
@@ -455,6 +565,18 @@ impl ContextMenu {
     }
 }
 
+/// Build a menu row, underlining the access key if it survived truncation.
+fn label_text(line: &str, label_start: usize, visible_end: usize, mnemonic: Option<usize>) -> Text {
+    let visible = mnemonic.filter(|&index| {
+        line.get(label_start..visible_end)
+            .is_some_and(|label| index < label.chars().count())
+    });
+    match visible {
+        Some(_) => Text::spans(underline_char(line, label_start, visible)),
+        None => Text::new(line),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -555,6 +677,82 @@ mod tests {
         let _ = menu.into_element();
     }
 
+    #[test]
+    fn test_menu_item_mnemonic() {
+        let item = MenuItem::new("save_as", "Save &As");
+        assert_eq!(item.label(), Some("Save &As"));
+        assert_eq!(item.mnemonic(), Some('a'));
+        assert_eq!(MenuItem::new("x", "Plain").mnemonic(), Some('p'));
+        assert_eq!(MenuItem::new("x", "→ 2x").mnemonic(), Some('2'));
+        assert_eq!(MenuItem::new("x", "…").mnemonic(), None);
+        assert_eq!(MenuItem::submenu("&Edit", vec![]).mnemonic(), Some('e'));
+
+        // Items built as literals get access keys the same way
+        let item = MenuItem::Action {
+            id: "open".to_string(),
+            label: "Re&open".to_string(),
+            shortcut: None,
+            disabled: false,
+            icon: None,
+        };
+        assert_eq!(item.mnemonic(), Some('o'));
+    }
+
+    #[test]
+    fn test_context_menu_mnemonic_activates_unique_item() {
+        let items = vec![MenuItem::new("cut", "Cu&t"), MenuItem::new("copy", "Copy")];
+        let mut state = ContextMenuState::new();
+        state.open_at(0, 0);
+
+        let outcome = handle_context_menu_input(&mut state, &items, "c", &Key::default());
+        assert_eq!(outcome, InteractionOutcome::Submitted("copy".to_string()));
+        assert!(!state.open);
+    }
+
+    #[test]
+    fn test_context_menu_ambiguous_mnemonic_cycles() {
+        let items = vec![
+            MenuItem::new("save", "&Save"),
+            MenuItem::separator(),
+            MenuItem::new("search", "&Search"),
+            MenuItem::new("skip", "&Skip").disabled(true),
+        ];
+        let mut state = ContextMenuState::new();
+        state.open_at(0, 0);
+        let key = Key::default();
+
+        let outcome = handle_context_menu_input(&mut state, &items, "s", &key);
+        assert_eq!(outcome, InteractionOutcome::Handled);
+        assert_eq!(state.selected, 2);
+
+        let outcome = handle_context_menu_input(&mut state, &items, "S", &key);
+        assert_eq!(outcome, InteractionOutcome::Handled);
+        assert_eq!(state.selected, 0);
+
+        let outcome = handle_context_menu_input(
+            &mut state,
+            &items,
+            "",
+            &Key {
+                return_key: true,
+                ..Key::default()
+            },
+        );
+        assert_eq!(outcome, InteractionOutcome::Submitted("save".to_string()));
+    }
+
+    #[test]
+    fn test_context_menu_renders_underlined_mnemonic() {
+        let items = vec![MenuItem::new("save", "&Save")];
+        let mut state = ContextMenuState::new();
+        state.open = true;
+
+        let element = ContextMenu::new(items).state(state).into_element();
+        let row = element.children.get(1).unwrap();
+        let spans = &row.spans.as_ref().unwrap()[0].spans;
+        assert!(spans.iter().any(|s| s.content == "S" && s.style.underline));
+    }
+
     #[test]
     fn test_context_menu_style() {
         let style = ContextMenuStyle::new().width(40).background(Color::Blue);
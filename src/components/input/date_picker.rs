@@ -0,0 +1,642 @@
+//! DatePicker component for choosing a date from a calendar popover
+//!
+//! A collapsed field showing the selected date that opens a [`Calendar`]
+//! for keyboard navigation. The calendar is an overlay anchored below the
+//! field, so it covers whatever follows instead of pushing it down, and
+//! flips above the field when there is no room below it on screen.
+//!
+//! # Features
+//!
+//! - Arrow keys move by day and week, PageUp/PageDown by month
+//! - Shift+PageUp/PageDown move by year, Home/End jump within the month
+//! - Optional min/max range and individually disabled dates
+//! - Enter selects the focused date, Escape closes without selecting
+//!
+//! # Example
+//!
+//! ```ignore
+//! use rnk::components::{CalendarDate, DatePicker, DatePickerState, handle_datepicker_input};
+//! use rnk::hooks::{use_signal, use_input};
+//!
+//! fn app() -> Element {
+//!     let state = use_signal(|| {
+//!         DatePickerState::new(CalendarDate::new(2024, 6, 15))
+//!             .min(CalendarDate::new(2024, 1, 1))
+//!             .max(CalendarDate::new(2024, 12, 31))
+//!     });
+//!
+//!     use_input(move |input, key| {
+//!         let mut s = state.get();
+//!         if handle_datepicker_input(&mut s, input, key) {
+//!             state.set(s);
+//!         }
+//!     });
+//!
+//!     DatePicker::new(&state.get()).into_element()
+//! }
+//! ```
+
+use std::fmt;
+
+use crate::components::{
+    Box as RnkBox, Calendar, InteractionMode, InteractionOutcome, Text, get_theme,
+};
+use crate::core::{
+    AccessibilityProps, AccessibilityRole, Anchor, BorderStyle, Color, Element, FlexDirection,
+};
+
+/// A calendar date (proleptic Gregorian)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    /// Year
+    pub year: i32,
+    /// Month (1-12)
+    pub month: u32,
+    /// Day of month (1-31)
+    pub day: u32,
+}
+
+impl CalendarDate {
+    /// Create a date, clamping the month and day into range
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        let month = month.clamp(1, 12);
+        let day = day.clamp(1, days_in_month(year, month));
+        Self { year, month, day }
+    }
+
+    /// Number of days in this date's month
+    pub fn days_in_month(&self) -> u32 {
+        days_in_month(self.year, self.month)
+    }
+
+    /// Move by a number of days
+    pub fn add_days(&self, days: i64) -> Self {
+        from_day_number(day_number(*self) + days)
+    }
+
+    /// Move by a number of months, clamping the day to the new month
+    pub fn add_months(&self, months: i32) -> Self {
+        let index = self.year * 12 + self.month as i32 - 1 + months;
+        Self::new(
+            index.div_euclid(12),
+            index.rem_euclid(12) as u32 + 1,
+            self.day,
+        )
+    }
+
+    /// Move by a number of years, clamping Feb 29 to Feb 28
+    pub fn add_years(&self, years: i32) -> Self {
+        self.add_months(years * 12)
+    }
+}
+
+impl fmt::Display for CalendarDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 (Howard Hinnant's `days_from_civil`)
+fn day_number(date: CalendarDate) -> i64 {
+    let year = date.year as i64 - i64::from(date.month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let month = date.month as i64;
+    let doy = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + date.day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Inverse of [`day_number`]
+fn from_day_number(days: i64) -> CalendarDate {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+    CalendarDate { year, month, day }
+}
+
+/// Date picker state
+#[derive(Debug, Clone)]
+pub struct DatePickerState {
+    /// Chosen date
+    selected: Option<CalendarDate>,
+    /// Date under the keyboard cursor
+    focused: CalendarDate,
+    /// Whether the calendar popover is shown
+    open: bool,
+    /// Earliest selectable date
+    min: Option<CalendarDate>,
+    /// Latest selectable date
+    max: Option<CalendarDate>,
+    /// Dates that cannot be selected
+    disabled: Vec<CalendarDate>,
+    /// Text shown when nothing is selected
+    placeholder: String,
+}
+
+impl DatePickerState {
+    /// Create a closed picker with the cursor on `focused` and no selection
+    pub fn new(focused: CalendarDate) -> Self {
+        Self {
+            selected: None,
+            focused,
+            open: false,
+            min: None,
+            max: None,
+            disabled: Vec::new(),
+            placeholder: "Select a date".to_string(),
+        }
+    }
+
+    /// Start with a selected date (the cursor moves to it)
+    pub fn value(mut self, date: CalendarDate) -> Self {
+        self.selected = Some(date);
+        self.focused = date;
+        self
+    }
+
+    /// Set the earliest selectable date
+    pub fn min(mut self, min: CalendarDate) -> Self {
+        self.min = Some(min);
+        self.focused = self.clamp(self.focused);
+        self
+    }
+
+    /// Set the latest selectable date
+    pub fn max(mut self, max: CalendarDate) -> Self {
+        self.max = Some(max);
+        self.focused = self.clamp(self.focused);
+        self
+    }
+
+    /// Set the selectable range
+    pub fn range(self, min: CalendarDate, max: CalendarDate) -> Self {
+        self.min(min).max(max)
+    }
+
+    /// Set dates that cannot be selected
+    pub fn disabled(mut self, dates: Vec<CalendarDate>) -> Self {
+        self.disabled = dates;
+        self
+    }
+
+    /// Set the text shown when nothing is selected
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Get the selected date
+    pub fn selected(&self) -> Option<CalendarDate> {
+        self.selected
+    }
+
+    /// Get the date under the cursor
+    pub fn focused(&self) -> CalendarDate {
+        self.focused
+    }
+
+    /// Get the selectable range
+    pub fn bounds(&self) -> (Option<CalendarDate>, Option<CalendarDate>) {
+        (self.min, self.max)
+    }
+
+    /// Check whether the calendar is shown
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Show the calendar with the cursor on the selected date
+    pub fn open(&mut self) {
+        if let Some(selected) = self.selected {
+            self.focused = selected;
+        }
+        self.open = true;
+    }
+
+    /// Hide the calendar
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// Check whether a date is in range and not disabled
+    pub fn is_selectable(&self, date: CalendarDate) -> bool {
+        self.min.is_none_or(|min| date >= min)
+            && self.max.is_none_or(|max| date <= max)
+            && !self.disabled.contains(&date)
+    }
+
+    /// Select a date, returning false if it is not selectable
+    pub fn select(&mut self, date: CalendarDate) -> bool {
+        if !self.is_selectable(date) {
+            return false;
+        }
+        self.selected = Some(date);
+        self.focused = date;
+        true
+    }
+
+    /// Clear the selection
+    pub fn clear(&mut self) {
+        self.selected = None;
+    }
+
+    /// Move the cursor to a date, clamped to the selectable range
+    pub fn focus(&mut self, date: CalendarDate) {
+        self.focused = self.clamp(date);
+    }
+
+    /// Move the cursor by a number of days
+    pub fn move_days(&mut self, days: i64) {
+        self.focus(self.focused.add_days(days));
+    }
+
+    /// Move the cursor by a number of months
+    pub fn move_months(&mut self, months: i32) {
+        self.focus(self.focused.add_months(months));
+    }
+
+    /// Move the cursor by a number of years
+    pub fn move_years(&mut self, years: i32) {
+        self.focus(self.focused.add_years(years));
+    }
+
+    /// Text shown in the collapsed field
+    pub fn display_text(&self) -> String {
+        match self.selected {
+            Some(date) => date.to_string(),
+            None => self.placeholder.clone(),
+        }
+    }
+
+    /// Disabled days (day of month) in the focused month
+    fn disabled_days(&self) -> Vec<u32> {
+        let CalendarDate { year, month, .. } = self.focused;
+        (1..=days_in_month(year, month))
+            .filter(|&day| !self.is_selectable(CalendarDate { year, month, day }))
+            .collect()
+    }
+
+    fn clamp(&self, mut date: CalendarDate) -> CalendarDate {
+        if let Some(min) = self.min {
+            date = date.max(min);
+        }
+        if let Some(max) = self.max {
+            date = date.min(max);
+        }
+        date
+    }
+}
+
+/// Date picker component
+#[derive(Debug, Clone)]
+pub struct DatePicker<'a> {
+    /// State reference
+    state: &'a DatePickerState,
+    /// Whether the field is focused
+    focused: bool,
+    /// Start weeks on Monday
+    monday_first: bool,
+    /// Accent color (defaults to the theme primary color)
+    color: Option<Color>,
+    /// Today's date, marked in the calendar
+    today: Option<CalendarDate>,
+}
+
+impl<'a> DatePicker<'a> {
+    /// Create a date picker for a state
+    pub fn new(state: &'a DatePickerState) -> Self {
+        Self {
+            state,
+            focused: true,
+            monday_first: false,
+            color: None,
+            today: None,
+        }
+    }
+
+    /// Set whether the field is focused
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Start calendar weeks on Monday
+    pub fn monday_first(mut self) -> Self {
+        self.monday_first = true;
+        self
+    }
+
+    /// Set the accent color
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Mark today's date in the calendar
+    pub fn today(mut self, today: CalendarDate) -> Self {
+        self.today = Some(today);
+        self
+    }
+
+    /// Convert to Element
+    pub fn into_element(self) -> Element {
+        let color = self.color.unwrap_or(get_theme().primary);
+
+        let mut value = Text::new(self.state.display_text());
+        if self.state.selected().is_none() {
+            value = value.dim();
+        } else if self.focused {
+            value = value.bold();
+        }
+        let indicator = if self.state.is_open() { "▴" } else { "▾" };
+        let field = RnkBox::new()
+            .flex_direction(FlexDirection::Row)
+            .child(value.into_element())
+            .child(
+                Text::new(format!(" {}", indicator))
+                    .color(color)
+                    .into_element(),
+            )
+            .into_element();
+        let field_id = field.id;
+
+        let mut column = RnkBox::new()
+            .flex_direction(FlexDirection::Column)
+            .child(field);
+        if self.state.is_open() {
+            let focused = self.state.focused();
+            let mut calendar = Calendar::new(focused.year, focused.month)
+                .header_color(color)
+                .focused(focused.day)
+                .disabled(self.state.disabled_days());
+            if let Some(selected) = self.state.selected()
+                && (selected.year, selected.month) == (focused.year, focused.month)
+            {
+                calendar = calendar.selected(selected.day);
+            }
+            if let Some(today) = self.today {
+                calendar = calendar.today(today.year, today.month, today.day);
+            }
+            if self.monday_first {
+                calendar = calendar.monday_first();
+            }
+            column = column.child(
+                RnkBox::new()
+                    .anchor(Anchor::new(field_id))
+                    .border_style(BorderStyle::Round)
+                    .border_color(color)
+                    .child(calendar.into_element())
+                    .into_element(),
+            );
+        }
+
+        column.into_element().with_accessibility(
+            AccessibilityProps::new(AccessibilityRole::TextInput)
+                .label("Date picker")
+                .value(self.state.display_text())
+                .focusable(self.focused),
+        )
+    }
+}
+
+/// Handle date picker keys
+pub fn handle_datepicker_input(
+    state: &mut DatePickerState,
+    input: &str,
+    key: &crate::hooks::Key,
+) -> bool {
+    handle_datepicker_input_with_mode(state, input, key, InteractionMode::Enabled).is_handled()
+}
+
+/// Handle date picker keys with explicit disabled/read-only behavior.
+///
+/// While closed, Enter, Space or Down opens the calendar. While open, the
+/// arrows move by day and week, PageUp/PageDown by month (by year with
+/// Shift) and Home/End to the first and last day of the month. Enter selects
+/// the focused date if it is selectable; Escape closes without selecting.
+pub fn handle_datepicker_input_with_mode(
+    state: &mut DatePickerState,
+    input: &str,
+    key: &crate::hooks::Key,
+    mode: InteractionMode,
+) -> InteractionOutcome<CalendarDate> {
+    if !mode.is_enabled() {
+        return InteractionOutcome::Ignored;
+    }
+
+    if !state.is_open() {
+        if key.return_key || key.down_arrow || input == " " {
+            state.open();
+            return InteractionOutcome::Handled;
+        }
+        return InteractionOutcome::Ignored;
+    }
+
+    if key.escape {
+        state.close();
+        return InteractionOutcome::Cancelled;
+    }
+    if key.return_key || input == " " {
+        let focused = state.focused();
+        if !state.select(focused) {
+            return InteractionOutcome::Handled;
+        }
+        state.close();
+        return InteractionOutcome::Submitted(focused);
+    }
+
+    let focused = state.focused();
+    if key.left_arrow {
+        state.move_days(-1);
+    } else if key.right_arrow {
+        state.move_days(1);
+    } else if key.up_arrow {
+        state.move_days(-7);
+    } else if key.down_arrow {
+        state.move_days(7);
+    } else if key.page_up && key.shift {
+        state.move_years(-1);
+    } else if key.page_down && key.shift {
+        state.move_years(1);
+    } else if key.page_up {
+        state.move_months(-1);
+    } else if key.page_down {
+        state.move_months(1);
+    } else if key.home {
+        state.focus(CalendarDate { day: 1, ..focused });
+    } else if key.end {
+        state.focus(CalendarDate {
+            day: focused.days_in_month(),
+            ..focused
+        });
+    } else {
+        return InteractionOutcome::Ignored;
+    }
+    InteractionOutcome::Handled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::Key;
+
+    fn date(year: i32, month: u32, day: u32) -> CalendarDate {
+        CalendarDate::new(year, month, day)
+    }
+
+    fn press(state: &mut DatePickerState, spec: &str) -> InteractionOutcome<CalendarDate> {
+        let key: Key = spec.parse().unwrap();
+        let input = match spec {
+            "Space" => " ".to_string(),
+            _ => String::new(),
+        };
+        handle_datepicker_input_with_mode(state, &input, &key, InteractionMode::Enabled)
+    }
+
+    #[test]
+    fn test_calendar_date_arithmetic() {
+        assert_eq!(date(2024, 2, 30), date(2024, 2, 29));
+        assert_eq!(date(2024, 2, 28).add_days(1), date(2024, 2, 29));
+        assert_eq!(date(2023, 12, 31).add_days(1), date(2024, 1, 1));
+        assert_eq!(date(2024, 3, 1).add_days(-1), date(2024, 2, 29));
+        assert_eq!(date(1970, 1, 1).add_days(0), date(1970, 1, 1));
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 1, 15).add_months(-1), date(2023, 12, 15));
+        assert_eq!(date(2024, 2, 29).add_years(1), date(2025, 2, 28));
+        assert_eq!(date(2024, 6, 5).to_string(), "2024-06-05");
+    }
+
+    #[test]
+    fn test_open_navigate_and_select() {
+        let mut state = DatePickerState::new(date(2024, 6, 15));
+        assert_eq!(press(&mut state, "Right"), InteractionOutcome::Ignored);
+
+        assert_eq!(press(&mut state, "Enter"), InteractionOutcome::Handled);
+        assert!(state.is_open());
+
+        press(&mut state, "Right");
+        press(&mut state, "Down");
+        assert_eq!(state.focused(), date(2024, 6, 23));
+        press(&mut state, "PageDown");
+        assert_eq!(state.focused(), date(2024, 7, 23));
+        press(&mut state, "Shift+PageUp");
+        assert_eq!(state.focused(), date(2023, 7, 23));
+        press(&mut state, "End");
+        assert_eq!(state.focused(), date(2023, 7, 31));
+
+        assert_eq!(
+            press(&mut state, "Enter"),
+            InteractionOutcome::Submitted(date(2023, 7, 31))
+        );
+        assert!(!state.is_open());
+        assert_eq!(state.selected(), Some(date(2023, 7, 31)));
+        assert_eq!(state.display_text(), "2023-07-31");
+    }
+
+    #[test]
+    fn test_escape_closes_without_selecting() {
+        let mut state = DatePickerState::new(date(2024, 6, 15)).value(date(2024, 6, 1));
+        press(&mut state, "Space");
+        assert_eq!(state.focused(), date(2024, 6, 1));
+        press(&mut state, "Left");
+        assert_eq!(press(&mut state, "Escape"), InteractionOutcome::Cancelled);
+        assert!(!state.is_open());
+        assert_eq!(state.selected(), Some(date(2024, 6, 1)));
+    }
+
+    #[test]
+    fn test_range_and_disabled_dates() {
+        let mut state = DatePickerState::new(date(2024, 6, 15))
+            .range(date(2024, 6, 10), date(2024, 6, 20))
+            .disabled(vec![date(2024, 6, 12)]);
+        state.open();
+
+        press(&mut state, "PageUp");
+        assert_eq!(state.focused(), date(2024, 6, 10));
+        press(&mut state, "Down");
+        press(&mut state, "Down");
+        assert_eq!(state.focused(), date(2024, 6, 20));
+
+        state.focus(date(2024, 6, 12));
+        assert_eq!(press(&mut state, "Enter"), InteractionOutcome::Handled);
+        assert!(state.is_open());
+        assert_eq!(state.selected(), None);
+        assert!(!state.select(date(2024, 6, 21)));
+
+        let disabled = state.disabled_days();
+        assert!(disabled.contains(&9) && disabled.contains(&12) && disabled.contains(&21));
+        assert!(!disabled.contains(&10) && !disabled.contains(&20));
+    }
+
+    #[test]
+    fn test_read_only_ignores_input() {
+        let mut state = DatePickerState::new(date(2024, 6, 15));
+        let key: Key = "Enter".parse().unwrap();
+        let outcome =
+            handle_datepicker_input_with_mode(&mut state, "", &key, InteractionMode::ReadOnly);
+        assert_eq!(outcome, InteractionOutcome::Ignored);
+        assert!(!state.is_open());
+    }
+
+    #[test]
+    fn test_render_collapsed_and_open() {
+        use crate::testing::TestRenderer;
+
+        let mut state = DatePickerState::new(date(2024, 6, 15)).placeholder("Pick one");
+        let renderer = TestRenderer::new(30, 12);
+        let collapsed = renderer.render_to_plain(&DatePicker::new(&state).into_element());
+        assert!(collapsed.contains("Pick one ▾"));
+
+        state.open();
+        let open = renderer.render_to_plain(&DatePicker::new(&state).into_element());
+        assert!(open.contains("▴"));
+        assert!(open.contains("June 2024"));
+        assert!(open.contains("15"));
+    }
+
+    #[test]
+    fn test_calendar_floats_over_following_content() {
+        use crate::testing::TestRenderer;
+
+        let mut state = DatePickerState::new(date(2024, 6, 15));
+        let render = |state: &DatePickerState| {
+            let root = RnkBox::new()
+                .flex_direction(FlexDirection::Column)
+                .child(DatePicker::new(state).into_element())
+                .child(Text::new("footer").into_element())
+                .into_element();
+            TestRenderer::new(30, 12).render_to_plain(&root)
+        };
+
+        let collapsed = render(&state);
+        assert_eq!(collapsed.lines().nth(1).map(str::trim_end), Some("footer"));
+
+        state.open();
+        let open = render(&state);
+        let lines: Vec<&str> = open.lines().collect();
+        // The footer keeps its row and the calendar is drawn over it
+        assert!(lines[1].starts_with('╭'));
+        assert!(!open.contains("footer"));
+        assert!(open.contains("June 2024"));
+    }
+}
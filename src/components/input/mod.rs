@@ -1,16 +1,23 @@
+mod autocomplete;
 mod code_editor;
 mod color_picker;
 mod command_palette;
 mod command_palette_accessibility;
 mod confirm;
 mod context_menu;
+mod date_picker;
 mod file_picker;
 mod multi_select;
+mod number_input;
 mod paginator;
 mod select_input;
 pub(crate) mod selection_list;
 mod text_input;
 
+pub use autocomplete::{
+    Autocomplete, AutocompleteState, AutocompleteStyle, filter_suggestions,
+    handle_autocomplete_input,
+};
 pub use code_editor::{CodeEditor, Language};
 pub use color_picker::{
     ColorPalette, ColorPicker, ColorPickerState, ColorPickerStyle, handle_color_picker_input,
@@ -20,21 +27,33 @@ pub use command_palette::{
 };
 pub use confirm::{
     ButtonStyle, Confirm, ConfirmState, ConfirmStyle, handle_confirm_input,
-    handle_confirm_input_with_mode,
+    handle_confirm_input_with_mode, handle_confirm_input_with_style,
+};
+pub use context_menu::{
+    ContextMenu, ContextMenuState, ContextMenuStyle, MenuItem, handle_context_menu_input,
+};
+pub use date_picker::{
+    CalendarDate, DatePicker, DatePickerState, handle_datepicker_input,
+    handle_datepicker_input_with_mode,
 };
-pub use context_menu::{ContextMenu, ContextMenuState, ContextMenuStyle, MenuItem};
 pub use file_picker::{
     FileEntry, FileFilter, FilePicker, FilePickerState, FilePickerStyle, FileType,
     handle_file_picker_input,
 };
 pub use multi_select::{
     MultiSelect, MultiSelectItem, MultiSelectState, MultiSelectStyle, handle_multi_select_input,
+    handle_multi_select_input_with,
+};
+pub use number_input::{
+    NumberInput, NumberInputState, NumberMode, handle_number_input, handle_number_input_mouse,
+    handle_number_input_with_mode,
 };
 pub use paginator::{
     Paginator, PaginatorState, PaginatorStyle, PaginatorType, handle_paginator_input,
 };
 pub use select_input::{
     SelectInput, SelectInputState, SelectInputStyle, SelectItem, handle_select_input,
+    handle_select_input_with,
 };
 pub use text_input::{
     TextInputHandle, TextInputOptions, TextInputState, handle_text_input, use_text_input,
@@ -4,8 +4,10 @@
 //! keyboard navigation and selection internally.
 
 use crate::components::Box as RnkBox;
-use crate::components::navigation::{NavigationConfig, handle_list_navigation};
-use crate::components::selection_list::{ListStyle, indicator_padding, render_list};
+use crate::components::navigation::{NavigationConfig, handle_list_navigation_with};
+use crate::components::selection_list::{
+    ListStyle, SelectableItem, indicator_padding, nearest_selectable, render_list,
+};
 use crate::components::{InteractionMode, InteractionOutcome};
 use crate::core::{AccessibilityProps, AccessibilityRole, Color, Element};
 use crate::hooks::{use_input, use_signal};
@@ -14,11 +16,13 @@ use crate::hooks::{use_input, use_signal};
 #[derive(Debug, Clone)]
 pub struct MultiSelectItem<T: Clone> {
     /// Display label for the item
-    pub label: String,
-    /// Value associated with the item
-    pub value: T,
+    label: String,
+    /// Value associated with the item, `None` for separators
+    value: Option<T>,
     /// Whether this item is initially selected
-    pub selected: bool,
+    selected: bool,
+    /// Whether the item is dimmed, skipped by navigation and cannot toggle
+    disabled: bool,
 }
 
 impl<T: Clone> MultiSelectItem<T> {
@@ -26,25 +30,78 @@ impl<T: Clone> MultiSelectItem<T> {
     pub fn new(label: impl Into<String>, value: T) -> Self {
         Self {
             label: label.into(),
-            value,
+            value: Some(value),
             selected: false,
+            disabled: false,
         }
     }
 
     /// Create a new item that is initially selected
     pub fn selected(label: impl Into<String>, value: T) -> Self {
+        Self::new(label, value).with_selected(true)
+    }
+
+    /// Create a separator row, drawn as a divider across the list
+    pub fn separator() -> Self {
         Self {
-            label: label.into(),
-            value,
-            selected: true,
+            label: String::new(),
+            value: None,
+            selected: false,
+            disabled: false,
         }
     }
 
+    /// Get the display label
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Get the value, or `None` for a separator
+    pub fn value(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+
+    /// Check if this item is initially selected
+    pub fn is_selected(&self) -> bool {
+        self.selected
+    }
+
     /// Set whether this item is selected
     pub fn with_selected(mut self, selected: bool) -> Self {
         self.selected = selected;
         self
     }
+
+    /// Set whether this item is disabled (dimmed and skipped by navigation)
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Check if this item is disabled
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Check if this is a separator row
+    pub fn is_separator(&self) -> bool {
+        self.value.is_none()
+    }
+
+    /// Check if this item can be highlighted and toggled
+    pub fn is_selectable(&self) -> bool {
+        !self.disabled && !self.is_separator()
+    }
+}
+
+impl<T: Clone> SelectableItem for MultiSelectItem<T> {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn is_separator(&self) -> bool {
+        self.value.is_none()
+    }
 }
 
 /// State for controlled MultiSelect usage.
@@ -349,7 +406,7 @@ impl<T: Clone + 'static> MultiSelect<T> {
         self.items
             .iter()
             .filter(|item| item.selected)
-            .map(|item| &item.value)
+            .filter_map(|item| item.value.as_ref())
             .collect()
     }
 
@@ -367,7 +424,7 @@ impl<T: Clone + 'static> MultiSelect<T> {
             );
         }
 
-        let initial_highlighted = self.highlighted;
+        let initial_highlighted = nearest_selectable(&self.items, self.highlighted);
         let initial_selections: Vec<bool> = self.items.iter().map(|i| i.selected).collect();
         let items = self.items.clone();
         let limit = self.limit;
@@ -383,6 +440,7 @@ impl<T: Clone + 'static> MultiSelect<T> {
         // Set up input handling if focused
         if is_focused {
             let items_len = items.len();
+            let selectable: Vec<bool> = items.iter().map(MultiSelectItem::is_selectable).collect();
             let state_for_input = state_signal.clone();
 
             use_input(move |input, key| {
@@ -391,8 +449,15 @@ impl<T: Clone + 'static> MultiSelect<T> {
                     .number_shortcuts(number_shortcuts);
 
                 let mut next = state_for_input.get();
-                let outcome =
-                    handle_multi_select_input(&mut next, items_len, input, key, &config, mode);
+                let outcome = handle_multi_select_input_with(
+                    &mut next,
+                    items_len,
+                    input,
+                    key,
+                    &config,
+                    mode,
+                    |i| selectable[i],
+                );
                 if outcome.is_handled() {
                     state_for_input.set(next);
                 }
@@ -435,6 +500,24 @@ pub fn handle_multi_select_input(
     key: &crate::hooks::Key,
     config: &NavigationConfig,
     mode: InteractionMode,
+) -> InteractionOutcome<Vec<usize>> {
+    handle_multi_select_input_with(state, item_count, input, key, config, mode, |_| true)
+}
+
+/// Handle MultiSelect input, skipping items that cannot be selected
+///
+/// Like [`handle_multi_select_input`], but navigation only lands on indices
+/// for which `is_selectable` returns true (see
+/// [`MultiSelectItem::is_selectable`]), and toggling, select-all and
+/// deselect-all leave unselectable items unchanged.
+pub fn handle_multi_select_input_with(
+    state: &mut MultiSelectState,
+    item_count: usize,
+    input: &str,
+    key: &crate::hooks::Key,
+    config: &NavigationConfig,
+    mode: InteractionMode,
+    is_selectable: impl Fn(usize) -> bool,
 ) -> InteractionOutcome<Vec<usize>> {
     if mode.is_disabled() || item_count == 0 {
         return InteractionOutcome::Ignored;
@@ -448,7 +531,8 @@ pub fn handle_multi_select_input(
     }
 
     let current = state.highlighted;
-    let result = handle_list_navigation(current, item_count, input, *key, config);
+    let result =
+        handle_list_navigation_with(current, item_count, input, *key, config, &is_selectable);
     if result.is_moved() {
         let new_pos = result.unwrap_or(current);
         if new_pos != current {
@@ -465,23 +549,28 @@ pub fn handle_multi_select_input(
         return InteractionOutcome::Submitted(state.selected_indices());
     }
 
-    if key.space {
-        if let Some(selected) = state.selected.get_mut(current) {
-            *selected = !*selected;
-            return InteractionOutcome::Changed(state.selected_indices());
-        }
+    if key.space
+        && is_selectable(current)
+        && let Some(selected) = state.selected.get_mut(current)
+    {
+        *selected = !*selected;
+        return InteractionOutcome::Changed(state.selected_indices());
     }
 
     if input == "a" && key.ctrl {
-        for selected in &mut state.selected {
-            *selected = true;
+        for (idx, selected) in state.selected.iter_mut().enumerate() {
+            if is_selectable(idx) {
+                *selected = true;
+            }
         }
         return InteractionOutcome::Changed(state.selected_indices());
     }
 
     if input == "d" && key.ctrl {
-        for selected in &mut state.selected {
-            *selected = false;
+        for (idx, selected) in state.selected.iter_mut().enumerate() {
+            if is_selectable(idx) {
+                *selected = false;
+            }
         }
         return InteractionOutcome::Changed(state.selected_indices());
     }
@@ -561,15 +650,29 @@ mod tests {
     #[test]
     fn test_multi_select_item_creation() {
         let item = MultiSelectItem::new("Test", 42);
-        assert_eq!(item.label, "Test");
-        assert_eq!(item.value, 42);
-        assert!(!item.selected);
+        assert_eq!(item.label(), "Test");
+        assert_eq!(item.value(), Some(&42));
+        assert!(!item.is_selected());
+    }
+
+    #[test]
+    fn test_separator_has_no_value() {
+        #[derive(Clone)]
+        struct Id(u8);
+
+        let items = [
+            MultiSelectItem::new("One", Id(1)),
+            MultiSelectItem::separator(),
+        ];
+        assert!(items[1].is_separator());
+        assert!(items[1].value().is_none());
+        assert_eq!(items[0].value().map(|id| id.0), Some(1));
     }
 
     #[test]
     fn test_multi_select_item_selected() {
         let item = MultiSelectItem::selected("Test", 42);
-        assert!(item.selected);
+        assert!(item.is_selected());
     }
 
     #[test]
@@ -703,6 +806,47 @@ mod tests {
         assert!(state.is_cancelled());
     }
 
+    #[test]
+    fn test_handle_multi_select_input_skips_unselectable_items() {
+        let items = [
+            MultiSelectItem::new("One", 1),
+            MultiSelectItem::separator(),
+            MultiSelectItem::new("Two", 2).disabled(true),
+            MultiSelectItem::new("Three", 3),
+        ];
+        let selectable = |i: usize| items[i].is_selectable();
+        let mut state = MultiSelectState::from_items(&items, 0);
+        let config = NavigationConfig::new();
+        let key = |f: fn(&mut crate::hooks::Key)| {
+            let mut key = crate::hooks::Key::default();
+            f(&mut key);
+            key
+        };
+
+        let outcome = handle_multi_select_input_with(
+            &mut state,
+            items.len(),
+            "",
+            &key(|k| k.down_arrow = true),
+            &config,
+            InteractionMode::Enabled,
+            selectable,
+        );
+        assert_eq!(outcome, InteractionOutcome::Handled);
+        assert_eq!(state.highlighted(), 3);
+
+        let outcome = handle_multi_select_input_with(
+            &mut state,
+            items.len(),
+            "a",
+            &key(|k| k.ctrl = true),
+            &config,
+            InteractionMode::Enabled,
+            selectable,
+        );
+        assert_eq!(outcome, InteractionOutcome::Changed(vec![0, 3]));
+    }
+
     #[test]
     fn test_handle_multi_select_input_modes() {
         let config = NavigationConfig::new().vim_navigation(true);
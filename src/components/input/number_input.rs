@@ -0,0 +1,699 @@
+//! NumberInput component for numeric fields with stepping
+//!
+//! A numeric field that can be typed into or adjusted with the keyboard,
+//! mouse wheel and optional `[-]`/`[+]` buttons.
+//!
+//! # Features
+//!
+//! - Integer and float modes
+//! - Optional min/max bounds and a configurable step
+//! - Arrow keys, PageUp/PageDown (10 steps) and Home/End (bounds)
+//! - Typed input that only accepts characters valid for the mode
+//! - Configurable decimals and unit suffix
+//!
+//! # Example
+//!
+//! ```ignore
+//! use rnk::components::{NumberInput, NumberInputState, handle_number_input};
+//! use rnk::hooks::{use_signal, use_input};
+//!
+//! fn app() -> Element {
+//!     let state = use_signal(|| NumberInputState::float(1.5).range(0.0, 10.0).step(0.5).suffix(" kg"));
+//!
+//!     use_input(move |input, key| {
+//!         let mut s = state.get();
+//!         if handle_number_input(&mut s, input, key) {
+//!             state.set(s);
+//!         }
+//!     });
+//!
+//!     NumberInput::new(&state.get()).buttons(true).into_element()
+//! }
+//! ```
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::components::{Box as RnkBox, InteractionMode, InteractionOutcome, Text, get_theme};
+use crate::core::{AccessibilityProps, AccessibilityRole, Color, Element, FlexDirection};
+use crate::hooks::use_mouse::{Mouse, MouseAction};
+
+/// Whether a number input holds integers or floats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberMode {
+    /// Whole numbers only
+    #[default]
+    Integer,
+    /// Decimal numbers
+    Float,
+}
+
+/// Button labels drawn around the value when buttons are enabled
+const DECREMENT_LABEL: &str = "[-]";
+const INCREMENT_LABEL: &str = "[+]";
+
+/// Number input state
+#[derive(Debug, Clone)]
+pub struct NumberInputState {
+    /// Committed value
+    value: f64,
+    /// Lower bound
+    min: Option<f64>,
+    /// Upper bound
+    max: Option<f64>,
+    /// Amount added per step
+    step: f64,
+    /// Integer or float
+    mode: NumberMode,
+    /// Decimals shown in float mode
+    decimals: usize,
+    /// Unit suffix shown after the value
+    suffix: String,
+    /// Text being typed, if editing
+    buffer: Option<String>,
+}
+
+impl NumberInputState {
+    /// Create an integer input
+    pub fn new(value: i64) -> Self {
+        Self {
+            value: value as f64,
+            min: None,
+            max: None,
+            step: 1.0,
+            mode: NumberMode::Integer,
+            decimals: 0,
+            suffix: String::new(),
+            buffer: None,
+        }
+    }
+
+    /// Create a float input showing two decimals
+    pub fn float(value: f64) -> Self {
+        Self {
+            value,
+            mode: NumberMode::Float,
+            decimals: 2,
+            ..Self::new(0)
+        }
+    }
+
+    /// Set the allowed range (inclusive)
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.min = Some(min.min(max));
+        self.max = Some(max.max(min));
+        self.value = self.clamp(self.value);
+        self
+    }
+
+    /// Set only the lower bound
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self.value = self.clamp(self.value);
+        self
+    }
+
+    /// Set only the upper bound
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self.value = self.clamp(self.value);
+        self
+    }
+
+    /// Set the step size (default: 1)
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step.abs();
+        self
+    }
+
+    /// Set the number of decimals shown in float mode
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Set a unit suffix such as `" kg"` or `"%"`
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Get the committed value
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Get the committed value rounded to an integer
+    pub fn value_i64(&self) -> i64 {
+        self.value.round() as i64
+    }
+
+    /// Set the value, clamped to the range (and rounded in integer mode)
+    pub fn set_value(&mut self, value: f64) {
+        self.value = self.clamp(value);
+        self.buffer = None;
+    }
+
+    /// Get the mode
+    pub fn mode(&self) -> NumberMode {
+        self.mode
+    }
+
+    /// Get the bounds
+    pub fn bounds(&self) -> (Option<f64>, Option<f64>) {
+        (self.min, self.max)
+    }
+
+    /// Add `steps` steps (negative to subtract)
+    pub fn step_by(&mut self, steps: i32) {
+        self.commit();
+        // Round away float noise such as 0.1 + 0.2
+        let value = self.value + self.step * steps as f64;
+        self.set_value((value * 1e10).round() / 1e10);
+    }
+
+    /// Add one step
+    pub fn increment(&mut self) {
+        self.step_by(1);
+    }
+
+    /// Subtract one step
+    pub fn decrement(&mut self) {
+        self.step_by(-1);
+    }
+
+    /// Check whether text is being typed
+    pub fn is_editing(&self) -> bool {
+        self.buffer.is_some()
+    }
+
+    /// The text being typed, if editing
+    pub fn edit_text(&self) -> Option<&str> {
+        self.buffer.as_deref()
+    }
+
+    /// Format a value with this input's decimals and suffix
+    pub fn format_value(&self, value: f64) -> String {
+        let number = match self.mode {
+            NumberMode::Integer => format!("{}", value.round() as i64),
+            NumberMode::Float => format!("{:.*}", self.decimals, value),
+        };
+        format!("{}{}", number, self.suffix)
+    }
+
+    /// Text shown for the field: the typed text while editing, else the value
+    pub fn display_text(&self) -> String {
+        match &self.buffer {
+            Some(buffer) => format!("{}{}", buffer, self.suffix),
+            None => self.format_value(self.value),
+        }
+    }
+
+    /// Type a character, returning `false` if it is not valid here
+    ///
+    /// Digits are always accepted; `.` once in float mode; `-` only as the
+    /// first character and only when the range allows negatives. Typing
+    /// while not editing replaces the value.
+    pub fn insert_char(&mut self, ch: char) -> bool {
+        let buffer = self.buffer.as_deref().unwrap_or("");
+        let valid = match ch {
+            '0'..='9' => true,
+            '.' => self.mode == NumberMode::Float && !buffer.contains('.'),
+            '-' => buffer.is_empty() && self.allows_negative(),
+            _ => false,
+        };
+        if valid {
+            self.buffer.get_or_insert_with(String::new).push(ch);
+        }
+        valid
+    }
+
+    /// Delete the last typed character
+    ///
+    /// When not editing, this starts editing from the current value.
+    pub fn backspace(&mut self) {
+        let raw = self.raw_text();
+        self.buffer.get_or_insert(raw).pop();
+    }
+
+    /// Commit the typed text, returning whether the value changed
+    ///
+    /// Text that does not parse (such as a lone `-`) is discarded.
+    pub fn commit(&mut self) -> bool {
+        let Some(buffer) = self.buffer.take() else {
+            return false;
+        };
+        match buffer.parse::<f64>() {
+            Ok(parsed) => {
+                let before = self.value;
+                self.set_value(parsed);
+                self.value != before
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Discard the typed text
+    pub fn cancel_edit(&mut self) {
+        self.buffer = None;
+    }
+
+    /// Columns occupied when rendered, optionally with buttons
+    pub fn rendered_width(&self, buttons: bool) -> usize {
+        let value = self.display_text().width();
+        if buttons {
+            DECREMENT_LABEL.width() + 1 + value + 1 + INCREMENT_LABEL.width()
+        } else {
+            value
+        }
+    }
+
+    fn allows_negative(&self) -> bool {
+        self.min.is_none_or(|min| min < 0.0)
+    }
+
+    /// Current value without suffix, as typed text
+    fn raw_text(&self) -> String {
+        let text = self.format_value(self.value);
+        text.strip_suffix(self.suffix.as_str())
+            .unwrap_or(&text)
+            .to_string()
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        let mut value = match self.mode {
+            NumberMode::Integer => value.round(),
+            NumberMode::Float => value,
+        };
+        if let Some(min) = self.min {
+            value = value.max(min);
+        }
+        if let Some(max) = self.max {
+            value = value.min(max);
+        }
+        value
+    }
+}
+
+impl Default for NumberInputState {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+/// Number input component
+#[derive(Debug, Clone)]
+pub struct NumberInput<'a> {
+    /// State reference
+    state: &'a NumberInputState,
+    /// Draw `[-]` and `[+]` buttons around the value
+    buttons: bool,
+    /// Whether the input is focused
+    focused: bool,
+    /// Value color
+    color: Option<Color>,
+    /// Button color (defaults to the theme primary color)
+    button_color: Option<Color>,
+}
+
+impl<'a> NumberInput<'a> {
+    /// Create a number input for a state
+    pub fn new(state: &'a NumberInputState) -> Self {
+        Self {
+            state,
+            buttons: false,
+            focused: true,
+            color: None,
+            button_color: None,
+        }
+    }
+
+    /// Show `[-]` and `[+]` buttons
+    pub fn buttons(mut self, show: bool) -> Self {
+        self.buttons = show;
+        self
+    }
+
+    /// Set whether the input is focused
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Set the value color
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the button color
+    pub fn button_color(mut self, color: Color) -> Self {
+        self.button_color = Some(color);
+        self
+    }
+
+    /// Convert to Element
+    pub fn into_element(self) -> Element {
+        let theme = get_theme();
+        let button_color = self.button_color.unwrap_or(theme.primary);
+        let (min, max) = self.state.bounds();
+        let value = self.state.value();
+
+        let mut value_text = Text::new(self.state.display_text());
+        if let Some(color) = self.color {
+            value_text = value_text.color(color);
+        }
+        if self.focused {
+            value_text = value_text.bold();
+        }
+        if self.state.is_editing() {
+            value_text = value_text.underline();
+        }
+
+        let mut row = RnkBox::new().flex_direction(FlexDirection::Row);
+        if self.buttons {
+            let button = |label: &str, enabled: bool| {
+                let text = Text::new(label);
+                if enabled {
+                    text.color(button_color).into_element()
+                } else {
+                    text.dim().into_element()
+                }
+            };
+            row = row
+                .child(button(DECREMENT_LABEL, min.is_none_or(|min| value > min)))
+                .child(Text::new(" ").into_element())
+                .child(value_text.into_element())
+                .child(Text::new(" ").into_element())
+                .child(button(INCREMENT_LABEL, max.is_none_or(|max| value < max)));
+        } else {
+            row = row.child(value_text.into_element());
+        }
+
+        row.into_element().with_accessibility(
+            AccessibilityProps::new(AccessibilityRole::TextInput)
+                .label("Number input")
+                .value(self.state.display_text())
+                .focusable(self.focused),
+        )
+    }
+}
+
+/// Handle number input keys
+pub fn handle_number_input(
+    state: &mut NumberInputState,
+    input: &str,
+    key: &crate::hooks::Key,
+) -> bool {
+    handle_number_input_with_mode(state, input, key, InteractionMode::Enabled).is_handled()
+}
+
+/// Handle number input keys with explicit disabled/read-only behavior.
+///
+/// Up/Down step the value, PageUp/PageDown step ten times, Home/End jump to
+/// the bounds and `+` increments. Enter commits typed text and submits the
+/// value; Escape discards typed text. Characters that are not valid for the
+/// mode are ignored.
+pub fn handle_number_input_with_mode(
+    state: &mut NumberInputState,
+    input: &str,
+    key: &crate::hooks::Key,
+    mode: InteractionMode,
+) -> InteractionOutcome<f64> {
+    if !mode.is_enabled() {
+        return InteractionOutcome::Ignored;
+    }
+
+    let before = state.value();
+    let changed = |state: &NumberInputState| {
+        if state.value() != before {
+            InteractionOutcome::Changed(state.value())
+        } else {
+            InteractionOutcome::Handled
+        }
+    };
+
+    let steps = if key.up_arrow {
+        Some(1)
+    } else if key.down_arrow {
+        Some(-1)
+    } else if key.page_up {
+        Some(10)
+    } else if key.page_down {
+        Some(-10)
+    } else {
+        None
+    };
+    if let Some(steps) = steps {
+        state.step_by(steps);
+        return changed(state);
+    }
+
+    if key.home || key.end {
+        let (min, max) = state.bounds();
+        let Some(target) = (if key.home { min } else { max }) else {
+            return InteractionOutcome::Ignored;
+        };
+        state.set_value(target);
+        return changed(state);
+    }
+
+    if key.return_key {
+        state.commit();
+        return InteractionOutcome::Submitted(state.value());
+    }
+    if key.escape {
+        if !state.is_editing() {
+            return InteractionOutcome::Ignored;
+        }
+        state.cancel_edit();
+        return InteractionOutcome::Handled;
+    }
+    if key.backspace {
+        state.backspace();
+        return InteractionOutcome::Handled;
+    }
+
+    if key.ctrl || key.alt {
+        return InteractionOutcome::Ignored;
+    }
+    let mut chars = input.chars();
+    let (Some(ch), None) = (chars.next(), chars.next()) else {
+        return InteractionOutcome::Ignored;
+    };
+    if ch == '+' {
+        state.increment();
+        return changed(state);
+    }
+    if state.insert_char(ch) {
+        InteractionOutcome::Handled
+    } else {
+        InteractionOutcome::Ignored
+    }
+}
+
+/// Handle mouse wheel and button clicks on a rendered number input
+///
+/// `origin` is the input's top-left cell on screen and `buttons` must match
+/// [`NumberInput::buttons`]. Returns whether the value changed.
+pub fn handle_number_input_mouse(
+    state: &mut NumberInputState,
+    mouse: &Mouse,
+    origin: (u16, u16),
+    buttons: bool,
+) -> bool {
+    let width = state.rendered_width(buttons) as u16;
+    if mouse.y != origin.1 || mouse.x < origin.0 || mouse.x >= origin.0 + width {
+        return false;
+    }
+
+    let before = state.value();
+    let column = (mouse.x - origin.0) as usize;
+    match mouse.action {
+        MouseAction::ScrollUp => state.increment(),
+        MouseAction::ScrollDown => state.decrement(),
+        _ if buttons && mouse.is_left_click() => {
+            if column < DECREMENT_LABEL.width() {
+                state.decrement();
+            } else if column >= width as usize - INCREMENT_LABEL.width() {
+                state.increment();
+            }
+        }
+        _ => {}
+    }
+    state.value() != before
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::Key;
+    use crate::hooks::use_mouse::MouseButton;
+
+    fn press(state: &mut NumberInputState, input: &str) -> InteractionOutcome<f64> {
+        handle_number_input_with_mode(state, input, &Key::default(), InteractionMode::Enabled)
+    }
+
+    fn special(state: &mut NumberInputState, key: Key) -> InteractionOutcome<f64> {
+        handle_number_input_with_mode(state, "", &key, InteractionMode::Enabled)
+    }
+
+    #[test]
+    fn test_step_and_clamp() {
+        let mut state = NumberInputState::new(8).range(0.0, 10.0).step(2.0);
+        state.increment();
+        assert_eq!(state.value_i64(), 10);
+        state.increment();
+        assert_eq!(state.value_i64(), 10);
+        state.step_by(-10);
+        assert_eq!(state.value_i64(), 0);
+
+        let mut float = NumberInputState::float(0.1).step(0.2);
+        float.increment();
+        assert_eq!(float.value(), 0.3);
+        assert_eq!(float.display_text(), "0.30");
+    }
+
+    #[test]
+    fn test_typed_input_is_validated() {
+        let mut state = NumberInputState::new(5).min(0.0);
+        assert!(!state.insert_char('-'));
+        assert!(!state.insert_char('.'));
+        assert!(!state.insert_char('x'));
+        assert!(state.insert_char('4'));
+        assert!(state.insert_char('2'));
+        assert_eq!(state.display_text(), "42");
+        assert!(state.commit());
+        assert_eq!(state.value_i64(), 42);
+
+        let mut float = NumberInputState::float(0.0).suffix(" kg");
+        for ch in "-1.5".chars() {
+            assert!(float.insert_char(ch));
+        }
+        assert!(!float.insert_char('.'));
+        assert_eq!(float.display_text(), "-1.5 kg");
+        float.commit();
+        assert_eq!(float.display_text(), "-1.50 kg");
+
+        // Unparseable text is discarded
+        float.insert_char('-');
+        assert!(!float.commit());
+        assert_eq!(float.value(), -1.5);
+    }
+
+    #[test]
+    fn test_handle_number_input_keys() {
+        let mut state = NumberInputState::new(3).range(0.0, 100.0);
+
+        let up = Key {
+            up_arrow: true,
+            ..Key::default()
+        };
+        assert_eq!(special(&mut state, up), InteractionOutcome::Changed(4.0));
+
+        let page_up = Key {
+            page_up: true,
+            ..Key::default()
+        };
+        assert_eq!(
+            special(&mut state, page_up),
+            InteractionOutcome::Changed(14.0)
+        );
+
+        let end = Key {
+            end: true,
+            ..Key::default()
+        };
+        assert_eq!(special(&mut state, end), InteractionOutcome::Changed(100.0));
+
+        assert_eq!(press(&mut state, "q"), InteractionOutcome::Ignored);
+        assert_eq!(press(&mut state, "7"), InteractionOutcome::Handled);
+        let enter = Key {
+            return_key: true,
+            ..Key::default()
+        };
+        assert_eq!(
+            special(&mut state, enter),
+            InteractionOutcome::Submitted(7.0)
+        );
+
+        let backspace = Key {
+            backspace: true,
+            ..Key::default()
+        };
+        special(&mut state, backspace);
+        assert_eq!(state.edit_text(), Some(""));
+        let escape = Key {
+            escape: true,
+            ..Key::default()
+        };
+        assert_eq!(special(&mut state, escape), InteractionOutcome::Handled);
+        assert_eq!(state.display_text(), "7");
+
+        let outcome = handle_number_input_with_mode(
+            &mut state,
+            "+",
+            &Key::default(),
+            InteractionMode::ReadOnly,
+        );
+        assert_eq!(outcome, InteractionOutcome::Ignored);
+        assert_eq!(press(&mut state, "+"), InteractionOutcome::Changed(8.0));
+    }
+
+    #[test]
+    fn test_number_input_mouse() {
+        let mut state = NumberInputState::new(5).suffix("%");
+        let mouse = |action, x| Mouse {
+            x,
+            y: 2,
+            action,
+            ctrl: false,
+            shift: false,
+            alt: false,
+        };
+
+        // "[-] 5% [+]" drawn at (10, 2)
+        assert_eq!(state.rendered_width(true), 10);
+        assert!(handle_number_input_mouse(
+            &mut state,
+            &mouse(MouseAction::ScrollUp, 14),
+            (10, 2),
+            true
+        ));
+        assert_eq!(state.value_i64(), 6);
+        assert!(handle_number_input_mouse(
+            &mut state,
+            &mouse(MouseAction::Press(MouseButton::Left), 11),
+            (10, 2),
+            true
+        ));
+        assert_eq!(state.value_i64(), 5);
+        assert!(handle_number_input_mouse(
+            &mut state,
+            &mouse(MouseAction::Press(MouseButton::Left), 19),
+            (10, 2),
+            true
+        ));
+        assert_eq!(state.value_i64(), 6);
+        // Clicking the value or outside does nothing
+        assert!(!handle_number_input_mouse(
+            &mut state,
+            &mouse(MouseAction::Press(MouseButton::Left), 14),
+            (10, 2),
+            true
+        ));
+        assert!(!handle_number_input_mouse(
+            &mut state,
+            &mouse(MouseAction::ScrollUp, 25),
+            (10, 2),
+            true
+        ));
+    }
+
+    #[test]
+    fn test_number_input_render() {
+        let state = NumberInputState::new(0).range(0.0, 5.0).suffix(" items");
+        let element = NumberInput::new(&state).buttons(true).into_element();
+        let plain = crate::testing::TestRenderer::new(30, 1).render_to_plain(&element);
+        assert_eq!(plain.trim_end(), "[-] 0 items [+]");
+    }
+}
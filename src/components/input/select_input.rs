@@ -4,9 +4,11 @@
 //! keyboard navigation internally.
 
 use crate::components::Box as RnkBox;
-use crate::components::navigation::{NavigationConfig, handle_list_navigation};
-use crate::components::selection_list::{ListStyle, indicator_padding, render_list};
-use crate::components::{InteractionMode, InteractionOutcome};
+use crate::components::navigation::{NavigationConfig, handle_list_navigation_with};
+use crate::components::selection_list::{
+    ListStyle, SelectableItem, indicator_padding, nearest_selectable, render_list,
+};
+use crate::components::{FocusStyle, InteractionMode, InteractionOutcome};
 use crate::core::{AccessibilityProps, AccessibilityRole, Color, Element};
 use crate::hooks::{Signal, use_input, use_signal};
 
@@ -14,9 +16,11 @@ use crate::hooks::{Signal, use_input, use_signal};
 #[derive(Debug, Clone)]
 pub struct SelectItem<T: Clone> {
     /// Display label for the item
-    pub label: String,
-    /// Value associated with the item
-    pub value: T,
+    label: String,
+    /// Value associated with the item, `None` for separators
+    value: Option<T>,
+    /// Whether the item is dimmed and skipped by navigation
+    disabled: bool,
 }
 
 impl<T: Clone> SelectItem<T> {
@@ -24,17 +28,65 @@ impl<T: Clone> SelectItem<T> {
     pub fn new(label: impl Into<String>, value: T) -> Self {
         Self {
             label: label.into(),
-            value,
+            value: Some(value),
+            disabled: false,
         }
     }
+
+    /// Create a separator row, drawn as a divider across the list
+    pub fn separator() -> Self {
+        Self {
+            label: String::new(),
+            value: None,
+            disabled: false,
+        }
+    }
+
+    /// Get the display label
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Get the value, or `None` for a separator
+    pub fn value(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+
+    /// Set whether this item is disabled (dimmed and skipped by navigation)
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Check if this item is disabled
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Check if this is a separator row
+    pub fn is_separator(&self) -> bool {
+        self.value.is_none()
+    }
+
+    /// Check if this item can be highlighted and submitted
+    pub fn is_selectable(&self) -> bool {
+        !self.disabled && !self.is_separator()
+    }
 }
 
 impl<T: Clone + ToString> From<T> for SelectItem<T> {
     fn from(value: T) -> Self {
-        Self {
-            label: value.to_string(),
-            value,
-        }
+        Self::new(value.to_string(), value)
+    }
+}
+
+impl<T: Clone> SelectableItem for SelectItem<T> {
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn is_separator(&self) -> bool {
+        self.value.is_none()
     }
 }
 
@@ -182,6 +234,8 @@ pub struct SelectInput<T: Clone + 'static> {
     number_shortcuts: bool,
     /// Input mode for disabled/read-only behavior.
     mode: InteractionMode,
+    /// Focus ring drawn around the list
+    focus_ring: Option<FocusStyle>,
 }
 
 impl<T: Clone + 'static> SelectInput<T> {
@@ -196,6 +250,7 @@ impl<T: Clone + 'static> SelectInput<T> {
             vim_navigation: true,
             number_shortcuts: true,
             mode: InteractionMode::Enabled,
+            focus_ring: None,
         }
     }
 
@@ -231,6 +286,12 @@ impl<T: Clone + 'static> SelectInput<T> {
         self
     }
 
+    /// Draw a focus ring around the list, e.g. `get_theme().focus_style()`
+    pub fn focus_ring(mut self, focus: FocusStyle) -> Self {
+        self.focus_ring = Some(focus);
+        self
+    }
+
     /// Enable or disable vim-style navigation (j/k keys)
     pub fn vim_navigation(mut self, enabled: bool) -> Self {
         self.vim_navigation = enabled;
@@ -298,7 +359,7 @@ impl<T: Clone + 'static> SelectInput<T> {
         }
 
         let items = self.items.clone();
-        let initial_highlighted = self.highlighted;
+        let initial_highlighted = nearest_selectable(&items, self.highlighted);
         let limit = self.limit;
         let style = self.style.clone();
         let is_focused = self.is_focused;
@@ -311,6 +372,7 @@ impl<T: Clone + 'static> SelectInput<T> {
         // Set up input handling if focused
         if is_focused {
             let items_len = items.len();
+            let selectable: Vec<bool> = items.iter().map(SelectItem::is_selectable).collect();
             let state_for_input = state_signal.clone();
 
             use_input(move |input, key| {
@@ -319,7 +381,15 @@ impl<T: Clone + 'static> SelectInput<T> {
                     .number_shortcuts(number_shortcuts);
 
                 let mut next = state_for_input.get();
-                let outcome = handle_select_input(&mut next, items_len, input, key, &config, mode);
+                let outcome = handle_select_input_with(
+                    &mut next,
+                    items_len,
+                    input,
+                    key,
+                    &config,
+                    mode,
+                    |i| selectable[i],
+                );
                 if outcome.is_handled() {
                     state_for_input.set(next);
                 }
@@ -335,10 +405,16 @@ impl<T: Clone + 'static> SelectInput<T> {
             .read_only(mode.is_read_only())
             .focusable(is_focused && !mode.is_disabled());
         if let Some(item) = items.get(highlighted) {
-            accessibility = accessibility.value(item.label.clone());
+            accessibility = accessibility.value(item.label());
         }
 
-        render_select_list(&items, state_signal, limit, &style).with_accessibility(accessibility)
+        let list = render_select_list(&items, state_signal, limit, &style);
+        match self.focus_ring {
+            Some(ring) => ring
+                .wrap(list, is_focused)
+                .with_accessibility(accessibility),
+            None => list.with_accessibility(accessibility),
+        }
     }
 }
 
@@ -350,6 +426,23 @@ pub fn handle_select_input(
     key: &crate::hooks::Key,
     config: &NavigationConfig,
     mode: InteractionMode,
+) -> InteractionOutcome<usize> {
+    handle_select_input_with(state, item_count, input, key, config, mode, |_| true)
+}
+
+/// Handle SelectInput input, skipping items that cannot be selected
+///
+/// Like [`handle_select_input`], but navigation only lands on indices for
+/// which `is_selectable` returns true (see [`SelectItem::is_selectable`]),
+/// and a highlight left on an unselectable item is never submitted.
+pub fn handle_select_input_with(
+    state: &mut SelectInputState,
+    item_count: usize,
+    input: &str,
+    key: &crate::hooks::Key,
+    config: &NavigationConfig,
+    mode: InteractionMode,
+    is_selectable: impl Fn(usize) -> bool,
 ) -> InteractionOutcome<usize> {
     if mode.is_disabled() || item_count == 0 {
         return InteractionOutcome::Ignored;
@@ -363,7 +456,8 @@ pub fn handle_select_input(
     }
 
     let current = state.highlighted;
-    let result = handle_list_navigation(current, item_count, input, *key, config);
+    let result =
+        handle_list_navigation_with(current, item_count, input, *key, config, &is_selectable);
     if result.is_moved() {
         let new_pos = result.unwrap_or(current);
         if new_pos != current {
@@ -376,7 +470,7 @@ pub fn handle_select_input(
         return InteractionOutcome::Ignored;
     }
 
-    if key.return_key || key.space {
+    if (key.return_key || key.space) && is_selectable(state.highlighted) {
         state.submitted = Some(state.highlighted);
         return InteractionOutcome::Submitted(state.highlighted);
     }
@@ -441,8 +535,8 @@ mod tests {
     #[test]
     fn test_select_item_creation() {
         let item = SelectItem::new("Test", 42);
-        assert_eq!(item.label, "Test");
-        assert_eq!(item.value, 42);
+        assert_eq!(item.label(), "Test");
+        assert_eq!(item.value(), Some(&42));
     }
 
     #[test]
@@ -457,6 +551,42 @@ mod tests {
         assert!(!select.is_empty());
     }
 
+    #[test]
+    fn test_select_input_focus_ring() {
+        use crate::components::{Theme, focus_border_style, with_theme};
+        use crate::hooks::context::{HookContext, with_hooks};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let ring = Theme::dark().focus_style();
+        let render = |focused: bool| {
+            let ctx = Rc::new(RefCell::new(HookContext::new()));
+            with_hooks(ctx, || {
+                SelectInput::new(vec![SelectItem::new("One", 1)])
+                    .focused(focused)
+                    .focus_ring(ring)
+                    .into_element()
+            })
+        };
+
+        with_theme(Theme::dark(), |_| {
+            let focused = render(true);
+            assert_eq!(
+                focused.style.border_style,
+                focus_border_style(true).border_style
+            );
+            assert_eq!(
+                focused.style.border_color,
+                focus_border_style(true).border_color
+            );
+            let rest = render(false);
+            assert_eq!(
+                rest.style.border_color,
+                focus_border_style(false).border_color
+            );
+        });
+    }
+
     #[test]
     fn test_select_input_empty() {
         let select: SelectInput<i32> = SelectInput::new(vec![]);
@@ -577,6 +707,69 @@ mod tests {
         assert!(state.is_cancelled());
     }
 
+    #[test]
+    fn test_handle_select_input_skips_disabled_and_separators() {
+        let items = [
+            SelectItem::new("One", 1),
+            SelectItem::separator(),
+            SelectItem::new("Two", 2).disabled(true),
+            SelectItem::new("Three", 3),
+        ];
+        let selectable = |i: usize| items[i].is_selectable();
+        let mut state = SelectInputState::new(0);
+        let config = NavigationConfig::new().number_shortcuts(true);
+        let down = crate::hooks::Key {
+            down_arrow: true,
+            ..Default::default()
+        };
+
+        let outcome = handle_select_input_with(
+            &mut state,
+            items.len(),
+            "",
+            &down,
+            &config,
+            InteractionMode::Enabled,
+            selectable,
+        );
+        assert_eq!(outcome, InteractionOutcome::Handled);
+        assert_eq!(state.highlighted(), 3);
+
+        // A stale highlight on a disabled item is never submitted
+        let mut state = SelectInputState::new(2);
+        let enter = crate::hooks::Key {
+            return_key: true,
+            ..Default::default()
+        };
+        let outcome = handle_select_input_with(
+            &mut state,
+            items.len(),
+            "",
+            &enter,
+            &config,
+            InteractionMode::Enabled,
+            selectable,
+        );
+        assert_eq!(outcome, InteractionOutcome::Ignored);
+    }
+
+    #[test]
+    fn test_select_input_renders_disabled_and_separator() {
+        use crate::testing::TestRenderer;
+
+        let items = vec![
+            SelectItem::new("One", 1),
+            SelectItem::separator(),
+            SelectItem::new("Two", 2).disabled(true),
+        ];
+        let element = SelectInput::new(items).focused(false).into_element();
+        let output = TestRenderer::new(20, 5).render_to_plain(&element);
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(lines[0], "❯ One");
+        assert_eq!(lines[1], "─────");
+        assert_eq!(lines[2], "  Two");
+    }
+
     #[test]
     fn test_handle_select_input_modes() {
         let config = NavigationConfig::new().vim_navigation(true);
@@ -3,6 +3,29 @@
 use crate::components::navigation::calculate_visible_range;
 use crate::components::{Box as RnkBox, Text};
 use crate::core::{Color, Element, FlexDirection};
+use crate::layout::measure::measure_text_width;
+
+/// Per-item flags shared by selectable list items.
+pub(crate) trait SelectableItem {
+    fn is_disabled(&self) -> bool;
+    fn is_separator(&self) -> bool;
+
+    fn is_selectable(&self) -> bool {
+        !self.is_disabled() && !self.is_separator()
+    }
+}
+
+/// First selectable index at or after `from`, else the last one before it.
+pub(crate) fn nearest_selectable<T: SelectableItem>(items: &[T], from: usize) -> usize {
+    (from..items.len())
+        .find(|&i| items[i].is_selectable())
+        .or_else(|| {
+            (0..from.min(items.len()))
+                .rev()
+                .find(|&i| items[i].is_selectable())
+        })
+        .unwrap_or(from)
+}
 
 /// Shared style accessors for selectable lists.
 pub(crate) trait ListStyle {
@@ -20,6 +43,9 @@ pub(crate) fn indicator_padding(indicator: &str) -> String {
 }
 
 /// Render a selectable list with shared highlight handling.
+///
+/// Disabled items are dimmed and never highlighted; separators draw a
+/// divider as wide as the widest item.
 pub(crate) fn render_list<T, S, F, G>(
     items: &[T],
     highlighted: usize,
@@ -29,6 +55,7 @@ pub(crate) fn render_list<T, S, F, G>(
     decorate: G,
 ) -> Element
 where
+    T: SelectableItem,
     S: ListStyle,
     F: Fn(&T, usize, bool, &str) -> String,
     G: Fn(&T, usize, &S, bool, Text) -> Text,
@@ -37,9 +64,24 @@ where
     let (start, end) = calculate_visible_range(highlighted, total_items, limit);
 
     let mut container = RnkBox::new().flex_direction(FlexDirection::Column);
+    let separator_width = items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| !item.is_separator())
+        .map(|(idx, item)| {
+            measure_text_width(&label_builder(item, idx, false, style.indicator_padding()))
+        })
+        .max()
+        .unwrap_or(0)
+        .max(1);
 
     for (idx, item) in items.iter().enumerate().take(end).skip(start) {
-        let is_highlighted = idx == highlighted;
+        if item.is_separator() {
+            let line = "─".repeat(separator_width);
+            container = container.child(Text::new(line).dim().into_element());
+            continue;
+        }
+        let is_highlighted = idx == highlighted && !item.is_disabled();
 
         let prefix = if is_highlighted {
             style.indicator()
@@ -50,7 +92,9 @@ where
         let label = label_builder(item, idx, is_highlighted, prefix);
         let mut text = Text::new(&label);
 
-        if is_highlighted {
+        if item.is_disabled() {
+            text = text.dim();
+        } else if is_highlighted {
             if let Some(color) = style.highlight_color() {
                 text = text.color(color);
             }
@@ -1,8 +1,8 @@
 //! TextInput component - Single-line text input with cursor
 
-use crate::components::{Box, InteractionMode, InteractionOutcome, Text};
-use crate::core::{AccessibilityProps, AccessibilityRole, Color, Element, FlexDirection};
-use crate::hooks::{FocusState, UseFocusOptions, use_focus, use_input, use_signal};
+use crate::components::{Box, FocusStyle, InteractionMode, InteractionOutcome, Text};
+use crate::core::{AccessibilityProps, AccessibilityRole, Color, Element, FlexDirection, Style};
+use crate::hooks::{FocusState, UseFocusOptions, use_focus, use_input, use_paste, use_signal};
 
 /// A single-line text input component
 ///
@@ -20,12 +20,20 @@ use crate::hooks::{FocusState, UseFocusOptions, use_focus, use_input, use_signal
 ///         .into_element()
 /// }
 /// ```
-#[derive(Clone, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct TextInputState {
     /// Current text value
     value: String,
     /// Cursor position (character index)
     cursor: usize,
+    /// Whether the field masks its value, as last seen from its options
+    masked: bool,
+    /// Whether a masked value is temporarily shown in plain text
+    revealed: bool,
+    /// Whether Caps Lock was on for the last typed key
+    caps_lock: bool,
+    /// Where the selection started; the cursor is its other end
+    anchor: Option<usize>,
 }
 
 impl TextInputState {
@@ -43,30 +51,121 @@ impl TextInputState {
     pub fn set_value(&mut self, value: impl Into<String>) {
         self.value = value.into();
         self.cursor = self.value.chars().count();
+        self.anchor = None;
     }
 
     /// Clear the input
     pub fn clear(&mut self) {
         self.value.clear();
         self.cursor = 0;
+        self.anchor = None;
     }
 
-    /// Insert character at cursor
+    /// Selected range as character indices, if any text is selected
+    pub fn selected_range(&self) -> Option<std::ops::Range<usize>> {
+        let anchor = self.anchor?;
+        let range = anchor.min(self.cursor)..anchor.max(self.cursor);
+        (!range.is_empty()).then_some(range)
+    }
+
+    /// Selected text, if any
+    ///
+    /// Returns `None` while a masked value is hidden, so a password can't
+    /// be copied out of the field without revealing it first.
+    pub fn selected_text(&self) -> Option<String> {
+        if self.masked && !self.revealed {
+            return None;
+        }
+        let range = self.selected_range()?;
+        Some(self.value[self.byte_pos(range.start)..self.byte_pos(range.end)].to_string())
+    }
+
+    /// Check if there's an active selection
+    pub fn has_selection(&self) -> bool {
+        self.selected_range().is_some()
+    }
+
+    /// Select all text, leaving the cursor at the end
+    pub fn select_all(&mut self) {
+        self.anchor = Some(0);
+        self.cursor = self.char_count();
+    }
+
+    /// Clear the selection, keeping the cursor where it is
+    pub fn clear_selection(&mut self) {
+        self.anchor = None;
+    }
+
+    /// Extend the selection one character left (Shift+Left)
+    pub fn select_left(&mut self) {
+        self.extend_selection(self.cursor.saturating_sub(1));
+    }
+
+    /// Extend the selection one character right (Shift+Right)
+    pub fn select_right(&mut self) {
+        self.extend_selection((self.cursor + 1).min(self.char_count()));
+    }
+
+    /// Extend the selection to the start (Shift+Home)
+    pub fn select_to_start(&mut self) {
+        self.extend_selection(0);
+    }
+
+    /// Extend the selection to the end (Shift+End)
+    pub fn select_to_end(&mut self) {
+        self.extend_selection(self.char_count());
+    }
+
+    /// Delete the selected text, returning whether anything was selected
+    pub fn delete_selection(&mut self) -> bool {
+        let Some(range) = self.take_selection() else {
+            return false;
+        };
+        let (start, end) = (self.byte_pos(range.start), self.byte_pos(range.end));
+        self.value.drain(start..end);
+        self.cursor = range.start;
+        true
+    }
+
+    /// Toggle showing a masked value in plain text
+    ///
+    /// Only affects rendering; [`value`](Self::value) always returns the
+    /// real text.
+    pub fn toggle_reveal(&mut self) {
+        self.revealed = !self.revealed;
+    }
+
+    /// Whether a masked value is currently revealed
+    pub fn is_revealed(&self) -> bool {
+        self.revealed
+    }
+
+    /// Whether Caps Lock was on for the last typed key
+    pub fn caps_lock(&self) -> bool {
+        self.caps_lock
+    }
+
+    /// Insert character at cursor, replacing the selection
     pub fn insert(&mut self, ch: char) {
+        self.delete_selection();
         let byte_pos = self.cursor_byte_pos();
         self.value.insert(byte_pos, ch);
         self.cursor += 1;
     }
 
-    /// Insert string at cursor
+    /// Insert string at cursor, replacing the selection
     pub fn insert_str(&mut self, s: &str) {
+        self.delete_selection();
         let byte_pos = self.cursor_byte_pos();
         self.value.insert_str(byte_pos, s);
         self.cursor += s.chars().count();
     }
 
-    /// Delete character before cursor (backspace)
+    /// Delete the selection or the character before cursor (backspace)
     pub fn backspace(&mut self) {
+        if self.delete_selection() {
+            return;
+        }
         if self.cursor > 0 {
             let byte_pos = self.cursor_byte_pos();
             let prev_char_start = self.prev_char_byte_pos();
@@ -75,8 +174,11 @@ impl TextInputState {
         }
     }
 
-    /// Delete character at cursor (delete)
+    /// Delete the selection or the character at cursor (delete)
     pub fn delete(&mut self) {
+        if self.delete_selection() {
+            return;
+        }
         let byte_pos = self.cursor_byte_pos();
         if byte_pos < self.value.len() {
             let next_char_end = self.next_char_byte_pos();
@@ -84,30 +186,47 @@ impl TextInputState {
         }
     }
 
-    /// Move cursor left
+    /// Move cursor left, or to the start of the selection
     pub fn move_left(&mut self) {
-        if self.cursor > 0 {
-            self.cursor -= 1;
+        match self.take_selection() {
+            Some(range) => self.cursor = range.start,
+            None => self.cursor = self.cursor.saturating_sub(1),
         }
     }
 
-    /// Move cursor right
+    /// Move cursor right, or to the end of the selection
     pub fn move_right(&mut self) {
-        if self.cursor < self.char_count() {
-            self.cursor += 1;
+        match self.take_selection() {
+            Some(range) => self.cursor = range.end,
+            None => self.cursor = (self.cursor + 1).min(self.char_count()),
         }
     }
 
     /// Move cursor to start
     pub fn move_to_start(&mut self) {
+        self.anchor = None;
         self.cursor = 0;
     }
 
     /// Move cursor to end
     pub fn move_to_end(&mut self) {
+        self.anchor = None;
         self.cursor = self.char_count();
     }
 
+    /// Move the cursor, starting a selection at the old position if needed
+    fn extend_selection(&mut self, cursor: usize) {
+        self.anchor.get_or_insert(self.cursor);
+        self.cursor = cursor;
+    }
+
+    /// Clear the selection, returning its range
+    fn take_selection(&mut self) -> Option<std::ops::Range<usize>> {
+        let range = self.selected_range();
+        self.anchor = None;
+        range
+    }
+
     /// Get character count
     fn char_count(&self) -> usize {
         self.value.chars().count()
@@ -115,9 +234,14 @@ impl TextInputState {
 
     /// Get byte position of cursor
     fn cursor_byte_pos(&self) -> usize {
+        self.byte_pos(self.cursor)
+    }
+
+    /// Get byte position of a character index
+    fn byte_pos(&self, char_idx: usize) -> usize {
         self.value
             .char_indices()
-            .nth(self.cursor)
+            .nth(char_idx)
             .map(|(i, _)| i)
             .unwrap_or(self.value.len())
     }
@@ -155,7 +279,7 @@ pub struct TextInputOptions {
     pub placeholder: Option<String>,
     /// Whether to mask input (for passwords)
     pub mask: bool,
-    /// Mask character (default: '*')
+    /// Mask character (default: '•')
     pub mask_char: char,
     /// Maximum length (0 = unlimited)
     pub max_length: usize,
@@ -169,6 +293,8 @@ pub struct TextInputOptions {
     pub cursor_color: Option<Color>,
     /// Input mode for disabled/read-only behavior.
     pub mode: InteractionMode,
+    /// Focus ring drawn around the field (none by default)
+    pub focus_ring: Option<FocusStyle>,
 }
 
 impl Default for TextInputOptions {
@@ -176,13 +302,14 @@ impl Default for TextInputOptions {
         Self {
             placeholder: None,
             mask: false,
-            mask_char: '*',
+            mask_char: '•',
             max_length: 0,
             focus: UseFocusOptions::default(),
             color: None,
             placeholder_color: None,
             cursor_color: None,
             mode: InteractionMode::Enabled,
+            focus_ring: None,
         }
     }
 }
@@ -197,8 +324,15 @@ impl TextInputOptions {
         self
     }
 
-    pub fn mask(mut self) -> Self {
-        self.mask = true;
+    /// Render every character as `mask` (password mode), or `None` for
+    /// plain text
+    ///
+    /// Ctrl+R toggles revealing the plain text while the field is focused.
+    pub fn mask(mut self, mask: Option<char>) -> Self {
+        self.mask = mask.is_some();
+        if let Some(ch) = mask {
+            self.mask_char = ch;
+        }
         self
     }
 
@@ -249,6 +383,12 @@ impl TextInputOptions {
         self.mode = InteractionMode::ReadOnly;
         self
     }
+
+    /// Draw a focus ring around the field, e.g. `get_theme().focus_style()`.
+    pub fn focus_ring(mut self, focus: FocusStyle) -> Self {
+        self.focus_ring = Some(focus);
+        self
+    }
 }
 
 /// Handle for controlling the text input
@@ -275,6 +415,11 @@ impl TextInputHandle {
         self.state.update(|s| s.clear());
     }
 
+    /// Toggle showing a masked value in plain text
+    pub fn toggle_reveal(&self) {
+        self.state.update(|s| s.toggle_reveal());
+    }
+
     /// Check if focused
     pub fn is_focused(&self) -> bool {
         self.focus.is_focused
@@ -282,8 +427,37 @@ impl TextInputHandle {
 
     /// Render the text input element
     pub fn view(&self) -> Element {
+        let field = self.field_view();
+        match &self.options.focus_ring {
+            Some(ring) => ring.wrap(field, self.focus.is_focused),
+            None => field,
+        }
+    }
+
+    fn field_view(&self) -> Element {
         let state = self.state.get();
         let options = &self.options;
+        // Focused label emphasis from the focus ring, if any
+        let label = match &options.focus_ring {
+            Some(ring) => ring.label_style(
+                Style {
+                    color: options.color,
+                    ..Style::new()
+                },
+                self.focus.is_focused,
+            ),
+            None => Style {
+                color: options.color,
+                ..Style::new()
+            },
+        };
+        let styled = |text: Text| {
+            let text = match label.color {
+                Some(color) => text.color(color),
+                None => text,
+            };
+            if label.bold { text.bold() } else { text }
+        };
         let accessible_label = options
             .placeholder
             .clone()
@@ -293,8 +467,9 @@ impl TextInputHandle {
             .disabled(options.mode.is_disabled())
             .read_only(options.mode.is_read_only())
             .focusable(!options.mode.is_disabled());
+        let masked = options.mask && !state.revealed;
         if !state.value.is_empty() {
-            let accessible_value = if options.mask {
+            let accessible_value = if masked {
                 options
                     .mask_char
                     .to_string()
@@ -315,7 +490,7 @@ impl TextInputHandle {
                 return text.into_element().with_accessibility(accessibility);
             }
             String::new()
-        } else if options.mask {
+        } else if masked {
             // Mask the input
             options
                 .mask_char
@@ -326,51 +501,60 @@ impl TextInputHandle {
         };
 
         if self.focus.is_focused {
-            // Split at cursor position for rendering
-            let chars: Vec<char> = display_value.chars().collect();
-            let (before, after) = chars.split_at(state.cursor.min(chars.len()));
-            let before: String = before.iter().collect();
-            let after: String = after.iter().collect();
-
-            let cursor_char = if after.is_empty() {
-                ' '
-            } else {
-                after.chars().next().unwrap_or(' ')
+            #[derive(Clone, Copy, PartialEq)]
+            enum Cell {
+                Plain,
+                Selected,
+                Cursor,
+            }
+
+            // A cursor past the last character sits on a blank cell
+            let mut chars: Vec<char> = display_value.chars().collect();
+            let cursor = state.cursor.min(chars.len());
+            if cursor == chars.len() {
+                chars.push(' ');
+            }
+            let selected = state.selected_range().unwrap_or_default();
+            let cell = |i: usize| {
+                if i == cursor {
+                    Cell::Cursor
+                } else if selected.contains(&i) {
+                    Cell::Selected
+                } else {
+                    Cell::Plain
+                }
             };
-            let after_cursor: String = after.chars().skip(1).collect();
 
             let cursor_color = options.cursor_color.unwrap_or(Color::Yellow);
 
-            Box::new()
-                .flex_direction(FlexDirection::Row)
-                .child({
-                    let mut text = Text::new(&before);
-                    if let Some(color) = options.color {
-                        text = text.color(color);
-                    }
-                    text.into_element()
-                })
-                .child(
-                    Text::new(cursor_char.to_string())
-                        .background(cursor_color)
-                        .color(Color::Black)
+            // Split into runs of cells drawn the same way
+            let mut row = Box::new().flex_direction(FlexDirection::Row);
+            let mut start = 0;
+            for end in 1..=chars.len() {
+                if end < chars.len() && cell(end) == cell(start) {
+                    continue;
+                }
+                let run: String = chars[start..end].iter().collect();
+                let text = match cell(start) {
+                    Cell::Plain => styled(Text::new(run)),
+                    Cell::Selected => styled(Text::new(run)).inverse(),
+                    Cell::Cursor => Text::new(run).background(cursor_color).color(Color::Black),
+                };
+                row = row.child(text.into_element());
+                start = end;
+            }
+            if options.mask && state.caps_lock {
+                row = row.child(
+                    Text::new(" ⇪ Caps Lock")
+                        .color(Color::Yellow)
                         .into_element(),
-                )
-                .child({
-                    let mut text = Text::new(&after_cursor);
-                    if let Some(color) = options.color {
-                        text = text.color(color);
-                    }
-                    text.into_element()
-                })
+                );
+            }
+            row.into_element().with_accessibility(accessibility)
+        } else {
+            styled(Text::new(&display_value))
                 .into_element()
                 .with_accessibility(accessibility)
-        } else {
-            let mut text = Text::new(&display_value);
-            if let Some(color) = options.color {
-                text = text.color(color);
-            }
-            text.into_element().with_accessibility(accessibility)
         }
     }
 }
@@ -403,9 +587,13 @@ impl TextInputHandle {
 /// }
 /// ```
 pub fn use_text_input(options: TextInputOptions) -> TextInputHandle {
-    let state = use_signal(TextInputState::default);
+    let state = use_signal(|| TextInputState {
+        masked: options.mask,
+        ..TextInputState::default()
+    });
     let focus = use_focus(options.focus.clone());
     let input_options = options.clone();
+    let paste_options = options.clone();
 
     // Handle input when focused
     use_input({
@@ -425,6 +613,26 @@ pub fn use_text_input(options: TextInputOptions) -> TextInputHandle {
         }
     });
 
+    // Pasted text goes through the same path as typed text, so max length
+    // and read-only/disabled modes apply; the field is single-line.
+    use_paste({
+        let state = state.clone();
+        let is_focused = focus.is_focused;
+
+        move |event| {
+            if !is_focused {
+                return;
+            }
+
+            let content: String = event.content.chars().filter(|c| !c.is_control()).collect();
+            let mut next = state.get();
+            let key = crate::hooks::Key::default();
+            if handle_text_input(&mut next, &content, &key, &paste_options).is_changed() {
+                state.set(next);
+            }
+        }
+    });
+
     TextInputHandle {
         state,
         focus,
@@ -435,7 +643,10 @@ pub fn use_text_input(options: TextInputOptions) -> TextInputHandle {
 /// Handle a text input key event against explicit state.
 ///
 /// Enabled mode edits state and returns changed/submitted/cancelled outcomes.
-/// Read-only mode allows cursor movement but blocks value mutation and submit.
+/// Shift+Left/Right/Home/End extend the selection and Ctrl+A selects all;
+/// typed text replaces the selection.
+/// Read-only mode allows cursor movement and selection but blocks value
+/// mutation and submit.
 /// Disabled mode ignores every input and leaves state unchanged.
 pub fn handle_text_input(
     state: &mut TextInputState,
@@ -443,6 +654,7 @@ pub fn handle_text_input(
     key: &crate::hooks::Key,
     options: &TextInputOptions,
 ) -> InteractionOutcome<String> {
+    state.masked = options.mask;
     if options.mode.is_disabled() {
         return InteractionOutcome::Ignored;
     }
@@ -452,25 +664,55 @@ pub fn handle_text_input(
     }
 
     if key.left_arrow {
-        state.move_left();
+        if key.shift {
+            state.select_left();
+        } else {
+            state.move_left();
+        }
         return InteractionOutcome::Handled;
     }
 
     if key.right_arrow {
-        state.move_right();
+        if key.shift {
+            state.select_right();
+        } else {
+            state.move_right();
+        }
         return InteractionOutcome::Handled;
     }
 
-    if key.home || (key.ctrl && input == "a") {
-        state.move_to_start();
+    if key.ctrl && input == "a" {
+        state.select_all();
+        return InteractionOutcome::Handled;
+    }
+
+    if key.home {
+        if key.shift {
+            state.select_to_start();
+        } else {
+            state.move_to_start();
+        }
         return InteractionOutcome::Handled;
     }
 
     if key.end || (key.ctrl && input == "e") {
-        state.move_to_end();
+        if key.shift {
+            state.select_to_end();
+        } else {
+            state.move_to_end();
+        }
         return InteractionOutcome::Handled;
     }
 
+    if options.mask && key.ctrl && input == "r" {
+        state.toggle_reveal();
+        return InteractionOutcome::Handled;
+    }
+
+    if key.character.is_some() {
+        state.caps_lock = key.caps_lock;
+    }
+
     if !options.mode.is_enabled() {
         return InteractionOutcome::Ignored;
     }
@@ -497,7 +739,10 @@ pub fn handle_text_input(
         let remaining = if options.max_length == 0 {
             input.chars().count()
         } else {
-            options.max_length.saturating_sub(state.char_count())
+            let selected = state.selected_range().map_or(0, |range| range.len());
+            options
+                .max_length
+                .saturating_sub(state.char_count() - selected)
         };
         if remaining == 0 {
             return InteractionOutcome::Ignored;
@@ -516,6 +761,7 @@ pub fn handle_text_input(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyEventState, KeyModifiers};
 
     #[test]
     fn test_text_input_state_basic() {
@@ -532,6 +778,27 @@ mod tests {
         assert_eq!(state.cursor, 5);
     }
 
+    #[test]
+    fn test_text_input_focus_ring_wraps_field() {
+        use crate::components::get_theme;
+        use crate::hooks::context::{HookContext, with_hooks};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let ring = get_theme().focus_style();
+        let ctx = Rc::new(RefCell::new(HookContext::new()));
+        let element = with_hooks(ctx, || {
+            let input = use_text_input(TextInputOptions::new().focus_ring(ring));
+            input.set_value("hello");
+            input.view()
+        });
+
+        // Not focused without a focus manager, so the rest border is drawn
+        assert_eq!(element.style.border_style, ring.rest_border);
+        assert_eq!(element.style.border_color, Some(ring.rest_color));
+        assert_eq!(element.children.len(), 1);
+    }
+
     #[test]
     fn test_text_input_state_backspace() {
         let mut state = TextInputState::default();
@@ -592,6 +859,81 @@ mod tests {
         assert_eq!(state.cursor, 1);
     }
 
+    #[test]
+    fn test_text_input_state_selection() {
+        let mut state = TextInputState::default();
+        state.set_value("你好 world");
+        assert_eq!(state.selected_range(), None);
+
+        state.select_left();
+        state.select_left();
+        assert_eq!(state.selected_range(), Some(6..8));
+        assert_eq!(state.selected_text().as_deref(), Some("ld"));
+
+        // A plain move collapses the selection to its edge
+        state.move_left();
+        assert_eq!(state.cursor(), 6);
+        assert!(!state.has_selection());
+
+        state.move_to_start();
+        state.select_right();
+        state.select_right();
+        assert_eq!(state.selected_text().as_deref(), Some("你好"));
+        state.insert('x');
+        assert_eq!(state.value(), "x world");
+        assert_eq!(state.cursor(), 1);
+
+        state.select_to_end();
+        state.backspace();
+        assert_eq!(state.value(), "x");
+        assert!(!state.has_selection());
+    }
+
+    #[test]
+    fn test_handle_text_input_selection_keys() {
+        use crate::hooks::Key;
+
+        let mut state = TextInputState::default();
+        let options = TextInputOptions::new().max_length(6);
+        state.set_value("abcdef");
+
+        let shift_home = Key {
+            home: true,
+            shift: true,
+            ..Default::default()
+        };
+        let outcome = handle_text_input(&mut state, "", &shift_home, &options);
+        assert_eq!(outcome, InteractionOutcome::Handled);
+        assert_eq!(state.selected_text().as_deref(), Some("abcdef"));
+
+        let shift_right = Key {
+            right_arrow: true,
+            shift: true,
+            ..Default::default()
+        };
+        handle_text_input(&mut state, "", &shift_right, &options);
+        assert_eq!(state.selected_text().as_deref(), Some("bcdef"));
+
+        // Typing replaces the selection, and the selected characters don't
+        // count against the max length
+        let outcome = handle_text_input(&mut state, "XYZ", &Key::default(), &options);
+        assert_eq!(outcome, InteractionOutcome::Changed("aXYZ".to_string()));
+
+        let ctrl_a = Key {
+            ctrl: true,
+            ..Default::default()
+        };
+        let outcome = handle_text_input(&mut state, "a", &ctrl_a, &options);
+        assert_eq!(outcome, InteractionOutcome::Handled);
+        assert_eq!(state.selected_range(), Some(0..4));
+
+        // Selecting is allowed in read-only mode, for copying
+        let mut read_only = TextInputState::default();
+        read_only.set_value("ro");
+        handle_text_input(&mut read_only, "a", &ctrl_a, &options.clone().read_only());
+        assert_eq!(read_only.selected_text().as_deref(), Some("ro"));
+    }
+
     #[test]
     fn test_handle_text_input_change_submit_and_cancel() {
         let mut state = TextInputState::default();
@@ -624,6 +966,118 @@ mod tests {
         assert_eq!(outcome, InteractionOutcome::Cancelled);
     }
 
+    #[test]
+    fn test_masked_input_reveal_toggle_keeps_value() {
+        let mut state = TextInputState::default();
+        let options = TextInputOptions::new().mask(Some('•'));
+        let ctrl_r = crate::hooks::Key {
+            ctrl: true,
+            ..Default::default()
+        };
+
+        handle_text_input(&mut state, "pw", &crate::hooks::Key::default(), &options);
+        assert_eq!(state.value(), "pw");
+        assert!(!state.is_revealed());
+
+        let outcome = handle_text_input(&mut state, "r", &ctrl_r, &options);
+        assert_eq!(outcome, InteractionOutcome::Handled);
+        assert!(state.is_revealed());
+        assert_eq!(state.value(), "pw");
+
+        // Ctrl+R is left alone for unmasked fields
+        let mut plain = TextInputState::default();
+        let outcome = handle_text_input(&mut plain, "r", &ctrl_r, &TextInputOptions::new());
+        assert_eq!(outcome, InteractionOutcome::Ignored);
+        assert!(!plain.is_revealed());
+    }
+
+    #[test]
+    fn test_masked_selection_hidden_until_revealed() {
+        let mut state = TextInputState::default();
+        let options = TextInputOptions::new().mask(Some('•'));
+        let ctrl = |input| {
+            (
+                input,
+                crate::hooks::Key {
+                    ctrl: true,
+                    ..Default::default()
+                },
+            )
+        };
+
+        handle_text_input(
+            &mut state,
+            "secret",
+            &crate::hooks::Key::default(),
+            &options,
+        );
+        let (input, key) = ctrl("a");
+        handle_text_input(&mut state, input, &key, &options);
+        assert!(state.has_selection());
+        assert_eq!(state.selected_text(), None);
+
+        let (input, key) = ctrl("r");
+        handle_text_input(&mut state, input, &key, &options);
+        assert_eq!(state.selected_text().as_deref(), Some("secret"));
+    }
+
+    #[test]
+    fn test_masked_input_renders_mask_until_revealed() {
+        use crate::hooks::context::{HookContext, with_hooks};
+        use crate::testing::TestRenderer;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let ctx = Rc::new(RefCell::new(HookContext::new()));
+        let render = || {
+            with_hooks(ctx.clone(), || {
+                let input = use_text_input(TextInputOptions::new().mask(Some('•')));
+                if input.value().is_empty() {
+                    input.set_value("secret");
+                }
+                input
+            })
+        };
+
+        let input = render();
+        let renderer = TestRenderer::new(20, 1);
+        assert_eq!(renderer.render_to_plain(&input.view()).trim(), "••••••");
+
+        input.toggle_reveal();
+        let input = render();
+        assert_eq!(renderer.render_to_plain(&input.view()).trim(), "secret");
+        assert_eq!(input.value(), "secret");
+    }
+
+    #[test]
+    fn test_mask_none_disables_masking() {
+        let options = TextInputOptions::new().mask(Some('#')).mask(None);
+        assert!(!options.mask);
+        assert_eq!(TextInputOptions::new().mask_char, '•');
+    }
+
+    #[test]
+    fn test_caps_lock_tracked_from_typed_keys() {
+        let mut state = TextInputState::default();
+        let options = TextInputOptions::new().mask(Some('•'));
+        let event = |ch, state| {
+            let mut event = KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE);
+            event.state = state;
+            let key = crate::hooks::Key::from_event(&event);
+            (crate::hooks::Key::char_from_event(&event), key)
+        };
+
+        // Lock state as a terminal with keyboard enhancement reports it
+        let (input, caps) = event('A', KeyEventState::CAPS_LOCK);
+        handle_text_input(&mut state, &input, &caps, &options);
+        assert!(state.caps_lock());
+
+        let (input, plain) = event('b', KeyEventState::NONE);
+        handle_text_input(&mut state, &input, &plain, &options);
+        assert!(!state.caps_lock());
+        assert_eq!(state.value(), "Ab");
+    }
+
     #[test]
     fn test_handle_text_input_modes() {
         let mut state = TextInputState::default();
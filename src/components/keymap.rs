@@ -87,6 +87,12 @@ impl Modifiers {
         alt: false,
         shift: true,
     };
+
+    pub const CTRL_ALT: Self = Self {
+        ctrl: true,
+        alt: true,
+        shift: false,
+    };
 }
 
 impl KeyBinding {
@@ -1,9 +1,12 @@
 //! Box component - Flexbox container
 
+use crate::components::Scrollbar;
 use crate::core::{
-    AlignItems, AlignSelf, BorderStyle, Color, Dimension, Display, Edges, Element, ElementType,
-    FlexDirection, JustifyContent, Overflow, Position, Style,
+    AlignItems, AlignSelf, Anchor, Backdrop, BorderStyle, Color, ColorFilter, Dimension, Display,
+    Edges, Element, ElementType, FlexDirection, JustifyContent, Overflow, Position, ShadowStyle,
+    Style, TransitionProperty,
 };
+use crate::hooks::ScrollState;
 
 /// Generate a Box style setter that directly assigns a value.
 macro_rules! style_setter {
@@ -57,6 +60,7 @@ pub struct Box {
     key: Option<String>,
     scroll_offset_x: Option<u16>,
     scroll_offset_y: Option<u16>,
+    scroll_state: Option<ScrollState>,
 }
 
 impl Box {
@@ -68,6 +72,7 @@ impl Box {
             key: None,
             scroll_offset_x: None,
             scroll_offset_y: None,
+            scroll_state: None,
         }
     }
 
@@ -229,6 +234,53 @@ impl Box {
         self
     }
 
+    /// Place this box next to another element as an overlay
+    ///
+    /// The box is taken out of the normal flow and drawn over the rest of
+    /// the tree, positioned against the anchor's rect once layout is known.
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.style.anchor = Some(anchor);
+        self.style.position = Position::Absolute;
+        self
+    }
+
+    /// Draw a drop shadow offset to the bottom-right
+    ///
+    /// The shadow takes no layout space and dims whatever is drawn beneath
+    /// it, so place the box where the offset has room (e.g. a modal).
+    pub fn shadow(mut self, shadow: ShadowStyle) -> Self {
+        self.style.shadow = Some(shadow);
+        self
+    }
+
+    /// Dim everything drawn beneath this box's rect
+    ///
+    /// Useful for a full-screen layer behind a dialog; the box's own
+    /// children are drawn on top of the scrim.
+    pub fn backdrop(mut self, backdrop: Backdrop) -> Self {
+        self.style.backdrop = Some(backdrop);
+        self
+    }
+
+    /// Run the colors of this box and everything inside it through a filter
+    pub fn color_filter(mut self, filter: ColorFilter) -> Self {
+        self.style.color_filter = Some(filter);
+        self
+    }
+
+    /// Animate `property` over `duration` when it changes between renders
+    ///
+    /// See [`Style::transition`].
+    pub fn transition(
+        mut self,
+        property: TransitionProperty,
+        duration: std::time::Duration,
+        easing: crate::animation::Easing,
+    ) -> Self {
+        self.style = self.style.transition(property, duration, easing);
+        self
+    }
+
     /// Alias for background
     pub fn bg(self, color: Color) -> Self {
         self.background(color)
@@ -269,6 +321,21 @@ impl Box {
         self
     }
 
+    /// Drive scrolling from a [`ScrollState`]
+    ///
+    /// The box takes its scroll offsets from the state. When `overflow_y` is
+    /// [`Overflow::Scroll`] and the state's content is taller than its
+    /// viewport, the content is clipped and a [`Scrollbar`] is drawn along
+    /// the right edge. Keep the state's viewport size in sync with the box's
+    /// inner height; use [`ScrollState::handle_key`] and
+    /// [`ScrollState::handle_mouse_wheel`] to move it.
+    pub fn scroll_state(mut self, state: &ScrollState) -> Self {
+        self.scroll_offset_x = Some(state.offset_x.min(u16::MAX as usize) as u16);
+        self.scroll_offset_y = Some(state.offset_y.min(u16::MAX as usize) as u16);
+        self.scroll_state = Some(state.clone());
+        self
+    }
+
     // === Positioning ===
 
     style_setter!(/// Set position type
@@ -305,6 +372,13 @@ impl Box {
 
     /// Convert to Element
     pub fn into_element(self) -> Element {
+        if let Some(state) = &self.scroll_state
+            && self.style.overflow_y == Overflow::Scroll
+            && state.content_height > state.viewport_height
+        {
+            return self.into_scroll_element();
+        }
+
         let mut element = Element::new(ElementType::Box);
         element.style = self.style;
         element.key = self.key;
@@ -315,6 +389,49 @@ impl Box {
         }
         element
     }
+
+    /// Split into a clipped content column plus a right-edge scrollbar.
+    ///
+    /// The outer box keeps sizing, border, padding and background; the inner
+    /// box inherits the child layout properties and the scroll offsets.
+    fn into_scroll_element(self) -> Element {
+        let state = self.scroll_state.unwrap_or_default();
+
+        let mut content = Element::new(ElementType::Box);
+        content.style.flex_direction = self.style.flex_direction;
+        content.style.align_items = self.style.align_items;
+        content.style.justify_content = self.style.justify_content;
+        content.style.gap = self.style.gap;
+        content.style.column_gap = self.style.column_gap;
+        content.style.row_gap = self.style.row_gap;
+        content.style.flex_grow = 1.0;
+        content.style.flex_shrink = 1.0;
+        content.style.overflow_x = self.style.overflow_x;
+        content.style.overflow_y = Overflow::Hidden;
+        content.scroll_offset_x = self.scroll_offset_x;
+        content.scroll_offset_y = self.scroll_offset_y;
+        for child in self.children {
+            content.add_child(child);
+        }
+
+        let scrollbar = Scrollbar::new()
+            .from_sizes(state.content_height, state.viewport_height, state.offset_y)
+            .length(state.viewport_height.min(u16::MAX as usize) as u16)
+            .into_element();
+
+        let mut element = Element::new(ElementType::Box);
+        element.style = self.style;
+        element.style.flex_direction = FlexDirection::Row;
+        element.style.align_items = AlignItems::default();
+        element.style.justify_content = JustifyContent::default();
+        element.style.gap = 0.0;
+        element.style.column_gap = None;
+        element.style.row_gap = None;
+        element.key = self.key;
+        element.add_child(content);
+        element.add_child(scrollbar);
+        element
+    }
 }
 
 #[cfg(test)]
@@ -352,4 +469,44 @@ mod tests {
         assert_eq!(element.style.border_style, BorderStyle::Round);
         assert_eq!(element.style.border_color, Some(Color::Cyan));
     }
+
+    fn scroll_box(state: &ScrollState) -> Element {
+        Box::new()
+            .flex_direction(FlexDirection::Column)
+            .height(4)
+            .width(10)
+            .overflow_y(Overflow::Scroll)
+            .scroll_state(state)
+            .children((0..8).map(|i| Element::text(format!("line {i}"))))
+            .into_element()
+    }
+
+    #[test]
+    fn test_box_scroll_state_renders_scrollbar() {
+        let mut state = ScrollState::with_viewport(9, 4);
+        state.set_content_size(9, 8);
+        state.scroll_down(2);
+
+        let element = scroll_box(&state);
+        assert_eq!(element.style.flex_direction, FlexDirection::Row);
+        assert_eq!(element.children.len(), 2);
+        assert_eq!(element.children.get(0).unwrap().scroll_offset_y, Some(2));
+
+        let output = crate::testing::TestRenderer::new(10, 4).render_to_plain(&element);
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines[0].starts_with("line 2"), "{output}");
+        assert!(lines[3].starts_with("line 5"), "{output}");
+        assert!(lines[0].ends_with('▲'), "{output}");
+        assert!(lines[3].ends_with('▼'), "{output}");
+    }
+
+    #[test]
+    fn test_box_scroll_state_without_overflow_has_no_scrollbar() {
+        let mut state = ScrollState::with_viewport(9, 10);
+        state.set_content_size(9, 8);
+
+        let element = scroll_box(&state);
+        assert_eq!(element.children.len(), 8);
+        assert_eq!(element.scroll_offset_y, Some(0));
+    }
 }
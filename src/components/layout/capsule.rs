@@ -1,8 +1,127 @@
-//! Shared capsule-style text helpers for small pill/label components.
+//! Capsule component - Pill-shaped labels with rounded ends
+//!
+//! [`Capsule`] draws its content on a background between two rounded
+//! Powerline end glyphs, for status pills and segmented controls. The
+//! crate-internal builders below are shared by badge, tag, chip and
+//! highlight.
 
-use crate::components::Text;
+use crate::components::{Span, Text};
 use crate::core::{Color, Element};
 
+/// Rounded left end (Powerline extra symbols)
+const ROUND_LEFT: &str = "\u{e0b6}";
+/// Rounded right end (Powerline extra symbols)
+const ROUND_RIGHT: &str = "\u{e0b4}";
+
+/// Pill-shaped label with rounded ends
+///
+/// The rounded ends are drawn in the background color, so the capsule is
+/// exactly its content plus one cell on each side. With several segments
+/// it becomes a segmented control: each label is padded by a space and the
+/// active one uses the active colors. The end glyphs need a Nerd Font or
+/// Powerline-patched font.
+///
+/// # Example
+///
+/// ```ignore
+/// Capsule::new("online").bg(Color::Green).fg(Color::Black).into_element();
+///
+/// Capsule::segmented(["Day", "Week", "Month"], 1)
+///     .active_bg(Color::Blue)
+///     .into_element();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Capsule {
+    segments: Vec<String>,
+    active: Option<usize>,
+    fg: Color,
+    bg: Color,
+    active_fg: Color,
+    active_bg: Color,
+}
+
+impl Capsule {
+    /// Create a capsule around `text`
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            segments: vec![text.into()],
+            active: None,
+            fg: Color::White,
+            bg: Color::Ansi256(240),
+            active_fg: Color::Black,
+            active_bg: Color::Cyan,
+        }
+    }
+
+    /// Create a segmented control with `active` highlighted
+    pub fn segmented<I, S>(labels: I, active: usize) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            segments: labels.into_iter().map(Into::into).collect(),
+            active: Some(active),
+            ..Self::new("")
+        }
+    }
+
+    /// Set the text color
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = color;
+        self
+    }
+
+    /// Set the background color
+    pub fn bg(mut self, color: Color) -> Self {
+        self.bg = color;
+        self
+    }
+
+    /// Set the text color of the active segment
+    pub fn active_fg(mut self, color: Color) -> Self {
+        self.active_fg = color;
+        self
+    }
+
+    /// Set the background color of the active segment
+    pub fn active_bg(mut self, color: Color) -> Self {
+        self.active_bg = color;
+        self
+    }
+
+    /// Convert to Element
+    pub fn into_element(self) -> Element {
+        let segmented = self.active.is_some();
+        let mut spans = Vec::with_capacity(self.segments.len() + 2);
+        let mut first_bg = self.bg;
+        let mut last_bg = self.bg;
+
+        for (index, label) in self.segments.into_iter().enumerate() {
+            let (fg, bg) = if self.active == Some(index) {
+                (self.active_fg, self.active_bg)
+            } else {
+                (self.fg, self.bg)
+            };
+            if index == 0 {
+                first_bg = bg;
+            }
+            last_bg = bg;
+
+            let content = if segmented {
+                format!(" {label} ")
+            } else {
+                label
+            };
+            spans.push(Span::new(content).fg(fg).bg(bg));
+        }
+
+        spans.insert(0, Span::new(ROUND_LEFT).fg(first_bg));
+        spans.push(Span::new(ROUND_RIGHT).fg(last_bg));
+        Text::spans(spans).into_element()
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum CapsuleShape {
     Padded,
@@ -166,4 +285,36 @@ impl CapsuleElementBuilder {
     }
 }
 
-// No direct unit tests here because `Text` content is not exposed publicly.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestRenderer;
+
+    #[test]
+    fn test_capsule_is_content_plus_ends() {
+        let element = Capsule::new("ok").bg(Color::Green).into_element();
+        let plain = TestRenderer::new(10, 1).render_to_plain(&element);
+        assert_eq!(plain.trim_end(), "\u{e0b6}ok\u{e0b4}");
+
+        let spans = element.spans.as_ref().unwrap()[0].spans.clone();
+        assert_eq!(spans[0].style.color, Some(Color::Green));
+        assert_eq!(spans[0].style.background_color, None);
+        assert_eq!(spans[1].style.background_color, Some(Color::Green));
+    }
+
+    #[test]
+    fn test_segmented_capsule_highlights_active() {
+        let element = Capsule::segmented(["A", "B"], 1)
+            .bg(Color::Black)
+            .active_bg(Color::Blue)
+            .into_element();
+        let plain = TestRenderer::new(10, 1).render_to_plain(&element);
+        assert_eq!(plain.trim_end(), "\u{e0b6} A  B \u{e0b4}");
+
+        let spans = element.spans.as_ref().unwrap()[0].spans.clone();
+        assert_eq!(spans[1].style.background_color, Some(Color::Black));
+        assert_eq!(spans[2].style.background_color, Some(Color::Blue));
+        // The right end continues the active segment's color
+        assert_eq!(spans[3].style.color, Some(Color::Blue));
+    }
+}
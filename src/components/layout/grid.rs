@@ -0,0 +1,245 @@
+//! Grid component - Fixed-column layouts
+//!
+//! Children flow into cells left to right, top to bottom. Cells can span
+//! several columns or rows. Layout is done by Taffy's CSS grid algorithm.
+
+use crate::core::{Dimension, Display, Element, ElementType, GridTrack, Style};
+
+/// A grid cell wrapping an element with optional spans
+#[derive(Debug, Clone)]
+pub struct GridCell {
+    element: Element,
+    col_span: u16,
+    row_span: u16,
+}
+
+impl GridCell {
+    /// Create a cell occupying a single track in each direction
+    pub fn new(element: Element) -> Self {
+        Self {
+            element,
+            col_span: 1,
+            row_span: 1,
+        }
+    }
+
+    /// Span this cell across `span` columns
+    pub fn col_span(mut self, span: u16) -> Self {
+        self.col_span = span.max(1);
+        self
+    }
+
+    /// Span this cell across `span` rows
+    pub fn row_span(mut self, span: u16) -> Self {
+        self.row_span = span.max(1);
+        self
+    }
+
+    /// Convert to Element
+    pub fn into_element(self) -> Element {
+        let mut element = self.element;
+        element.style.grid_column_span = self.col_span;
+        element.style.grid_row_span = self.row_span;
+        element
+    }
+}
+
+impl From<Element> for GridCell {
+    fn from(element: Element) -> Self {
+        Self::new(element)
+    }
+}
+
+/// Grid layout component
+///
+/// # Example
+///
+/// ```ignore
+/// Grid::new()
+///     .columns(3)
+///     .gap(1)
+///     .child(Stat::new("CPU", "42%").into_element())
+///     .child(Stat::new("Memory", "1.2G").into_element())
+///     .child(Stat::new("Disk", "80%").into_element())
+///     .cell(GridCell::new(chart).col_span(2))
+///     .child(Stat::new("Uptime", "3d").into_element())
+///     .into_element()
+/// ```
+#[derive(Debug, Clone)]
+pub struct Grid {
+    columns: Vec<GridTrack>,
+    row_gap: u16,
+    column_gap: u16,
+    width: Dimension,
+    cells: Vec<GridCell>,
+}
+
+impl Default for Grid {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Grid {
+    /// Create a single-column grid that fills the available width
+    pub fn new() -> Self {
+        Self {
+            columns: vec![GridTrack::Fr(1.0)],
+            row_gap: 0,
+            column_gap: 0,
+            width: Dimension::Percent(100.0),
+            cells: Vec::new(),
+        }
+    }
+
+    /// Use `count` equal-width columns
+    pub fn columns(mut self, count: usize) -> Self {
+        self.columns = vec![GridTrack::Fr(1.0); count.max(1)];
+        self
+    }
+
+    /// Use an explicit column template
+    pub fn template(mut self, columns: impl IntoIterator<Item = GridTrack>) -> Self {
+        self.columns = columns.into_iter().collect();
+        if self.columns.is_empty() {
+            self.columns.push(GridTrack::Fr(1.0));
+        }
+        self
+    }
+
+    /// Set both row and column gaps
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.row_gap = gap;
+        self.column_gap = gap;
+        self
+    }
+
+    /// Set the gap between rows
+    pub fn row_gap(mut self, gap: u16) -> Self {
+        self.row_gap = gap;
+        self
+    }
+
+    /// Set the gap between columns
+    pub fn column_gap(mut self, gap: u16) -> Self {
+        self.column_gap = gap;
+        self
+    }
+
+    /// Set the grid width (defaults to 100% of the parent)
+    pub fn width(mut self, width: impl Into<Dimension>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Add a child occupying one cell
+    pub fn child(mut self, element: Element) -> Self {
+        self.cells.push(GridCell::new(element));
+        self
+    }
+
+    /// Add multiple children, one cell each
+    pub fn children(mut self, elements: impl IntoIterator<Item = Element>) -> Self {
+        self.cells.extend(elements.into_iter().map(GridCell::new));
+        self
+    }
+
+    /// Add a cell with spans
+    pub fn cell(mut self, cell: GridCell) -> Self {
+        self.cells.push(cell);
+        self
+    }
+
+    /// Number of columns in the template
+    pub fn column_count(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Convert to Element
+    pub fn into_element(self) -> Element {
+        let mut element = Element::new(ElementType::Box);
+        element.style = Style::new();
+        element.style.display = Display::Grid;
+        element.style.grid_template_columns = self.columns;
+        element.style.row_gap = Some(self.row_gap as f32);
+        element.style.column_gap = Some(self.column_gap as f32);
+        element.style.width = self.width;
+
+        for cell in self.cells {
+            element.add_child(cell.into_element());
+        }
+        element
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::Text;
+    use crate::testing::TestRenderer;
+
+    fn label(text: &str) -> Element {
+        Text::new(text).into_element()
+    }
+
+    #[test]
+    fn test_grid_builds_grid_style() {
+        let element = Grid::new()
+            .columns(3)
+            .gap(1)
+            .cell(GridCell::new(label("wide")).col_span(2).row_span(2))
+            .into_element();
+
+        assert_eq!(element.style.display, Display::Grid);
+        assert_eq!(element.style.grid_template_columns.len(), 3);
+        assert_eq!(element.style.column_gap, Some(1.0));
+
+        let cell = element.children.get(0).unwrap();
+        assert_eq!(cell.style.grid_column_span, 2);
+        assert_eq!(cell.style.grid_row_span, 2);
+    }
+
+    #[test]
+    fn test_grid_lays_out_three_by_two() {
+        let element = Grid::new()
+            .columns(3)
+            .children(["A", "B", "C", "D", "E", "F"].map(label))
+            .into_element();
+
+        let output = TestRenderer::new(12, 2).render_to_plain(&element);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0].trim_end(), "A   B   C");
+        assert_eq!(lines[1].trim_end(), "D   E   F");
+    }
+
+    #[test]
+    fn test_grid_col_span_and_template() {
+        let element = Grid::new()
+            .template([GridTrack::Points(4.0), GridTrack::Fr(1.0)])
+            .column_gap(1)
+            .cell(GridCell::new(label("Title")).col_span(2))
+            .child(label("k"))
+            .child(label("v"))
+            .into_element();
+
+        let output = TestRenderer::new(10, 2).render_to_plain(&element);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0].trim_end(), "Title");
+        assert_eq!(lines[1].trim_end(), "k    v");
+    }
+
+    #[test]
+    fn test_grid_row_span_pushes_later_cells() {
+        let element = Grid::new()
+            .columns(2)
+            .cell(GridCell::new(label("Tall")).row_span(2))
+            .child(label("x"))
+            .child(label("y"))
+            .into_element();
+
+        let output = TestRenderer::new(10, 2).render_to_plain(&element);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0].trim_end(), "Tall x");
+        assert_eq!(lines[1].trim_end(), "     y");
+    }
+}
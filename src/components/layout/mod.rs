@@ -1,23 +1,34 @@
 mod box_component;
 pub(crate) mod capsule;
+mod grid;
 pub mod navigation;
+mod responsive;
+mod router;
 mod scrollable;
 mod scrollbar;
 mod spacer;
+mod split_pane;
 mod table;
 mod tabs;
 mod transform;
 mod tree;
 
 pub use box_component::Box;
+pub use capsule::Capsule;
+pub use grid::{Grid, GridCell};
 pub use navigation::{
     NavigationConfig, NavigationResult, SelectionState, calculate_visible_range,
-    handle_list_navigation,
+    handle_grid_navigation, handle_list_navigation, handle_list_navigation_with,
 };
+pub use responsive::Responsive;
+pub use router::Router;
 pub use scrollable::{ScrollableBox, fixed_bottom_layout, virtual_scroll_view};
 pub use scrollbar::{Scrollbar, ScrollbarOrientation, ScrollbarSymbols};
-pub use spacer::Spacer;
+pub use spacer::{HSpace, Spacer, VSpace};
+pub use split_pane::{
+    SplitDirection, SplitPane, SplitPaneState, handle_split_pane_input, handle_split_pane_mouse,
+};
 pub use table::{Cell, Constraint, Row, Table, TableState};
-pub use tabs::{Tab, Tabs};
+pub use tabs::{Tab, Tabs, TabsAction, TabsState, handle_tabs_input, handle_tabs_mouse};
 pub use transform::Transform;
 pub use tree::{Tree, TreeNode, TreeState, TreeStyle, handle_tree_input};
@@ -1,7 +1,7 @@
 //! Shared navigation utilities for list-based components
 //!
 //! This module provides common navigation logic used by SelectInput, MultiSelect,
-//! and other list-based components.
+//! and other list-based components, plus 2D navigation for grid layouts.
 
 use crate::hooks::Key;
 
@@ -22,44 +22,80 @@ pub trait SelectionState {
     /// Set the scroll offset
     fn set_offset(&mut self, offset: usize);
 
-    /// Select the next item
+    /// Whether the item at `index` can be selected
+    ///
+    /// Navigation skips items for which this returns false, such as section
+    /// headers or disabled rows. Every item is selectable by default.
+    fn is_selectable(&self, _index: usize) -> bool {
+        true
+    }
+
+    /// Select the next selectable item
     fn select_next(&mut self, len: usize) {
         if len == 0 {
             self.select(None);
             return;
         }
-        let new_index = match self.selected() {
+        let target = match self.selected() {
             Some(i) => (i + 1).min(len - 1),
             None => 0,
         };
-        self.select(Some(new_index));
+        let found = (target..len)
+            .find(|&i| self.is_selectable(i))
+            .or_else(|| (0..target).rev().find(|&i| self.is_selectable(i)));
+        self.select(found);
     }
 
-    /// Select the previous item
+    /// Select the previous selectable item
     fn select_previous(&mut self, len: usize) {
         if len == 0 {
             self.select(None);
             return;
         }
-        let new_index = match self.selected() {
-            Some(i) => i.saturating_sub(1),
+        let target = match self.selected() {
+            Some(i) => i.saturating_sub(1).min(len - 1),
             None => 0,
         };
-        self.select(Some(new_index));
+        let found = (0..=target)
+            .rev()
+            .find(|&i| self.is_selectable(i))
+            .or_else(|| (target..len).find(|&i| self.is_selectable(i)));
+        self.select(found);
     }
 
-    /// Select the first item
+    /// Select the first selectable item
     fn select_first(&mut self, len: usize) {
-        if len > 0 {
-            self.select(Some(0));
+        if let Some(index) = (0..len).find(|&i| self.is_selectable(i)) {
+            self.select(Some(index));
         }
     }
 
-    /// Select the last item
+    /// Select the last selectable item
     fn select_last(&mut self, len: usize) {
-        if len > 0 {
-            self.select(Some(len - 1));
+        if let Some(index) = (0..len).rev().find(|&i| self.is_selectable(i)) {
+            self.select(Some(index));
+        }
+    }
+
+    /// Move the selection for a navigation key press
+    ///
+    /// Uses [`handle_list_navigation_with`], so unselectable items are
+    /// skipped just like with the `select_*` methods.
+    fn handle_navigation(
+        &mut self,
+        len: usize,
+        input: &str,
+        key: Key,
+        config: &NavigationConfig,
+    ) -> NavigationResult {
+        let current = self.selected().unwrap_or(0);
+        let result = handle_list_navigation_with(current, len, input, key, config, |i| {
+            self.is_selectable(i)
+        });
+        if let NavigationResult::Moved(index) = result {
+            self.select(Some(index));
         }
+        result
     }
 
     /// Adjust scroll offset to keep selection visible
@@ -109,8 +145,10 @@ pub struct NavigationConfig {
     pub vim_navigation: bool,
     /// Enable number shortcuts (1-9)
     pub number_shortcuts: bool,
-    /// Page size for page up/down
+    /// Page size for page up/down (rows when navigating a grid)
     pub page_size: usize,
+    /// Wrap around at the edges instead of stopping
+    pub wrap: bool,
 }
 
 impl Default for NavigationConfig {
@@ -119,6 +157,7 @@ impl Default for NavigationConfig {
             vim_navigation: false,
             number_shortcuts: false,
             page_size: 5,
+            wrap: false,
         }
     }
 }
@@ -146,6 +185,12 @@ impl NavigationConfig {
         self.page_size = size;
         self
     }
+
+    /// Enable wrap-around at the edges
+    pub fn wrap(mut self, enabled: bool) -> Self {
+        self.wrap = enabled;
+        self
+    }
 }
 
 /// Handle list navigation based on input and key events
@@ -176,28 +221,119 @@ pub fn handle_list_navigation(
     input: &str,
     key: Key,
     config: &NavigationConfig,
+) -> NavigationResult {
+    handle_list_navigation_with(current, total, input, key, config, |_| true)
+}
+
+/// Handle list navigation, skipping items that cannot be selected
+///
+/// Like [`handle_list_navigation`], but lands only on indices for which
+/// `is_selectable` returns true, so disabled items and separators are
+/// stepped over. Arrows keep moving in their direction (wrapping with
+/// `config.wrap`), Home/End find the first/last selectable item, and a
+/// number shortcut to an unselectable item is ignored.
+///
+/// # Example
+///
+/// ```
+/// use rnk::components::navigation::{
+///     NavigationConfig, NavigationResult, handle_list_navigation_with,
+/// };
+/// use rnk::hooks::Key;
+///
+/// let disabled = [false, true, true, false];
+/// let down = Key { down_arrow: true, ..Key::default() };
+/// let result =
+///     handle_list_navigation_with(0, 4, "", down, &NavigationConfig::default(), |i| !disabled[i]);
+/// assert_eq!(result, NavigationResult::Moved(3));
+/// ```
+pub fn handle_list_navigation_with(
+    current: usize,
+    total: usize,
+    input: &str,
+    key: Key,
+    config: &NavigationConfig,
+    is_selectable: impl Fn(usize) -> bool,
 ) -> NavigationResult {
     if total == 0 {
         return NavigationResult::None;
     }
+    let Some((target, seek)) = list_target(current, total, input, key, config) else {
+        return NavigationResult::None;
+    };
+
+    let max_index = total - 1;
+    let backward = |from: usize| (0..=from).rev().find(|&i| is_selectable(i));
+    let forward = |from: usize| (from..=max_index).find(|&i| is_selectable(i));
+    let found = match seek {
+        Seek::Exact => {
+            if !is_selectable(target) {
+                return NavigationResult::None;
+            }
+            Some(target)
+        }
+        Seek::Backward { wrap } => backward(target)
+            .or_else(|| wrap.then(|| backward(max_index)).flatten())
+            .or_else(|| forward(target)),
+        Seek::Forward { wrap } => forward(target)
+            .or_else(|| wrap.then(|| forward(0)).flatten())
+            .or_else(|| backward(target)),
+    };
+
+    NavigationResult::Moved(found.unwrap_or(current))
+}
 
+/// Direction to look in when a list target cannot be selected
+#[derive(Clone, Copy)]
+enum Seek {
+    /// Take the target or nothing (number shortcuts)
+    Exact,
+    /// Look at lower indices first, continuing from the end if `wrap`
+    Backward { wrap: bool },
+    /// Look at higher indices first, continuing from the start if `wrap`
+    Forward { wrap: bool },
+}
+
+/// Raw target index for a list key press, before skipping unselectable items
+fn list_target(
+    current: usize,
+    total: usize,
+    input: &str,
+    key: Key,
+    config: &NavigationConfig,
+) -> Option<(usize, Seek)> {
     let max_index = total.saturating_sub(1);
+    let wrap = config.wrap;
+    let up = || {
+        if current == 0 && wrap {
+            (max_index, Seek::Backward { wrap })
+        } else {
+            (current.saturating_sub(1), Seek::Backward { wrap })
+        }
+    };
+    let down = || {
+        if current >= max_index && wrap {
+            (0, Seek::Forward { wrap })
+        } else {
+            ((current + 1).min(max_index), Seek::Forward { wrap })
+        }
+    };
 
     // Arrow key navigation
     if key.up_arrow {
-        return NavigationResult::Moved(current.saturating_sub(1));
+        return Some(up());
     }
     if key.down_arrow {
-        return NavigationResult::Moved((current + 1).min(max_index));
+        return Some(down());
     }
 
     // Vim-style navigation
     if config.vim_navigation {
         if input == "k" {
-            return NavigationResult::Moved(current.saturating_sub(1));
+            return Some(up());
         }
         if input == "j" {
-            return NavigationResult::Moved((current + 1).min(max_index));
+            return Some(down());
         }
     }
 
@@ -207,7 +343,7 @@ pub fn handle_list_navigation(
             if (1..=9).contains(&num) {
                 let index = (num as usize) - 1;
                 if index < total {
-                    return NavigationResult::Moved(index);
+                    return Some((index, Seek::Exact));
                 }
             }
         }
@@ -215,21 +351,131 @@ pub fn handle_list_navigation(
 
     // Home/End navigation
     if key.home {
-        return NavigationResult::Moved(0);
+        return Some((0, Seek::Forward { wrap: false }));
     }
     if key.end {
-        return NavigationResult::Moved(max_index);
+        return Some((max_index, Seek::Backward { wrap: false }));
     }
 
     // Page up/down
     if key.page_up {
-        return NavigationResult::Moved(current.saturating_sub(config.page_size));
+        return Some((
+            current.saturating_sub(config.page_size),
+            Seek::Backward { wrap: false },
+        ));
     }
     if key.page_down {
-        return NavigationResult::Moved((current + config.page_size).min(max_index));
+        return Some((
+            (current + config.page_size).min(max_index),
+            Seek::Forward { wrap: false },
+        ));
     }
 
-    NavigationResult::None
+    None
+}
+
+/// Handle 2D navigation over a grid laid out row-major from a flat index
+///
+/// Items fill rows of `columns` cells; the last row may be partial. Arrows
+/// (and `h`/`j`/`k`/`l` with vim navigation) move one cell, Home/End jump to
+/// the first/last item, and PageUp/PageDown move `config.page_size` rows.
+/// With `config.wrap`, moving past an edge wraps to the other end of the
+/// same row or column; otherwise the cursor stays put.
+///
+/// # Example
+///
+/// ```
+/// use rnk::components::navigation::{NavigationConfig, NavigationResult, handle_grid_navigation};
+/// use rnk::hooks::Key;
+///
+/// // 3 columns: [0 1 2] [3 4 5] [6 7]
+/// let down = Key { down_arrow: true, ..Key::default() };
+/// let result = handle_grid_navigation(1, 8, 3, "", down, &NavigationConfig::default());
+/// assert_eq!(result, NavigationResult::Moved(4));
+/// ```
+pub fn handle_grid_navigation(
+    current: usize,
+    total: usize,
+    columns: usize,
+    input: &str,
+    key: Key,
+    config: &NavigationConfig,
+) -> NavigationResult {
+    if total == 0 {
+        return NavigationResult::None;
+    }
+
+    let columns = columns.max(1);
+    let max_index = total - 1;
+    let current = current.min(max_index);
+    let row = current / columns;
+    let col = current % columns;
+    let last_row = max_index / columns;
+    // Last item in `row` (the final row may be partial)
+    let row_end = |row: usize| (row * columns + columns - 1).min(max_index);
+    // Item in `col` of the bottom-most row that has one
+    let column_bottom = |col: usize| {
+        let index = last_row * columns + col;
+        if index > max_index {
+            index.saturating_sub(columns)
+        } else {
+            index
+        }
+    };
+
+    let vim = config.vim_navigation;
+    let target = if key.left_arrow || (vim && input == "h") {
+        if col > 0 {
+            current - 1
+        } else if config.wrap {
+            row_end(row)
+        } else {
+            current
+        }
+    } else if key.right_arrow || (vim && input == "l") {
+        if current < row_end(row) {
+            current + 1
+        } else if config.wrap {
+            row * columns
+        } else {
+            current
+        }
+    } else if key.up_arrow || (vim && input == "k") {
+        if row > 0 {
+            current - columns
+        } else if config.wrap {
+            column_bottom(col)
+        } else {
+            current
+        }
+    } else if key.down_arrow || (vim && input == "j") {
+        if row < last_row {
+            (current + columns).min(max_index)
+        } else if config.wrap {
+            col
+        } else {
+            current
+        }
+    } else if key.home {
+        0
+    } else if key.end {
+        max_index
+    } else if key.page_up {
+        current
+            .saturating_sub(config.page_size * columns)
+            .max(col.min(current))
+    } else if key.page_down {
+        let jump = current + config.page_size * columns;
+        if jump <= max_index {
+            jump
+        } else {
+            column_bottom(col).max(current)
+        }
+    } else {
+        return NavigationResult::None;
+    };
+
+    NavigationResult::Moved(target)
 }
 
 /// Calculate visible range for a scrollable list
@@ -289,6 +535,43 @@ mod tests {
         assert_eq!(config.page_size, 10);
     }
 
+    #[test]
+    fn test_navigation_skips_unselectable_items() {
+        // 0 and 3 are selectable, 1, 2 and 4 are not
+        let selectable = |i: usize| i == 0 || i == 3;
+        let config = NavigationConfig::default().number_shortcuts(true);
+        let down = Key {
+            down_arrow: true,
+            ..Key::default()
+        };
+        let up = Key {
+            up_arrow: true,
+            ..Key::default()
+        };
+        let end = Key {
+            end: true,
+            ..Key::default()
+        };
+
+        let nav = |current, input, key, config: &NavigationConfig| {
+            handle_list_navigation_with(current, 5, input, key, config, selectable)
+        };
+        assert_eq!(nav(0, "", down, &config), NavigationResult::Moved(3));
+        assert_eq!(nav(3, "", up, &config), NavigationResult::Moved(0));
+        // Nothing selectable below the last item: stay put
+        assert_eq!(nav(3, "", down, &config), NavigationResult::Moved(3));
+        assert_eq!(nav(0, "", end, &config), NavigationResult::Moved(3));
+        assert_eq!(nav(0, "2", Key::default(), &config), NavigationResult::None);
+        assert_eq!(
+            nav(0, "4", Key::default(), &config),
+            NavigationResult::Moved(3)
+        );
+
+        let wrapping = config.clone().wrap(true);
+        assert_eq!(nav(3, "", down, &wrapping), NavigationResult::Moved(0));
+        assert_eq!(nav(0, "", up, &wrapping), NavigationResult::Moved(3));
+    }
+
     #[test]
     fn test_arrow_navigation() {
         let config = NavigationConfig::default();
@@ -404,6 +687,142 @@ mod tests {
         assert_eq!(result, NavigationResult::None);
     }
 
+    #[test]
+    fn test_list_navigation_wrap() {
+        let config = NavigationConfig::default().wrap(true);
+        let up = Key {
+            up_arrow: true,
+            ..Key::default()
+        };
+        let down = Key {
+            down_arrow: true,
+            ..Key::default()
+        };
+        assert_eq!(
+            handle_list_navigation(0, 4, "", up, &config),
+            NavigationResult::Moved(3)
+        );
+        assert_eq!(
+            handle_list_navigation(3, 4, "", down, &config),
+            NavigationResult::Moved(0)
+        );
+    }
+
+    fn grid(current: usize, key: Key, config: &NavigationConfig) -> NavigationResult {
+        // 3 columns, 8 items: [0 1 2] [3 4 5] [6 7]
+        handle_grid_navigation(current, 8, 3, "", key, config)
+    }
+
+    #[test]
+    fn test_grid_arrows_without_wrap() {
+        let config = NavigationConfig::default();
+        let left = Key {
+            left_arrow: true,
+            ..Key::default()
+        };
+        let right = Key {
+            right_arrow: true,
+            ..Key::default()
+        };
+        let up = Key {
+            up_arrow: true,
+            ..Key::default()
+        };
+        let down = Key {
+            down_arrow: true,
+            ..Key::default()
+        };
+
+        assert_eq!(grid(4, left, &config), NavigationResult::Moved(3));
+        assert_eq!(grid(3, left, &config), NavigationResult::Moved(3));
+        assert_eq!(grid(4, right, &config), NavigationResult::Moved(5));
+        assert_eq!(grid(7, right, &config), NavigationResult::Moved(7));
+        assert_eq!(grid(4, up, &config), NavigationResult::Moved(1));
+        assert_eq!(grid(1, up, &config), NavigationResult::Moved(1));
+        assert_eq!(grid(1, down, &config), NavigationResult::Moved(4));
+        // Below 5 there is no item; move to the last one
+        assert_eq!(grid(5, down, &config), NavigationResult::Moved(7));
+        assert_eq!(grid(7, down, &config), NavigationResult::Moved(7));
+    }
+
+    #[test]
+    fn test_grid_arrows_with_wrap() {
+        let config = NavigationConfig::default().wrap(true);
+        let left = Key {
+            left_arrow: true,
+            ..Key::default()
+        };
+        let right = Key {
+            right_arrow: true,
+            ..Key::default()
+        };
+        let up = Key {
+            up_arrow: true,
+            ..Key::default()
+        };
+        let down = Key {
+            down_arrow: true,
+            ..Key::default()
+        };
+
+        assert_eq!(grid(3, left, &config), NavigationResult::Moved(5));
+        assert_eq!(grid(6, left, &config), NavigationResult::Moved(7));
+        assert_eq!(grid(7, right, &config), NavigationResult::Moved(6));
+        assert_eq!(grid(1, up, &config), NavigationResult::Moved(7));
+        assert_eq!(grid(2, up, &config), NavigationResult::Moved(5));
+        assert_eq!(grid(7, down, &config), NavigationResult::Moved(1));
+    }
+
+    #[test]
+    fn test_grid_home_end_and_paging() {
+        let config = NavigationConfig::default().page_size(2);
+        let home = Key {
+            home: true,
+            ..Key::default()
+        };
+        let end = Key {
+            end: true,
+            ..Key::default()
+        };
+        let page_up = Key {
+            page_up: true,
+            ..Key::default()
+        };
+        let page_down = Key {
+            page_down: true,
+            ..Key::default()
+        };
+
+        assert_eq!(grid(4, home, &config), NavigationResult::Moved(0));
+        assert_eq!(grid(4, end, &config), NavigationResult::Moved(7));
+        assert_eq!(grid(1, page_down, &config), NavigationResult::Moved(7));
+        assert_eq!(grid(2, page_down, &config), NavigationResult::Moved(5));
+        assert_eq!(grid(7, page_up, &config), NavigationResult::Moved(1));
+        assert_eq!(grid(4, page_up, &config), NavigationResult::Moved(1));
+    }
+
+    #[test]
+    fn test_grid_vim_keys_and_empty() {
+        let config = NavigationConfig::default().vim_navigation(true);
+        let key = Key::default();
+        assert_eq!(
+            handle_grid_navigation(0, 8, 3, "l", key, &config),
+            NavigationResult::Moved(1)
+        );
+        assert_eq!(
+            handle_grid_navigation(0, 8, 3, "j", key, &config),
+            NavigationResult::Moved(3)
+        );
+        assert_eq!(
+            handle_grid_navigation(0, 0, 3, "l", key, &config),
+            NavigationResult::None
+        );
+        assert_eq!(
+            handle_grid_navigation(0, 8, 3, "x", key, &config),
+            NavigationResult::None
+        );
+    }
+
     #[test]
     fn test_calculate_visible_range_no_limit() {
         let (start, end) = calculate_visible_range(5, 20, None);
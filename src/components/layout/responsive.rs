@@ -0,0 +1,179 @@
+//! Responsive component - Pick a layout from the width a container is given
+//!
+//! Container queries for the terminal: instead of the terminal width,
+//! [`Responsive`] chooses between layouts by the width its own box was laid
+//! out with on the previous frame (see
+//! [`measure_element_by_key`](crate::hooks::measure_element_by_key)). When a
+//! new layout leaves the container in a different width range, the renderer
+//! renders again right away, so a card can stack its contents when squeezed
+//! into a sidebar and lay them out in a row when given the full width.
+
+use crate::components::Box as RnkBox;
+use crate::core::{Dimension, Element};
+use crate::hooks::measure_element_by_key;
+
+type ResponsiveView = Box<dyn FnOnce() -> Element>;
+
+/// Renders one of several layouts depending on its own measured width
+///
+/// The container fills the width of its parent, so its width is decided by
+/// the surrounding layout rather than by the chosen view.
+///
+/// # Example
+///
+/// ```ignore
+/// Responsive::new("profile-card")
+///     .at(0, || stacked_card())
+///     .at(60, || wide_card())
+///     .into_element()
+/// ```
+pub struct Responsive {
+    key: String,
+    views: Vec<(u16, ResponsiveView)>,
+    fallback_width: Option<u16>,
+}
+
+impl Responsive {
+    /// Create a responsive container
+    ///
+    /// `key` must be unique and stable across renders; it is how the
+    /// container's width is looked up from the previous layout.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            views: Vec::new(),
+            fallback_width: None,
+        }
+    }
+
+    /// Render `view` when the container is at least `min_width` cells wide
+    ///
+    /// The view with the largest matching `min_width` wins. When the
+    /// container is narrower than every entry, the narrowest one is used.
+    pub fn at<F>(mut self, min_width: u16, view: F) -> Self
+    where
+        F: FnOnce() -> Element + 'static,
+    {
+        let index = self.views.partition_point(|(width, _)| *width <= min_width);
+        self.views.insert(index, (min_width, Box::new(view)));
+        self
+    }
+
+    /// Width assumed before the container has been measured
+    ///
+    /// Defaults to the terminal width.
+    pub fn fallback_width(mut self, width: u16) -> Self {
+        self.fallback_width = Some(width);
+        self
+    }
+
+    /// Width the view will be chosen for
+    pub fn width(&self) -> u16 {
+        measure_element_by_key(&self.key)
+            .map(|dims| dims.width as u16)
+            .or(self.fallback_width)
+            .unwrap_or_else(|| crate::hooks::get_terminal_size().0)
+    }
+
+    /// Convert to Element
+    pub fn into_element(self) -> Element {
+        let width = self.width();
+        let mut container = RnkBox::new()
+            .key(self.key.clone())
+            .width(Dimension::Percent(100.0));
+
+        let chosen = self
+            .views
+            .iter()
+            .rposition(|(min_width, _)| *min_width <= width)
+            .unwrap_or(0);
+        if chosen < self.views.len() {
+            // Widths that would pick the same view again
+            let low = if chosen == 0 { 0 } else { self.views[chosen].0 };
+            let high = self
+                .views
+                .get(chosen + 1)
+                .map_or(u16::MAX, |(min_width, _)| *min_width);
+            crate::runtime::with_current_runtime(|ctx| {
+                ctx.record_responsive_range(self.key.clone(), low..high)
+            });
+
+            let (_, view) = self.views.into_iter().nth(chosen).expect("index in range");
+            container = container.child(view());
+        }
+
+        container.into_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::Text;
+    use crate::layout::LayoutEngine;
+    use crate::renderer::pipeline::RenderPipeline;
+    use crate::runtime::{RuntimeContext, with_runtime};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    fn card(fallback: u16) -> Element {
+        Responsive::new("card")
+            .fallback_width(fallback)
+            .at(60, || Text::new("wide").into_element())
+            .at(0, || Text::new("narrow").into_element())
+            .at(30, || Text::new("medium").into_element())
+            .into_element()
+    }
+
+    #[test]
+    fn test_responsive_picks_largest_matching_view() {
+        let text = |element: Element| {
+            crate::testing::TestRenderer::new(80, 1)
+                .render_to_plain(&element)
+                .trim_end()
+                .to_string()
+        };
+        assert_eq!(text(card(10)), "narrow");
+        assert_eq!(text(card(30)), "medium");
+        assert_eq!(text(card(120)), "wide");
+    }
+
+    #[test]
+    fn test_responsive_rerenders_for_measured_width() {
+        let ctx = Rc::new(RefCell::new(RuntimeContext::new()));
+        let requested = Arc::new(AtomicBool::new(false));
+        let flag = requested.clone();
+        ctx.borrow_mut().set_render_callback(Arc::new(move || {
+            flag.store(true, Ordering::SeqCst);
+        }));
+
+        let mut engine = LayoutEngine::new();
+        let mut previous = None;
+        let mut frame = || {
+            let root = with_runtime(ctx.clone(), || {
+                RnkBox::new().width(40).child(card(80)).into_element()
+            });
+            let output = RenderPipeline::render_dynamic_frame(
+                &root,
+                80,
+                5,
+                &mut engine,
+                &ctx,
+                &mut previous,
+            );
+            crate::testing::strip_ansi_codes(&output.render())
+                .trim()
+                .to_string()
+        };
+
+        // Before measurement the fallback picks the wide view, but the
+        // container is only 40 cells wide, so another frame is requested
+        assert_eq!(frame(), "wide");
+        assert!(requested.swap(false, Ordering::SeqCst));
+
+        assert_eq!(frame(), "medium");
+        assert!(!requested.load(Ordering::SeqCst));
+    }
+}
@@ -0,0 +1,160 @@
+//! Router component - Render the element for the current route
+//!
+//! Pairs with [`use_history`](crate::hooks::use_history) for multi-screen
+//! apps: the history holds the navigation stack and the router maps its
+//! current route to a view. Routes are checked in the order they were added.
+
+use crate::components::Box as RnkBox;
+use crate::core::Element;
+use crate::hooks::HistoryHandle;
+
+type RouteMatcher<R> = Box<dyn Fn(&R) -> bool>;
+type RouteView<R> = Box<dyn FnOnce(&R) -> Element>;
+
+/// Maps the current route to an element
+///
+/// # Example
+///
+/// ```ignore
+/// let nav = use_history(Screen::Settings);
+///
+/// Router::from_history(&nav)
+///     .route(Screen::Settings, || settings_view())
+///     .matching(|s| matches!(s, Screen::Detail(_)), |s| detail_view(s))
+///     .fallback(|_| Text::new("Not found").into_element())
+///     .into_element()
+/// ```
+pub struct Router<R> {
+    current: R,
+    routes: Vec<(RouteMatcher<R>, RouteView<R>)>,
+    fallback: Option<RouteView<R>>,
+}
+
+impl<R: 'static> Router<R> {
+    /// Create a router for the given current route
+    pub fn new(current: R) -> Self {
+        Self {
+            current,
+            routes: Vec::new(),
+            fallback: None,
+        }
+    }
+
+    /// Create a router for a navigation history's current route
+    pub fn from_history(history: &HistoryHandle<R>) -> Self
+    where
+        R: Clone + Send + Sync,
+    {
+        Self::new(history.get())
+    }
+
+    /// Render `view` when the current route equals `route`
+    pub fn route<F>(self, route: R, view: F) -> Self
+    where
+        R: PartialEq,
+        F: FnOnce() -> Element + 'static,
+    {
+        self.matching(move |current| *current == route, move |_| view())
+    }
+
+    /// Render `view` when `matcher` accepts the current route
+    pub fn matching<M, F>(mut self, matcher: M, view: F) -> Self
+    where
+        M: Fn(&R) -> bool + 'static,
+        F: FnOnce(&R) -> Element + 'static,
+    {
+        self.routes.push((Box::new(matcher), Box::new(view)));
+        self
+    }
+
+    /// Render `view` when no route matches
+    pub fn fallback<F>(mut self, view: F) -> Self
+    where
+        F: FnOnce(&R) -> Element + 'static,
+    {
+        self.fallback = Some(Box::new(view));
+        self
+    }
+
+    /// Get the current route
+    pub fn current(&self) -> &R {
+        &self.current
+    }
+
+    /// Convert to Element
+    ///
+    /// Renders the first matching route, then the fallback, and an empty
+    /// box when neither applies.
+    pub fn into_element(self) -> Element {
+        let Self {
+            current,
+            routes,
+            fallback,
+        } = self;
+
+        let view = routes
+            .into_iter()
+            .find(|(matcher, _)| matcher(&current))
+            .map(|(_, view)| view)
+            .or(fallback);
+
+        match view {
+            Some(view) => view(&current),
+            None => RnkBox::new().into_element(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::Text;
+    use crate::hooks::use_history;
+    use crate::testing::{TestRenderer, hook_harness};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Screen {
+        Settings,
+        Detail(usize),
+        Missing,
+    }
+
+    fn view(screen: Screen) -> String {
+        let element = Router::new(screen)
+            .route(Screen::Settings, || Text::new("settings").into_element())
+            .matching(
+                |s| matches!(s, Screen::Detail(_)),
+                |s| Text::new(format!("{s:?}")).into_element(),
+            )
+            .fallback(|_| Text::new("not found").into_element())
+            .into_element();
+        TestRenderer::new(20, 1).render_to_plain(&element)
+    }
+
+    #[test]
+    fn test_router_picks_first_match_then_fallback() {
+        assert_eq!(view(Screen::Settings).trim_end(), "settings");
+        assert_eq!(view(Screen::Detail(3)).trim_end(), "Detail(3)");
+        assert_eq!(view(Screen::Missing).trim_end(), "not found");
+
+        let empty = Router::new(Screen::Missing).into_element();
+        assert!(empty.children.is_empty());
+    }
+
+    #[test]
+    fn test_router_follows_history() {
+        let mut harness = hook_harness(|| {
+            let nav = use_history(Screen::Settings);
+            let route = Router::from_history(&nav).current().clone();
+            (nav, route)
+        });
+
+        harness.act(|(nav, _)| nav.push(Screen::Detail(1)));
+        assert_eq!(harness.result().1, Screen::Detail(1));
+
+        harness.act(|(nav, _)| {
+            nav.back();
+        });
+        assert_eq!(harness.result().1, Screen::Settings);
+    }
+}
@@ -2,9 +2,17 @@
 //!
 //! Provides vertical and horizontal scrollbar widgets.
 
+use std::time::Duration;
+
+use crate::animation::Easing;
 use crate::components::{Box as RnkBox, Text};
 use crate::core::{Color, Element, FlexDirection};
 
+/// How long an auto-hiding scrollbar stays fully visible after a scroll
+const AUTO_HIDE_DELAY: Duration = Duration::from_millis(800);
+/// How long an auto-hiding scrollbar takes to fade out after the delay
+const AUTO_HIDE_FADE: Duration = Duration::from_millis(400);
+
 /// Scrollbar orientation
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ScrollbarOrientation {
@@ -84,6 +92,46 @@ impl ScrollbarSymbols {
             end: Some('╩'),
         }
     }
+
+    /// Plain ASCII style for terminals without box drawing glyphs
+    pub fn ascii() -> Self {
+        Self {
+            track: '|',
+            thumb: '#',
+            begin: None,
+            end: None,
+        }
+    }
+
+    /// Plain ASCII style for horizontal scrollbars
+    pub fn ascii_horizontal() -> Self {
+        Self {
+            track: '-',
+            thumb: '#',
+            begin: None,
+            end: None,
+        }
+    }
+
+    /// Thin style with a light dashed track
+    pub fn thin() -> Self {
+        Self {
+            track: '╎',
+            thumb: '│',
+            begin: None,
+            end: None,
+        }
+    }
+
+    /// Braille style with an invisible track
+    pub fn braille() -> Self {
+        Self {
+            track: '⠀',
+            thumb: '⣿',
+            begin: None,
+            end: None,
+        }
+    }
 }
 
 /// Scrollbar component builder
@@ -103,6 +151,10 @@ pub struct Scrollbar {
     viewport_ratio: f32,
     /// Total length in cells
     length: u16,
+    /// Time since the last scroll, when auto-hiding
+    since_scroll: Option<Duration>,
+    /// Show a faint track instead of nothing when the content fits
+    track_when_fits: bool,
     /// Key for reconciliation
     key: Option<String>,
 }
@@ -118,6 +170,8 @@ impl Scrollbar {
             position: 0.0,
             viewport_ratio: 0.5,
             length: 10,
+            since_scroll: None,
+            track_when_fits: false,
             key: None,
         }
     }
@@ -189,14 +243,102 @@ impl Scrollbar {
         self
     }
 
+    /// Only show the scrollbar while scrolling
+    ///
+    /// `since_scroll` is the time since the content last scrolled. The
+    /// scrollbar stays visible briefly, then fades out; keep re-rendering
+    /// (e.g. with `use_interval`) until [`is_hidden`](Self::is_hidden).
+    pub fn auto_hide(mut self, since_scroll: Duration) -> Self {
+        self.since_scroll = Some(since_scroll);
+        self
+    }
+
+    /// Show a full-length faint track when the content fits the viewport
+    ///
+    /// By default nothing is drawn in that case.
+    pub fn track_when_fits(mut self, show: bool) -> Self {
+        self.track_when_fits = show;
+        self
+    }
+
     /// Set key
     pub fn key(mut self, key: impl Into<String>) -> Self {
         self.key = Some(key.into());
         self
     }
 
+    /// Auto-hide fade level (`0.0` fully visible, `1.0` hidden)
+    pub fn fade_level(&self) -> f32 {
+        let Some(elapsed) = self.since_scroll else {
+            return 0.0;
+        };
+        let Some(fading) = elapsed.checked_sub(AUTO_HIDE_DELAY) else {
+            return 0.0;
+        };
+        let t = fading.as_secs_f32() / AUTO_HIDE_FADE.as_secs_f32();
+        Easing::EaseIn.apply(t.min(1.0))
+    }
+
+    /// Whether the scrollbar currently draws nothing
+    pub fn is_hidden(&self) -> bool {
+        let fits = self.viewport_ratio >= 1.0;
+        (fits && !self.track_when_fits) || self.fade_level() >= 1.0
+    }
+
+    /// Empty element that keeps the scrollbar's footprint
+    fn placeholder(self) -> Element {
+        let mut container = RnkBox::new();
+        container = match self.orientation {
+            ScrollbarOrientation::Vertical => container.width(1).height(self.length),
+            ScrollbarOrientation::Horizontal => container.width(self.length).height(1),
+        };
+        if let Some(key) = self.key {
+            container = container.key(key);
+        }
+        container.into_element()
+    }
+
+    /// Faint full-length track shown when the content fits
+    fn fitted_track(self) -> Element {
+        let track = self.symbols.track;
+        let length = self.length as usize;
+        let mut container = RnkBox::new();
+        if self.orientation == ScrollbarOrientation::Vertical {
+            container = container.flex_direction(FlexDirection::Column);
+        }
+        if let Some(key) = self.key.clone() {
+            container = container.key(key);
+        }
+        let faint = |content: String| {
+            let mut text = Text::new(content).dim();
+            if let Some(color) = self.track_color {
+                text = text.color(color);
+            }
+            text.into_element()
+        };
+        match self.orientation {
+            ScrollbarOrientation::Vertical => {
+                for _ in 0..length {
+                    container = container.child(faint(track.to_string()));
+                }
+            }
+            ScrollbarOrientation::Horizontal => {
+                container = container.child(faint(track.to_string().repeat(length)));
+            }
+        }
+        container.into_element()
+    }
+
     /// Convert to element
     pub fn into_element(self) -> Element {
+        if self.is_hidden() {
+            return self.placeholder();
+        }
+        if self.viewport_ratio >= 1.0 {
+            return self.fitted_track();
+        }
+
+        let fade = self.fade_level();
         let total_length = self.length as usize;
         let has_begin = self.symbols.begin.is_some();
         let has_end = self.symbols.end.is_some();
@@ -257,7 +399,9 @@ impl Scrollbar {
                 } else if let Some(color) = self.track_color {
                     text = text.color(color);
                 }
-                container = container.child(text.into_element());
+                let mut element = text.into_element();
+                element.style.faint_level = Some(fade);
+                container = container.child(element);
             }
 
             container.into_element()
@@ -275,7 +419,9 @@ impl Scrollbar {
             if let Some(key) = self.key {
                 container = container.key(key);
             }
-            container = container.child(text.into_element());
+            let mut element = text.into_element();
+            element.style.faint_level = Some(fade);
+            container = container.child(element);
             container.into_element()
         }
     }
@@ -328,4 +474,46 @@ mod tests {
         let horizontal = Scrollbar::horizontal();
         assert_eq!(horizontal.orientation, ScrollbarOrientation::Horizontal);
     }
+
+    #[test]
+    fn test_scrollbar_presets() {
+        assert_eq!(ScrollbarSymbols::ascii().thumb, '#');
+        assert_eq!(ScrollbarSymbols::ascii_horizontal().track, '-');
+        assert_eq!(ScrollbarSymbols::thin().thumb, '│');
+        assert_eq!(ScrollbarSymbols::braille().thumb, '⣿');
+        assert!(ScrollbarSymbols::braille().begin.is_none());
+    }
+
+    #[test]
+    fn test_scrollbar_auto_hide_fades_out() {
+        let bar = || Scrollbar::new().from_sizes(100, 20, 0);
+
+        assert_eq!(bar().auto_hide(Duration::ZERO).fade_level(), 0.0);
+        assert_eq!(bar().auto_hide(AUTO_HIDE_DELAY).fade_level(), 0.0);
+
+        let fading = bar().auto_hide(AUTO_HIDE_DELAY + AUTO_HIDE_FADE / 2);
+        assert!(fading.fade_level() > 0.0 && fading.fade_level() < 1.0);
+        assert!(!fading.is_hidden());
+
+        let hidden = bar().auto_hide(AUTO_HIDE_DELAY + AUTO_HIDE_FADE);
+        assert!(hidden.is_hidden());
+        assert!(hidden.into_element().children.is_empty());
+    }
+
+    #[test]
+    fn test_scrollbar_hidden_when_content_fits() {
+        let fits = Scrollbar::new().from_sizes(10, 20, 0).length(4);
+        assert!(fits.is_hidden());
+        assert!(fits.into_element().children.is_empty());
+
+        let track = Scrollbar::new()
+            .symbols(ScrollbarSymbols::block())
+            .from_sizes(10, 20, 0)
+            .length(4)
+            .track_when_fits(true);
+        assert!(!track.is_hidden());
+        let element = track.into_element();
+        assert_eq!(element.children.len(), 4);
+        assert!(element.children.get(0).unwrap().style.dim);
+    }
 }
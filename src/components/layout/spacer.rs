@@ -1,6 +1,10 @@
 //! Spacer component - Flexible space in layouts
+//!
+//! [`Spacer`] grows to fill free space; [`VSpace`] and [`HSpace`] reserve an
+//! exact number of rows or columns instead, for spacing that must not
+//! depend on the size of the container (e.g. in inline output).
 
-use crate::core::{Element, ElementType, Style};
+use crate::core::{Dimension, Element, ElementType, Style};
 
 /// Spacer component that expands to fill available space
 ///
@@ -42,6 +46,54 @@ impl Spacer {
     }
 }
 
+/// Fixed vertical gap of exactly `n` rows
+///
+/// Unlike [`Spacer`], it neither grows nor shrinks.
+///
+/// # Example
+///
+/// ```ignore
+/// Box::new()
+///     .flex_direction(FlexDirection::Column)
+///     .child(Text::new("Title").into_element())
+///     .child(VSpace(2).into_element())
+///     .child(Text::new("Body").into_element())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct VSpace(pub u16);
+
+impl VSpace {
+    /// Convert to Element
+    pub fn into_element(self) -> Element {
+        fixed_space(0, self.0)
+    }
+}
+
+/// Fixed horizontal gap of exactly `n` columns
+///
+/// Unlike [`Spacer`], it neither grows nor shrinks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HSpace(pub u16);
+
+impl HSpace {
+    /// Convert to Element
+    pub fn into_element(self) -> Element {
+        fixed_space(self.0, 0)
+    }
+}
+
+/// Empty box pinned to `width` x `height` cells
+fn fixed_space(width: u16, height: u16) -> Element {
+    let mut element = Element::new(ElementType::Box);
+    element.style = Style::new();
+    element.style.width = Dimension::Points(width as f32);
+    element.style.height = Dimension::Points(height as f32);
+    element.style.min_width = element.style.width;
+    element.style.min_height = element.style.height;
+    element.style.flex_shrink = 0.0;
+    element
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,4 +110,27 @@ mod tests {
         let element = Spacer::new().flex(2.0).into_element();
         assert_eq!(element.style.flex_grow, 2.0);
     }
+
+    #[test]
+    fn test_fixed_spaces_reserve_exact_cells() {
+        use crate::components::{Box, Text};
+        use crate::core::FlexDirection;
+
+        let element = Box::new()
+            .flex_direction(FlexDirection::Column)
+            .child(
+                Box::new()
+                    .child(Text::new("a").into_element())
+                    .child(HSpace(3).into_element())
+                    .child(Text::new("bcd").into_element())
+                    .into_element(),
+            )
+            .child(VSpace(2).into_element())
+            .child(Text::new("e").into_element())
+            .into_element();
+
+        let output = crate::testing::TestRenderer::new(8, 5).render_to_plain(&element);
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["a   bcd", "", "", "e"]);
+    }
 }
@@ -0,0 +1,514 @@
+//! Resizable two-pane layout
+//!
+//! [`SplitPane`] places two children on either side of a one-cell divider.
+//! The split is stored as a ratio, so the panes keep their proportions when
+//! the terminal is resized. [`SplitPaneState`] holds the ratio between
+//! renders; feed it mouse events with [`handle_split_pane_mouse`] and key
+//! events with [`handle_split_pane_input`] to let the user move the divider.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use rnk::components::{SplitDirection, SplitPane, SplitPaneState};
+//! use rnk::prelude::*;
+//!
+//! fn app() -> Element {
+//!     let state = use_signal(|| SplitPaneState::new(0.3).min_sizes(10, 20));
+//!
+//!     SplitPane::new(sidebar(), editor())
+//!         .direction(SplitDirection::Horizontal)
+//!         .state(&state.get())
+//!         .into_element()
+//! }
+//! ```
+
+use crate::components::Box as RnkBox;
+use crate::core::{AlignItems, BorderStyle, Color, Dimension, Element, FlexDirection, Overflow};
+use crate::hooks::{Key, Mouse, MouseAction, MouseButton};
+
+/// Which way the panes are laid out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SplitDirection {
+    /// Panes side by side with a vertical divider
+    #[default]
+    Horizontal,
+    /// Panes stacked with a horizontal divider
+    Vertical,
+}
+
+/// Divider position and interaction state for a [`SplitPane`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SplitPaneState {
+    ratio: f32,
+    min_first: u16,
+    min_second: u16,
+    dragging: bool,
+    focused: bool,
+}
+
+impl SplitPaneState {
+    /// Create a state with the first pane taking `ratio` (0.0 to 1.0) of
+    /// the space
+    pub fn new(ratio: f32) -> Self {
+        Self {
+            ratio: clamp_ratio(ratio),
+            min_first: 0,
+            min_second: 0,
+            dragging: false,
+            focused: false,
+        }
+    }
+
+    /// Set the minimum size in cells of each pane
+    pub fn min_sizes(mut self, first: u16, second: u16) -> Self {
+        self.min_first = first;
+        self.min_second = second;
+        self
+    }
+
+    /// Share of the space (0.0 to 1.0) given to the first pane
+    pub fn ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    /// Set the share of the space given to the first pane
+    pub fn set_ratio(&mut self, ratio: f32) {
+        self.ratio = clamp_ratio(ratio);
+    }
+
+    /// Minimum sizes of the first and second panes
+    pub fn min_size(&self) -> (u16, u16) {
+        (self.min_first, self.min_second)
+    }
+
+    /// Check whether the divider is being dragged
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Check whether the divider has keyboard focus
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Give or take keyboard focus from the divider
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Size in cells of the first pane when the split spans `total` cells
+    ///
+    /// `total` includes the divider.
+    pub fn first_size(&self, total: u16) -> u16 {
+        let available = total.saturating_sub(1);
+        let size = (self.ratio * available as f32).round() as u16;
+        let max = available.saturating_sub(self.min_second);
+        size.min(max).max(self.min_first.min(available))
+    }
+
+    /// Put the divider at `offset` cells from the start of a `total`-cell
+    /// split, respecting the minimum pane sizes
+    pub fn set_divider(&mut self, offset: u16, total: u16) {
+        let available = total.saturating_sub(1);
+        if available == 0 {
+            return;
+        }
+        let max = available.saturating_sub(self.min_second);
+        let offset = offset.min(max).max(self.min_first.min(available));
+        self.ratio = offset as f32 / available as f32;
+    }
+
+    /// Move the divider by `cells` (negative moves toward the first pane)
+    pub fn resize_by(&mut self, cells: i32, total: u16) {
+        let offset = (self.first_size(total) as i32 + cells).max(0);
+        self.set_divider(offset.min(u16::MAX as i32) as u16, total);
+    }
+}
+
+impl Default for SplitPaneState {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+fn clamp_ratio(ratio: f32) -> f32 {
+    if ratio.is_nan() {
+        0.5
+    } else {
+        ratio.clamp(0.0, 1.0)
+    }
+}
+
+/// Two panes separated by a draggable divider
+#[derive(Debug, Clone)]
+pub struct SplitPane {
+    first: Element,
+    second: Element,
+    direction: SplitDirection,
+    ratio: f32,
+    min_first: u16,
+    min_second: u16,
+    width: Dimension,
+    height: Dimension,
+    divider_color: Option<Color>,
+    focus_color: Color,
+    divider_focused: bool,
+    key: Option<String>,
+}
+
+impl SplitPane {
+    /// Create a split with `first` on the left (or top) and `second` on the
+    /// right (or bottom)
+    pub fn new(first: Element, second: Element) -> Self {
+        Self {
+            first,
+            second,
+            direction: SplitDirection::default(),
+            ratio: 0.5,
+            min_first: 0,
+            min_second: 0,
+            width: Dimension::Percent(100.0),
+            height: Dimension::Percent(100.0),
+            divider_color: None,
+            focus_color: Color::Cyan,
+            divider_focused: false,
+            key: None,
+        }
+    }
+
+    /// Set the layout direction
+    pub fn direction(mut self, direction: SplitDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Set the share of the space (0.0 to 1.0) given to the first pane
+    pub fn ratio(mut self, ratio: f32) -> Self {
+        self.ratio = clamp_ratio(ratio);
+        self
+    }
+
+    /// Set the minimum size in cells of each pane
+    pub fn min_sizes(mut self, first: u16, second: u16) -> Self {
+        self.min_first = first;
+        self.min_second = second;
+        self
+    }
+
+    /// Take the ratio, minimum sizes and divider focus from a state
+    pub fn state(mut self, state: &SplitPaneState) -> Self {
+        self.ratio = state.ratio;
+        self.min_first = state.min_first;
+        self.min_second = state.min_second;
+        self.divider_focused = state.focused || state.dragging;
+        self
+    }
+
+    /// Set the width (defaults to the full parent width)
+    pub fn width(mut self, width: impl Into<Dimension>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the height (defaults to the full parent height)
+    pub fn height(mut self, height: impl Into<Dimension>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Set the divider color
+    pub fn divider_color(mut self, color: Color) -> Self {
+        self.divider_color = Some(color);
+        self
+    }
+
+    /// Set the divider color used while it is focused or dragged
+    pub fn focus_color(mut self, color: Color) -> Self {
+        self.focus_color = color;
+        self
+    }
+
+    /// Set key
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// Convert to element
+    pub fn into_element(self) -> Element {
+        let horizontal = self.direction == SplitDirection::Horizontal;
+
+        // Flex weights keep the ratio as the container is resized
+        let pane = |child: Element, grow: f32, min: u16| {
+            let pane = RnkBox::new()
+                .flex_grow(grow)
+                .flex_shrink(1.0)
+                .flex_basis(0)
+                .overflow(Overflow::Hidden)
+                .child(child);
+            if horizontal {
+                pane.min_width(min)
+            } else {
+                pane.min_height(min)
+            }
+        };
+
+        // A one-sided border follows the pane's size on the cross axis
+        let mut divider = RnkBox::new()
+            .flex_shrink(0.0)
+            .border_style(BorderStyle::Single);
+        divider = if horizontal {
+            divider.width(1).border(false, false, false, true)
+        } else {
+            divider.height(1).border(true, false, false, false)
+        };
+        if self.divider_focused {
+            divider = divider.border_color(self.focus_color);
+        } else if let Some(color) = self.divider_color {
+            divider = divider.border_color(color);
+        } else {
+            divider = divider.border_dim(true);
+        }
+
+        let mut container = RnkBox::new()
+            .flex_direction(if horizontal {
+                FlexDirection::Row
+            } else {
+                FlexDirection::Column
+            })
+            .align_items(AlignItems::Stretch)
+            .width(self.width)
+            .height(self.height)
+            .child(pane(self.first, self.ratio, self.min_first).into_element())
+            .child(divider.into_element())
+            .child(pane(self.second, 1.0 - self.ratio, self.min_second).into_element());
+
+        if let Some(key) = self.key {
+            container = container.key(key);
+        }
+
+        container.into_element()
+    }
+}
+
+/// Drag the divider of a split pane with the mouse
+///
+/// `area` is the split's on-screen rectangle as `(x, y, width, height)`.
+/// Pressing on the divider starts a drag, dragging moves it and releasing
+/// ends the drag. Returns `true` if the event was used.
+pub fn handle_split_pane_mouse(
+    state: &mut SplitPaneState,
+    mouse: &Mouse,
+    direction: SplitDirection,
+    area: (u16, u16, u16, u16),
+) -> bool {
+    let (x, y, width, height) = area;
+    let (pos, cross, start, cross_start, total, cross_len) = match direction {
+        SplitDirection::Horizontal => (mouse.x, mouse.y, x, y, width, height),
+        SplitDirection::Vertical => (mouse.y, mouse.x, y, x, height, width),
+    };
+
+    match mouse.action {
+        MouseAction::Press(MouseButton::Left) => {
+            let inside = cross >= cross_start && cross - cross_start < cross_len;
+            let on_divider = pos >= start && pos - start == state.first_size(total);
+            if inside && on_divider {
+                state.dragging = true;
+                return true;
+            }
+            false
+        }
+        MouseAction::Drag(MouseButton::Left) if state.dragging => {
+            state.set_divider(pos.saturating_sub(start), total);
+            true
+        }
+        MouseAction::Release(MouseButton::Left) if state.dragging => {
+            state.dragging = false;
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Move a focused divider with the arrow keys
+///
+/// Left/Right (or Up/Down for a vertical split) move the divider one cell;
+/// Home and End move it as far as the minimum pane sizes allow. `total` is
+/// the split's size along its direction. Does nothing unless the divider
+/// is focused.
+pub fn handle_split_pane_input(
+    state: &mut SplitPaneState,
+    key: &Key,
+    direction: SplitDirection,
+    total: u16,
+) -> bool {
+    if !state.focused {
+        return false;
+    }
+
+    let (back, forward) = match direction {
+        SplitDirection::Horizontal => (key.left_arrow, key.right_arrow),
+        SplitDirection::Vertical => (key.up_arrow, key.down_arrow),
+    };
+
+    if back {
+        state.resize_by(-1, total);
+    } else if forward {
+        state.resize_by(1, total);
+    } else if key.home {
+        state.set_divider(0, total);
+    } else if key.end {
+        state.set_divider(total, total);
+    } else {
+        return false;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::Text;
+    use crate::testing::TestRenderer;
+
+    fn press(action: MouseAction, x: u16, y: u16) -> Mouse {
+        Mouse {
+            x,
+            y,
+            action,
+            ctrl: false,
+            shift: false,
+            alt: false,
+        }
+    }
+
+    fn rows(element: &Element, width: u16, height: u16) -> Vec<String> {
+        TestRenderer::new(width, height)
+            .render_to_plain(element)
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_split_pane_keeps_ratio_across_sizes() {
+        let split = || {
+            SplitPane::new(
+                Text::new("left").into_element(),
+                Text::new("right").into_element(),
+            )
+            .ratio(0.25)
+            .into_element()
+        };
+
+        assert_eq!(rows(&split(), 21, 2), ["left │right", "     │"]);
+        assert_eq!(rows(&split(), 41, 1), ["left      │right"]);
+    }
+
+    #[test]
+    fn test_divider_spans_tall_panes() {
+        let element = SplitPane::new(
+            Text::new("left").into_element(),
+            Text::new("right").into_element(),
+        )
+        .ratio(0.25)
+        .into_element();
+
+        let rows = rows(&element, 21, 300);
+        assert_eq!(rows.len(), 300);
+        assert!(rows.iter().all(|row| row.chars().nth(5) == Some('│')));
+    }
+
+    #[test]
+    fn test_vertical_split_stacks_panes() {
+        let element = SplitPane::new(
+            Text::new("top").into_element(),
+            Text::new("bottom").into_element(),
+        )
+        .direction(SplitDirection::Vertical)
+        .into_element();
+
+        assert_eq!(rows(&element, 6, 5), ["top", "", "──────", "bottom"]);
+    }
+
+    #[test]
+    fn test_min_sizes_clamp_divider() {
+        let mut state = SplitPaneState::new(0.5).min_sizes(4, 6);
+
+        state.set_divider(0, 21);
+        assert_eq!(state.first_size(21), 4);
+        state.set_divider(20, 21);
+        assert_eq!(state.first_size(21), 14);
+        state.resize_by(-3, 21);
+        assert_eq!(state.first_size(21), 11);
+    }
+
+    #[test]
+    fn test_mouse_drag_moves_divider() {
+        let mut state = SplitPaneState::new(0.5);
+        let area = (2, 1, 21, 5);
+
+        // Pressing off the divider does nothing
+        assert!(!handle_split_pane_mouse(
+            &mut state,
+            &press(MouseAction::Press(MouseButton::Left), 5, 2),
+            SplitDirection::Horizontal,
+            area,
+        ));
+        assert!(handle_split_pane_mouse(
+            &mut state,
+            &press(MouseAction::Press(MouseButton::Left), 12, 2),
+            SplitDirection::Horizontal,
+            area,
+        ));
+        assert!(state.is_dragging());
+
+        handle_split_pane_mouse(
+            &mut state,
+            &press(MouseAction::Drag(MouseButton::Left), 7, 3),
+            SplitDirection::Horizontal,
+            area,
+        );
+        assert_eq!(state.first_size(21), 5);
+        assert!((state.ratio() - 0.25).abs() < f32::EPSILON);
+
+        handle_split_pane_mouse(
+            &mut state,
+            &press(MouseAction::Release(MouseButton::Left), 7, 3),
+            SplitDirection::Horizontal,
+            area,
+        );
+        assert!(!state.is_dragging());
+    }
+
+    #[test]
+    fn test_keyboard_moves_focused_divider() {
+        let mut state = SplitPaneState::new(0.5);
+        let right = Key {
+            right_arrow: true,
+            ..Key::default()
+        };
+
+        assert!(!handle_split_pane_input(
+            &mut state,
+            &right,
+            SplitDirection::Horizontal,
+            11
+        ));
+        state.set_focused(true);
+        assert!(handle_split_pane_input(
+            &mut state,
+            &right,
+            SplitDirection::Horizontal,
+            11
+        ));
+        assert_eq!(state.first_size(11), 6);
+
+        let home = Key {
+            home: true,
+            ..Key::default()
+        };
+        handle_split_pane_input(&mut state, &home, SplitDirection::Horizontal, 11);
+        assert_eq!(state.first_size(11), 0);
+    }
+}
@@ -1,18 +1,27 @@
 //! Table component for displaying tabular data
 //!
 //! Provides a table widget with headers, rows, and optional selection.
+//! Cells can span several columns or rows for grouped headers and merged
+//! summary cells. [`Table::from_csv`] and [`Table::from_tsv`] build a table
+//! straight from delimited text.
 
 use crate::components::navigation::SelectionState;
 use crate::components::{Box as RnkBox, Line, Span, Text};
 use crate::core::{Color, Element, FlexDirection, Style};
+use unicode_width::UnicodeWidthChar;
 
 /// Table cell content
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct Cell {
     /// Cell content
     pub content: Line,
     /// Cell style
     pub style: Option<Style>,
+    /// Number of columns the cell occupies
+    pub colspan: u16,
+    /// Number of rows the cell occupies
+    pub rowspan: u16,
 }
 
 impl Cell {
@@ -21,6 +30,8 @@ impl Cell {
         Self {
             content: Line::raw(content),
             style: None,
+            colspan: 1,
+            rowspan: 1,
         }
     }
 
@@ -29,6 +40,8 @@ impl Cell {
         Self {
             content: line,
             style: None,
+            colspan: 1,
+            rowspan: 1,
         }
     }
 
@@ -37,6 +50,8 @@ impl Cell {
         Self {
             content: Line::from_spans(spans),
             style: None,
+            colspan: 1,
+            rowspan: 1,
         }
     }
 
@@ -52,6 +67,25 @@ impl Cell {
         style.color = Some(color);
         self
     }
+
+    /// Span this cell across `span` columns
+    ///
+    /// Cells after it in the row move right, and no column separator is
+    /// drawn inside the merged region.
+    pub fn colspan(mut self, span: u16) -> Self {
+        self.colspan = span.max(1);
+        self
+    }
+
+    /// Span this cell across `span` rows
+    ///
+    /// The cell's columns stay blank in the rows below it, and those rows'
+    /// cells flow around it. Spans do not cross from the header into the
+    /// data rows.
+    pub fn rowspan(mut self, span: u16) -> Self {
+        self.rowspan = span.max(1);
+        self
+    }
 }
 
 impl<T: Into<String>> From<T> for Cell {
@@ -98,6 +132,79 @@ impl Row {
     }
 }
 
+/// Position of a cell in the column grid
+#[derive(Debug, Clone, Copy)]
+struct Slot<'a> {
+    /// First column the slot covers
+    column: usize,
+    /// Number of columns covered
+    colspan: usize,
+    /// The cell, or `None` where a cell from a row above spans down
+    cell: Option<&'a Cell>,
+}
+
+impl Slot<'_> {
+    fn end(&self) -> usize {
+        self.column + self.colspan
+    }
+}
+
+/// Emit slots for the columns at `column` still covered from rows above
+fn skip_covered<'a>(covered: &[(usize, usize)], column: &mut usize, slots: &mut Vec<Slot<'a>>) {
+    while let Some(&(rows_left, colspan)) = covered.get(*column)
+        && rows_left > 0
+    {
+        slots.push(Slot {
+            column: *column,
+            colspan,
+            cell: None,
+        });
+        *column += colspan;
+    }
+}
+
+/// Lay rows out on the column grid, flowing cells around row spans
+fn place_rows<'a>(rows: impl IntoIterator<Item = &'a Row>) -> Vec<Vec<Slot<'a>>> {
+    // Rows still covered and the column span, keyed by first column
+    let mut covered: Vec<(usize, usize)> = Vec::new();
+    let mut placed = Vec::new();
+
+    for row in rows {
+        let mut slots = Vec::new();
+        let mut column = 0;
+        for cell in &row.cells {
+            skip_covered(&covered, &mut column, &mut slots);
+            let colspan = cell.colspan.max(1) as usize;
+            if cell.rowspan > 1 {
+                if covered.len() <= column {
+                    covered.resize(column + 1, (0, 0));
+                }
+                // Counted down at the end of this row
+                covered[column] = (cell.rowspan as usize, colspan);
+            }
+            slots.push(Slot {
+                column,
+                colspan,
+                cell: Some(cell),
+            });
+            column += colspan;
+        }
+
+        // Spans from above that lie past this row's last cell
+        while column < covered.len() {
+            skip_covered(&covered, &mut column, &mut slots);
+            column += 1;
+        }
+
+        for (rows_left, _) in &mut covered {
+            *rows_left = rows_left.saturating_sub(1);
+        }
+        placed.push(slots);
+    }
+
+    placed
+}
+
 /// Table state for tracking selection
 #[derive(Debug, Clone, Default)]
 pub struct TableState {
@@ -153,6 +260,58 @@ pub enum Constraint {
     Percentage(u16),
     /// Fill remaining space with ratio
     Ratio(u16, u16),
+    /// Widest cell content, clamped to optional bounds
+    Auto {
+        /// Smallest width the column may shrink to
+        min: Option<u16>,
+        /// Largest width the column may grow to
+        max: Option<u16>,
+    },
+    /// Share of the width left over after every other column, by weight
+    Fill(u16),
+}
+
+impl Constraint {
+    /// Content-sized column without bounds
+    pub fn auto() -> Self {
+        Constraint::Auto {
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Starting width for a column whose widest cell is `content` wide
+    fn base_width(&self, content: usize, available: usize) -> usize {
+        match *self {
+            Constraint::Length(n) => n as usize,
+            Constraint::Min(n) => content.max(n as usize),
+            Constraint::Max(n) => content.min(n as usize),
+            Constraint::Percentage(p) => available * (p.min(100) as usize) / 100,
+            Constraint::Ratio(num, den) => {
+                if den == 0 {
+                    0
+                } else {
+                    available * (num.min(den) as usize) / den as usize
+                }
+            }
+            Constraint::Auto { min, max } => {
+                let width = content.max(min.unwrap_or(0) as usize);
+                max.map_or(width, |max| width.min(max as usize))
+            }
+            Constraint::Fill(_) => 0,
+        }
+    }
+
+    /// Narrowest width the column may be squeezed to when space runs out,
+    /// or `None` if the width is fixed
+    fn shrink_floor(&self) -> Option<usize> {
+        match *self {
+            Constraint::Min(n) => Some(n as usize),
+            Constraint::Max(_) => Some(1),
+            Constraint::Auto { min, .. } => Some(min.unwrap_or(1) as usize),
+            _ => None,
+        }
+    }
 }
 
 impl Default for Constraint {
@@ -176,6 +335,8 @@ pub struct Table {
     highlight_symbol: Option<String>,
     /// Column separator
     column_separator: Option<String>,
+    /// Total width to lay columns out in (terminal width when unset)
+    width: Option<u16>,
     /// Key for reconciliation
     key: Option<String>,
 }
@@ -190,10 +351,71 @@ impl Table {
             highlight_style: Style::new(),
             highlight_symbol: None,
             column_separator: Some(" ".to_string()),
+            width: None,
             key: None,
         }
     }
 
+    /// Build a table from comma-separated text
+    ///
+    /// Fields may be quoted with `"`, which lets them contain commas, line
+    /// breaks and doubled `""` quotes. Cells stay on one line, so a line
+    /// break inside a field is shown as `⏎`. With `has_header`, the first record
+    /// becomes a bold header row. Every row is padded with empty cells or
+    /// cut to the header's width (or the widest row without a header), and
+    /// blank lines are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rnk::components::Table;
+    ///
+    /// let table = Table::from_csv("name,notes\nrnk,\"fast, small\"\n", true);
+    /// assert_eq!(table.len(), 1);
+    /// ```
+    pub fn from_csv(text: &str, has_header: bool) -> Self {
+        Self::from_records(parse_delimited(text, ',', true), has_header)
+    }
+
+    /// Build a table from tab-separated text
+    ///
+    /// Like [`from_csv`](Self::from_csv), but fields are split on tabs
+    /// and never quoted, as in the output of most command line tools.
+    pub fn from_tsv(text: &str, has_header: bool) -> Self {
+        Self::from_records(parse_delimited(text, '\t', false), has_header)
+    }
+
+    fn from_records(mut records: Vec<Vec<String>>, has_header: bool) -> Self {
+        // Cells are drawn on one line, so quoted line breaks are shown as a
+        // marker instead of spilling into the rows below
+        for field in records.iter_mut().flatten() {
+            if field.contains(['\r', '\n']) {
+                *field = field
+                    .replace("\r\n", "\n")
+                    .replace(['\r', '\n'], LINE_BREAK_MARKER);
+            }
+        }
+        let header = (has_header && !records.is_empty()).then(|| records.remove(0));
+        let columns = match &header {
+            Some(header) => header.len(),
+            None => records.iter().map(Vec::len).max().unwrap_or(0),
+        };
+        let row = |mut fields: Vec<String>| {
+            fields.resize(columns, String::new());
+            Row::new(fields)
+        };
+
+        let mut table = Self::new().rows(records.into_iter().map(row));
+        if let Some(mut fields) = header {
+            fields.resize(columns, String::new());
+            table.header =
+                Some(Row::new(fields.into_iter().map(|field| {
+                    Cell::from_spans(vec![Span::new(field).bold()])
+                })));
+        }
+        table
+    }
+
     /// Set header row
     pub fn header(mut self, header: Row) -> Self {
         self.header = Some(header);
@@ -242,6 +464,14 @@ impl Table {
         self
     }
 
+    /// Set the total width used to resolve column constraints
+    ///
+    /// Defaults to the terminal width, so `Fill` columns track resizes.
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = Some(width);
+        self
+    }
+
     /// Set key
     pub fn key(mut self, key: impl Into<String>) -> Self {
         self.key = Some(key.into());
@@ -258,11 +488,140 @@ impl Table {
         self.rows.is_empty()
     }
 
+    /// Header and data rows laid out on the column grid
+    fn placements(&self) -> (Vec<Vec<Slot<'_>>>, Vec<Vec<Slot<'_>>>) {
+        (place_rows(&self.header), place_rows(&self.rows))
+    }
+
+    /// Number of columns across the header, rows and constraints
+    fn column_count(&self) -> usize {
+        let (header, rows) = self.placements();
+        header
+            .iter()
+            .chain(&rows)
+            .filter_map(|slots| slots.last().map(Slot::end))
+            .chain(std::iter::once(self.widths.len()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Resolve column widths for a table `total_width` cells wide
+    ///
+    /// Each column starts from its constraint (content-sized columns measure
+    /// their widest cell), then any slack is shared between `Fill` columns
+    /// by weight. If the columns don't fit, shrinkable columns give up width
+    /// from the widest down, never below their minimum.
+    ///
+    /// A cell spanning several columns counts the separators it covers as
+    /// content room, and widens its columns evenly if it still doesn't fit.
+    pub fn column_widths(&self, total_width: u16) -> Vec<u16> {
+        let columns = self.column_count();
+        if columns == 0 {
+            return Vec::new();
+        }
+
+        let separator_width = self
+            .column_separator
+            .as_deref()
+            .map(unicode_width::UnicodeWidthStr::width)
+            .unwrap_or(1);
+        let symbol_width = self.highlight_symbol.as_ref().map(|s| s.len()).unwrap_or(0);
+        let available = (total_width as usize)
+            .saturating_sub(symbol_width)
+            .saturating_sub(separator_width * (columns - 1));
+
+        let (header, rows) = self.placements();
+        let mut spanning = Vec::new();
+        let mut content = vec![0usize; columns];
+        for slot in header.iter().chain(&rows).flatten() {
+            let Some(cell) = slot.cell else { continue };
+            if slot.colspan == 1 {
+                content[slot.column] = content[slot.column].max(cell.content.width());
+            } else {
+                spanning.push((slot.column, slot.colspan, cell.content.width()));
+            }
+        }
+
+        // Narrow spans first, so wider ones see the columns they cover grown
+        spanning.sort_by_key(|&(_, colspan, _)| colspan);
+        for (column, colspan, width) in spanning {
+            let range = column..(column + colspan).min(columns);
+            let room =
+                separator_width * (range.len() - 1) + content[range.clone()].iter().sum::<usize>();
+            let extra = width.saturating_sub(room);
+            for (i, column) in range.clone().enumerate() {
+                content[column] += extra / range.len() + usize::from(i < extra % range.len());
+            }
+        }
+
+        let constraints: Vec<Constraint> = (0..columns)
+            .map(|i| self.widths.get(i).copied().unwrap_or_default())
+            .collect();
+        let mut widths: Vec<usize> = constraints
+            .iter()
+            .zip(&content)
+            .map(|(constraint, &content)| constraint.base_width(content, available))
+            .collect();
+
+        let used: usize = widths.iter().sum();
+        if used < available {
+            let fills: Vec<(usize, usize)> = constraints
+                .iter()
+                .enumerate()
+                .filter_map(|(i, c)| match c {
+                    Constraint::Fill(weight) if *weight > 0 => Some((i, *weight as usize)),
+                    _ => None,
+                })
+                .collect();
+            let total_weight: usize = fills.iter().map(|(_, weight)| weight).sum();
+            if !fills.is_empty() {
+                let slack = available - used;
+                let mut given = 0;
+                for &(i, weight) in &fills {
+                    let share = slack * weight / total_weight;
+                    widths[i] += share;
+                    given += share;
+                }
+                // Hand out rounding leftovers one cell at a time, left to right
+                for &(i, _) in fills.iter().cycle().take(slack - given) {
+                    widths[i] += 1;
+                }
+            }
+        } else {
+            let mut overflow = used - available;
+            while overflow > 0 {
+                let widest = constraints
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, c)| c.shrink_floor().is_some_and(|floor| widths[*i] > floor))
+                    .max_by_key(|(i, _)| (widths[*i], std::cmp::Reverse(*i)))
+                    .map(|(i, _)| i);
+                match widest {
+                    Some(i) => {
+                        widths[i] -= 1;
+                        overflow -= 1;
+                    }
+                    None => break,
+                }
+            }
+        }
+
+        widths
+            .into_iter()
+            .map(|w| w.min(u16::MAX as usize) as u16)
+            .collect()
+    }
+
     /// Render the table with state
     pub fn render(self, state: &TableState) -> Element {
         let selected = state.selected;
         let separator = self.column_separator.as_deref().unwrap_or(" ");
         let symbol_width = self.highlight_symbol.as_ref().map(|s| s.len()).unwrap_or(0);
+        let total_width = self
+            .width
+            .unwrap_or_else(|| crate::hooks::get_terminal_size().0);
+        let widths = self.column_widths(total_width);
+        let (header_slots, row_slots) = self.placements();
 
         let mut container = RnkBox::new().flex_direction(FlexDirection::Column);
 
@@ -271,15 +630,15 @@ impl Table {
         }
 
         // Render header if present
-        if let Some(header) = &self.header {
-            let header_element = self.render_row(header, separator, false, symbol_width);
+        for slots in &header_slots {
+            let header_element = self.render_row(slots, &widths, separator, false, symbol_width);
             container = container.child(header_element);
         }
 
         // Render data rows
-        for (idx, row) in self.rows.iter().enumerate() {
+        for (idx, slots) in row_slots.iter().enumerate() {
             let is_selected = selected == Some(idx);
-            let row_element = self.render_row(row, separator, is_selected, symbol_width);
+            let row_element = self.render_row(slots, &widths, separator, is_selected, symbol_width);
             container = container.child(row_element);
         }
 
@@ -289,7 +648,8 @@ impl Table {
     /// Render a single row
     fn render_row(
         &self,
-        row: &Row,
+        slots: &[Slot<'_>],
+        widths: &[u16],
         separator: &str,
         is_selected: bool,
         symbol_width: usize,
@@ -305,16 +665,30 @@ impl Table {
             }
         }
 
-        // Add cells
-        for (i, cell) in row.cells.iter().enumerate() {
-            if i > 0 {
+        // Add cells, padded or truncated to the columns they cover
+        let separator_width = unicode_width::UnicodeWidthStr::width(separator);
+        let mut slots = slots.iter().peekable();
+        let mut column = 0;
+        while column < widths.len() {
+            if column > 0 {
                 spans.push(Span::new(separator));
             }
 
-            // Clone the cell content spans
-            for span in &cell.content.spans {
-                spans.push(span.clone());
+            let slot = slots.next_if(|slot| slot.column == column);
+            let colspan = slot
+                .map_or(1, |slot| slot.colspan)
+                .min(widths.len() - column);
+            let width = widths[column..column + colspan]
+                .iter()
+                .map(|&w| w as usize)
+                .sum::<usize>()
+                + separator_width * (colspan - 1);
+
+            match slot.and_then(|slot| slot.cell) {
+                Some(cell) => spans.extend(fit_line(&cell.content, width)),
+                None => spans.push(Span::new(" ".repeat(width))),
             }
+            column += colspan;
         }
 
         let line = Line::from_spans(spans);
@@ -344,6 +718,98 @@ impl Table {
     }
 }
 
+/// Stands in for a line break inside a CSV field
+const LINE_BREAK_MARKER: &str = "⏎";
+
+/// Split delimited text into records of fields
+///
+/// With `quoted`, a field starting with `"` runs to the next lone `"`, so it
+/// can hold delimiters and line breaks; `""` inside it is a literal quote.
+/// `\r\n` line endings are accepted and empty records are dropped.
+fn parse_delimited(text: &str, delimiter: char, quoted: bool) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        if in_quotes {
+            if ch != '"' {
+                field.push(ch);
+            } else if chars.next_if_eq(&'"').is_some() {
+                field.push('"');
+            } else {
+                in_quotes = false;
+            }
+        } else if quoted && ch == '"' && field.is_empty() {
+            in_quotes = true;
+        } else if ch == delimiter {
+            record.push(std::mem::take(&mut field));
+        } else if ch == '\n' || (ch == '\r' && chars.peek() == Some(&'\n')) {
+            chars.next_if_eq(&'\n');
+            record.push(std::mem::take(&mut field));
+            push_record(&mut records, std::mem::take(&mut record));
+        } else {
+            field.push(ch);
+        }
+    }
+    record.push(field);
+    push_record(&mut records, record);
+    records
+}
+
+/// Keep a record unless it came from a blank line
+fn push_record(records: &mut Vec<Vec<String>>, record: Vec<String>) {
+    if record.len() > 1 || record.first().is_some_and(|field| !field.is_empty()) {
+        records.push(record);
+    }
+}
+
+/// Pad `line` with spaces to `width`, or cut it short with an ellipsis
+fn fit_line(line: &Line, width: usize) -> Vec<Span> {
+    let content_width = line.width();
+    if content_width <= width {
+        let mut spans = line.spans.clone();
+        if content_width < width {
+            spans.push(Span::new(" ".repeat(width - content_width)));
+        }
+        return spans;
+    }
+
+    let mut spans = Vec::new();
+    if width == 0 {
+        return spans;
+    }
+
+    // Leave one cell for the ellipsis
+    let budget = width - 1;
+    let mut used = 0;
+    for span in &line.spans {
+        let mut content = String::new();
+        for ch in span.content.chars() {
+            let ch_width = ch.width().unwrap_or(0);
+            if used + ch_width > budget {
+                break;
+            }
+            content.push(ch);
+            used += ch_width;
+        }
+        let truncated = content.len() < span.content.len();
+        if !content.is_empty() {
+            spans.push(Span::styled(content, span.style.clone()));
+        }
+        if truncated {
+            break;
+        }
+    }
+    spans.push(Span::new("…"));
+    if used < budget {
+        spans.push(Span::new(" ".repeat(budget - used)));
+    }
+    spans
+}
+
 impl Default for Table {
     fn default() -> Self {
         Self::new()
@@ -379,6 +845,78 @@ mod tests {
         assert!(table.header.is_some());
     }
 
+    #[test]
+    fn test_column_widths_auto_sizes_to_content() {
+        let table = Table::new()
+            .header(Row::new(vec!["Name", "Age"]))
+            .rows(vec![
+                Row::new(vec!["Alice", "30"]),
+                Row::new(vec!["Bob", "7"]),
+            ]);
+
+        assert_eq!(table.column_widths(80), vec![5, 3]);
+    }
+
+    #[test]
+    fn test_column_widths_auto_clamps_to_bounds() {
+        let table = Table::new()
+            .rows(vec![Row::new(vec!["a", "a very long description"])])
+            .widths([
+                Constraint::Auto {
+                    min: Some(4),
+                    max: None,
+                },
+                Constraint::Auto {
+                    min: None,
+                    max: Some(10),
+                },
+            ]);
+
+        assert_eq!(table.column_widths(80), vec![4, 10]);
+    }
+
+    #[test]
+    fn test_column_widths_fill_distributes_slack_by_weight() {
+        let table = Table::new()
+            .rows(vec![Row::new(vec!["id", "x", "y"])])
+            .widths([Constraint::auto(), Constraint::Fill(1), Constraint::Fill(2)]);
+
+        // 20 - 2 separators - 2 for the id column leaves 16 to share 1:2
+        assert_eq!(table.column_widths(20), vec![2, 6, 10]);
+    }
+
+    #[test]
+    fn test_column_widths_shrink_widest_to_fit() {
+        let table = Table::new()
+            .rows(vec![Row::new(vec!["abcdefghij", "abcdef", "ab"])])
+            .widths([
+                Constraint::Auto {
+                    min: Some(4),
+                    max: None,
+                },
+                Constraint::auto(),
+                Constraint::Length(2),
+            ]);
+
+        // 2 separators leave 14 cells: the widest column gives way first
+        assert_eq!(table.column_widths(16), vec![6, 6, 2]);
+    }
+
+    #[test]
+    fn test_render_pads_and_truncates_cells() {
+        let table = Table::new()
+            .width(12)
+            .header(Row::new(vec!["Name", "Note"]))
+            .row(Row::new(vec!["Al", "something long"]))
+            .widths([Constraint::auto(), Constraint::Fill(1)]);
+
+        let output =
+            crate::testing::TestRenderer::new(12, 2).render_to_plain(&table.into_element());
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0].trim_end(), "Name Note");
+        assert_eq!(lines[1], "Al   someth…");
+    }
+
     #[test]
     fn test_table_state() {
         let mut state = TableState::new();
@@ -392,4 +930,106 @@ mod tests {
         state.select_previous(5);
         assert_eq!(state.selected, Some(0));
     }
+
+    #[test]
+    fn test_colspan_merges_columns_and_shifts_cells() {
+        let table = Table::new()
+            .width(15)
+            .column_separator("|")
+            .header(Row::new(vec![
+                Cell::new("Name"),
+                Cell::new("Totals").colspan(2),
+            ]))
+            .row(Row::new(vec!["Al", "1", "2"]))
+            .row(Row::new(vec![
+                Cell::new("All items").colspan(2),
+                Cell::new("3"),
+            ]));
+
+        // "Totals" widens both columns it covers, then "All items" still
+        // needs one more cell than 4 + "|" + 3
+        assert_eq!(table.column_widths(15), vec![5, 3, 2]);
+
+        let output =
+            crate::testing::TestRenderer::new(15, 3).render_to_plain(&table.into_element());
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["Name |Totals", "Al   |1  |2", "All items|3"]);
+    }
+
+    #[test]
+    fn test_rowspan_covers_rows_below() {
+        let table = Table::new().width(12).column_separator("|").rows(vec![
+            Row::new(vec![
+                Cell::new("Q1").rowspan(2),
+                Cell::new("Jan"),
+                Cell::new("5"),
+            ]),
+            Row::new(vec!["Feb", "7"]),
+            Row::new(vec!["Q2", "Apr", "9"]),
+        ]);
+
+        assert_eq!(table.column_count(), 3);
+        let output =
+            crate::testing::TestRenderer::new(12, 3).render_to_plain(&table.into_element());
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["Q1|Jan|5", "  |Feb|7", "Q2|Apr|9"]);
+    }
+
+    #[test]
+    fn test_parse_csv_quoting() {
+        let records = parse_delimited("a,\"b,c\",\"say \"\"hi\"\"\"\r\n\n\"x\ny\",z", ',', true);
+        assert_eq!(
+            records,
+            vec![vec!["a", "b,c", "say \"hi\""], vec!["x\ny", "z"],]
+        );
+
+        // TSV leaves quotes alone
+        let records = parse_delimited("\"a\"\tb\n", '\t', false);
+        assert_eq!(records, vec![vec!["\"a\"", "b"]]);
+    }
+
+    #[test]
+    fn test_from_csv_keeps_multiline_fields_on_one_row() {
+        let table = Table::from_csv("name,notes\nrnk,\"line one\r\nline two\"\nink,js\n", true)
+            .column_separator("|");
+        assert_eq!(table.len(), 2);
+
+        let output =
+            crate::testing::TestRenderer::new(30, 3).render_to_plain(&table.into_element());
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(
+            lines,
+            vec!["name|notes", "rnk |line one⏎line two", "ink |js"]
+        );
+    }
+
+    #[test]
+    fn test_from_csv_pads_and_truncates_to_header() {
+        let table = Table::from_csv("name,size\nsrc,4\nREADME\nCargo.toml,2,extra\n", true)
+            .width(16)
+            .column_separator("|");
+        assert_eq!(table.len(), 3);
+        assert_eq!(table.column_count(), 2);
+
+        let header = table.header.as_ref().unwrap();
+        assert!(header.cells[0].content.spans[0].style.bold);
+
+        let output =
+            crate::testing::TestRenderer::new(16, 4).render_to_plain(&table.into_element());
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "name      |size",
+                "src       |4",
+                "README    |",
+                "Cargo.toml|2"
+            ]
+        );
+
+        let table = Table::from_tsv("a\tb\tc\nd\n", false);
+        assert_eq!(table.len(), 2);
+        assert!(table.header.is_none());
+        assert_eq!(table.column_count(), 3);
+    }
 }
@@ -1,9 +1,12 @@
 //! Tabs component for tabbed interfaces
 //!
-//! Provides a tab bar widget with selectable tabs.
+//! Provides a tab bar widget with selectable tabs. With [`TabsState`] the
+//! bar can also close tabs and offer a trailing `+` for opening new ones;
+//! route events through [`handle_tabs_input`] and [`handle_tabs_mouse`].
 
 use crate::components::{Box as RnkBox, Line, Span, Text};
 use crate::core::{Color, Element, Style};
+use crate::hooks::{Key, Mouse};
 
 /// Tab item
 #[derive(Debug, Clone)]
@@ -36,6 +39,102 @@ impl<T: Into<String>> From<T> for Tab {
     }
 }
 
+/// Something in the tab bar the user activated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TabsAction {
+    /// A tab was selected
+    Select(usize),
+    /// A tab's close button was activated
+    Close(usize),
+    /// The trailing `+` was activated; the app should open a tab
+    NewTab,
+}
+
+/// Open tabs and the active index for a closeable tab bar
+#[derive(Debug, Clone, Default)]
+pub struct TabsState {
+    tabs: Vec<Tab>,
+    active: usize,
+}
+
+impl TabsState {
+    /// Create a state with the first tab active
+    pub fn new<I, T>(tabs: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Tab>,
+    {
+        Self {
+            tabs: tabs.into_iter().map(Into::into).collect(),
+            active: 0,
+        }
+    }
+
+    /// Open tabs
+    pub fn tabs(&self) -> &[Tab] {
+        &self.tabs
+    }
+
+    /// Index of the active tab
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Get number of tabs
+    pub fn len(&self) -> usize {
+        self.tabs.len()
+    }
+
+    /// Check if empty
+    pub fn is_empty(&self) -> bool {
+        self.tabs.is_empty()
+    }
+
+    /// Activate a tab (ignored if out of range)
+    pub fn select(&mut self, index: usize) {
+        if index < self.tabs.len() {
+            self.active = index;
+        }
+    }
+
+    /// Activate the next tab, wrapping around
+    pub fn next(&mut self) {
+        if !self.tabs.is_empty() {
+            self.active = (self.active + 1) % self.tabs.len();
+        }
+    }
+
+    /// Activate the previous tab, wrapping around
+    pub fn prev(&mut self) {
+        if !self.tabs.is_empty() {
+            self.active = (self.active + self.tabs.len() - 1) % self.tabs.len();
+        }
+    }
+
+    /// Append a tab and make it active, returning its index
+    pub fn push(&mut self, tab: impl Into<Tab>) -> usize {
+        self.tabs.push(tab.into());
+        self.active = self.tabs.len() - 1;
+        self.active
+    }
+
+    /// Remove a tab
+    ///
+    /// Closing the active tab activates the tab that slides into its place,
+    /// or the new last tab when it was the last one. Tabs before the active
+    /// one shift it left so the same tab stays active.
+    pub fn close(&mut self, index: usize) -> Option<Tab> {
+        if index >= self.tabs.len() {
+            return None;
+        }
+        let tab = self.tabs.remove(index);
+        if index < self.active || self.active >= self.tabs.len() {
+            self.active = self.active.saturating_sub(1);
+        }
+        Some(tab)
+    }
+}
+
 /// Tabs component builder
 #[derive(Debug, Clone)]
 pub struct Tabs {
@@ -49,6 +148,10 @@ pub struct Tabs {
     normal_style: Style,
     /// Divider between tabs
     divider: String,
+    /// Close button shown after each title
+    close_symbol: Option<String>,
+    /// Trailing new-tab affordance
+    new_tab_symbol: Option<String>,
     /// Key for reconciliation
     key: Option<String>,
 }
@@ -65,10 +168,17 @@ impl Tabs {
             highlight_style,
             normal_style: Style::new(),
             divider: " | ".to_string(),
+            close_symbol: None,
+            new_tab_symbol: None,
             key: None,
         }
     }
 
+    /// Create tabs showing the tabs and active index of a [`TabsState`]
+    pub fn from_state(state: &TabsState) -> Self {
+        Self::from_items(state.tabs.iter().cloned()).selected(state.active)
+    }
+
     /// Create tabs from items
     pub fn from_items<I, T>(items: I) -> Self
     where
@@ -126,12 +236,74 @@ impl Tabs {
         self
     }
 
+    /// Show a `×` close button after each tab title
+    pub fn closeable(mut self, closeable: bool) -> Self {
+        self.close_symbol = closeable.then(|| "×".to_string());
+        self
+    }
+
+    /// Set the close button glyph (implies [`Tabs::closeable`])
+    pub fn close_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.close_symbol = Some(symbol.into());
+        self
+    }
+
+    /// Show a trailing `+` that opens a new tab
+    pub fn new_tab_button(mut self, show: bool) -> Self {
+        self.new_tab_symbol = show.then(|| "+".to_string());
+        self
+    }
+
     /// Set key
     pub fn key(mut self, key: impl Into<String>) -> Self {
         self.key = Some(key.into());
         self
     }
 
+    /// What a click at `column` (relative to the start of the bar) activates
+    pub fn action_at(&self, column: u16) -> Option<TabsAction> {
+        let mut x = 0usize;
+        for (text, _, action) in self.segments() {
+            let width = unicode_width::UnicodeWidthStr::width(text.as_str());
+            if (column as usize) < x + width {
+                return action;
+            }
+            x += width;
+        }
+        None
+    }
+
+    /// Text pieces of the bar with their style and click action
+    fn segments(&self) -> Vec<(String, Segment, Option<TabsAction>)> {
+        let mut segments = Vec::new();
+        for (i, tab) in self.tabs.iter().enumerate() {
+            if i > 0 {
+                segments.push((self.divider.clone(), Segment::Divider, None));
+            }
+            let selected = i == self.selected;
+            segments.push((
+                tab.title.clone(),
+                Segment::Title(selected),
+                Some(TabsAction::Select(i)),
+            ));
+            if let Some(close) = &self.close_symbol {
+                segments.push((
+                    " ".to_string(),
+                    Segment::Title(selected),
+                    Some(TabsAction::Select(i)),
+                ));
+                segments.push((close.clone(), Segment::Control, Some(TabsAction::Close(i))));
+            }
+        }
+        if let Some(plus) = &self.new_tab_symbol {
+            if !self.tabs.is_empty() {
+                segments.push((self.divider.clone(), Segment::Divider, None));
+            }
+            segments.push((plus.clone(), Segment::Control, Some(TabsAction::NewTab)));
+        }
+        segments
+    }
+
     /// Get number of tabs
     pub fn len(&self) -> usize {
         self.tabs.len()
@@ -146,15 +318,15 @@ impl Tabs {
     pub fn into_element(self) -> Element {
         let mut spans = Vec::new();
 
-        for (i, tab) in self.tabs.iter().enumerate() {
-            // Add divider between tabs (not before first)
-            if i > 0 {
-                spans.push(Span::new(&self.divider).dim());
-            }
-
-            // Create tab span
-            let is_selected = i == self.selected;
-            let mut span = Span::new(&tab.title);
+        for (text, segment, _) in self.segments() {
+            let is_selected = match segment {
+                Segment::Divider | Segment::Control => {
+                    spans.push(Span::new(text).dim());
+                    continue;
+                }
+                Segment::Title(selected) => selected,
+            };
+            let mut span = Span::new(text);
 
             if is_selected {
                 if let Some(color) = self.highlight_style.color {
@@ -198,6 +370,60 @@ impl Default for Tabs {
     }
 }
 
+/// How a piece of the tab bar is styled
+#[derive(Debug, Clone, Copy)]
+enum Segment {
+    Divider,
+    /// Part of a tab; `true` when it is the selected tab
+    Title(bool),
+    /// Close button or new-tab button
+    Control,
+}
+
+/// Handle tab bar shortcuts: `Ctrl+W` closes the active tab and `Ctrl+T`
+/// asks for a new one
+///
+/// Closing is applied to `state`; opening is left to the caller, who knows
+/// what the new tab holds.
+pub fn handle_tabs_input(state: &mut TabsState, input: &str, key: &Key) -> Option<TabsAction> {
+    if !key.ctrl {
+        return None;
+    }
+    match input {
+        "w" | "W" if !state.is_empty() => {
+            let index = state.active;
+            state.close(index);
+            Some(TabsAction::Close(index))
+        }
+        "t" | "T" => Some(TabsAction::NewTab),
+        _ => None,
+    }
+}
+
+/// Map a click on the tab bar to a [`TabsAction`]
+///
+/// `tabs` is the bar as rendered and `origin` its top-left cell on screen.
+/// Selecting and closing are applied to `state`.
+pub fn handle_tabs_mouse(
+    state: &mut TabsState,
+    tabs: &Tabs,
+    mouse: &Mouse,
+    origin: (u16, u16),
+) -> Option<TabsAction> {
+    if !mouse.is_left_click() || mouse.y != origin.1 || mouse.x < origin.0 {
+        return None;
+    }
+    let action = tabs.action_at(mouse.x - origin.0)?;
+    match action {
+        TabsAction::Select(index) => state.select(index),
+        TabsAction::Close(index) => {
+            state.close(index);
+        }
+        TabsAction::NewTab => {}
+    }
+    Some(action)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,4 +451,95 @@ mod tests {
         let tabs = Tabs::new().tab("Home").tab("Settings").divider(" │ ");
         assert_eq!(tabs.divider, " │ ");
     }
+
+    fn bar_text(tabs: Tabs) -> String {
+        let element = tabs.into_element();
+        let text = element.children.get(0).unwrap();
+        text.spans.as_ref().unwrap()[0]
+            .spans
+            .iter()
+            .map(|span| span.content.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_closing_tabs_activates_neighbor() {
+        let mut state = TabsState::new(["a", "b", "c", "d"]);
+        state.select(1);
+
+        // Closing the active tab activates the one that slides into place
+        assert_eq!(state.close(1).unwrap().title, "b");
+        assert_eq!(state.tabs()[state.active()].title, "c");
+
+        // Closing a tab before the active one keeps the same tab active
+        state.close(0);
+        assert_eq!(state.tabs()[state.active()].title, "c");
+
+        // Closing the last tab while active falls back to the left
+        state.select(1);
+        state.close(1);
+        assert_eq!(state.tabs()[state.active()].title, "c");
+
+        state.close(0);
+        assert!(state.is_empty());
+        assert_eq!(state.active(), 0);
+        assert!(state.close(0).is_none());
+    }
+
+    #[test]
+    fn test_closeable_bar_layout_and_hit_testing() {
+        let state = TabsState::new(["one", "two"]);
+        let tabs = Tabs::from_state(&state)
+            .closeable(true)
+            .new_tab_button(true);
+
+        assert_eq!(bar_text(tabs.clone()), "one × | two × | +");
+        assert_eq!(tabs.action_at(0), Some(TabsAction::Select(0)));
+        assert_eq!(tabs.action_at(4), Some(TabsAction::Close(0)));
+        assert_eq!(tabs.action_at(6), None);
+        assert_eq!(tabs.action_at(12), Some(TabsAction::Close(1)));
+        assert_eq!(tabs.action_at(16), Some(TabsAction::NewTab));
+        assert_eq!(tabs.action_at(17), None);
+    }
+
+    #[test]
+    fn test_tabs_shortcuts_and_clicks() {
+        let mut state = TabsState::new(["one", "two", "three"]);
+        let ctrl = Key {
+            ctrl: true,
+            ..Key::default()
+        };
+
+        assert_eq!(
+            handle_tabs_input(&mut state, "t", &ctrl),
+            Some(TabsAction::NewTab)
+        );
+        assert_eq!(state.push("four"), 3);
+        assert_eq!(
+            handle_tabs_input(&mut state, "w", &ctrl),
+            Some(TabsAction::Close(3))
+        );
+        assert_eq!(state.len(), 3);
+        assert_eq!(handle_tabs_input(&mut state, "w", &Key::default()), None);
+
+        let tabs = Tabs::from_state(&state).closeable(true);
+        let click = |x| Mouse {
+            x,
+            y: 2,
+            action: crate::hooks::MouseAction::Press(crate::hooks::MouseButton::Left),
+            ctrl: false,
+            shift: false,
+            alt: false,
+        };
+        assert_eq!(
+            handle_tabs_mouse(&mut state, &tabs, &click(13), (5, 2)),
+            Some(TabsAction::Select(1))
+        );
+        assert_eq!(state.active(), 1);
+        assert_eq!(
+            handle_tabs_mouse(&mut state, &tabs, &click(9), (5, 2)),
+            Some(TabsAction::Close(0))
+        );
+        assert_eq!(state.tabs()[state.active()].title, "two");
+    }
 }
@@ -1,6 +1,7 @@
-//! Transform component - Apply text transformations
+//! Transform component - Apply text transformations, color filters and
+//! mirroring
 
-use crate::core::{Element, ElementType, Style};
+use crate::core::{ColorFilter, Element, ElementType, Style};
 
 /// Text transformation function type
 pub type TransformFn = Box<dyn Fn(&str) -> String + Send + Sync>;
@@ -15,6 +16,9 @@ pub type TransformFn = Box<dyn Fn(&str) -> String + Send + Sync>;
 /// ```
 pub struct Transform {
     transform: Option<TransformFn>,
+    color_filter: Option<ColorFilter>,
+    mirror_horizontal: bool,
+    flip_vertical: bool,
     children: Vec<Element>,
 }
 
@@ -26,6 +30,68 @@ impl Transform {
     {
         Self {
             transform: Some(Box::new(transform)),
+            color_filter: None,
+            mirror_horizontal: false,
+            flip_vertical: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// Create a transform that runs its children's rendered colors through
+    /// a filter
+    ///
+    /// The filter works on the rendered cells, so nested styles, borders and
+    /// backgrounds are all covered.
+    ///
+    /// ```ignore
+    /// Transform::color_filter(ColorFilter::Grayscale)
+    ///     .child(settings_panel())
+    /// ```
+    pub fn color_filter(filter: ColorFilter) -> Self {
+        Self {
+            transform: None,
+            color_filter: Some(filter),
+            mirror_horizontal: false,
+            flip_vertical: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// Create a transform that mirrors its children's rendered output left
+    /// to right
+    ///
+    /// Works on the rendered cells: wide characters stay intact and
+    /// box-drawing corners, brackets and arrows are swapped for their
+    /// mirror image, so a panel's border still closes. Text reads
+    /// backwards, which suits decorative art more than prose.
+    ///
+    /// ```ignore
+    /// Box::new()
+    ///     .flex_direction(FlexDirection::Row)
+    ///     .child(wing())
+    ///     .child(Transform::mirror_horizontal().child(wing()).into_element())
+    /// ```
+    pub fn mirror_horizontal() -> Self {
+        Self {
+            transform: None,
+            color_filter: None,
+            mirror_horizontal: true,
+            flip_vertical: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// Create a transform that flips its children's rendered output upside
+    /// down
+    ///
+    /// Rows are drawn in reverse order, with corners and half blocks
+    /// swapped for their upside-down counterparts.
+    pub fn flip_vertical() -> Self {
+        Self {
+            transform: None,
+            color_filter: None,
+            mirror_horizontal: false,
+            flip_vertical: true,
             children: Vec::new(),
         }
     }
@@ -46,6 +112,9 @@ impl Transform {
     pub fn into_element(self) -> Element {
         let mut element = Element::new(ElementType::Box);
         element.style = Style::new();
+        element.style.color_filter = self.color_filter;
+        element.style.mirror_horizontal = self.mirror_horizontal;
+        element.style.flip_vertical = self.flip_vertical;
 
         // Apply transform to all text children
         for mut child in self.children {
@@ -119,4 +188,81 @@ mod tests {
         let child = element.children.iter().next().expect("should have child");
         assert_eq!(child.text_content, Some(">>> test <<<".to_string()));
     }
+
+    #[test]
+    fn test_transform_color_filter_grays_rendered_cells() {
+        use crate::components::Box as RnkBox;
+        use crate::core::Color;
+        use crate::layout::LayoutEngine;
+        use crate::renderer::Output;
+        use crate::renderer::tree_renderer::render_element_tree;
+
+        let element = Transform::color_filter(ColorFilter::Grayscale)
+            .child(
+                RnkBox::new()
+                    .background(Color::Rgb(0, 0, 255))
+                    .child(Text::new("hi").color(Color::Rgb(255, 0, 0)).into_element())
+                    .into_element(),
+            )
+            .into_element();
+
+        let mut engine = LayoutEngine::new();
+        engine.compute(&element, 4, 1);
+        let mut output = Output::new(4, 1);
+        render_element_tree(&element, &engine, &mut output, 0.0, 0.0);
+
+        let cell = output.cell_at(0, 0).unwrap();
+        assert_eq!(cell.fg, Some(Color::Rgb(127, 127, 127)));
+        assert_eq!(cell.bg, Some(Color::Rgb(76, 76, 76)));
+    }
+
+    fn render_plain(element: &Element, width: u16, height: u16) -> Vec<String> {
+        crate::testing::TestRenderer::new(width, height)
+            .render_to_plain(element)
+            .lines()
+            .map(|line| line.trim_end().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_transform_mirror_horizontal() {
+        use crate::components::Box as RnkBox;
+        use crate::core::BorderStyle;
+
+        let panel = || {
+            RnkBox::new()
+                .border_style(BorderStyle::Round)
+                .width(8)
+                .child(Text::new("a中(b").into_element())
+                .into_element()
+        };
+        assert_eq!(render_plain(&panel(), 8, 3)[1], "│a中(b │");
+
+        let mirrored = Transform::mirror_horizontal().child(panel()).into_element();
+        assert_eq!(
+            render_plain(&mirrored, 8, 3),
+            vec!["╭──────╮", "│ b)中a│", "╰──────╯"]
+        );
+    }
+
+    #[test]
+    fn test_transform_flip_vertical() {
+        use crate::components::Box as RnkBox;
+        use crate::core::{BorderStyle, FlexDirection};
+
+        let element = Transform::flip_vertical()
+            .child(
+                RnkBox::new()
+                    .border_style(BorderStyle::Single)
+                    .flex_direction(FlexDirection::Column)
+                    .child(Text::new("top▀").into_element())
+                    .child(Text::new("low").into_element())
+                    .into_element(),
+            )
+            .into_element();
+        assert_eq!(
+            render_plain(&element, 6, 4),
+            vec!["┌────┐", "│low │", "│top▄│", "└────┘"]
+        );
+    }
 }
@@ -10,6 +10,7 @@
 //! - Keyboard navigation
 //! - Customizable icons and indentation
 //! - Selection support
+//! - Lazily loaded children
 //!
 //! # Example
 //!
@@ -32,10 +33,11 @@
 
 use crate::components::{Box as RnkBox, Text};
 use crate::core::{Color, Element, FlexDirection};
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
 
 /// A node in the tree
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct TreeNode<T: Clone> {
     /// Unique identifier for this node
     pub id: String,
@@ -45,6 +47,8 @@ pub struct TreeNode<T: Clone> {
     pub data: Option<T>,
     /// Child nodes
     pub children: Vec<TreeNode<T>>,
+    /// Children exist but have not been loaded yet
+    pub lazy: bool,
 }
 
 impl<T: Clone> TreeNode<T> {
@@ -55,6 +59,19 @@ impl<T: Clone> TreeNode<T> {
             label: label.into(),
             data: None,
             children: Vec::new(),
+            lazy: false,
+        }
+    }
+
+    /// Create a node whose children are loaded on first expand
+    ///
+    /// Expanding it queues its id on [`TreeState::take_pending_expand`] and
+    /// shows a loading placeholder until [`TreeNode::load_children`] splices
+    /// the children in.
+    pub fn lazy(id: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            lazy: true,
+            ..Self::new(id, label)
         }
     }
 
@@ -70,6 +87,7 @@ impl<T: Clone> TreeNode<T> {
             label: label.into(),
             data: Some(data),
             children: Vec::new(),
+            lazy: false,
         }
     }
 
@@ -87,12 +105,12 @@ impl<T: Clone> TreeNode<T> {
 
     /// Check if this is a leaf node
     pub fn is_leaf(&self) -> bool {
-        self.children.is_empty()
+        self.children.is_empty() && !self.lazy
     }
 
-    /// Check if this node has children
+    /// Check if this node has children (loaded or not)
     pub fn has_children(&self) -> bool {
-        !self.children.is_empty()
+        !self.children.is_empty() || self.lazy
     }
 
     /// Get total node count (including self and all descendants)
@@ -113,6 +131,35 @@ impl<T: Clone> TreeNode<T> {
         None
     }
 
+    /// Find a node by ID for modification
+    pub fn find_mut(&mut self, id: &str) -> Option<&mut TreeNode<T>> {
+        if self.id == id {
+            return Some(self);
+        }
+        self.children
+            .iter_mut()
+            .find_map(|child| child.find_mut(id))
+    }
+
+    /// Splice loaded children into the lazy node `id`
+    ///
+    /// Returns `false` if no node has that id. Call
+    /// [`TreeState::rebuild_visible`] afterwards to show the new children.
+    pub fn load_children(
+        &mut self,
+        id: &str,
+        children: impl IntoIterator<Item = TreeNode<T>>,
+    ) -> bool {
+        match self.find_mut(id) {
+            Some(node) => {
+                node.children = children.into_iter().collect();
+                node.lazy = false;
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Get all node IDs in depth-first order
     pub fn all_ids(&self) -> Vec<String> {
         let mut ids = vec![self.id.clone()];
@@ -134,6 +181,10 @@ pub struct TreeState {
     visible_nodes: Vec<String>,
     /// Current cursor position in visible nodes
     cursor: usize,
+    /// Lazy nodes that were expanded and still need their children
+    loading: HashSet<String>,
+    /// Lazy nodes waiting for the app to start loading them
+    pending_expand: VecDeque<String>,
 }
 
 impl TreeState {
@@ -144,6 +195,8 @@ impl TreeState {
             selected: None,
             visible_nodes: Vec::new(),
             cursor: 0,
+            loading: HashSet::new(),
+            pending_expand: VecDeque::new(),
         };
         state.rebuild_visible(root);
         state
@@ -257,6 +310,19 @@ impl TreeState {
         self.selected = self.focused().map(|s| s.to_string());
     }
 
+    /// Take the next lazy node that was expanded and needs its children
+    ///
+    /// Each expanded lazy node is reported once; load its children and pass
+    /// them to [`TreeNode::load_children`].
+    pub fn take_pending_expand(&mut self) -> Option<String> {
+        self.pending_expand.pop_front()
+    }
+
+    /// Check if a lazy node is expanded and waiting for its children
+    pub fn is_loading(&self, id: &str) -> bool {
+        self.loading.contains(id)
+    }
+
     /// Rebuild visible nodes list based on expanded state
     pub fn rebuild_visible<T: Clone>(&mut self, root: &TreeNode<T>) {
         self.visible_nodes.clear();
@@ -269,6 +335,11 @@ impl TreeState {
 
     fn collect_visible<T: Clone>(&mut self, node: &TreeNode<T>) {
         self.visible_nodes.push(node.id.clone());
+        if !node.lazy {
+            self.loading.remove(&node.id);
+        } else if self.is_expanded(&node.id) && self.loading.insert(node.id.clone()) {
+            self.pending_expand.push_back(node.id.clone());
+        }
         if self.is_expanded(&node.id) {
             for child in &node.children {
                 self.collect_visible(child);
@@ -284,6 +355,7 @@ impl TreeState {
 
 /// Style configuration for the tree
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct TreeStyle {
     /// Indentation per level (in spaces)
     pub indent: usize,
@@ -309,6 +381,8 @@ pub struct TreeStyle {
     pub focused_bg: Option<Color>,
     /// Show tree lines
     pub show_lines: bool,
+    /// Placeholder shown under an expanded node whose children are loading
+    pub loading_label: String,
 }
 
 impl Default for TreeStyle {
@@ -326,6 +400,7 @@ impl Default for TreeStyle {
             focused_color: Some(Color::Cyan),
             focused_bg: None,
             show_lines: true,
+            loading_label: "Loading…".to_string(),
         }
     }
 }
@@ -378,6 +453,12 @@ impl TreeStyle {
         self
     }
 
+    /// Set the placeholder shown while lazy children load
+    pub fn loading_label(mut self, label: impl Into<String>) -> Self {
+        self.loading_label = label.into();
+        self
+    }
+
     /// Use folder-style icons
     pub fn folder_icons() -> Self {
         Self {
@@ -482,26 +563,7 @@ impl<'a, T: Clone> Tree<'a, T> {
         let is_selected = self.state.selected() == Some(&node.id);
         let is_expanded = self.state.is_expanded(&node.id);
 
-        // Build prefix (tree lines)
-        let mut prefix = String::new();
-        if self.style.show_lines && depth > 0 {
-            for &is_last in &parent_is_last[..parent_is_last.len().saturating_sub(1)] {
-                if is_last {
-                    prefix.push_str("  ");
-                } else {
-                    prefix.push_str(&self.style.vertical_line);
-                }
-            }
-            if let Some(&is_last) = parent_is_last.last() {
-                if is_last {
-                    prefix.push_str(&self.style.last_connector);
-                } else {
-                    prefix.push_str(&self.style.connector);
-                }
-            }
-        } else {
-            prefix = " ".repeat(depth * self.style.indent);
-        }
+        let prefix = self.prefix(depth, &parent_is_last);
 
         // Build icon
         let icon = if node.is_leaf() {
@@ -534,6 +596,15 @@ impl<'a, T: Clone> Tree<'a, T> {
 
         elements.push(text.into_element());
 
+        // Placeholder while lazy children load
+        if is_expanded && node.lazy {
+            let mut placeholder_is_last = parent_is_last.clone();
+            placeholder_is_last.push(true);
+            let prefix = self.prefix(depth + 1, &placeholder_is_last);
+            let line = format!("{}{}", prefix, self.style.loading_label);
+            elements.push(Text::new(line).dim().into_element());
+        }
+
         // Render children if expanded
         if is_expanded {
             let child_count = node.children.len();
@@ -547,6 +618,30 @@ impl<'a, T: Clone> Tree<'a, T> {
 
         elements
     }
+
+    /// Build the indentation or tree-line prefix for a row
+    fn prefix(&self, depth: usize, parent_is_last: &[bool]) -> String {
+        let mut prefix = String::new();
+        if self.style.show_lines && depth > 0 {
+            for &is_last in &parent_is_last[..parent_is_last.len().saturating_sub(1)] {
+                if is_last {
+                    prefix.push_str("  ");
+                } else {
+                    prefix.push_str(&self.style.vertical_line);
+                }
+            }
+            if let Some(&is_last) = parent_is_last.last() {
+                if is_last {
+                    prefix.push_str(&self.style.last_connector);
+                } else {
+                    prefix.push_str(&self.style.connector);
+                }
+            }
+        } else {
+            prefix = " ".repeat(depth * self.style.indent);
+        }
+        prefix
+    }
 }
 
 /// Handle tree input
@@ -726,4 +821,59 @@ mod tests {
         let _plus_minus = TreeStyle::plus_minus_icons();
         let _minimal = TreeStyle::minimal();
     }
+
+    #[test]
+    fn test_lazy_node_requests_children_once() {
+        let mut tree = TreeNode::<()>::new("root", "Root").child(TreeNode::lazy("src", "src"));
+        let mut state = TreeState::with_root_expanded(&tree);
+        assert!(!tree.find("src").unwrap().is_leaf());
+        assert_eq!(state.take_pending_expand(), None);
+
+        state.expand("src");
+        state.rebuild_visible(&tree);
+        state.rebuild_visible(&tree);
+        assert!(state.is_loading("src"));
+        assert_eq!(state.take_pending_expand().as_deref(), Some("src"));
+        assert_eq!(state.take_pending_expand(), None);
+        // The placeholder is not a navigable row
+        assert_eq!(state.visible_count(), 2);
+
+        assert!(tree.load_children(
+            "src",
+            [
+                TreeNode::leaf("main", "main.rs"),
+                TreeNode::leaf("lib", "lib.rs")
+            ]
+        ));
+        assert!(!tree.load_children("missing", []));
+        state.rebuild_visible(&tree);
+        assert!(!state.is_loading("src"));
+        assert_eq!(state.visible_count(), 4);
+    }
+
+    #[test]
+    fn test_lazy_node_renders_loading_placeholder() {
+        let mut tree = TreeNode::<()>::new("root", "Root").child(TreeNode::lazy("src", "src"));
+        let mut state = TreeState::with_root_expanded(&tree);
+        state.expand("src");
+        state.rebuild_visible(&tree);
+
+        let style = TreeStyle::minimal().loading_label("...");
+        let render = |tree: &TreeNode<()>, state: &TreeState| {
+            let element = Tree::new(tree, state).style(style.clone()).into_element();
+            crate::testing::TestRenderer::new(20, 4)
+                .render_to_plain(&element)
+                .lines()
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(render(&tree, &state), ["▾ Root", "  ▾ src", "    ..."]);
+
+        tree.load_children("src", [TreeNode::leaf("main", "main.rs")]);
+        state.rebuild_visible(&tree);
+        assert_eq!(
+            render(&tree, &state),
+            ["▾ Root", "  ▾ src", "      main.rs"]
+        );
+    }
 }
@@ -16,67 +16,81 @@ pub mod viewport;
 pub(crate) use display::{capsule_variant, status};
 pub(crate) use input::selection_list;
 pub(crate) use layout::capsule;
+pub(crate) use theme::ThemeStack;
 
 // display
-pub use action::{ActionButton, ActionRole, ActionShape, ActionState, ActionStyle};
+pub use action::{ActionButton, ActionRole, ActionShape, ActionState, ActionStyle, Mnemonic};
 pub use display::text;
 pub use display::{
-    Accordion, AccordionItem, Avatar, AvatarSize, Badge, BadgeVariant, Bar, BarChart,
-    BarChartOrientation, Breadcrumb, Calendar, CapsuleVariant, Card, Chip, Divider,
-    DividerOrientation, DividerStyle, EmptyState, Gauge, Gradient, Highlight, HighlightVariant,
-    Hyperlink, HyperlinkBuilder, KeyHint, Line, LineChart, Link, List, ListItem, ListState,
-    Markdown, Message, MessageRole, Newline, Progress, ProgressSymbols, Quote, QuoteStyle, Rating,
-    RatingStyle, RatingSymbols, Series, Skeleton, SkeletonVariant, Span, Sparkline, Stat, Static,
-    StopwatchState, Tag, Text, ThinkingBlock, TimerState, ToolCall, Trend, breadcrumb_from_path,
-    format_duration_hhmmss, format_duration_mmss, format_duration_precise,
-    set_hyperlinks_supported, supports_hyperlinks,
+    AbbreviateMode, Accordion, AccordionItem, Avatar, AvatarSize, Badge, BadgeVariant, Bar,
+    BarChart, BarChartOrientation, BigFont, BigText, Breadcrumb, Calendar, CapsuleVariant, Card,
+    Chip, Divider, DividerOrientation, DividerStyle, EmptyState, Gauge, Gradient, Highlight,
+    HighlightVariant, Hyperlink, HyperlinkBuilder, KeyHint, KeyHints, Line, LineChart, Link, List,
+    ListItem, ListState, Markdown, Message, MessageRole, MessageState, Newline, Progress,
+    ProgressSymbols, Quote, QuoteStyle, Rating, RatingStyle, RatingSymbols, Series, Skeleton,
+    SkeletonVariant, Span, Sparkline, Stat, StatFormat, Static, StaticState, StopwatchState, Tag,
+    Text, ThinkingBlock, TimerState, ToolCall, ToolCallStatus, Trend, breadcrumb_from_path,
+    format_duration_hhmmss, format_duration_mmss, format_duration_precise, handle_link_input,
+    handle_tool_call_input, open_url, set_hyperlinks_supported, supports_hyperlinks,
+    use_static_state,
 };
 // feedback
 pub use feedback::{
     Alert, AlertLevel, Cursor, CursorShape, CursorState, CursorStyle, DevTools, DevToolsTab,
-    Dialog, DialogState, Help, HelpMode, HelpStyle, KeyBinding, Modal, ModalAlign, Notification,
-    NotificationBorder, NotificationItem, NotificationLevel, NotificationPosition,
-    NotificationState, NotificationStyle, Popover, PopoverArrow, PopoverBorder, PopoverPosition,
-    PopoverStyle, Spinner, SpinnerBuilder, StatusBar, Step, StepStatus, Stepper,
-    StepperOrientation, StepperStyle, Toast, Tooltip, TooltipPosition, editor_help,
-    navigation_help, vim_navigation_help,
+    Dialog, DialogState, Help, HelpMode, HelpStyle, KeyBinding, Modal, ModalAlign, ModalStack,
+    ModalStackState, Notification, NotificationBorder, NotificationItem, NotificationLevel,
+    NotificationPosition, NotificationState, NotificationStyle, Popover, PopoverArrow,
+    PopoverBorder, PopoverPosition, PopoverStyle, Spinner, SpinnerBuilder, StatusBar, Step,
+    StepStatus, Stepper, StepperOrientation, StepperStyle, Toast, Tooltip, TooltipPosition,
+    editor_help, handle_alert_input, handle_help_input, handle_modal_stack_input,
+    handle_modal_stack_input_with_mode, navigation_help, vim_navigation_help,
 };
 // input
 pub use input::{
-    ButtonStyle, CodeEditor, ColorPalette, ColorPicker, ColorPickerState, ColorPickerStyle,
-    Command, CommandPalette, CommandPaletteState, CommandPaletteStyle, Confirm, ConfirmState,
-    ConfirmStyle, ContextMenu, ContextMenuState, ContextMenuStyle, FileEntry, FileFilter,
+    Autocomplete, AutocompleteState, AutocompleteStyle, ButtonStyle, CalendarDate, CodeEditor,
+    ColorPalette, ColorPicker, ColorPickerState, ColorPickerStyle, Command, CommandPalette,
+    CommandPaletteState, CommandPaletteStyle, Confirm, ConfirmState, ConfirmStyle, ContextMenu,
+    ContextMenuState, ContextMenuStyle, DatePicker, DatePickerState, FileEntry, FileFilter,
     FilePicker, FilePickerState, FilePickerStyle, FileType, Language, MenuItem, MultiSelect,
-    MultiSelectItem, MultiSelectState, MultiSelectStyle, Paginator, PaginatorState, PaginatorStyle,
-    PaginatorType, SelectInput, SelectInputState, SelectInputStyle, SelectItem, TextInputHandle,
-    TextInputOptions, TextInputState, handle_color_picker_input, handle_command_palette_input,
-    handle_confirm_input, handle_confirm_input_with_mode, handle_file_picker_input,
-    handle_multi_select_input, handle_paginator_input, handle_select_input, handle_text_input,
-    use_text_input,
+    MultiSelectItem, MultiSelectState, MultiSelectStyle, NumberInput, NumberInputState, NumberMode,
+    Paginator, PaginatorState, PaginatorStyle, PaginatorType, SelectInput, SelectInputState,
+    SelectInputStyle, SelectItem, TextInputHandle, TextInputOptions, TextInputState,
+    filter_suggestions, handle_autocomplete_input, handle_color_picker_input,
+    handle_command_palette_input, handle_confirm_input, handle_confirm_input_with_mode,
+    handle_confirm_input_with_style, handle_context_menu_input, handle_datepicker_input,
+    handle_datepicker_input_with_mode, handle_file_picker_input, handle_multi_select_input,
+    handle_multi_select_input_with, handle_number_input, handle_number_input_mouse,
+    handle_number_input_with_mode, handle_paginator_input, handle_select_input,
+    handle_select_input_with, handle_text_input, use_text_input,
 };
 pub use interaction::{InteractionMode, InteractionOutcome};
 pub use textarea::{
     Position as TextAreaPosition, Selection as TextAreaSelection, TextArea, TextAreaAction,
     TextAreaKeyMap, TextAreaState, TextAreaStyle, apply_textarea_action, handle_textarea_input,
-    handle_textarea_input_with_mode,
+    handle_textarea_input_with_mode, handle_textarea_mouse,
 };
 pub use viewport::{
-    Viewport, ViewportAction, ViewportKeyMap, ViewportState, ViewportStyle, apply_viewport_action,
+    SearchMatch as ViewportSearchMatch, SearchOptions as ViewportSearchOptions, Viewport,
+    ViewportAction, ViewportKeyMap, ViewportState, ViewportStyle, apply_viewport_action,
     handle_viewport_input, handle_viewport_input_with_mode,
 };
 // layout
 pub use layout::navigation;
 pub use layout::{
-    Box, Cell, Constraint, NavigationConfig, NavigationResult, Row, ScrollableBox, Scrollbar,
-    ScrollbarOrientation, ScrollbarSymbols, SelectionState, Spacer, Tab, Table, TableState, Tabs,
-    Transform, Tree, TreeNode, TreeState, TreeStyle, calculate_visible_range, fixed_bottom_layout,
-    handle_list_navigation, handle_tree_input, virtual_scroll_view,
+    Box, Capsule, Cell, Constraint, Grid, GridCell, HSpace, NavigationConfig, NavigationResult,
+    Responsive, Router, Row, ScrollableBox, Scrollbar, ScrollbarOrientation, ScrollbarSymbols,
+    SelectionState, Spacer, SplitDirection, SplitPane, SplitPaneState, Tab, Table, TableState,
+    Tabs, TabsAction, TabsState, Transform, Tree, TreeNode, TreeState, TreeStyle, VSpace,
+    calculate_visible_range, fixed_bottom_layout, handle_grid_navigation, handle_list_navigation,
+    handle_list_navigation_with, handle_split_pane_input, handle_split_pane_mouse,
+    handle_tabs_input, handle_tabs_mouse, handle_tree_input, virtual_scroll_view,
 };
 pub use theme::{
     BackgroundColors, BorderColors, BorderTokens, ButtonColors, ComponentColors, ComponentState,
-    ComponentVariant, Density, DensityTokens, DesignTokens, FocusTokens, InputColors, ListColors,
-    ProgressColors, SemanticColor, SpacingTokens, StateTokens, SymbolTokens, TextColors, Theme,
-    ThemeBuilder, VariantStyle, get_theme, set_theme, with_theme,
+    ComponentVariant, Density, DensityTokens, DesignTokens, FocusStyle, FocusTokens, InputColors,
+    ListColors, ProgressColors, SemanticColor, SpacingTokens, StateTokens, SymbolTokens,
+    TextColors, Theme, ThemeBuilder, ThemeOverrideGuard, VariantStyle, focus_border_style,
+    get_theme, override_theme, set_theme, with_theme,
 };
 
 // Implement From<T> for Element for all components with into_element()
@@ -89,6 +103,7 @@ crate::impl_into_element!(
     Avatar,
     Badge,
     BarChart,
+    BigText,
     Breadcrumb,
     Calendar,
     Card,
@@ -98,6 +113,7 @@ crate::impl_into_element!(
     Gauge,
     Highlight,
     KeyHint,
+    KeyHints,
     LineChart,
     Link,
     List,
@@ -125,6 +141,7 @@ crate::impl_into_element!(
     Stepper,
     Tooltip,
     // Input
+    Autocomplete,
     CodeEditor,
     ColorPicker,
     CommandPalette,
@@ -132,9 +149,13 @@ crate::impl_into_element!(
     Paginator,
     // Layout
     Box,
+    Capsule,
     ScrollableBox,
     Scrollbar,
     Spacer,
+    HSpace,
+    VSpace,
+    SplitPane,
     Table,
     Tabs,
     Transform,
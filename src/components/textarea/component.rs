@@ -8,7 +8,7 @@ use crate::core::{
 };
 
 use super::keymap::{TextAreaAction, TextAreaKeyMap};
-use super::state::TextAreaState;
+use super::state::{Position, TextAreaState};
 
 /// Style configuration for the textarea
 #[derive(Debug, Clone)]
@@ -289,13 +289,17 @@ impl<'a> TextArea<'a> {
         }
 
         // Render visible lines
-        let cursor = self.state.cursor();
+        let cursors = self.state.cursors();
         let scroll_offset = self.state.scroll_offset();
 
         for (row, line) in self.state.visible_lines() {
-            let is_cursor_line = row == cursor.row;
+            let cursor_cols: Vec<usize> = cursors
+                .iter()
+                .filter(|pos| pos.row == row)
+                .map(|pos| pos.col)
+                .collect();
 
-            let line_element = self.render_line(row, line, is_cursor_line, line_num_width);
+            let line_element = self.render_line(row, line, &cursor_cols, line_num_width);
 
             container = container.child(line_element);
         }
@@ -329,10 +333,9 @@ impl<'a> TextArea<'a> {
         &self,
         row: usize,
         line: &str,
-        is_cursor_line: bool,
+        cursor_cols: &[usize],
         line_num_width: usize,
     ) -> Element {
-        let cursor = self.state.cursor();
         let mut parts = Vec::new();
 
         // Line number
@@ -351,9 +354,9 @@ impl<'a> TextArea<'a> {
             parts.push(Text::new(&self.style.prompt).into_element());
         }
 
-        // Line content with cursor
-        if is_cursor_line && self.focused {
-            let content = self.render_line_with_cursor(line, cursor.col);
+        // Line content with cursors
+        if !cursor_cols.is_empty() && self.focused {
+            let content = self.render_line_with_cursors(line, cursor_cols);
             parts.push(content);
         } else {
             let mut text = Text::new(line);
@@ -373,50 +376,57 @@ impl<'a> TextArea<'a> {
         }
     }
 
-    /// Render a line with cursor
-    fn render_line_with_cursor(&self, line: &str, cursor_col: usize) -> Element {
+    /// Render a line with one or more cursors (columns in ascending order)
+    fn render_line_with_cursors(&self, line: &str, cursor_cols: &[usize]) -> Element {
         let chars: Vec<char> = line.chars().collect();
+        let mut container = RnkBox::new().flex_direction(FlexDirection::Row);
+        let mut start = 0;
 
-        // Split line into before cursor, cursor char, and after cursor
-        let before: String = chars.iter().take(cursor_col).collect();
-        let cursor_char = chars.get(cursor_col).copied().unwrap_or(' ');
-        let after: String = chars.iter().skip(cursor_col + 1).collect();
+        for &cursor_col in cursor_cols {
+            if cursor_col < start {
+                continue;
+            }
 
-        let mut container = RnkBox::new().flex_direction(FlexDirection::Row);
+            // Text between the previous cursor and this one
+            let before: String = chars.iter().take(cursor_col).skip(start).collect();
+            if !before.is_empty() {
+                container = container.child(self.render_plain(&before));
+            }
 
-        // Before cursor
-        if !before.is_empty() {
-            let mut text = Text::new(&before);
-            if let Some(color) = self.style.text_color {
-                text = text.color(color);
+            // Cursor
+            let cursor_char = chars.get(cursor_col).copied().unwrap_or(' ');
+            let cursor_str = if cursor_char == ' ' {
+                self.style.cursor_char.to_string()
+            } else {
+                cursor_char.to_string()
+            };
+            let mut cursor_text = Text::new(&cursor_str);
+            if let Some(color) = self.style.cursor_color {
+                cursor_text = cursor_text.background(color);
             }
-            container = container.child(text.into_element());
-        }
+            cursor_text = cursor_text.color(Color::Black);
+            container = container.child(cursor_text.into_element());
 
-        // Cursor
-        let cursor_str = if cursor_char == ' ' {
-            self.style.cursor_char.to_string()
-        } else {
-            cursor_char.to_string()
-        };
-        let mut cursor_text = Text::new(&cursor_str);
-        if let Some(color) = self.style.cursor_color {
-            cursor_text = cursor_text.background(color);
+            start = cursor_col + 1;
         }
-        cursor_text = cursor_text.color(Color::Black);
-        container = container.child(cursor_text.into_element());
 
-        // After cursor
+        // After the last cursor
+        let after: String = chars.iter().skip(start).collect();
         if !after.is_empty() {
-            let mut text = Text::new(&after);
-            if let Some(color) = self.style.text_color {
-                text = text.color(color);
-            }
-            container = container.child(text.into_element());
+            container = container.child(self.render_plain(&after));
         }
 
         container.into_element()
     }
+
+    /// Render a run of text without cursors
+    fn render_plain(&self, text: &str) -> Element {
+        let mut text = Text::new(text);
+        if let Some(color) = self.style.text_color {
+            text = text.color(color);
+        }
+        text.into_element()
+    }
 }
 
 /// Handle textarea input and return the action performed
@@ -445,6 +455,44 @@ pub fn handle_textarea_input(
     None
 }
 
+/// Handle a mouse event over the textarea's text and return whether it was used
+///
+/// `origin` is the screen cell where the first visible line's text starts,
+/// after any border, line numbers, and prompt. A click moves the cursor,
+/// Alt+Click adds a cursor, and Alt+drag selects a column block.
+pub fn handle_textarea_mouse(
+    state: &mut TextAreaState,
+    mouse: &crate::hooks::Mouse,
+    origin: (u16, u16),
+) -> bool {
+    use crate::hooks::{MouseAction, MouseButton};
+
+    let (x, y) = origin;
+    if mouse.x < x || mouse.y < y {
+        return false;
+    }
+    let pos = Position::new(
+        state.scroll_offset() + (mouse.y - y) as usize,
+        (mouse.x - x) as usize,
+    );
+
+    match mouse.action {
+        MouseAction::Press(MouseButton::Left) if mouse.alt => {
+            state.add_cursor(pos);
+            state.start_block_selection(pos);
+        }
+        MouseAction::Press(MouseButton::Left) => {
+            state.end_block_selection();
+            state.set_cursor(pos);
+        }
+        MouseAction::Drag(MouseButton::Left) if mouse.alt => state.extend_block_selection(pos),
+        MouseAction::Drag(MouseButton::Left) => state.select_to(pos),
+        MouseAction::Release(MouseButton::Left) => state.end_block_selection(),
+        _ => return false,
+    }
+    true
+}
+
 /// Handle textarea input with explicit disabled/read-only behavior.
 pub fn handle_textarea_input_with_mode(
     state: &mut TextAreaState,
@@ -554,6 +602,10 @@ pub fn apply_textarea_action(state: &mut TextAreaState, action: TextAreaAction)
             // Would need redo history in state
         }
 
+        // Multiple cursors
+        TextAreaAction::AddCursorAbove => state.add_cursor_above(),
+        TextAreaAction::AddCursorBelow => state.add_cursor_below(),
+
         // Special
         TextAreaAction::InsertNewline => state.insert_char('\n'),
         TextAreaAction::InsertTab => state.insert_char('\t'),
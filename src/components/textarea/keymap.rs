@@ -65,6 +65,12 @@ pub struct TextAreaKeyMap {
     /// Redo (default: Ctrl+Y, Ctrl+Shift+Z)
     pub redo: Vec<KeyBinding>,
 
+    // Multiple cursors
+    /// Add a cursor on the line above (default: Ctrl+Alt+Up)
+    pub add_cursor_above: Vec<KeyBinding>,
+    /// Add a cursor on the line below (default: Ctrl+Alt+Down)
+    pub add_cursor_below: Vec<KeyBinding>,
+
     // Special
     /// Insert newline (default: Enter)
     pub newline: Vec<KeyBinding>,
@@ -108,6 +114,8 @@ impl Default for TextAreaKeyMap {
                 KeyBinding::ctrl('y'),
                 KeyBinding::new(KeyType::Char('z'), Modifiers::CTRL_SHIFT),
             ],
+            add_cursor_above: vec![KeyBinding::new(KeyType::Up, Modifiers::CTRL_ALT)],
+            add_cursor_below: vec![KeyBinding::new(KeyType::Down, Modifiers::CTRL_ALT)],
             newline: vec![KeyBinding::special(KeyType::Enter)],
             tab: vec![KeyBinding::special(KeyType::Tab)],
         }
@@ -144,6 +152,8 @@ impl TextAreaKeyMap {
             paste: vec![],
             undo: vec![],
             redo: vec![],
+            add_cursor_above: vec![],
+            add_cursor_below: vec![],
             newline: vec![KeyBinding::special(KeyType::Enter)],
             tab: vec![KeyBinding::special(KeyType::Tab)],
         }
@@ -173,6 +183,8 @@ impl TextAreaKeyMap {
             paste: vec![],
             undo: vec![],
             redo: vec![],
+            add_cursor_above: vec![],
+            add_cursor_below: vec![],
             newline: vec![],
             tab: vec![],
         }
@@ -202,6 +214,8 @@ impl TextAreaKeyMap {
             (&self.paste, TextAreaAction::Paste),
             (&self.undo, TextAreaAction::Undo),
             (&self.redo, TextAreaAction::Redo),
+            (&self.add_cursor_above, TextAreaAction::AddCursorAbove),
+            (&self.add_cursor_below, TextAreaAction::AddCursorBelow),
             (&self.newline, TextAreaAction::InsertNewline),
             (&self.tab, TextAreaAction::InsertTab),
         ];
@@ -240,6 +254,8 @@ pub enum TextAreaAction {
     Paste,
     Undo,
     Redo,
+    AddCursorAbove,
+    AddCursorBelow,
     InsertNewline,
     InsertTab,
 }
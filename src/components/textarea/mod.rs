@@ -7,11 +7,13 @@
 //! - Multi-line text editing
 //! - Cursor navigation (character, word, line, document)
 //! - Text selection
+//! - Multiple cursors and column (block) selection
 //! - Line numbers
 //! - Customizable key bindings
 //! - Placeholder text
 //! - Character/line limits
 //! - Soft tabs (spaces) or hard tabs
+//! - Optional bracket/quote auto-pairing and auto-indent
 //!
 //! # Example
 //!
@@ -63,6 +65,10 @@
 //! | `Ctrl+K` | Delete line |
 //! | `Enter` | Insert newline |
 //! | `Tab` | Insert tab/spaces |
+//! | `Ctrl+Alt+↑` / `Ctrl+Alt+↓` | Add cursor above/below |
+//!
+//! With [`handle_textarea_mouse`], Alt+Click adds a cursor and Alt+drag
+//! selects a column block. Typing then edits at every cursor.
 
 mod component;
 mod keymap;
@@ -70,7 +76,7 @@ mod state;
 
 pub use component::{
     TextArea, TextAreaStyle, apply_textarea_action, handle_textarea_input,
-    handle_textarea_input_with_mode,
+    handle_textarea_input_with_mode, handle_textarea_mouse,
 };
 pub use keymap::{KeyBinding, KeyType, Modifiers, TextAreaAction, TextAreaKeyMap};
 pub use state::{Position, Selection, TextAreaState};
@@ -1,11 +1,15 @@
 //! TextArea state management
 //!
 //! Manages text content, cursor position, selection, and editing operations.
+//! Secondary cursors can be added for multi-cursor and block editing; edits
+//! then apply at every cursor.
 
 use std::cmp;
 
 /// Position in the text (row, column)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+///
+/// Ordering follows document order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
 pub struct Position {
     pub row: usize,
     pub col: usize,
@@ -43,6 +47,21 @@ impl Selection {
     }
 }
 
+/// A secondary cursor and its selection
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Caret {
+    cursor: Position,
+    selection: Option<Selection>,
+}
+
+impl Caret {
+    /// Where the caret's text range begins, used to order edits
+    fn start(&self) -> Position {
+        self.selection
+            .map_or(self.cursor, |s| s.start.min(self.cursor))
+    }
+}
+
 /// TextArea state containing text content and cursor
 #[derive(Debug, Clone)]
 pub struct TextAreaState {
@@ -55,6 +74,12 @@ pub struct TextAreaState {
     /// Selection (None = no selection, just cursor)
     selection: Option<Selection>,
 
+    /// Secondary cursors, each with its own selection
+    extra_cursors: Vec<Caret>,
+
+    /// Where an Alt+drag block selection started
+    block_anchor: Option<Position>,
+
     /// Maximum number of lines (None = unlimited)
     max_lines: Option<usize>,
 
@@ -84,6 +109,33 @@ pub struct TextAreaState {
 
     /// Whether to use soft tabs (spaces instead of \t)
     soft_tabs: bool,
+
+    /// Whether typing an opening bracket or quote inserts its closer
+    auto_pairs: bool,
+
+    /// Whether new lines copy the previous line's indentation
+    auto_indent: bool,
+
+    /// Closers inserted by auto-pairing that typing can step over
+    auto_closers: Vec<Position>,
+}
+
+/// Characters auto-paired with their closers
+const AUTO_PAIRS: [(char, char); 6] = [
+    ('(', ')'),
+    ('[', ']'),
+    ('{', '}'),
+    ('"', '"'),
+    ('\'', '\''),
+    ('`', '`'),
+];
+
+/// The closer paired with an opening character
+fn closer_for(open: char) -> Option<char> {
+    AUTO_PAIRS
+        .iter()
+        .find(|(o, _)| *o == open)
+        .map(|(_, close)| *close)
 }
 
 impl Default for TextAreaState {
@@ -92,6 +144,8 @@ impl Default for TextAreaState {
             lines: vec![String::new()],
             cursor: Position::default(),
             selection: None,
+            extra_cursors: Vec::new(),
+            block_anchor: None,
             max_lines: None,
             max_length: None,
             read_only: false,
@@ -102,6 +156,9 @@ impl Default for TextAreaState {
             show_line_numbers: false,
             tab_width: 4,
             soft_tabs: true,
+            auto_pairs: false,
+            auto_indent: false,
+            auto_closers: Vec::new(),
         }
     }
 }
@@ -138,6 +195,8 @@ impl TextAreaState {
         }
         self.clamp_cursor();
         self.selection = None;
+        self.extra_cursors.clear();
+        self.auto_closers.clear();
     }
 
     /// Get the entire content as a string
@@ -176,6 +235,8 @@ impl TextAreaState {
         self.lines = vec![String::new()];
         self.cursor = Position::default();
         self.selection = None;
+        self.extra_cursors.clear();
+        self.auto_closers.clear();
         self.scroll_offset = 0;
     }
 
@@ -186,8 +247,9 @@ impl TextAreaState {
         self.cursor
     }
 
-    /// Set cursor position
+    /// Set cursor position, dropping any secondary cursors
     pub fn set_cursor(&mut self, pos: Position) {
+        self.extra_cursors.clear();
         self.cursor = pos;
         self.clamp_cursor();
         self.ensure_cursor_visible();
@@ -207,6 +269,10 @@ impl TextAreaState {
 
     /// Move cursor left by one character
     pub fn move_left(&mut self) {
+        if self.has_multiple_cursors() {
+            self.move_each_cursor(Self::move_left);
+            return;
+        }
         if self.cursor.col > 0 {
             self.cursor.col -= 1;
         } else if self.cursor.row > 0 {
@@ -220,6 +286,10 @@ impl TextAreaState {
 
     /// Move cursor right by one character
     pub fn move_right(&mut self) {
+        if self.has_multiple_cursors() {
+            self.move_each_cursor(Self::move_right);
+            return;
+        }
         let line_len = self.current_line_len();
         if self.cursor.col < line_len {
             self.cursor.col += 1;
@@ -234,6 +304,10 @@ impl TextAreaState {
 
     /// Move cursor up by one line
     pub fn move_up(&mut self) {
+        if self.has_multiple_cursors() {
+            self.move_each_cursor(Self::move_up);
+            return;
+        }
         if self.cursor.row > 0 {
             self.cursor.row -= 1;
             self.clamp_cursor_col();
@@ -244,6 +318,10 @@ impl TextAreaState {
 
     /// Move cursor down by one line
     pub fn move_down(&mut self) {
+        if self.has_multiple_cursors() {
+            self.move_each_cursor(Self::move_down);
+            return;
+        }
         if self.cursor.row < self.lines.len() - 1 {
             self.cursor.row += 1;
             self.clamp_cursor_col();
@@ -254,18 +332,27 @@ impl TextAreaState {
 
     /// Move cursor to start of line
     pub fn move_to_line_start(&mut self) {
+        if self.has_multiple_cursors() {
+            self.move_each_cursor(Self::move_to_line_start);
+            return;
+        }
         self.cursor.col = 0;
         self.clear_selection();
     }
 
     /// Move cursor to end of line
     pub fn move_to_line_end(&mut self) {
+        if self.has_multiple_cursors() {
+            self.move_each_cursor(Self::move_to_line_end);
+            return;
+        }
         self.cursor.col = self.current_line_len();
         self.clear_selection();
     }
 
     /// Move cursor to start of text
     pub fn move_to_start(&mut self) {
+        self.extra_cursors.clear();
         self.cursor = Position::default();
         self.clear_selection();
         self.ensure_cursor_visible();
@@ -273,6 +360,7 @@ impl TextAreaState {
 
     /// Move cursor to end of text
     pub fn move_to_end(&mut self) {
+        self.extra_cursors.clear();
         self.cursor.row = self.lines.len() - 1;
         self.cursor.col = self.current_line_len();
         self.clear_selection();
@@ -281,6 +369,10 @@ impl TextAreaState {
 
     /// Move cursor to previous word
     pub fn move_word_left(&mut self) {
+        if self.has_multiple_cursors() {
+            self.move_each_cursor(Self::move_word_left);
+            return;
+        }
         if self.cursor.col == 0 {
             if self.cursor.row > 0 {
                 self.cursor.row -= 1;
@@ -308,6 +400,10 @@ impl TextAreaState {
 
     /// Move cursor to next word
     pub fn move_word_right(&mut self) {
+        if self.has_multiple_cursors() {
+            self.move_each_cursor(Self::move_word_right);
+            return;
+        }
         let line_len = self.current_line_len();
         if self.cursor.col >= line_len {
             if self.cursor.row < self.lines.len() - 1 {
@@ -338,6 +434,10 @@ impl TextAreaState {
 
     /// Insert a character at cursor position
     pub fn insert_char(&mut self, ch: char) {
+        if self.has_multiple_cursors() {
+            self.edit_each_cursor(|state| state.insert_char(ch));
+            return;
+        }
         if self.read_only {
             return;
         }
@@ -353,9 +453,15 @@ impl TextAreaState {
         self.delete_selection();
 
         if ch == '\n' {
-            self.insert_newline();
+            if self.auto_indent {
+                self.insert_indented_newline();
+            } else {
+                self.insert_newline();
+            }
         } else if ch == '\t' {
             self.insert_tab();
+        } else if self.auto_pairs && self.type_over_closer(ch) {
+            // Stepped over an auto-inserted closer
         } else {
             // Check line length limit
             if let Some(max_len) = self.max_length {
@@ -364,22 +470,145 @@ impl TextAreaState {
                 }
             }
 
-            let line = &mut self.lines[self.cursor.row];
-            let byte_pos = char_to_byte_pos(line, self.cursor.col);
-            line.insert(byte_pos, ch);
+            let closer = self
+                .auto_pairs
+                .then(|| self.pair_closer(ch))
+                .flatten()
+                .filter(|_| self.has_room_for(2));
+            let mut inserted = ch.to_string();
+            inserted.extend(closer);
+
+            let row = self.cursor.row;
+            let col = self.cursor.col;
+            let line = &mut self.lines[row];
+            let byte_pos = char_to_byte_pos(line, col);
+            line.insert_str(byte_pos, &inserted);
+            self.shift_auto_closers(row, col, inserted.chars().count() as isize);
             self.cursor.col += 1;
+            if closer.is_some() {
+                self.auto_closers.push(self.cursor);
+            }
         }
 
         self.ensure_cursor_visible();
     }
 
+    /// Closer to insert after `ch`, if it opens a pair here
+    ///
+    /// Brackets pair before whitespace, a closer or the end of the line.
+    /// Quotes also need a non-word character before them, so apostrophes
+    /// in words such as "don't" stay single.
+    fn pair_closer(&self, ch: char) -> Option<char> {
+        let close = closer_for(ch)?;
+        let line = &self.lines[self.cursor.row];
+        let mut chars = line.chars().skip(self.cursor.col.saturating_sub(1));
+        let before = if self.cursor.col > 0 {
+            chars.next()
+        } else {
+            None
+        };
+        let after = chars.next();
+
+        let after_ok = after
+            .is_none_or(|c| c.is_whitespace() || AUTO_PAIRS.iter().any(|(_, close)| *close == c));
+        let before_ok = ch != close || before.is_none_or(|c| !c.is_alphanumeric() && c != close);
+        (after_ok && before_ok).then_some(close)
+    }
+
+    /// Step over an auto-inserted closer equal to `ch` at the cursor
+    fn type_over_closer(&mut self, ch: char) -> bool {
+        let Some(index) = self.auto_closers.iter().position(|pos| *pos == self.cursor) else {
+            return false;
+        };
+        if self.lines[self.cursor.row].chars().nth(self.cursor.col) != Some(ch) {
+            return false;
+        }
+        self.auto_closers.remove(index);
+        self.cursor.col += 1;
+        true
+    }
+
+    /// Move tracked closers on `row` at or after `col` by `delta` columns
+    fn shift_auto_closers(&mut self, row: usize, col: usize, delta: isize) {
+        for pos in &mut self.auto_closers {
+            if pos.row == row && pos.col >= col {
+                pos.col = pos.col.saturating_add_signed(delta);
+            }
+        }
+    }
+
+    /// Check whether `count` more characters fit the line and char limits
+    fn has_room_for(&self, count: usize) -> bool {
+        self.char_limit
+            .is_none_or(|limit| self.char_count() + count <= limit)
+            && self
+                .max_length
+                .is_none_or(|max| self.current_line_len() + count <= max)
+    }
+
+    /// Insert a newline that keeps the indentation of the text before the
+    /// cursor, one level deeper after an opening bracket
+    ///
+    /// Pressing Enter between an opening bracket and its closer moves the
+    /// closer to its own line at the original indentation.
+    fn insert_indented_newline(&mut self) {
+        let row = self.cursor.row;
+        let line: Vec<char> = self.lines[row].chars().collect();
+        let col = self.cursor.col.min(line.len());
+        let indent: String = line[..col]
+            .iter()
+            .take_while(|c| **c == ' ' || **c == '\t')
+            .collect();
+        let before = col.checked_sub(1).map(|i| line[i]);
+        let after = line.get(col).copied();
+        let opens = matches!(before, Some('(' | '[' | '{'));
+
+        let line_count = self.lines.len();
+        self.insert_newline();
+        if self.lines.len() == line_count {
+            return;
+        }
+        self.auto_closers.clear();
+
+        let mut inner = indent.clone();
+        if opens {
+            inner.push_str(&self.indent_unit());
+        }
+        self.lines[self.cursor.row].insert_str(0, &inner);
+        self.cursor.col = inner.chars().count();
+
+        let splits_pair = opens && after.is_some() && after == before.and_then(closer_for);
+        if splits_pair {
+            let inner_pos = self.cursor;
+            self.insert_newline();
+            if self.lines.len() > line_count + 1 {
+                self.lines[self.cursor.row].insert_str(0, &indent);
+            }
+            self.cursor = inner_pos;
+        }
+    }
+
+    /// One level of indentation
+    fn indent_unit(&self) -> String {
+        if self.soft_tabs {
+            " ".repeat(self.tab_width)
+        } else {
+            "\t".to_string()
+        }
+    }
+
     /// Insert a string at cursor position
     pub fn insert_string(&mut self, s: &str) {
+        if self.has_multiple_cursors() {
+            self.edit_each_cursor(|state| state.insert_string(s));
+            return;
+        }
         if self.read_only {
             return;
         }
 
         self.delete_selection();
+        self.auto_closers.clear();
 
         for ch in s.chars() {
             // Check limits
@@ -430,6 +659,7 @@ impl TextAreaState {
         self.cursor.row += 1;
         self.cursor.col = 0;
         self.lines.insert(self.cursor.row, rest);
+        self.auto_closers.clear();
     }
 
     /// Insert a tab (or spaces if soft tabs)
@@ -443,12 +673,17 @@ impl TextAreaState {
             let line = &mut self.lines[self.cursor.row];
             let byte_pos = char_to_byte_pos(line, self.cursor.col);
             line.insert(byte_pos, '\t');
+            self.shift_auto_closers(self.cursor.row, self.cursor.col, 1);
             self.cursor.col += 1;
         }
     }
 
     /// Delete character before cursor (backspace)
     pub fn delete_before_cursor(&mut self) {
+        if self.has_multiple_cursors() {
+            self.edit_each_cursor(Self::delete_before_cursor);
+            return;
+        }
         if self.read_only {
             return;
         }
@@ -458,12 +693,25 @@ impl TextAreaState {
         }
 
         if self.cursor.col > 0 {
-            let line = &mut self.lines[self.cursor.row];
-            let byte_pos = char_to_byte_pos(line, self.cursor.col - 1);
-            let end_pos = char_to_byte_pos(line, self.cursor.col);
+            // Removing an opener also removes the closer auto-inserted with it
+            let row = self.cursor.row;
+            let start = self.cursor.col - 1;
+            let line = &mut self.lines[row];
+            let mut chars = line.chars().skip(start);
+            let (open, close) = (chars.next(), chars.next());
+            let pair = self.auto_closers.contains(&self.cursor)
+                && open.and_then(closer_for).is_some_and(|c| Some(c) == close);
+            let width = if pair { 2 } else { 1 };
+
+            let byte_pos = char_to_byte_pos(line, start);
+            let end_pos = char_to_byte_pos(line, start + width);
             line.replace_range(byte_pos..end_pos, "");
-            self.cursor.col -= 1;
+            self.auto_closers
+                .retain(|pos| pos.row != row || !(start..start + width).contains(&pos.col));
+            self.shift_auto_closers(row, start + width, -(width as isize));
+            self.cursor.col = start;
         } else if self.cursor.row > 0 {
+            self.auto_closers.clear();
             // Merge with previous line
             let current_line = self.lines.remove(self.cursor.row);
             self.cursor.row -= 1;
@@ -476,6 +724,10 @@ impl TextAreaState {
 
     /// Delete character after cursor (delete key)
     pub fn delete_after_cursor(&mut self) {
+        if self.has_multiple_cursors() {
+            self.edit_each_cursor(Self::delete_after_cursor);
+            return;
+        }
         if self.read_only {
             return;
         }
@@ -484,6 +736,7 @@ impl TextAreaState {
             return;
         }
 
+        self.auto_closers.clear();
         let line_len = self.current_line_len();
         if self.cursor.col < line_len {
             let line = &mut self.lines[self.cursor.row];
@@ -499,6 +752,10 @@ impl TextAreaState {
 
     /// Delete word before cursor
     pub fn delete_word_before(&mut self) {
+        if self.has_multiple_cursors() {
+            self.edit_each_cursor(Self::delete_word_before);
+            return;
+        }
         if self.read_only {
             return;
         }
@@ -521,6 +778,10 @@ impl TextAreaState {
 
     /// Delete word after cursor
     pub fn delete_word_after(&mut self) {
+        if self.has_multiple_cursors() {
+            self.edit_each_cursor(Self::delete_word_after);
+            return;
+        }
         if self.read_only {
             return;
         }
@@ -557,6 +818,7 @@ impl TextAreaState {
             return;
         }
 
+        self.extra_cursors.clear();
         if self.lines.len() > 1 {
             self.lines.remove(self.cursor.row);
             if self.cursor.row >= self.lines.len() {
@@ -586,6 +848,7 @@ impl TextAreaState {
 
     /// Start or extend selection
     pub fn select_to(&mut self, pos: Position) {
+        self.extra_cursors.clear();
         let start = self.selection.map_or(self.cursor, |s| s.start);
         self.selection = Some(Selection::new(start, pos));
         self.cursor = pos;
@@ -594,6 +857,7 @@ impl TextAreaState {
 
     /// Select all text
     pub fn select_all(&mut self) {
+        self.extra_cursors.clear();
         let end = Position::new(
             self.lines.len() - 1,
             self.lines.last().map_or(0, |l| l.chars().count()),
@@ -675,10 +939,235 @@ impl TextAreaState {
         // Update cursor
         self.cursor = sel.start;
         self.selection = None;
+        self.auto_closers.clear();
 
         true
     }
 
+    // ========== Multiple Cursors ==========
+
+    /// All cursor positions in document order, the primary one included
+    pub fn cursors(&self) -> Vec<Position> {
+        let mut cursors: Vec<Position> = std::iter::once(self.cursor)
+            .chain(self.extra_cursors.iter().map(|caret| caret.cursor))
+            .collect();
+        cursors.sort();
+        cursors
+    }
+
+    /// Non-empty selections of all cursors in document order
+    pub fn selections(&self) -> Vec<Selection> {
+        let mut selections: Vec<Selection> = std::iter::once(self.selection)
+            .chain(self.extra_cursors.iter().map(|caret| caret.selection))
+            .flatten()
+            .filter(|s| !s.is_empty())
+            .collect();
+        selections.sort_by_key(|s| s.start);
+        selections
+    }
+
+    /// Number of cursors, the primary one included
+    pub fn cursor_count(&self) -> usize {
+        1 + self.extra_cursors.len()
+    }
+
+    /// Check if secondary cursors are active
+    pub fn has_multiple_cursors(&self) -> bool {
+        !self.extra_cursors.is_empty()
+    }
+
+    /// Add a cursor at `pos` and make it the primary one (Alt+Click)
+    ///
+    /// Adding a cursor where a secondary one already sits removes it instead.
+    pub fn add_cursor(&mut self, pos: Position) {
+        let pos = self.clamp_position(pos);
+        if pos == self.cursor {
+            return;
+        }
+        if let Some(index) = self.extra_cursors.iter().position(|c| c.cursor == pos) {
+            self.extra_cursors.remove(index);
+            return;
+        }
+        self.extra_cursors.push(Caret {
+            cursor: self.cursor,
+            selection: self.selection.take(),
+        });
+        self.cursor = pos;
+        self.ensure_cursor_visible();
+    }
+
+    /// Add a cursor on the line above the topmost cursor (Ctrl+Alt+Up)
+    pub fn add_cursor_above(&mut self) {
+        let top = self.cursors()[0];
+        if top.row > 0 {
+            self.add_cursor(Position::new(top.row - 1, top.col));
+        }
+    }
+
+    /// Add a cursor on the line below the bottommost cursor (Ctrl+Alt+Down)
+    pub fn add_cursor_below(&mut self) {
+        let bottom = self.cursors()[self.cursor_count() - 1];
+        if bottom.row + 1 < self.lines.len() {
+            self.add_cursor(Position::new(bottom.row + 1, bottom.col));
+        }
+    }
+
+    /// Select the column block between `anchor` and `head` (Alt+drag)
+    ///
+    /// Each line in the block gets its own cursor and selection, so typing
+    /// replaces the block on every line at once. The primary cursor sits on
+    /// the `head` line.
+    pub fn select_block(&mut self, anchor: Position, head: Position) {
+        let last_row = self.lines.len() - 1;
+        let (top, bottom) = (anchor.row.min(last_row), head.row.min(last_row));
+        let (left, right) = (anchor.col.min(head.col), anchor.col.max(head.col));
+
+        self.extra_cursors.clear();
+        let rows: Vec<usize> = if top <= bottom {
+            (top..=bottom).collect()
+        } else {
+            (bottom..=top).rev().collect()
+        };
+        for row in rows {
+            let len = self.line_len(row);
+            let range = Selection::new(
+                Position::new(row, left.min(len)),
+                Position::new(row, right.min(len)),
+            );
+            let caret = Caret {
+                cursor: Position::new(row, head.col.min(len)),
+                selection: (!range.is_empty()).then_some(range),
+            };
+            if row == bottom {
+                self.cursor = caret.cursor;
+                self.selection = caret.selection;
+            } else {
+                self.extra_cursors.push(caret);
+            }
+        }
+        self.ensure_cursor_visible();
+    }
+
+    /// Drop all secondary cursors, keeping the primary one
+    pub fn clear_extra_cursors(&mut self) {
+        self.extra_cursors.clear();
+        self.block_anchor = None;
+    }
+
+    /// Begin an Alt+drag block selection at `pos`
+    pub fn start_block_selection(&mut self, pos: Position) {
+        self.block_anchor = Some(self.clamp_position(pos));
+    }
+
+    /// Extend the block selection started by
+    /// [`start_block_selection`](Self::start_block_selection) to `pos`
+    pub fn extend_block_selection(&mut self, pos: Position) {
+        let anchor = *self.block_anchor.get_or_insert(pos);
+        self.select_block(anchor, pos);
+    }
+
+    /// Finish the current block selection, keeping its cursors
+    pub fn end_block_selection(&mut self) {
+        self.block_anchor = None;
+    }
+
+    /// Remove every cursor into a list ordered back to front
+    fn take_carets(&mut self) -> Vec<(Caret, bool)> {
+        let primary = Caret {
+            cursor: self.cursor,
+            selection: self.selection,
+        };
+        let mut carets: Vec<(Caret, bool)> = std::iter::once((primary, true))
+            .chain(self.extra_cursors.drain(..).map(|caret| (caret, false)))
+            .collect();
+        carets.sort_by_key(|(caret, _)| cmp::Reverse(caret.start()));
+        carets
+    }
+
+    /// Put cursors back after an operation, merging any that now coincide
+    fn restore_carets(&mut self, carets: Vec<(Caret, bool)>) {
+        for (caret, primary) in &carets {
+            if *primary {
+                self.cursor = caret.cursor;
+                self.selection = caret.selection;
+            }
+        }
+        for (caret, primary) in carets {
+            let taken = caret.cursor == self.cursor
+                || self.extra_cursors.iter().any(|c| c.cursor == caret.cursor);
+            if !primary && !taken {
+                self.extra_cursors.push(caret);
+            }
+        }
+        self.ensure_cursor_visible();
+    }
+
+    /// Run a cursor movement at every cursor
+    fn move_each_cursor(&mut self, mut op: impl FnMut(&mut Self)) {
+        let carets = self.take_carets();
+        let mut moved = Vec::with_capacity(carets.len());
+        for (caret, primary) in carets {
+            self.cursor = caret.cursor;
+            self.selection = caret.selection;
+            op(self);
+            let caret = Caret {
+                cursor: self.cursor,
+                selection: self.selection,
+            };
+            moved.push((caret, primary));
+        }
+        self.restore_carets(moved);
+    }
+
+    /// Run an edit at every cursor
+    ///
+    /// Edits apply in reverse document order so the cursors still waiting
+    /// keep valid positions. Cursors already edited are tracked relative to
+    /// the end of the text, which the remaining edits never touch.
+    fn edit_each_cursor(&mut self, mut op: impl FnMut(&mut Self)) {
+        let carets = self.take_carets();
+        let mut edited = Vec::with_capacity(carets.len());
+        for (caret, primary) in carets {
+            self.cursor = caret.cursor;
+            self.selection = caret.selection;
+            op(self);
+            let selection = self
+                .selection
+                .map(|s| (self.offset_from_end(s.start), self.offset_from_end(s.end)));
+            edited.push((self.offset_from_end(self.cursor), selection, primary));
+        }
+
+        let carets = edited
+            .into_iter()
+            .map(|(cursor, selection, primary)| {
+                let caret = Caret {
+                    cursor: self.position_from_end(cursor),
+                    selection: selection.map(|(start, end)| {
+                        Selection::new(self.position_from_end(start), self.position_from_end(end))
+                    }),
+                };
+                (caret, primary)
+            })
+            .collect();
+        self.restore_carets(carets);
+    }
+
+    /// Express a position as (lines after it, chars after it on its line)
+    fn offset_from_end(&self, pos: Position) -> (usize, usize) {
+        let row = self.lines.len().saturating_sub(1).saturating_sub(pos.row);
+        (row, self.line_len(pos.row).saturating_sub(pos.col))
+    }
+
+    /// Inverse of [`offset_from_end`](Self::offset_from_end)
+    fn position_from_end(&self, (rows_after, cols_after): (usize, usize)) -> Position {
+        let row = self
+            .lines
+            .len()
+            .saturating_sub(1)
+            .saturating_sub(rows_after);
+        Position::new(row, self.line_len(row).saturating_sub(cols_after))
+    }
+
     // ========== Configuration ==========
 
     /// Set maximum number of lines
@@ -742,6 +1231,36 @@ impl TextAreaState {
         self.soft_tabs = soft;
     }
 
+    /// Auto-close brackets and quotes as they are typed
+    ///
+    /// Typing `(` inserts `()` with the cursor between; typing `)` right
+    /// before the auto-inserted closer steps over it, and Backspace between
+    /// an empty pair removes both. The pair is inserted by a single
+    /// [`insert_char`](Self::insert_char), so snapshot-based undo (e.g.
+    /// [`use_history`](crate::hooks::use_history)) sees one action.
+    pub fn set_auto_pairs(&mut self, enabled: bool) {
+        self.auto_pairs = enabled;
+        self.auto_closers.clear();
+    }
+
+    /// Check whether brackets and quotes are auto-closed
+    pub fn auto_pairs(&self) -> bool {
+        self.auto_pairs
+    }
+
+    /// Indent typed new lines to match the current line
+    ///
+    /// Lines after an opening bracket get one more level (a tab, or
+    /// `tab_width` spaces with soft tabs). Pasted text is not re-indented.
+    pub fn set_auto_indent(&mut self, enabled: bool) {
+        self.auto_indent = enabled;
+    }
+
+    /// Check whether new lines are auto-indented
+    pub fn auto_indent(&self) -> bool {
+        self.auto_indent
+    }
+
     /// Enable/disable line numbers
     pub fn set_show_line_numbers(&mut self, show: bool) {
         self.show_line_numbers = show;
@@ -782,6 +1301,17 @@ impl TextAreaState {
             .map_or(0, |l| l.chars().count())
     }
 
+    /// Get a line's length in characters
+    fn line_len(&self, row: usize) -> usize {
+        self.lines.get(row).map_or(0, |l| l.chars().count())
+    }
+
+    /// Clamp a position into the text
+    fn clamp_position(&self, pos: Position) -> Position {
+        let row = pos.row.min(self.lines.len().saturating_sub(1));
+        Position::new(row, pos.col.min(self.line_len(row)))
+    }
+
     /// Clamp cursor to valid position
     fn clamp_cursor(&mut self) {
         self.cursor.row = cmp::min(self.cursor.row, self.lines.len().saturating_sub(1));
@@ -933,4 +1463,144 @@ mod tests {
         state.move_word_left();
         assert_eq!(state.cursor().col, 6);
     }
+
+    #[test]
+    fn test_multi_cursor_insert_and_delete() {
+        let mut state = TextAreaState::new();
+        state.set_content("ab\ncd\nef");
+        state.set_cursor(Position::new(0, 1));
+        state.add_cursor_below();
+        state.add_cursor_below();
+        assert_eq!(state.cursor_count(), 3);
+
+        state.insert_string("X\n");
+        assert_eq!(state.content(), "aX\nb\ncX\nd\neX\nf");
+        assert_eq!(
+            state.cursors(),
+            vec![
+                Position::new(1, 0),
+                Position::new(3, 0),
+                Position::new(5, 0)
+            ]
+        );
+
+        state.delete_before_cursor();
+        state.delete_before_cursor();
+        assert_eq!(state.content(), "ab\ncd\nef");
+        assert_eq!(
+            state.cursors(),
+            vec![
+                Position::new(0, 1),
+                Position::new(1, 1),
+                Position::new(2, 1)
+            ]
+        );
+
+        state.move_to_line_end();
+        state.insert_char(';');
+        assert_eq!(state.content(), "ab;\ncd;\nef;");
+    }
+
+    #[test]
+    fn test_multi_cursor_same_line_and_merge() {
+        let mut state = TextAreaState::new();
+        state.set_content("a b c");
+        state.add_cursor(Position::new(0, 2));
+        state.add_cursor(Position::new(0, 4));
+        state.insert_char('_');
+        assert_eq!(state.content(), "_a _b _c");
+        assert_eq!(state.cursor(), Position::new(0, 7));
+
+        // Moving every cursor to the line start collapses them into one
+        state.move_to_line_start();
+        assert_eq!(state.cursor_count(), 1);
+
+        // Adding at an existing secondary cursor toggles it off
+        state.add_cursor(Position::new(0, 3));
+        state.add_cursor(Position::new(0, 5));
+        state.add_cursor(Position::new(0, 3));
+        assert_eq!(state.cursor_count(), 2);
+    }
+
+    #[test]
+    fn test_block_selection_replaces_each_row() {
+        let mut state = TextAreaState::new();
+        state.set_content("let a = 1;\nlet bb = 2;\nx");
+        state.start_block_selection(Position::new(0, 4));
+        state.extend_block_selection(Position::new(2, 6));
+        state.end_block_selection();
+
+        assert_eq!(state.cursor_count(), 3);
+        assert_eq!(state.selections().len(), 2);
+        assert_eq!(state.cursor(), Position::new(2, 1));
+
+        state.insert_char('v');
+        assert_eq!(state.content(), "let v= 1;\nlet v = 2;\nxv");
+
+        state.clear_extra_cursors();
+        assert!(!state.has_multiple_cursors());
+    }
+
+    #[test]
+    fn test_auto_pairs_insert_type_over_and_backspace() {
+        let mut state = TextAreaState::new();
+        state.set_auto_pairs(true);
+        for ch in "f(x".chars() {
+            state.insert_char(ch);
+        }
+        assert_eq!(state.content(), "f(x)");
+        assert_eq!(state.cursor(), Position::new(0, 3));
+
+        // Typing the closer steps over the auto-inserted one
+        state.insert_char(')');
+        assert_eq!(state.content(), "f(x)");
+        assert_eq!(state.cursor(), Position::new(0, 4));
+
+        // Backspace inside an empty pair removes both characters
+        state.insert_char('[');
+        assert_eq!(state.content(), "f(x)[]");
+        state.delete_before_cursor();
+        assert_eq!(state.content(), "f(x)");
+
+        // Apostrophes inside words are not paired
+        state.set_content("don");
+        state.insert_char('\'');
+        assert_eq!(state.content(), "don'");
+
+        // Openers before a word are not paired
+        state.set_content("x");
+        state.move_to_line_start();
+        state.insert_char('(');
+        assert_eq!(state.content(), "(x");
+    }
+
+    #[test]
+    fn test_auto_indent_copies_and_nests_indentation() {
+        let mut state = TextAreaState::new();
+        state.set_auto_pairs(true);
+        state.set_auto_indent(true);
+        for ch in "  if x {".chars() {
+            state.insert_char(ch);
+        }
+        assert_eq!(state.content(), "  if x {}");
+
+        state.insert_char('\n');
+        assert_eq!(state.content(), "  if x {\n      \n  }");
+        assert_eq!(state.cursor(), Position::new(1, 6));
+
+        state.insert_char('y');
+        state.insert_char('\n');
+        assert_eq!(state.content(), "  if x {\n      y\n      \n  }");
+        assert_eq!(state.cursor(), Position::new(2, 6));
+    }
+
+    #[test]
+    fn test_auto_features_disabled_by_default() {
+        let mut state = TextAreaState::new();
+        assert!(!state.auto_pairs());
+        assert!(!state.auto_indent());
+        state.insert_string("  (");
+        state.insert_char('\n');
+        assert_eq!(state.content(), "  (\n");
+    }
 }
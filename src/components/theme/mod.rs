@@ -4,15 +4,24 @@
 
 use crate::core::Color;
 
+mod stack;
 mod tokens;
 
+use stack::LayerId;
+pub(crate) use stack::ThemeStack;
 pub use tokens::{
     BorderTokens, ComponentState, ComponentVariant, Density, DensityTokens, DesignTokens,
-    FocusTokens, SpacingTokens, StateTokens, SymbolTokens, VariantStyle,
+    FocusStyle, FocusTokens, SpacingTokens, StateTokens, SymbolTokens, VariantStyle,
+    focus_border_style,
 };
 
 /// A complete theme definition
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Theme {
     /// Theme name
     pub name: String,
@@ -40,6 +49,11 @@ pub struct Theme {
 
 /// Text color variants
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct TextColors {
     /// Primary text color
     pub primary: Color,
@@ -55,6 +69,11 @@ pub struct TextColors {
 
 /// Background color variants
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct BackgroundColors {
     /// Default background
     pub default: Color,
@@ -70,6 +89,11 @@ pub struct BackgroundColors {
 
 /// Border color variants
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct BorderColors {
     /// Default border
     pub default: Color,
@@ -83,6 +107,11 @@ pub struct BorderColors {
 
 /// Component-specific colors
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct ComponentColors {
     /// Input field colors
     pub input: InputColors,
@@ -96,6 +125,11 @@ pub struct ComponentColors {
 
 /// Input field colors
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct InputColors {
     /// Input background
     pub background: Color,
@@ -111,6 +145,11 @@ pub struct InputColors {
 
 /// Button colors
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct ButtonColors {
     /// Primary button background
     pub primary_bg: Color,
@@ -128,6 +167,11 @@ pub struct ButtonColors {
 
 /// List/menu colors
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct ListColors {
     /// Item background
     pub item_bg: Color,
@@ -145,6 +189,11 @@ pub struct ListColors {
 
 /// Progress bar colors
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct ProgressColors {
     /// Track/background color
     pub track: Color,
@@ -160,6 +209,31 @@ impl Default for Theme {
     }
 }
 
+// Sub-structs default to the dark theme so partial theme files fill gaps
+// from the same base as `Theme::default()`.
+macro_rules! impl_dark_default {
+    ($($ty:ident => $($field:ident).+),* $(,)?) => {
+        $(
+            impl Default for $ty {
+                fn default() -> Self {
+                    Theme::dark().$($field).+
+                }
+            }
+        )*
+    };
+}
+
+impl_dark_default!(
+    TextColors => text,
+    BackgroundColors => background,
+    BorderColors => border,
+    ComponentColors => components,
+    InputColors => components.input,
+    ButtonColors => components.button,
+    ListColors => components.list,
+    ProgressColors => components.progress,
+);
+
 impl Theme {
     /// Create a theme builder with the given name
     pub fn builder(name: impl Into<String>) -> ThemeBuilder {
@@ -557,6 +631,31 @@ impl Theme {
         }
     }
 
+    /// Load a theme from JSON
+    ///
+    /// Colors use the textual forms accepted by `Color::from_str` (`"#ff8800"`,
+    /// `"bright_black"`, `"rgb(1, 2, 3)"`, `"ansi(42)"`). Any field missing from
+    /// the document falls back to the dark theme, so partial files work.
+    ///
+    /// ```
+    /// use rnk::components::Theme;
+    /// use rnk::core::Color;
+    ///
+    /// let theme = Theme::from_json(r##"{ "name": "ocean", "primary": "#0088ff" }"##).unwrap();
+    /// assert_eq!(theme.primary, Color::Rgb(0, 136, 255));
+    /// assert_eq!(theme.error, Theme::dark().error);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Serialize the theme to pretty-printed JSON
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("theme serialization cannot fail")
+    }
+
     /// List available theme names
     pub fn available_themes() -> Vec<&'static str> {
         vec![
@@ -721,19 +820,25 @@ impl ThemeBuilder {
 
 // Global theme context (thread-local)
 thread_local! {
-    static CURRENT_THEME: std::cell::RefCell<Theme> = std::cell::RefCell::new(Theme::dark());
+    static CURRENT_THEME: std::cell::RefCell<ThemeStack> =
+        std::cell::RefCell::new(ThemeStack::new(Theme::dark()));
 }
 
-/// Set the current theme
-pub fn set_theme(theme: Theme) {
+/// Run `f` on the theme stack of the current runtime, or the thread's
+fn with_theme_stack<R>(f: impl FnOnce(&mut ThemeStack) -> R) -> R {
     if let Some(ctx) = crate::runtime::current_runtime() {
-        ctx.borrow_mut().set_theme(theme);
-        return;
+        return f(ctx.borrow_mut().theme_stack_mut());
     }
 
-    CURRENT_THEME.with(|t| {
-        *t.borrow_mut() = theme;
-    });
+    CURRENT_THEME.with(|stack| f(&mut stack.borrow_mut()))
+}
+
+/// Set the current theme
+///
+/// Overrides from [`override_theme`] that are still alive stay applied on
+/// top of the new theme.
+pub fn set_theme(theme: Theme) {
+    with_theme_stack(|stack| stack.set_base(theme));
 }
 
 /// Get the current theme
@@ -742,7 +847,42 @@ pub fn get_theme() -> Theme {
         return ctx.borrow().theme();
     }
 
-    CURRENT_THEME.with(|t| t.borrow().clone())
+    CURRENT_THEME.with(|stack| stack.borrow().current().clone())
+}
+
+/// Removes its override from the theme when dropped
+#[must_use = "the override is undone as soon as the guard is dropped"]
+pub struct ThemeOverrideGuard {
+    layer: LayerId,
+}
+
+impl Drop for ThemeOverrideGuard {
+    fn drop(&mut self) {
+        with_theme_stack(|stack| stack.remove(self.layer));
+    }
+}
+
+/// Tweak part of the current theme until the returned guard is dropped
+///
+/// The closure is layered over the base theme and every earlier override,
+/// so anything it leaves alone is inherited. It is re-applied when the base
+/// changes through [`set_theme`], and dropping a guard removes only its own
+/// layer, whatever order the guards are dropped in.
+///
+/// ```ignore
+/// let _errors = override_theme(|t| {
+///     t.primary = Color::Red;
+///     t.border.focused = Color::Red;
+/// });
+/// // Components rendered here see the red-shifted palette
+/// ```
+pub fn override_theme<F>(modify: F) -> ThemeOverrideGuard
+where
+    F: Fn(&mut Theme) + 'static,
+{
+    ThemeOverrideGuard {
+        layer: with_theme_stack(|stack| stack.push(modify)),
+    }
 }
 
 /// Execute a closure with a specific theme
@@ -750,23 +890,8 @@ pub fn with_theme<F, R>(theme: Theme, f: F) -> R
 where
     F: FnOnce(&Theme) -> R,
 {
-    struct ThemeRestoreGuard {
-        previous_theme: Option<Theme>,
-    }
-
-    impl Drop for ThemeRestoreGuard {
-        fn drop(&mut self) {
-            if let Some(previous_theme) = self.previous_theme.take() {
-                set_theme(previous_theme);
-            }
-        }
-    }
-
-    let old_theme = get_theme();
-    set_theme(theme.clone());
-    let _guard = ThemeRestoreGuard {
-        previous_theme: Some(old_theme),
-    };
+    let layer = theme.clone();
+    let _guard = override_theme(move |current| *current = layer.clone());
     f(&theme)
 }
 
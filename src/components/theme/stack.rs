@@ -0,0 +1,69 @@
+//! Base theme plus the overrides layered on top of it
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::Theme;
+
+type ThemeLayer = Box<dyn Fn(&mut Theme)>;
+
+/// Identifies an override pushed onto a [`ThemeStack`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LayerId(u64);
+
+/// A base theme and the [`override_theme`](super::override_theme) layers
+/// applied over it, in push order
+///
+/// Replacing the base keeps the layers, and a layer can be removed out of
+/// order without disturbing the others.
+pub(crate) struct ThemeStack {
+    base: Theme,
+    layers: Vec<(LayerId, ThemeLayer)>,
+    /// `base` with every layer applied
+    current: Theme,
+}
+
+impl ThemeStack {
+    pub(crate) fn new(base: Theme) -> Self {
+        Self {
+            current: base.clone(),
+            base,
+            layers: Vec::new(),
+        }
+    }
+
+    /// The theme with every layer applied
+    pub(crate) fn current(&self) -> &Theme {
+        &self.current
+    }
+
+    /// Replace the base theme, keeping the layers on top of it
+    pub(crate) fn set_base(&mut self, base: Theme) {
+        self.base = base;
+        self.rebuild();
+    }
+
+    /// Apply `layer` on top of the current layers
+    pub(crate) fn push(&mut self, layer: impl Fn(&mut Theme) + 'static) -> LayerId {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        let id = LayerId(NEXT_ID.fetch_add(1, Ordering::Relaxed));
+        layer(&mut self.current);
+        self.layers.push((id, Box::new(layer)));
+        id
+    }
+
+    /// Remove a layer; does nothing if it is not on this stack
+    pub(crate) fn remove(&mut self, id: LayerId) {
+        let before = self.layers.len();
+        self.layers.retain(|(layer_id, _)| *layer_id != id);
+        if self.layers.len() != before {
+            self.rebuild();
+        }
+    }
+
+    fn rebuild(&mut self) {
+        self.current = self.base.clone();
+        for (_, layer) in &self.layers {
+            layer(&mut self.current);
+        }
+    }
+}
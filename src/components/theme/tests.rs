@@ -108,6 +108,54 @@ fn test_with_theme_restores_after_panic() {
     assert_eq!(get_theme().name, original.name);
 }
 
+#[test]
+fn test_override_theme_layers_and_restores() {
+    set_theme(Theme::dark());
+    let base = get_theme();
+
+    {
+        let _outer = override_theme(|t| t.primary = Color::Red);
+        assert_eq!(get_theme().primary, Color::Red);
+        assert_eq!(get_theme().secondary, base.secondary);
+        assert_eq!(get_theme().name, "dark");
+
+        {
+            let _inner = override_theme(|t| t.border.focused = Color::Magenta);
+            let theme = get_theme();
+            assert_eq!(theme.primary, Color::Red);
+            assert_eq!(theme.border.focused, Color::Magenta);
+        }
+
+        assert_eq!(get_theme().primary, Color::Red);
+        assert_eq!(get_theme().border.focused, base.border.focused);
+    }
+
+    assert_eq!(get_theme().primary, base.primary);
+}
+
+#[test]
+fn test_override_theme_survives_set_theme_and_out_of_order_drops() {
+    set_theme(Theme::dark());
+
+    let outer = override_theme(|t| t.primary = Color::Red);
+    let inner = override_theme(|t| t.border.focused = Color::Magenta);
+
+    set_theme(Theme::light());
+    let theme = get_theme();
+    assert_eq!(theme.name, "light");
+    assert_eq!(theme.primary, Color::Red);
+    assert_eq!(theme.border.focused, Color::Magenta);
+
+    drop(outer);
+    let theme = get_theme();
+    assert_eq!(theme.primary, Theme::light().primary);
+    assert_eq!(theme.border.focused, Color::Magenta);
+
+    drop(inner);
+    assert_eq!(get_theme().border.focused, Theme::light().border.focused);
+    set_theme(Theme::dark());
+}
+
 #[test]
 fn test_theme_isolated_per_runtime_context() {
     use crate::runtime::{RuntimeContext, set_current_runtime};
@@ -169,3 +217,53 @@ fn test_semantic_fg_bg() {
     let bg = theme.semantic_bg(SemanticColor::Error);
     assert!(bg.starts_with("\x1b["));
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_theme_json_round_trip() {
+    for name in Theme::available_themes() {
+        let theme = Theme::by_name(name).unwrap();
+        let parsed = Theme::from_json(&theme.to_json()).unwrap();
+        assert_eq!(parsed.name, theme.name);
+        assert_eq!(parsed.primary, theme.primary);
+        assert_eq!(parsed.text.link, theme.text.link);
+        assert_eq!(
+            parsed.components.progress.fill,
+            theme.components.progress.fill
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_theme_json_partial_falls_back_to_dark() {
+    let theme = Theme::from_json(
+        r##"{
+            "name": "partial",
+            "primary": "#112233",
+            "text": { "link": "bright_blue" },
+            "components": { "list": { "selected_bg": "ansi(24)" } }
+        }"##,
+    )
+    .unwrap();
+    let dark = Theme::dark();
+
+    assert_eq!(theme.name, "partial");
+    assert_eq!(theme.primary, Color::Rgb(0x11, 0x22, 0x33));
+    assert_eq!(theme.secondary, dark.secondary);
+    assert_eq!(theme.text.link, Color::BrightBlue);
+    assert_eq!(theme.text.primary, dark.text.primary);
+    assert_eq!(theme.components.list.selected_bg, Color::Ansi256(24));
+    assert_eq!(
+        theme.components.list.item_text,
+        dark.components.list.item_text
+    );
+    assert_eq!(theme.components.input.cursor, dark.components.input.cursor);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_theme_json_invalid_color() {
+    let err = Theme::from_json(r#"{ "primary": "not-a-color" }"#).unwrap_err();
+    assert!(err.to_string().contains("unknown color name"));
+}
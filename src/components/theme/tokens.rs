@@ -1,8 +1,9 @@
 //! Design tokens and variant resolvers derived from a theme.
 
-use crate::core::{BorderStyle, Color};
+use crate::components::Box as RnkBox;
+use crate::core::{BorderStyle, Color, Element, Style};
 
-use super::Theme;
+use super::{Theme, get_theme};
 
 /// Shared component variants used by theme-aware components.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -111,6 +112,8 @@ pub struct FocusTokens {
     pub marker: &'static str,
     /// Whether focused labels should be bold.
     pub bold: bool,
+    /// Whether focused labels should use a brighter color.
+    pub brighten: bool,
 }
 
 /// State presentation tokens.
@@ -221,6 +224,7 @@ impl Default for FocusTokens {
         Self {
             marker: ">",
             bold: true,
+            brighten: false,
         }
     }
 }
@@ -250,7 +254,125 @@ impl Default for SymbolTokens {
     }
 }
 
+/// Unified focus-ring presentation consulted by focusable components.
+///
+/// Resolved from `theme.border.focused` and the focus design tokens so every
+/// component draws focus the same way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusStyle {
+    /// Border style while focused.
+    pub focused_border: BorderStyle,
+    /// Border color while focused.
+    pub focused_color: Color,
+    /// Border style while not focused.
+    pub rest_border: BorderStyle,
+    /// Border color while not focused.
+    pub rest_color: Color,
+    /// Whether focused labels are bold.
+    pub bold: bool,
+    /// Whether focused labels use a brighter color.
+    pub brighten: bool,
+}
+
+impl FocusStyle {
+    /// Resolve the focus style of a theme.
+    pub fn from_theme(theme: &Theme) -> Self {
+        let tokens = theme.design_tokens();
+        Self {
+            focused_border: tokens.borders.focus,
+            focused_color: theme.border.focused,
+            rest_border: tokens.borders.control,
+            rest_color: theme.border.default,
+            bold: tokens.focus.bold,
+            brighten: tokens.focus.brighten,
+        }
+    }
+
+    /// Set whether focused labels are bold.
+    pub fn bold(mut self, bold: bool) -> Self {
+        self.bold = bold;
+        self
+    }
+
+    /// Set whether focused labels use a brighter color.
+    pub fn brighten(mut self, brighten: bool) -> Self {
+        self.brighten = brighten;
+        self
+    }
+
+    /// Border style and color for the given focus state.
+    pub fn border_style(&self, focused: bool) -> Style {
+        let (border, color) = if focused {
+            (self.focused_border, self.focused_color)
+        } else {
+            (self.rest_border, self.rest_color)
+        };
+        let mut style = Style::new();
+        style.border_style = border;
+        style.border_color = Some(color);
+        style
+    }
+
+    /// Apply focused label emphasis (bold/brighten) to `style`.
+    pub fn label_style(&self, mut style: Style, focused: bool) -> Style {
+        if focused {
+            style.bold |= self.bold;
+            if self.brighten {
+                style.color = Some(style.color.map_or(Color::BrightWhite, brighten));
+            }
+        }
+        style
+    }
+
+    /// Surround `element` with the focus ring.
+    pub fn wrap(&self, element: Element, focused: bool) -> Element {
+        let border = self.border_style(focused);
+        let mut ring = RnkBox::new().border_style(border.border_style);
+        if let Some(color) = border.border_color {
+            ring = ring.border_color(color);
+        }
+        ring.child(element).into_element()
+    }
+}
+
+/// Border style for a focusable component, resolved from the current theme.
+///
+/// Focused components get `theme.border.focused` with the focus border
+/// token; unfocused ones get the default control border.
+pub fn focus_border_style(focused: bool) -> Style {
+    get_theme().focus_style().border_style(focused)
+}
+
+/// Brighter counterpart of a color: named colors map to their bright
+/// variants, other colors are blended toward white.
+fn brighten(color: Color) -> Color {
+    match color {
+        Color::Reset | Color::White => Color::BrightWhite,
+        Color::Black => Color::BrightBlack,
+        Color::Red => Color::BrightRed,
+        Color::Green => Color::BrightGreen,
+        Color::Yellow => Color::BrightYellow,
+        Color::Blue => Color::BrightBlue,
+        Color::Magenta => Color::BrightMagenta,
+        Color::Cyan => Color::BrightCyan,
+        Color::BrightBlack
+        | Color::BrightRed
+        | Color::BrightGreen
+        | Color::BrightYellow
+        | Color::BrightBlue
+        | Color::BrightMagenta
+        | Color::BrightCyan
+        | Color::BrightWhite => color,
+        Color::Rgb(..) | Color::Ansi256(_) => color.blend(&Color::Rgb(255, 255, 255), 0.3),
+    }
+}
+
 impl Theme {
+    /// Resolve the shared focus-ring presentation for this theme.
+    pub fn focus_style(&self) -> FocusStyle {
+        FocusStyle::from_theme(self)
+    }
+
     /// Resolve non-color design tokens for this theme.
     ///
     /// Tokens are derived rather than stored on `Theme` so adding this API does
@@ -348,4 +470,42 @@ mod tests {
         assert_eq!(style.border, theme.border.focused);
         assert!(style.bold);
     }
+
+    #[test]
+    fn test_focus_style_resolves_theme_border() {
+        let theme = Theme::dark();
+        let focus = theme.focus_style();
+
+        let focused = focus.border_style(true);
+        assert_eq!(focused.border_style, BorderStyle::Bold);
+        assert_eq!(focused.border_color, Some(theme.border.focused));
+
+        let rest = focus.border_style(false);
+        assert_eq!(rest.border_style, BorderStyle::Single);
+        assert_eq!(rest.border_color, Some(theme.border.default));
+    }
+
+    #[test]
+    fn test_focus_border_style_uses_current_theme() {
+        let style = super::super::with_theme(Theme::light(), |_| focus_border_style(true));
+        assert_eq!(style.border_color, Some(Theme::light().border.focused));
+    }
+
+    #[test]
+    fn test_focus_label_style_bold_and_brighten() {
+        let focus = Theme::dark().focus_style().brighten(true);
+        let base = Style::new().fg(Color::Cyan);
+
+        let focused = focus.label_style(base.clone(), true);
+        assert!(focused.bold);
+        assert_eq!(focused.color, Some(Color::BrightCyan));
+
+        let rest = focus.label_style(base, false);
+        assert!(!rest.bold);
+        assert_eq!(rest.color, Some(Color::Cyan));
+
+        let plain = Theme::dark().focus_style().label_style(Style::new(), true);
+        assert!(plain.bold);
+        assert_eq!(plain.color, None);
+    }
 }
@@ -3,16 +3,18 @@
 //! A high-performance scrollable view for displaying large text content,
 //! similar to Bubbles' viewport component.
 
-use crate::components::{Box as RnkBox, InteractionMode, InteractionOutcome, Text};
+use crate::components::{Box as RnkBox, InteractionMode, InteractionOutcome, Span, Text};
 use crate::core::{
-    AccessibilityProps, AccessibilityRole, BorderStyle, Color, Element, FlexDirection, Overflow,
+    AccessibilityProps, AccessibilityRole, BorderStyle, Color, Element, FlexDirection,
+    JustifyContent, Overflow,
 };
 
 use super::keymap::{ViewportAction, ViewportKeyMap};
-use super::state::ViewportState;
+use super::state::{Row, ViewportState};
 
 /// Style configuration for the viewport
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct ViewportStyle {
     /// Border style
     pub border: Option<BorderStyle>,
@@ -34,6 +36,12 @@ pub struct ViewportStyle {
     pub scrollbar_color: Option<Color>,
     /// Scrollbar track color
     pub scrollbar_track_color: Option<Color>,
+    /// Background of search matches
+    pub match_background: Color,
+    /// Background of the focused search match
+    pub current_match_background: Color,
+    /// Show a `3/17` match counter below the content while searching
+    pub match_indicator: bool,
 }
 
 impl Default for ViewportStyle {
@@ -49,6 +57,9 @@ impl Default for ViewportStyle {
             scrollbar: false,
             scrollbar_color: Some(Color::BrightBlack),
             scrollbar_track_color: None,
+            match_background: Color::Yellow,
+            current_match_background: Color::Cyan,
+            match_indicator: false,
         }
     }
 }
@@ -106,6 +117,24 @@ impl ViewportStyle {
         self.scrollbar_color = Some(color);
         self
     }
+
+    /// Set the background of search matches
+    pub fn match_background(mut self, color: Color) -> Self {
+        self.match_background = color;
+        self
+    }
+
+    /// Set the background of the focused search match
+    pub fn current_match_background(mut self, color: Color) -> Self {
+        self.current_match_background = color;
+        self
+    }
+
+    /// Show the match counter while searching
+    pub fn match_indicator(mut self, show: bool) -> Self {
+        self.match_indicator = show;
+        self
+    }
 }
 
 /// Viewport component for scrollable text content
@@ -198,6 +227,12 @@ impl<'a> Viewport<'a> {
         self
     }
 
+    /// Show the `3/17` match counter below the content while searching
+    pub fn match_indicator(mut self, show: bool) -> Self {
+        self.style.match_indicator = show;
+        self
+    }
+
     /// Get the keymap for external input handling
     pub fn get_keymap(&self) -> &ViewportKeyMap {
         &self.keymap
@@ -240,21 +275,22 @@ impl<'a> Viewport<'a> {
             0
         };
 
-        // Render visible lines
-        let y_offset = self.state.y_offset();
-        for (i, line) in self.state.visible_lines().enumerate() {
-            let global_line_num = y_offset + i + 1;
-
+        // Render visible rows
+        for row in self.state.visible_rows() {
             let line_element = if self.style.line_numbers {
-                // Line with line number
-                let num_str = format!("{:>width$} ", global_line_num, width = line_num_width);
+                // Line with line number (blank on wrapped continuation rows)
+                let num_str = if row.start == 0 {
+                    format!("{:>width$} ", row.line + 1, width = line_num_width)
+                } else {
+                    " ".repeat(line_num_width + 1)
+                };
                 let mut num_text = Text::new(&num_str);
                 if let Some(color) = self.style.line_number_color {
                     num_text = num_text.color(color);
                 }
                 num_text = num_text.dim();
 
-                let mut content_text = Text::new(line);
+                let mut content_text = self.row_text(row);
                 if let Some(color) = self.style.text_color {
                     content_text = content_text.color(color);
                 }
@@ -266,7 +302,7 @@ impl<'a> Viewport<'a> {
                     .into_element()
             } else {
                 // Line without line number
-                let mut text = Text::new(line);
+                let mut text = self.row_text(row);
                 if let Some(color) = self.style.text_color {
                     text = text.color(color);
                 }
@@ -277,22 +313,77 @@ impl<'a> Viewport<'a> {
         }
 
         // Add scrollbar if enabled
-        if self.style.scrollbar && !self.state.fits_in_viewport() {
+        let body = if self.style.scrollbar && !self.state.fits_in_viewport() {
             // Scrollbar is rendered separately
             let scrollbar = self.render_scrollbar();
-            return RnkBox::new()
+            RnkBox::new()
                 .flex_direction(FlexDirection::Row)
                 .child(container.into_element())
                 .child(scrollbar)
-                .into_element()
-                .with_accessibility(self.accessibility_props());
-        }
+        } else {
+            container
+        };
 
-        container
-            .into_element()
+        // Add the match counter below the content while searching
+        let body = match self.state.match_indicator() {
+            Some(indicator) if self.style.match_indicator => RnkBox::new()
+                .flex_direction(FlexDirection::Column)
+                .child(body.into_element())
+                .child(
+                    RnkBox::new()
+                        .width(width as i32)
+                        .justify_content(JustifyContent::FlexEnd)
+                        .child(Text::new(indicator).dim().into_element())
+                        .into_element(),
+                ),
+            _ => body,
+        };
+
+        body.into_element()
             .with_accessibility(self.accessibility_props())
     }
 
+    /// Build the text for one visible row, highlighting search matches
+    fn row_text(&self, row: Row) -> Text {
+        let line = &self.state.lines()[row.line];
+        let matches = self.state.matches();
+        let first = matches.partition_point(|m| (m.line, m.end) <= (row.line, row.start));
+        let on_row = matches[first..]
+            .iter()
+            .take_while(|m| m.line == row.line && m.start < row.end)
+            .collect::<Vec<_>>();
+        if on_row.is_empty() {
+            return Text::new(&line[row.start..row.end]);
+        }
+
+        let current = self.state.current_match_index();
+        let mut spans = Vec::with_capacity(on_row.len() * 2 + 1);
+        let mut cursor = row.start;
+        for (offset, found) in on_row.into_iter().enumerate() {
+            // A match may continue onto the next wrapped row
+            let start = found.start.max(row.start);
+            let end = found.end.min(row.end);
+            if start > cursor {
+                spans.push(Span::new(&line[cursor..start]));
+            }
+            let background = if current == Some(first + offset) {
+                self.style.current_match_background
+            } else {
+                self.style.match_background
+            };
+            spans.push(
+                Span::new(&line[start..end])
+                    .color(background.contrast_text())
+                    .background(background),
+            );
+            cursor = end;
+        }
+        if cursor < row.end {
+            spans.push(Span::new(&line[cursor..row.end]));
+        }
+        Text::spans(spans)
+    }
+
     fn accessibility_props(&self) -> AccessibilityProps {
         AccessibilityProps::new(AccessibilityRole::Viewport)
             .label("Viewport")
@@ -307,7 +398,7 @@ impl<'a> Viewport<'a> {
     /// Render the scrollbar
     fn render_scrollbar(&self) -> Element {
         let height = self.state.height();
-        let total_lines = self.state.total_line_count();
+        let total_lines = self.state.row_count();
 
         if total_lines == 0 || height == 0 {
             return RnkBox::new().into_element();
@@ -396,6 +487,13 @@ pub fn apply_viewport_action(state: &mut ViewportState, action: ViewportAction)
         ViewportAction::ScrollRight => state.scroll_right(1),
         ViewportAction::GotoLeft => state.goto_left(),
         ViewportAction::GotoRight => state.goto_right(),
+        ViewportAction::ToggleWrap => state.toggle_wrap(),
+        ViewportAction::NextMatch => {
+            state.next_match();
+        }
+        ViewportAction::PrevMatch => {
+            state.prev_match();
+        }
     }
 }
 
@@ -462,4 +560,68 @@ mod tests {
         assert_eq!(outcome, InteractionOutcome::Ignored);
         assert_eq!(state.y_offset(), 1);
     }
+
+    #[test]
+    fn test_viewport_highlights_search_matches() {
+        let mut state = ViewportState::new(20, 3);
+        state.set_content("a cat and a cat");
+        state.set_search("cat");
+        state.next_match();
+
+        let element = Viewport::new(&state).into_element();
+        let text = element.children.get(0).unwrap().spans.as_ref().unwrap();
+        let spans = &text[0].spans;
+        let contents: Vec<_> = spans.iter().map(|s| s.content.as_str()).collect();
+        assert_eq!(contents, vec!["a ", "cat", " and a ", "cat"]);
+        assert_eq!(spans[1].style.background_color, Some(Color::Yellow));
+        assert_eq!(spans[3].style.background_color, Some(Color::Cyan));
+
+        let mut keyed = state.clone();
+        handle_viewport_input(
+            &mut keyed,
+            "n",
+            &crate::hooks::Key::default(),
+            &ViewportKeyMap::default(),
+        );
+        assert_eq!(keyed.current_match_index(), Some(0));
+    }
+
+    #[test]
+    fn test_viewport_match_indicator() {
+        let mut state = ViewportState::new(12, 2);
+        state.set_content("x\ny x");
+        state.set_search("x");
+
+        let element = Viewport::new(&state).match_indicator(true).into_element();
+        let plain = crate::testing::TestRenderer::new(12, 3).render_to_plain(&element);
+        let lines: Vec<_> = plain.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["x", "y x", "         1/2"]);
+    }
+
+    #[test]
+    fn test_viewport_wrap_rows_and_highlights() {
+        let mut state = ViewportState::new(8, 4);
+        state.set_content("alpha beta gamma\nend");
+        state.set_wrap(true);
+        state.set_search("beta gamma");
+
+        let element = Viewport::new(&state).line_numbers(true).into_element();
+        let plain = crate::testing::TestRenderer::new(8, 4).render_to_plain(&element);
+        let lines: Vec<_> = plain.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["1 alpha", "  beta", "  gamma", "2 end"]);
+
+        // The match spans two rows and is highlighted on both
+        let second = element.children.get(1).unwrap().children.get(1).unwrap();
+        assert_eq!(second.text_content.as_deref(), Some("beta"));
+        assert_eq!(second.style.background_color, Some(Color::Cyan));
+
+        let mut keyed = state.clone();
+        handle_viewport_input(
+            &mut keyed,
+            "w",
+            &crate::hooks::Key::default(),
+            &ViewportKeyMap::default(),
+        );
+        assert!(!keyed.is_wrapping());
+    }
 }
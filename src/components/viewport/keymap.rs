@@ -34,6 +34,15 @@ pub struct ViewportKeyMap {
     pub goto_left: Vec<KeyBinding>,
     /// Go to right edge (default: End with Shift, $)
     pub goto_right: Vec<KeyBinding>,
+
+    /// Toggle soft-wrapping of long lines (default: w)
+    pub toggle_wrap: Vec<KeyBinding>,
+
+    // Search
+    /// Focus the next search match (default: n)
+    pub next_match: Vec<KeyBinding>,
+    /// Focus the previous search match (default: N)
+    pub prev_match: Vec<KeyBinding>,
 }
 
 impl Default for ViewportKeyMap {
@@ -55,6 +64,9 @@ impl Default for ViewportKeyMap {
             right: vec![KeyBinding::special(KeyType::Right), KeyBinding::char('l')],
             goto_left: vec![KeyBinding::char('0')],
             goto_right: vec![KeyBinding::char('$')],
+            toggle_wrap: vec![KeyBinding::char('w')],
+            next_match: vec![KeyBinding::char('n')],
+            prev_match: vec![KeyBinding::char('N')],
         }
     }
 }
@@ -80,6 +92,9 @@ impl ViewportKeyMap {
             right: vec![KeyBinding::special(KeyType::Right)],
             goto_left: vec![],
             goto_right: vec![],
+            toggle_wrap: vec![],
+            next_match: vec![],
+            prev_match: vec![],
         }
     }
 
@@ -98,6 +113,9 @@ impl ViewportKeyMap {
             right: vec![KeyBinding::char('l')],
             goto_left: vec![KeyBinding::char('0')],
             goto_right: vec![KeyBinding::char('$')],
+            toggle_wrap: vec![KeyBinding::char('w')],
+            next_match: vec![KeyBinding::char('n')],
+            prev_match: vec![KeyBinding::char('N')],
         }
     }
 
@@ -116,6 +134,9 @@ impl ViewportKeyMap {
             right: vec![],
             goto_left: vec![],
             goto_right: vec![],
+            toggle_wrap: vec![],
+            next_match: vec![],
+            prev_match: vec![],
         }
     }
 
@@ -134,6 +155,9 @@ impl ViewportKeyMap {
             (&self.right, ViewportAction::ScrollRight),
             (&self.goto_left, ViewportAction::GotoLeft),
             (&self.goto_right, ViewportAction::GotoRight),
+            (&self.toggle_wrap, ViewportAction::ToggleWrap),
+            (&self.next_match, ViewportAction::NextMatch),
+            (&self.prev_match, ViewportAction::PrevMatch),
         ];
         for (bindings, action) in checks {
             for binding in *bindings {
@@ -161,6 +185,9 @@ pub enum ViewportAction {
     ScrollRight,
     GotoLeft,
     GotoRight,
+    ToggleWrap,
+    NextMatch,
+    PrevMatch,
 }
 
 #[cfg(test)]
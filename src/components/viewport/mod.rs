@@ -5,10 +5,11 @@
 //!
 //! # Features
 //!
-//! - Vertical and horizontal scrolling
+//! - Vertical and horizontal scrolling, or soft-wrapping at word boundaries
 //! - Keyboard navigation (vim-style and arrow keys)
 //! - Mouse wheel support
 //! - Line numbers
+//! - Search with match highlighting
 //! - Scrollbar indicator
 //! - Customizable key bindings
 //!
@@ -63,6 +64,9 @@
 //! | `→` / `l` | Scroll right |
 //! | `0` | Go to left edge |
 //! | `$` | Go to right edge |
+//! | `w` | Toggle line wrapping |
+//! | `n` | Next search match |
+//! | `N` | Previous search match |
 
 mod component;
 mod keymap;
@@ -73,4 +77,4 @@ pub use component::{
     handle_viewport_input_with_mode,
 };
 pub use keymap::{KeyBinding, KeyType, Modifiers, ViewportAction, ViewportKeyMap};
-pub use state::ViewportState;
+pub use state::{SearchMatch, SearchOptions, ViewportState};
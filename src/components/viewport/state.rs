@@ -4,6 +4,69 @@
 
 use std::cmp;
 
+use regex_lite::{Regex, RegexBuilder};
+
+/// Options controlling how [`ViewportState::set_search_with`] matches
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchOptions {
+    /// Ignore case when matching
+    pub case_insensitive: bool,
+    /// Treat the query as a regular expression
+    ///
+    /// A query that fails to compile is searched for literally instead.
+    pub regex: bool,
+}
+
+impl SearchOptions {
+    /// Literal, case-sensitive search
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ignore case when matching
+    pub fn case_insensitive(mut self, enabled: bool) -> Self {
+        self.case_insensitive = enabled;
+        self
+    }
+
+    /// Treat the query as a regular expression
+    pub fn regex(mut self, enabled: bool) -> Self {
+        self.regex = enabled;
+        self
+    }
+}
+
+/// A search match inside the viewport content
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchMatch {
+    /// Line index
+    pub line: usize,
+    /// Byte offset where the match starts
+    pub start: usize,
+    /// Byte offset just past the end of the match
+    pub end: usize,
+}
+
+/// A visual row: the byte range of one content line shown on a single row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Row {
+    /// Content line index
+    pub(super) line: usize,
+    /// Byte offset where the row starts
+    pub(super) start: usize,
+    /// Byte offset where the row's visible text ends
+    pub(super) end: usize,
+}
+
+/// Active search: the compiled pattern and its matches
+#[derive(Debug, Clone)]
+struct Search {
+    query: String,
+    pattern: Regex,
+    matches: Vec<SearchMatch>,
+    current: Option<usize>,
+}
+
 /// Viewport state containing scroll position and content
 #[derive(Debug, Clone)]
 pub struct ViewportState {
@@ -30,6 +93,15 @@ pub struct ViewportState {
 
     /// Whether mouse wheel scrolling is enabled
     mouse_wheel_enabled: bool,
+
+    /// Active search, if any
+    search: Option<Search>,
+
+    /// Whether long lines soft-wrap instead of scrolling horizontally
+    wrap: bool,
+
+    /// Visual rows while wrapping (empty otherwise)
+    rows: Vec<Row>,
 }
 
 impl Default for ViewportState {
@@ -43,6 +115,9 @@ impl Default for ViewportState {
             max_line_width: 0,
             mouse_wheel_delta: 3,
             mouse_wheel_enabled: true,
+            search: None,
+            wrap: false,
+            rows: Vec::new(),
         }
     }
 }
@@ -72,14 +147,18 @@ impl ViewportState {
             .unwrap_or(0);
 
         // Clamp scroll position to valid range
+        self.rebuild_rows();
         self.clamp_scroll();
+        self.refresh_search();
     }
 
     /// Set content from pre-split lines
     pub fn set_lines(&mut self, lines: Vec<String>) {
         self.max_line_width = lines.iter().map(|l| unicode_width(l)).max().unwrap_or(0);
         self.lines = lines;
+        self.rebuild_rows();
         self.clamp_scroll();
+        self.refresh_search();
     }
 
     /// Append a line to the content
@@ -88,6 +167,16 @@ impl ViewportState {
         if line_width > self.max_line_width {
             self.max_line_width = line_width;
         }
+        if let Some(search) = &mut self.search {
+            let index = self.lines.len();
+            search
+                .matches
+                .extend(find_matches(&search.pattern, index, &line));
+        }
+        if self.wrap {
+            let index = self.lines.len();
+            self.rows.extend(wrap_rows(index, &line, self.width));
+        }
         self.lines.push(line);
     }
 
@@ -104,12 +193,18 @@ impl ViewportState {
         self.y_offset = 0;
         self.x_offset = 0;
         self.max_line_width = 0;
+        self.rows.clear();
+        self.refresh_search();
     }
 
     /// Set viewport dimensions
     pub fn set_size(&mut self, width: usize, height: usize) {
+        let width_changed = width != self.width;
         self.width = width;
         self.height = height;
+        if width_changed {
+            self.rewrap();
+        }
         self.clamp_scroll();
     }
 
@@ -121,7 +216,11 @@ impl ViewportState {
 
     /// Set viewport width
     pub fn set_width(&mut self, width: usize) {
+        let width_changed = width != self.width;
         self.width = width;
+        if width_changed {
+            self.rewrap();
+        }
         self.clamp_scroll();
     }
 
@@ -135,6 +234,35 @@ impl ViewportState {
         self.width
     }
 
+    // ========== Wrapping ==========
+
+    /// Soft-wrap long lines at word boundaries instead of scrolling horizontally
+    ///
+    /// While wrapping, offsets and counts refer to visual rows and the
+    /// horizontal offset stays at zero. The top visible line is kept in view
+    /// when switching modes.
+    pub fn set_wrap(&mut self, wrap: bool) {
+        if wrap == self.wrap {
+            return;
+        }
+        let top_line = self.row_line(self.y_offset);
+        self.wrap = wrap;
+        self.x_offset = 0;
+        self.rebuild_rows();
+        self.y_offset = self.row_of(top_line, 0);
+        self.clamp_scroll();
+    }
+
+    /// Switch between wrapping and horizontal scrolling
+    pub fn toggle_wrap(&mut self) {
+        self.set_wrap(!self.wrap);
+    }
+
+    /// Check whether long lines soft-wrap
+    pub fn is_wrapping(&self) -> bool {
+        self.wrap
+    }
+
     // ========== Scroll Position ==========
 
     /// Get current vertical scroll offset
@@ -303,19 +431,31 @@ impl ViewportState {
         self.lines.len()
     }
 
-    /// Number of visible lines (min of content and viewport height)
+    /// Number of rows the content occupies
+    ///
+    /// Equals [`total_line_count`](Self::total_line_count) unless wrapping,
+    /// where each wrapped line counts once per row.
+    pub fn row_count(&self) -> usize {
+        if self.wrap {
+            self.rows.len()
+        } else {
+            self.lines.len()
+        }
+    }
+
+    /// Number of visible rows (min of content and viewport height)
     pub fn visible_line_count(&self) -> usize {
-        cmp::min(self.lines.len().saturating_sub(self.y_offset), self.height)
+        cmp::min(self.row_count().saturating_sub(self.y_offset), self.height)
     }
 
     /// Check if content fits in viewport (no scrolling needed)
     pub fn fits_in_viewport(&self) -> bool {
-        self.lines.len() <= self.height
+        self.row_count() <= self.height
     }
 
     /// Check if horizontal scrolling is needed
     pub fn needs_horizontal_scroll(&self) -> bool {
-        self.max_line_width > self.width
+        !self.wrap && self.max_line_width > self.width
     }
 
     // ========== Content Access ==========
@@ -325,39 +465,254 @@ impl ViewportState {
         &self.lines
     }
 
-    /// Get visible lines (respecting scroll offset)
+    /// Get visible rows (respecting scroll offset and wrapping)
     pub fn visible_lines(&self) -> impl Iterator<Item = &str> {
-        self.lines
-            .iter()
-            .skip(self.y_offset)
-            .take(self.height)
-            .map(|s| s.as_str())
+        self.visible_rows()
+            .map(|row| &self.lines[row.line][row.start..row.end])
     }
 
-    /// Get visible lines with horizontal offset applied
+    /// Get visible rows with horizontal offset applied
     pub fn visible_lines_clipped(&self) -> Vec<String> {
-        self.lines
-            .iter()
-            .skip(self.y_offset)
-            .take(self.height)
+        self.visible_lines()
             .map(|line| clip_line(line, self.x_offset, self.width))
             .collect()
     }
 
+    /// Visible rows as byte ranges of content lines
+    pub(super) fn visible_rows(&self) -> impl Iterator<Item = Row> + '_ {
+        let end = cmp::min(self.y_offset + self.height, self.row_count());
+        (self.y_offset..end).map(|index| {
+            if self.wrap {
+                self.rows[index]
+            } else {
+                Row {
+                    line: index,
+                    start: 0,
+                    end: self.lines[index].len(),
+                }
+            }
+        })
+    }
+
     /// Get a specific line by index
     pub fn line(&self, index: usize) -> Option<&str> {
         self.lines.get(index).map(|s| s.as_str())
     }
 
+    // ========== Search ==========
+
+    /// Search the content for a literal, case-sensitive query
+    ///
+    /// Jumps to the first match at or below the current scroll position.
+    /// An empty query clears the search.
+    pub fn set_search(&mut self, query: &str) {
+        self.set_search_with(query, SearchOptions::default());
+    }
+
+    /// Search the content with explicit options
+    pub fn set_search_with(&mut self, query: &str, options: SearchOptions) {
+        if query.is_empty() {
+            self.search = None;
+            return;
+        }
+
+        let Some(pattern) = compile_search(query, options) else {
+            self.search = None;
+            return;
+        };
+        let matches = self
+            .lines
+            .iter()
+            .enumerate()
+            .flat_map(|(index, line)| find_matches(&pattern, index, line))
+            .collect::<Vec<_>>();
+        let first = matches
+            .iter()
+            .position(|m| m.line >= self.y_offset)
+            .or_else(|| (!matches.is_empty()).then_some(0));
+
+        self.search = Some(Search {
+            query: query.to_string(),
+            pattern,
+            matches,
+            current: None,
+        });
+        if let Some(index) = first {
+            self.focus_match(index);
+        }
+    }
+
+    /// Clear the active search
+    pub fn clear_search(&mut self) {
+        self.search = None;
+    }
+
+    /// The active search query, if any
+    pub fn search_query(&self) -> Option<&str> {
+        self.search.as_ref().map(|s| s.query.as_str())
+    }
+
+    /// All matches of the active search, in content order
+    pub fn matches(&self) -> &[SearchMatch] {
+        self.search.as_ref().map_or(&[], |s| &s.matches)
+    }
+
+    /// Number of matches of the active search
+    pub fn match_count(&self) -> usize {
+        self.matches().len()
+    }
+
+    /// Index of the focused match
+    pub fn current_match_index(&self) -> Option<usize> {
+        self.search.as_ref().and_then(|s| s.current)
+    }
+
+    /// The focused match
+    pub fn current_match(&self) -> Option<SearchMatch> {
+        let search = self.search.as_ref()?;
+        search.current.map(|index| search.matches[index])
+    }
+
+    /// Focus the next match, wrapping at the end (returns false if there are none)
+    pub fn next_match(&mut self) -> bool {
+        let Some(search) = &self.search else {
+            return false;
+        };
+        let count = search.matches.len();
+        if count == 0 {
+            return false;
+        }
+        let next = search.current.map_or(0, |index| (index + 1) % count);
+        self.focus_match(next);
+        true
+    }
+
+    /// Focus the previous match, wrapping at the start (returns false if there are none)
+    pub fn prev_match(&mut self) -> bool {
+        let Some(search) = &self.search else {
+            return false;
+        };
+        let count = search.matches.len();
+        if count == 0 {
+            return false;
+        }
+        let prev = search
+            .current
+            .map_or(count - 1, |index| (index + count - 1) % count);
+        self.focus_match(prev);
+        true
+    }
+
+    /// Match position indicator such as `3/17`
+    ///
+    /// Returns `None` when no search is active and `0/0` when the query has
+    /// no matches.
+    pub fn match_indicator(&self) -> Option<String> {
+        let search = self.search.as_ref()?;
+        let current = search.current.map_or(0, |index| index + 1);
+        Some(format!("{}/{}", current, search.matches.len()))
+    }
+
     // ========== Internal Helpers ==========
 
+    /// Focus a match and scroll it into view
+    fn focus_match(&mut self, index: usize) {
+        let Some(search) = &mut self.search else {
+            return;
+        };
+        search.current = Some(index);
+        let found = search.matches[index];
+
+        let row = self.row_of(found.line, found.start);
+        if row < self.y_offset || row >= self.y_offset + self.height {
+            self.y_offset = row.saturating_sub(self.height / 2);
+        }
+        if self.wrap {
+            self.clamp_scroll();
+            return;
+        }
+
+        let line = &self.lines[found.line];
+        let start = unicode_width(&line[..found.start]);
+        let end = unicode_width(&line[..found.end]);
+        if start < self.x_offset {
+            self.x_offset = start;
+        } else if end > self.x_offset + self.width {
+            self.x_offset = end.saturating_sub(self.width);
+        }
+        self.clamp_scroll();
+    }
+
+    /// Recompute matches after the content changed
+    fn refresh_search(&mut self) {
+        let Some(search) = &mut self.search else {
+            return;
+        };
+        search.matches = self
+            .lines
+            .iter()
+            .enumerate()
+            .flat_map(|(index, line)| find_matches(&search.pattern, index, line))
+            .collect();
+        search.current = search
+            .current
+            .filter(|_| !search.matches.is_empty())
+            .map(|index| index.min(search.matches.len() - 1));
+    }
+
+    /// Content line shown on a row
+    fn row_line(&self, row: usize) -> usize {
+        if self.wrap {
+            self.rows.get(row).map_or(0, |r| r.line)
+        } else {
+            row
+        }
+    }
+
+    /// Row showing a byte offset of a content line
+    fn row_of(&self, line: usize, byte: usize) -> usize {
+        if self.wrap {
+            self.rows
+                .partition_point(|r| (r.line, r.start) <= (line, byte))
+                .saturating_sub(1)
+        } else {
+            line
+        }
+    }
+
+    /// Recompute visual rows for the current content and width
+    fn rebuild_rows(&mut self) {
+        self.rows = if self.wrap {
+            self.lines
+                .iter()
+                .enumerate()
+                .flat_map(|(index, line)| wrap_rows(index, line, self.width))
+                .collect()
+        } else {
+            Vec::new()
+        };
+    }
+
+    /// Re-wrap after a width change, keeping the top visible line in view
+    fn rewrap(&mut self) {
+        if !self.wrap {
+            return;
+        }
+        let top_line = self.row_line(self.y_offset);
+        self.rebuild_rows();
+        self.y_offset = self.row_of(top_line, 0);
+    }
+
     /// Maximum valid Y offset
     fn max_y_offset(&self) -> usize {
-        self.lines.len().saturating_sub(self.height)
+        self.row_count().saturating_sub(self.height)
     }
 
     /// Maximum valid X offset
     fn max_x_offset(&self) -> usize {
+        if self.wrap {
+            return 0;
+        }
         self.max_line_width.saturating_sub(self.width)
     }
 
@@ -368,6 +723,83 @@ impl ViewportState {
     }
 }
 
+/// Compile a search query, falling back to a literal match for invalid regexes
+fn compile_search(query: &str, options: SearchOptions) -> Option<Regex> {
+    let build = |pattern: &str| {
+        RegexBuilder::new(pattern)
+            .case_insensitive(options.case_insensitive)
+            .build()
+            .ok()
+    };
+    let literal = || build(&regex_lite::escape(query));
+    if options.regex {
+        build(query).or_else(literal)
+    } else {
+        literal()
+    }
+}
+
+/// Non-empty matches of a pattern within one line
+fn find_matches<'a>(
+    pattern: &'a Regex,
+    line_index: usize,
+    line: &'a str,
+) -> impl Iterator<Item = SearchMatch> + 'a {
+    pattern
+        .find_iter(line)
+        .filter(|m| !m.is_empty())
+        .map(move |m| SearchMatch {
+            line: line_index,
+            start: m.start(),
+            end: m.end(),
+        })
+}
+
+/// Split a line into rows no wider than `width`, breaking after whitespace
+///
+/// Words longer than the width are broken mid-word. Whitespace at a break
+/// is dropped from the visible row.
+fn wrap_rows(line_index: usize, line: &str, width: usize) -> Vec<Row> {
+    use unicode_width::UnicodeWidthChar;
+
+    let row = |start: usize, end: usize| Row {
+        line: line_index,
+        start,
+        end: start + line[start..end].trim_end().len(),
+    };
+    if width == 0 {
+        return vec![row(0, line.len())];
+    }
+
+    let mut rows = Vec::new();
+    let mut start = 0;
+    let mut row_width = 0;
+    let mut break_at = None;
+    for (i, ch) in line.char_indices() {
+        let char_width = ch.width().unwrap_or(0);
+        if ch.is_whitespace() {
+            // Whitespace may hang past the edge; the row breaks after it
+            row_width += char_width;
+            break_at = Some(i + ch.len_utf8());
+            continue;
+        }
+        if row_width + char_width > width && i > start {
+            let next = break_at.filter(|&b| b > start).unwrap_or(i);
+            rows.push(row(start, next));
+            start = next;
+            row_width = unicode_width(&line[start..i]);
+            break_at = None;
+        }
+        row_width += char_width;
+    }
+    rows.push(Row {
+        line: line_index,
+        start,
+        end: line.len(),
+    });
+    rows
+}
+
 /// Calculate unicode display width of a string
 fn unicode_width(s: &str) -> usize {
     use unicode_width::UnicodeWidthStr;
@@ -532,4 +964,141 @@ mod tests {
         assert_eq!(clip_line("你a", 1, 2), "a");
         assert_eq!(clip_line("a你b", 2, 2), "b");
     }
+
+    #[test]
+    fn test_search_next_prev_wraps_and_scrolls() {
+        let mut state = ViewportState::new(80, 3);
+        state.set_content("foo\nbar\nfoo bar foo\nbaz\nbaz\nbaz\nfoo");
+
+        state.set_search("foo");
+        assert_eq!(state.match_count(), 4);
+        assert_eq!(state.match_indicator().as_deref(), Some("1/4"));
+        assert_eq!(
+            state.current_match(),
+            Some(SearchMatch {
+                line: 0,
+                start: 0,
+                end: 3
+            })
+        );
+
+        assert!(state.next_match());
+        assert!(state.next_match());
+        assert_eq!(
+            state.current_match().map(|m| (m.line, m.start)),
+            Some((2, 8))
+        );
+
+        assert!(state.next_match());
+        assert_eq!(state.match_indicator().as_deref(), Some("4/4"));
+        assert!(state.y_offset() <= 6 && 6 < state.y_offset() + 3);
+
+        assert!(state.next_match());
+        assert_eq!(state.current_match_index(), Some(0));
+        assert_eq!(state.y_offset(), 0);
+
+        assert!(state.prev_match());
+        assert_eq!(state.current_match_index(), Some(3));
+    }
+
+    #[test]
+    fn test_search_options_and_regex_fallback() {
+        let mut state = ViewportState::new(80, 10);
+        state.set_content("Error: a\nerror: b\n[x] (y)");
+
+        state.set_search("error");
+        assert_eq!(state.match_count(), 1);
+
+        state.set_search_with("error", SearchOptions::new().case_insensitive(true));
+        assert_eq!(state.match_count(), 2);
+
+        state.set_search_with("[a-b]$", SearchOptions::new().regex(true));
+        assert_eq!(state.match_count(), 2);
+
+        // An invalid regex is searched for literally
+        state.set_search_with("(y", SearchOptions::new().regex(true));
+        assert_eq!(
+            state.matches(),
+            &[SearchMatch {
+                line: 2,
+                start: 4,
+                end: 6
+            }]
+        );
+
+        state.set_search("missing");
+        assert_eq!(state.match_indicator().as_deref(), Some("0/0"));
+        assert!(!state.next_match());
+
+        state.set_search("");
+        assert_eq!(state.search_query(), None);
+        assert_eq!(state.match_indicator(), None);
+    }
+
+    #[test]
+    fn test_search_follows_content_changes() {
+        let mut state = ViewportState::new(10, 5);
+        state.set_content("one\ntwo");
+        state.set_search("o");
+        assert_eq!(state.match_count(), 2);
+
+        state.push_line("four".to_string());
+        assert_eq!(state.matches().last().map(|m| m.line), Some(2));
+
+        state.set_content("none here");
+        assert_eq!(state.match_count(), 1);
+
+        // Matches past the right edge scroll horizontally into view
+        state.set_content("0123456789abcdefXYZ");
+        state.set_search("XYZ");
+        assert_eq!(state.x_offset(), 9);
+    }
+
+    #[test]
+    fn test_wrap_breaks_at_word_boundaries() {
+        let mut state = ViewportState::new(10, 3);
+        state.set_content("the quick brown fox jumps\nshort\nabcdefghijklmno");
+        assert!(state.needs_horizontal_scroll());
+
+        state.set_wrap(true);
+        assert!(state.is_wrapping());
+        assert!(!state.needs_horizontal_scroll());
+        assert_eq!(state.total_line_count(), 3);
+        assert_eq!(state.row_count(), 6);
+
+        state.goto_bottom();
+        assert_eq!(state.y_offset(), 3);
+        let rows: Vec<_> = state.visible_lines().collect();
+        assert_eq!(rows, vec!["short", "abcdefghij", "klmno"]);
+
+        state.goto_top();
+        let rows: Vec<_> = state.visible_lines().collect();
+        assert_eq!(rows, vec!["the quick", "brown fox", "jumps"]);
+
+        state.goto_right();
+        assert_eq!(state.x_offset(), 0);
+    }
+
+    #[test]
+    fn test_toggle_wrap_keeps_top_line_and_rewraps_on_resize() {
+        let mut state = ViewportState::new(10, 2);
+        state.set_content("aaa bbb ccc ddd\nline two\nline three\nline four");
+        state.set_wrap(true);
+        state.set_y_offset(2);
+        assert_eq!(state.visible_lines().next(), Some("line two"));
+
+        state.toggle_wrap();
+        assert!(!state.is_wrapping());
+        assert_eq!(state.y_offset(), 1);
+
+        state.toggle_wrap();
+        assert_eq!(state.y_offset(), 2);
+
+        state.set_width(4);
+        assert_eq!(state.visible_lines().next(), Some("line"));
+        assert_eq!(state.row_count(), 11);
+
+        state.push_line("tail end".to_string());
+        assert_eq!(state.row_count(), 13);
+    }
 }
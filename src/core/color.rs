@@ -1,6 +1,8 @@
 //! Color types for terminal styling
 
 use crossterm::style::Color as CrosstermColor;
+use std::fmt;
+use std::str::FromStr;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Global flag for dark background detection
@@ -47,13 +49,68 @@ pub fn detect_background() -> Option<bool> {
     None
 }
 
+/// Set when the next app start should ask the terminal for its background
+static BACKGROUND_QUERY_REQUESTED: AtomicBool = AtomicBool::new(false);
+
 /// Initialize background detection
 ///
 /// Call this at application startup to auto-detect terminal background.
+/// The guess from [`detect_background`] applies right away. The app started
+/// next also asks the terminal for its actual background color with OSC 11
+/// and, if the terminal answers, corrects the guess through
+/// [`init_background_detection_from_reply`] without blocking input.
 pub fn init_background_detection() {
     if let Some(dark) = detect_background() {
         set_dark_background(dark);
     }
+    BACKGROUND_QUERY_REQUESTED.store(true, Ordering::SeqCst);
+}
+
+/// Initialize background detection from a terminal's OSC 11 reply
+///
+/// The reported color decides dark or light; an unparsable reply falls back
+/// to [`detect_background`].
+pub fn init_background_detection_from_reply(reply: &[u8]) {
+    let dark = parse_background_reply(reply)
+        .map(|color| color.is_dark())
+        .or_else(detect_background);
+    if let Some(dark) = dark {
+        set_dark_background(dark);
+    }
+}
+
+/// Take the pending request to query the terminal background
+pub(crate) fn take_background_query_request() -> bool {
+    BACKGROUND_QUERY_REQUESTED.swap(false, Ordering::SeqCst)
+}
+
+/// Parse a terminal's reply to an OSC 11 background query
+///
+/// Accepts `ESC ] 11 ; rgb:RRRR/GGGG/BBBB` terminated by BEL or ST, with
+/// 1-4 hex digits per channel (`rgba:` replies are accepted too). Bytes
+/// around the reply, such as other query responses, are ignored.
+pub fn parse_background_reply(reply: &[u8]) -> Option<Color> {
+    let start = reply.windows(5).position(|w| w == b"\x1b]11;")? + 5;
+    let body = &reply[start..];
+    let end = body.iter().position(|&b| b == 0x07 || b == 0x1b)?;
+    let body = std::str::from_utf8(&body[..end]).ok()?;
+    let (scheme, channels) = body.split_once(':')?;
+    if scheme != "rgb" && scheme != "rgba" {
+        return None;
+    }
+
+    let mut channels = channels.split('/').map(|hex| {
+        if hex.is_empty() || hex.len() > 4 {
+            return None;
+        }
+        let value = u32::from_str_radix(hex, 16).ok()?;
+        let max = (1u32 << (4 * hex.len())) - 1;
+        Some(((value * 255 + max / 2) / max) as u8)
+    });
+    let r = channels.next()??;
+    let g = channels.next()??;
+    let b = channels.next()??;
+    Some(Color::Rgb(r, g, b))
 }
 
 /// Color type supporting various color formats
@@ -90,6 +147,73 @@ pub enum Color {
     Rgb(u8, u8, u8),
 }
 
+/// Color space used when blending two colors
+///
+/// Averaging gamma-encoded sRGB values is fast but darkens midpoints (a
+/// red→green sweep passes through a muddy brown). Blending in linear light
+/// keeps perceived brightness even.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlendSpace {
+    /// Interpolate the gamma-encoded sRGB channels directly
+    #[default]
+    Srgb,
+    /// Convert to linear light, interpolate, then convert back
+    Linear,
+}
+
+/// Post-processing filter applied to already rendered cell colors
+///
+/// Used by [`Transform::color_filter`](crate::components::Transform::color_filter), e.g. to
+/// render an inactive panel in grayscale without restyling its children.
+/// `Color::Reset` (the terminal default) is left untouched by every filter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorFilter {
+    /// Replace each color with a gray of the same luminance
+    Grayscale,
+    /// Warm brownish tone, like an old photograph
+    Sepia,
+    /// Invert each RGB channel
+    Invert,
+    /// Blend each color halfway toward the given color
+    Tint(Color),
+}
+
+impl ColorFilter {
+    /// Apply the filter to a single color
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rnk::core::{Color, ColorFilter};
+    ///
+    /// assert_eq!(ColorFilter::Invert.apply(Color::Rgb(255, 0, 10)), Color::Rgb(0, 255, 245));
+    /// assert_eq!(ColorFilter::Grayscale.apply(Color::White), Color::Rgb(229, 229, 229));
+    /// ```
+    pub fn apply(&self, color: Color) -> Color {
+        if color == Color::Reset {
+            return color;
+        }
+        let (r, g, b) = color.to_rgb();
+        match *self {
+            ColorFilter::Grayscale => {
+                let level = linear_to_srgb(color.relative_luminance());
+                Color::Rgb(level, level, level)
+            }
+            ColorFilter::Sepia => {
+                let (r, g, b) = (r as f64, g as f64, b as f64);
+                let channel = |v: f64| v.round().min(255.0) as u8;
+                Color::Rgb(
+                    channel(0.393 * r + 0.769 * g + 0.189 * b),
+                    channel(0.349 * r + 0.686 * g + 0.168 * b),
+                    channel(0.272 * r + 0.534 * g + 0.131 * b),
+                )
+            }
+            ColorFilter::Invert => Color::Rgb(255 - r, 255 - g, 255 - b),
+            ColorFilter::Tint(tint) => color.blend(&tint, 0.5),
+        }
+    }
+}
+
 impl Color {
     /// Create a color from a hex string (e.g., "#ff0000" or "ff0000")
     ///
@@ -201,6 +325,220 @@ impl Color {
         }
     }
 
+    /// Relative luminance (WCAG 2.x) in the range `0.0..=1.0`
+    ///
+    /// Named and palette colors are resolved through the standard xterm
+    /// palette. `Color::Reset` is treated as black.
+    pub fn relative_luminance(&self) -> f64 {
+        let (r, g, b) = self.to_rgb();
+        0.2126 * srgb_to_linear(r) + 0.7152 * srgb_to_linear(g) + 0.0722 * srgb_to_linear(b)
+    }
+
+    /// Whether this color reads as dark
+    ///
+    /// True when white text would contrast better against it than black,
+    /// i.e. its relative luminance is below ~0.179.
+    pub fn is_dark(&self) -> bool {
+        self.relative_luminance() < 0.179
+    }
+
+    /// WCAG contrast ratio between two colors (`1.0` to `21.0`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rnk::core::Color;
+    ///
+    /// let ratio = Color::Rgb(0, 0, 0).contrast_ratio(&Color::Rgb(255, 255, 255));
+    /// assert!((ratio - 21.0).abs() < 0.01);
+    /// ```
+    pub fn contrast_ratio(&self, other: &Color) -> f64 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        let (lighter, darker) = if a > b { (a, b) } else { (b, a) };
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    /// Readable foreground for text drawn on this color
+    ///
+    /// Returns [`Color::Black`] or [`Color::White`], whichever contrasts more
+    /// with `self` as a background.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rnk::core::Color;
+    ///
+    /// assert_eq!(Color::Yellow.contrast_text(), Color::Black);
+    /// assert_eq!(Color::Rgb(20, 30, 90).contrast_text(), Color::White);
+    /// ```
+    pub fn contrast_text(&self) -> Color {
+        self.contrast_text_with(Color::White, Color::Black)
+    }
+
+    /// Pick whichever of `light` and `dark` contrasts more with this color
+    ///
+    /// Useful with theme text colors instead of plain black and white.
+    pub fn contrast_text_with(&self, light: Color, dark: Color) -> Color {
+        if self.contrast_ratio(&light) >= self.contrast_ratio(&dark) {
+            light
+        } else {
+            dark
+        }
+    }
+
+    /// Linearly blend toward `other` by `t` (`0.0` keeps `self`, `1.0` yields `other`)
+    ///
+    /// Both colors are resolved to RGB first, so the result is always
+    /// `Color::Rgb`. `t` is clamped to `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rnk::core::Color;
+    ///
+    /// let mid = Color::Rgb(0, 0, 0).blend(&Color::Rgb(200, 100, 50), 0.5);
+    /// assert_eq!(mid, Color::Rgb(100, 50, 25));
+    /// ```
+    pub fn blend(&self, other: &Color, t: f32) -> Color {
+        self.blend_in(other, t, BlendSpace::Srgb)
+    }
+
+    /// Blend toward `other` by `t` in the given color space
+    ///
+    /// [`BlendSpace::Linear`] gives visually even gradients at the cost of a
+    /// few extra float operations per channel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rnk::core::{BlendSpace, Color};
+    ///
+    /// let red = Color::Rgb(255, 0, 0);
+    /// let green = Color::Rgb(0, 255, 0);
+    /// assert_eq!(red.blend_in(&green, 0.5, BlendSpace::Srgb), Color::Rgb(128, 128, 0));
+    /// assert_eq!(red.blend_in(&green, 0.5, BlendSpace::Linear), Color::Rgb(188, 188, 0));
+    /// ```
+    pub fn blend_in(&self, other: &Color, t: f32, space: BlendSpace) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let (r1, g1, b1) = self.to_rgb();
+        let (r2, g2, b2) = other.to_rgb();
+        let mix = |a: u8, b: u8| match space {
+            BlendSpace::Srgb => (a as f32 + (b as f32 - a as f32) * t).round() as u8,
+            BlendSpace::Linear => {
+                let (a, b) = (srgb_to_linear(a), srgb_to_linear(b));
+                linear_to_srgb(a + (b - a) * t as f64)
+            }
+        };
+        Color::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+    }
+
+    /// Build an RGB color from HSL
+    ///
+    /// `hue` is in degrees and wraps around; `saturation` and `lightness`
+    /// are clamped to `0.0..=1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rnk::core::Color;
+    ///
+    /// assert_eq!(Color::from_hsl(120.0, 1.0, 0.5), Color::Rgb(0, 255, 0));
+    /// ```
+    pub fn from_hsl(hue: f64, saturation: f64, lightness: f64) -> Color {
+        let hue = hue.rem_euclid(360.0);
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        let h = hue / 60.0;
+        let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+        let (r, g, b) = match h as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = lightness - chroma / 2.0;
+        let to_u8 = |v: f64| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Color::Rgb(to_u8(r), to_u8(g), to_u8(b))
+    }
+
+    /// Convert to HSL as `(hue, saturation, lightness)`
+    ///
+    /// Hue is in degrees (`0.0..360.0`); saturation and lightness are in
+    /// `0.0..=1.0`. Named and palette colors are resolved through the
+    /// standard xterm palette first. Grays report a hue of `0.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rnk::core::Color;
+    ///
+    /// let (h, s, l) = Color::Rgb(0, 0, 255).to_hsl();
+    /// assert_eq!((h, s, l), (240.0, 1.0, 0.5));
+    /// ```
+    pub fn to_hsl(&self) -> (f64, f64, f64) {
+        let (r, g, b) = self.to_rgb();
+        let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let lightness = (max + min) / 2.0;
+        let delta = max - min;
+        if delta == 0.0 {
+            return (0.0, 0.0, lightness);
+        }
+
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        (hue, saturation, lightness)
+    }
+
+    /// Resolve a color to its RGB components using the standard xterm palette
+    ///
+    /// Named colors map to xterm's default 16-color values, `Ansi256` indices
+    /// go through the 6×6×6 cube and grayscale ramp, and `Rgb` passes through
+    /// unchanged. `Reset` has no color of its own and resolves to black.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rnk::core::Color;
+    ///
+    /// assert_eq!(Color::Cyan.to_rgb(), (0, 205, 205));
+    /// assert_eq!(Color::BrightBlack.to_rgb(), (127, 127, 127));
+    /// assert_eq!(Color::Rgb(1, 2, 3).to_rgb(), (1, 2, 3));
+    /// ```
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        match *self {
+            Color::Rgb(r, g, b) => (r, g, b),
+            Color::Ansi256(code) => ansi256_to_rgb(code),
+            Color::Reset | Color::Black => (0, 0, 0),
+            Color::Red => (205, 0, 0),
+            Color::Green => (0, 205, 0),
+            Color::Yellow => (205, 205, 0),
+            Color::Blue => (0, 0, 238),
+            Color::Magenta => (205, 0, 205),
+            Color::Cyan => (0, 205, 205),
+            Color::White => (229, 229, 229),
+            Color::BrightBlack => (127, 127, 127),
+            Color::BrightRed => (255, 0, 0),
+            Color::BrightGreen => (0, 255, 0),
+            Color::BrightYellow => (255, 255, 0),
+            Color::BrightBlue => (92, 92, 255),
+            Color::BrightMagenta => (255, 0, 255),
+            Color::BrightCyan => (0, 255, 255),
+            Color::BrightWhite => (255, 255, 255),
+        }
+    }
+
     /// Push ANSI color codes into a `Vec<u8>` for batch SGR sequence building.
     pub(crate) fn push_ansi_codes(&self, background: bool, codes: &mut Vec<u8>) {
         let base: u8 = if background { 40 } else { 30 };
@@ -239,6 +577,252 @@ impl Color {
     }
 }
 
+/// Error returned when parsing a [`Color`] from a string fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColorParseError {
+    /// The input was empty or only whitespace
+    Empty,
+    /// A `#` color that is not 3 or 6 hex digits
+    InvalidHex(String),
+    /// A malformed `rgb(r, g, b)` expression
+    InvalidRgb(String),
+    /// A malformed `ansi(N)` expression or an index above 255
+    InvalidAnsi(String),
+    /// A name that is not one of the standard color names
+    UnknownName(String),
+}
+
+impl fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty color string"),
+            Self::InvalidHex(s) => {
+                write!(f, "invalid hex color '{}': expected #RGB or #RRGGBB", s)
+            }
+            Self::InvalidRgb(s) => write!(
+                f,
+                "invalid rgb color '{}': expected rgb(r, g, b) with values 0-255",
+                s
+            ),
+            Self::InvalidAnsi(s) => write!(
+                f,
+                "invalid palette color '{}': expected ansi(N) with N in 0-255",
+                s
+            ),
+            Self::UnknownName(s) => write!(f, "unknown color name '{}'", s),
+        }
+    }
+}
+
+impl std::error::Error for ColorParseError {}
+
+impl FromStr for Color {
+    type Err = ColorParseError;
+
+    /// Parse a color from text (case-insensitive)
+    ///
+    /// Accepted forms:
+    /// - `#RRGGBB` and `#RGB`
+    /// - `rgb(r, g, b)` with components 0-255
+    /// - `ansi(N)` for 256-color palette indices
+    /// - standard names such as `red`, `bright_black` (also `bright-black`,
+    ///   `brightblack`), `gray`/`grey`, and `reset`/`default`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rnk::core::Color;
+    ///
+    /// assert_eq!("#f80".parse::<Color>(), Ok(Color::Rgb(255, 136, 0)));
+    /// assert_eq!("RGB(1, 2, 3)".parse::<Color>(), Ok(Color::Rgb(1, 2, 3)));
+    /// assert_eq!("Bright_Black".parse::<Color>(), Ok(Color::BrightBlack));
+    /// assert_eq!("ansi(42)".parse::<Color>(), Ok(Color::Ansi256(42)));
+    /// assert!("chartreuse-ish".parse::<Color>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.trim();
+        if input.is_empty() {
+            return Err(ColorParseError::Empty);
+        }
+        let lower = input.to_ascii_lowercase();
+
+        if let Some(hex) = lower.strip_prefix('#') {
+            return parse_hex_digits(hex).ok_or_else(|| ColorParseError::InvalidHex(input.into()));
+        }
+
+        if let Some(args) = function_args(&lower, "rgb") {
+            let parts: Vec<&str> = args.split(',').map(str::trim).collect();
+            return match parts.as_slice() {
+                [r, g, b] => match (r.parse(), g.parse(), b.parse()) {
+                    (Ok(r), Ok(g), Ok(b)) => Ok(Color::Rgb(r, g, b)),
+                    _ => Err(ColorParseError::InvalidRgb(input.into())),
+                },
+                _ => Err(ColorParseError::InvalidRgb(input.into())),
+            };
+        }
+
+        if let Some(args) = function_args(&lower, "ansi") {
+            return args
+                .trim()
+                .parse()
+                .map(Color::Ansi256)
+                .map_err(|_| ColorParseError::InvalidAnsi(input.into()));
+        }
+
+        let name: String = lower
+            .chars()
+            .filter(|c| !matches!(c, '_' | '-' | ' '))
+            .collect();
+        let color = match name.as_str() {
+            "reset" | "default" | "none" => Color::Reset,
+            "black" => Color::Black,
+            "red" => Color::Red,
+            "green" => Color::Green,
+            "yellow" => Color::Yellow,
+            "blue" => Color::Blue,
+            "magenta" | "purple" => Color::Magenta,
+            "cyan" => Color::Cyan,
+            "white" => Color::White,
+            "brightblack" | "gray" | "grey" => Color::BrightBlack,
+            "brightred" => Color::BrightRed,
+            "brightgreen" => Color::BrightGreen,
+            "brightyellow" => Color::BrightYellow,
+            "brightblue" => Color::BrightBlue,
+            "brightmagenta" | "brightpurple" => Color::BrightMagenta,
+            "brightcyan" => Color::BrightCyan,
+            "brightwhite" => Color::BrightWhite,
+            _ => return Err(ColorParseError::UnknownName(input.into())),
+        };
+        Ok(color)
+    }
+}
+
+/// Parse 3 or 6 hex digits (without `#`) into an RGB color
+fn parse_hex_digits(hex: &str) -> Option<Color> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    match hex.len() {
+        6 => Color::try_hex(hex),
+        3 => {
+            let digit = |i: usize| u8::from_str_radix(&hex[i..=i], 16).ok().map(|v| v * 17);
+            Some(Color::Rgb(digit(0)?, digit(1)?, digit(2)?))
+        }
+        _ => None,
+    }
+}
+
+/// Extract the argument text of `name(...)`, if `input` has that shape
+fn function_args<'a>(input: &'a str, name: &str) -> Option<&'a str> {
+    input
+        .strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')
+}
+
+impl fmt::Display for Color {
+    /// Format the color in the textual form accepted by [`Color::from_str`]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Reset => write!(f, "reset"),
+            Color::Black => write!(f, "black"),
+            Color::Red => write!(f, "red"),
+            Color::Green => write!(f, "green"),
+            Color::Yellow => write!(f, "yellow"),
+            Color::Blue => write!(f, "blue"),
+            Color::Magenta => write!(f, "magenta"),
+            Color::Cyan => write!(f, "cyan"),
+            Color::White => write!(f, "white"),
+            Color::BrightBlack => write!(f, "bright_black"),
+            Color::BrightRed => write!(f, "bright_red"),
+            Color::BrightGreen => write!(f, "bright_green"),
+            Color::BrightYellow => write!(f, "bright_yellow"),
+            Color::BrightBlue => write!(f, "bright_blue"),
+            Color::BrightMagenta => write!(f, "bright_magenta"),
+            Color::BrightCyan => write!(f, "bright_cyan"),
+            Color::BrightWhite => write!(f, "bright_white"),
+            Color::Ansi256(code) => write!(f, "ansi({})", code),
+            Color::Rgb(r, g, b) => write!(f, "#{:02x}{:02x}{:02x}", r, g, b),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Color {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        text.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Decode an sRGB channel to linear light (`0.0..=1.0`)
+fn srgb_to_linear(c: u8) -> f64 {
+    let c = c as f64 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Encode a linear-light value back to an sRGB channel
+fn linear_to_srgb(c: f64) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let encoded = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (encoded * 255.0).round() as u8
+}
+
+/// Resolve a 256-color palette index to RGB using the xterm palette
+fn ansi256_to_rgb(code: u8) -> (u8, u8, u8) {
+    const BASIC: [Color; 16] = [
+        Color::Black,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::White,
+        Color::BrightBlack,
+        Color::BrightRed,
+        Color::BrightGreen,
+        Color::BrightYellow,
+        Color::BrightBlue,
+        Color::BrightMagenta,
+        Color::BrightCyan,
+        Color::BrightWhite,
+    ];
+    const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+    match code {
+        0..=15 => BASIC[code as usize].to_rgb(),
+        16..=231 => {
+            let index = code - 16;
+            (
+                CUBE_LEVELS[(index / 36) as usize],
+                CUBE_LEVELS[((index / 6) % 6) as usize],
+                CUBE_LEVELS[(index % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + (code - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
 /// Adaptive color that changes based on terminal background
 ///
 /// This allows specifying different colors for light and dark backgrounds,
@@ -398,6 +982,73 @@ mod tests {
             .unwrap_or_else(|err| err.into_inner())
     }
 
+    #[test]
+    fn test_parse_background_reply() {
+        assert_eq!(
+            parse_background_reply(b"\x1b]11;rgb:ffff/ffff/ffff\x07"),
+            Some(Color::Rgb(255, 255, 255))
+        );
+        assert_eq!(
+            parse_background_reply(b"\x1b]11;rgb:1e1e/2020/3030\x1b\\\x1b[3;1R"),
+            Some(Color::Rgb(30, 32, 48))
+        );
+        assert_eq!(
+            parse_background_reply(b"\x1b]11;rgba:f/80/000/ffff\x07"),
+            Some(Color::Rgb(255, 128, 0))
+        );
+        assert_eq!(parse_background_reply(b"\x1b[3;1R"), None);
+        assert_eq!(parse_background_reply(b"\x1b]11;rgb:ff/ff\x07"), None);
+        assert_eq!(parse_background_reply(b"\x1b]11;rgb:ff/ff/ff"), None);
+    }
+
+    #[test]
+    fn test_is_dark() {
+        assert!(Color::Rgb(30, 32, 48).is_dark());
+        assert!(Color::Rgb(100, 100, 100).is_dark());
+        assert!(!Color::Rgb(253, 246, 227).is_dark());
+        assert!(!Color::White.is_dark());
+    }
+
+    #[test]
+    fn test_contrast_text() {
+        assert_eq!(Color::Rgb(0, 0, 0).contrast_text(), Color::White);
+        assert_eq!(Color::Blue.contrast_text(), Color::White);
+        assert_eq!(Color::Rgb(253, 246, 227).contrast_text(), Color::Black);
+        assert_eq!(Color::BrightGreen.contrast_text(), Color::Black);
+
+        let light = Color::Rgb(220, 220, 220);
+        let dark = Color::Rgb(40, 40, 40);
+        assert_eq!(Color::BrightYellow.contrast_text_with(light, dark), dark);
+        assert_eq!(Color::Ansi256(17).contrast_text_with(light, dark), light);
+    }
+
+    #[test]
+    fn test_hsl_round_trip() {
+        for color in [
+            Color::Rgb(255, 0, 0),
+            Color::Rgb(30, 144, 255),
+            Color::Rgb(120, 120, 120),
+            Color::Rgb(250, 200, 10),
+        ] {
+            let (h, s, l) = color.to_hsl();
+            assert_eq!(Color::from_hsl(h, s, l), color);
+        }
+    }
+
+    #[test]
+    fn test_from_hsl_wraps_hue_and_clamps() {
+        assert_eq!(Color::from_hsl(-120.0, 1.0, 0.5), Color::Rgb(0, 0, 255));
+        assert_eq!(Color::from_hsl(480.0, 1.0, 0.5), Color::Rgb(0, 255, 0));
+        assert_eq!(Color::from_hsl(0.0, 2.0, 1.5), Color::Rgb(255, 255, 255));
+    }
+
+    #[test]
+    fn test_to_hsl_resolves_named_colors() {
+        let (h, s, l) = Color::BrightRed.to_hsl();
+        assert_eq!((h, s, l), (0.0, 1.0, 0.5));
+        assert_eq!(Color::Black.to_hsl(), (0.0, 0.0, 0.0));
+    }
+
     #[test]
     fn test_hex_color() {
         assert_eq!(Color::hex("#ff0000"), Color::Rgb(255, 0, 0));
@@ -539,6 +1190,138 @@ mod tests {
         assert_eq!(Color::Reset.to_ansi_fg(), "\x1b[0m");
     }
 
+    #[test]
+    fn test_from_str_hex() {
+        assert_eq!("#ff0000".parse(), Ok(Color::Rgb(255, 0, 0)));
+        assert_eq!("#AbCdEf".parse(), Ok(Color::Rgb(0xab, 0xcd, 0xef)));
+        assert_eq!("#fff".parse(), Ok(Color::Rgb(255, 255, 255)));
+        assert_eq!("  #0a0  ".parse(), Ok(Color::Rgb(0, 170, 0)));
+        assert_eq!(
+            "#ff00".parse::<Color>(),
+            Err(ColorParseError::InvalidHex("#ff00".into()))
+        );
+        assert!("#ggg".parse::<Color>().is_err());
+        assert!("#fé0".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_from_str_rgb_and_ansi() {
+        assert_eq!("rgb(10,20,30)".parse(), Ok(Color::Rgb(10, 20, 30)));
+        assert_eq!("RGB( 10 , 20 , 30 )".parse(), Ok(Color::Rgb(10, 20, 30)));
+        assert!(matches!(
+            "rgb(256, 0, 0)".parse::<Color>(),
+            Err(ColorParseError::InvalidRgb(_))
+        ));
+        assert!("rgb(1, 2)".parse::<Color>().is_err());
+
+        assert_eq!("ansi(196)".parse(), Ok(Color::Ansi256(196)));
+        assert_eq!("ANSI( 7 )".parse(), Ok(Color::Ansi256(7)));
+        assert!(matches!(
+            "ansi(300)".parse::<Color>(),
+            Err(ColorParseError::InvalidAnsi(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_str_names() {
+        assert_eq!("red".parse(), Ok(Color::Red));
+        assert_eq!("RED".parse(), Ok(Color::Red));
+        assert_eq!("bright_black".parse(), Ok(Color::BrightBlack));
+        assert_eq!("Bright-Cyan".parse(), Ok(Color::BrightCyan));
+        assert_eq!("brightwhite".parse(), Ok(Color::BrightWhite));
+        assert_eq!("grey".parse(), Ok(Color::BrightBlack));
+        assert_eq!("reset".parse(), Ok(Color::Reset));
+        assert_eq!("".parse::<Color>(), Err(ColorParseError::Empty));
+        assert_eq!(
+            "teal".parse::<Color>(),
+            Err(ColorParseError::UnknownName("teal".into()))
+        );
+    }
+
+    #[test]
+    fn test_color_parse_error_display() {
+        let err = "rgb(x)".parse::<Color>().unwrap_err();
+        assert!(err.to_string().contains("rgb(r, g, b)"));
+    }
+
+    #[test]
+    fn test_display_round_trips() {
+        let colors = [
+            Color::Reset,
+            Color::Cyan,
+            Color::BrightBlack,
+            Color::BrightWhite,
+            Color::Ansi256(42),
+            Color::Rgb(1, 171, 255),
+        ];
+        for color in colors {
+            assert_eq!(color.to_string().parse(), Ok(color));
+        }
+        assert_eq!(Color::Rgb(255, 0, 16).to_string(), "#ff0010");
+    }
+
+    #[test]
+    fn test_relative_luminance_bounds() {
+        assert!(Color::Rgb(0, 0, 0).relative_luminance().abs() < 1e-9);
+        assert!((Color::BrightWhite.relative_luminance() - 1.0).abs() < 1e-9);
+        assert!(Color::Yellow.relative_luminance() > Color::Blue.relative_luminance());
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        let ratio = Color::Black.contrast_ratio(&Color::BrightWhite);
+        assert!((ratio - 21.0).abs() < 0.01);
+        assert!((Color::Red.contrast_ratio(&Color::Red) - 1.0).abs() < 1e-9);
+        assert_eq!(
+            Color::Blue.contrast_ratio(&Color::White),
+            Color::White.contrast_ratio(&Color::Blue)
+        );
+    }
+
+    #[test]
+    fn test_ansi256_palette_resolution() {
+        assert_eq!(ansi256_to_rgb(1), (205, 0, 0));
+        assert_eq!(ansi256_to_rgb(16), (0, 0, 0));
+        assert_eq!(ansi256_to_rgb(196), (255, 0, 0));
+        assert_eq!(ansi256_to_rgb(231), (255, 255, 255));
+        assert_eq!(ansi256_to_rgb(232), (8, 8, 8));
+        assert_eq!(ansi256_to_rgb(255), (238, 238, 238));
+    }
+
+    #[test]
+    fn test_color_filters() {
+        assert_eq!(
+            ColorFilter::Grayscale.apply(Color::Rgb(255, 0, 0)),
+            Color::Rgb(127, 127, 127)
+        );
+        assert_eq!(
+            ColorFilter::Sepia.apply(Color::Rgb(100, 100, 100)),
+            Color::Rgb(135, 120, 94)
+        );
+        assert_eq!(
+            ColorFilter::Tint(Color::Rgb(0, 0, 200)).apply(Color::Black),
+            Color::Rgb(0, 0, 100)
+        );
+        for filter in [
+            ColorFilter::Grayscale,
+            ColorFilter::Sepia,
+            ColorFilter::Invert,
+        ] {
+            assert_eq!(filter.apply(Color::Reset), Color::Reset);
+        }
+    }
+
+    #[test]
+    fn test_to_rgb_named_colors_match_xterm() {
+        assert_eq!(Color::Black.to_rgb(), (0, 0, 0));
+        assert_eq!(Color::Blue.to_rgb(), (0, 0, 238));
+        assert_eq!(Color::White.to_rgb(), (229, 229, 229));
+        assert_eq!(Color::BrightBlue.to_rgb(), (92, 92, 255));
+        assert_eq!(Color::BrightWhite.to_rgb(), (255, 255, 255));
+        assert_eq!(Color::Rgb(12, 34, 56).to_rgb(), (12, 34, 56));
+        assert_eq!(Color::Ansi256(9).to_rgb(), Color::BrightRed.to_rgb());
+    }
+
     #[test]
     fn test_to_ansi_bg() {
         assert_eq!(Color::Red.to_ansi_bg(), "\x1b[41m");
@@ -10,9 +10,11 @@ mod element;
 mod style;
 mod vnode;
 
+pub(crate) use color::take_background_query_request;
 pub use color::{
-    AdaptiveColor, Color, adaptive_colors, detect_background, init_background_detection,
-    is_dark_background, set_dark_background,
+    AdaptiveColor, BlendSpace, Color, ColorFilter, ColorParseError, adaptive_colors,
+    detect_background, init_background_detection, init_background_detection_from_reply,
+    is_dark_background, parse_background_reply, set_dark_background,
 };
 #[doc(hidden)]
 pub use component::{Component, ComponentInstance, StatelessComponent};
@@ -20,8 +22,9 @@ pub use element::{
     AccessibilityProps, AccessibilityRole, Children, Element, ElementId, ElementType,
 };
 pub use style::{
-    AlignItems, AlignSelf, BorderStyle, Dimension, Display, Edges, FlexDirection, JustifyContent,
-    Overflow, Position, Style, TextWrap,
+    AlignItems, AlignSelf, Anchor, AnchorPlacement, Backdrop, BorderStyle, Dimension, Display,
+    Edges, FlexDirection, GridTrack, JustifyContent, Overflow, Position, ShadowStyle, Style,
+    StyleTransition, TextOverflow, TextWrap, TransitionProperty,
 };
 #[doc(hidden)]
 pub use vnode::{NodeKey, Props, VNode, VNodeType};
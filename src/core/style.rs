@@ -1,6 +1,8 @@
 //! Style system for elements
 
-use crate::core::Color;
+use crate::animation::Easing;
+use crate::core::{Color, ColorFilter, ElementId};
+use std::time::Duration;
 
 /// Generate `impl From<LocalEnum> for taffy::TaffyEnum` for enums with matching variant names.
 macro_rules! impl_taffy_from {
@@ -90,10 +92,11 @@ impl_taffy_from!(JustifyContent => taffy::JustifyContent {
 pub enum Display {
     #[default]
     Flex,
+    Grid,
     None,
 }
 
-impl_taffy_from!(Display => taffy::Display { Flex, None });
+impl_taffy_from!(Display => taffy::Display { Flex, Grid, None });
 
 /// Position type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -127,6 +130,20 @@ pub enum TextWrap {
     TruncateEnd,
 }
 
+/// How text that does not fit its laid-out width is shown
+///
+/// With [`TextOverflow::Ellipsis`] the text is kept on a single line per
+/// source line and cut with `…` at the element's final layout width, so it
+/// shrinks along with its container instead of wrapping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextOverflow {
+    /// Overflowing text is clipped (or wrapped, for text that wraps)
+    #[default]
+    Clip,
+    /// Text stays on one line and is truncated with an ellipsis
+    Ellipsis,
+}
+
 /// Border style
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum BorderStyle {
@@ -204,6 +221,131 @@ macro_rules! impl_numeric_from {
 
 impl_numeric_from!(Dimension::Points, u16, i32, f32);
 
+/// Drop shadow drawn offset to the bottom-right of a box
+///
+/// The shadow darkens the cells beneath it rather than painting over them:
+/// text stays readable but dimmed, and backgrounds are tinted toward
+/// `color`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShadowStyle {
+    /// Offset in cells as `(columns right, rows down)`
+    pub offset: (u16, u16),
+    /// Color the covered cells are tinted toward
+    pub color: Color,
+    /// Soften the outer edge with a lighter one-cell fringe
+    pub blur: bool,
+}
+
+impl Default for ShadowStyle {
+    fn default() -> Self {
+        // Two columns per row keeps the shadow visually square
+        Self {
+            offset: (2, 1),
+            color: Color::BrightBlack,
+            blur: false,
+        }
+    }
+}
+
+/// Scrim drawn over everything beneath a box, across the box's whole rect
+///
+/// Like [`ShadowStyle`], it darkens the cells already drawn instead of
+/// hiding them, so stacked backdrops dim the content below progressively.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backdrop {
+    /// Color the covered cells are tinted toward
+    pub color: Color,
+    /// Tint strength (`0.0..=1.0`)
+    pub strength: f32,
+}
+
+impl Default for Backdrop {
+    fn default() -> Self {
+        Self {
+            color: Color::Black,
+            strength: 0.4,
+        }
+    }
+}
+
+/// Side of the anchor element an overlay is placed on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnchorPlacement {
+    /// Above the anchor, left edges aligned
+    Top,
+    /// Below the anchor, left edges aligned
+    #[default]
+    Bottom,
+    /// Left of the anchor, top edges aligned
+    Left,
+    /// Right of the anchor, top edges aligned
+    Right,
+}
+
+/// Positions an overlay next to another element's computed rect
+///
+/// The rect is resolved during rendering, after layout, so overlays follow
+/// their anchor without hand-computed coordinates. Anchored elements are
+/// painted after the rest of the tree and flip to the opposite side when
+/// they would not fit on screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Anchor {
+    /// Element the overlay is placed against
+    pub target: ElementId,
+    /// Side of the target to place the overlay on
+    pub placement: AnchorPlacement,
+    /// Cells left between the target and the overlay
+    pub gap: u16,
+}
+
+impl Anchor {
+    /// Anchor below `target` with no gap
+    pub fn new(target: ElementId) -> Self {
+        Self {
+            target,
+            placement: AnchorPlacement::default(),
+            gap: 0,
+        }
+    }
+
+    /// Set the side of the target to place the overlay on
+    pub fn placement(mut self, placement: AnchorPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Set the cells left between the target and the overlay
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+}
+
+/// Track size for `Display::Grid` column templates
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridTrack {
+    /// Fixed number of cells
+    Points(f32),
+    /// Percentage of the container width (0-100)
+    Percent(f32),
+    /// Share of the remaining space, weighted like CSS `fr`
+    Fr(f32),
+    /// Sized to fit the content
+    Auto,
+}
+
+impl From<GridTrack> for taffy::TrackSizingFunction {
+    fn from(track: GridTrack) -> Self {
+        use taffy::style_helpers::{auto, fr, length, percent};
+        match track {
+            GridTrack::Points(v) => length(v),
+            GridTrack::Percent(v) => percent(v / 100.0),
+            GridTrack::Fr(v) => fr(v),
+            GridTrack::Auto => auto(),
+        }
+    }
+}
+
 /// Edge values for padding/margin
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct Edges {
@@ -265,6 +407,43 @@ macro_rules! impl_edges_from {
 
 impl_edges_from!(f32, u16, i32);
 
+/// Auto-placed grid line spanning `span` tracks
+fn grid_span(span: u16) -> taffy::Line<taffy::GridPlacement> {
+    if span > 1 {
+        taffy::style_helpers::span(span)
+    } else {
+        taffy::Line::default()
+    }
+}
+
+/// A style property that can be animated with [`Style::transition`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransitionProperty {
+    /// Foreground color
+    Color,
+    /// Background color
+    BackgroundColor,
+    /// Border color
+    BorderColor,
+    /// Width, when it is a fixed number of cells
+    Width,
+    /// Height, when it is a fixed number of cells
+    Height,
+    /// Padding on all four edges
+    Padding,
+}
+
+/// How one property animates when its value changes between renders
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StyleTransition {
+    /// Property that animates
+    pub property: TransitionProperty,
+    /// Time from the old value to the new one; zero switches instantly
+    pub duration: Duration,
+    /// Curve applied to the progress through `duration`
+    pub easing: Easing,
+}
+
 /// Complete style definition
 #[derive(Debug, Clone, PartialEq)]
 pub struct Style {
@@ -295,6 +474,11 @@ pub struct Style {
     pub row_gap: Option<f32>,
     pub column_gap: Option<f32>,
 
+    // Grid
+    pub grid_template_columns: Vec<GridTrack>,
+    pub grid_column_span: u16,
+    pub grid_row_span: u16,
+
     // Size
     pub width: Dimension,
     pub height: Dimension,
@@ -316,6 +500,24 @@ pub struct Style {
     pub border_left: bool,
     pub border_right: bool,
 
+    // Shadow
+    pub shadow: Option<ShadowStyle>,
+    pub backdrop: Option<Backdrop>,
+    /// Filter applied to the colors of everything drawn inside this box
+    pub color_filter: Option<ColorFilter>,
+    /// URL the cells drawn inside this box link to (OSC 8)
+    pub hyperlink: Option<String>,
+    /// Mirror everything drawn inside this box left to right
+    pub mirror_horizontal: bool,
+    /// Flip everything drawn inside this box upside down
+    pub flip_vertical: bool,
+
+    // Overlay anchor
+    pub anchor: Option<Anchor>,
+
+    /// Properties animated when they change between renders
+    pub transitions: Vec<StyleTransition>,
+
     // Colors
     pub color: Option<Color>,
     pub background_color: Option<Color>,
@@ -326,8 +528,16 @@ pub struct Style {
     pub underline: bool,
     pub strikethrough: bool,
     pub dim: bool,
+    /// Graded fade toward the background (`0.0` opaque, `1.0` fully faded)
+    ///
+    /// `None` leaves the fade to [`Style::merge`]; an explicit `Some(0.0)`
+    /// clears a fade inherited from a parent.
+    pub faint_level: Option<f32>,
     pub inverse: bool,
+    /// Draw the text's rows as double-width lines (DECDWL)
+    pub double_width: bool,
     pub text_wrap: TextWrap,
+    pub text_overflow: TextOverflow,
 
     // Overflow
     pub overflow_x: Overflow,
@@ -366,6 +576,9 @@ impl Style {
             gap: 0.0,
             row_gap: None,
             column_gap: None,
+            grid_template_columns: Vec::new(),
+            grid_column_span: 1,
+            grid_row_span: 1,
             width: Dimension::default(),
             height: Dimension::default(),
             min_width: Dimension::default(),
@@ -383,6 +596,14 @@ impl Style {
             border_bottom: true,
             border_left: true,
             border_right: true,
+            shadow: None,
+            backdrop: None,
+            color_filter: None,
+            hyperlink: None,
+            mirror_horizontal: false,
+            flip_vertical: false,
+            anchor: None,
+            transitions: Vec::new(),
             color: None,
             background_color: None,
             bold: false,
@@ -390,8 +611,11 @@ impl Style {
             underline: false,
             strikethrough: false,
             dim: false,
+            faint_level: None,
             inverse: false,
+            double_width: false,
             text_wrap: TextWrap::default(),
+            text_overflow: TextOverflow::default(),
             overflow_x: Overflow::default(),
             overflow_y: Overflow::default(),
             is_static: false,
@@ -444,12 +668,45 @@ impl Style {
         self
     }
 
+    /// Fade the foreground toward the background by `level`
+    ///
+    /// `0.0` renders exactly as without fading and `1.0` makes the text the
+    /// same color as its background (black when no background is set).
+    /// Unlike [`Style::dim`], this can be driven smoothly by an animation value.
+    pub fn faint_level(mut self, level: f32) -> Self {
+        self.faint_level = Some(if level.is_nan() {
+            0.0
+        } else {
+            level.clamp(0.0, 1.0)
+        });
+        self
+    }
+
     /// Set inverse colors
     pub fn inverse(mut self) -> Self {
         self.inverse = true;
         self
     }
 
+    /// Draw text with every character twice as wide, for banner headers
+    ///
+    /// The rows the text is on are switched to double-width lines (DECDWL),
+    /// and layout reserves two cells per character. Anything else drawn on
+    /// those rows is doubled too, so give the text rows of its own. On
+    /// terminals without support (see
+    /// [`supports_double_width_lines`](crate::runtime::supports_double_width_lines))
+    /// the text is drawn at normal size.
+    pub fn double_width(mut self) -> Self {
+        self.double_width = true;
+        self
+    }
+
+    /// Set how text that overflows its layout width is shown
+    pub fn text_overflow(mut self, overflow: TextOverflow) -> Self {
+        self.text_overflow = overflow;
+        self
+    }
+
     // ========== Padding Methods ==========
 
     /// Set all padding
@@ -642,6 +899,46 @@ impl Style {
         self
     }
 
+    // ========== Transition Methods ==========
+
+    /// Animate `property` over `duration` whenever it changes
+    ///
+    /// While the app runs, a new value is reached by interpolating from the
+    /// one shown in the previous frame instead of snapping to it. Elements
+    /// are matched across renders by their `key`, or by their position in
+    /// the tree. Colors blend in RGB; widths and heights only animate
+    /// between fixed sizes and snap otherwise. Transitions finish instantly
+    /// when [reduced motion](crate::animation::prefers_reduced_motion) is on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rnk::core::{Color, Style, TransitionProperty};
+    /// use rnk::animation::Easing;
+    /// use std::time::Duration;
+    ///
+    /// let style = Style::new().bg(Color::Blue).transition(
+    ///     TransitionProperty::BackgroundColor,
+    ///     Duration::from_millis(200),
+    ///     Easing::EaseOut,
+    /// );
+    /// assert_eq!(style.transitions.len(), 1);
+    /// ```
+    pub fn transition(
+        mut self,
+        property: TransitionProperty,
+        duration: Duration,
+        easing: Easing,
+    ) -> Self {
+        self.transitions.retain(|t| t.property != property);
+        self.transitions.push(StyleTransition {
+            property,
+            duration,
+            easing,
+        });
+        self
+    }
+
     // ========== Style Combination ==========
 
     /// Merge another style into this one (other takes precedence for set values)
@@ -670,9 +967,18 @@ impl Style {
         if other.dim {
             self.dim = true;
         }
+        if other.faint_level.is_some() {
+            self.faint_level = other.faint_level;
+        }
         if other.inverse {
             self.inverse = true;
         }
+        if other.double_width {
+            self.double_width = true;
+        }
+        if other.text_overflow != TextOverflow::Clip {
+            self.text_overflow = other.text_overflow;
+        }
 
         // Border
         if other.border_style != BorderStyle::None {
@@ -681,6 +987,30 @@ impl Style {
         if other.border_color.is_some() {
             self.border_color = other.border_color;
         }
+        if other.shadow.is_some() {
+            self.shadow = other.shadow;
+        }
+        if other.backdrop.is_some() {
+            self.backdrop = other.backdrop;
+        }
+        if other.color_filter.is_some() {
+            self.color_filter = other.color_filter;
+        }
+        if other.hyperlink.is_some() {
+            self.hyperlink = other.hyperlink.clone();
+        }
+        if other.mirror_horizontal {
+            self.mirror_horizontal = true;
+        }
+        if other.flip_vertical {
+            self.flip_vertical = true;
+        }
+        if other.anchor.is_some() {
+            self.anchor = other.anchor;
+        }
+        for transition in &other.transitions {
+            self = self.transition(transition.property, transition.duration, transition.easing);
+        }
 
         self
     }
@@ -768,6 +1098,13 @@ impl Style {
                 width: taffy::LengthPercentage::Length(self.column_gap.unwrap_or(self.gap)),
                 height: taffy::LengthPercentage::Length(self.row_gap.unwrap_or(self.gap)),
             },
+            grid_template_columns: self
+                .grid_template_columns
+                .iter()
+                .map(|&track| track.into())
+                .collect(),
+            grid_column: grid_span(self.grid_column_span),
+            grid_row: grid_span(self.grid_row_span),
             size: taffy::Size {
                 width: self.width.into(),
                 height: self.height.into(),
@@ -967,6 +1304,23 @@ mod tests {
         assert_eq!(merged.padding, Edges::all(1.0)); // Preserved
     }
 
+    #[test]
+    fn test_faint_level_clamped_and_merged() {
+        assert_eq!(Style::new().faint_level(2.0).faint_level, Some(1.0));
+        assert_eq!(Style::new().faint_level(-1.0).faint_level, Some(0.0));
+        assert_eq!(Style::new().faint_level(f32::NAN).faint_level, Some(0.0));
+
+        let merged = Style::new().faint_level(0.3).merge(&Style::new());
+        assert_eq!(merged.faint_level, Some(0.3));
+        let merged = Style::new().merge(&Style::new().faint_level(0.6));
+        assert_eq!(merged.faint_level, Some(0.6));
+        // An explicit zero resets an inherited fade
+        let merged = Style::new()
+            .faint_level(0.6)
+            .merge(&Style::new().faint_level(0.0));
+        assert_eq!(merged.faint_level, Some(0.0));
+    }
+
     #[test]
     fn test_preset_styles() {
         let error = Style::error();
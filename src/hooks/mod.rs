@@ -56,6 +56,7 @@ mod use_cmd;
 mod use_context;
 mod use_counter;
 mod use_debounce;
+mod use_drag_drop;
 mod use_effect;
 pub mod use_focus;
 mod use_form;
@@ -82,6 +83,7 @@ mod use_set;
 mod use_signal;
 mod use_state;
 mod use_stdio;
+pub(crate) mod use_terminal_focus;
 mod use_toggle;
 mod use_transition;
 mod use_window_size;
@@ -90,17 +92,23 @@ mod use_window_title;
 // === State Management ===
 pub use use_context::{Context, create_context, use_context, with_context};
 pub use use_counter::{CounterHandle, use_counter, use_counter_zero};
-pub use use_form::{FormField, FormHandle, use_form, use_form_empty};
+pub use use_form::{
+    AsyncValidator, FormField, FormHandle, FormSchema, Validator, use_form, use_form_empty,
+    use_form_with_schema,
+};
 pub use use_history::{HistoryHandle, use_history, use_history_with_size};
 pub use use_list::{ListHandle, use_list, use_list_empty};
 pub use use_local_storage::{LocalStorageHandle, use_local_storage, use_local_storage_with_dir};
 pub use use_map::{MapHandle, use_map, use_map_empty, use_map_from};
 pub use use_memo::{MemoizedCallback, use_callback, use_memo};
 pub use use_previous::{use_changed, use_is_first_render, use_previous};
-pub use use_reducer::{Dispatch, use_reducer, use_reducer_lazy};
+pub use use_reducer::{
+    ActionLogEntry, ActionLogger, Dispatch, Middleware, MiddlewareApi, Thunk, ThunkAction,
+    thunk_middleware, use_reducer, use_reducer_lazy, use_reducer_with_middleware,
+};
 pub use use_ref::{RefHandle, use_ref};
 pub use use_set::{SetHandle, use_set, use_set_empty};
-pub use use_signal::{Signal, use_signal};
+pub use use_signal::{Signal, batch, use_signal};
 pub use use_state::{StateSetter, use_state};
 pub use use_toggle::{ToggleHandle, use_toggle, use_toggle_off, use_toggle_on};
 
@@ -114,17 +122,20 @@ pub use use_transition::{TransitionHandle, use_transition, use_transition_with_e
 
 // === Input & Focus ===
 pub use paste::{
-    BracketedPasteGuard, PasteEvent, disable_bracketed_paste, dispatch_paste,
-    enable_bracketed_paste, is_bracketed_paste_enabled, use_paste,
+    BracketedPasteGuard, PasteConfig, PasteEvent, disable_bracketed_paste, dispatch_paste,
+    enable_bracketed_paste, is_bracketed_paste_enabled, use_paste, use_paste_with_config,
 };
 pub use use_clipboard::{
     ClipboardHandle, is_clipboard_available, read_clipboard, use_clipboard, write_clipboard,
 };
+pub use use_drag_drop::{DragDropHandle, DragState, DropTarget, use_drag_drop};
 pub use use_focus::{
     FocusManagerHandle, FocusState, ScopedFocusOptions, UseFocusOptions, use_focus,
     use_focus_manager, use_focus_traversal, use_focus_traversal_in_scope, use_scoped_focus,
 };
-pub use use_input::{Key, KeyCodeKind, MediaKeyKind, use_input};
+pub use use_input::{
+    Key, KeyCodeKind, KeyParseError, MediaKeyKind, use_input, use_input_throttled,
+};
 pub use use_keyboard_shortcut::{
     Modifiers, Shortcut, ShortcutKey, use_keyboard_shortcut, use_keyboard_shortcuts,
 };
@@ -142,7 +153,7 @@ pub use use_idle::{
     IdleConfig, IdleState, idle_duration, is_idle, record_activity, use_idle, use_idle_seconds,
     use_idle_state,
 };
-pub use use_interval::{use_interval, use_interval_when, use_timeout};
+pub use use_interval::{use_interval, use_interval_when, use_interval_when_focused, use_timeout};
 
 // === UI State ===
 pub use use_accessibility::{
@@ -160,12 +171,14 @@ pub use use_online::{
     use_online,
 };
 pub use use_scroll::{ScrollHandle, ScrollState, use_scroll};
+pub use use_terminal_focus::{is_terminal_focused, set_terminal_focused, use_terminal_focus};
 pub use use_window_size::{
     get_terminal_size, use_is_tall_enough, use_is_wide_enough, use_window_height, use_window_size,
     use_window_width,
 };
 pub use use_window_title::{
-    WindowTitleGuard, clear_window_title, set_window_title, use_window_title, use_window_title_fn,
+    WindowTitleGuard, clear_window_title, push_window_title, set_window_title, use_window_title,
+    use_window_title_fn,
 };
 
 // === Context ===
@@ -4,6 +4,8 @@
 //! When enabled, pasted text is wrapped in escape sequences,
 //! allowing the application to distinguish between typed and pasted input.
 
+use crate::cmd::TerminalCmd;
+use crate::renderer::registry::queue_terminal_cmd;
 use std::io::{self, Write};
 use std::sync::atomic::{AtomicBool, Ordering};
 
@@ -23,23 +25,32 @@ pub fn is_bracketed_paste_enabled() -> bool {
 ///
 /// This allows the application to handle pasted text differently
 /// from typed input (e.g., not triggering shortcuts).
+///
+/// Inside a running app the sequence goes through the app's terminal, so it
+/// reaches the configured output and is skipped without terminal control.
 pub fn enable_bracketed_paste() -> io::Result<()> {
-    let mut stdout = io::stdout();
-    stdout.write_all(b"\x1b[?2004h")?;
-    stdout.flush()?;
+    set_bracketed_paste(TerminalCmd::EnableBracketedPaste, b"\x1b[?2004h")?;
     BRACKETED_PASTE_ENABLED.store(true, Ordering::SeqCst);
     Ok(())
 }
 
 /// Disable bracketed paste mode
 pub fn disable_bracketed_paste() -> io::Result<()> {
-    let mut stdout = io::stdout();
-    stdout.write_all(b"\x1b[?2004l")?;
-    stdout.flush()?;
+    set_bracketed_paste(TerminalCmd::DisableBracketedPaste, b"\x1b[?2004l")?;
     BRACKETED_PASTE_ENABLED.store(false, Ordering::SeqCst);
     Ok(())
 }
 
+/// Hand `cmd` to the running app, or write `escape` to stdout without one
+fn set_bracketed_paste(cmd: TerminalCmd, escape: &[u8]) -> io::Result<()> {
+    if queue_terminal_cmd(cmd) {
+        return Ok(());
+    }
+    let mut stdout = io::stdout();
+    stdout.write_all(escape)?;
+    stdout.flush()
+}
+
 /// RAII guard for bracketed paste mode
 ///
 /// Enables bracketed paste on creation and disables it on drop.
@@ -120,6 +131,67 @@ impl PasteEvent {
     pub fn lines(&self) -> impl Iterator<Item = &str> {
         self.content.lines()
     }
+
+    /// Apply `config` to the pasted text
+    pub fn normalized(&self, config: &PasteConfig) -> PasteEvent {
+        let mut content = if config.normalize_line_endings {
+            self.content.replace("\r\n", "\n").replace('\r', "\n")
+        } else {
+            self.content.clone()
+        };
+        if config.trim_trailing_newline {
+            let trimmed = content
+                .strip_suffix("\r\n")
+                .or_else(|| content.strip_suffix('\n'))
+                .or_else(|| content.strip_suffix('\r'))
+                .map(str::len);
+            if let Some(len) = trimmed {
+                content.truncate(len);
+            }
+        }
+        PasteEvent { content }
+    }
+}
+
+/// How pasted text is cleaned up before it reaches a handler
+///
+/// Everything is off by default, so handlers see exactly what the terminal
+/// sent.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PasteConfig {
+    /// Drop one trailing line ending, which terminals often include when a
+    /// whole line is copied and which would otherwise submit a form
+    pub trim_trailing_newline: bool,
+    /// Turn CRLF and lone CR line endings into LF
+    pub normalize_line_endings: bool,
+}
+
+impl PasteConfig {
+    /// Create a config that passes pastes through unchanged
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Config suited to form fields: line endings normalized and a single
+    /// trailing newline trimmed
+    pub fn form() -> Self {
+        Self {
+            trim_trailing_newline: true,
+            normalize_line_endings: true,
+        }
+    }
+
+    /// Set whether a single trailing line ending is trimmed
+    pub fn trim_trailing_newline(mut self, trim: bool) -> Self {
+        self.trim_trailing_newline = trim;
+        self
+    }
+
+    /// Set whether CRLF and CR line endings become LF
+    pub fn normalize_line_endings(mut self, normalize: bool) -> Self {
+        self.normalize_line_endings = normalize;
+        self
+    }
 }
 
 /// Register a paste handler for the current render pass (requires RuntimeContext).
@@ -167,6 +239,23 @@ where
     register_paste_handler(handler);
 }
 
+/// Hook to handle paste events, cleaned up according to `config`
+///
+/// # Example
+///
+/// ```ignore
+/// // A line copied with its newline doesn't submit the form
+/// use_paste_with_config(PasteConfig::form(), move |event| {
+///     field.update(|value| value.push_str(event.content()));
+/// });
+/// ```
+pub fn use_paste_with_config<F>(config: PasteConfig, handler: F)
+where
+    F: Fn(&PasteEvent) + 'static,
+{
+    use_paste(move |event| handler(&event.normalized(&config)));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -207,6 +296,58 @@ mod tests {
         assert_eq!(lines, vec!["A", "B", "C"]);
     }
 
+    #[test]
+    fn test_paste_normalized() {
+        let event = PasteEvent::new("one\r\ntwo\rthree\r\n");
+        assert_eq!(event.normalized(&PasteConfig::new()), event);
+        assert_eq!(
+            event.normalized(&PasteConfig::new().normalize_line_endings(true)),
+            PasteEvent::new("one\ntwo\nthree\n")
+        );
+        assert_eq!(
+            event.normalized(&PasteConfig::form()).content(),
+            "one\ntwo\nthree"
+        );
+        assert_eq!(
+            event
+                .normalized(&PasteConfig::new().trim_trailing_newline(true))
+                .content(),
+            "one\r\ntwo\rthree"
+        );
+
+        // Only a single newline is trimmed, so intentional blank lines stay
+        let blank_lines = PasteEvent::new("a\n\n\n");
+        assert_eq!(
+            blank_lines.normalized(&PasteConfig::form()).content(),
+            "a\n\n"
+        );
+    }
+
+    #[test]
+    fn test_use_paste_with_config() {
+        use crate::hooks::context::{HookContext, with_hooks};
+        use crate::runtime::{RuntimeContext, set_current_runtime};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let ctx = Rc::new(RefCell::new(RuntimeContext::new()));
+        set_current_runtime(Some(ctx));
+
+        let received = Rc::new(RefCell::new(String::new()));
+        let hooks = Rc::new(RefCell::new(HookContext::new()));
+        with_hooks(hooks, || {
+            let received = received.clone();
+            use_paste_with_config(PasteConfig::form(), move |event| {
+                *received.borrow_mut() = event.content().to_string();
+            });
+        });
+
+        dispatch_paste("user@example.com\r\n");
+        assert_eq!(*received.borrow(), "user@example.com");
+
+        set_current_runtime(None);
+    }
+
     #[test]
     fn test_bracketed_paste_flag() {
         // Initially should be false
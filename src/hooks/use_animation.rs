@@ -5,6 +5,8 @@
 use crate::animation::{Animation, AnimationInstance, AnimationState};
 use crate::hooks::context::{RenderCallback, current_context};
 use crate::hooks::lock_utils::{read_or_recover, write_or_recover};
+use crate::renderer::frame_rate::AnimationActivity;
+use crate::runtime::{Clock, current_clock};
 use std::sync::{Arc, RwLock};
 use std::time::Instant;
 
@@ -14,6 +16,8 @@ pub struct AnimationHandle {
     instance: Arc<RwLock<AnimationInstance>>,
     last_tick: Arc<RwLock<Instant>>,
     render_callback: Option<RenderCallback>,
+    activity: Arc<AnimationActivity>,
+    clock: Clock,
 }
 
 impl AnimationHandle {
@@ -35,17 +39,22 @@ impl AnimationHandle {
     /// Start or resume the animation
     pub fn play(&self) {
         write_or_recover(&self.instance).play();
+        // Time spent idle or paused does not count toward the animation
+        *write_or_recover(&self.last_tick) = self.clock.now();
+        self.sync_activity();
         self.trigger_render();
     }
 
     /// Pause the animation
     pub fn pause(&self) {
         write_or_recover(&self.instance).pause();
+        self.sync_activity();
     }
 
     /// Reset the animation to the beginning
     pub fn reset(&self) {
         write_or_recover(&self.instance).reset();
+        self.sync_activity();
         self.trigger_render();
     }
 
@@ -69,9 +78,9 @@ impl AnimationHandle {
         read_or_recover(&self.instance).progress()
     }
 
-    /// Tick the animation (called internally by the framework)
+    /// Tick the animation (called on each render of `use_animation`)
     pub fn tick(&self) {
-        let now = Instant::now();
+        let now = self.clock.now();
         let delta = {
             let mut last = write_or_recover(&self.last_tick);
             let delta = now.duration_since(*last);
@@ -81,6 +90,7 @@ impl AnimationHandle {
 
         let was_running = self.is_running();
         write_or_recover(&self.instance).tick(delta);
+        self.sync_activity();
 
         // Only trigger render if animation is still running
         if was_running && self.is_running() {
@@ -88,6 +98,11 @@ impl AnimationHandle {
         }
     }
 
+    /// Keep the frame rate controller's active-animation count in step
+    fn sync_activity(&self) {
+        self.activity.set(self.is_running());
+    }
+
     fn trigger_render(&self) {
         if let Some(callback) = &self.render_callback {
             callback();
@@ -109,6 +124,9 @@ impl AnimationHandle {
     pub fn try_play(&self) -> bool {
         if let Ok(mut guard) = self.instance.write() {
             guard.play();
+            drop(guard);
+            *write_or_recover(&self.last_tick) = self.clock.now();
+            self.sync_activity();
             self.trigger_render();
             true
         } else {
@@ -120,6 +138,8 @@ impl AnimationHandle {
     pub fn try_pause(&self) -> bool {
         if let Ok(mut guard) = self.instance.write() {
             guard.pause();
+            drop(guard);
+            self.sync_activity();
             true
         } else {
             false
@@ -130,6 +150,8 @@ impl AnimationHandle {
     pub fn try_reset(&self) -> bool {
         if let Ok(mut guard) = self.instance.write() {
             guard.reset();
+            drop(guard);
+            self.sync_activity();
             self.trigger_render();
             true
         } else {
@@ -179,10 +201,13 @@ fn new_animation_handle(
     render_callback: Option<RenderCallback>,
 ) -> AnimationHandle {
     let instance = animation.start();
+    let clock = current_clock();
     AnimationHandle {
         instance: Arc::new(RwLock::new(instance)),
-        last_tick: Arc::new(RwLock::new(Instant::now())),
+        last_tick: Arc::new(RwLock::new(clock.now())),
         render_callback,
+        activity: Arc::default(),
+        clock,
     }
 }
 
@@ -229,10 +254,18 @@ pub fn use_animation(init: impl FnOnce() -> Animation) -> AnimationHandle {
         handle: new_animation_handle(animation_for_hook, render_callback.clone()),
     });
 
-    storage
+    let handle = storage
         .get::<AnimationStorage>()
         .map(|s| s.handle)
-        .unwrap_or_else(|| new_animation_handle(animation, render_callback))
+        .unwrap_or_else(|| new_animation_handle(animation, render_callback));
+    drop(ctx_ref);
+
+    // Advance by the time since the last frame, so a running animation
+    // moves in step with rendering
+    if handle.is_running() {
+        handle.tick();
+    }
+    handle
 }
 
 /// Create an animation that starts automatically
@@ -277,6 +310,8 @@ mod tests {
             instance: Arc::new(RwLock::new(anim.start())),
             last_tick: Arc::new(RwLock::new(Instant::now())),
             render_callback: None,
+            activity: Arc::default(),
+            clock: Clock::system(),
         };
 
         assert_eq!(handle.state(), AnimationState::Idle);
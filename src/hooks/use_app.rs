@@ -27,6 +27,16 @@ impl crate::renderer::registry::AppSink for NoopSink {
     fn queue_terminal_cmd(&self, _cmd: crate::cmd::TerminalCmd) {}
 
     fn request_suspend(&self) {}
+
+    fn set_debug_layout(&self, _enabled: bool) {}
+
+    fn is_debug_layout(&self) -> bool {
+        false
+    }
+
+    fn has_terminal_control(&self) -> bool {
+        false
+    }
 }
 
 fn noop_app_context() -> AppContext {
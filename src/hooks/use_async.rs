@@ -22,8 +22,74 @@
 //!     }
 //! }
 //! ```
+//!
+//! # Refetching
+//!
+//! [`AsyncHandle::load`] registers a loader and returns the command that runs
+//! it; [`AsyncHandle::refetch`] re-runs it, cancelling the request in flight.
+//! The returned [`Cmd`] must be handed to the runtime (e.g. returned from a
+//! `use_cmd` closure). Only the latest request can update the state, so a
+//! search box always shows the newest query's result:
+//!
+//! ```rust,ignore
+//! let results = use_async_state::<Vec<String>, String>();
+//! let query = use_signal(String::new);
+//!
+//! use_cmd(query.get(), {
+//!     let results = results.clone();
+//!     move |q| {
+//!         results.load(move |token| {
+//!             let q = q.clone();
+//!             async move { search(&q, token).await }
+//!         })
+//!     }
+//! });
+//!
+//! // While refetching, keep showing the previous results dimmed
+//! let dim = results.is_stale();
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
 
+use crate::cmd::Cmd;
+use crate::hooks::use_ref::{RefHandle, use_ref};
 use crate::hooks::use_signal::{Signal, use_signal};
+use crate::renderer::CancelToken;
+
+type LoaderFuture<T, E> = Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'static>>;
+type Loader<T, E> = Arc<dyn Fn(CancelToken) -> LoaderFuture<T, E> + Send + Sync>;
+
+/// Loader and request bookkeeping shared by every copy of a handle
+struct AsyncControl<T, E> {
+    loader: Option<Loader<T, E>>,
+    token: CancelToken,
+    generation: u64,
+    previous: Option<T>,
+}
+
+impl<T: Clone, E> Clone for AsyncControl<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            loader: self.loader.clone(),
+            token: self.token.clone(),
+            generation: self.generation,
+            previous: self.previous.clone(),
+        }
+    }
+}
+
+impl<T, E> Default for AsyncControl<T, E> {
+    fn default() -> Self {
+        Self {
+            loader: None,
+            token: CancelToken::new(),
+            generation: 0,
+            previous: None,
+        }
+    }
+}
 
 /// Async operation state
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -81,6 +147,7 @@ impl<T, E> AsyncState<T, E> {
 #[derive(Clone)]
 pub struct AsyncHandle<T, E> {
     state: Signal<AsyncState<T, E>>,
+    control: RefHandle<AsyncControl<T, E>>,
 }
 
 impl<T, E> AsyncHandle<T, E>
@@ -132,6 +199,113 @@ where
     pub fn reset(&self) {
         self.state.set(AsyncState::Idle);
     }
+
+    /// Register a loader and build the command that runs it
+    ///
+    /// The loader receives a [`CancelToken`] that is cancelled when the
+    /// request is superseded by [`refetch`](Self::refetch) or aborted by
+    /// [`cancel`](Self::cancel); long-running loaders can check it to stop
+    /// early. Results of superseded requests are discarded either way.
+    ///
+    /// The request only starts once the returned command is executed.
+    pub fn load<F, Fut>(&self, loader: F) -> Cmd
+    where
+        F: Fn(CancelToken) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<T, E>> + Send + 'static,
+    {
+        let loader: Loader<T, E> = Arc::new(move |token| Box::pin(loader(token)));
+        self.control.update(|control| control.loader = Some(loader));
+        self.refetch()
+    }
+
+    /// Build a command that re-runs the loader
+    ///
+    /// Executing the command cancels any request in flight and moves the
+    /// state to [`AsyncState::Loading`]. The previous successful value stays
+    /// available through [`stale_value`](Self::stale_value) until the new
+    /// result arrives. Returns [`Cmd::none`] if no loader was registered with
+    /// [`load`](Self::load).
+    pub fn refetch(&self) -> Cmd {
+        if self.control.with(|control| control.loader.is_none()) {
+            return Cmd::none();
+        }
+        let handle = self.clone();
+        Cmd::perform(move || async move {
+            if let Some(fetch) = handle.start_fetch() {
+                fetch.await;
+            }
+        })
+    }
+
+    /// Abort the request in flight
+    ///
+    /// Restores the previous successful value if there was one, otherwise
+    /// returns to [`AsyncState::Idle`].
+    pub fn cancel(&self) {
+        let previous = self.control.with_mut(|control| {
+            control.token.cancel();
+            control.generation += 1;
+            control.previous.take()
+        });
+        if self.state.get().is_loading() {
+            self.state
+                .set(previous.map_or(AsyncState::Idle, AsyncState::Success));
+        }
+    }
+
+    /// Check if a refetch is in flight while an older value is available
+    pub fn is_stale(&self) -> bool {
+        self.state.get().is_loading() && self.control.with(|c| c.previous.is_some())
+    }
+
+    /// The last successful value, kept while a refetch is in flight
+    pub fn stale_value(&self) -> Option<T> {
+        if !self.state.get().is_loading() {
+            return None;
+        }
+        self.control.with(|c| c.previous.clone())
+    }
+
+    /// Token for the current request
+    pub fn cancel_token(&self) -> CancelToken {
+        self.control.with(|c| c.token.clone())
+    }
+
+    /// Start a new request and return the future that completes it
+    fn start_fetch(&self) -> Option<impl Future<Output = ()> + Send + 'static> {
+        let current = self.state.get();
+        let (loader, token, generation) = self.control.with_mut(|control| {
+            let loader = control.loader.clone()?;
+            control.token.cancel();
+            control.token = CancelToken::new();
+            control.generation += 1;
+            if let AsyncState::Success(value) = current {
+                control.previous = Some(value);
+            }
+            Some((loader, control.token.clone(), control.generation))
+        })?;
+
+        self.state.set(AsyncState::Loading);
+
+        let state = self.state.clone();
+        let control = self.control.clone();
+        Some(async move {
+            let result = loader(token.clone()).await;
+            let latest = control.with_mut(|control| {
+                let latest = !token.is_cancelled() && control.generation == generation;
+                if latest {
+                    control.previous = None;
+                }
+                latest
+            });
+            if latest {
+                state.set(match result {
+                    Ok(value) => AsyncState::Success(value),
+                    Err(error) => AsyncState::Error(error),
+                });
+            }
+        })
+    }
 }
 
 /// Create an async state handle
@@ -141,7 +315,8 @@ where
     E: Clone + Send + Sync + 'static,
 {
     let state = use_signal(|| AsyncState::Idle);
-    AsyncHandle { state }
+    let control = use_ref(AsyncControl::default);
+    AsyncHandle { state, control }
 }
 
 /// Create an async state handle with initial value
@@ -151,7 +326,8 @@ where
     E: Clone + Send + Sync + 'static,
 {
     let state = use_signal(|| initial);
-    AsyncHandle { state }
+    let control = use_ref(AsyncControl::default);
+    AsyncHandle { state, control }
 }
 
 #[cfg(test)]
@@ -176,6 +352,95 @@ mod tests {
         assert_eq!(state.error(), Some(&"error".to_string()));
     }
 
+    fn run(future: impl Future<Output = ()>) {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(future);
+    }
+
+    fn run_cmd(cmd: Cmd) {
+        let Cmd::Perform { future } = cmd else {
+            panic!("expected a perform command");
+        };
+        run(future);
+    }
+
+    #[test]
+    fn test_refetch_discards_superseded_response() {
+        let handle: AsyncHandle<String, String> = use_async_state();
+        handle.control.update(|c| {
+            c.loader = Some(Arc::new(|_| Box::pin(async { Ok("v".to_string()) })));
+        });
+
+        let first = handle.start_fetch().unwrap();
+        let first_token = handle.cancel_token();
+        let second = handle.start_fetch().unwrap();
+        assert!(first_token.is_cancelled());
+        assert!(handle.is_loading());
+
+        run(first);
+        assert!(handle.is_loading());
+        run(second);
+        assert_eq!(handle.state(), AsyncState::Success("v".to_string()));
+    }
+
+    #[test]
+    fn test_refetch_keeps_stale_value() {
+        let handle: AsyncHandle<String, String> = use_async_state();
+        handle.control.update(|c| {
+            c.loader = Some(Arc::new(|_| Box::pin(async { Ok("new".to_string()) })));
+        });
+        handle.set_success("old".to_string());
+        assert!(!handle.is_stale());
+
+        let cmd = handle.start_fetch().unwrap();
+        assert!(handle.is_stale());
+        assert_eq!(handle.stale_value(), Some("old".to_string()));
+
+        run(cmd);
+        assert!(!handle.is_stale());
+        assert_eq!(handle.state().value(), Some(&"new".to_string()));
+    }
+
+    #[test]
+    fn test_cancel_restores_previous_value() {
+        let handle: AsyncHandle<String, String> = use_async_state();
+        handle.control.update(|c| {
+            c.loader = Some(Arc::new(|_| Box::pin(async { Ok("new".to_string()) })));
+        });
+        assert!(handle.start_fetch().is_some());
+        handle.cancel();
+        assert!(handle.is_idle());
+
+        handle.set_success("old".to_string());
+        let cmd = handle.start_fetch().unwrap();
+        let token = handle.cancel_token();
+        handle.cancel();
+        assert!(token.is_cancelled());
+        assert_eq!(handle.state().value(), Some(&"old".to_string()));
+
+        run(cmd);
+        assert_eq!(handle.state().value(), Some(&"old".to_string()));
+    }
+
+    #[test]
+    fn test_refetch_without_loader_is_noop() {
+        let handle: AsyncHandle<String, String> = use_async_state();
+        assert!(handle.refetch().is_none());
+        assert!(handle.is_idle());
+    }
+
+    #[test]
+    fn test_load_starts_when_command_runs() {
+        let handle: AsyncHandle<String, String> = use_async_state();
+        let cmd = handle.load(|_| async { Ok("v".to_string()) });
+        assert!(handle.is_idle());
+
+        run_cmd(cmd);
+        assert_eq!(handle.state(), AsyncState::Success("v".to_string()));
+    }
+
     #[test]
     fn test_use_async_state_compiles() {
         fn _test() {
@@ -28,6 +28,7 @@
 //! ```
 
 use crate::hooks::use_signal::{Signal, use_signal};
+use crate::runtime::{Clock, TimerId, current_clock};
 use std::sync::mpsc;
 use std::time::{Duration, Instant};
 
@@ -94,22 +95,26 @@ where
 /// since the last change to `value`.
 ///
 /// Uses one worker thread per hook instance. New values are pushed to the
-/// worker, which keeps only the latest pending value and delay.
+/// worker, which keeps only the latest pending value and delay. Under a
+/// manual [`Clock`] the pending value is a timer on that clock instead.
 pub fn use_debounce<T>(value: T, delay: Duration) -> T
 where
     T: Clone + PartialEq + Send + Sync + 'static,
 {
+    let clock = current_clock();
     let debounced = use_signal(|| value.clone());
     let last_value = use_signal(|| value.clone());
     let last_delay = use_signal(|| delay);
     let worker_tx: Signal<Option<mpsc::Sender<DebounceMessage<T>>>> = use_signal(|| None);
+    let manual_timer: Signal<Option<TimerId>> = use_signal(|| None);
 
-    if worker_tx.get().is_none() {
+    if worker_tx.get().is_none() && !clock.is_manual() {
         worker_tx.set(Some(spawn_debounce_worker(debounced.clone())));
     }
 
     // Zero-delay debounce should update immediately.
     if delay.is_zero() {
+        cancel_manual_timer(&clock, &manual_timer);
         if last_value.get() != value {
             last_value.set(value.clone());
         }
@@ -132,7 +137,17 @@ where
         last_delay.set(delay);
     }
 
-    if value_changed || delay_changed {
+    if (value_changed || delay_changed) && clock.is_manual() {
+        cancel_manual_timer(&clock, &manual_timer);
+        let latest_value = last_value.get();
+        let target = debounced.clone();
+        let timer = clock.schedule(last_delay.get(), None, move || {
+            if target.get() != latest_value {
+                target.set(latest_value.clone());
+            }
+        });
+        manual_timer.set(timer);
+    } else if value_changed || delay_changed {
         let latest_value = last_value.get();
         let latest_delay = last_delay.get();
 
@@ -161,24 +176,37 @@ where
     debounced.get()
 }
 
+fn cancel_manual_timer(clock: &Clock, timer: &Signal<Option<TimerId>>) {
+    if let Some(id) = timer.get() {
+        clock.cancel(id);
+        timer.set(None);
+    }
+}
+
 /// Handle for tracking debounce state
 #[derive(Clone)]
 pub struct DebounceHandle {
     pending: Signal<bool>,
     last_trigger: Signal<Instant>,
     delay: Duration,
+    clock: Clock,
 }
 
 impl DebounceHandle {
     /// Trigger the debounce timer
     pub fn trigger(&self) {
         self.pending.set(true);
-        self.last_trigger.set(Instant::now());
+        self.last_trigger.set(self.clock.now());
     }
 
     /// Check if the debounce period has elapsed
     pub fn is_ready(&self) -> bool {
-        self.pending.get() && self.last_trigger.get().elapsed() >= self.delay
+        self.pending.get()
+            && self
+                .clock
+                .now()
+                .saturating_duration_since(self.last_trigger.get())
+                >= self.delay
     }
 
     /// Reset the debounce state
@@ -194,13 +222,15 @@ impl DebounceHandle {
 
 /// Create a debounce handle for manual control
 pub fn use_debounce_handle(delay: Duration) -> DebounceHandle {
+    let clock = current_clock();
     let pending = use_signal(|| false);
-    let last_trigger = use_signal(Instant::now);
+    let last_trigger = use_signal(|| clock.now());
 
     DebounceHandle {
         pending,
         last_trigger,
         delay,
+        clock,
     }
 }
 
@@ -344,7 +374,8 @@ where
 /// `interval`). Values pushed within the throttle window are not silently
 /// dropped — the latest pending value is delivered after the window closes.
 ///
-/// Uses one worker thread per hook instance. Pass `Duration::ZERO` to disable
+/// Uses one worker thread per hook instance, or a timer on the runtime's
+/// manual [`Clock`] when one is installed. Pass `Duration::ZERO` to disable
 /// throttling entirely (every value passes through immediately).
 ///
 /// # Example
@@ -368,8 +399,10 @@ where
     let throttled = use_signal(|| value.clone());
     let last_emit = use_signal(|| None::<Instant>);
     let worker_tx: Signal<Option<mpsc::Sender<ThrottleMessage<T>>>> = use_signal(|| None);
+    let manual_timer: Signal<Option<TimerId>> = use_signal(|| None);
+    let clock = current_clock();
 
-    let now = Instant::now();
+    let now = clock.now();
     let last_emit_at = last_emit.get();
     let should_emit_now = interval.is_zero()
         || match last_emit_at {
@@ -380,11 +413,26 @@ where
     if should_emit_now {
         throttled.set(value);
         last_emit.set(Some(now));
+        cancel_manual_timer(&clock, &manual_timer);
         clear_throttle_schedule(&worker_tx);
         return throttled.get();
     }
 
-    if let Some(emitted_at) = last_emit_at {
+    if let Some(emitted_at) = last_emit_at
+        && clock.is_manual()
+    {
+        cancel_manual_timer(&clock, &manual_timer);
+        let (target, emitted, at) = (throttled.clone(), last_emit.clone(), clock.clone());
+        let timer = clock.schedule(
+            (emitted_at + interval).saturating_duration_since(now),
+            None,
+            move || {
+                target.set(value.clone());
+                emitted.set(Some(at.now()));
+            },
+        );
+        manual_timer.set(timer);
+    } else if let Some(emitted_at) = last_emit_at {
         send_throttle_schedule(
             &worker_tx,
             throttled.clone(),
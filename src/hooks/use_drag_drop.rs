@@ -0,0 +1,315 @@
+//! Drag-and-drop gesture tracking on top of mouse events
+//!
+//! [`use_drag_drop`] turns a left-button press, the drag motion that follows
+//! and the final release into one gesture. Drop targets are rectangles keyed
+//! by id; register them each render (typically from measured layout) and the
+//! hook hit-tests the pointer against them.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use rnk::prelude::*;
+//!
+//! fn list() -> Element {
+//!     let drag = use_drag_drop();
+//!     drag.set_targets(vec![
+//!         DropTarget::new("a", 0, 0, 20, 1),
+//!         DropTarget::new("b", 0, 1, 20, 1),
+//!     ]);
+//!
+//!     if let Some(done) = drag.take_drop() {
+//!         // move `done.source` to `done.over_target`
+//!     }
+//!
+//!     let hovered = drag.drag().and_then(|d| d.over_target);
+//!     Text::new(format!("over: {:?}", hovered)).into_element()
+//! }
+//! ```
+
+use crate::hooks::use_mouse::{Mouse, MouseAction, MouseButton, use_mouse};
+use crate::hooks::use_ref::{RefHandle, use_ref};
+use crate::hooks::use_signal::{Signal, use_signal};
+
+/// A rectangular drop target
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DropTarget {
+    /// Identifier reported in [`DragState`]
+    pub id: String,
+    /// Left column
+    pub x: u16,
+    /// Top row
+    pub y: u16,
+    /// Width in columns
+    pub width: u16,
+    /// Height in rows
+    pub height: u16,
+}
+
+impl DropTarget {
+    /// Create a drop target covering the given rectangle
+    pub fn new(id: impl Into<String>, x: u16, y: u16, width: u16, height: u16) -> Self {
+        Self {
+            id: id.into(),
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Check whether a screen cell falls inside this target
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        x >= self.x && y >= self.y && (x - self.x) < self.width && (y - self.y) < self.height
+    }
+}
+
+/// Snapshot of a drag gesture
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DragState {
+    /// Target under the pointer when the button was pressed
+    pub source: Option<String>,
+    /// Target currently under the pointer
+    pub over_target: Option<String>,
+    /// Position where the drag started
+    pub start: (u16, u16),
+    /// Latest pointer position
+    pub current: (u16, u16),
+    /// Movement from `start` to `current` in cells
+    pub delta: (i32, i32),
+}
+
+impl DragState {
+    fn new(source: Option<String>, x: u16, y: u16) -> Self {
+        Self {
+            over_target: source.clone(),
+            source,
+            start: (x, y),
+            current: (x, y),
+            delta: (0, 0),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct Gesture {
+    /// Pressed but not yet moved
+    pressed: Option<DragState>,
+    /// Moving with the button held
+    dragging: Option<DragState>,
+    /// Finished drag waiting for `take_drop`
+    dropped: Option<DragState>,
+}
+
+/// Handle returned by [`use_drag_drop`]
+#[derive(Clone)]
+pub struct DragDropHandle {
+    gesture: Signal<Gesture>,
+    targets: RefHandle<Vec<DropTarget>>,
+}
+
+impl DragDropHandle {
+    /// Replace the drop targets used for hit-testing
+    ///
+    /// This does not trigger a render, so it is safe to call while rendering.
+    pub fn set_targets(&self, targets: Vec<DropTarget>) {
+        self.targets.set(targets);
+    }
+
+    /// Id of the topmost target at a position
+    ///
+    /// Later targets win, matching paint order.
+    pub fn hit_test(&self, x: u16, y: u16) -> Option<String> {
+        self.targets.with(|targets| {
+            targets
+                .iter()
+                .rev()
+                .find(|target| target.contains(x, y))
+                .map(|target| target.id.clone())
+        })
+    }
+
+    /// The drag in progress, if the pointer has moved since the press
+    pub fn drag(&self) -> Option<DragState> {
+        self.gesture.with(|g| g.dragging.clone())
+    }
+
+    /// Check whether a drag is in progress
+    pub fn is_dragging(&self) -> bool {
+        self.gesture.with(|g| g.dragging.is_some())
+    }
+
+    /// Take the most recently completed drag
+    ///
+    /// `over_target` holds the target it was released on. A press and
+    /// release without movement is a click and produces no drop.
+    pub fn take_drop(&self) -> Option<DragState> {
+        let dropped = self.gesture.with(|g| g.dropped.clone())?;
+        self.gesture.set_silent(Gesture {
+            dropped: None,
+            ..self.gesture.get()
+        });
+        Some(dropped)
+    }
+
+    /// Abandon the current gesture without producing a drop
+    pub fn cancel(&self) {
+        self.gesture.update(|g| {
+            g.pressed = None;
+            g.dragging = None;
+        });
+    }
+
+    /// Feed a mouse event into the gesture
+    ///
+    /// Returns `true` if the event changed the drag state. [`use_drag_drop`]
+    /// calls this for every mouse event; call it directly when routing
+    /// events yourself.
+    pub fn handle_mouse(&self, mouse: &Mouse) -> bool {
+        match mouse.action {
+            MouseAction::Press(MouseButton::Left) => {
+                let source = self.hit_test(mouse.x, mouse.y);
+                self.gesture.update(|g| {
+                    g.pressed = Some(DragState::new(source, mouse.x, mouse.y));
+                    g.dragging = None;
+                });
+                true
+            }
+            MouseAction::Drag(MouseButton::Left) => {
+                let Some(mut state) = self
+                    .gesture
+                    .with(|g| g.dragging.clone().or(g.pressed.clone()))
+                else {
+                    return false;
+                };
+                state.current = (mouse.x, mouse.y);
+                state.delta = (
+                    mouse.x as i32 - state.start.0 as i32,
+                    mouse.y as i32 - state.start.1 as i32,
+                );
+                state.over_target = self.hit_test(mouse.x, mouse.y);
+                self.gesture.update(|g| {
+                    g.pressed = None;
+                    g.dragging = Some(state);
+                });
+                true
+            }
+            MouseAction::Release(MouseButton::Left) => {
+                let gesture = self.gesture.get();
+                if gesture.pressed.is_none() && gesture.dragging.is_none() {
+                    return false;
+                }
+                let over_target = self.hit_test(mouse.x, mouse.y);
+                self.gesture.update(|g| {
+                    g.pressed = None;
+                    if let Some(mut state) = g.dragging.take() {
+                        state.over_target = over_target;
+                        g.dropped = Some(state);
+                    }
+                });
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Track left-button drag gestures and hit-test them against drop targets
+pub fn use_drag_drop() -> DragDropHandle {
+    let handle = DragDropHandle {
+        gesture: use_signal(Gesture::default),
+        targets: use_ref(Vec::new),
+    };
+
+    let handler = handle.clone();
+    use_mouse(move |mouse| {
+        handler.handle_mouse(mouse);
+    });
+
+    handle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::hook_harness;
+
+    fn mouse(action: MouseAction, x: u16, y: u16) -> Mouse {
+        Mouse {
+            x,
+            y,
+            action,
+            ctrl: false,
+            shift: false,
+            alt: false,
+        }
+    }
+
+    fn list_targets() -> Vec<DropTarget> {
+        vec![
+            DropTarget::new("a", 0, 0, 10, 1),
+            DropTarget::new("b", 0, 1, 10, 1),
+            DropTarget::new("c", 0, 2, 10, 1),
+        ]
+    }
+
+    #[test]
+    fn test_drop_target_contains() {
+        let target = DropTarget::new("t", 2, 3, 4, 2);
+        assert!(target.contains(2, 3));
+        assert!(target.contains(5, 4));
+        assert!(!target.contains(6, 4));
+        assert!(!target.contains(1, 3));
+        assert!(!DropTarget::new("empty", 0, 0, 0, 0).contains(0, 0));
+    }
+
+    #[test]
+    fn test_drag_gesture_reports_source_target_and_delta() {
+        let mut harness = hook_harness(|| {
+            let drag = use_drag_drop();
+            drag.set_targets(list_targets());
+            drag
+        });
+
+        harness.send_mouse(mouse(MouseAction::Press(MouseButton::Left), 3, 0));
+        assert!(!harness.result().is_dragging());
+
+        harness.send_mouse(mouse(MouseAction::Drag(MouseButton::Left), 5, 1));
+        let drag = harness.result().drag().unwrap();
+        assert_eq!(drag.source.as_deref(), Some("a"));
+        assert_eq!(drag.over_target.as_deref(), Some("b"));
+        assert_eq!(drag.start, (3, 0));
+        assert_eq!(drag.current, (5, 1));
+        assert_eq!(drag.delta, (2, 1));
+
+        harness.send_mouse(mouse(MouseAction::Release(MouseButton::Left), 1, 2));
+        let handle = harness.result().clone();
+        assert!(!handle.is_dragging());
+        let dropped = handle.take_drop().unwrap();
+        assert_eq!(dropped.source.as_deref(), Some("a"));
+        assert_eq!(dropped.over_target.as_deref(), Some("c"));
+        assert_eq!(handle.take_drop(), None);
+    }
+
+    #[test]
+    fn test_click_without_motion_is_not_a_drop() {
+        let mut harness = hook_harness(|| {
+            let drag = use_drag_drop();
+            drag.set_targets(list_targets());
+            drag
+        });
+
+        harness.send_mouse(mouse(MouseAction::Press(MouseButton::Left), 3, 0));
+        harness.send_mouse(mouse(MouseAction::Release(MouseButton::Left), 3, 0));
+        assert_eq!(harness.result().take_drop(), None);
+
+        // Dragging outside every target still tracks the gesture
+        harness.send_mouse(mouse(MouseAction::Press(MouseButton::Left), 3, 1));
+        harness.send_mouse(mouse(MouseAction::Drag(MouseButton::Left), 20, 1));
+        let drag = harness.result().drag().unwrap();
+        assert_eq!(drag.over_target, None);
+        assert_eq!(drag.delta, (17, 0));
+
+        harness.result().cancel();
+        assert!(!harness.render().is_dragging());
+    }
+}
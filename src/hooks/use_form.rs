@@ -26,31 +26,191 @@
 //! }
 //! ```
 
+//!
+//! # Validation
+//!
+//! [`use_form_with_schema`] attaches validators per field. Sync validators run
+//! on every [`FormHandle::set`]; async validators (e.g. "is this username
+//! taken?") run after a debounce through the command system, and the field
+//! reports [`FormHandle::is_pending`] until they settle.
+//!
+//! ```rust,ignore
+//! let form = use_form_with_schema(
+//!     FormSchema::new()
+//!         .field("username", "")
+//!         .validate("username", |v| {
+//!             if v.len() < 3 { Err("Too short".into()) } else { Ok(()) }
+//!         })
+//!         .validate_async("username", Duration::from_millis(300), |v| async move {
+//!             if v == "admin" { Err("Already taken".into()) } else { Ok(()) }
+//!         }),
+//! );
+//!
+//! if form.is_pending("username") {
+//!     // show a spinner
+//! }
+//! ```
+
+use crate::cmd::Cmd;
+use crate::hooks::use_cmd::use_cmd;
+use crate::hooks::use_ref::{RefHandle, use_ref};
 use crate::hooks::use_signal::{Signal, use_signal};
 use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Synchronous field validator: `Err` carries the message to show
+pub type Validator = Arc<dyn Fn(&str) -> Result<(), String> + Send + Sync>;
+
+/// Asynchronous field validator, run debounced after sync validators pass
+pub type AsyncValidator =
+    Arc<dyn Fn(String) -> Pin<Box<dyn Future<Output = Result<(), String>> + Send>> + Send + Sync>;
 
 /// Form field with value and validation
 #[derive(Clone, Debug)]
 pub struct FormField {
     pub value: String,
+    /// First validation error, shown next to the field
     pub error: Option<String>,
+    /// Every failing validator's message, in declaration order
+    pub errors: Vec<String>,
     pub touched: bool,
+    /// Whether the value differs from its initial value
+    pub changed: bool,
+    /// Whether an async validator is still running for the current value
+    pub pending: bool,
+    /// Value the field was created or reset with
+    pub initial: String,
 }
 
 impl FormField {
     fn new(value: impl Into<String>) -> Self {
+        let value = value.into();
         Self {
-            value: value.into(),
+            initial: value.clone(),
+            value,
             error: None,
+            errors: Vec::new(),
             touched: false,
+            changed: false,
+            pending: false,
         }
     }
+
+    fn set_errors(&mut self, errors: Vec<String>) {
+        self.error = errors.first().cloned();
+        self.errors = errors;
+    }
+}
+
+/// Validators attached to one field
+#[derive(Clone, Default)]
+struct FieldRules {
+    validators: Vec<Validator>,
+    async_validator: Option<(Duration, AsyncValidator)>,
+}
+
+impl FieldRules {
+    fn check(&self, value: &str) -> Vec<String> {
+        self.validators
+            .iter()
+            .filter_map(|validate| validate(value).err())
+            .collect()
+    }
+}
+
+/// Per-field validators for [`use_form_with_schema`]
+#[derive(Clone, Default)]
+pub struct FormSchema {
+    fields: Vec<(String, String)>,
+    rules: HashMap<String, FieldRules>,
+}
+
+impl FormSchema {
+    /// Create an empty schema
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare a field and its initial value
+    pub fn field(mut self, name: impl Into<String>, initial: impl Into<String>) -> Self {
+        self.fields.push((name.into(), initial.into()));
+        self
+    }
+
+    /// Add a sync validator to a field
+    pub fn validate<F>(mut self, name: impl Into<String>, validator: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.rules
+            .entry(name.into())
+            .or_default()
+            .validators
+            .push(Arc::new(validator));
+        self
+    }
+
+    /// Set a field's async validator, run `debounce` after the last edit
+    ///
+    /// The async validator only runs once every sync validator passes. A
+    /// field has at most one; setting another replaces it.
+    pub fn validate_async<F, Fut>(
+        mut self,
+        name: impl Into<String>,
+        debounce: Duration,
+        validator: F,
+    ) -> Self
+    where
+        F: Fn(String) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        let validator: AsyncValidator = Arc::new(move |value| Box::pin(validator(value)));
+        self.rules.entry(name.into()).or_default().async_validator = Some((debounce, validator));
+        self
+    }
+}
+
+/// A debounced async validation waiting to be handed to the command system
+#[derive(Clone)]
+struct AsyncJob {
+    field: String,
+    value: String,
+    generation: u64,
+}
+
+/// Validation bookkeeping that must not trigger re-renders
+#[derive(Clone, Default)]
+struct FormValidation {
+    rules: HashMap<String, FieldRules>,
+    /// Bumped on every edit so stale async results can be dropped
+    generations: HashMap<String, u64>,
+    /// Value each field last finished async validation for, and its error
+    settled: HashMap<String, (String, Option<String>)>,
+    queued: Vec<AsyncJob>,
+    /// Bumped whenever jobs are queued, to wake the render-time dispatcher
+    epoch: u64,
+}
+
+impl FormValidation {
+    fn bump(&mut self, name: &str) -> u64 {
+        let generation = self.generations.entry(name.to_string()).or_default();
+        *generation += 1;
+        *generation
+    }
+
+    fn is_current(&self, name: &str, generation: u64) -> bool {
+        self.generations.get(name) == Some(&generation)
+    }
 }
 
 /// Handle for form operations
 #[derive(Clone)]
 pub struct FormHandle {
     fields: Signal<HashMap<String, FormField>>,
+    validation: RefHandle<FormValidation>,
 }
 
 impl FormHandle {
@@ -63,13 +223,24 @@ impl FormHandle {
         })
     }
 
-    /// Set a field value
+    /// Set a field value and validate it
     pub fn set(&self, name: &str, value: impl Into<String>) {
         let value = value.into();
+        if !self.fields.with(|f| f.contains_key(name)) {
+            return;
+        }
+
+        let pending = self.schedule_validation(name, &value, true);
+        let errors = self.sync_errors(name, &value);
         self.fields.update(|f| {
             if let Some(field) = f.get_mut(name) {
+                field.changed = value != field.initial;
                 field.value = value;
                 field.touched = true;
+                if let Some(errors) = errors {
+                    field.set_errors(errors);
+                    field.pending = pending;
+                }
             }
         });
     }
@@ -80,11 +251,18 @@ impl FormHandle {
             .with(|f| f.get(name).and_then(|field| field.error.clone()))
     }
 
+    /// Get every error reported for a field
+    pub fn field_errors(&self, name: &str) -> Vec<String> {
+        self.fields
+            .with(|f| f.get(name).map(|field| field.errors.clone()))
+            .unwrap_or_default()
+    }
+
     /// Set a field's error
     pub fn set_error(&self, name: &str, error: impl Into<String>) {
         self.fields.update(|f| {
             if let Some(field) = f.get_mut(name) {
-                field.error = Some(error.into());
+                field.set_errors(vec![error.into()]);
             }
         });
     }
@@ -93,7 +271,7 @@ impl FormHandle {
     pub fn clear_error(&self, name: &str) {
         self.fields.update(|f| {
             if let Some(field) = f.get_mut(name) {
-                field.error = None;
+                field.set_errors(Vec::new());
             }
         });
     }
@@ -113,17 +291,44 @@ impl FormHandle {
         });
     }
 
-    /// Check if the form is valid (no errors)
-    pub fn is_valid(&self) -> bool {
+    /// Check if a field's value differs from its initial value
+    pub fn is_field_changed(&self, name: &str) -> bool {
         self.fields
-            .with(|f| f.values().all(|field| field.error.is_none()))
+            .with(|f| f.get(name).map(|field| field.changed).unwrap_or(false))
+    }
+
+    /// Check if an async validator is still running for a field
+    pub fn is_pending(&self, name: &str) -> bool {
+        self.fields
+            .with(|f| f.get(name).map(|field| field.pending).unwrap_or(false))
+    }
+
+    /// Check if any async validator is still running
+    pub fn is_any_pending(&self) -> bool {
+        self.fields.with(|f| f.values().any(|field| field.pending))
+    }
+
+    /// Check if the form is valid: no errors and no pending validation
+    pub fn is_valid(&self) -> bool {
+        self.fields.with(|f| {
+            f.values()
+                .all(|field| field.error.is_none() && !field.pending)
+        })
     }
 
     /// Check if any field has been touched
+    ///
+    /// Use [`is_any_field_changed`](Self::is_any_field_changed) to ask
+    /// whether a value differs from its initial value instead.
     pub fn is_dirty(&self) -> bool {
         self.fields.with(|f| f.values().any(|field| field.touched))
     }
 
+    /// Check if any field's value differs from its initial value
+    pub fn is_any_field_changed(&self) -> bool {
+        self.fields.with(|f| f.values().any(|field| field.changed))
+    }
+
     /// Get all field values as a HashMap
     pub fn values(&self) -> HashMap<String, String> {
         self.fields.with(|f| {
@@ -133,8 +338,51 @@ impl FormHandle {
         })
     }
 
+    /// Run every field's validators, marking all fields touched
+    ///
+    /// Async validators only rerun for values they haven't settled yet.
+    /// Returns whether the form is valid right now.
+    pub fn validate_all(&self) -> bool {
+        let values = self.values();
+        for (name, value) in &values {
+            // Leave in-flight async checks running rather than restarting them
+            let pending = self.is_pending(name) || self.schedule_validation(name, value, false);
+            let errors = self.sync_errors(name, value);
+            self.fields.update(|f| {
+                if let Some(field) = f.get_mut(name) {
+                    field.touched = true;
+                    if let Some(errors) = errors {
+                        field.set_errors(errors);
+                        field.pending = pending;
+                    }
+                }
+            });
+        }
+        self.is_valid()
+    }
+
+    /// Validate and, if every field passes with nothing pending, call
+    /// `on_submit` with the values
+    ///
+    /// Returns whether `on_submit` ran.
+    pub fn submit(&self, on_submit: impl FnOnce(HashMap<String, String>)) -> bool {
+        if !self.validate_all() {
+            return false;
+        }
+        on_submit(self.values());
+        true
+    }
+
     /// Reset all fields to initial values
     pub fn reset(&self, initial: Vec<(&str, &str)>) {
+        self.validation.update(|v| {
+            let names: Vec<String> = v.generations.keys().cloned().collect();
+            for name in names {
+                v.bump(&name);
+            }
+            v.settled.clear();
+            v.queued.clear();
+        });
         self.fields.update(|f| {
             f.clear();
             for (name, value) in initial {
@@ -147,7 +395,7 @@ impl FormHandle {
     pub fn clear_errors(&self) {
         self.fields.update(|f| {
             for field in f.values_mut() {
-                field.error = None;
+                field.set_errors(Vec::new());
             }
         });
     }
@@ -160,17 +408,113 @@ impl FormHandle {
                 .collect()
         })
     }
+
+    /// Known errors for `value`: sync validator messages plus any settled
+    /// async result for the same value. `None` if the field has no rules.
+    fn sync_errors(&self, name: &str, value: &str) -> Option<Vec<String>> {
+        self.validation.with(|v| {
+            let mut errors = v.rules.get(name)?.check(value);
+            if let Some((settled, Some(error))) = v.settled.get(name)
+                && settled == value
+            {
+                errors.push(error.clone());
+            }
+            Some(errors)
+        })
+    }
+
+    /// Queue an async check for `value` unless sync validation fails or it
+    /// already settled. `invalidate` drops in-flight checks for older values.
+    /// Returns whether the field is now pending.
+    fn schedule_validation(&self, name: &str, value: &str, invalidate: bool) -> bool {
+        let mut pending = false;
+        self.validation.update(|v| {
+            let Some(rules) = v.rules.get(name) else {
+                return;
+            };
+            let needs_async = rules.async_validator.is_some()
+                && rules.check(value).is_empty()
+                && v.settled.get(name).map(|(settled, _)| settled.as_str()) != Some(value);
+            if !invalidate && !needs_async {
+                return;
+            }
+
+            let generation = v.bump(name);
+            if needs_async {
+                v.queued.push(AsyncJob {
+                    field: name.to_string(),
+                    value: value.to_string(),
+                    generation,
+                });
+                v.epoch += 1;
+                pending = true;
+            }
+        });
+        pending
+    }
+
+    /// Turn queued async validations into a command
+    fn dispatch_async_jobs(&self) -> Cmd {
+        let jobs = self.validation.with(|v| {
+            v.queued
+                .iter()
+                .filter_map(|job| {
+                    let (debounce, validator) = v.rules.get(&job.field)?.async_validator.clone()?;
+                    Some((job.clone(), debounce, validator))
+                })
+                .collect::<Vec<_>>()
+        });
+        self.validation.update(|v| v.queued.clear());
+
+        Cmd::batch(jobs.into_iter().map(|(job, debounce, validator)| {
+            let form = self.clone();
+            Cmd::sleep(debounce).and_then(Cmd::perform(move || async move {
+                form.run_async_job(job, validator).await;
+            }))
+        }))
+    }
+
+    async fn run_async_job(self, job: AsyncJob, validator: AsyncValidator) {
+        // A newer edit superseded this value while it was debouncing
+        if !self
+            .validation
+            .with(|v| v.is_current(&job.field, job.generation))
+        {
+            return;
+        }
+
+        let result = validator(job.value.clone()).await;
+
+        let current = self
+            .validation
+            .with(|v| v.is_current(&job.field, job.generation));
+        if !current {
+            return;
+        }
+        let error = result.err();
+        self.validation.update(|v| {
+            v.settled
+                .insert(job.field.clone(), (job.value.clone(), error.clone()));
+        });
+        self.fields.update(|f| {
+            if let Some(field) = f.get_mut(&job.field) {
+                field.pending = false;
+                let mut errors = field.errors.clone();
+                errors.extend(error);
+                field.set_errors(errors);
+            }
+        });
+    }
 }
 
 /// Create a form state
 pub fn use_form(initial: Vec<(&str, &str)>) -> FormHandle {
-    let fields = use_signal(|| {
-        initial
-            .into_iter()
-            .map(|(name, value)| (name.to_string(), FormField::new(value)))
-            .collect()
-    });
-    FormHandle { fields }
+    let schema = initial
+        .into_iter()
+        .fold(FormSchema::new(), |schema, (name, value)| {
+            schema.field(name, value)
+        });
+    use_form_with_schema(schema)
 }
 
 /// Create an empty form state
@@ -178,6 +522,32 @@ pub fn use_form_empty() -> FormHandle {
     use_form(Vec::new())
 }
 
+/// Create a form state whose fields are validated by `schema`
+///
+/// The schema is read on the first render only, like other hook initial
+/// values.
+pub fn use_form_with_schema(schema: FormSchema) -> FormHandle {
+    let FormSchema { fields, rules } = schema;
+    let fields = use_signal(move || {
+        fields
+            .into_iter()
+            .map(|(name, value)| (name, FormField::new(value)))
+            .collect()
+    });
+    let validation = use_ref(move || FormValidation {
+        rules,
+        ..FormValidation::default()
+    });
+    let form = FormHandle { fields, validation };
+
+    // Hand queued async validations to the command system after each edit
+    let epoch = form.validation.with(|v| v.epoch);
+    let dispatcher = form.clone();
+    use_cmd(epoch, move |_| dispatcher.dispatch_async_jobs());
+
+    form
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,6 +570,113 @@ mod tests {
         assert!(field.error.is_none());
     }
 
+    fn username_schema() -> FormSchema {
+        FormSchema::new()
+            .field("username", "")
+            .field("bio", "")
+            .validate("username", |v| {
+                if v.is_empty() {
+                    Err("Required".to_string())
+                } else {
+                    Ok(())
+                }
+            })
+            .validate("username", |v| {
+                if v.chars().all(|c| c.is_ascii_alphanumeric()) {
+                    Ok(())
+                } else {
+                    Err("Letters and digits only".to_string())
+                }
+            })
+            .validate_async("username", Duration::from_millis(10), |v| async move {
+                if v == "admin" {
+                    Err("Already taken".to_string())
+                } else {
+                    Ok(())
+                }
+            })
+    }
+
+    /// Run the queued validations; keep the executor alive until they settle
+    fn run_queued_cmds(
+        harness: &crate::testing::HookHarness<FormHandle>,
+    ) -> crate::cmd::CmdExecutor {
+        let cmds = harness
+            .runtime_context()
+            .borrow()
+            .hook_context()
+            .borrow_mut()
+            .take_cmds();
+        let (tx, _rx) = tokio::sync::mpsc::unbounded_channel();
+        let executor = crate::cmd::CmdExecutor::new(tx);
+        for cmd in cmds {
+            executor.execute(cmd);
+        }
+        executor
+    }
+
+    #[test]
+    fn test_sync_validators_aggregate_errors() {
+        let mut harness = crate::testing::hook_harness(|| use_form_with_schema(username_schema()));
+
+        harness.act(|form| form.set("username", "a b"));
+        let form = harness.result().clone();
+        assert_eq!(
+            form.error("username"),
+            Some("Letters and digits only".into())
+        );
+        assert!(form.is_touched("username"));
+        assert!(form.is_field_changed("username"));
+        assert!(!form.is_pending("username"));
+        assert!(!form.is_valid());
+
+        harness.act(|form| form.set("username", ""));
+        let form = harness.result().clone();
+        assert_eq!(form.field_errors("username"), vec!["Required".to_string()]);
+        assert!(!form.is_field_changed("username"));
+        assert!(!form.is_any_field_changed());
+        assert!(form.is_dirty());
+    }
+
+    #[test]
+    fn test_async_validator_runs_debounced_and_blocks_submit() {
+        let mut harness = crate::testing::hook_harness(|| use_form_with_schema(username_schema()));
+
+        harness.act(|form| form.set("username", "admin"));
+        let executor = run_queued_cmds(&harness);
+
+        let form = harness.result().clone();
+        assert!(form.is_pending("username"));
+        assert!(!form.is_valid());
+        assert!(!form.submit(|_| panic!("submitted while pending")));
+
+        assert!(harness.wait_for(Duration::from_secs(2), |form| !form.is_any_pending()));
+        let form = harness.result().clone();
+        assert_eq!(form.error("username"), Some("Already taken".into()));
+        assert!(!form.submit(|_| panic!("submitted while failing")));
+        executor.shutdown();
+    }
+
+    #[test]
+    fn test_async_validator_drops_stale_results() {
+        let mut harness = crate::testing::hook_harness(|| use_form_with_schema(username_schema()));
+
+        harness.act(|form| {
+            form.set("username", "admin");
+            form.set("username", "alice");
+        });
+        let executor = run_queued_cmds(&harness);
+
+        assert!(harness.wait_for(Duration::from_secs(2), |form| !form.is_any_pending()));
+        let form = harness.result().clone();
+        assert_eq!(form.error("username"), None);
+
+        let mut submitted = None;
+        assert!(form.submit(|values| submitted = Some(values)));
+        assert_eq!(submitted.unwrap()["username"], "alice");
+        executor.shutdown();
+    }
+
     #[test]
     fn test_form_errors() {
         fn _test() {
@@ -1,6 +1,9 @@
 //! use_history hook for undo/redo functionality
 //!
-//! Provides history tracking with undo and redo capabilities.
+//! Provides history tracking with undo and redo capabilities. The same stack
+//! doubles as browser-style navigation for multi-screen apps: `push` a route,
+//! then `back`/`forward` through it, and render the current route with
+//! [`Router`](crate::components::Router).
 //!
 //! # Example
 //!
@@ -25,6 +28,21 @@
 //!     Text::new(history.get()).into_element()
 //! }
 //! ```
+//!
+//! # Navigation
+//!
+//! ```rust,ignore
+//! #[derive(Clone, PartialEq)]
+//! enum Screen { Settings, Detail(usize) }
+//!
+//! let nav = use_history(Screen::Settings);
+//! // nav.push(Screen::Detail(3)); nav.back();
+//!
+//! Router::from_history(&nav)
+//!     .route(Screen::Settings, || settings_view())
+//!     .matching(|s| matches!(s, Screen::Detail(_)), |s| detail_view(s))
+//!     .into_element()
+//! ```
 
 use crate::hooks::use_signal::{Signal, use_signal};
 
@@ -127,6 +145,31 @@ where
         });
     }
 
+    /// Replace the current state without recording history
+    pub fn replace(&self, value: T) {
+        self.signal.update(|s| s.present = value);
+    }
+
+    /// Navigate back to the previous route (same as [`undo`](Self::undo))
+    pub fn back(&self) -> bool {
+        self.undo()
+    }
+
+    /// Navigate forward again after going back (same as [`redo`](Self::redo))
+    pub fn forward(&self) -> bool {
+        self.redo()
+    }
+
+    /// Check if there is a previous route to go back to
+    pub fn can_go_back(&self) -> bool {
+        self.can_undo()
+    }
+
+    /// Check if there is a route to go forward to
+    pub fn can_go_forward(&self) -> bool {
+        self.can_redo()
+    }
+
     /// Go to a specific point in history (0 = oldest)
     pub fn go_to(&self, index: usize) {
         self.signal.update(|s| {
@@ -170,6 +213,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::hook_harness;
 
     #[test]
     fn test_use_history_compiles() {
@@ -204,4 +248,43 @@ mod tests {
             // Should only keep last 10
         }
     }
+
+    #[test]
+    fn test_history_navigation_truncates_forward() {
+        let mut harness = hook_harness(|| use_history("settings"));
+        assert!(!harness.result().can_go_back());
+
+        harness.act(|nav| nav.push("detail"));
+        harness.act(|nav| nav.push("sub-detail"));
+        harness.act(|nav| {
+            assert!(nav.back());
+        });
+        assert_eq!(harness.result().get(), "detail");
+        assert!(harness.result().can_go_forward());
+
+        // A new push drops the forward entries
+        harness.act(|nav| nav.push("other"));
+        assert!(!harness.result().can_go_forward());
+        assert!(!harness.result().forward());
+
+        harness.act(|nav| nav.replace("renamed"));
+        harness.act(|nav| {
+            nav.back();
+        });
+        assert_eq!(harness.result().get(), "detail");
+        assert!(harness.result().forward());
+        assert_eq!(harness.result().get(), "renamed");
+    }
+
+    #[test]
+    fn test_history_stack_is_bounded() {
+        let mut harness = hook_harness(|| use_history_with_size(0, 3));
+        harness.act(|nav| (1..=10).for_each(|i| nav.push(i)));
+
+        let nav = harness.result();
+        assert_eq!(nav.get(), 10);
+        assert_eq!(nav.undo_count(), 3);
+        while nav.back() {}
+        assert_eq!(nav.get(), 7);
+    }
 }
@@ -1,6 +1,11 @@
 //! Input handling hook
 
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MediaKeyCode};
+use crossterm::event::{
+    KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MediaKeyCode,
+};
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 /// Typed key code for pattern matching and robust key handling.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
@@ -56,6 +61,35 @@ impl MediaKeyKind {
             _ => None,
         }
     }
+
+    fn to_event_code(self) -> MediaKeyCode {
+        match self {
+            Self::Play => MediaKeyCode::Play,
+            Self::Pause => MediaKeyCode::Pause,
+            Self::PlayPause => MediaKeyCode::PlayPause,
+            Self::Stop => MediaKeyCode::Stop,
+            Self::Next => MediaKeyCode::TrackNext,
+            Self::Previous => MediaKeyCode::TrackPrevious,
+            Self::VolumeUp => MediaKeyCode::RaiseVolume,
+            Self::VolumeDown => MediaKeyCode::LowerVolume,
+            Self::VolumeMute => MediaKeyCode::MuteVolume,
+        }
+    }
+
+    /// Canonical name used in keybinding strings
+    fn name(self) -> &'static str {
+        match self {
+            Self::Play => "Play",
+            Self::Pause => "Pause",
+            Self::PlayPause => "PlayPause",
+            Self::Stop => "Stop",
+            Self::Next => "Next",
+            Self::Previous => "Previous",
+            Self::VolumeUp => "VolumeUp",
+            Self::VolumeDown => "VolumeDown",
+            Self::VolumeMute => "VolumeMute",
+        }
+    }
 }
 
 impl KeyCodeKind {
@@ -84,6 +118,76 @@ impl KeyCodeKind {
             _ => Self::Unknown,
         }
     }
+
+    pub(crate) fn to_event_code(self) -> KeyCode {
+        match self {
+            Self::Up => KeyCode::Up,
+            Self::Down => KeyCode::Down,
+            Self::Left => KeyCode::Left,
+            Self::Right => KeyCode::Right,
+            Self::PageUp => KeyCode::PageUp,
+            Self::PageDown => KeyCode::PageDown,
+            Self::Home => KeyCode::Home,
+            Self::End => KeyCode::End,
+            Self::Insert => KeyCode::Insert,
+            Self::Enter => KeyCode::Enter,
+            Self::Escape => KeyCode::Esc,
+            Self::Tab => KeyCode::Tab,
+            Self::BackTab => KeyCode::BackTab,
+            Self::Backspace => KeyCode::Backspace,
+            Self::Delete => KeyCode::Delete,
+            Self::Char(ch) => KeyCode::Char(ch),
+            Self::Function(n) => KeyCode::F(n),
+            Self::Media(media) => KeyCode::Media(media.to_event_code()),
+            Self::Unknown => KeyCode::Null,
+        }
+    }
+
+    /// Parse a key name from a keybinding string (case-insensitive)
+    fn from_name(name: &str) -> Option<Self> {
+        let mut chars = name.chars();
+        if let (Some(ch), None) = (chars.next(), chars.next()) {
+            return Some(Self::Char(ch));
+        }
+
+        let lower = name.to_ascii_lowercase();
+        let code = match lower.as_str() {
+            "up" => Self::Up,
+            "down" => Self::Down,
+            "left" => Self::Left,
+            "right" => Self::Right,
+            "pageup" | "pgup" => Self::PageUp,
+            "pagedown" | "pgdn" | "pgdown" => Self::PageDown,
+            "home" => Self::Home,
+            "end" => Self::End,
+            "insert" | "ins" => Self::Insert,
+            "enter" | "return" => Self::Enter,
+            "esc" | "escape" => Self::Escape,
+            "tab" => Self::Tab,
+            "backtab" => Self::BackTab,
+            "backspace" => Self::Backspace,
+            "delete" | "del" => Self::Delete,
+            "space" => Self::Char(' '),
+            "plus" => Self::Char('+'),
+            "play" => Self::Media(MediaKeyKind::Play),
+            "pause" => Self::Media(MediaKeyKind::Pause),
+            "playpause" => Self::Media(MediaKeyKind::PlayPause),
+            "stop" => Self::Media(MediaKeyKind::Stop),
+            "next" => Self::Media(MediaKeyKind::Next),
+            "previous" | "prev" => Self::Media(MediaKeyKind::Previous),
+            "volumeup" => Self::Media(MediaKeyKind::VolumeUp),
+            "volumedown" => Self::Media(MediaKeyKind::VolumeDown),
+            "volumemute" | "mute" => Self::Media(MediaKeyKind::VolumeMute),
+            other => {
+                let n = other.strip_prefix('f')?.parse::<u8>().ok()?;
+                if n == 0 {
+                    return None;
+                }
+                Self::Function(n)
+            }
+        };
+        Some(code)
+    }
 }
 
 /// Key information for input handlers
@@ -135,6 +239,13 @@ pub struct Key {
     pub shift: bool,
     pub alt: bool,
     pub meta: bool,
+    /// Caps Lock was on when the key was pressed. Only terminals using the
+    /// kitty keyboard protocol report this; elsewhere it stays `false`.
+    pub caps_lock: bool,
+    /// The event is an auto-repeat from a held key. Only terminals using the
+    /// kitty keyboard protocol report this; elsewhere repeats arrive as
+    /// ordinary presses and this stays `false`.
+    pub repeat: bool,
 
     // Media keys
     pub media_play: bool,
@@ -202,6 +313,8 @@ impl Key {
             shift: modifiers.contains(KeyModifiers::SHIFT) || matches!(code, KeyCodeKind::BackTab),
             alt: modifiers.contains(KeyModifiers::ALT),
             meta: modifiers.contains(KeyModifiers::SUPER),
+            caps_lock: event.state.contains(KeyEventState::CAPS_LOCK),
+            repeat: event.kind == KeyEventKind::Repeat,
 
             // Media keys
             media_play: matches!(code, KeyCodeKind::Media(MediaKeyKind::Play)),
@@ -240,6 +353,136 @@ impl Key {
     }
 }
 
+impl fmt::Display for Key {
+    /// Format as a keybinding string
+    ///
+    /// Modifiers come first in the order `Ctrl+Alt+Shift+Meta`, followed by
+    /// the key: a named key (`Enter`, `Esc`, `Tab`, `Backspace`, `Up`,
+    /// `PageDown`, `F5`, ...) or the character itself. Space and `+` are
+    /// written as `Space` and `Plus`. Back-tab is written as `Shift+Tab`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let shift = self.shift || self.code == KeyCodeKind::BackTab;
+        for (held, name) in [
+            (self.ctrl, "Ctrl"),
+            (self.alt, "Alt"),
+            (shift, "Shift"),
+            (self.meta, "Meta"),
+        ] {
+            if held {
+                write!(f, "{}+", name)?;
+            }
+        }
+
+        match self.code {
+            KeyCodeKind::Up => f.write_str("Up"),
+            KeyCodeKind::Down => f.write_str("Down"),
+            KeyCodeKind::Left => f.write_str("Left"),
+            KeyCodeKind::Right => f.write_str("Right"),
+            KeyCodeKind::PageUp => f.write_str("PageUp"),
+            KeyCodeKind::PageDown => f.write_str("PageDown"),
+            KeyCodeKind::Home => f.write_str("Home"),
+            KeyCodeKind::End => f.write_str("End"),
+            KeyCodeKind::Insert => f.write_str("Insert"),
+            KeyCodeKind::Enter => f.write_str("Enter"),
+            KeyCodeKind::Escape => f.write_str("Esc"),
+            KeyCodeKind::Tab | KeyCodeKind::BackTab => f.write_str("Tab"),
+            KeyCodeKind::Backspace => f.write_str("Backspace"),
+            KeyCodeKind::Delete => f.write_str("Delete"),
+            KeyCodeKind::Char(' ') => f.write_str("Space"),
+            KeyCodeKind::Char('+') => f.write_str("Plus"),
+            KeyCodeKind::Char(ch) => write!(f, "{}", ch),
+            KeyCodeKind::Function(n) => write!(f, "F{}", n),
+            KeyCodeKind::Media(media) => f.write_str(media.name()),
+            KeyCodeKind::Unknown => f.write_str("Unknown"),
+        }
+    }
+}
+
+/// Error returned when parsing a [`Key`] from a keybinding string fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyParseError {
+    /// The input was empty or only whitespace
+    Empty,
+    /// A modifier other than Ctrl, Alt, Shift or Meta
+    UnknownModifier(String),
+    /// A key name that is neither a named key nor a single character
+    UnknownKey(String),
+}
+
+impl fmt::Display for KeyParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty key string"),
+            Self::UnknownModifier(s) => write!(
+                f,
+                "unknown modifier '{}': expected Ctrl, Alt, Shift or Meta",
+                s
+            ),
+            Self::UnknownKey(s) => write!(f, "unknown key '{}'", s),
+        }
+    }
+}
+
+impl std::error::Error for KeyParseError {}
+
+impl FromStr for Key {
+    type Err = KeyParseError;
+
+    /// Parse a keybinding string such as `Ctrl+Shift+A` or `Alt+Enter`
+    ///
+    /// Accepts the format produced by `Display`. Names are case-insensitive,
+    /// and common aliases are understood (`Control`, `Option`, `Super`,
+    /// `Cmd`, `Return`, `Escape`, `Del`, `PgUp`, ...). `Shift+Tab` parses as
+    /// back-tab, and `Shift` with a lowercase letter yields the uppercase
+    /// character, as terminals report it.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = s.trim();
+        if input.is_empty() {
+            return Err(KeyParseError::Empty);
+        }
+
+        // A trailing "+" after a separator is the plus key itself
+        let (modifier_part, key_name) = match input.rsplit_once('+') {
+            Some((mods, "")) => (mods.strip_suffix('+').unwrap_or(mods), "+"),
+            Some((mods, key)) => (mods, key.trim()),
+            None => ("", input),
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for name in modifier_part.split('+').filter(|m| !m.trim().is_empty()) {
+            modifiers |= match name.trim().to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" | "option" | "opt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                "meta" | "super" | "cmd" | "command" => KeyModifiers::SUPER,
+                _ => return Err(KeyParseError::UnknownModifier(name.trim().to_string())),
+            };
+        }
+
+        let shift = modifiers.contains(KeyModifiers::SHIFT);
+        let code = match KeyCodeKind::from_name(key_name) {
+            Some(KeyCodeKind::Tab) if shift => KeyCodeKind::BackTab,
+            Some(KeyCodeKind::Char(ch)) if shift => KeyCodeKind::Char(ch.to_ascii_uppercase()),
+            Some(code) => code,
+            None => return Err(KeyParseError::UnknownKey(key_name.to_string())),
+        };
+
+        Ok(Key::from_event(&KeyEvent::new(
+            code.to_event_code(),
+            modifiers,
+        )))
+    }
+}
+
+impl Key {
+    /// Parse a keybinding string such as `Ctrl+Shift+A`
+    ///
+    /// See the [`FromStr`] impl for the accepted format.
+    pub fn parse(s: &str) -> Result<Self, KeyParseError> {
+        s.parse()
+    }
+}
+
 /// Input handler type (boxed, for public use)
 pub type InputHandler = Box<dyn Fn(&str, &Key)>;
 
@@ -299,6 +542,49 @@ where
     register_input_handler(handler);
 }
 
+/// Hook to handle keyboard input, dropping rapid repeats of the same key
+///
+/// Auto-repeats arriving within `min_interval` of the last handled key are
+/// ignored, which keeps a held arrow key from scrolling hundreds of lines.
+/// Once the terminal has reported a [`Key::repeat`], only flagged repeats
+/// are throttled, so quickly tapping a key still goes through. Until then,
+/// any press of the last handled key within `min_interval` counts as a
+/// repeat. Pressing a different key always goes through.
+///
+/// # Example
+///
+/// ```ignore
+/// use_input_throttled(Duration::from_millis(50), move |_input, key| {
+///     if key.down_arrow {
+///         scroll.update(|s| *s += 1);
+///     }
+/// });
+/// ```
+pub fn use_input_throttled<F>(min_interval: Duration, handler: F)
+where
+    F: Fn(&str, &Key) + 'static,
+{
+    let last = crate::hooks::use_ref(|| None::<(KeyCodeKind, Instant)>);
+    let reports_repeat = crate::hooks::use_ref(|| false);
+    let clock = crate::runtime::current_clock();
+    use_input(move |input, key| {
+        let now = clock.now();
+        if key.repeat {
+            reports_repeat.set(true);
+        }
+        let is_repeat = key.repeat || !reports_repeat.get();
+        let throttled = is_repeat
+            && last.get().is_some_and(|(code, at)| {
+                code == key.code && now.duration_since(at) < min_interval
+            });
+        if throttled {
+            return;
+        }
+        last.set(Some((key.code, now)));
+        handler(input, key);
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -466,4 +752,146 @@ mod tests {
             let _state = use_signal(|| 1usize);
         });
     }
+
+    #[test]
+    fn test_key_repeat_flag() {
+        let mut event = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+        assert!(!Key::from_event(&event).repeat);
+
+        event.kind = KeyEventKind::Repeat;
+        assert!(Key::from_event(&event).repeat);
+    }
+
+    #[test]
+    fn test_use_input_throttled_drops_fast_repeats() {
+        use crate::hooks::use_signal;
+        use crate::testing::hook_harness;
+
+        let mut harness = hook_harness(|| {
+            let count = use_signal(|| 0usize);
+            let handler_count = count.clone();
+            use_input_throttled(Duration::from_secs(60), move |_input, _key| {
+                handler_count.update(|c| *c += 1);
+            });
+            count.get()
+        });
+
+        harness.send_key(KeyCodeKind::Down);
+        harness.send_key(KeyCodeKind::Down);
+        assert_eq!(*harness.send_key(KeyCodeKind::Down), 1);
+
+        // A different key is never throttled
+        assert_eq!(*harness.send_key(KeyCodeKind::Up), 2);
+        assert_eq!(*harness.send_key(KeyCodeKind::Down), 3);
+    }
+
+    #[test]
+    fn test_use_input_throttled_allows_after_interval() {
+        use crate::hooks::use_signal;
+        use crate::testing::hook_harness;
+
+        let mut harness = hook_harness(|| {
+            let count = use_signal(|| 0usize);
+            let handler_count = count.clone();
+            use_input_throttled(Duration::from_millis(10), move |_input, _key| {
+                handler_count.update(|c| *c += 1);
+            });
+            count.get()
+        });
+
+        harness.send_key(KeyCodeKind::Down);
+        harness.advance(Duration::from_millis(20));
+        assert_eq!(*harness.send_key(KeyCodeKind::Down), 2);
+    }
+
+    #[test]
+    fn test_use_input_throttled_uses_reported_repeats() {
+        use crate::hooks::use_signal;
+        use crate::testing::hook_harness;
+
+        let mut harness = hook_harness(|| {
+            let count = use_signal(|| 0usize);
+            let handler_count = count.clone();
+            use_input_throttled(Duration::from_secs(60), move |_input, _key| {
+                handler_count.update(|c| *c += 1);
+            });
+            count.get()
+        });
+        let mut repeat = KeyEvent::new(KeyCode::Down, KeyModifiers::NONE);
+        repeat.kind = KeyEventKind::Repeat;
+
+        harness.send_key(KeyCodeKind::Down);
+        assert_eq!(*harness.send_key_event(repeat), 1);
+
+        // Now that repeats are reported, fresh presses are never throttled
+        assert_eq!(*harness.send_key(KeyCodeKind::Down), 2);
+        assert_eq!(*harness.send_key(KeyCodeKind::Down), 3);
+        assert_eq!(*harness.send_key_event(repeat), 3);
+    }
+
+    #[test]
+    fn test_key_display_canonical_format() {
+        let key = Key::from_event(&KeyEvent::new(
+            KeyCode::Char('A'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        ));
+        assert_eq!(key.to_string(), "Ctrl+Shift+A");
+
+        let key = Key::from_event(&KeyEvent::new(KeyCode::Enter, KeyModifiers::ALT));
+        assert_eq!(key.to_string(), "Alt+Enter");
+
+        let key = Key::from_event(&KeyEvent::new(KeyCode::BackTab, KeyModifiers::NONE));
+        assert_eq!(key.to_string(), "Shift+Tab");
+
+        let key = Key::from_event(&KeyEvent::new(KeyCode::Char(' '), KeyModifiers::CONTROL));
+        assert_eq!(key.to_string(), "Ctrl+Space");
+    }
+
+    #[test]
+    fn test_key_parse_round_trip() {
+        for text in [
+            "Ctrl+Shift+A",
+            "Alt+Enter",
+            "Esc",
+            "Shift+Tab",
+            "Ctrl+Alt+Delete",
+            "F12",
+            "PageDown",
+            "q",
+            "Ctrl+Plus",
+            "Meta+Left",
+            "VolumeUp",
+        ] {
+            let key = Key::parse(text).unwrap();
+            assert_eq!(key.to_string(), text);
+        }
+    }
+
+    #[test]
+    fn test_key_parse_aliases_and_flags() {
+        let key = Key::parse("control+shift+a").unwrap();
+        assert!(key.ctrl && key.shift);
+        assert!(key.is_char('A'));
+
+        let key: Key = "shift+tab".parse().unwrap();
+        assert!(key.back_tab);
+
+        assert!(Key::parse("Return").unwrap().return_key);
+        assert!(Key::parse("Ctrl++").unwrap().is_char('+'));
+        assert!(Key::parse("f5").unwrap().f5);
+    }
+
+    #[test]
+    fn test_key_parse_errors() {
+        assert_eq!(Key::parse("  ").unwrap_err(), KeyParseError::Empty);
+        assert_eq!(
+            Key::parse("Hyper+A").unwrap_err(),
+            KeyParseError::UnknownModifier("Hyper".to_string())
+        );
+        assert_eq!(
+            Key::parse("Ctrl+Banana").unwrap_err(),
+            KeyParseError::UnknownKey("Banana".to_string())
+        );
+        assert!(Key::parse("F0").is_err());
+    }
 }
@@ -23,11 +23,13 @@
 use crate::cmd::Cmd;
 use crate::hooks::use_cmd::use_cmd_once;
 use crate::hooks::use_effect::use_effect;
+use crate::hooks::use_terminal_focus::{is_terminal_focused, use_terminal_focus};
+use crate::runtime::current_clock;
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::sync::OnceLock;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::mpsc;
+use std::sync::{Mutex, OnceLock};
 use std::time::Duration;
 use std::time::Instant;
 
@@ -150,12 +152,17 @@ pub fn use_interval_when<F>(delay: Duration, enabled: bool, callback: F)
 where
     F: Fn() + Send + Sync + Clone + 'static,
 {
+    let clock = current_clock();
     use_effect(
         move || {
             if !enabled || delay.is_zero() {
                 return None;
             }
 
+            if let Some(timer) = clock.schedule(delay, Some(delay), callback.clone()) {
+                return Some(Box::new(move || clock.cancel(timer)) as Box<dyn FnOnce() + Send>);
+            }
+
             let id = NEXT_INTERVAL_ID.fetch_add(1, Ordering::Relaxed);
             let callback: Arc<dyn Fn() + Send + Sync> = Arc::new(callback.clone());
             let scheduler = interval_scheduler().clone();
@@ -174,6 +181,24 @@ where
     );
 }
 
+/// Run a callback at regular intervals while the terminal has focus
+///
+/// The interval is unregistered when the terminal reports focus loss and
+/// resumes on focus gain, so background apps stop spending CPU on ticks
+/// nobody sees. Terminals without focus reporting always count as focused.
+pub fn use_interval_when_focused<F>(delay: Duration, callback: F)
+where
+    F: Fn() + Send + Sync + Clone + 'static,
+{
+    let focused = use_terminal_focus();
+    use_interval_when(delay, focused, move || {
+        // A tick can race the re-render that unregisters the interval
+        if is_terminal_focused() {
+            callback();
+        }
+    });
+}
+
 /// Run a callback once after a delay (setTimeout equivalent)
 ///
 /// The callback will be called once after the specified delay.
@@ -182,6 +207,21 @@ where
     F: FnOnce() + Send + 'static,
 {
     use_cmd_once(move |_| {
+        let clock = current_clock();
+        if clock.is_manual() {
+            let callback = Mutex::new(Some(callback));
+            clock.schedule(delay, None, move || {
+                let pending = callback
+                    .lock()
+                    .ok()
+                    .and_then(|mut callback| callback.take());
+                if let Some(callback) = pending {
+                    callback();
+                }
+            });
+            return Cmd::none();
+        }
+
         Cmd::sleep(delay).and_then(Cmd::perform(move || async move {
             callback();
         }))
@@ -256,6 +296,29 @@ mod tests {
         drop(ctx);
     }
 
+    #[test]
+    fn test_use_interval_when_focused_skips_while_unfocused() {
+        use crate::hooks::use_terminal_focus::{FOCUS_TEST_LOCK, set_terminal_focused};
+
+        let _guard = FOCUS_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        set_terminal_focused(false);
+
+        let ctx = Rc::new(RefCell::new(HookContext::new()));
+        let count = Arc::new(AtomicUsize::new(0));
+        let count_clone = count.clone();
+        with_hooks(ctx.clone(), || {
+            use_interval_when_focused(Duration::from_millis(10), move || {
+                count_clone.fetch_add(1, Ordering::SeqCst);
+            });
+        });
+
+        std::thread::sleep(Duration::from_millis(80));
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+
+        set_terminal_focused(true);
+        drop(ctx);
+    }
+
     #[test]
     fn test_use_interval_when_toggle_stops_ticks() {
         let ctx = Rc::new(RefCell::new(HookContext::new()));
@@ -1,6 +1,8 @@
 //! use_reducer hook for complex state management
 //!
 //! Provides Redux-style state management with actions and reducers.
+//! [`use_reducer_with_middleware`] adds Redux-style middleware, with a
+//! built-in [`thunk_middleware`] and [`ActionLogger`].
 //!
 //! # Example
 //!
@@ -45,8 +47,10 @@
 //! }
 //! ```
 
-use crate::hooks::use_signal::use_signal;
-use std::sync::Arc;
+use crate::hooks::lock_utils::{read_or_recover, write_or_recover};
+use crate::hooks::use_signal::{Signal, use_signal};
+use std::fmt;
+use std::sync::{Arc, RwLock};
 
 /// Handle for dispatching actions
 #[derive(Clone)]
@@ -115,6 +119,241 @@ where
     (state.get(), dispatch)
 }
 
+/// A middleware wrapping a reducer's dispatch
+///
+/// Called with the store API, the action, and `next`, which passes the action
+/// on to the next middleware (and finally the reducer). A middleware can
+/// inspect or transform the action, skip `next` to swallow it, or dispatch
+/// other actions through [`MiddlewareApi::dispatch`].
+pub type Middleware<S, A> = Arc<dyn Fn(&MiddlewareApi<S, A>, A, &dyn Fn(A)) + Send + Sync>;
+
+/// Store access handed to middleware
+pub struct MiddlewareApi<S, A> {
+    store: Arc<MiddlewareStore<S, A>>,
+}
+
+impl<S: Clone + Send + Sync + 'static, A: 'static> MiddlewareApi<S, A> {
+    /// Get the current state
+    pub fn get_state(&self) -> S {
+        self.store.state.get()
+    }
+
+    /// Dispatch an action through the whole middleware chain
+    pub fn dispatch(&self, action: A) {
+        self.store.dispatch_from(0, action);
+    }
+
+    /// Get a dispatch handle that outlives this call, e.g. for async work
+    pub fn dispatcher(&self) -> Dispatch<A> {
+        self.store.dispatcher()
+    }
+}
+
+type ReducerFn<S, A> = Arc<dyn Fn(&S, A) -> S + Send + Sync>;
+
+struct MiddlewareStore<S, A> {
+    state: Signal<S>,
+    reducer: ReducerFn<S, A>,
+    middlewares: Vec<Middleware<S, A>>,
+}
+
+impl<S: Clone + Send + Sync + 'static, A: 'static> MiddlewareStore<S, A> {
+    fn dispatch_from(self: &Arc<Self>, index: usize, action: A) {
+        match self.middlewares.get(index) {
+            Some(middleware) => {
+                let api = MiddlewareApi {
+                    store: self.clone(),
+                };
+                middleware(&api, action, &|action| {
+                    self.dispatch_from(index + 1, action)
+                });
+            }
+            None => {
+                let current = self.state.get();
+                self.state.set((self.reducer)(&current, action));
+            }
+        }
+    }
+
+    fn dispatcher(self: &Arc<Self>) -> Dispatch<A> {
+        let store = self.clone();
+        Dispatch {
+            dispatch_fn: Arc::new(move |action: A| store.dispatch_from(0, action)),
+        }
+    }
+}
+
+/// Create a reducer-based state whose dispatch runs through middleware
+///
+/// Middlewares run in order: the first one sees every action first, and the
+/// reducer runs after the last one calls `next`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let logger = ActionLogger::new();
+/// let (state, dispatch) = use_reducer_with_middleware(
+///     State::default(),
+///     reducer,
+///     vec![thunk_middleware(), logger.middleware()],
+/// );
+/// ```
+pub fn use_reducer_with_middleware<S, A, F>(
+    initial: S,
+    reducer: F,
+    middlewares: Vec<Middleware<S, A>>,
+) -> (S, Dispatch<A>)
+where
+    S: Clone + Send + Sync + 'static,
+    A: 'static,
+    F: Fn(&S, A) -> S + Send + Sync + 'static,
+{
+    let state = use_signal(|| initial);
+    let store = Arc::new(MiddlewareStore {
+        state: state.clone(),
+        reducer: Arc::new(reducer),
+        middlewares,
+    });
+
+    (state.get(), store.dispatcher())
+}
+
+type ThunkFn<S, A> = Box<dyn FnOnce(&Dispatch<A>, &dyn Fn() -> S) + Send>;
+
+/// A side-effecting action run by [`thunk_middleware`]
+///
+/// The closure receives a dispatch handle and a state getter, so it can read
+/// the current state, dispatch plain actions, or hand the dispatcher to a
+/// background thread.
+pub struct Thunk<S, A> {
+    run: ThunkFn<S, A>,
+}
+
+impl<S, A> Thunk<S, A> {
+    /// Create a thunk from a closure
+    pub fn new(run: impl FnOnce(&Dispatch<A>, &dyn Fn() -> S) + Send + 'static) -> Self {
+        Self { run: Box::new(run) }
+    }
+}
+
+impl<S, A> fmt::Debug for Thunk<S, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Thunk")
+    }
+}
+
+/// Actions that can carry a [`Thunk`]
+///
+/// Implement this for an action enum with a thunk variant so
+/// [`thunk_middleware`] can pick thunks out of the action stream.
+///
+/// ```rust,ignore
+/// enum Action {
+///     Loaded(Vec<Item>),
+///     Thunk(Thunk<State, Action>),
+/// }
+///
+/// impl ThunkAction<State> for Action {
+///     fn into_thunk(self) -> Result<Thunk<State, Action>, Self> {
+///         match self {
+///             Action::Thunk(thunk) => Ok(thunk),
+///             other => Err(other),
+///         }
+///     }
+/// }
+/// ```
+pub trait ThunkAction<S>: Sized {
+    /// Return the thunk this action carries, or the action itself otherwise
+    fn into_thunk(self) -> Result<Thunk<S, Self>, Self>;
+}
+
+/// Middleware that runs thunk actions instead of passing them to the reducer
+///
+/// Place it first so thunks never reach later middleware or the reducer.
+pub fn thunk_middleware<S, A>() -> Middleware<S, A>
+where
+    S: Clone + Send + Sync + 'static,
+    A: ThunkAction<S> + 'static,
+{
+    Arc::new(|api, action, next| match action.into_thunk() {
+        Ok(thunk) => {
+            let dispatch = api.dispatcher();
+            (thunk.run)(&dispatch, &|| api.get_state());
+        }
+        Err(action) => next(action),
+    })
+}
+
+/// One action recorded by an [`ActionLogger`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActionLogEntry<S> {
+    /// The action, formatted with `Debug`
+    pub action: String,
+    /// State before the action
+    pub prev_state: S,
+    /// State after the action
+    pub next_state: S,
+}
+
+/// Records every action and the state transition it caused
+///
+/// Clones share the same log, so keep one outside the component to inspect
+/// entries in tests or a debug panel.
+#[derive(Clone)]
+pub struct ActionLogger<S> {
+    entries: Arc<RwLock<Vec<ActionLogEntry<S>>>>,
+}
+
+impl<S: Clone + Send + Sync + 'static> ActionLogger<S> {
+    /// Create an empty logger
+    pub fn new() -> Self {
+        Self {
+            entries: Arc::new(RwLock::new(Vec::new())),
+        }
+    }
+
+    /// Create the middleware that records into this logger
+    pub fn middleware<A: fmt::Debug + 'static>(&self) -> Middleware<S, A> {
+        let entries = self.entries.clone();
+        Arc::new(move |api, action, next| {
+            let label = format!("{:?}", action);
+            let prev_state = api.get_state();
+            next(action);
+            write_or_recover(&entries).push(ActionLogEntry {
+                action: label,
+                prev_state,
+                next_state: api.get_state(),
+            });
+        })
+    }
+
+    /// Get a copy of all recorded entries, oldest first
+    pub fn entries(&self) -> Vec<ActionLogEntry<S>> {
+        read_or_recover(&self.entries).clone()
+    }
+
+    /// Get the number of recorded entries
+    pub fn len(&self) -> usize {
+        read_or_recover(&self.entries).len()
+    }
+
+    /// Check if nothing has been recorded
+    pub fn is_empty(&self) -> bool {
+        read_or_recover(&self.entries).is_empty()
+    }
+
+    /// Discard all recorded entries
+    pub fn clear(&self) {
+        write_or_recover(&self.entries).clear();
+    }
+}
+
+impl<S: Clone + Send + Sync + 'static> Default for ActionLogger<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -190,4 +429,82 @@ mod tests {
         assert_eq!(state.value, 0);
         assert_eq!(init_calls.load(Ordering::SeqCst), 1);
     }
+
+    #[derive(Debug)]
+    enum ThunkTestAction {
+        Add(i32),
+        Thunk(Thunk<TestState, ThunkTestAction>),
+    }
+
+    impl ThunkAction<TestState> for ThunkTestAction {
+        fn into_thunk(self) -> Result<Thunk<TestState, Self>, Self> {
+            match self {
+                ThunkTestAction::Thunk(thunk) => Ok(thunk),
+                other => Err(other),
+            }
+        }
+    }
+
+    fn thunk_test_reducer(state: &TestState, action: ThunkTestAction) -> TestState {
+        match action {
+            ThunkTestAction::Add(n) => TestState {
+                value: state.value + n,
+            },
+            ThunkTestAction::Thunk(_) => state.clone(),
+        }
+    }
+
+    #[test]
+    fn test_thunk_and_logger_middleware() {
+        let ctx = Rc::new(RefCell::new(HookContext::new()));
+        let logger = ActionLogger::new();
+
+        let middlewares = vec![thunk_middleware(), logger.middleware()];
+        let (_, dispatch) = with_hooks(ctx.clone(), || {
+            use_reducer_with_middleware(TestState { value: 1 }, thunk_test_reducer, middlewares)
+        });
+
+        dispatch.dispatch(ThunkTestAction::Add(2));
+        // The thunk reads state and dispatches through the full chain
+        dispatch.dispatch(ThunkTestAction::Thunk(Thunk::new(
+            |dispatch, get_state: &dyn Fn() -> TestState| {
+                let value = get_state().value;
+                dispatch.dispatch(ThunkTestAction::Add(value * 10));
+            },
+        )));
+
+        let (state, _) = with_hooks(ctx.clone(), || {
+            use_reducer_with_middleware(TestState { value: 0 }, thunk_test_reducer, vec![])
+        });
+        assert_eq!(state.value, 33);
+
+        let entries = logger.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].action, "Add(2)");
+        assert_eq!(entries[0].prev_state, TestState { value: 1 });
+        assert_eq!(entries[0].next_state, TestState { value: 3 });
+        assert_eq!(entries[1].action, "Add(30)");
+        assert_eq!(entries[1].next_state, TestState { value: 33 });
+    }
+
+    #[test]
+    fn test_middleware_can_swallow_actions() {
+        let ctx = Rc::new(RefCell::new(HookContext::new()));
+        let only_positive: Middleware<TestState, TestAction> =
+            Arc::new(|_, action, next| match action {
+                TestAction::Add(n) if n < 0 => {}
+                action => next(action),
+            });
+
+        let (_, dispatch) = with_hooks(ctx.clone(), || {
+            use_reducer_with_middleware(TestState { value: 0 }, test_reducer, vec![only_positive])
+        });
+        dispatch.dispatch(TestAction::Add(-5));
+        dispatch.dispatch(TestAction::Add(4));
+
+        let (state, _) = with_hooks(ctx.clone(), || {
+            use_reducer(TestState { value: 0 }, test_reducer)
+        });
+        assert_eq!(state.value, 4);
+    }
 }
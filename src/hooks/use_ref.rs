@@ -61,6 +61,11 @@ impl<T> RefHandle<T> {
         f(&mut write_or_recover(&self.value));
     }
 
+    /// Mutate the current value in place and return a result.
+    pub fn with_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        f(&mut write_or_recover(&self.value))
+    }
+
     /// Try to mutate the current value in place, returning false if lock is poisoned.
     pub fn try_update(&self, f: impl FnOnce(&mut T)) -> bool {
         if let Ok(mut guard) = self.value.write() {
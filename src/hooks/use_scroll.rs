@@ -3,6 +3,8 @@
 //! Provides scroll state management for scrollable content areas.
 
 use crate::hooks::lock_utils::{read_or_recover, write_or_recover};
+use crate::hooks::use_input::Key;
+use crate::hooks::use_mouse::{Mouse, MouseAction};
 use std::sync::{Arc, RwLock};
 
 /// Scroll state for a scrollable area
@@ -177,6 +179,46 @@ impl ScrollState {
         (start, end)
     }
 
+    /// Apply a key press (arrows, Page Up/Down, Home/End)
+    ///
+    /// Returns true if the key was a scroll key and the offset changed.
+    pub fn handle_key(&mut self, key: &Key) -> bool {
+        let before = (self.offset_x, self.offset_y);
+        if key.up_arrow {
+            self.scroll_up(1);
+        } else if key.down_arrow {
+            self.scroll_down(1);
+        } else if key.left_arrow {
+            self.scroll_left(1);
+        } else if key.right_arrow {
+            self.scroll_right(1);
+        } else if key.page_up {
+            self.page_up();
+        } else if key.page_down {
+            self.page_down();
+        } else if key.home {
+            self.scroll_to_top();
+        } else if key.end {
+            self.scroll_to_bottom();
+        }
+        before != (self.offset_x, self.offset_y)
+    }
+
+    /// Apply a mouse wheel event, scrolling `lines` per tick
+    ///
+    /// Returns true if the event was a wheel event and the offset changed.
+    pub fn handle_mouse_wheel(&mut self, mouse: &Mouse, lines: usize) -> bool {
+        let before = (self.offset_x, self.offset_y);
+        match mouse.action {
+            MouseAction::ScrollUp => self.scroll_up(lines),
+            MouseAction::ScrollDown => self.scroll_down(lines),
+            MouseAction::ScrollLeft => self.scroll_left(lines),
+            MouseAction::ScrollRight => self.scroll_right(lines),
+            _ => {}
+        }
+        before != (self.offset_x, self.offset_y)
+    }
+
     /// Clamp offset to valid range
     fn clamp_offset(&mut self) {
         self.offset_y = self.offset_y.min(self.max_offset_y());
@@ -294,6 +336,16 @@ impl ScrollHandle {
         write_or_recover(&self.state).scroll_to_item(index);
     }
 
+    /// Apply a key press, returning true if the offset changed
+    pub fn handle_key(&self, key: &Key) -> bool {
+        write_or_recover(&self.state).handle_key(key)
+    }
+
+    /// Apply a mouse wheel event, returning true if the offset changed
+    pub fn handle_mouse_wheel(&self, mouse: &Mouse, lines: usize) -> bool {
+        write_or_recover(&self.state).handle_mouse_wheel(mouse, lines)
+    }
+
     /// Check if can scroll up
     pub fn can_scroll_up(&self) -> bool {
         read_or_recover(&self.state).can_scroll_up()
@@ -502,6 +554,39 @@ mod tests {
         assert_eq!(state.offset_y, 40); // Clamped to max
     }
 
+    #[test]
+    fn test_handle_key_and_mouse_wheel() {
+        let mut state = ScrollState::with_viewport(10, 5);
+        state.set_content_size(10, 20);
+
+        let down = Key {
+            down_arrow: true,
+            ..Key::default()
+        };
+        assert!(state.handle_key(&down));
+        assert_eq!(state.offset_y, 1);
+
+        let end = Key {
+            end: true,
+            ..Key::default()
+        };
+        assert!(state.handle_key(&end));
+        assert_eq!(state.offset_y, 15);
+        assert!(!state.handle_key(&down));
+        assert!(!state.handle_key(&Key::default()));
+
+        let wheel_up = Mouse {
+            x: 0,
+            y: 0,
+            action: MouseAction::ScrollUp,
+            ctrl: false,
+            shift: false,
+            alt: false,
+        };
+        assert!(state.handle_mouse_wheel(&wheel_up, 3));
+        assert_eq!(state.offset_y, 12);
+    }
+
     #[test]
     fn test_scroll_up() {
         let mut state = ScrollState::new();
@@ -2,8 +2,16 @@
 
 use crate::hooks::context::{RenderCallback, current_context};
 use crate::hooks::lock_utils::{read_or_recover, write_or_recover};
+use std::cell::{Cell, RefCell};
 use std::sync::{Arc, RwLock};
 
+thread_local! {
+    /// Nesting depth of [`batch`] calls on this thread
+    static BATCH_DEPTH: Cell<usize> = const { Cell::new(0) };
+    /// Render callbacks deferred by the current batch, without duplicates
+    static BATCH_PENDING: RefCell<Vec<RenderCallback>> = const { RefCell::new(Vec::new()) };
+}
+
 /// A reactive signal that triggers re-renders when updated
 #[derive(Clone)]
 pub struct Signal<T> {
@@ -109,9 +117,19 @@ impl<T> Signal<T> {
     }
 
     fn trigger_render(&self) {
-        if let Some(callback) = &self.render_callback {
+        let Some(callback) = &self.render_callback else {
+            return;
+        };
+        if BATCH_DEPTH.with(Cell::get) == 0 {
             callback();
+            return;
         }
+        BATCH_PENDING.with(|pending| {
+            let mut pending = pending.borrow_mut();
+            if !pending.iter().any(|queued| Arc::ptr_eq(queued, callback)) {
+                pending.push(callback.clone());
+            }
+        });
     }
 }
 
@@ -127,6 +145,50 @@ impl<T: std::fmt::Debug> std::fmt::Debug for Signal<T> {
     }
 }
 
+/// Coalesce the render requests of every signal write inside `f`
+///
+/// Writes still take effect immediately, but each render target is asked
+/// to render once, when the outermost `batch` returns. Nested calls join
+/// the enclosing batch. Only writes made on the calling thread are batched.
+///
+/// # Example
+///
+/// ```ignore
+/// use_input(move |_, key| {
+///     if key.return_key {
+///         batch(|| {
+///             items.update(|items| items.push(draft.get()));
+///             draft.set(String::new());
+///             status.set("Added".into());
+///         });
+///     }
+/// });
+/// ```
+pub fn batch<R>(f: impl FnOnce() -> R) -> R {
+    /// Closes the batch even if `f` panics
+    struct BatchGuard;
+
+    impl Drop for BatchGuard {
+        fn drop(&mut self) {
+            let depth = BATCH_DEPTH.with(|depth| {
+                depth.set(depth.get() - 1);
+                depth.get()
+            });
+            if depth == 0 {
+                let pending =
+                    BATCH_PENDING.with(|pending| std::mem::take(&mut *pending.borrow_mut()));
+                for callback in pending {
+                    callback();
+                }
+            }
+        }
+    }
+
+    BATCH_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let _guard = BatchGuard;
+    f()
+}
+
 /// Internal storage for signal
 #[derive(Clone)]
 struct SignalStorage<T> {
@@ -308,4 +370,75 @@ mod tests {
         handle.join().unwrap();
         assert_eq!(signal.get(), 200);
     }
+
+    fn counting_callback() -> (RenderCallback, Arc<AtomicUsize>) {
+        let renders = Arc::new(AtomicUsize::new(0));
+        let counter = renders.clone();
+        let callback: RenderCallback = Arc::new(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        (callback, renders)
+    }
+
+    #[test]
+    fn test_batch_coalesces_renders() {
+        let (callback, renders) = counting_callback();
+        let count = Signal::new(0i32, Some(callback.clone()));
+        let name = Signal::new(String::new(), Some(callback));
+
+        let result = batch(|| {
+            count.set(1);
+            name.set("Bob".to_string());
+            count.update(|c| *c += 1);
+            // Writes are visible inside the batch
+            assert_eq!(count.get(), 2);
+            assert_eq!(renders.load(Ordering::SeqCst), 0);
+            "done"
+        });
+
+        assert_eq!(result, "done");
+        assert_eq!(renders.load(Ordering::SeqCst), 1);
+
+        count.set(3);
+        assert_eq!(renders.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_nested_batch_flushes_once_at_outermost() {
+        let (callback, renders) = counting_callback();
+        let (other_callback, other_renders) = counting_callback();
+        let signal = Signal::new(0i32, Some(callback));
+        let other = Signal::new(0i32, Some(other_callback));
+
+        batch(|| {
+            signal.set(1);
+            batch(|| {
+                signal.set(2);
+                other.set(1);
+            });
+            assert_eq!(renders.load(Ordering::SeqCst), 0);
+            signal.set(3);
+        });
+
+        assert_eq!(renders.load(Ordering::SeqCst), 1);
+        assert_eq!(other_renders.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_batch_flushes_after_panic() {
+        let (callback, renders) = counting_callback();
+        let signal = Signal::new(0i32, Some(callback));
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            batch(|| {
+                signal.set(1);
+                panic!("handler failed");
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(renders.load(Ordering::SeqCst), 1);
+        signal.set(2);
+        assert_eq!(renders.load(Ordering::SeqCst), 2);
+    }
 }
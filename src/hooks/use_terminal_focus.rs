@@ -0,0 +1,72 @@
+//! use_terminal_focus hook for tracking terminal window focus
+//!
+//! When the terminal supports focus reporting (`CSI ? 1004 h`), the event
+//! loop receives focus-in/focus-out events and records them here. Terminals
+//! that never report focus are treated as always focused.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use rnk::prelude::*;
+//!
+//! fn app() -> Element {
+//!     let focused = use_terminal_focus();
+//!
+//!     if focused {
+//!         Text::new("Watching for changes").into_element()
+//!     } else {
+//!         Text::new("Paused (terminal unfocused)").dim().into_element()
+//!     }
+//! }
+//! ```
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Last focus state reported by the terminal
+static TERMINAL_FOCUSED: AtomicBool = AtomicBool::new(true);
+
+/// Serializes tests that flip the process-wide focus flag
+#[cfg(test)]
+pub(crate) static FOCUS_TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Check whether the terminal window currently has focus
+///
+/// Safe to call from any thread, including interval callbacks.
+pub fn is_terminal_focused() -> bool {
+    TERMINAL_FOCUSED.load(Ordering::SeqCst)
+}
+
+/// Record a terminal focus change
+///
+/// Called by the event loop when a focus event arrives. Custom event loops
+/// can call this to feed their own focus events into the hooks.
+pub fn set_terminal_focused(focused: bool) {
+    TERMINAL_FOCUSED.store(focused, Ordering::SeqCst);
+}
+
+/// Hook to check whether the terminal window has focus
+///
+/// Returns `true` when focus reporting is unavailable. The app re-renders on
+/// every focus change, so the returned value is always current.
+pub fn use_terminal_focus() -> bool {
+    is_terminal_focused()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_terminal_focused_round_trip() {
+        let _guard = FOCUS_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let original = is_terminal_focused();
+
+        set_terminal_focused(false);
+        assert!(!use_terminal_focus());
+
+        set_terminal_focused(true);
+        assert!(use_terminal_focus());
+
+        set_terminal_focused(original);
+    }
+}
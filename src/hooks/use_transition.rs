@@ -2,9 +2,11 @@
 //!
 //! Provides a simple hook for transitioning between values with easing.
 
-use crate::animation::{Animation, AnimationInstance, Easing, FillMode};
+use crate::animation::{Animation, AnimationInstance, Easing, FillMode, prefers_reduced_motion};
 use crate::hooks::context::{RenderCallback, current_context};
 use crate::hooks::lock_utils::{read_or_recover, write_or_recover};
+use crate::renderer::frame_rate::AnimationActivity;
+use crate::runtime::{Clock, current_clock};
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 
@@ -18,6 +20,8 @@ pub struct TransitionHandle {
     easing: Easing,
     last_tick: Arc<RwLock<Instant>>,
     render_callback: Option<RenderCallback>,
+    activity: Arc<AnimationActivity>,
+    clock: Clock,
 }
 
 impl TransitionHandle {
@@ -47,7 +51,15 @@ impl TransitionHandle {
     }
 
     /// Set a new target value and start transitioning
+    ///
+    /// With [reduced motion](crate::animation::prefers_reduced_motion) on,
+    /// the value jumps straight to the target.
     pub fn set(&self, value: f32) {
+        if prefers_reduced_motion() {
+            self.set_immediate(value);
+            return;
+        }
+
         let current = self.get();
         *write_or_recover(&self.target) = value;
 
@@ -55,6 +67,7 @@ impl TransitionHandle {
             // Already at target, no transition needed
             *write_or_recover(&self.current) = value;
             *write_or_recover(&self.instance) = None;
+            self.sync_activity();
             return;
         }
 
@@ -70,8 +83,9 @@ impl TransitionHandle {
         instance.play();
 
         *write_or_recover(&self.instance) = Some(instance);
-        *write_or_recover(&self.last_tick) = Instant::now();
+        *write_or_recover(&self.last_tick) = self.clock.now();
 
+        self.sync_activity();
         self.trigger_render();
     }
 
@@ -80,6 +94,7 @@ impl TransitionHandle {
         *write_or_recover(&self.current) = value;
         *write_or_recover(&self.target) = value;
         *write_or_recover(&self.instance) = None;
+        self.sync_activity();
         self.trigger_render();
     }
 
@@ -90,9 +105,9 @@ impl TransitionHandle {
             .is_some_and(|i| i.is_running())
     }
 
-    /// Tick the transition (called internally)
+    /// Tick the transition (called on each render of `use_transition`)
     pub fn tick(&self) {
-        let now = Instant::now();
+        let now = self.clock.now();
         let delta = {
             let mut last = write_or_recover(&self.last_tick);
             let delta = now.duration_since(*last);
@@ -109,6 +124,8 @@ impl TransitionHandle {
                 // Update current to final value
                 *write_or_recover(&self.current) = *read_or_recover(&self.target);
                 *instance_guard = None;
+                drop(instance_guard);
+                self.sync_activity();
             } else if was_running && instance.is_running() {
                 drop(instance_guard);
                 self.trigger_render();
@@ -116,6 +133,11 @@ impl TransitionHandle {
         }
     }
 
+    /// Keep the frame rate controller's active-animation count in step
+    fn sync_activity(&self) {
+        self.activity.set(self.is_transitioning());
+    }
+
     fn trigger_render(&self) {
         if let Some(callback) = &self.render_callback {
             callback();
@@ -146,6 +168,10 @@ impl TransitionHandle {
 
     /// Try to set a new target value, returning false if lock is poisoned
     pub fn try_set(&self, value: f32) -> bool {
+        if prefers_reduced_motion() {
+            return self.try_set_immediate(value);
+        }
+
         let current = match self.try_get() {
             Some(v) => v,
             None => return false,
@@ -163,6 +189,7 @@ impl TransitionHandle {
             if self.instance.write().ok().map(|mut g| *g = None).is_none() {
                 return false;
             }
+            self.sync_activity();
             return true;
         }
 
@@ -190,12 +217,13 @@ impl TransitionHandle {
             .last_tick
             .write()
             .ok()
-            .map(|mut g| *g = Instant::now())
+            .map(|mut g| *g = self.clock.now())
             .is_none()
         {
             return false;
         }
 
+        self.sync_activity();
         self.trigger_render();
         true
     }
@@ -211,6 +239,7 @@ impl TransitionHandle {
         if self.instance.write().ok().map(|mut g| *g = None).is_none() {
             return false;
         }
+        self.sync_activity();
         self.trigger_render();
         true
     }
@@ -246,14 +275,17 @@ fn new_transition_handle(
     easing: Easing,
     render_callback: Option<RenderCallback>,
 ) -> TransitionHandle {
+    let clock = current_clock();
     TransitionHandle {
         current: Arc::new(RwLock::new(initial)),
         target: Arc::new(RwLock::new(initial)),
         instance: Arc::new(RwLock::new(None)),
         duration,
         easing,
-        last_tick: Arc::new(RwLock::new(Instant::now())),
+        last_tick: Arc::new(RwLock::new(clock.now())),
         render_callback,
+        activity: Arc::default(),
+        clock,
     }
 }
 
@@ -311,10 +343,18 @@ pub fn use_transition_with_easing(
         handle: new_transition_handle(initial, duration, easing, render_callback.clone()),
     });
 
-    storage
+    let handle = storage
         .get::<TransitionStorage>()
         .map(|s| s.handle)
-        .unwrap_or_else(|| new_transition_handle(initial, duration, easing, render_callback))
+        .unwrap_or_else(|| new_transition_handle(initial, duration, easing, render_callback));
+    drop(ctx_ref);
+
+    // Advance by the time since the last frame, so a running transition
+    // moves in step with rendering
+    if handle.is_transitioning() {
+        handle.tick();
+    }
+    handle
 }
 
 #[cfg(test)]
@@ -335,6 +375,8 @@ mod tests {
             easing: Easing::Linear,
             last_tick: Arc::new(RwLock::new(Instant::now())),
             render_callback: None,
+            activity: Arc::default(),
+            clock: Clock::system(),
         };
 
         assert_eq!(handle.get(), 0.0);
@@ -351,6 +393,8 @@ mod tests {
             easing: Easing::Linear,
             last_tick: Arc::new(RwLock::new(Instant::now())),
             render_callback: None,
+            activity: Arc::default(),
+            clock: Clock::system(),
         };
 
         handle.set(100.0);
@@ -358,6 +402,26 @@ mod tests {
         assert_eq!(handle.target(), 100.0);
     }
 
+    #[test]
+    fn test_transition_jumps_to_target_with_reduced_motion() {
+        let handle = TransitionHandle {
+            current: Arc::new(RwLock::new(0.0)),
+            target: Arc::new(RwLock::new(0.0)),
+            instance: Arc::new(RwLock::new(None)),
+            duration: Duration::from_millis(100),
+            easing: Easing::Linear,
+            last_tick: Arc::new(RwLock::new(Instant::now())),
+            render_callback: None,
+            activity: Arc::default(),
+            clock: Clock::system(),
+        };
+
+        crate::animation::with_reduced_motion(true, || handle.set(100.0));
+        assert!(!handle.is_transitioning());
+        assert_eq!(handle.get(), 100.0);
+        assert_eq!(handle.target(), 100.0);
+    }
+
     #[test]
     fn test_transition_immediate() {
         let handle = TransitionHandle {
@@ -368,6 +432,8 @@ mod tests {
             easing: Easing::Linear,
             last_tick: Arc::new(RwLock::new(Instant::now())),
             render_callback: None,
+            activity: Arc::default(),
+            clock: Clock::system(),
         };
 
         handle.set_immediate(50.0);
@@ -411,6 +477,8 @@ mod tests {
             easing: Easing::Linear,
             last_tick: Arc::new(RwLock::new(Instant::now())),
             render_callback: None,
+            activity: Arc::default(),
+            clock: Clock::system(),
         };
 
         // Setting to same value should not start transition
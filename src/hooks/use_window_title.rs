@@ -1,8 +1,16 @@
 //! Window title control hook
 //!
 //! Provides functions to set the terminal window title.
+//!
+//! Titles set through [`use_window_title`] and [`push_window_title`] form a
+//! stack: a pushed title shows until its guard is dropped, then the title
+//! below it comes back, however the guards nest. The terminal is only
+//! written to when the visible title actually changes.
 
+use crate::cmd::TerminalCmd;
+use crate::renderer::registry::queue_terminal_cmd;
 use std::io::{Write, stdout};
+use std::sync::{LazyLock, Mutex};
 
 /// ANSI escape sequence for setting window title (OSC - Operating System Command)
 /// Format: ESC ] 0 ; <title> BEL  or  ESC ] 0 ; <title> ST
@@ -24,8 +32,116 @@ fn restore_title_escape() -> &'static str {
     "\x1b]0;\x07"
 }
 
+/// What the terminal should show after the title stack changed
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TitleChange {
+    Unchanged,
+    Set(String),
+    Restore,
+}
+
+/// Titles owned by hooks and guards, top of stack visible
+#[derive(Debug, Default)]
+struct TitleStack {
+    /// Title from `use_window_title`, shown when nothing is pushed
+    base: Option<String>,
+    /// Pushed titles with the id of their guard
+    entries: Vec<(u64, String)>,
+    next_id: u64,
+    /// Title last written to the terminal
+    shown: Option<String>,
+}
+
+impl TitleStack {
+    fn set_base(&mut self, title: &str) -> TitleChange {
+        self.base = Some(title.to_string());
+        self.sync()
+    }
+
+    fn push(&mut self, title: &str) -> (u64, TitleChange) {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.entries.push((id, title.to_string()));
+        (id, self.sync())
+    }
+
+    fn remove(&mut self, id: u64) -> TitleChange {
+        self.entries.retain(|(entry_id, _)| *entry_id != id);
+        self.sync()
+    }
+
+    fn sync(&mut self) -> TitleChange {
+        let top = self
+            .entries
+            .last()
+            .map(|(_, title)| title)
+            .or(self.base.as_ref());
+        if top == self.shown.as_ref() {
+            return TitleChange::Unchanged;
+        }
+        self.shown = top.cloned();
+        match top {
+            Some(title) => TitleChange::Set(title.clone()),
+            None => TitleChange::Restore,
+        }
+    }
+}
+
+static TITLE_STACK: LazyLock<Mutex<TitleStack>> = LazyLock::new(Default::default);
+
+fn with_title_stack(f: impl FnOnce(&mut TitleStack) -> TitleChange) {
+    let change = match TITLE_STACK.lock() {
+        Ok(mut stack) => f(&mut stack),
+        Err(poisoned) => f(&mut poisoned.into_inner()),
+    };
+    match change {
+        TitleChange::Unchanged => {}
+        TitleChange::Set(title) => set_window_title(&title),
+        TitleChange::Restore => write_restore_title(),
+    }
+}
+
+fn write_restore_title() {
+    if queue_terminal_cmd(TerminalCmd::SetWindowTitle(String::new())) {
+        return;
+    }
+    let mut stdout = stdout();
+    let _ = write!(stdout, "{}", restore_title_escape());
+    let _ = stdout.flush();
+}
+
+/// Show `title` until the returned guard is dropped
+///
+/// Titles pushed later take precedence; dropping a guard brings back the
+/// title below it, even when guards are dropped out of order.
+///
+/// # Example
+///
+/// ```ignore
+/// use rnk::hooks::push_window_title;
+///
+/// let _title = push_window_title("Uploading… 40%");
+/// // ... the previous title returns when `_title` goes out of scope
+/// ```
+pub fn push_window_title(title: &str) -> WindowTitleGuard {
+    let mut guard_id = 0;
+    with_title_stack(|stack| {
+        let (id, change) = stack.push(title);
+        guard_id = id;
+        change
+    });
+    WindowTitleGuard {
+        original_title: None,
+        stack_id: Some(guard_id),
+    }
+}
+
 /// Set the terminal window title
 ///
+/// This writes the title directly, bypassing the title stack used by
+/// [`use_window_title`] and [`push_window_title`]. Inside a running app it
+/// goes through the app's terminal, and is skipped without terminal control.
+///
 /// # Example
 ///
 /// ```ignore
@@ -34,6 +150,9 @@ fn restore_title_escape() -> &'static str {
 /// set_window_title("My Application - v1.0");
 /// ```
 pub fn set_window_title(title: &str) {
+    if queue_terminal_cmd(TerminalCmd::SetWindowTitle(sanitize_title(title))) {
+        return;
+    }
     let mut stdout = stdout();
     let _ = write!(stdout, "{}", set_title_escape(title));
     let _ = stdout.flush();
@@ -45,33 +164,42 @@ pub fn clear_window_title() {
 }
 
 /// RAII guard for window title that restores on drop
+///
+/// Guards from [`push_window_title`] remove their title from the stack;
+/// guards from [`WindowTitleGuard::new`] write `original_title` back.
 pub struct WindowTitleGuard {
     original_title: Option<String>,
+    stack_id: Option<u64>,
 }
 
 impl WindowTitleGuard {
     /// Create a new guard that will attempt to restore the title on drop
     pub fn new(original_title: Option<String>) -> Self {
-        Self { original_title }
+        Self {
+            original_title,
+            stack_id: None,
+        }
     }
 }
 
 impl Drop for WindowTitleGuard {
     fn drop(&mut self) {
-        if let Some(ref title) = self.original_title {
+        if let Some(id) = self.stack_id {
+            with_title_stack(|stack| stack.remove(id));
+        } else if let Some(ref title) = self.original_title {
             set_window_title(title);
         } else {
             // Try to restore to empty
-            let mut stdout = stdout();
-            let _ = write!(stdout, "{}", restore_title_escape());
-            let _ = stdout.flush();
+            write_restore_title();
         }
     }
 }
 
 /// Hook to set the window title
 ///
-/// The title will be set each time the component renders.
+/// The title is the bottom of the title stack: it shows whenever no title
+/// from [`push_window_title`] is active, and is only written to the
+/// terminal when it changes.
 ///
 /// # Example
 ///
@@ -92,7 +220,7 @@ impl Drop for WindowTitleGuard {
 /// }
 /// ```
 pub fn use_window_title(title: &str) {
-    set_window_title(title);
+    with_title_stack(|stack| stack.set_base(title));
 }
 
 /// Hook to set the window title with a function
@@ -116,7 +244,7 @@ pub fn use_window_title_fn<F>(f: F)
 where
     F: FnOnce() -> String,
 {
-    set_window_title(&f());
+    use_window_title(&f());
 }
 
 #[cfg(test)]
@@ -167,4 +295,31 @@ mod tests {
             "payload must not contain terminal control characters"
         );
     }
+
+    #[test]
+    fn test_title_stack_restores_nested_titles() {
+        let mut stack = TitleStack::default();
+        assert_eq!(stack.set_base("App"), TitleChange::Set("App".into()));
+        assert_eq!(stack.set_base("App"), TitleChange::Unchanged);
+
+        let (modal, change) = stack.push("Modal");
+        assert_eq!(change, TitleChange::Set("Modal".into()));
+        let (progress, change) = stack.push("Progress");
+        assert_eq!(change, TitleChange::Set("Progress".into()));
+
+        // The base changing underneath pushed titles doesn't show
+        assert_eq!(stack.set_base("App 2"), TitleChange::Unchanged);
+
+        // Dropping a guard out of order keeps the top title
+        assert_eq!(stack.remove(modal), TitleChange::Unchanged);
+        assert_eq!(stack.remove(progress), TitleChange::Set("App 2".into()));
+    }
+
+    #[test]
+    fn test_title_stack_restores_terminal_title_when_empty() {
+        let mut stack = TitleStack::default();
+        let (id, _) = stack.push("Busy");
+        assert_eq!(stack.remove(id), TitleChange::Restore);
+        assert_eq!(stack.remove(id), TitleChange::Unchanged);
+    }
 }
@@ -1,8 +1,11 @@
 //! Layout engine using Taffy
 
-use crate::core::{Element, ElementId, ElementType, NodeKey, Props, VNode, VNodeType};
-use crate::layout::measure::measure_text_width;
+use crate::core::{
+    Element, ElementId, ElementType, NodeKey, Props, Style, TextOverflow, VNode, VNodeType,
+};
+use crate::layout::measure::MeasureCache;
 use crate::reconciler::{Patch, diff};
+use crate::runtime::supports_double_width_lines;
 use std::collections::HashMap;
 use taffy::{AvailableSpace, NodeId, TaffyTree};
 
@@ -30,6 +33,15 @@ pub struct IncrementalLayoutOutcome {
 #[derive(Clone)]
 struct NodeContext {
     text_content: Option<String>,
+    /// Text is laid out on one row per line and truncated when painted
+    single_line: bool,
+    /// Every character takes two cells (double-width lines)
+    double_width: bool,
+}
+
+/// Whether text with `style` is drawn on double-width lines
+fn is_double_width(style: &Style) -> bool {
+    style.double_width && supports_double_width_lines()
 }
 
 /// Layout engine that computes element positions
@@ -45,6 +57,8 @@ pub struct LayoutEngine {
     last_width: u16,
     /// Last computed height
     last_height: u16,
+    /// Text measurements reused across layout passes
+    measure_cache: MeasureCache,
 }
 
 impl LayoutEngine {
@@ -57,6 +71,7 @@ impl LayoutEngine {
             root_node: None,
             last_width: 0,
             last_height: 0,
+            measure_cache: MeasureCache::default(),
         }
     }
 
@@ -87,6 +102,8 @@ impl LayoutEngine {
 
         let context = NodeContext {
             text_content: element.text_content.clone(),
+            single_line: element.style.text_overflow == TextOverflow::Ellipsis,
+            double_width: is_double_width(&element.style),
         };
 
         // Create node with measure function for text
@@ -121,7 +138,12 @@ impl LayoutEngine {
                     height: AvailableSpace::Definite(height as f32),
                 },
                 |known_dimensions, available_space, _node_id, node_context, _style| {
-                    measure_text_node(known_dimensions, available_space, node_context)
+                    measure_text_node(
+                        known_dimensions,
+                        available_space,
+                        node_context,
+                        &mut self.measure_cache,
+                    )
                 },
             );
         }
@@ -200,7 +222,11 @@ impl LayoutEngine {
             _ => None,
         };
 
-        let context = NodeContext { text_content };
+        let context = NodeContext {
+            text_content,
+            single_line: vnode.props.style.text_overflow == TextOverflow::Ellipsis,
+            double_width: is_double_width(&vnode.props.style),
+        };
 
         // Create node
         let node_id = if vnode.is_text() {
@@ -295,7 +321,12 @@ impl LayoutEngine {
                     height: AvailableSpace::Definite(height as f32),
                 },
                 |known_dimensions, available_space, _node_id, node_context, _style| {
-                    measure_text_node(known_dimensions, available_space, node_context)
+                    measure_text_node(
+                        known_dimensions,
+                        available_space,
+                        node_context,
+                        &mut self.measure_cache,
+                    )
                 },
             );
         }
@@ -369,6 +400,10 @@ impl LayoutEngine {
     fn update_node_props(&mut self, key: NodeKey, props: &Props) -> bool {
         if let Some(&node_id) = self.vnode_map.get(&key) {
             let new_style = props.to_taffy();
+            if let Some(ctx) = self.taffy.get_node_context_mut(node_id) {
+                ctx.single_line = props.style.text_overflow == TextOverflow::Ellipsis;
+                ctx.double_width = is_double_width(&props.style);
+            }
             if self.taffy.set_style(node_id, new_style).is_ok() {
                 return true;
             }
@@ -454,7 +489,12 @@ impl LayoutEngine {
                     height: AvailableSpace::Definite(self.last_height as f32),
                 },
                 |known_dimensions, available_space, _node_id, node_context, _style| {
-                    measure_text_node(known_dimensions, available_space, node_context)
+                    measure_text_node(
+                        known_dimensions,
+                        available_space,
+                        node_context,
+                        &mut self.measure_cache,
+                    )
                 },
             );
         }
@@ -529,6 +569,24 @@ impl LayoutEngine {
         self.element_keys.get(&element_id).copied()
     }
 
+    /// Drop cached text measurements
+    ///
+    /// Entries for changed text age out on their own; clearing only frees
+    /// memory, e.g. after leaving a text-heavy screen.
+    pub fn clear_measure_cache(&mut self) {
+        self.measure_cache.clear();
+    }
+
+    /// Get the text measurement cache
+    pub fn measure_cache(&self) -> &MeasureCache {
+        &self.measure_cache
+    }
+
+    /// Get the text measurement cache mutably, e.g. to resize it
+    pub fn measure_cache_mut(&mut self) -> &mut MeasureCache {
+        &mut self.measure_cache
+    }
+
     /// Check if the engine has a valid tree
     pub fn has_tree(&self) -> bool {
         self.root_node.is_some()
@@ -551,7 +609,14 @@ fn measure_text_node(
     known_dimensions: taffy::Size<Option<f32>>,
     available_space: taffy::Size<AvailableSpace>,
     node_context: Option<&mut NodeContext>,
+    cache: &mut MeasureCache,
 ) -> taffy::Size<f32> {
+    let single_line = node_context.as_ref().is_some_and(|ctx| ctx.single_line);
+    let scale = if node_context.as_ref().is_some_and(|ctx| ctx.double_width) {
+        2
+    } else {
+        1
+    };
     let text = node_context
         .and_then(|ctx| ctx.text_content.as_ref())
         .map(|s| s.as_str())
@@ -564,36 +629,26 @@ fn measure_text_node(
         };
     }
 
-    // Measure text using unicode-width
-    let text_width = measure_text_width(text) as f32;
-
-    // Calculate height considering text wrapping
+    // Measure text using unicode-width, wrapping to the available width
+    // Single-line text never wraps; it is truncated to its box when painted
     let available_width = match available_space.width {
-        AvailableSpace::Definite(w) => Some(w as usize),
+        AvailableSpace::Definite(w) if !single_line => Some(w as usize / scale),
         _ => None,
     };
-
-    let text_height = if let Some(max_width) = available_width {
-        if max_width > 0 && text_width > max_width as f32 {
-            // Text needs wrapping - count wrapped lines without allocation
-            use super::measure::count_wrapped_lines_by_width;
-            count_wrapped_lines_by_width(text, max_width) as f32
-        } else {
-            text.lines().count().max(1) as f32
-        }
-    } else {
-        text.lines().count().max(1) as f32
-    };
+    let (text_width, text_height) = cache.measure(text, available_width);
+    let text_width = (text_width * scale) as f32;
 
     let width = known_dimensions
         .width
         .unwrap_or_else(|| match available_space.width {
             AvailableSpace::Definite(w) => text_width.min(w),
+            // Let truncating text shrink down to its ellipsis
+            AvailableSpace::MinContent if single_line => text_width.min(1.0),
             AvailableSpace::MinContent => text_width,
             AvailableSpace::MaxContent => text_width,
         });
 
-    let height = known_dimensions.height.unwrap_or(text_height);
+    let height = known_dimensions.height.unwrap_or(text_height as f32);
 
     taffy::Size { width, height }
 }
@@ -869,4 +924,38 @@ mod tests {
         // root + text "A" + inner box + text "B" = 4 nodes
         assert_eq!(engine.node_count(), 4);
     }
+
+    #[test]
+    fn test_measure_cache_reused_across_passes_and_widths() {
+        let mut engine = LayoutEngine::new();
+        let root = Element::text("one two three four five six");
+
+        engine.compute(&root, 10, 24);
+        let narrow = engine.get_layout(root.id).unwrap();
+        let misses = engine.measure_cache().misses();
+        assert_eq!(engine.measure_cache().len(), 1);
+
+        engine.compute(&root, 10, 24);
+        assert_eq!(engine.measure_cache().misses(), misses);
+        assert_eq!(engine.get_layout(root.id).unwrap().height, narrow.height);
+
+        engine.compute(&root, 80, 24);
+        assert_eq!(engine.get_layout(root.id).unwrap().height, 1.0);
+        assert!(narrow.height > 1.0);
+
+        engine.clear_measure_cache();
+        assert!(engine.measure_cache().is_empty());
+    }
+
+    #[test]
+    fn test_double_width_text_reserves_two_cells_per_char() {
+        let _double_width = crate::runtime::DoubleWidthLinesOverride::new(true);
+        let root = crate::components::Text::new("Header")
+            .double_width()
+            .into_element();
+
+        let mut engine = LayoutEngine::new();
+        engine.compute(&root, 80, 24);
+        assert_eq!(engine.get_layout(root.id).unwrap().width, 12.0);
+    }
 }
@@ -1,11 +1,31 @@
 //! Text measurement utilities
 
+use std::collections::{BTreeMap, HashMap};
+
 use unicode_segmentation::UnicodeSegmentation;
-use unicode_width::UnicodeWidthStr;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-#[inline]
-fn grapheme_width(grapheme: &str) -> usize {
-    UnicodeWidthStr::width(grapheme)
+/// Zero width joiner, glues emoji into a single glyph
+const ZWJ: char = '\u{200D}';
+/// Variation selector 16, requests emoji presentation
+const VS16: char = '\u{FE0F}';
+
+/// Display width of a single grapheme cluster
+///
+/// Matches what modern terminals draw: an emoji ZWJ sequence
+/// (👨‍👩‍👧) or a VS16-qualified emoji (❤️) occupies two cells rather than the
+/// sum of its code points. Other clusters use their Unicode width.
+pub fn grapheme_width(grapheme: &str) -> usize {
+    if grapheme.chars().nth(1).is_none() {
+        return UnicodeWidthStr::width(grapheme);
+    }
+
+    let joins_emoji = grapheme.contains(ZWJ) && grapheme.chars().any(|ch| ch.width() == Some(2));
+    if joins_emoji || grapheme.contains(VS16) {
+        2
+    } else {
+        UnicodeWidthStr::width(grapheme)
+    }
 }
 
 #[inline]
@@ -35,12 +55,12 @@ fn take_prefix_by_width(text: &str, max_width: usize) -> String {
     let mut result = String::new();
     let mut width = 0;
     for grapheme in text.graphemes(true) {
-        let grapheme_width = grapheme_width(grapheme);
-        if width + grapheme_width > max_width {
+        let cluster_width = grapheme_width(grapheme);
+        if width + cluster_width > max_width {
             break;
         }
         result.push_str(grapheme);
-        width += grapheme_width;
+        width += cluster_width;
     }
     result
 }
@@ -52,12 +72,25 @@ fn take_prefix_by_width(text: &str, max_width: usize) -> String {
 /// - Emoji sequences (including ZWJ sequences like 👨‍👩‍👧‍👦)
 /// - Combining characters (e.g., é = e + combining acute)
 /// - Zero-width characters
+/// - Embedded escape sequences (SGR, OSC 8 hyperlinks), which take no width
 pub fn measure_text_width(text: &str) -> usize {
     if let Some(width) = ascii_width_fast_path(text) {
         return width;
     }
 
-    text.graphemes(true).map(grapheme_width).sum()
+    let mut width = 0;
+    let mut rest = text;
+    while let Some(pos) = rest.find('\x1b') {
+        width += rest[..pos]
+            .graphemes(true)
+            .map(grapheme_width)
+            .sum::<usize>();
+        rest = &rest[pos..];
+        // A lone ESC is skipped like any other control character
+        let len = ansi_escape_len(rest).unwrap_or(1);
+        rest = &rest[len..];
+    }
+    width + rest.graphemes(true).map(grapheme_width).sum::<usize>()
 }
 
 /// Measure the display width using grapheme clusters (alias for measure_text_width)
@@ -86,6 +119,163 @@ pub fn measure_text(text: &str) -> (usize, usize) {
     (width, height)
 }
 
+/// Default number of distinct texts kept by a [`MeasureCache`]
+pub const DEFAULT_MEASURE_CACHE_CAPACITY: usize = 1024;
+
+/// Wrap widths remembered per text; a resize only adds one more
+const WIDTHS_PER_TEXT: usize = 4;
+
+/// Cached measurements for one text
+#[derive(Debug, Clone)]
+struct MeasureEntry {
+    /// Unwrapped display width
+    width: usize,
+    /// Line counts per available width (`None` = unconstrained)
+    heights: Vec<(Option<usize>, usize)>,
+    /// Access stamp, the entry's key in `MeasureCache::recency`
+    last_used: u64,
+}
+
+/// LRU cache of text measurements keyed by `(text, available_width)`
+///
+/// Wrapping a paragraph is the hot part of laying out text-heavy screens,
+/// and most text is unchanged between frames. Changed text simply misses
+/// and the stale entry ages out.
+#[derive(Debug, Clone)]
+pub struct MeasureCache {
+    entries: HashMap<String, MeasureEntry>,
+    /// Texts by access stamp, oldest first, so eviction is `O(log n)`
+    recency: BTreeMap<u64, String>,
+    capacity: usize,
+    clock: u64,
+    hits: u64,
+    misses: u64,
+}
+
+impl Default for MeasureCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_MEASURE_CACHE_CAPACITY)
+    }
+}
+
+impl MeasureCache {
+    /// Create a cache holding up to `capacity` distinct texts (0 disables it)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            capacity,
+            clock: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Measure `(width, height)` of `text` wrapped to `available_width`
+    ///
+    /// The width is the unwrapped display width; the height counts wrapped
+    /// lines when the text is wider than `available_width`.
+    pub fn measure(&mut self, text: &str, available_width: Option<usize>) -> (usize, usize) {
+        if self.capacity == 0 {
+            let width = measure_text_width(text);
+            return (width, wrapped_height(text, width, available_width));
+        }
+
+        self.clock += 1;
+        if let Some(entry) = self.entries.get_mut(text) {
+            if let Some(key) = self.recency.remove(&entry.last_used) {
+                self.recency.insert(self.clock, key);
+            }
+            entry.last_used = self.clock;
+            let width = entry.width;
+            if let Some(&(_, height)) = entry.heights.iter().find(|(w, _)| *w == available_width) {
+                self.hits += 1;
+                return (width, height);
+            }
+
+            self.misses += 1;
+            let height = wrapped_height(text, width, available_width);
+            if entry.heights.len() >= WIDTHS_PER_TEXT {
+                entry.heights.remove(0);
+            }
+            entry.heights.push((available_width, height));
+            return (width, height);
+        }
+
+        self.misses += 1;
+        let width = measure_text_width(text);
+        let height = wrapped_height(text, width, available_width);
+        if self.entries.len() >= self.capacity {
+            self.evict_least_recent();
+        }
+        self.recency.insert(self.clock, text.to_string());
+        self.entries.insert(
+            text.to_string(),
+            MeasureEntry {
+                width,
+                heights: vec![(available_width, height)],
+                last_used: self.clock,
+            },
+        );
+        (width, height)
+    }
+
+    /// Drop all cached measurements
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    /// Number of distinct texts cached
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if nothing is cached
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Maximum number of distinct texts kept
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Change the capacity, evicting the least recently used texts to fit
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.entries.len() > capacity {
+            self.evict_least_recent();
+        }
+    }
+
+    /// Lookups answered from the cache
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Lookups that had to measure
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    fn evict_least_recent(&mut self) {
+        if let Some((_, text)) = self.recency.pop_first() {
+            self.entries.remove(&text);
+        }
+    }
+}
+
+/// Line count of `text` (of display width `width`) wrapped to `available_width`
+fn wrapped_height(text: &str, width: usize, available_width: Option<usize>) -> usize {
+    match available_width {
+        Some(max_width) if max_width > 0 && width > max_width => {
+            count_wrapped_lines_by_width(text, max_width)
+        }
+        _ => text.lines().count().max(1),
+    }
+}
+
 /// Wrap text to fit within a maximum width (grapheme-aware)
 pub fn wrap_text(text: &str, max_width: usize) -> String {
     if max_width == 0 {
@@ -137,6 +327,167 @@ pub fn wrap_text(text: &str, max_width: usize) -> String {
     result
 }
 
+/// Wrap text containing ANSI escape sequences to a maximum width
+///
+/// Escape sequences take no width and are never split. SGR styling that is
+/// active at a line break is closed with a reset before the break and
+/// re-emitted at the start of the next line, so each wrapped line renders
+/// with the correct style on its own. An OSC 8 hyperlink open at a line
+/// break is closed before the break and reopened after it, so the link never
+/// spans the newline.
+///
+/// # Examples
+///
+/// ```
+/// use rnk::layout::wrap_ansi;
+///
+/// let wrapped = wrap_ansi("\x1b[31mhello world\x1b[0m", 5);
+/// assert_eq!(
+///     wrapped,
+///     "\x1b[31mhello\x1b[0m\n\x1b[31m worl\x1b[0m\n\x1b[31md\x1b[0m"
+/// );
+/// ```
+pub fn wrap_ansi(text: &str, max_width: usize) -> String {
+    if max_width == 0 || text.is_empty() {
+        return String::new();
+    }
+
+    let mut result = String::with_capacity(text.len());
+    // SGR sequences applied since the last reset, in order
+    let mut active: Vec<&str> = Vec::new();
+    // OSC 8 sequence that opened the current hyperlink, if one is open
+    let mut link: Option<&str> = None;
+    let mut current_width = 0;
+    let mut rest = text;
+
+    let break_line = |result: &mut String, active: &[&str], link: Option<&str>| {
+        if link.is_some() {
+            result.push_str(OSC8_CLOSE);
+        }
+        if !active.is_empty() {
+            result.push_str("\x1b[0m");
+        }
+        result.push('\n');
+        for seq in active {
+            result.push_str(seq);
+        }
+        if let Some(open) = link {
+            result.push_str(open);
+        }
+    };
+
+    while !rest.is_empty() {
+        if let Some(len) = ansi_escape_len(rest) {
+            let seq = &rest[..len];
+            if let Some(params) = sgr_params(seq) {
+                if params.is_empty() || params == "0" {
+                    active.clear();
+                } else {
+                    if params.starts_with("0;") {
+                        active.clear();
+                    }
+                    active.push(seq);
+                }
+            } else if let Some(uri) = osc8_uri(seq) {
+                link = (!uri.is_empty()).then_some(seq);
+            }
+            result.push_str(seq);
+            rest = &rest[len..];
+            continue;
+        }
+
+        // Plain run up to the next escape (a lone ESC is treated as text)
+        let skip = rest.chars().next().map_or(1, char::len_utf8);
+        let end = rest[skip..].find('\x1b').map_or(rest.len(), |i| i + skip);
+        let (plain, tail) = rest.split_at(end);
+        for grapheme in plain.graphemes(true) {
+            if grapheme == "\n" {
+                break_line(&mut result, &active, link);
+                current_width = 0;
+                continue;
+            }
+
+            let width = grapheme_width(grapheme);
+            if current_width + width > max_width && current_width > 0 {
+                break_line(&mut result, &active, link);
+                current_width = 0;
+            }
+            result.push_str(grapheme);
+            current_width += width;
+        }
+        rest = tail;
+    }
+
+    result
+}
+
+/// Remove escape sequences from `text`, leaving only the visible text
+///
+/// Drops everything [`ansi_escape_len`] recognizes. An unterminated
+/// sequence swallows the rest of the input, as a terminal would.
+pub(crate) fn strip_ansi(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(esc) = rest.find('\x1b') {
+        result.push_str(&rest[..esc]);
+        match ansi_escape_len(&rest[esc..]) {
+            Some(len) => rest = &rest[esc + len..],
+            None => return result,
+        }
+    }
+    result.push_str(rest);
+
+    result
+}
+
+/// Length in bytes of the escape sequence at the start of `text`, if any
+///
+/// Recognizes CSI (`ESC [ ... final`), OSC/DCS/SOS/PM/APC strings terminated
+/// by BEL or ST (`ESC \`), and two-byte escapes. Unterminated sequences
+/// return `None`.
+pub(crate) fn ansi_escape_len(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    if bytes.first() != Some(&0x1b) {
+        return None;
+    }
+
+    match *bytes.get(1)? {
+        b'[' => bytes[2..]
+            .iter()
+            .position(|b| (0x40..=0x7e).contains(b))
+            .map(|i| i + 3),
+        b']' | b'P' | b'X' | b'^' | b'_' => {
+            let body = &bytes[2..];
+            body.iter().enumerate().find_map(|(i, &b)| match b {
+                0x07 => Some(i + 3),
+                0x1b if body.get(i + 1) == Some(&b'\\') => Some(i + 4),
+                _ => None,
+            })
+        }
+        0x20..=0x7e => Some(2),
+        _ => None,
+    }
+}
+
+/// Sequence that closes an OSC 8 hyperlink
+const OSC8_CLOSE: &str = "\x1b]8;;\x1b\\";
+
+/// URI of an OSC 8 hyperlink sequence (`ESC ] 8 ; params ; uri ST`), empty
+/// when it closes the link, or `None` for other sequences
+fn osc8_uri(seq: &str) -> Option<&str> {
+    let body = seq.strip_prefix("\x1b]8;")?;
+    let body = body
+        .strip_suffix('\x07')
+        .or_else(|| body.strip_suffix("\x1b\\"))?;
+    body.split_once(';').map(|(_, uri)| uri)
+}
+
+/// Parameters of an SGR sequence (`ESC [ params m`), or `None` for other sequences
+fn sgr_params(seq: &str) -> Option<&str> {
+    seq.strip_prefix("\x1b[")?.strip_suffix('m')
+}
+
 /// Truncate text to fit within a maximum width (grapheme-aware)
 pub fn truncate_text(text: &str, max_width: usize, ellipsis: &str) -> String {
     if let (Some(text_width), Some(ellipsis_width)) =
@@ -508,6 +859,74 @@ pub enum TextAlign {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_wrap_ansi_plain_matches_wrap_text() {
+        assert_eq!(wrap_ansi("hello world", 5), wrap_text("hello world", 5));
+        assert_eq!(wrap_ansi("你好世界", 4), "你好\n世界");
+        assert_eq!(wrap_ansi("", 5), "");
+        assert_eq!(wrap_ansi("abc", 0), "");
+    }
+
+    #[test]
+    fn test_wrap_ansi_carries_style_across_break() {
+        let wrapped = wrap_ansi("\x1b[1;32mabcdef\x1b[0mgh", 4);
+        assert_eq!(wrapped, "\x1b[1;32mabcd\x1b[0m\n\x1b[1;32mef\x1b[0mgh");
+    }
+
+    #[test]
+    fn test_wrap_ansi_ignores_escape_width() {
+        let text = "\x1b[31ma\x1b[32mb\x1b[33mc\x1b[0m";
+        assert_eq!(wrap_ansi(text, 3), text);
+
+        let lines: Vec<String> = wrap_ansi("\x1b[38;2;1;2;3mabcdefgh", 3)
+            .lines()
+            .map(strip_ansi)
+            .collect();
+        assert_eq!(lines, vec!["abc", "def", "gh"]);
+    }
+
+    #[test]
+    fn test_wrap_ansi_reset_clears_carry() {
+        let wrapped = wrap_ansi("\x1b[31mab\x1b[mcd", 2);
+        assert_eq!(wrapped, "\x1b[31mab\x1b[m\ncd");
+    }
+
+    #[test]
+    fn test_wrap_ansi_passes_osc_through() {
+        let link = "\x1b]8;;https://example.com\x07link\x1b]8;;\x07";
+        assert_eq!(wrap_ansi(link, 4), link);
+    }
+
+    #[test]
+    fn test_wrap_ansi_reopens_hyperlink_across_break() {
+        let open = "\x1b]8;;https://example.com\x07";
+        let wrapped = wrap_ansi(&format!("{open}linked\x1b]8;;\x07!"), 4);
+        assert_eq!(
+            wrapped,
+            format!("{open}link\x1b]8;;\x1b\\\n{open}ed\x1b]8;;\x07!")
+        );
+    }
+
+    #[test]
+    fn test_measure_text_width_skips_escapes() {
+        assert_eq!(measure_text_width("\x1b[1;34mbold\x1b[0m"), 4);
+        assert_eq!(
+            measure_text_width("a \x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\ 好"),
+            9
+        );
+    }
+
+    #[test]
+    fn test_ansi_escape_len() {
+        assert_eq!(ansi_escape_len("\x1b[31mx"), Some(5));
+        assert_eq!(ansi_escape_len("\x1b[?25h"), Some(6));
+        assert_eq!(ansi_escape_len("\x1b]0;title\x07x"), Some(10));
+        assert_eq!(ansi_escape_len("\x1b]0;t\x1b\\x"), Some(7));
+        assert_eq!(ansi_escape_len("\x1b7"), Some(2));
+        assert_eq!(ansi_escape_len("\x1b[31"), None);
+        assert_eq!(ansi_escape_len("abc"), None);
+    }
+
     #[test]
     fn test_measure_ascii() {
         assert_eq!(measure_text_width("hello"), 5);
@@ -578,6 +997,18 @@ mod tests {
         // Note: Width may vary by terminal, but grapheme count should be 1
     }
 
+    #[test]
+    fn test_grapheme_width_emoji_sequences() {
+        assert_eq!(grapheme_width("👨\u{200D}👩\u{200D}👧"), 2);
+        assert_eq!(grapheme_width("🏳\u{FE0F}\u{200D}🌈"), 2);
+        assert_eq!(grapheme_width("❤\u{FE0F}"), 2);
+        assert_eq!(grapheme_width("#\u{FE0F}\u{20E3}"), 2);
+        assert_eq!(grapheme_width("❤"), 1);
+        assert_eq!(grapheme_width("e\u{301}"), 1);
+        assert_eq!(grapheme_width("a\u{200D}"), 1);
+        assert_eq!(measure_text_width("hi 👨\u{200D}👩\u{200D}👧!"), 6);
+    }
+
     #[test]
     fn test_grapheme_clusters_combining() {
         // e + combining acute accent = 1 grapheme
@@ -629,4 +1060,58 @@ mod tests {
     fn test_count_wrapped_lines_ascii_with_wrap() {
         assert_eq!(count_wrapped_lines_by_width("abcdef", 3), 2);
     }
+
+    #[test]
+    fn test_measure_cache_hits_and_width_changes() {
+        let mut cache = MeasureCache::new(8);
+        let text = "the quick brown fox jumps";
+
+        assert_eq!(cache.measure(text, Some(10)), (25, 3));
+        assert_eq!(cache.measure(text, Some(10)), (25, 3));
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+
+        // A new width is measured again rather than reusing the old height
+        assert_eq!(cache.measure(text, Some(80)), (25, 1));
+        assert_eq!(cache.measure(text, None), (25, 1));
+        assert_eq!(cache.len(), 1);
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_measure_cache_evicts_least_recent() {
+        let mut cache = MeasureCache::new(2);
+        cache.measure("a", None);
+        cache.measure("b", None);
+        cache.measure("a", None);
+        cache.measure("c", None);
+
+        assert_eq!(cache.len(), 2);
+        let misses = cache.misses();
+        cache.measure("a", None);
+        assert_eq!(cache.misses(), misses);
+        cache.measure("b", None);
+        assert_eq!(cache.misses(), misses + 1);
+
+        cache.set_capacity(0);
+        assert!(cache.is_empty());
+        assert_eq!(cache.measure("abc", Some(2)), (3, 2));
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_measure_cache_recency_tracks_entries() {
+        let mut cache = MeasureCache::new(3);
+        for text in ["a", "b", "c", "a", "d", "b", "e"] {
+            cache.measure(text, None);
+            assert_eq!(cache.recency.len(), cache.len());
+        }
+        // Oldest to newest after the last access
+        let order: Vec<&str> = cache.recency.values().map(String::as_str).collect();
+        assert_eq!(order, ["d", "b", "e"]);
+
+        cache.clear();
+        assert!(cache.recency.is_empty());
+    }
 }
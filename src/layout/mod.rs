@@ -6,8 +6,8 @@ mod utils;
 
 pub use engine::{Layout, LayoutEngine};
 pub use measure::{
-    TextAlign, display_width, measure_text, measure_text_width, pad_text, truncate_middle,
-    truncate_start, truncate_text, wrap_text,
+    MeasureCache, TextAlign, display_width, grapheme_width, measure_text, measure_text_width,
+    pad_text, truncate_middle, truncate_start, truncate_text, wrap_ansi, wrap_text,
 };
 pub use utils::{
     Position, center, center_horizontal, center_vertical, h_gap, h_spacer, join_horizontal,
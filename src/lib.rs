@@ -183,10 +183,11 @@ pub use crate::renderer::{
     println_trimmed,
     // Main entry points
     render,
+    // Element rendering APIs
+    render_frame_to,
     render_fullscreen,
     render_handle,
     render_inline,
-    // Element rendering APIs
     render_to_string,
     render_to_string_auto,
     render_to_string_no_trim,
@@ -194,4 +195,6 @@ pub use crate::renderer::{
     render_to_string_with_options,
     // Cross-thread APIs
     request_render,
+    set_debug_layout,
+    toggle_debug_layout,
 };
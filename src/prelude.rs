@@ -10,15 +10,16 @@
 //! ## Functional Groups
 //!
 //! - **Core**: Element, Style, Color, layout primitives
-//! - **Layout Components**: Box, Spacer, Transform, Static
+//! - **Layout Components**: Box, Grid, Spacer, SplitPane, Transform, Static
 //! - **Display Components**: Text, List, Table, Tree, Tabs
-//! - **Input Components**: TextInput, SelectInput, MultiSelect, Confirm
+//! - **Input Components**: TextInput, SelectInput, MultiSelect, Confirm, NumberInput,
+//!   DatePicker
 //! - **Text Editing**: TextArea, Viewport
 //! - **Feedback Components**: Progress, Spinner, Notification, Toast, Modal
 //! - **Navigation Components**: Paginator, Scrollbar, Help
 //! - **Hooks - State**: use_signal, use_memo, use_callback
 //! - **Hooks - Effects**: use_effect, use_cmd
-//! - **Hooks - Input**: use_input, use_mouse, use_paste, use_focus
+//! - **Hooks - Input**: use_input, use_mouse, use_drag_drop, use_paste, use_focus
 //! - **Hooks - Utilities**: use_scroll, use_measure, use_app
 //! - **Hooks - Animation**: use_animation, use_transition
 //! - **Rendering**: render, AppBuilder, render_to_string
@@ -38,23 +39,29 @@
 // =============================================================================
 
 pub use crate::core::{
-    AlignItems, BorderStyle, Color, Display, Element, ElementId, FlexDirection, JustifyContent,
-    Overflow, Position, Style, TextWrap,
+    AlignItems, Anchor, AnchorPlacement, Backdrop, BlendSpace, BorderStyle, Color, ColorFilter,
+    Display, Element, ElementId, FlexDirection, GridTrack, JustifyContent, Overflow, Position,
+    ShadowStyle, Style, TextOverflow, TextWrap, TransitionProperty,
 };
 
 // =============================================================================
 // Layout Components
 // =============================================================================
 
-pub use crate::components::{Box, Box as LayoutBox, Spacer, Static, Transform};
+pub use crate::components::{
+    Box, Box as LayoutBox, Capsule, Grid, GridCell, HSpace, Responsive, Router, Spacer,
+    SplitDirection, SplitPane, SplitPaneState, Static, StaticState, Transform, VSpace,
+    use_static_state,
+};
 
 // =============================================================================
 // Display Components - Text & Content
 // =============================================================================
 
 pub use crate::components::{
-    Cursor, CursorShape, CursorState, CursorStyle, Gradient, Hyperlink, HyperlinkBuilder, Line,
-    Message, MessageRole, Newline, Span, Text, ThinkingBlock, ToolCall, set_hyperlinks_supported,
+    BigFont, BigText, Cursor, CursorShape, CursorState, CursorStyle, Gradient, Hyperlink,
+    HyperlinkBuilder, Line, Message, MessageRole, MessageState, Newline, Span, Text, ThinkingBlock,
+    ToolCall, ToolCallStatus, handle_tool_call_input, set_hyperlinks_supported,
     supports_hyperlinks,
 };
 
@@ -64,8 +71,9 @@ pub use crate::components::{
 
 pub use crate::components::{
     Bar, BarChart, BarChartOrientation, Cell, Constraint, List, ListColors, ListItem, ListState,
-    Row, Sparkline, Tab, Table, TableState, Tabs, Tree, TreeNode, TreeState, TreeStyle,
-    calculate_visible_range, handle_list_navigation, handle_tree_input, virtual_scroll_view,
+    Row, Sparkline, Tab, Table, TableState, Tabs, TabsAction, TabsState, Tree, TreeNode, TreeState,
+    TreeStyle, calculate_visible_range, handle_grid_navigation, handle_list_navigation,
+    handle_list_navigation_with, handle_tree_input, virtual_scroll_view,
 };
 
 // =============================================================================
@@ -73,21 +81,27 @@ pub use crate::components::{
 // =============================================================================
 
 pub use crate::components::{
-    ColorPalette, ColorPicker, ColorPickerState, ColorPickerStyle, Command, CommandPalette,
-    CommandPaletteState, CommandPaletteStyle, Confirm, ConfirmState, ConfirmStyle, FileEntry,
-    FileFilter, FilePicker, FilePickerState, FilePickerStyle, FileType, InteractionMode,
+    Autocomplete, AutocompleteState, AutocompleteStyle, CalendarDate, ColorPalette, ColorPicker,
+    ColorPickerState, ColorPickerStyle, Command, CommandPalette, CommandPaletteState,
+    CommandPaletteStyle, Confirm, ConfirmState, ConfirmStyle, DatePicker, DatePickerState,
+    FileEntry, FileFilter, FilePicker, FilePickerState, FilePickerStyle, FileType, InteractionMode,
     InteractionOutcome, MultiSelect, MultiSelectItem, MultiSelectState, MultiSelectStyle,
-    SelectInput, SelectInputState, SelectInputStyle, SelectItem, SelectionState, TextInputHandle,
-    TextInputOptions, TextInputState, handle_color_picker_input, handle_command_palette_input,
-    handle_confirm_input, handle_confirm_input_with_mode, handle_file_picker_input,
-    handle_multi_select_input, handle_select_input, handle_text_input, use_text_input,
+    NumberInput, NumberInputState, NumberMode, SelectInput, SelectInputState, SelectInputStyle,
+    SelectItem, SelectionState, TextInputHandle, TextInputOptions, TextInputState,
+    filter_suggestions, handle_autocomplete_input, handle_color_picker_input,
+    handle_command_palette_input, handle_confirm_input, handle_confirm_input_with_mode,
+    handle_confirm_input_with_style, handle_datepicker_input, handle_datepicker_input_with_mode,
+    handle_file_picker_input, handle_multi_select_input, handle_multi_select_input_with,
+    handle_number_input, handle_number_input_mouse, handle_number_input_with_mode,
+    handle_select_input, handle_select_input_with, handle_text_input, use_text_input,
 };
 
 pub use crate::components::{
     TextArea, TextAreaAction, TextAreaKeyMap, TextAreaPosition, TextAreaSelection, TextAreaState,
-    TextAreaStyle, Viewport, ViewportAction, ViewportKeyMap, ViewportState, ViewportStyle,
-    apply_textarea_action, apply_viewport_action, handle_textarea_input,
-    handle_textarea_input_with_mode, handle_viewport_input, handle_viewport_input_with_mode,
+    TextAreaStyle, Viewport, ViewportAction, ViewportKeyMap, ViewportSearchMatch,
+    ViewportSearchOptions, ViewportState, ViewportStyle, apply_textarea_action,
+    apply_viewport_action, handle_textarea_input, handle_textarea_input_with_mode,
+    handle_textarea_mouse, handle_viewport_input, handle_viewport_input_with_mode,
 };
 
 // =============================================================================
@@ -95,10 +109,11 @@ pub use crate::components::{
 // =============================================================================
 
 pub use crate::components::{
-    Dialog, DialogState, Gauge, Modal, ModalAlign, Notification, NotificationBorder,
-    NotificationItem, NotificationLevel, NotificationPosition, NotificationState,
-    NotificationStyle, Progress, ProgressColors, ProgressSymbols, Spinner, SpinnerBuilder,
-    StopwatchState, TimerState, Toast,
+    Dialog, DialogState, Gauge, Modal, ModalAlign, ModalStack, ModalStackState, Notification,
+    NotificationBorder, NotificationItem, NotificationLevel, NotificationPosition,
+    NotificationState, NotificationStyle, Progress, ProgressColors, ProgressSymbols, Spinner,
+    SpinnerBuilder, StopwatchState, TimerState, Toast, handle_modal_stack_input,
+    handle_modal_stack_input_with_mode,
 };
 
 // =============================================================================
@@ -108,8 +123,8 @@ pub use crate::components::{
 pub use crate::components::{
     Help, HelpMode, HelpStyle, KeyBinding, NavigationConfig, NavigationResult, Paginator,
     PaginatorState, PaginatorStyle, PaginatorType, ScrollableBox, Scrollbar, ScrollbarOrientation,
-    ScrollbarSymbols, editor_help, fixed_bottom_layout, handle_paginator_input, navigation_help,
-    vim_navigation_help,
+    ScrollbarSymbols, editor_help, fixed_bottom_layout, handle_help_input, handle_paginator_input,
+    navigation_help, vim_navigation_help,
 };
 
 // =============================================================================
@@ -117,8 +132,9 @@ pub use crate::components::{
 // =============================================================================
 
 pub use crate::components::{
-    BackgroundColors, BorderColors, ButtonColors, ButtonStyle, ComponentColors, InputColors,
-    SemanticColor, TextColors, Theme, ThemeBuilder, get_theme, set_theme, with_theme,
+    BackgroundColors, BorderColors, ButtonColors, ButtonStyle, ComponentColors, FocusStyle,
+    InputColors, SemanticColor, TextColors, Theme, ThemeBuilder, focus_border_style, get_theme,
+    override_theme, set_theme, with_theme,
 };
 
 // =============================================================================
@@ -133,6 +149,8 @@ pub use crate::components::{
 // Rendering APIs
 // =============================================================================
 
+pub use crate::runtime::OsSignal;
+
 pub use crate::renderer::{
     // Types
     AppBuilder,
@@ -151,10 +169,11 @@ pub use crate::renderer::{
     println_trimmed,
     // Main entry points
     render,
+    // Element rendering APIs
+    render_frame_to,
     render_fullscreen,
     render_handle,
     render_inline,
-    // Element rendering APIs
     render_to_string,
     render_to_string_auto,
     render_to_string_no_trim,
@@ -162,6 +181,9 @@ pub use crate::renderer::{
     render_to_string_with_options,
     // Cross-thread APIs
     request_render,
+    // Layout debugging
+    set_debug_layout,
+    toggle_debug_layout,
 };
 
 // =============================================================================
@@ -169,8 +191,9 @@ pub use crate::renderer::{
 // =============================================================================
 
 pub use crate::hooks::{
-    Context, Deps, DepsHash, MemoizedCallback, RefHandle, Signal, StateSetter, create_context,
-    use_callback, use_context, use_memo, use_ref, use_signal, use_state, with_context,
+    Context, Deps, DepsHash, MemoizedCallback, RefHandle, Signal, StateSetter, batch,
+    create_context, use_callback, use_context, use_memo, use_ref, use_signal, use_state,
+    with_context,
 };
 
 // =============================================================================
@@ -185,6 +208,7 @@ pub use crate::hooks::{
 // Hooks - Animation
 // =============================================================================
 
+pub use crate::animation::{prefers_reduced_motion, set_reduced_motion};
 pub use crate::hooks::{
     AnimationHandle, TransitionHandle, use_animation, use_animation_auto, use_transition,
     use_transition_with_easing,
@@ -195,9 +219,10 @@ pub use crate::hooks::{
 // =============================================================================
 
 pub use crate::hooks::{
-    BracketedPasteGuard, Key, KeyCodeKind, MediaKeyKind, Mouse, MouseAction, MouseButton,
-    PasteEvent, disable_bracketed_paste, dispatch_paste, enable_bracketed_paste,
-    is_bracketed_paste_enabled, is_mouse_enabled, use_input, use_mouse, use_paste,
+    BracketedPasteGuard, DragDropHandle, DragState, DropTarget, Key, KeyCodeKind, MediaKeyKind,
+    Mouse, MouseAction, MouseButton, PasteConfig, PasteEvent, disable_bracketed_paste,
+    dispatch_paste, enable_bracketed_paste, is_bracketed_paste_enabled, is_mouse_enabled,
+    use_drag_drop, use_input, use_input_throttled, use_mouse, use_paste, use_paste_with_config,
 };
 
 // =============================================================================
@@ -225,9 +250,9 @@ pub use crate::hooks::{
 
 pub use crate::hooks::{
     AppContext, StderrHandle, StdinHandle, StdoutHandle, WindowTitleGuard,
-    clear_screen_reader_cache, clear_window_title, set_screen_reader_enabled, set_window_title,
-    use_app, use_frame_rate, use_is_screen_reader_enabled, use_stderr, use_stdin, use_stdout,
-    use_window_title, use_window_title_fn,
+    clear_screen_reader_cache, clear_window_title, push_window_title, set_screen_reader_enabled,
+    set_window_title, use_app, use_frame_rate, use_is_screen_reader_enabled, use_stderr, use_stdin,
+    use_stdout, use_window_title, use_window_title_fn,
 };
 
 // =============================================================================
@@ -286,7 +311,7 @@ pub mod widgets {
 /// ```
 pub mod testing {
     pub use crate::testing::*;
-    pub use crate::{assert_snapshot, golden_test, inline_snapshot};
+    pub use crate::{assert_element_snapshot, assert_snapshot, golden_test, inline_snapshot};
 }
 
 #[cfg(test)]
@@ -12,18 +12,52 @@ use crate::core::{Element, VNode};
 use crate::hooks::use_mouse::is_mouse_enabled;
 use crate::layout::LayoutEngine;
 use crate::renderer::Terminal;
-use crate::runtime::{RuntimeContext, set_current_runtime, with_runtime};
+use crate::runtime::{
+    RuntimeContext, SignalCallbacks, install_panic_hook, restore_terminal, set_current_runtime,
+    with_runtime,
+};
 use tokio::sync::mpsc;
 
 use super::builder::{AppOptions, CancelToken};
+use super::debug_layout::render_layout_overlay;
 use super::filter::FilterChain;
 use super::pipeline::RenderPipeline;
 use super::registry::{AppRuntime, AppSink, RenderHandle, register_app};
 use super::runtime::EventLoop;
 use super::runtime_bridge::RuntimeBridge;
 use super::static_content::StaticRenderer;
+use super::style_transition::StyleTransitions;
 use super::terminal_controller::TerminalController;
 
+/// Runs a cleanup closure on drop unless disarmed
+///
+/// Guards `App::run` so an early `?` return or a panic unwinding through the
+/// event loop still leaves the terminal usable.
+struct CleanupGuard<C: FnMut()> {
+    cleanup: Option<C>,
+}
+
+impl<C: FnMut()> CleanupGuard<C> {
+    fn new(cleanup: C) -> Self {
+        Self {
+            cleanup: Some(cleanup),
+        }
+    }
+
+    /// Skip the cleanup (the normal exit path already ran it)
+    fn disarm(&mut self) {
+        self.cleanup = None;
+    }
+}
+
+impl<C: FnMut()> Drop for CleanupGuard<C> {
+    fn drop(&mut self) {
+        if let Some(cleanup) = self.cleanup.as_mut() {
+            cleanup();
+        }
+    }
+}
+
 /// Application state
 pub struct App<F>
 where
@@ -53,6 +87,10 @@ where
     runtime_context: Rc<RefCell<RuntimeContext>>,
     /// Previous VNode snapshot for incremental reconciliation.
     previous_vnode: Option<VNode>,
+    /// OS signal callbacks from `AppBuilder::on_signal`
+    signal_callbacks: SignalCallbacks,
+    /// Values of properties animated with `Style::transition`
+    style_transitions: StyleTransitions,
 }
 
 impl<F> App<F>
@@ -123,9 +161,50 @@ where
             cmd_render_rx: Some(cmd_render_rx),
             runtime_context,
             previous_vnode: None,
+            signal_callbacks: SignalCallbacks::default(),
+            style_transitions: StyleTransitions::new(),
+        }
+    }
+
+    /// Write frames to `writer` instead of stdout
+    pub(crate) fn with_output(mut self, writer: Box<dyn std::io::Write + Send>) -> Self {
+        let mut terminal = Terminal::with_output(writer);
+        terminal.set_terminal_control(self.terminal.has_terminal_control());
+        self.terminal = terminal;
+        self
+    }
+
+    /// Set whether the layout debug overlay starts enabled
+    pub(crate) fn with_debug_layout(self, enabled: bool) -> Self {
+        self.runtime.set_debug_layout(enabled);
+        self
+    }
+
+    /// Set whether raw mode, cursor movement and screen control are used
+    pub(crate) fn with_terminal_control(mut self, enabled: bool) -> Self {
+        self.terminal.set_terminal_control(enabled);
+        self.runtime.set_terminal_control(enabled);
+        self
+    }
+
+    /// Current terminal size
+    ///
+    /// Without terminal control the output may not be a terminal at all, so
+    /// the last known size is kept when the size cannot be queried.
+    fn terminal_size(&self) -> std::io::Result<(u16, u16)> {
+        match Terminal::size() {
+            Err(_) if !self.terminal.has_terminal_control() => {
+                Ok((self.last_width, self.last_height))
+            }
+            size => size,
         }
     }
 
+    pub(crate) fn with_signal_callbacks(mut self, callbacks: SignalCallbacks) -> Self {
+        self.signal_callbacks = callbacks;
+        self
+    }
+
     /// Run the application
     pub fn run(mut self) -> std::io::Result<()> {
         let _app_guard = register_app(self.runtime.clone());
@@ -138,6 +217,19 @@ where
         }
         let _runtime_guard = CurrentRuntimeGuard;
 
+        // Restore the terminal on early returns and panics; the hook runs
+        // before the panic message prints, the guard while unwinding
+        let restore_on_panic = self.options.restore_on_panic;
+        let terminal_control = self.terminal.has_terminal_control();
+        if restore_on_panic && terminal_control {
+            install_panic_hook();
+        }
+        let mut terminal_guard = CleanupGuard::new(move || {
+            if terminal_control && (restore_on_panic || !std::thread::panicking()) {
+                restore_terminal();
+            }
+        });
+
         // Enter terminal mode based on options
         if self.options.alternate_screen {
             self.terminal.enter()?;
@@ -177,6 +269,16 @@ where
             event_loop = event_loop.with_render_rx(rx);
         }
 
+        event_loop = event_loop.with_signal_callbacks(std::mem::take(&mut self.signal_callbacks));
+
+        // Ask for the background color requested by `init_background_detection`;
+        // the event loop picks the reply out of the input
+        if crate::core::take_background_query_request()
+            && self.terminal.request_background_color()?
+        {
+            event_loop.expect_background_reply();
+        }
+
         // Run event loop with render callback (handle suspend/resume)
         loop {
             event_loop.run(|| {
@@ -191,7 +293,7 @@ where
                 RuntimeBridge::handle_println_messages(&mut self.terminal, &self.runtime)?;
 
                 // Handle resize
-                let (width, height) = Terminal::size()?;
+                let (width, height) = self.terminal_size()?;
                 if width != self.last_width || height != self.last_height {
                     self.handle_resize(width, height);
                 }
@@ -214,6 +316,7 @@ where
         } else {
             self.terminal.exit_inline()?;
         }
+        terminal_guard.disarm();
 
         Ok(())
     }
@@ -247,7 +350,7 @@ where
 
     fn render_frame(&mut self) -> std::io::Result<()> {
         // Get terminal size
-        let (width, height) = Terminal::size()?;
+        let (width, height) = self.terminal_size()?;
 
         // Build element tree under a unified runtime+hook lifecycle.
         let root = with_runtime(self.runtime_context.clone(), || (self.component)());
@@ -273,9 +376,11 @@ where
         }
 
         // Filter out static elements from the tree for dynamic rendering
-        let dynamic_root = self.static_renderer.filter_static_elements(&root);
+        let mut dynamic_root = self.static_renderer.filter_static_elements(&root);
+        self.style_transitions
+            .apply(&mut dynamic_root, std::time::Instant::now());
 
-        let rendered = RenderPipeline::render_dynamic_frame(
+        let mut output = RenderPipeline::render_dynamic_frame(
             &dynamic_root,
             width,
             height,
@@ -283,8 +388,15 @@ where
             &self.runtime_context,
             &mut self.previous_vnode,
         );
+        if self.runtime.is_debug_layout() {
+            render_layout_overlay(&dynamic_root, &self.layout_engine, &mut output, 0.0, 0.0);
+        }
 
-        self.terminal.render(&rendered)
+        if self.options.cell_diff {
+            self.terminal.render_cells(output)
+        } else {
+            self.terminal.render(&output.render())
+        }
     }
 
     /// Request exit
@@ -313,6 +425,45 @@ mod tests {
         assert_eq!(is_alt_screen(), None);
     }
 
+    #[test]
+    fn test_cleanup_guard_runs_on_early_return() {
+        use std::cell::Cell;
+
+        fn run(cleaned: &Cell<bool>, fail: bool) -> std::io::Result<()> {
+            let mut guard = CleanupGuard::new(|| cleaned.set(true));
+            if fail {
+                return Err(std::io::Error::other("render failed"));
+            }
+            guard.disarm();
+            Ok(())
+        }
+
+        let cleaned = Cell::new(false);
+        assert!(run(&cleaned, true).is_err());
+        assert!(cleaned.get());
+
+        let cleaned = Cell::new(false);
+        assert!(run(&cleaned, false).is_ok());
+        assert!(!cleaned.get());
+    }
+
+    #[test]
+    fn test_cleanup_guard_runs_while_unwinding() {
+        use std::panic::{AssertUnwindSafe, catch_unwind};
+        use std::sync::atomic::AtomicUsize;
+
+        let cleaned = AtomicUsize::new(0);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            let _guard = CleanupGuard::new(|| {
+                cleaned.fetch_add(1, Ordering::SeqCst);
+            });
+            panic!("render panicked");
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(cleaned.load(Ordering::SeqCst), 1);
+    }
+
     #[test]
     fn test_exit_sets_should_exit_flag() {
         let app = App::new(|| Element::text("ok"));
@@ -0,0 +1,251 @@
+//! Picks the terminal's OSC 11 background reply out of the input stream
+//!
+//! crossterm does not understand OSC replies, so `ESC ] 11 ; rgb:… BEL`
+//! arrives as an Alt+`]` key followed by one key per character and a Ctrl+G
+//! (or Alt+`\` for an ST terminator). [`BackgroundReplyWatcher`] holds back
+//! keys that could still be such a reply and either reassembles the reply
+//! bytes or releases the keys unchanged, so user input is never lost.
+
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use std::time::{Duration, Instant};
+
+/// How long after the query a reply is still expected
+const REPLY_WINDOW: Duration = Duration::from_secs(1);
+
+/// Gap after which held-back keys are treated as typed input
+const IDLE_FLUSH: Duration = Duration::from_millis(50);
+
+/// Reassembles an OSC 11 reply from key events
+#[derive(Debug, Default)]
+pub(crate) struct BackgroundReplyWatcher {
+    deadline: Option<Instant>,
+    held: Vec<KeyEvent>,
+    bytes: Vec<u8>,
+    last_held: Option<Instant>,
+    reply: Option<Vec<u8>>,
+}
+
+impl BackgroundReplyWatcher {
+    /// Start expecting a reply to a query sent at `now`
+    pub(crate) fn expect_reply(&mut self, now: Instant) {
+        self.deadline = Some(now + REPLY_WINDOW);
+    }
+
+    /// Feed one input event, returning the events to dispatch
+    pub(crate) fn feed(&mut self, event: Event, now: Instant) -> Vec<Event> {
+        if !self.watching(now) {
+            let mut events = self.release();
+            events.push(event);
+            return events;
+        }
+
+        let Event::Key(key) = event else {
+            let mut events = self.release();
+            events.push(event);
+            return events;
+        };
+        if key.kind == KeyEventKind::Release {
+            return if self.held.is_empty() {
+                vec![event]
+            } else {
+                Vec::new()
+            };
+        }
+
+        if self.held.is_empty() {
+            if key.code == KeyCode::Char(']') && key.modifiers == KeyModifiers::ALT {
+                self.held.push(key);
+                self.bytes.extend_from_slice(b"\x1b]");
+                self.last_held = Some(now);
+                return Vec::new();
+            }
+            return vec![event];
+        }
+
+        match reply_byte(&key) {
+            Some(Byte::Body(byte)) if is_reply_prefix(&self.bytes, byte) => {
+                self.held.push(key);
+                self.bytes.push(byte);
+                self.last_held = Some(now);
+                Vec::new()
+            }
+            Some(Byte::End(terminator)) if is_reply_prefix(&self.bytes, b'#') => {
+                let mut reply = std::mem::take(&mut self.bytes);
+                reply.extend_from_slice(terminator);
+                self.held.clear();
+                self.last_held = None;
+                self.deadline = None;
+                self.reply = Some(reply);
+                Vec::new()
+            }
+            _ => {
+                let mut events = self.release();
+                events.extend(self.feed(event, now));
+                events
+            }
+        }
+    }
+
+    /// Release held keys once input has gone quiet or the window closed
+    pub(crate) fn flush_idle(&mut self, now: Instant) -> Vec<Event> {
+        let idle = self
+            .last_held
+            .is_some_and(|last| now.duration_since(last) >= IDLE_FLUSH);
+        if idle || !self.watching(now) {
+            self.release()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Take the reassembled reply, if one arrived
+    pub(crate) fn take_reply(&mut self) -> Option<Vec<u8>> {
+        self.reply.take()
+    }
+
+    fn watching(&self, now: Instant) -> bool {
+        self.deadline.is_some_and(|deadline| now < deadline)
+    }
+
+    fn release(&mut self) -> Vec<Event> {
+        self.bytes.clear();
+        self.last_held = None;
+        self.held.drain(..).map(Event::Key).collect()
+    }
+}
+
+enum Byte {
+    Body(u8),
+    End(&'static [u8]),
+}
+
+/// The reply byte a key event stands for
+fn reply_byte(key: &KeyEvent) -> Option<Byte> {
+    let KeyCode::Char(c) = key.code else {
+        return None;
+    };
+    if key.modifiers == KeyModifiers::CONTROL && c == 'g' {
+        return Some(Byte::End(b"\x07"));
+    }
+    if key.modifiers == KeyModifiers::ALT && c == '\\' {
+        return Some(Byte::End(b"\x1b\\"));
+    }
+    let plain = key.modifiers.difference(KeyModifiers::SHIFT).is_empty();
+    (plain && c.is_ascii()).then_some(Byte::Body(c as u8))
+}
+
+/// Whether `bytes` followed by `next` can still be an OSC 11 reply
+///
+/// `#` stands for a terminator and is only accepted after a complete
+/// `rgb:`/`rgba:` body.
+fn is_reply_prefix(bytes: &[u8], next: u8) -> bool {
+    const HEAD: &[u8] = b"\x1b]11;";
+    if bytes.len() < HEAD.len() {
+        return next != b'#' && HEAD[bytes.len()] == next;
+    }
+    let body = &bytes[HEAD.len()..];
+    if next == b'#' {
+        let body = std::str::from_utf8(body).unwrap_or("");
+        return body
+            .split_once(':')
+            .is_some_and(|(_, channels)| channels.split('/').count() >= 3);
+    }
+    match body.iter().position(|&b| b == b':') {
+        None => (next == b':' && !body.is_empty()) || (body.len() < 4 && next.is_ascii_lowercase()),
+        Some(_) => next.is_ascii_hexdigit() || next == b'/',
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(text: &str) -> Vec<Event> {
+        text.chars()
+            .map(|c| Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)))
+            .collect()
+    }
+
+    fn alt(c: char) -> Event {
+        Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::ALT))
+    }
+
+    fn bel() -> Event {
+        Event::Key(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::CONTROL))
+    }
+
+    #[test]
+    fn test_reassembles_bel_terminated_reply() {
+        let now = Instant::now();
+        let mut watcher = BackgroundReplyWatcher::default();
+        watcher.expect_reply(now);
+
+        let mut events = vec![alt(']')];
+        events.extend(keys("11;rgb:0000/2b2b/3636"));
+        events.push(bel());
+        for event in events {
+            assert!(watcher.feed(event, now).is_empty());
+        }
+        assert_eq!(
+            watcher.take_reply().unwrap(),
+            b"\x1b]11;rgb:0000/2b2b/3636\x07".to_vec()
+        );
+
+        // The window closes once the reply arrived
+        assert_eq!(watcher.feed(alt(']'), now), vec![alt(']')]);
+    }
+
+    #[test]
+    fn test_reassembles_st_terminated_reply() {
+        let now = Instant::now();
+        let mut watcher = BackgroundReplyWatcher::default();
+        watcher.expect_reply(now);
+
+        watcher.feed(alt(']'), now);
+        for event in keys("11;rgb:ff/ff/ff") {
+            watcher.feed(event, now);
+        }
+        assert!(watcher.feed(alt('\\'), now).is_empty());
+        assert_eq!(
+            watcher.take_reply().unwrap(),
+            b"\x1b]11;rgb:ff/ff/ff\x1b\\".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_typed_keys_are_released_in_order() {
+        let now = Instant::now();
+        let mut watcher = BackgroundReplyWatcher::default();
+        watcher.expect_reply(now);
+
+        assert_eq!(watcher.feed(keys("a").remove(0), now), keys("a"));
+        assert!(watcher.feed(alt(']'), now).is_empty());
+        assert!(watcher.feed(keys("1").remove(0), now).is_empty());
+
+        let mut expected = vec![alt(']')];
+        expected.extend(keys("1x"));
+        assert_eq!(watcher.feed(keys("x").remove(0), now), expected);
+        assert!(watcher.take_reply().is_none());
+    }
+
+    #[test]
+    fn test_held_keys_flush_when_idle() {
+        let now = Instant::now();
+        let mut watcher = BackgroundReplyWatcher::default();
+        watcher.expect_reply(now);
+
+        watcher.feed(alt(']'), now);
+        assert!(watcher.flush_idle(now).is_empty());
+        assert_eq!(watcher.flush_idle(now + IDLE_FLUSH), vec![alt(']')]);
+    }
+
+    #[test]
+    fn test_passes_everything_when_not_expecting_reply() {
+        let now = Instant::now();
+        let mut watcher = BackgroundReplyWatcher::default();
+        assert_eq!(watcher.feed(alt(']'), now), vec![alt(']')]);
+
+        watcher.expect_reply(now);
+        assert_eq!(watcher.feed(alt(']'), now + REPLY_WINDOW), vec![alt(']')]);
+    }
+}
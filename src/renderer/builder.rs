@@ -2,13 +2,16 @@
 //!
 //! This module provides configuration types for the application runner.
 
+use std::io::Write;
+use std::ops::ControlFlow;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
 use crate::core::Element;
+use crate::runtime::{OsSignal, SignalCallbacks};
 
 use super::app::App;
-use super::filter::{EventFilter, FilterChain, FilterResult};
+use super::filter::{EventFilter, FilterChain, FilterResult, HotkeyFilter};
 use super::frame_rate::FrameRateConfig;
 
 /// A token for cancelling the application from external code.
@@ -87,6 +90,18 @@ pub struct AppOptions {
     pub max_fps: u32,
     /// Collect frame rate statistics (default: false)
     pub collect_frame_stats: bool,
+    /// Restore the terminal before a panic message prints (default: true)
+    ///
+    /// Installs a panic hook that leaves the alternate screen, disables raw
+    /// mode, shows the cursor, and turns off mouse capture and bracketed
+    /// paste, so the panic output lands on a usable terminal.
+    pub restore_on_panic: bool,
+    /// Write only changed cells in fullscreen mode (default: false)
+    ///
+    /// Diffs each frame against the previous one cell by cell and emits
+    /// cursor moves plus the changed cells, instead of rewriting every
+    /// changed line. Inline mode always uses line-level diffing.
+    pub cell_diff: bool,
 }
 
 impl Default for AppOptions {
@@ -99,11 +114,25 @@ impl Default for AppOptions {
             min_fps: 10,
             max_fps: 120,
             collect_frame_stats: false,
+            restore_on_panic: true,
+            cell_diff: false,
         }
     }
 }
 
 impl AppOptions {
+    /// Set whether the terminal is restored when the app panics
+    pub fn restore_on_panic(mut self, enabled: bool) -> Self {
+        self.restore_on_panic = enabled;
+        self
+    }
+
+    /// Set whether fullscreen frames are written with cell-level diffing
+    pub fn cell_diff(mut self, enabled: bool) -> Self {
+        self.cell_diff = enabled;
+        self
+    }
+
     /// Convert to FrameRateConfig
     pub fn to_frame_rate_config(&self) -> FrameRateConfig {
         let mut config = FrameRateConfig::new(self.fps);
@@ -147,6 +176,10 @@ where
     options: AppOptions,
     filter_chain: FilterChain,
     cancel_token: Option<CancelToken>,
+    signal_callbacks: SignalCallbacks,
+    output: Option<Box<dyn Write + Send>>,
+    terminal_control: bool,
+    debug_layout: bool,
 }
 
 impl<F> AppBuilder<F>
@@ -160,6 +193,10 @@ where
             options: AppOptions::default(),
             filter_chain: FilterChain::new(),
             cancel_token: None,
+            signal_callbacks: SignalCallbacks::default(),
+            output: None,
+            terminal_control: true,
+            debug_layout: false,
         }
     }
 
@@ -238,6 +275,77 @@ where
         self
     }
 
+    /// Set whether the terminal is restored when the app panics.
+    ///
+    /// Default is `true`. Disable this when the application installs its own
+    /// panic hook that handles terminal cleanup.
+    pub fn restore_on_panic(mut self, enabled: bool) -> Self {
+        self.options.restore_on_panic = enabled;
+        self
+    }
+
+    /// Write only changed cells in fullscreen mode.
+    ///
+    /// Default is `false`. Mostly static UIs with a small animated region
+    /// (a spinner, a blinking cursor) write far fewer bytes per frame with
+    /// this enabled. Has no effect in inline mode.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// render(my_app)
+    ///     .fullscreen()
+    ///     .cell_diff(true)
+    ///     .run()?;
+    /// ```
+    pub fn cell_diff(mut self, enabled: bool) -> Self {
+        self.options.cell_diff = enabled;
+        self
+    }
+
+    /// Write frames to `writer` instead of stdout.
+    ///
+    /// Input is still read from the terminal. For sinks that are not a
+    /// terminal, such as log files, pipes or sockets, also disable
+    /// [`terminal_control`](Self::terminal_control) so no cursor or
+    /// alternate-screen sequences are written.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let log = std::fs::File::create("frames.log")?;
+    /// render(my_app)
+    ///     .output(log)
+    ///     .terminal_control(false)
+    ///     .run()?;
+    /// ```
+    pub fn output(mut self, writer: impl Write + Send + 'static) -> Self {
+        self.output = Some(Box::new(writer));
+        self
+    }
+
+    /// Set whether to use raw mode, cursor movement and screen control.
+    ///
+    /// Default is `true`. Disable this when output goes somewhere other
+    /// than a terminal, such as a pipe, log file or socket. Each changed
+    /// frame is then written in full followed by a newline, and fullscreen
+    /// mode, mouse capture and terminal commands such as cursor visibility
+    /// or the window title become no-ops.
+    pub fn terminal_control(mut self, enabled: bool) -> Self {
+        self.terminal_control = enabled;
+        self
+    }
+
+    /// Outline every element with its computed `w×h` size.
+    ///
+    /// Default is `false`. Outline colors change with nesting depth. Bind
+    /// [`toggle_debug_layout`](super::toggle_debug_layout) to a hotkey to
+    /// switch the overlay while the app runs.
+    pub fn debug_layout(mut self, enabled: bool) -> Self {
+        self.debug_layout = enabled;
+        self
+    }
+
     /// Add an event filter to the filter chain.
     ///
     /// Filters are applied in priority order (higher priority first).
@@ -295,6 +403,25 @@ where
         self
     }
 
+    /// Register global hotkeys that fire regardless of focus.
+    ///
+    /// The hotkeys run before every other event filter. See
+    /// [`HotkeyFilter`] for matching rules.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use rnk::renderer::HotkeyFilter;
+    ///
+    /// render(my_app)
+    ///     .with_hotkeys(HotkeyFilter::new().register(Key::parse("F1")?, show_help))
+    ///     .run()?;
+    /// ```
+    pub fn with_hotkeys(mut self, hotkeys: HotkeyFilter) -> Self {
+        self.filter_chain.add(hotkeys.into_filter());
+        self
+    }
+
     /// Set a cancel token for external cancellation.
     ///
     /// This allows external code to cancel the application by calling
@@ -327,6 +454,40 @@ where
         self
     }
 
+    /// Register a callback for an OS signal.
+    ///
+    /// Return `ControlFlow::Break(())` to exit the app or
+    /// `ControlFlow::Continue(())` to keep running. Without a callback,
+    /// `Interrupt` (Ctrl+C) exits unless `exit_on_ctrl_c(false)` is set,
+    /// `Terminate` and `Hangup` exit, and `WindowChange` re-renders at the
+    /// new size. The app always re-renders on `WindowChange`; callbacks run
+    /// before that render.
+    ///
+    /// `Terminate` and `Hangup` are only caught on Unix. On other platforms
+    /// their callbacks never fire, while Ctrl+C and resizes still do.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use rnk::prelude::*;
+    /// use rnk::runtime::OsSignal;
+    /// use std::ops::ControlFlow;
+    ///
+    /// render(my_app)
+    ///     .on_signal(OsSignal::Interrupt, || {
+    ///         save_draft();
+    ///         ControlFlow::Break(())
+    ///     })
+    ///     .run()?;
+    /// ```
+    pub fn on_signal<H>(mut self, signal: OsSignal, handler: H) -> Self
+    where
+        H: Fn() -> ControlFlow<()> + 'static,
+    {
+        self.signal_callbacks.register(signal, Box::new(handler));
+        self
+    }
+
     /// Get the current options
     pub fn options(&self) -> &AppOptions {
         &self.options
@@ -334,13 +495,19 @@ where
 
     /// Run the application
     pub fn run(self) -> std::io::Result<()> {
-        App::with_full_config(
+        let mut app = App::with_full_config(
             self.component,
             self.options,
             self.filter_chain,
             self.cancel_token,
         )
-        .run()
+        .with_signal_callbacks(self.signal_callbacks)
+        .with_terminal_control(self.terminal_control)
+        .with_debug_layout(self.debug_layout);
+        if let Some(output) = self.output {
+            app = app.with_output(output);
+        }
+        app.run()
     }
 }
 
@@ -412,6 +579,30 @@ mod tests {
         assert_eq!(options.fps, 60);
         assert!(options.exit_on_ctrl_c);
         assert!(!options.alternate_screen);
+        assert!(options.restore_on_panic);
+        assert!(!options.cell_diff);
+        assert!(!options.restore_on_panic(false).restore_on_panic);
+    }
+
+    #[test]
+    fn test_app_builder_cell_diff() {
+        fn dummy() -> Element {
+            Text::new("test").into_element()
+        }
+        let builder = AppBuilder::new(dummy).fullscreen().cell_diff(true);
+        assert!(builder.options().cell_diff);
+    }
+
+    #[test]
+    fn test_app_builder_output_and_terminal_control() {
+        fn dummy() -> Element {
+            Text::new("test").into_element()
+        }
+        let builder = AppBuilder::new(dummy)
+            .output(Vec::new())
+            .terminal_control(false);
+        assert!(builder.output.is_some());
+        assert!(!builder.terminal_control);
     }
 
     #[test]
@@ -422,6 +613,9 @@ mod tests {
         let builder = AppBuilder::new(dummy);
         assert!(!builder.options().alternate_screen);
         assert_eq!(builder.options().fps, 60);
+        assert!(builder.terminal_control);
+        assert!(!builder.debug_layout);
+        assert!(builder.debug_layout(true).debug_layout);
     }
 
     #[test]
@@ -0,0 +1,142 @@
+//! Layout debug overlay.
+//!
+//! Outlines every element's computed rect and labels it with its `w×h`
+//! size, like a browser inspector's element highlight. Outline colors cycle
+//! by nesting depth so siblings and parents are easy to tell apart. Enable
+//! it with [`AppOptions::debug_layout`](super::AppOptions::debug_layout) or
+//! at runtime with [`toggle_debug_layout`](super::toggle_debug_layout).
+
+use crate::core::{Color, Display, Element, Style};
+use crate::layout::LayoutEngine;
+use crate::layout::measure::measure_text_width;
+use crate::renderer::Output;
+
+/// Outline colors, one per nesting depth (cycled)
+const DEPTH_COLORS: [Color; 6] = [
+    Color::Cyan,
+    Color::Magenta,
+    Color::Yellow,
+    Color::Green,
+    Color::Blue,
+    Color::Red,
+];
+
+/// Draw the layout overlay for an element tree on top of `output`
+///
+/// Uses the same offsets as the tree renderer, scroll offsets included.
+/// Elements narrower or shorter than two cells have no room for an outline
+/// and are skipped, as are hidden ones.
+pub(crate) fn render_layout_overlay(
+    element: &Element,
+    layout_engine: &LayoutEngine,
+    output: &mut Output,
+    offset_x: f32,
+    offset_y: f32,
+) {
+    overlay_node(element, layout_engine, output, offset_x, offset_y, 0);
+}
+
+fn overlay_node(
+    element: &Element,
+    layout_engine: &LayoutEngine,
+    output: &mut Output,
+    offset_x: f32,
+    offset_y: f32,
+    depth: usize,
+) {
+    if element.style.display == Display::None {
+        return;
+    }
+
+    let layout = layout_engine.get_layout(element.id).unwrap_or_default();
+    let x = (offset_x + layout.x).round() as i32;
+    let y = (offset_y + layout.y).round() as i32;
+    let width = layout.width.round().max(0.0) as i32;
+    let height = layout.height.round().max(0.0) as i32;
+
+    if width >= 2 && height >= 2 {
+        let style = Style {
+            color: Some(DEPTH_COLORS[depth % DEPTH_COLORS.len()]),
+            ..Style::new()
+        };
+        draw_outline(output, (x, y, width, height), &style);
+
+        let label = format!("{}×{}", width, height);
+        let label_width = measure_text_width(&label) as i32;
+        if label_width + 2 <= width {
+            put(output, x + width - 1 - label_width, y, &label, &style);
+        }
+    }
+
+    let child_x = offset_x + layout.x - element.scroll_offset_x.unwrap_or(0) as f32;
+    let child_y = offset_y + layout.y - element.scroll_offset_y.unwrap_or(0) as f32;
+    for child in &element.children {
+        overlay_node(child, layout_engine, output, child_x, child_y, depth + 1);
+    }
+}
+
+/// Draw a single-line box along the edge of a rect
+fn draw_outline(output: &mut Output, (x, y, width, height): (i32, i32, i32, i32), style: &Style) {
+    let (right, bottom) = (x + width - 1, y + height - 1);
+    for col in x + 1..right {
+        put(output, col, y, "─", style);
+        put(output, col, bottom, "─", style);
+    }
+    for row in y + 1..bottom {
+        put(output, x, row, "│", style);
+        put(output, right, row, "│", style);
+    }
+    put(output, x, y, "┌", style);
+    put(output, right, y, "┐", style);
+    put(output, x, bottom, "└", style);
+    put(output, right, bottom, "┘", style);
+}
+
+/// Write text at a signed position, skipping anything off screen
+fn put(output: &mut Output, x: i32, y: i32, text: &str, style: &Style) {
+    if x < 0 || y < 0 || x >= output.width as i32 || y >= output.height as i32 {
+        return;
+    }
+    output.write(x as u16, y as u16, text, style);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{Box, Text};
+    use crate::renderer::tree_renderer::render_element_tree;
+
+    #[test]
+    fn test_overlay_outlines_and_labels_nested_boxes() {
+        let element = Box::new()
+            .width(12)
+            .height(5)
+            .padding(1.0)
+            .child(
+                Box::new()
+                    .width(8)
+                    .height(3)
+                    .padding(1.0)
+                    .child(Text::new("x").into_element())
+                    .into_element(),
+            )
+            .into_element();
+
+        let mut engine = LayoutEngine::new();
+        engine.compute(&element, 12, 5);
+        let mut output = Output::new(12, 5);
+        render_element_tree(&element, &engine, &mut output, 0.0, 0.0);
+        render_layout_overlay(&element, &engine, &mut output, 0.0, 0.0);
+
+        let plain = crate::testing::strip_ansi_codes(&output.render());
+        let lines: Vec<&str> = plain.lines().collect();
+        assert_eq!(lines[0], "┌──────12×5┐");
+        assert_eq!(lines[1], "│┌───8×3┐  │");
+        assert_eq!(lines[2], "││x     │  │");
+        assert_eq!(lines[4], "└──────────┘");
+
+        // Parent and child outlines use different depth colors
+        assert_eq!(output.cell_at(0, 0).unwrap().fg, Some(Color::Cyan));
+        assert_eq!(output.cell_at(1, 1).unwrap().fg, Some(Color::Magenta));
+    }
+}
@@ -2,11 +2,16 @@
 //!
 //! This module provides a middleware mechanism for intercepting and
 //! modifying events before they reach the application.
+//! [`HotkeyFilter`] builds on it for app-wide shortcuts that fire
+//! regardless of which component has focus.
 
-use crossterm::event::Event;
+use crossterm::event::{Event, KeyEventKind};
+
+use crate::hooks::use_input::{Key, KeyCodeKind};
 
 /// Result of filtering an event
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum FilterResult {
     /// Pass the event through unchanged
     Pass(Event),
@@ -14,6 +19,8 @@ pub enum FilterResult {
     Replace(Event),
     /// Block the event (don't process it)
     Block,
+    /// The filter handled the event itself; don't process it further
+    Consumed,
 }
 
 impl FilterResult {
@@ -31,6 +38,11 @@ impl FilterResult {
     pub fn block() -> Self {
         FilterResult::Block
     }
+
+    /// Create a Consumed result
+    pub fn consumed() -> Self {
+        FilterResult::Consumed
+    }
 }
 
 /// Type alias for filter functions
@@ -135,7 +147,7 @@ impl FilterChain {
             match filter.apply(event) {
                 FilterResult::Pass(e) => event = e,
                 FilterResult::Replace(e) => event = e,
-                FilterResult::Block => return None,
+                FilterResult::Block | FilterResult::Consumed => return None,
             }
         }
         Some(event)
@@ -165,6 +177,151 @@ impl std::fmt::Debug for FilterChain {
     }
 }
 
+/// Handler invoked when a hotkey matches
+pub type HotkeyHandler = Box<dyn Fn() + Send + Sync>;
+
+struct Hotkey {
+    key: Key,
+    handler: HotkeyHandler,
+    consume: bool,
+}
+
+/// Global hotkeys matched before events reach components
+///
+/// Each registered key combination runs its handler whenever it is
+/// pressed, whichever widget has focus. Keys registered with
+/// [`register`](Self::register) are consumed; those registered with
+/// [`register_passthrough`](Self::register_passthrough) still reach the
+/// app afterwards. Letters held with `Ctrl` or `Alt` match either case,
+/// so `Ctrl+Q` and `Ctrl+q` are the same hotkey.
+///
+/// # Example
+///
+/// ```ignore
+/// use rnk::prelude::*;
+/// use rnk::renderer::{CancelToken, HotkeyFilter};
+///
+/// let token = CancelToken::new();
+/// let quit = token.clone();
+/// let hotkeys = HotkeyFilter::new()
+///     .register(Key::parse("Ctrl+Q")?, move || quit.cancel())
+///     .register(Key::parse("F1")?, || show_help());
+///
+/// render(app)
+///     .with_cancel_token(token)
+///     .with_hotkeys(hotkeys)
+///     .run()?;
+/// ```
+#[derive(Default)]
+pub struct HotkeyFilter {
+    hotkeys: Vec<Hotkey>,
+}
+
+impl HotkeyFilter {
+    /// Create a filter with no hotkeys
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `handler` on `key` and consume the event
+    pub fn register<F>(mut self, key: Key, handler: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.hotkeys.push(Hotkey {
+            key,
+            handler: Box::new(handler),
+            consume: true,
+        });
+        self
+    }
+
+    /// Run `handler` on `key` and let the event continue to the app
+    pub fn register_passthrough<F>(mut self, key: Key, handler: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.hotkeys.push(Hotkey {
+            key,
+            handler: Box::new(handler),
+            consume: false,
+        });
+        self
+    }
+
+    /// Number of registered hotkeys
+    pub fn len(&self) -> usize {
+        self.hotkeys.len()
+    }
+
+    /// Check whether no hotkeys are registered
+    pub fn is_empty(&self) -> bool {
+        self.hotkeys.is_empty()
+    }
+
+    /// Run the handlers matching `event`
+    ///
+    /// Returns [`FilterResult::Consumed`] when a consuming hotkey matched,
+    /// and passes every other event through. Key releases never match.
+    pub fn apply(&self, event: Event) -> FilterResult {
+        let Event::Key(key_event) = &event else {
+            return FilterResult::Pass(event);
+        };
+        if key_event.kind == KeyEventKind::Release {
+            return FilterResult::Pass(event);
+        }
+
+        let pressed = Key::from_event(key_event);
+        let mut consumed = false;
+        for hotkey in self
+            .hotkeys
+            .iter()
+            .filter(|h| hotkey_matches(&h.key, &pressed))
+        {
+            (hotkey.handler)();
+            consumed |= hotkey.consume;
+        }
+
+        if consumed {
+            FilterResult::Consumed
+        } else {
+            FilterResult::Pass(event)
+        }
+    }
+
+    /// Wrap the hotkeys in an [`EventFilter`] named `"hotkeys"`
+    ///
+    /// The filter gets a high priority so hotkeys run before other filters.
+    pub fn into_filter(self) -> EventFilter {
+        EventFilter::with_priority("hotkeys", 1000, move |event| self.apply(event))
+    }
+}
+
+impl std::fmt::Debug for HotkeyFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let keys: Vec<String> = self.hotkeys.iter().map(|h| h.key.to_string()).collect();
+        f.debug_struct("HotkeyFilter").field("keys", &keys).finish()
+    }
+}
+
+/// Compare key code and modifiers, ignoring letter case under Ctrl/Alt
+fn hotkey_matches(hotkey: &Key, pressed: &Key) -> bool {
+    fn code(key: &Key) -> KeyCodeKind {
+        match key.code {
+            KeyCodeKind::Char(ch) if (key.ctrl || key.alt) && !key.shift => {
+                KeyCodeKind::Char(ch.to_ascii_lowercase())
+            }
+            code => code,
+        }
+    }
+
+    code(hotkey) == code(pressed)
+        && hotkey.ctrl == pressed.ctrl
+        && hotkey.alt == pressed.alt
+        && hotkey.shift == pressed.shift
+        && hotkey.meta == pressed.meta
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -337,4 +494,59 @@ mod tests {
         let result = chain.apply(event);
         assert!(result.is_none());
     }
+
+    #[test]
+    fn test_hotkey_filter_consumes_and_passes_through() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let quits = Arc::new(AtomicUsize::new(0));
+        let helps = Arc::new(AtomicUsize::new(0));
+        let (quit, help) = (quits.clone(), helps.clone());
+        let hotkeys = HotkeyFilter::new()
+            .register(Key::parse("Ctrl+Q").unwrap(), move || {
+                quit.fetch_add(1, Ordering::SeqCst);
+            })
+            .register_passthrough(Key::parse("F1").unwrap(), move || {
+                help.fetch_add(1, Ordering::SeqCst);
+            });
+
+        let ctrl_q = Event::Key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL));
+        assert!(matches!(hotkeys.apply(ctrl_q), FilterResult::Consumed));
+        assert!(matches!(
+            hotkeys.apply(make_key_event(KeyCode::F(1))),
+            FilterResult::Pass(_)
+        ));
+        assert!(matches!(
+            hotkeys.apply(make_key_event(KeyCode::Char('q'))),
+            FilterResult::Pass(_)
+        ));
+        assert_eq!(quits.load(Ordering::SeqCst), 1);
+        assert_eq!(helps.load(Ordering::SeqCst), 1);
+
+        let mut chain = FilterChain::new();
+        chain.add(hotkeys.into_filter());
+        let ctrl_q = Event::Key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL));
+        assert!(chain.apply(ctrl_q).is_none());
+        assert!(chain.apply(make_key_event(KeyCode::F(1))).is_some());
+        assert_eq!(quits.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_hotkey_filter_ignores_releases() {
+        use crossterm::event::{KeyEventKind, KeyEventState};
+
+        let hotkeys = HotkeyFilter::new().register(Key::parse("Esc").unwrap(), || {});
+        let release = Event::Key(KeyEvent {
+            code: KeyCode::Esc,
+            modifiers: KeyModifiers::NONE,
+            kind: KeyEventKind::Release,
+            state: KeyEventState::NONE,
+        });
+        assert!(matches!(hotkeys.apply(release), FilterResult::Pass(_)));
+        assert!(matches!(
+            hotkeys.apply(make_key_event(KeyCode::Esc)),
+            FilterResult::Consumed
+        ));
+    }
 }
@@ -2,12 +2,94 @@
 //!
 //! This module provides configurable frame rate control with statistics
 //! collection and adaptive frame rate support.
+//!
+//! It also tracks how many animations are in motion. While any are, the
+//! event loop renders at the target FPS without waiting for a render
+//! request; once everything is at rest it goes back to rendering only on
+//! demand, so an idle app uses no CPU.
 
 use std::collections::VecDeque;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
 
+/// Number of animations currently in motion
+static ACTIVE_ANIMATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Mark one more animation as in motion
+///
+/// While at least one animation is registered, frames are rendered at the
+/// target FPS. `use_animation` and `use_transition` register themselves;
+/// call this for hand-driven animation such as a
+/// [`SpringValue`](crate::animation::SpringValue) when you set its target,
+/// and [`unregister_active_animation`] once it
+/// [settles](crate::animation::SpringValue::is_settled).
+pub fn register_active_animation() {
+    ACTIVE_ANIMATIONS.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Mark one animation as at rest again
+///
+/// Unbalanced calls are ignored once the count reaches zero.
+pub fn unregister_active_animation() {
+    decrement(&ACTIVE_ANIMATIONS);
+}
+
+fn decrement(counter: &AtomicUsize) {
+    let _ = counter.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+        count.checked_sub(1)
+    });
+}
+
+/// Number of animations currently registered as in motion
+pub fn active_animation_count() -> usize {
+    ACTIVE_ANIMATIONS.load(Ordering::SeqCst)
+}
+
+/// Registration of a single animation, kept in sync with its running state
+///
+/// Unregisters on drop, so an unmounted component cannot leave the app
+/// rendering at full rate.
+#[derive(Debug)]
+pub(crate) struct AnimationActivity {
+    active: AtomicBool,
+    counter: &'static AtomicUsize,
+}
+
+impl Default for AnimationActivity {
+    fn default() -> Self {
+        Self {
+            active: AtomicBool::new(false),
+            counter: &ACTIVE_ANIMATIONS,
+        }
+    }
+}
+
+impl AnimationActivity {
+    /// Register or unregister, if the state changed
+    pub(crate) fn set(&self, active: bool) {
+        if self.active.swap(active, Ordering::SeqCst) != active {
+            if active {
+                self.counter.fetch_add(1, Ordering::SeqCst);
+            } else {
+                decrement(self.counter);
+            }
+        }
+    }
+
+    /// Whether this animation is registered as in motion
+    #[cfg(test)]
+    pub(crate) fn is_active(&self) -> bool {
+        self.active.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for AnimationActivity {
+    fn drop(&mut self) {
+        self.set(false);
+    }
+}
+
 /// Configuration for frame rate control
 #[derive(Debug, Clone)]
 pub struct FrameRateConfig {
@@ -181,6 +263,19 @@ impl FrameRateController {
         self.last_frame.elapsed() >= self.frame_duration()
     }
 
+    /// Check if any animation is in motion
+    pub fn has_active_animations(&self) -> bool {
+        active_animation_count() > 0
+    }
+
+    /// Check if a frame is due
+    ///
+    /// A frame is due once the frame interval has passed and either a
+    /// render was requested or an animation is in motion.
+    pub fn should_render_frame(&self, render_requested: bool) -> bool {
+        (render_requested || self.has_active_animations()) && self.should_render()
+    }
+
     /// Record a frame render and update statistics
     ///
     /// Call this after each frame render with the time taken to render.
@@ -369,4 +464,35 @@ mod tests {
         controller.reset_stats();
         assert_eq!(controller.stats().total_frames, 0);
     }
+
+    #[test]
+    fn test_animation_activity_registers_once_and_on_drop() {
+        // A private counter keeps concurrently running animation tests out
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let activity = AnimationActivity {
+            active: AtomicBool::new(false),
+            counter: &COUNTER,
+        };
+        activity.set(true);
+        activity.set(true);
+        assert_eq!(COUNTER.load(Ordering::SeqCst), 1);
+        activity.set(false);
+        assert_eq!(COUNTER.load(Ordering::SeqCst), 0);
+        decrement(&COUNTER);
+        assert_eq!(COUNTER.load(Ordering::SeqCst), 0);
+
+        activity.set(true);
+        drop(activity);
+        assert_eq!(COUNTER.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_active_animation_forces_frames() {
+        let mut controller = FrameRateController::new(FrameRateConfig::new(120));
+        controller.last_frame = Instant::now() - Duration::from_millis(50);
+        register_active_animation();
+        assert!(controller.should_render_frame(false));
+        unregister_active_animation();
+        assert!(controller.should_render_frame(true));
+    }
 }
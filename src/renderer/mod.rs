@@ -35,10 +35,12 @@
 //! ```
 
 mod app;
+mod background_reply;
 mod builder;
+pub(crate) mod debug_layout;
 pub(crate) mod element_renderer;
 mod filter;
-mod frame_rate;
+pub(crate) mod frame_rate;
 mod output;
 pub(crate) mod pipeline;
 pub(crate) mod registry;
@@ -46,6 +48,7 @@ pub(crate) mod render_to_string;
 pub(crate) mod runtime;
 pub(crate) mod runtime_bridge;
 pub(crate) mod static_content;
+pub(crate) mod style_transition;
 mod terminal;
 pub(crate) mod terminal_controller;
 pub(crate) mod tree_renderer;
@@ -59,20 +62,24 @@ pub use builder::{AppBuilder, AppOptions, CancelToken, render, render_fullscreen
 // Registry APIs
 pub use registry::{
     IntoPrintable, ModeSwitch, Printable, RenderHandle, enter_alt_screen, exit_alt_screen,
-    is_alt_screen, println, println_trimmed, render_handle, request_render,
+    is_alt_screen, println, println_trimmed, render_handle, request_render, set_debug_layout,
+    toggle_debug_layout,
 };
 
 // Element rendering APIs
 pub use render_to_string::{
-    RenderOptions, render_to_string, render_to_string_auto, render_to_string_no_trim,
-    render_to_string_raw, render_to_string_with_options,
+    RenderOptions, render_frame_to, render_to_string, render_to_string_auto,
+    render_to_string_no_trim, render_to_string_raw, render_to_string_with_options,
 };
 
 // Frame rate control
-pub use frame_rate::{FrameRateConfig, FrameRateController, FrameRateStats, SharedFrameRateStats};
+pub use frame_rate::{
+    FrameRateConfig, FrameRateController, FrameRateStats, SharedFrameRateStats,
+    active_animation_count, register_active_animation, unregister_active_animation,
+};
 
 // Event filtering
-pub use filter::{EventFilter, FilterChain, FilterResult};
+pub use filter::{EventFilter, FilterChain, FilterResult, HotkeyFilter, HotkeyHandler};
 
 // Terminal and output
 pub use output::{ClipRegion, Output};
@@ -1,13 +1,26 @@
 //! Output buffer for terminal rendering
 
-use crate::core::{Color, Style};
+use crate::core::{Color, ColorFilter, Style, is_dark_background};
+use crate::layout::grapheme_width;
+use crate::layout::measure::ansi_escape_len;
+use crate::runtime::supports_double_width_lines;
 use std::fmt::Write as FmtWrite;
+use std::sync::Arc;
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthChar;
 
 /// A styled character in the output grid
 #[derive(Debug, Clone, Default)]
 pub struct StyledChar {
     pub ch: char,
+    /// Code points after `ch` in a multi-codepoint grapheme cluster
+    /// (combining marks, ZWJ sequences, variation selectors)
+    ///
+    /// Escape sequences embedded in written text, other than hyperlinks,
+    /// also ride on the cell they precede, so `ch` may then be `ESC`.
+    tail: Option<Box<str>>,
+    /// OSC 8 hyperlink the cell belongs to, as its `params;URI` pair
+    pub link: Option<Arc<str>>,
     pub fg: Option<Color>,
     pub bg: Option<Color>,
     pub bold: bool,
@@ -29,7 +42,9 @@ impl StyledChar {
     pub fn with_style(ch: char, style: &Style) -> Self {
         Self {
             ch,
-            fg: style.color,
+            tail: None,
+            link: None,
+            fg: faded_foreground(style),
             bg: style.background_color,
             bold: style.bold,
             italic: style.italic,
@@ -40,6 +55,17 @@ impl StyledChar {
         }
     }
 
+    /// Create a styled cell holding a whole grapheme cluster
+    pub fn from_grapheme(grapheme: &str, style: &Style) -> Self {
+        let mut chars = grapheme.chars();
+        let ch = chars.next().unwrap_or(' ');
+        let tail = chars.as_str();
+        Self {
+            tail: (!tail.is_empty()).then(|| tail.into()),
+            ..Self::with_style(ch, style)
+        }
+    }
+
     /// Check if this char has any styling
     pub fn has_style(&self) -> bool {
         self.fg.is_some()
@@ -65,6 +91,160 @@ impl StyledChar {
     }
 }
 
+/// Resolve the foreground after applying `Style::faint_level`
+///
+/// The foreground (terminal default treated as white) is blended toward the
+/// background, or toward black when no background is set. A level of `0.0`
+/// leaves the foreground untouched.
+fn faded_foreground(style: &Style) -> Option<Color> {
+    let level = style.faint_level.unwrap_or(0.0);
+    if level <= 0.0 {
+        return style.color;
+    }
+    let fg = style.color.unwrap_or(Color::White);
+    let bg = style.background_color.unwrap_or(Color::Black);
+    Some(fg.blend(&bg, level))
+}
+
+/// Cells a grapheme cluster takes when written
+///
+/// Control characters such as tabs have no display width but are still
+/// drawn as one cell, as in the ASCII fast path. Other zero-width clusters
+/// (a lone zero-width space or joiner) take no cell and are dropped.
+fn cell_width(grapheme: &str) -> usize {
+    match grapheme_width(grapheme) {
+        0 if grapheme.starts_with(char::is_control) => 1,
+        width => width,
+    }
+}
+
+/// Whether two cells look identical on screen
+fn same_cell(a: &StyledChar, b: &StyledChar) -> bool {
+    a.ch == b.ch && a.tail == b.tail && a.link == b.link && a.same_style(b)
+}
+
+/// Link a cell gets from an OSC 8 escape, or `None` if `seq` isn't one
+///
+/// The inner `None` is the closing sequence (an empty URI).
+fn osc8_link(seq: &str) -> Option<Option<Arc<str>>> {
+    let body = seq.strip_prefix("\x1b]8;")?;
+    let body = body
+        .strip_suffix("\x1b\\")
+        .or_else(|| body.strip_suffix('\x07'))?;
+    let (_, uri) = body.split_once(';')?;
+    Some((!uri.is_empty()).then(|| body.into()))
+}
+
+/// Whether a cell shows anything besides an unstyled blank
+fn is_visible(cell: &StyledChar) -> bool {
+    cell.ch != ' ' || cell.has_style()
+}
+
+/// Glyphs that turn into each other when mirrored left to right
+const MIRROR_HORIZONTAL: &[(char, char)] = &[
+    ('┌', '┐'),
+    ('└', '┘'),
+    ('├', '┤'),
+    ('╭', '╮'),
+    ('╰', '╯'),
+    ('┏', '┓'),
+    ('┗', '┛'),
+    ('┣', '┫'),
+    ('╔', '╗'),
+    ('╚', '╝'),
+    ('╠', '╣'),
+    ('▌', '▐'),
+    ('▘', '▝'),
+    ('▖', '▗'),
+    ('▛', '▜'),
+    ('▙', '▟'),
+    ('◢', '◣'),
+    ('◤', '◥'),
+    ('╱', '╲'),
+    ('/', '\\'),
+    ('(', ')'),
+    ('[', ']'),
+    ('{', '}'),
+    ('<', '>'),
+    ('«', '»'),
+    ('‹', '›'),
+    ('←', '→'),
+    ('◀', '▶'),
+];
+
+/// Glyphs that turn into each other when flipped upside down
+const MIRROR_VERTICAL: &[(char, char)] = &[
+    ('┌', '└'),
+    ('┐', '┘'),
+    ('┬', '┴'),
+    ('╭', '╰'),
+    ('╮', '╯'),
+    ('┏', '┗'),
+    ('┓', '┛'),
+    ('┳', '┻'),
+    ('╔', '╚'),
+    ('╗', '╝'),
+    ('╦', '╩'),
+    ('▀', '▄'),
+    ('▔', '▁'),
+    ('▘', '▖'),
+    ('▝', '▗'),
+    ('▛', '▙'),
+    ('▜', '▟'),
+    ('◢', '◥'),
+    ('◣', '◤'),
+    ('╱', '╲'),
+    ('/', '\\'),
+    ('↑', '↓'),
+    ('▲', '▼'),
+];
+
+/// The mirror image of `ch` in `pairs`, or `ch` itself
+fn mirrored(ch: char, pairs: &[(char, char)]) -> char {
+    pairs
+        .iter()
+        .find_map(|&(a, b)| {
+            if ch == a {
+                Some(b)
+            } else if ch == b {
+                Some(a)
+            } else {
+                None
+            }
+        })
+        .unwrap_or(ch)
+}
+
+/// Drop escape sequences carried by a cell
+///
+/// Raw escapes, such as a color switched mid-text, apply from one cell
+/// onward, so they can't survive the cells being reordered.
+fn strip_cell_escapes(cell: &mut StyledChar) {
+    let has_escape = cell.ch == '\x1b' || cell.tail.as_deref().is_some_and(|t| t.contains('\x1b'));
+    if !has_escape {
+        return;
+    }
+
+    let mut content = String::from(cell.ch);
+    content.push_str(cell.tail.as_deref().unwrap_or(""));
+    let mut plain = String::new();
+    let mut rest = content.as_str();
+    while let Some(c) = rest.chars().next() {
+        match ansi_escape_len(rest) {
+            Some(len) => rest = &rest[len..],
+            None => {
+                plain.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+
+    let mut chars = plain.chars();
+    cell.ch = chars.next().unwrap_or(' ');
+    let tail: String = chars.collect();
+    cell.tail = (!tail.is_empty()).then(|| tail.into());
+}
+
 /// Clip region for overflow handling
 #[derive(Debug, Clone)]
 pub struct ClipRegion {
@@ -91,6 +271,8 @@ pub struct Output {
     dirty_rows: Vec<bool>,
     /// Quick check if any row is dirty
     any_dirty: bool,
+    /// Rows drawn as double-width lines (DECDWL)
+    double_width_rows: Vec<bool>,
 }
 
 impl Output {
@@ -105,11 +287,58 @@ impl Output {
             clip_stack: Vec::new(),
             dirty_rows: vec![false; height as usize],
             any_dirty: false,
+            double_width_rows: vec![false; height as usize],
+        }
+    }
+
+    /// Resize the buffer, keeping the overlapping top-left region
+    ///
+    /// New cells are filled with spaces. Rows that lose content to a narrower
+    /// width, and rows added by a taller height, are marked dirty; existing
+    /// dirty flags on surviving rows are kept.
+    pub fn resize(&mut self, width: u16, height: u16) {
+        if width == self.width && height == self.height {
+            return;
         }
+        self.assert_no_active_clips("resize");
+
+        let old_width = self.width as usize;
+        let old_height = self.height as usize;
+        let new_width = width as usize;
+        let new_height = height as usize;
+        let keep_cols = old_width.min(new_width);
+        let keep_rows = old_height.min(new_height);
+
+        let mut grid = vec![StyledChar::new(' '); new_width * new_height];
+        let mut dirty_rows = vec![true; new_height];
+
+        for row in 0..keep_rows {
+            let old_row = &self.grid[row * old_width..(row + 1) * old_width];
+            let new_row = &mut grid[row * new_width..(row + 1) * new_width];
+            new_row[..keep_cols].clone_from_slice(&old_row[..keep_cols]);
+
+            let mut changed = old_row[keep_cols..]
+                .iter()
+                .any(|cell| cell.ch != ' ' || cell.has_style());
+
+            // A wide char whose placeholder fell off the edge can't be shown
+            if keep_cols > 0 && keep_cols < old_width && old_row[keep_cols].ch == '\0' {
+                new_row[keep_cols - 1] = StyledChar::new(' ');
+                changed = true;
+            }
+
+            dirty_rows[row] = changed || self.dirty_rows[row];
+        }
+
+        self.width = width;
+        self.height = height;
+        self.grid = grid;
+        self.any_dirty = dirty_rows.iter().any(|&dirty| dirty);
+        self.dirty_rows = dirty_rows;
+        self.double_width_rows.resize(new_height, false);
     }
 
     /// Get a reference to a cell at (col, row)
-    #[cfg(test)]
     #[inline]
     fn get(&self, col: usize, row: usize) -> Option<&StyledChar> {
         if col < self.width as usize && row < self.height as usize {
@@ -133,6 +362,25 @@ impl Output {
         self.get(col, row)
     }
 
+    /// Check if a row is drawn as a double-width line
+    pub fn is_double_width_row(&self, row: usize) -> bool {
+        self.double_width_rows.get(row).copied().unwrap_or(false)
+    }
+
+    /// Line size escape for a row, if any row uses double-width lines
+    ///
+    /// Line sizes stick to terminal rows, so once double-width lines are in
+    /// use, normal rows reset theirs explicitly.
+    fn line_size_escape(&self, row: usize) -> &'static str {
+        if self.is_double_width_row(row) {
+            "\x1b#6"
+        } else if self.double_width_rows.contains(&true) {
+            "\x1b#5"
+        } else {
+            ""
+        }
+    }
+
     /// Check if any row has been modified
     pub fn is_dirty(&self) -> bool {
         self.any_dirty
@@ -182,38 +430,148 @@ impl Output {
         }
 
         let mut line = String::new();
-        let mut current_style: Option<StyledChar> = None;
+        if last_content_idx == 0 {
+            return line;
+        }
+        line.push_str(self.line_size_escape(row_idx));
+        let cells = self.row_iter(row_idx).take(last_content_idx);
+        if self.is_double_width_row(row_idx) {
+            // The terminal draws each cell twice as wide; skip the columns
+            // the doubled characters cover
+            self.push_cells(&mut line, cells.step_by(2));
+        } else {
+            self.push_cells(&mut line, cells);
+        }
+        line
+    }
 
-        for (i, cell) in self.row_iter(row_idx).enumerate() {
-            if i >= last_content_idx {
-                break;
-            }
+    /// Append styled cells, skipping wide-char placeholders, opening and
+    /// closing links as they change, and resetting the style at the end
+    fn push_cells<'a>(&self, out: &mut String, cells: impl Iterator<Item = &'a StyledChar>) {
+        let mut current_style: Option<&StyledChar> = None;
+        let mut current_link: Option<&Arc<str>> = None;
 
+        for cell in cells {
             if cell.ch == '\0' {
                 continue;
             }
 
-            let need_style_change = match &current_style {
+            let need_style_change = match current_style {
                 None => cell.has_style(),
                 Some(prev) => !cell.same_style(prev),
             };
 
             if need_style_change {
                 if current_style.is_some() {
-                    line.push_str("\x1b[0m");
+                    out.push_str("\x1b[0m");
                 }
-                self.apply_style(&mut line, cell);
-                current_style = Some(cell.clone());
+                self.apply_style(out, cell);
+                current_style = Some(cell);
             }
 
-            line.push(cell.ch);
+            if cell.link.as_ref() != current_link {
+                if current_link.is_some() {
+                    out.push_str("\x1b]8;;\x1b\\");
+                }
+                if let Some(link) = &cell.link {
+                    let _ = write!(out, "\x1b]8;{}\x1b\\", link);
+                }
+                current_link = cell.link.as_ref();
+            }
+
+            out.push(cell.ch);
+            if let Some(tail) = &cell.tail {
+                out.push_str(tail);
+            }
         }
 
+        if current_link.is_some() {
+            out.push_str("\x1b]8;;\x1b\\");
+        }
         if current_style.is_some() {
-            line.push_str("\x1b[0m");
+            out.push_str("\x1b[0m");
         }
+    }
 
-        line
+    /// Escape sequences that turn `previous` into this frame on screen
+    ///
+    /// Only cells that differ are written, each run of changed cells preceded
+    /// by an absolute cursor move, so the result is meant for a fullscreen
+    /// (alternate screen) terminal showing `previous` at the top-left. Cells
+    /// outside `previous` are assumed blank; content `previous` had outside
+    /// this frame is erased. Returns an empty string when nothing changed.
+    pub fn diff(&self, previous: &Output) -> String {
+        self.assert_no_active_clips("diff");
+        let blank = StyledChar::new(' ');
+        let width = self.width as usize;
+        let prev_cell = |col: usize, row: usize| previous.get(col, row).unwrap_or(&blank);
+        let mut out = String::new();
+
+        for row in 0..self.height as usize {
+            let cells = &self.grid[row * width..(row + 1) * width];
+
+            // Columns on double-width lines don't match the grid, so those
+            // lines, and lines that stop being one, are redrawn whole
+            let double_width = self.is_double_width_row(row);
+            if double_width || previous.is_double_width_row(row) {
+                let columns = width.max(previous.width as usize);
+                let changed = double_width != previous.is_double_width_row(row)
+                    || (0..columns).any(|col| {
+                        !same_cell(self.get(col, row).unwrap_or(&blank), prev_cell(col, row))
+                    });
+                if changed {
+                    let line_size = if double_width { "\x1b#6" } else { "\x1b#5" };
+                    let _ = write!(out, "\x1b[{};1H\x1b[2K{}", row + 1, line_size);
+                    let end = cells.iter().rposition(is_visible).map_or(0, |i| i + 1);
+                    if double_width {
+                        self.push_cells(&mut out, cells[..end].iter().step_by(2));
+                    } else {
+                        self.push_cells(&mut out, cells[..end].iter());
+                    }
+                }
+                continue;
+            }
+
+            let mut col = 0;
+            while col < width {
+                if same_cell(&cells[col], prev_cell(col, row)) {
+                    col += 1;
+                    continue;
+                }
+
+                // Never start or end a run in the middle of a wide char
+                let start = if cells[col].ch == '\0' && col > 0 {
+                    col - 1
+                } else {
+                    col
+                };
+                let mut end = col + 1;
+                while end < width && !same_cell(&cells[end], prev_cell(end, row)) {
+                    end += 1;
+                }
+                if end < width && cells[end].ch == '\0' {
+                    end += 1;
+                }
+
+                let _ = write!(out, "\x1b[{};{}H", row + 1, start + 1);
+                self.push_cells(&mut out, cells[start..end].iter());
+                col = end;
+            }
+
+            let stale_tail = (width..previous.width as usize)
+                .any(|col| previous.get(col, row).is_some_and(is_visible));
+            if stale_tail {
+                let _ = write!(out, "\x1b[{};{}H\x1b[K", row + 1, width + 1);
+            }
+        }
+
+        for row in self.height as usize..previous.height as usize {
+            if previous.row_iter(row).any(is_visible) {
+                let _ = write!(out, "\x1b[{};1H\x1b[2K", row + 1);
+            }
+        }
+
+        out
     }
 
     /// Mark a row as dirty
@@ -234,13 +592,18 @@ impl Output {
             return;
         }
 
+        if style.double_width && supports_double_width_lines() {
+            self.write_double_width(col, row, text, style);
+            return;
+        }
+
         // Mark row as dirty before any modifications
         self.mark_dirty(row);
 
         let width = self.width as usize;
         let clip_region = self.clip_stack.last().cloned();
 
-        if text.is_ascii() && clip_region.is_none() {
+        if text.is_ascii() && clip_region.is_none() && !text.contains('\x1b') {
             for byte in text.bytes() {
                 if byte == b'\n' || col >= width {
                     break;
@@ -251,35 +614,131 @@ impl Output {
             return;
         }
 
-        if clip_region.is_none() {
-            for ch in text.chars() {
-                if ch == '\n' || col >= width {
-                    break;
+        // Place whole grapheme clusters so emoji ZWJ sequences and
+        // combining marks occupy the cells terminals actually draw. Escape
+        // sequences take no cells: OSC 8 links become the link of the cells
+        // they cover, others are emitted with the next cell.
+        let mut pending = String::new();
+        let mut link = None;
+        let mut last_col = None;
+        let mut rest = text;
+        'segments: while !rest.is_empty() {
+            if let Some(len) = ansi_escape_len(rest) {
+                match osc8_link(&rest[..len]) {
+                    Some(target) => link = target,
+                    None => pending.push_str(&rest[..len]),
+                }
+                rest = &rest[len..];
+                continue;
+            }
+
+            // Plain run up to the next escape (a lone ESC is treated as text)
+            let skip = rest.chars().next().map_or(1, char::len_utf8);
+            let end = rest[skip..].find('\x1b').map_or(rest.len(), |i| i + skip);
+            let (plain, tail) = rest.split_at(end);
+            rest = tail;
+
+            for grapheme in plain.graphemes(true) {
+                if grapheme.contains('\n') || col >= width {
+                    break 'segments;
+                }
+
+                let cluster_width = cell_width(grapheme);
+                if cluster_width == 0 {
+                    continue;
+                }
+
+                // Check clip region
+                if let Some(clip) = clip_region.as_ref()
+                    && !clip.contains(col as u16, row as u16)
+                {
+                    col += cluster_width;
+                    continue;
                 }
 
-                let char_width = ch.width().unwrap_or(1);
-                self.write_char_at(col, row, ch, char_width, style);
-                col += char_width;
+                let mut cell = if pending.is_empty() {
+                    StyledChar::from_grapheme(grapheme, style)
+                } else {
+                    pending.push_str(grapheme);
+                    let cell = StyledChar::from_grapheme(&pending, style);
+                    pending.clear();
+                    cell
+                };
+                cell.link = link.clone();
+                self.place_cell_at(col, row, cell, cluster_width);
+                last_col = Some(col);
+                col += cluster_width;
             }
-            return;
         }
 
-        for ch in text.chars() {
-            if ch == '\n' || col >= width {
-                break;
+        // Escapes after the last visible cell (resets) still need emitting,
+        // even when the text was cut off
+        while let Some(pos) = rest.find('\x1b') {
+            rest = &rest[pos..];
+            let len = ansi_escape_len(rest).unwrap_or(1);
+            if len > 1 && osc8_link(&rest[..len]).is_none() {
+                pending.push_str(&rest[..len]);
             }
+            rest = &rest[len..];
+        }
+        if let Some(col) = last_col
+            && !pending.is_empty()
+        {
+            let cell = &mut self.grid[row * width + col];
+            let mut tail = cell.tail.take().map(String::from).unwrap_or_default();
+            tail.push_str(&pending);
+            cell.tail = Some(tail.into());
+        }
+    }
+
+    /// Write text on a double-width line, two cells per column
+    ///
+    /// The row becomes a double-width line. Characters start on even
+    /// columns, since those are the only ones the terminal can address on
+    /// such a line. Escape sequences in `text` other than OSC 8 links are
+    /// dropped.
+    fn write_double_width(&mut self, x: usize, row: usize, text: &str, style: &Style) {
+        self.mark_dirty(row);
+        self.double_width_rows[row] = true;
 
-            // Check clip region
-            let char_width = ch.width().unwrap_or(1);
-            if let Some(clip) = clip_region.as_ref()
-                && !clip.contains(col as u16, row as u16)
-            {
-                col += char_width;
+        let width = self.width as usize;
+        let clip_region = self.clip_stack.last().cloned();
+        let mut col = x & !1;
+        let mut link = None;
+        let mut rest = text;
+        while let Some(grapheme) = rest.graphemes(true).next() {
+            if let Some(len) = ansi_escape_len(rest) {
+                if let Some(target) = osc8_link(&rest[..len]) {
+                    link = target;
+                }
+                rest = &rest[len..];
                 continue;
             }
+            rest = &rest[grapheme.len()..];
+            if grapheme.contains('\n') {
+                break;
+            }
 
-            self.write_char_at(col, row, ch, char_width, style);
-            col += char_width;
+            let cluster_width = cell_width(grapheme) * 2;
+            if cluster_width == 0 {
+                continue;
+            }
+            if col + cluster_width > width {
+                break;
+            }
+            let clipped = clip_region
+                .as_ref()
+                .is_some_and(|clip| !clip.contains(col as u16, row as u16));
+            if !clipped {
+                let mut cell = StyledChar::from_grapheme(grapheme, style);
+                cell.link = link.clone();
+                self.place_cell_at(col, row, cell, 2);
+                // A wide character covers two more columns
+                if cluster_width == 4 {
+                    self.place_cell_at(col + 2, row, StyledChar::new('\0'), 2);
+                }
+            }
+            col += cluster_width;
         }
     }
 
@@ -315,13 +774,30 @@ impl Output {
         char_width: usize,
         style: &Style,
     ) {
+        self.place_cell_at(col, row, StyledChar::with_style(ch, style), char_width);
+    }
+
+    /// Place a cell `char_width` columns wide, fixing up any wide char it
+    /// partially overwrites
+    ///
+    /// A cell without its own background keeps the one already painted
+    /// there, so text drawn inside a filled box stays on the box color.
+    fn place_cell_at(&mut self, col: usize, row: usize, mut cell: StyledChar, char_width: usize) {
         let width = self.width as usize;
         let row_start = row * width;
         let idx = row_start + col;
 
+        if cell.bg.is_none() {
+            cell.bg = self.grid[idx].bg;
+        }
+
         // Handle wide character at buffer boundary - skip if it won't fit
         if char_width == 2 && col + 1 >= width {
-            self.grid[idx] = StyledChar::with_style(' ', style);
+            self.grid[idx] = StyledChar {
+                ch: ' ',
+                tail: None,
+                ..cell
+            };
             return;
         }
 
@@ -331,30 +807,232 @@ impl Output {
         }
 
         // Handle overwriting wide character's first half
-        let old_char_width = self.grid[idx].ch.width().unwrap_or(1);
-        if old_char_width == 2 && col + 1 < width {
+        if col + 1 < width && self.grid[idx + 1].ch == '\0' {
             self.grid[idx + 1] = StyledChar::new(' ');
         }
 
-        self.grid[idx] = StyledChar::with_style(ch, style);
+        self.grid[idx] = cell;
 
         // For wide characters (width=2), mark the next cell as a placeholder
         if char_width == 2 && col + 1 < width {
             let next_idx = idx + 1;
-            if self.grid[next_idx].ch != '\0' {
-                let next_char_width = self.grid[next_idx].ch.width().unwrap_or(1);
-                if next_char_width == 2 && col + 2 < width {
-                    self.grid[idx + 2] = StyledChar::new(' ');
-                }
+            // The next cell may itself start a wide char whose placeholder
+            // would be orphaned
+            if col + 2 < width && self.grid[idx + 2].ch == '\0' {
+                self.grid[idx + 2] = StyledChar::new(' ');
             }
             self.grid[next_idx] = StyledChar::new('\0');
         }
     }
 
+    /// Darken the cells already drawn in a rectangle toward `color`
+    ///
+    /// Text stays visible but dimmed, and backgrounds are tinted by
+    /// `strength` (`0.0..=1.0`). Cells without a background are treated as
+    /// the terminal default, black or white per [`is_dark_background`].
+    /// Respects the current clip region.
+    pub fn shade_rect(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        color: Color,
+        strength: f32,
+    ) {
+        let base = if is_dark_background() {
+            Color::Black
+        } else {
+            Color::White
+        };
+        let clip_region = self.clip_stack.last().cloned();
+        let cols = (x as usize)..(x as usize + width as usize).min(self.width as usize);
+        let rows = (y as usize)..(y as usize + height as usize).min(self.height as usize);
+
+        for row in rows {
+            self.mark_dirty(row);
+            for col in cols.clone() {
+                if let Some(clip) = clip_region.as_ref()
+                    && !clip.contains(col as u16, row as u16)
+                {
+                    continue;
+                }
+                let cell = &mut self.grid[row * self.width as usize + col];
+                cell.bg = Some(cell.bg.unwrap_or(base).blend(&color, strength));
+                cell.dim = true;
+            }
+        }
+    }
+
+    /// Run already drawn foreground and background colors in a rectangle
+    /// through a [`ColorFilter`]
+    ///
+    /// Cells using the terminal default colors are left as they are.
+    pub fn filter_rect(&mut self, x: u16, y: u16, width: u16, height: u16, filter: &ColorFilter) {
+        let clip_region = self.clip_stack.last().cloned();
+        let cols = (x as usize)..(x as usize + width as usize).min(self.width as usize);
+        let rows = (y as usize)..(y as usize + height as usize).min(self.height as usize);
+
+        for row in rows {
+            self.mark_dirty(row);
+            for col in cols.clone() {
+                if let Some(clip) = clip_region.as_ref()
+                    && !clip.contains(col as u16, row as u16)
+                {
+                    continue;
+                }
+                let cell = &mut self.grid[row * self.width as usize + col];
+                cell.fg = cell.fg.map(|color| filter.apply(color));
+                cell.bg = cell.bg.map(|color| filter.apply(color));
+            }
+        }
+    }
+
+    /// Turn what is drawn in a rectangle into an OSC 8 hyperlink
+    ///
+    /// On each row, the cells from the first drawn one to the last get the
+    /// link. Rendering opens it before the first linked cell of a run and
+    /// closes it after the last, so no link is left open across a line
+    /// break. Rows with nothing drawn are left alone.
+    pub fn link_rect(&mut self, x: u16, y: u16, width: u16, height: u16, url: &str) {
+        let link: Arc<str> = format!(";{}", url).into();
+        let clip_region = self.clip_stack.last().cloned();
+        let cols = (x as usize)..(x as usize + width as usize).min(self.width as usize);
+        let rows = (y as usize)..(y as usize + height as usize).min(self.height as usize);
+        let row_width = self.width as usize;
+
+        for row in rows {
+            let mut linked = cols.clone().filter(|&col| {
+                let cell = &self.grid[row * row_width + col];
+                cell.ch != '\0'
+                    && is_visible(cell)
+                    && clip_region
+                        .as_ref()
+                        .is_none_or(|clip| clip.contains(col as u16, row as u16))
+            });
+            let Some(first) = linked.next() else {
+                continue;
+            };
+            let last = linked.next_back().unwrap_or(first);
+            self.mark_dirty(row);
+
+            for col in first..=last {
+                let unclipped = clip_region
+                    .as_ref()
+                    .is_none_or(|clip| clip.contains(col as u16, row as u16));
+                if unclipped {
+                    self.grid[row * row_width + col].link = Some(link.clone());
+                }
+            }
+        }
+    }
+
+    /// Columns and rows of a rectangle that are on screen and unclipped
+    fn visible_span(
+        &self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+    ) -> (std::ops::Range<usize>, std::ops::Range<usize>) {
+        let (mut x1, mut y1) = (x as usize, y as usize);
+        let mut x2 = (x as usize + width as usize).min(self.width as usize);
+        let mut y2 = (y as usize + height as usize).min(self.height as usize);
+        if let Some(clip) = self.clip_stack.last() {
+            x1 = x1.max(clip.x1 as usize);
+            y1 = y1.max(clip.y1 as usize);
+            x2 = x2.min(clip.x2 as usize);
+            y2 = y2.min(clip.y2 as usize);
+        }
+        (x1..x2.max(x1), y1..y2.max(y1))
+    }
+
+    /// Blank out wide characters cut in half by the edges of `cols`, and
+    /// drop escapes, so the cells in range can be moved freely
+    fn detach_cells(&mut self, row: usize, cols: &std::ops::Range<usize>) {
+        let width = self.width as usize;
+        let start = row * width;
+        if cols.is_empty() {
+            return;
+        }
+        if self.grid[start + cols.start].ch == '\0' {
+            self.grid[start + cols.start] = StyledChar::new(' ');
+            if cols.start > 0 {
+                self.grid[start + cols.start - 1] = StyledChar::new(' ');
+            }
+        }
+        if cols.end < width && self.grid[start + cols.end].ch == '\0' {
+            self.grid[start + cols.end - 1] = StyledChar::new(' ');
+            self.grid[start + cols.end] = StyledChar::new(' ');
+        }
+        for col in cols.clone() {
+            strip_cell_escapes(&mut self.grid[start + col]);
+        }
+    }
+
+    /// Mirror what is drawn in a rectangle left to right
+    ///
+    /// Wide characters move as a unit, and box-drawing corners, brackets and
+    /// arrows are swapped for their mirror image. Links stay with their
+    /// cells; other escape sequences attached to the cells are dropped.
+    pub fn mirror_rect(&mut self, x: u16, y: u16, width: u16, height: u16) {
+        let (cols, rows) = self.visible_span(x, y, width, height);
+        let row_width = self.width as usize;
+        for row in rows {
+            self.detach_cells(row, &cols);
+            let start = row * row_width;
+            let cells = &mut self.grid[start + cols.start..start + cols.end];
+            cells.reverse();
+            // A reversed wide char has its placeholder first; swap them back
+            let mut col = 0;
+            while col < cells.len() {
+                if cells[col].ch == '\0' && col + 1 < cells.len() {
+                    cells.swap(col, col + 1);
+                    col += 2;
+                } else {
+                    col += 1;
+                }
+            }
+            for cell in cells.iter_mut() {
+                cell.ch = mirrored(cell.ch, MIRROR_HORIZONTAL);
+            }
+            self.mark_dirty(row);
+        }
+    }
+
+    /// Flip what is drawn in a rectangle upside down
+    ///
+    /// Rows are reversed and glyphs with an upside-down counterpart, like
+    /// box-drawing corners and half blocks, are swapped for it. Links stay
+    /// with their cells; other escape sequences attached to the cells are
+    /// dropped.
+    pub fn flip_rect(&mut self, x: u16, y: u16, width: u16, height: u16) {
+        let (cols, rows) = self.visible_span(x, y, width, height);
+        let row_width = self.width as usize;
+        for row in rows.clone() {
+            self.detach_cells(row, &cols);
+            for col in cols.clone() {
+                let cell = &mut self.grid[row * row_width + col];
+                cell.ch = mirrored(cell.ch, MIRROR_VERTICAL);
+            }
+            self.mark_dirty(row);
+        }
+
+        let (mut top, mut bottom) = (rows.start, rows.end);
+        while top + 1 < bottom {
+            bottom -= 1;
+            for col in cols.clone() {
+                self.grid
+                    .swap(top * row_width + col, bottom * row_width + col);
+            }
+            top += 1;
+        }
+    }
+
     /// Fill a rectangle with a character
     pub fn fill_rect(&mut self, x: u16, y: u16, width: u16, height: u16, ch: char, style: &Style) {
-        for row in y..(y + height).min(self.height) {
-            for col in x..(x + width).min(self.width) {
+        for row in y..y.saturating_add(height).min(self.height) {
+            for col in x..x.saturating_add(width).min(self.width) {
                 self.write_char(col, row, ch, style);
             }
         }
@@ -523,6 +1201,35 @@ mod tests {
         assert!(rendered.contains("\x1b["));
     }
 
+    #[test]
+    fn test_faint_level_zero_matches_plain_style() {
+        let base = Style::new().fg(Color::Cyan).bg(Color::Blue);
+        let mut plain = Output::new(10, 1);
+        plain.write(0, 0, "fade", &base);
+        let mut faded = Output::new(10, 1);
+        faded.write(0, 0, "fade", &base.clone().faint_level(0.0));
+
+        assert_eq!(plain.render(), faded.render());
+    }
+
+    #[test]
+    fn test_faint_level_blends_toward_background() {
+        let mut output = Output::new(10, 1);
+        let style = Style::new()
+            .fg(Color::Rgb(200, 200, 200))
+            .bg(Color::Rgb(0, 0, 100))
+            .faint_level(0.5);
+        output.write(0, 0, "a", &style);
+        assert_eq!(
+            output.cell_at(0, 0).unwrap().fg,
+            Some(Color::Rgb(100, 100, 150))
+        );
+
+        let full = Style::new().fg(Color::Rgb(200, 200, 200)).faint_level(1.0);
+        output.write(1, 0, "b", &full);
+        assert_eq!(output.cell_at(1, 0).unwrap().fg, Some(Color::Rgb(0, 0, 0)));
+    }
+
     #[test]
     fn test_wide_char_placeholder() {
         let mut output = Output::new(80, 24);
@@ -665,6 +1372,38 @@ mod tests {
         assert_eq!(output.cell_at(3, 0).unwrap().ch, '\0');
     }
 
+    #[test]
+    fn test_zwj_emoji_occupies_two_cells() {
+        let mut output = Output::new(10, 1);
+        output.write(0, 0, "👨\u{200D}👩\u{200D}👧x", &Style::default());
+
+        assert_eq!(output.cell_at(0, 0).unwrap().ch, '👨');
+        assert_eq!(output.cell_at(1, 0).unwrap().ch, '\0');
+        assert_eq!(output.cell_at(2, 0).unwrap().ch, 'x');
+        assert_eq!(output.render(), "👨\u{200D}👩\u{200D}👧x");
+    }
+
+    #[test]
+    fn test_control_chars_take_a_cell_and_zero_width_chars_none() {
+        let mut output = Output::new(10, 1);
+        output.write(0, 0, "é\tx\u{200B}y", &Style::default());
+
+        assert_eq!(output.cell_at(1, 0).unwrap().ch, '\t');
+        assert_eq!(output.cell_at(2, 0).unwrap().ch, 'x');
+        assert_eq!(output.cell_at(3, 0).unwrap().ch, 'y');
+    }
+
+    #[test]
+    fn test_vs16_emoji_and_combining_marks() {
+        let mut output = Output::new(10, 1);
+        output.write(0, 0, "❤\u{FE0F}e\u{301}!", &Style::default());
+
+        assert_eq!(output.cell_at(1, 0).unwrap().ch, '\0');
+        assert_eq!(output.cell_at(2, 0).unwrap().ch, 'e');
+        assert_eq!(output.cell_at(3, 0).unwrap().ch, '!');
+        assert_eq!(output.render(), "❤\u{FE0F}e\u{301}!");
+    }
+
     #[test]
     fn test_dirty_tracking_initial_state() {
         let output = Output::new(80, 24);
@@ -735,6 +1474,198 @@ mod tests {
         assert_eq!(dirty_rows[1].1, "Line 2");
     }
 
+    #[test]
+    fn test_shade_rect_dims_existing_cells() {
+        let mut output = Output::new(4, 2);
+        output.write(
+            0,
+            0,
+            "ab",
+            &Style::new().fg(Color::Red).bg(Color::Rgb(0, 0, 0)),
+        );
+        output.shade_rect(1, 0, 2, 1, Color::Rgb(100, 100, 100), 0.5);
+
+        let untouched = output.get(0, 0).unwrap();
+        assert!(!untouched.dim);
+        assert_eq!(untouched.bg, Some(Color::Rgb(0, 0, 0)));
+
+        let text = output.get(1, 0).unwrap();
+        assert_eq!(text.ch, 'b');
+        assert_eq!(text.fg, Some(Color::Red));
+        assert!(text.dim);
+        assert_eq!(text.bg, Some(Color::Rgb(50, 50, 50)));
+
+        let blank = output.get(2, 0).unwrap();
+        assert_eq!(blank.ch, ' ');
+        assert!(blank.dim && blank.bg.is_some());
+        assert!(output.get(1, 1).unwrap().bg.is_none());
+    }
+
+    #[test]
+    fn test_write_escapes_take_no_cells() {
+        let mut output = Output::new(4, 1);
+        let link = "\x1b]8;;https://example.com\x1b\\link text\x1b]8;;\x1b\\";
+        output.write(0, 0, link, &Style::default());
+
+        assert_eq!(output.get(3, 0).unwrap().ch, 'k');
+        assert_eq!(
+            output.render(),
+            "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\"
+        );
+    }
+
+    #[test]
+    fn test_link_rect_links_each_drawn_row() {
+        let mut output = Output::new(6, 3);
+        output.write(1, 0, "ab", &Style::default());
+        output.write(2, 2, "c", &Style::default());
+        output.link_rect(0, 0, 6, 3, "https://x.io");
+
+        assert_eq!(
+            output.render(),
+            " \x1b]8;;https://x.io\x1b\\ab\x1b]8;;\x1b\\\r\n\r\n  \x1b]8;;https://x.io\x1b\\c\x1b]8;;\x1b\\"
+        );
+    }
+
+    #[test]
+    fn test_links_are_cell_attributes() {
+        let mut output = Output::new(6, 1);
+        output.write(
+            0,
+            0,
+            "\x1b]8;id=1;https://x.io\x1b\\ab\x1b]8;;\x1b\\c",
+            &Style::default(),
+        );
+
+        let first = output.cell_at(0, 0).unwrap();
+        assert_eq!(first.ch, 'a');
+        assert!(first.tail.is_none());
+        assert_eq!(first.link.as_deref(), Some("id=1;https://x.io"));
+        assert!(output.cell_at(2, 0).unwrap().link.is_none());
+
+        // Mirroring keeps each cell's link
+        output.mirror_rect(0, 0, 3, 1);
+        assert_eq!(
+            output.render(),
+            "c\x1b]8;id=1;https://x.io\x1b\\ba\x1b]8;;\x1b\\"
+        );
+    }
+
+    #[test]
+    fn test_diff_closes_links_in_partial_runs() {
+        let mut previous = Output::new(6, 1);
+        previous.write(0, 0, "abcd", &Style::default());
+        let mut output = Output::new(6, 1);
+        output.write(0, 0, "abcd", &Style::default());
+        output.link_rect(0, 0, 6, 1, "https://x.io");
+        output.write(1, 0, "X", &Style::default());
+
+        // Only the linked cells changed; the overwritten one breaks the link
+        assert_eq!(
+            output.diff(&previous),
+            "\x1b[1;1H\x1b]8;;https://x.io\x1b\\a\x1b]8;;\x1b\\X\x1b]8;;https://x.io\x1b\\cd\x1b]8;;\x1b\\"
+        );
+    }
+
+    #[test]
+    fn test_resize_preserves_overlapping_content() {
+        let mut output = Output::new(6, 2);
+        output.write(0, 0, "Hello", &Style::default());
+        output.write(0, 1, "Hi", &Style::default());
+        output.clear_dirty();
+
+        output.resize(8, 3);
+        assert_eq!((output.width, output.height), (8, 3));
+        assert_eq!(output.cell_at(0, 0).unwrap().ch, 'H');
+        assert_eq!(output.cell_at(7, 0).unwrap().ch, ' ');
+        assert!(!output.is_row_dirty(0));
+        assert!(!output.is_row_dirty(1));
+        assert!(output.is_row_dirty(2));
+        assert_eq!(output.render_fixed_height(), "Hello\r\nHi\r\n");
+    }
+
+    #[test]
+    fn test_resize_shrink_marks_truncated_rows_dirty() {
+        let mut output = Output::new(6, 3);
+        output.write(0, 0, "Hello", &Style::default());
+        output.write(0, 1, "Hi", &Style::default());
+        output.write(0, 2, "Gone", &Style::default());
+        output.clear_dirty();
+
+        output.resize(3, 2);
+        let dirty: Vec<usize> = output.dirty_row_indices().collect();
+        assert_eq!(dirty, vec![0]);
+        assert_eq!(output.render_fixed_height(), "Hel\r\nHi");
+    }
+
+    #[test]
+    fn test_resize_drops_split_wide_char() {
+        let mut output = Output::new(4, 1);
+        output.write(0, 0, "a你", &Style::default());
+        output.clear_dirty();
+
+        output.resize(2, 1);
+        assert_eq!(output.cell_at(1, 0).unwrap().ch, ' ');
+        assert!(output.is_row_dirty(0));
+        assert_eq!(output.render_fixed_height(), "a");
+    }
+
+    #[test]
+    fn test_diff_identical_frames_is_empty() {
+        let mut previous = Output::new(10, 2);
+        previous.write(0, 0, "Hello", &Style::default());
+        let mut next = Output::new(10, 2);
+        next.write(0, 0, "Hello", &Style::default());
+
+        assert!(next.diff(&previous).is_empty());
+    }
+
+    #[test]
+    fn test_diff_writes_only_changed_cells() {
+        let mut previous = Output::new(10, 2);
+        previous.write(0, 0, "Hello", &Style::default());
+        previous.write(0, 1, "World", &Style::default());
+        let mut next = Output::new(10, 2);
+        next.write(0, 0, "Hallo", &Style::default());
+        next.write(0, 1, "World", &Style::default());
+
+        assert_eq!(next.diff(&previous), "\x1b[1;2Ha");
+    }
+
+    #[test]
+    fn test_diff_styles_changed_run() {
+        let previous = Output::new(4, 1);
+        let mut next = Output::new(4, 1);
+        let bold = Style {
+            bold: true,
+            ..Style::default()
+        };
+        next.write(1, 0, "ab", &bold);
+
+        assert_eq!(next.diff(&previous), "\x1b[1;2H\x1b[1mab\x1b[0m");
+    }
+
+    #[test]
+    fn test_diff_keeps_wide_chars_whole() {
+        let mut previous = Output::new(4, 1);
+        previous.write(0, 0, "你", &Style::default());
+        let mut next = Output::new(4, 1);
+        next.write(0, 0, "好", &Style::default());
+
+        assert_eq!(next.diff(&previous), "\x1b[1;1H好");
+    }
+
+    #[test]
+    fn test_diff_erases_content_outside_new_frame() {
+        let mut previous = Output::new(6, 3);
+        previous.write(0, 0, "abcdef", &Style::default());
+        previous.write(0, 2, "gone", &Style::default());
+        let mut next = Output::new(3, 2);
+        next.write(0, 0, "abc", &Style::default());
+
+        assert_eq!(next.diff(&previous), "\x1b[1;4H\x1b[K\x1b[3;1H\x1b[2K");
+    }
+
     #[test]
     fn test_render_after_clear_dirty_preserves_content() {
         let mut output = Output::new(10, 2);
@@ -787,4 +1718,30 @@ mod tests {
         });
         let _ = output.render();
     }
+
+    #[test]
+    fn test_double_width_rows() {
+        let _double_width = crate::runtime::DoubleWidthLinesOverride::new(true);
+        let style = Style::new().double_width();
+
+        let mut output = Output::new(10, 2);
+        output.write(1, 0, "Hi", &style);
+        output.write(0, 1, "x", &Style::new());
+        assert!(output.is_double_width_row(0));
+        assert_eq!(output.cell_at(0, 0).unwrap().ch, 'H');
+        assert_eq!(output.cell_at(2, 0).unwrap().ch, 'i');
+        assert_eq!(output.render(), "\x1b#6Hi\r\n\x1b#5x");
+
+        // The whole line is redrawn when it changes, and reset to normal
+        // size once it stops being double-width
+        let mut next = Output::new(10, 2);
+        next.write(0, 0, "Ho", &style);
+        next.write(0, 1, "x", &Style::new());
+        assert_eq!(next.diff(&output), "\x1b[1;1H\x1b[2K\x1b#6Ho");
+
+        let mut plain = Output::new(10, 2);
+        plain.write(0, 0, "Hi", &Style::new());
+        plain.write(0, 1, "x", &Style::new());
+        assert_eq!(plain.diff(&output), "\x1b[1;1H\x1b[2K\x1b#5Hi");
+    }
 }
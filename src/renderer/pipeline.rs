@@ -21,7 +21,7 @@ impl RenderPipeline {
         layout_engine: &mut LayoutEngine,
         runtime_context: &Rc<RefCell<RuntimeContext>>,
         previous_vnode: &mut Option<VNode>,
-    ) -> String {
+    ) -> Output {
         // Compute layout with reconciler diff/patch when possible.
         let (current_vnode, _layout_outcome) = layout_engine.compute_element_incremental(
             dynamic_root,
@@ -44,6 +44,14 @@ impl RenderPipeline {
                 key_aliases,
             );
 
+        // Responsive containers chose their view from the previous layout;
+        // render again if the new one gives them a width for another view.
+        let ctx = runtime_context.borrow();
+        if ctx.has_stale_responsive_layout() {
+            ctx.request_render();
+        }
+        drop(ctx);
+
         // Get content size from root layout.
         let root_layout = layout_engine
             .get_layout(dynamic_root.id)
@@ -54,7 +62,7 @@ impl RenderPipeline {
         // Render to output buffer.
         let mut output = Output::new(content_width, render_height);
         render_element(dynamic_root, layout_engine, &mut output, 0.0, 0.0);
-        output.render()
+        output
     }
 
     fn collect_key_aliases(
@@ -129,6 +129,9 @@ pub(crate) trait AppSink: Send + Sync {
     fn queue_exec(&self, request: ExecRequest);
     fn queue_terminal_cmd(&self, cmd: TerminalCmd);
     fn request_suspend(&self);
+    fn set_debug_layout(&self, enabled: bool);
+    fn is_debug_layout(&self) -> bool;
+    fn has_terminal_control(&self) -> bool;
 }
 
 // === Mode Switch ===
@@ -153,6 +156,8 @@ pub(crate) struct AppRuntime {
     exec_queue: Mutex<Vec<ExecRequest>>,
     terminal_cmd_queue: Mutex<Vec<TerminalCmd>>,
     suspend_request: AtomicBool,
+    debug_layout: AtomicBool,
+    terminal_control: AtomicBool,
 }
 
 impl AppRuntime {
@@ -166,6 +171,8 @@ impl AppRuntime {
             exec_queue: Mutex::new(Vec::new()),
             terminal_cmd_queue: Mutex::new(Vec::new()),
             suspend_request: AtomicBool::new(false),
+            debug_layout: AtomicBool::new(false),
+            terminal_control: AtomicBool::new(true),
         })
     }
 
@@ -173,6 +180,10 @@ impl AppRuntime {
         self.id
     }
 
+    pub(crate) fn set_terminal_control(&self, enabled: bool) {
+        self.terminal_control.store(enabled, Ordering::SeqCst);
+    }
+
     pub(crate) fn set_alt_screen_state(&self, value: bool) {
         self.alt_screen_state.store(value, Ordering::SeqCst);
     }
@@ -293,6 +304,20 @@ impl AppSink for AppRuntime {
     fn request_suspend(&self) {
         self.request_suspend();
     }
+
+    fn set_debug_layout(&self, enabled: bool) {
+        if self.debug_layout.swap(enabled, Ordering::SeqCst) != enabled {
+            self.request_render();
+        }
+    }
+
+    fn is_debug_layout(&self) -> bool {
+        self.debug_layout.load(Ordering::SeqCst)
+    }
+
+    fn has_terminal_control(&self) -> bool {
+        self.terminal_control.load(Ordering::SeqCst)
+    }
 }
 
 // === Global Registry ===
@@ -317,6 +342,22 @@ pub(crate) fn current_app_sink() -> Option<Arc<dyn AppSink>> {
     registry.get(&id).cloned()
 }
 
+/// Check whether the current app, if any, controls the terminal
+///
+/// Never blocks on the registry, so it is safe to call from a panic hook.
+/// Returns `true` when no app is running.
+pub(crate) fn current_app_controls_terminal() -> bool {
+    let Some(id) = AppId::from_raw(CURRENT_APP.load(Ordering::SeqCst)) else {
+        return true;
+    };
+    let Ok(registry) = registry().try_lock() else {
+        return true;
+    };
+    registry
+        .get(&id)
+        .is_none_or(|sink| sink.has_terminal_control())
+}
+
 fn current_runtime_render_handle() -> Option<RenderHandle> {
     crate::runtime::current_runtime().and_then(|ctx| ctx.borrow().render_handle().cloned())
 }
@@ -495,6 +536,33 @@ pub fn is_alt_screen() -> Option<bool> {
     current_render_handle().map(|handle| handle.is_alt_screen())
 }
 
+/// Show or hide the layout debug overlay.
+///
+/// The overlay outlines every element and labels it with its `w×h` size.
+/// This can be called from any thread, including hotkey handlers.
+pub fn set_debug_layout(enabled: bool) {
+    if let Some(handle) = current_render_handle() {
+        handle.set_debug_layout(enabled);
+    }
+}
+
+/// Toggle the layout debug overlay.
+///
+/// # Example
+///
+/// ```ignore
+/// use rnk::renderer::HotkeyFilter;
+///
+/// render(my_app)
+///     .with_hotkeys(HotkeyFilter::new().register(Key::parse("F12")?, rnk::toggle_debug_layout))
+///     .run()?;
+/// ```
+pub fn toggle_debug_layout() {
+    if let Some(handle) = current_render_handle() {
+        handle.set_debug_layout(!handle.is_debug_layout());
+    }
+}
+
 /// Queue an exec request to run an external process.
 ///
 /// This is used internally by the Cmd system to queue exec requests.
@@ -509,10 +577,17 @@ pub(crate) fn queue_exec_request(request: ExecRequest) {
 /// Queue a terminal control command.
 ///
 /// This is used internally by the Cmd system to queue terminal commands
-/// like ClearScreen, HideCursor, ShowCursor, etc.
-pub(crate) fn queue_terminal_cmd(cmd: TerminalCmd) {
-    if let Some(handle) = current_render_handle() {
-        handle.queue_terminal_cmd(cmd);
+/// like ClearScreen, HideCursor, ShowCursor, etc. The app's terminal writes
+/// the escape sequence to its output, or drops it without terminal control.
+///
+/// Returns `false` when no app is running to take the command.
+pub(crate) fn queue_terminal_cmd(cmd: TerminalCmd) -> bool {
+    match current_render_handle() {
+        Some(handle) => {
+            handle.queue_terminal_cmd(cmd);
+            true
+        }
+        None => false,
     }
 }
 
@@ -576,6 +651,16 @@ impl RenderHandle {
         self.sink.request_suspend();
     }
 
+    /// Show or hide the layout debug overlay
+    pub fn set_debug_layout(&self, enabled: bool) {
+        self.sink.set_debug_layout(enabled);
+    }
+
+    /// Check if the layout debug overlay is shown
+    pub fn is_debug_layout(&self) -> bool {
+        self.sink.is_debug_layout()
+    }
+
     pub(crate) fn queue_exec(&self, request: ExecRequest) {
         self.sink.queue_exec(request);
     }
@@ -827,6 +912,26 @@ mod tests {
         set_current_runtime(None);
     }
 
+    #[test]
+    fn test_title_and_paste_escapes_go_through_the_app() {
+        let runtime = AppRuntime::new(false);
+        set_current_runtime(Some(runtime_context_with_handle(runtime.clone())));
+
+        crate::hooks::set_window_title("build\x07 done");
+        crate::hooks::enable_bracketed_paste().unwrap();
+
+        assert_eq!(
+            runtime.take_terminal_cmds(),
+            vec![
+                TerminalCmd::SetWindowTitle("build done".to_string()),
+                TerminalCmd::EnableBracketedPaste,
+            ]
+        );
+
+        crate::hooks::disable_bracketed_paste().unwrap();
+        set_current_runtime(None);
+    }
+
     #[test]
     fn test_queue_exec_request_uses_runtime_handle_without_registry() {
         let runtime = AppRuntime::new(false);
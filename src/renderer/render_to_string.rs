@@ -2,9 +2,26 @@
 //!
 //! This module provides utilities for rendering elements to strings
 //! outside of the main application runtime.
+//!
+//! The variants differ only in the [`RenderOptions`] they pass along:
+//!
+//! | Function | Trailing spaces | Line endings | ANSI styling |
+//! |----------|-----------------|--------------|--------------|
+//! | [`render_to_string`] | trimmed | LF | kept |
+//! | [`render_to_string_no_trim`] | kept | LF | kept |
+//! | [`render_to_string_raw`] | kept | CRLF | always kept |
+//! | [`render_to_string_auto`] | trimmed | LF | stripped for `NO_COLOR`, `TERM=dumb` or non-tty stdout |
+//!
+//! Only the `auto` variant looks at the environment. Explicit
+//! [`render_to_string_with_options`] calls do exactly what the options say.
+//!
+//! [`render_frame_to`] renders a fixed-size frame straight into any writer.
+
+use std::io::{IsTerminal, Write};
 
 use crate::core::Element;
 use crate::layout::LayoutEngine;
+use crate::layout::measure::strip_ansi;
 use crate::renderer::tree_renderer::render_element_tree;
 use crate::renderer::{Output, Terminal};
 
@@ -27,6 +44,11 @@ impl Default for RenderOptions {
     }
 }
 
+/// Decide whether output should be plain text for the given environment.
+fn plain_output_requested(no_color: Option<&str>, term: Option<&str>, is_tty: bool) -> bool {
+    no_color.is_some_and(|value| !value.is_empty()) || term == Some("dumb") || !is_tty
+}
+
 /// Render an element to a string with full control over options.
 pub fn render_to_string_with_options(
     element: &Element,
@@ -34,7 +56,11 @@ pub fn render_to_string_with_options(
     options: &RenderOptions,
 ) -> String {
     let raw = RenderHelper.render_to_output(element, width);
+    finish_output(raw, options)
+}
 
+/// Apply line ending normalization and trimming to rendered output
+fn finish_output(raw: String, options: &RenderOptions) -> String {
     if !options.normalize_line_endings {
         return raw;
     }
@@ -88,7 +114,8 @@ pub fn render_to_string_no_trim(element: &Element, width: u16) -> String {
 /// Render an element to a string with CRLF line endings for raw mode.
 ///
 /// Use this when writing to a terminal in raw mode, where `\n` alone
-/// does not perform a carriage return.
+/// does not perform a carriage return. ANSI styling is always kept,
+/// regardless of `NO_COLOR` or whether stdout is a terminal.
 pub fn render_to_string_raw(element: &Element, width: u16) -> String {
     render_to_string_with_options(
         element,
@@ -102,6 +129,10 @@ pub fn render_to_string_raw(element: &Element, width: u16) -> String {
 
 /// Render an element to a string with automatic width detection.
 ///
+/// Uses the terminal width (80 columns if unknown). ANSI styling is
+/// stripped when the environment asks for plain text: `NO_COLOR` is set,
+/// `TERM=dumb`, or stdout is not a terminal (piped to a file or `grep`).
+///
 /// # Example
 ///
 /// ```ignore
@@ -113,7 +144,56 @@ pub fn render_to_string_raw(element: &Element, width: u16) -> String {
 /// ```
 pub fn render_to_string_auto(element: &Element) -> String {
     let (width, _) = Terminal::size().unwrap_or((80, 24));
-    render_to_string(element, width)
+    let plain = plain_output_requested(
+        std::env::var("NO_COLOR").ok().as_deref(),
+        std::env::var("TERM").ok().as_deref(),
+        std::io::stdout().is_terminal(),
+    );
+    if plain {
+        render_to_plain_string(element, width)
+    } else {
+        render_to_string(element, width)
+    }
+}
+
+/// Render like [`render_to_string`], with all ANSI escape sequences removed
+fn render_to_plain_string(element: &Element, width: u16) -> String {
+    let raw = strip_ansi(&RenderHelper.render_to_output(element, width));
+    finish_output(raw, &RenderOptions::default())
+}
+
+/// Render one frame of `width` x `height` cells into `writer`.
+///
+/// Unlike the `render_to_string` family, the frame has exactly `height`
+/// lines, each ending in LF, so fixed layouts (fullscreen views, percentage
+/// sizes) render as they would on a terminal of that size. Trailing spaces
+/// are trimmed and ANSI styling is kept. Use this for non-interactive
+/// rendering into files, sockets or server responses.
+///
+/// # Example
+///
+/// ```ignore
+/// use rnk::prelude::*;
+///
+/// let mut frame = Vec::new();
+/// rnk::render_frame_to(&mut frame, &dashboard(), 80, 24)?;
+/// ```
+pub fn render_frame_to(
+    mut writer: impl Write,
+    element: &Element,
+    width: u16,
+    height: u16,
+) -> std::io::Result<()> {
+    let mut engine = LayoutEngine::new();
+    engine.compute(element, width, height);
+
+    let mut output = Output::new(width, height);
+    render_element_tree(element, &engine, &mut output, 0.0, 0.0);
+
+    for line in output.render_fixed_height().split("\r\n") {
+        writeln!(writer, "{}", line.trim_end())?;
+    }
+    writer.flush()
 }
 
 /// Helper struct for rendering elements outside the app runtime
@@ -279,6 +359,38 @@ mod tests {
         assert!(not_trimmed.contains("Hi"));
     }
 
+    #[test]
+    fn test_plain_output_requested() {
+        assert!(!plain_output_requested(None, Some("xterm-256color"), true));
+        assert!(plain_output_requested(Some("1"), Some("xterm"), true));
+        assert!(!plain_output_requested(Some(""), Some("xterm"), true));
+        assert!(plain_output_requested(None, Some("dumb"), true));
+        assert!(plain_output_requested(None, Some("xterm"), false));
+    }
+
+    #[test]
+    fn test_render_to_plain_string_strips_ansi() {
+        let element = Text::new("Styled")
+            .color(crate::core::Color::Red)
+            .bold()
+            .into_element();
+
+        let styled = render_to_string(&element, 20);
+        assert!(styled.contains('\x1b'));
+
+        let plain = render_to_plain_string(&element, 20);
+        assert_eq!(plain, "Styled");
+
+        let raw = render_to_string_raw(&element, 20);
+        assert!(raw.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_strip_ansi_handles_osc_links() {
+        let text = "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\ \x1b[1mbold\x1b[0m";
+        assert_eq!(strip_ansi(text), "link bold");
+    }
+
     #[test]
     fn test_render_to_string_applies_scroll_offset() {
         let element = Box::new()
@@ -307,4 +419,21 @@ mod tests {
         assert!(output.contains("line-0"));
         assert!(output.contains("line-1099"));
     }
+
+    #[test]
+    fn test_render_frame_to_writes_fixed_height_frame() {
+        let element = Box::new()
+            .border_style(BorderStyle::Single)
+            .child(Text::new("Hi").into_element())
+            .into_element();
+
+        let mut frame = Vec::new();
+        render_frame_to(&mut frame, &element, 6, 5).unwrap();
+        let frame = strip_ansi(&String::from_utf8(frame).unwrap());
+
+        assert_eq!(frame.lines().count(), 5);
+        assert!(frame.ends_with('\n'));
+        assert!(!frame.contains('\r'));
+        assert!(frame.lines().any(|line| line.contains("Hi")));
+    }
 }
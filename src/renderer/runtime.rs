@@ -9,10 +9,15 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
+use crate::core::init_background_detection_from_reply;
+use crate::hooks::dispatch_paste;
+use crate::hooks::set_terminal_focused;
 use crate::hooks::use_input::dispatch_key_event;
 use crate::hooks::use_mouse::dispatch_mouse_event;
 use crate::renderer::Terminal;
+use crate::runtime::{OsSignal, SignalCallbacks, take_pending_signal};
 
+use super::background_reply::BackgroundReplyWatcher;
 use super::filter::FilterChain;
 use super::frame_rate::FrameRateController;
 use super::registry::{AppRuntime, AppSink};
@@ -29,6 +34,10 @@ pub(crate) struct EventLoop {
     cancel_flag: Option<Arc<AtomicBool>>,
     /// Render notifications from background tasks
     render_rx: Option<mpsc::UnboundedReceiver<()>>,
+    /// Callbacks registered with `AppBuilder::on_signal`
+    signal_callbacks: SignalCallbacks,
+    /// Pending OSC 11 background reply
+    background_reply: BackgroundReplyWatcher,
 }
 
 impl EventLoop {
@@ -47,6 +56,8 @@ impl EventLoop {
             filter_chain,
             cancel_flag: None,
             render_rx: None,
+            signal_callbacks: SignalCallbacks::default(),
+            background_reply: BackgroundReplyWatcher::default(),
         }
     }
 
@@ -60,6 +71,16 @@ impl EventLoop {
         self
     }
 
+    pub(crate) fn with_signal_callbacks(mut self, callbacks: SignalCallbacks) -> Self {
+        self.signal_callbacks = callbacks;
+        self
+    }
+
+    /// Watch the input for the reply to a background color query just sent
+    pub(crate) fn expect_background_reply(&mut self) {
+        self.background_reply.expect_reply(Instant::now());
+    }
+
     /// Run the event loop
     ///
     /// Returns when should_exit is set or an error occurs
@@ -67,6 +88,9 @@ impl EventLoop {
     where
         F: FnMut() -> std::io::Result<()>,
     {
+        // Catch OS signals that have callbacks until the loop returns
+        let _signal_guard = self.signal_callbacks.install();
+
         // Initial render
         let start = Instant::now();
         on_render()?;
@@ -74,13 +98,27 @@ impl EventLoop {
 
         loop {
             // Handle input events
-            if let Some(event) = Terminal::poll_event(Duration::from_millis(10))? {
+            let mut events = match Terminal::poll_event(Duration::from_millis(10))? {
+                Some(event) => self.background_reply.feed(event, Instant::now()),
+                None => Vec::new(),
+            };
+            events.extend(self.background_reply.flush_idle(Instant::now()));
+            for event in events {
                 // Apply event filters
                 if let Some(filtered_event) = self.filter_chain.apply(event) {
                     self.handle_event(filtered_event);
                 }
                 // If filter returned None, the event was blocked
             }
+            if let Some(reply) = self.background_reply.take_reply() {
+                init_background_detection_from_reply(&reply);
+                self.runtime.request_render();
+            }
+
+            // Handle OS signals caught since the last iteration
+            while let Some(signal) = take_pending_signal() {
+                self.handle_signal(signal);
+            }
 
             // Check exit condition
             if self.should_exit.load(Ordering::SeqCst) {
@@ -104,11 +142,10 @@ impl EventLoop {
             // Drain render notifications from background tasks
             self.drain_render_notifications();
 
-            // Check if render is needed
+            // Render when requested, or every frame while animations run
             let render_requested = self.runtime.render_requested();
-            let time_elapsed = self.frame_rate.should_render();
 
-            if render_requested && time_elapsed {
+            if self.frame_rate.should_render_frame(render_requested) {
                 self.runtime.clear_render_request();
                 let start = Instant::now();
                 on_render()?;
@@ -123,14 +160,17 @@ impl EventLoop {
     fn handle_event(&mut self, event: Event) {
         match event {
             Event::Key(key_event) => {
-                // Ignore key release/repeat events to avoid duplicate actions.
-                if key_event.kind != KeyEventKind::Press {
+                // Ignore key releases; repeats are dispatched with `Key::repeat`
+                // set so handlers can coalesce held keys.
+                if key_event.kind == KeyEventKind::Release {
                     return;
                 }
 
-                // Handle Ctrl+C
-                if self.exit_on_ctrl_c && Terminal::is_ctrl_c(&Event::Key(key_event)) {
-                    self.should_exit.store(true, Ordering::SeqCst);
+                // Handle Ctrl+C, which raw mode delivers as a key press
+                if Terminal::is_ctrl_c(&Event::Key(key_event))
+                    && (self.exit_on_ctrl_c || self.signal_callbacks.handles(OsSignal::Interrupt))
+                {
+                    self.handle_signal(OsSignal::Interrupt);
                     return;
                 }
 
@@ -162,12 +202,34 @@ impl EventLoop {
                 // Request re-render after mouse event
                 self.runtime.request_render();
             }
+            Event::Paste(content) => {
+                // Bracketed paste arrives as one event; hand it to use_paste
+                dispatch_paste(&content);
+
+                // Record user activity for idle detection
+                crate::hooks::record_activity();
+
+                // Request re-render after paste
+                self.runtime.request_render();
+            }
+            Event::FocusGained | Event::FocusLost => {
+                set_terminal_focused(matches!(event, Event::FocusGained));
+                // Re-render so focus-gated hooks pick up the change
+                self.runtime.request_render();
+            }
             Event::Resize(_new_width, _new_height) => {
                 // Resize is handled by the App itself
                 // Just request re-render
+                self.handle_signal(OsSignal::WindowChange);
                 self.runtime.request_render();
             }
-            _ => {}
+        }
+    }
+
+    /// Run signal callbacks and exit if they (or the default) ask to
+    fn handle_signal(&mut self, signal: OsSignal) {
+        if self.signal_callbacks.dispatch(signal) {
+            self.should_exit.store(true, Ordering::SeqCst);
         }
     }
 
@@ -273,6 +335,33 @@ mod tests {
         set_current_runtime(None);
     }
 
+    #[test]
+    fn test_event_loop_paste_dispatch_requests_render() {
+        use crate::hooks::paste::register_paste_handler;
+        use crate::runtime::{RuntimeContext, set_current_runtime};
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let runtime = AppRuntime::new(false);
+        runtime.clear_render_request();
+        let should_exit = Arc::new(AtomicBool::new(false));
+        let mut event_loop = create_event_loop(runtime.clone(), should_exit);
+
+        let rt_ctx = Rc::new(RefCell::new(RuntimeContext::new()));
+        set_current_runtime(Some(rt_ctx.clone()));
+
+        let pasted = Rc::new(RefCell::new(String::new()));
+        let pasted_clone = pasted.clone();
+        register_paste_handler(move |event| pasted_clone.borrow_mut().push_str(event.content()));
+
+        event_loop.handle_event(Event::Paste("hunter2".to_string()));
+
+        assert_eq!(*pasted.borrow(), "hunter2");
+        assert!(runtime.render_requested());
+
+        set_current_runtime(None);
+    }
+
     #[test]
     fn test_event_loop_ignores_key_release_events() {
         use crate::runtime::{RuntimeContext, set_current_runtime};
@@ -303,6 +392,43 @@ mod tests {
         set_current_runtime(None);
     }
 
+    #[test]
+    fn test_event_loop_dispatches_reported_repeats_and_lock_state() {
+        use crate::hooks::Key;
+        use crate::runtime::{RuntimeContext, set_current_runtime};
+        use crossterm::event::KeyEventState;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let runtime = AppRuntime::new(false);
+        let should_exit = Arc::new(AtomicBool::new(false));
+        let mut event_loop = create_event_loop(runtime, should_exit);
+
+        let rt_ctx = Rc::new(RefCell::new(RuntimeContext::new()));
+        set_current_runtime(Some(rt_ctx.clone()));
+
+        let keys: Rc<RefCell<Vec<Key>>> = Rc::new(RefCell::new(Vec::new()));
+        let keys_clone = keys.clone();
+        register_input_handler(move |_input, key| keys_clone.borrow_mut().push(*key));
+
+        // With keyboard enhancement pushed, a held key arrives as a press
+        // followed by repeats, and lock state rides along on every event
+        let mut press = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE);
+        press.state = KeyEventState::CAPS_LOCK;
+        let mut repeat = press;
+        repeat.kind = KeyEventKind::Repeat;
+        event_loop.handle_event(Event::Key(press));
+        event_loop.handle_event(Event::Key(repeat));
+
+        let keys = keys.borrow();
+        assert_eq!(keys.len(), 2);
+        assert!(!keys[0].repeat);
+        assert!(keys[1].repeat);
+        assert!(keys.iter().all(|key| key.caps_lock));
+
+        set_current_runtime(None);
+    }
+
     #[test]
     fn test_event_loop_mouse_dispatch_requests_render() {
         use crate::runtime::{RuntimeContext, set_current_runtime};
@@ -337,6 +463,27 @@ mod tests {
         set_current_runtime(None);
     }
 
+    #[test]
+    fn test_event_loop_focus_events_update_focus_state() {
+        use crate::hooks::is_terminal_focused;
+        use crate::hooks::use_terminal_focus::FOCUS_TEST_LOCK;
+
+        let _guard = FOCUS_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let runtime = AppRuntime::new(false);
+        runtime.clear_render_request();
+        let should_exit = Arc::new(AtomicBool::new(false));
+        let mut event_loop = create_event_loop(runtime.clone(), should_exit);
+
+        event_loop.handle_event(Event::FocusLost);
+        assert!(!is_terminal_focused());
+        assert!(runtime.render_requested());
+
+        runtime.clear_render_request();
+        event_loop.handle_event(Event::FocusGained);
+        assert!(is_terminal_focused());
+        assert!(runtime.render_requested());
+    }
+
     #[test]
     fn test_event_loop_render_rx_requests_render() {
         let runtime = AppRuntime::new(false);
@@ -358,4 +505,71 @@ mod tests {
 
         assert!(runtime.render_requested());
     }
+
+    #[test]
+    fn test_event_loop_ctrl_c_signal_callback() {
+        use std::ops::ControlFlow;
+
+        let ctrl_c = || Event::Key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+
+        // Default: Ctrl+C exits
+        let should_exit = Arc::new(AtomicBool::new(false));
+        let mut event_loop = create_event_loop(AppRuntime::new(false), should_exit.clone());
+        event_loop.handle_event(ctrl_c());
+        assert!(should_exit.load(Ordering::SeqCst));
+
+        // A Continue callback keeps the app running
+        let should_exit = Arc::new(AtomicBool::new(false));
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let calls_clone = calls.clone();
+        let mut callbacks = SignalCallbacks::default();
+        callbacks.register(
+            OsSignal::Interrupt,
+            Box::new(move || {
+                calls_clone.fetch_add(1, Ordering::SeqCst);
+                ControlFlow::Continue(())
+            }),
+        );
+        let mut event_loop = create_event_loop(AppRuntime::new(false), should_exit.clone())
+            .with_signal_callbacks(callbacks);
+        event_loop.handle_event(ctrl_c());
+        assert!(!should_exit.load(Ordering::SeqCst));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // A Break callback exits
+        let should_exit = Arc::new(AtomicBool::new(false));
+        let mut callbacks = SignalCallbacks::default();
+        callbacks.register(OsSignal::Interrupt, Box::new(|| ControlFlow::Break(())));
+        let mut event_loop = create_event_loop(AppRuntime::new(false), should_exit.clone())
+            .with_signal_callbacks(callbacks);
+        event_loop.handle_event(ctrl_c());
+        assert!(should_exit.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_event_loop_resize_runs_window_change_callback() {
+        use std::ops::ControlFlow;
+
+        let runtime = AppRuntime::new(false);
+        runtime.clear_render_request();
+        let should_exit = Arc::new(AtomicBool::new(false));
+        let resized = Arc::new(AtomicBool::new(false));
+        let resized_clone = resized.clone();
+        let mut callbacks = SignalCallbacks::default();
+        callbacks.register(
+            OsSignal::WindowChange,
+            Box::new(move || {
+                resized_clone.store(true, Ordering::SeqCst);
+                ControlFlow::Continue(())
+            }),
+        );
+        let mut event_loop = create_event_loop(runtime.clone(), should_exit.clone())
+            .with_signal_callbacks(callbacks);
+
+        event_loop.handle_event(Event::Resize(100, 40));
+
+        assert!(resized.load(Ordering::SeqCst));
+        assert!(runtime.render_requested());
+        assert!(!should_exit.load(Ordering::SeqCst));
+    }
 }
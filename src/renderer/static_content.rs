@@ -86,7 +86,7 @@ impl StaticRenderer {
         new_lines: &[String],
         terminal: &mut Terminal,
     ) -> std::io::Result<()> {
-        use std::io::{Write, stdout};
+        use std::io::Write;
 
         // Skip if no lines to commit
         if new_lines.is_empty() {
@@ -96,10 +96,15 @@ impl StaticRenderer {
         // Clear current dynamic UI first (like Ink's log.clear())
         terminal.clear()?;
 
-        let mut stdout = stdout();
+        let mut stdout = terminal.writer();
+        let erase = if terminal.has_terminal_control() {
+            "\x1b[K"
+        } else {
+            ""
+        };
         for line in new_lines {
             // Write the line with erase-to-end-of-line to ensure clean output
-            writeln!(stdout, "{}\x1b[K", line)?;
+            writeln!(stdout, "{}{}", line, erase)?;
             self.committed_lines.push(line.clone());
         }
         stdout.flush()?;
@@ -184,6 +189,36 @@ mod tests {
         assert!(!lines.is_empty());
     }
 
+    #[test]
+    fn test_static_state_lines_printed_once_across_frames() {
+        use crate::components::StaticState;
+
+        let renderer = StaticRenderer::new();
+        let log = StaticState::new();
+        let frame = |log: &StaticState| {
+            Box::new()
+                .child(log.view())
+                .child(Text::new("live progress").into_element())
+                .into_element()
+        };
+
+        log.push(Text::new("compiled a"));
+        log.push(Text::new("compiled b"));
+        let lines = renderer.extract_static_content(&frame(&log), 40);
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].contains("compiled a"));
+        assert!(lines[1].contains("compiled b"));
+        // Done by `use_static_state` once the frame's effects run
+        log.commit();
+
+        assert!(renderer.extract_static_content(&frame(&log), 40).is_empty());
+
+        log.push(Text::new("compiled c"));
+        let lines = renderer.extract_static_content(&frame(&log), 40);
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].contains("compiled c"));
+    }
+
     #[test]
     fn test_filter_nested_static() {
         let renderer = StaticRenderer::new();
@@ -0,0 +1,284 @@
+//! Runtime side of [`Style::transition`](crate::core::Style::transition).
+//!
+//! Each frame, the tracker walks the element tree before layout and, for
+//! every transitioned property, compares the requested value with the one
+//! seen last frame. A change starts a tween from the value currently on
+//! screen, and the element's style is overwritten with the tweened value
+//! until it settles. While any tween is running the frame rate controller
+//! keeps rendering at its target FPS.
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use crate::animation::prefers_reduced_motion;
+use crate::core::{Color, Dimension, Edges, Element, Style, StyleTransition, TransitionProperty};
+use crate::renderer::frame_rate::AnimationActivity;
+
+/// A snapshot of one transitionable property
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Value {
+    Color(Option<Color>),
+    Size(Dimension),
+    Edges(Edges),
+}
+
+impl TransitionProperty {
+    fn read(self, style: &Style) -> Value {
+        match self {
+            TransitionProperty::Color => Value::Color(style.color),
+            TransitionProperty::BackgroundColor => Value::Color(style.background_color),
+            TransitionProperty::BorderColor => Value::Color(style.border_color),
+            TransitionProperty::Width => Value::Size(style.width),
+            TransitionProperty::Height => Value::Size(style.height),
+            TransitionProperty::Padding => Value::Edges(style.padding),
+        }
+    }
+
+    fn write(self, style: &mut Style, value: Value) {
+        match (self, value) {
+            (TransitionProperty::Color, Value::Color(color)) => style.color = color,
+            (TransitionProperty::BackgroundColor, Value::Color(color)) => {
+                style.background_color = color
+            }
+            (TransitionProperty::BorderColor, Value::Color(color)) => style.border_color = color,
+            (TransitionProperty::Width, Value::Size(size)) => style.width = size,
+            (TransitionProperty::Height, Value::Size(size)) => style.height = size,
+            (TransitionProperty::Padding, Value::Edges(edges)) => style.padding = edges,
+            _ => {}
+        }
+    }
+}
+
+/// Value between `from` and `to` at eased progress `t`, if the pair can be
+/// interpolated at all
+fn interpolate(from: Value, to: Value, t: f32) -> Option<Value> {
+    let lerp = |a: f32, b: f32| a + (b - a) * t;
+    match (from, to) {
+        (Value::Color(Some(a)), Value::Color(Some(b)))
+            if a != Color::Reset && b != Color::Reset =>
+        {
+            Some(Value::Color(Some(a.blend(&b, t))))
+        }
+        (Value::Size(Dimension::Points(a)), Value::Size(Dimension::Points(b))) => {
+            Some(Value::Size(Dimension::Points(lerp(a, b).round())))
+        }
+        (Value::Edges(a), Value::Edges(b)) => Some(Value::Edges(Edges {
+            top: lerp(a.top, b.top).round(),
+            right: lerp(a.right, b.right).round(),
+            bottom: lerp(a.bottom, b.bottom).round(),
+            left: lerp(a.left, b.left).round(),
+        })),
+        _ => None,
+    }
+}
+
+/// One property moving from `from` to `to`
+#[derive(Debug, Clone, Copy)]
+struct Tween {
+    from: Value,
+    to: Value,
+    start: Instant,
+    transition: StyleTransition,
+}
+
+impl Tween {
+    fn progress(&self, now: Instant) -> f32 {
+        if self.transition.duration.is_zero() {
+            return 1.0;
+        }
+        let elapsed = now.saturating_duration_since(self.start);
+        (elapsed.as_secs_f32() / self.transition.duration.as_secs_f32()).min(1.0)
+    }
+
+    fn is_running(&self, now: Instant) -> bool {
+        self.from != self.to && self.progress(now) < 1.0
+    }
+
+    fn value_at(&self, now: Instant) -> Value {
+        let t = self.progress(now);
+        if t >= 1.0 {
+            return self.to;
+        }
+        interpolate(self.from, self.to, self.transition.easing.apply(t)).unwrap_or(self.to)
+    }
+}
+
+/// Tracks transitioned style values across frames
+#[derive(Default)]
+pub(crate) struct StyleTransitions {
+    tweens: HashMap<(String, TransitionProperty), Tween>,
+    activity: AnimationActivity,
+}
+
+impl StyleTransitions {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace transitioned values in `root` with their value at `now`
+    ///
+    /// Elements that are no longer in the tree are forgotten.
+    pub(crate) fn apply(&mut self, root: &mut Element, now: Instant) {
+        let mut previous = std::mem::take(&mut self.tweens);
+        let reduced_motion = prefers_reduced_motion();
+        let mut running = false;
+        self.visit(
+            root,
+            "root",
+            &mut previous,
+            now,
+            reduced_motion,
+            &mut running,
+        );
+        self.activity.set(running);
+    }
+
+    /// Whether any property is still between its old and new value
+    #[cfg(test)]
+    fn is_running(&self, now: Instant) -> bool {
+        self.tweens.values().any(|tween| tween.is_running(now))
+    }
+
+    fn visit(
+        &mut self,
+        element: &mut Element,
+        path: &str,
+        previous: &mut HashMap<(String, TransitionProperty), Tween>,
+        now: Instant,
+        reduced_motion: bool,
+        running: &mut bool,
+    ) {
+        for transition in element.style.transitions.clone() {
+            let property = transition.property;
+            let target = property.read(&element.style);
+            let key = (path.to_string(), property);
+            let tween = match previous.remove(&key) {
+                Some(tween) if tween.to == target && !reduced_motion => tween,
+                Some(tween) if !reduced_motion => Tween {
+                    from: tween.value_at(now),
+                    to: target,
+                    start: now,
+                    transition,
+                },
+                _ => Tween {
+                    from: target,
+                    to: target,
+                    start: now,
+                    transition,
+                },
+            };
+
+            property.write(&mut element.style, tween.value_at(now));
+            *running |= tween.is_running(now);
+            self.tweens.insert(key, tween);
+        }
+
+        for (index, child) in element.children.iter_mut().enumerate() {
+            let child_path = match &child.key {
+                Some(key) => format!("{path}#{key}"),
+                None => format!("{path}/{index}"),
+            };
+            self.visit(child, &child_path, previous, now, reduced_motion, running);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::Easing;
+    use crate::components::Box;
+    use std::time::Duration;
+
+    fn panel(background: Color, width: u16) -> Element {
+        Box::new()
+            .child(
+                Box::new()
+                    .width(width)
+                    .background(background)
+                    .transition(
+                        TransitionProperty::BackgroundColor,
+                        Duration::from_millis(100),
+                        Easing::Linear,
+                    )
+                    .transition(
+                        TransitionProperty::Width,
+                        Duration::from_millis(100),
+                        Easing::Linear,
+                    )
+                    .into_element(),
+            )
+            .into_element()
+    }
+
+    fn inner_style(root: &Element) -> &Style {
+        &root.children.iter().next().unwrap().style
+    }
+
+    #[test]
+    fn test_changed_values_interpolate_then_settle() {
+        let mut transitions = StyleTransitions::new();
+        let start = Instant::now();
+
+        let mut first = panel(Color::Rgb(0, 0, 0), 10);
+        transitions.apply(&mut first, start);
+        assert_eq!(inner_style(&first).width, Dimension::Points(10.0));
+        assert!(!transitions.is_running(start));
+
+        let mut changed = panel(Color::Rgb(200, 100, 0), 20);
+        transitions.apply(&mut changed, start);
+        assert_eq!(
+            inner_style(&changed).background_color,
+            Some(Color::Rgb(0, 0, 0))
+        );
+        assert!(transitions.is_running(start));
+
+        let halfway = start + Duration::from_millis(50);
+        let mut frame = panel(Color::Rgb(200, 100, 0), 20);
+        transitions.apply(&mut frame, halfway);
+        assert_eq!(inner_style(&frame).width, Dimension::Points(15.0));
+        assert_eq!(
+            inner_style(&frame).background_color,
+            Some(Color::Rgb(100, 50, 0))
+        );
+
+        let done = start + Duration::from_millis(100);
+        let mut frame = panel(Color::Rgb(200, 100, 0), 20);
+        transitions.apply(&mut frame, done);
+        assert_eq!(inner_style(&frame).width, Dimension::Points(20.0));
+        assert_eq!(
+            inner_style(&frame).background_color,
+            Some(Color::Rgb(200, 100, 0))
+        );
+        assert!(!transitions.is_running(done));
+    }
+
+    #[test]
+    fn test_retarget_starts_from_current_value() {
+        let mut transitions = StyleTransitions::new();
+        let start = Instant::now();
+        transitions.apply(&mut panel(Color::Black, 0), start);
+        transitions.apply(&mut panel(Color::Black, 20), start);
+
+        // Halfway to 20, head back to 0: the new tween starts at 10
+        let halfway = start + Duration::from_millis(50);
+        let mut frame = panel(Color::Black, 0);
+        transitions.apply(&mut frame, halfway);
+        assert_eq!(inner_style(&frame).width, Dimension::Points(10.0));
+
+        let mut frame = panel(Color::Black, 0);
+        transitions.apply(&mut frame, halfway + Duration::from_millis(50));
+        assert_eq!(inner_style(&frame).width, Dimension::Points(5.0));
+    }
+
+    #[test]
+    fn test_values_that_cannot_interpolate_snap() {
+        let from = Value::Size(Dimension::Auto);
+        let to = Value::Size(Dimension::Points(4.0));
+        assert_eq!(interpolate(from, to, 0.5), None);
+        assert_eq!(
+            interpolate(Value::Color(None), Value::Color(Some(Color::Red)), 0.5),
+            None
+        );
+    }
+}
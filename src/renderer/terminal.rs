@@ -13,13 +13,20 @@
 
 use crossterm::{
     cursor::{Hide, MoveTo, Show},
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
+    event::{
+        self, DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture,
+        Event, KeyCode, KeyModifiers, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags,
+        PushKeyboardEnhancementFlags,
+    },
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
-use std::io::{Write, stdout};
+use std::io::{IsTerminal, Write, stdout};
+use std::sync::{Arc, Mutex, PoisonError};
 use std::time::Duration;
 
+use super::Output;
+
 /// ANSI escape codes for terminal control
 mod ansi {
     /// Move cursor to specific position (1-indexed)
@@ -80,6 +87,43 @@ mod ansi {
     pub fn leave_alt_screen() -> &'static str {
         "\x1b[?1049l"
     }
+
+    /// Request the background color (OSC 11)
+    pub fn request_background_color() -> &'static str {
+        "\x1b]11;?\x07"
+    }
+}
+
+/// Destination for everything a [`Terminal`] writes
+#[derive(Clone, Default)]
+pub(crate) enum Sink {
+    /// The process's standard output
+    #[default]
+    Stdout,
+    /// A caller-supplied writer
+    Writer(Arc<Mutex<dyn Write + Send>>),
+}
+
+impl Write for Sink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Sink::Stdout => stdout().write(buf),
+            Sink::Writer(writer) => writer
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Sink::Stdout => stdout().flush(),
+            Sink::Writer(writer) => writer
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .flush(),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -128,11 +172,15 @@ fn fullscreen_line_diff<'a>(
 /// 2. **Line-level diff**: Only update lines that have changed
 ///
 /// This significantly reduces terminal I/O and improves perceived performance.
+/// In fullscreen mode, [`render_cells`](Self::render_cells) goes further and
+/// writes only the cells that changed.
 pub struct Terminal {
     /// Previous frame's lines for incremental rendering (line-level diff)
     previous_lines: Vec<String>,
     /// Last complete output string (for fast-path identical frame detection)
     last_output: String,
+    /// Previous frame buffer for cell-level diffing (`render_cells`)
+    previous_frame: Option<Output>,
     /// Whether we're in alternate screen mode
     alternate_screen: bool,
     /// Whether cursor is hidden
@@ -141,8 +189,16 @@ pub struct Terminal {
     raw_mode: bool,
     /// Whether mouse mode is enabled
     mouse_enabled: bool,
+    /// Whether focus in/out reporting is enabled
+    focus_reporting: bool,
+    /// Whether keyboard enhancement flags have been pushed
+    keyboard_enhancement: bool,
     /// Number of lines rendered in inline mode (for cursor positioning)
     inline_lines_rendered: usize,
+    /// Where frames and control sequences are written
+    sink: Sink,
+    /// Whether raw mode, cursor movement and screen control are used
+    control: bool,
 }
 
 impl Terminal {
@@ -151,14 +207,50 @@ impl Terminal {
         Self {
             previous_lines: Vec::new(),
             last_output: String::new(),
+            previous_frame: None,
             alternate_screen: false,
             cursor_hidden: false,
             raw_mode: false,
             mouse_enabled: false,
+            focus_reporting: false,
+            keyboard_enhancement: false,
             inline_lines_rendered: 0,
+            sink: Sink::Stdout,
+            control: true,
         }
     }
 
+    /// Create a terminal that writes to `writer` instead of stdout
+    ///
+    /// Terminal control stays enabled; turn it off with
+    /// [`set_terminal_control`](Self::set_terminal_control) when the writer
+    /// is not a terminal.
+    pub fn with_output(writer: impl Write + Send + 'static) -> Self {
+        let mut terminal = Self::new();
+        terminal.sink = Sink::Writer(Arc::new(Mutex::new(writer)));
+        terminal
+    }
+
+    /// Enable or disable terminal control (default: enabled)
+    ///
+    /// Without control the terminal never enters raw mode or the alternate
+    /// screen and writes no cursor, mouse or focus sequences. Each changed
+    /// frame is written in full, followed by a newline, which suits pipes,
+    /// log files and sockets.
+    pub fn set_terminal_control(&mut self, enabled: bool) {
+        self.control = enabled;
+    }
+
+    /// Check whether terminal control is enabled
+    pub fn has_terminal_control(&self) -> bool {
+        self.control
+    }
+
+    /// Writer for this terminal's output
+    pub(crate) fn writer(&self) -> Sink {
+        self.sink.clone()
+    }
+
     /// Check if currently in alternate screen mode
     pub fn is_alt_screen(&self) -> bool {
         self.alternate_screen
@@ -166,11 +258,16 @@ impl Terminal {
 
     /// Enter raw mode and alternate screen (fullscreen mode)
     pub fn enter(&mut self) -> std::io::Result<()> {
+        self.alternate_screen = true;
+        if !self.control {
+            return Ok(());
+        }
         enable_raw_mode()?;
         self.raw_mode = true;
-        execute!(stdout(), EnterAlternateScreen, Hide)?;
-        self.alternate_screen = true;
+        execute!(self.writer(), EnterAlternateScreen, Hide)?;
         self.cursor_hidden = true;
+        self.enable_focus_reporting()?;
+        self.enable_keyboard_enhancement()?;
         Ok(())
     }
 
@@ -178,13 +275,18 @@ impl Terminal {
     pub fn exit(&mut self) -> std::io::Result<()> {
         // Disable mouse capture first
         if self.mouse_enabled {
-            execute!(stdout(), DisableMouseCapture)?;
+            execute!(self.writer(), DisableMouseCapture)?;
             self.mouse_enabled = false;
         }
+        self.disable_focus_reporting()?;
+        self.disable_keyboard_enhancement()?;
         if self.alternate_screen {
-            execute!(stdout(), Show, LeaveAlternateScreen)?;
+            if self.control {
+                execute!(self.writer(), Show, LeaveAlternateScreen)?;
+            }
             self.alternate_screen = false;
             self.cursor_hidden = false;
+            self.previous_frame = None;
         }
         if self.raw_mode {
             disable_raw_mode()?;
@@ -195,28 +297,46 @@ impl Terminal {
 
     /// Enter inline mode (renders in current terminal position)
     pub fn enter_inline(&mut self) -> std::io::Result<()> {
+        self.inline_lines_rendered = 0;
+        if !self.control {
+            return Ok(());
+        }
         enable_raw_mode()?;
         self.raw_mode = true;
 
+        // Start on a fresh line if the prompt left the cursor mid-row;
+        // terminals that never answer the query are assumed to be at column 0
+        let column = self
+            .query_cursor_position()
+            .map(|(col, _)| col)
+            .unwrap_or(0);
+        let mut stdout = self.writer();
+        if column > 0 {
+            write!(stdout, "\r\n")?;
+        }
+
         // Hide cursor during rendering
-        let mut stdout = stdout();
         write!(stdout, "{}", ansi::hide_cursor())?;
         stdout.flush()?;
         self.cursor_hidden = true;
         self.inline_lines_rendered = 0;
+        self.enable_focus_reporting()?;
+        self.enable_keyboard_enhancement()?;
 
         Ok(())
     }
 
     /// Exit inline mode
     pub fn exit_inline(&mut self) -> std::io::Result<()> {
-        let mut stdout = stdout();
+        let mut stdout = self.writer();
 
         // Disable mouse capture first
         if self.mouse_enabled {
             execute!(stdout, DisableMouseCapture)?;
             self.mouse_enabled = false;
         }
+        self.disable_focus_reporting()?;
+        self.disable_keyboard_enhancement()?;
 
         // Show cursor
         if self.cursor_hidden {
@@ -226,7 +346,7 @@ impl Terminal {
 
         // Move to the end of output and add newline
         let line_count = self.previous_lines.len();
-        if line_count > 0 {
+        if self.control && line_count > 0 {
             // We're at the last line, just add a newline
             writeln!(stdout)?;
         }
@@ -249,8 +369,13 @@ impl Terminal {
         if self.alternate_screen {
             return Ok(());
         }
+        if !self.control {
+            self.alternate_screen = true;
+            self.repaint();
+            return Ok(());
+        }
 
-        let mut stdout = stdout();
+        let mut stdout = self.writer();
 
         // First, clear any inline content we've rendered
         self.clear_inline_content()?;
@@ -269,6 +394,7 @@ impl Terminal {
 
         self.alternate_screen = true;
         self.previous_lines.clear();
+        self.previous_frame = None;
         self.inline_lines_rendered = 0;
 
         Ok(())
@@ -282,8 +408,13 @@ impl Terminal {
         if !self.alternate_screen {
             return Ok(());
         }
+        if !self.control {
+            self.alternate_screen = false;
+            self.repaint();
+            return Ok(());
+        }
 
-        let mut stdout = stdout();
+        let mut stdout = self.writer();
 
         // Leave alternate screen using raw ANSI
         write!(stdout, "{}", ansi::leave_alt_screen())?;
@@ -298,6 +429,7 @@ impl Terminal {
 
         self.alternate_screen = false;
         self.previous_lines.clear();
+        self.previous_frame = None;
         self.inline_lines_rendered = 0;
 
         // Re-hide cursor for rendering
@@ -313,8 +445,13 @@ impl Terminal {
         if self.previous_lines.is_empty() {
             return Ok(());
         }
+        if !self.control {
+            self.previous_lines.clear();
+            self.inline_lines_rendered = 0;
+            return Ok(());
+        }
 
-        let mut stdout = stdout();
+        let mut stdout = self.writer();
         let line_count = self.previous_lines.len();
 
         // Move up to the start of our content
@@ -357,14 +494,18 @@ impl Terminal {
             return Ok(());
         }
 
-        let mut stdout = stdout();
+        let mut stdout = self.writer();
 
         // Clear current UI content
         self.clear_inline_content()?;
 
         // Write the message with proper line endings
         for line in message.lines() {
-            write!(stdout, "{}{}\r\n", line, ansi::erase_end_of_line())?;
+            if self.control {
+                write!(stdout, "{}{}\r\n", line, ansi::erase_end_of_line())?;
+            } else {
+                writeln!(stdout, "{}", line)?;
+            }
         }
 
         stdout.flush()?;
@@ -381,13 +522,17 @@ impl Terminal {
     /// 1. Fast path: Skip entirely if output is identical to last frame
     /// 2. Line-level diff: Only update changed lines
     pub fn render(&mut self, output: &str) -> std::io::Result<()> {
+        self.previous_frame = None;
+
         // Fast path: if output is identical to last frame, skip rendering entirely
         // This is a key optimization from Bubbletea that significantly reduces I/O
         if output == self.last_output && !self.previous_lines.is_empty() {
             return Ok(());
         }
 
-        let result = if self.alternate_screen {
+        let result = if !self.control {
+            self.render_plain(output)
+        } else if self.alternate_screen {
             self.render_fullscreen(output)
         } else {
             self.render_inline(output)
@@ -401,9 +546,55 @@ impl Terminal {
         result
     }
 
+    /// Render a frame buffer, writing only the cells that changed
+    ///
+    /// Cell-level diffing needs absolute cursor positioning, so it only
+    /// applies in fullscreen mode. Inline mode, and the first frame after a
+    /// repaint or clear, fall back to [`render`](Self::render).
+    pub fn render_cells(&mut self, output: Output) -> std::io::Result<()> {
+        if !self.control {
+            return self.render(&output.render());
+        }
+        let previous = match self.previous_frame.take() {
+            Some(previous) if self.alternate_screen => previous,
+            _ => {
+                self.render(&output.render())?;
+                if self.alternate_screen {
+                    self.previous_frame = Some(output);
+                }
+                return Ok(());
+            }
+        };
+
+        let diff = output.diff(&previous);
+        if !diff.is_empty() {
+            let mut stdout = self.writer();
+            stdout.write_all(diff.as_bytes())?;
+            stdout.flush()?;
+        }
+
+        // The line-diff state no longer describes the screen
+        self.previous_lines.clear();
+        self.last_output.clear();
+        self.previous_frame = Some(output);
+
+        Ok(())
+    }
+
+    /// Render without terminal control: each frame in full, then a newline
+    fn render_plain(&mut self, output: &str) -> std::io::Result<()> {
+        let mut stdout = self.writer();
+        let frame = output.replace("\r\n", "\n");
+        writeln!(stdout, "{}", frame)?;
+        stdout.flush()?;
+
+        self.previous_lines = frame.lines().map(str::to_string).collect();
+        Ok(())
+    }
+
     /// Render in fullscreen/alternate screen mode
     fn render_fullscreen(&mut self, output: &str) -> std::io::Result<()> {
-        let mut stdout = stdout();
+        let mut stdout = self.writer();
 
         // Move to top-left
         execute!(stdout, MoveTo(0, 0))?;
@@ -452,7 +643,7 @@ impl Terminal {
     /// which is used for diff optimization. After repaint(), previous_lines
     /// is cleared but inline_lines_rendered still reflects screen state.
     fn render_inline(&mut self, output: &str) -> std::io::Result<()> {
-        let mut stdout = stdout();
+        let mut stdout = self.writer();
         let new_lines: Vec<&str> = output.lines().collect();
         let new_count = new_lines.len();
 
@@ -515,12 +706,22 @@ impl Terminal {
 
     /// Clear the current output
     pub fn clear(&mut self) -> std::io::Result<()> {
-        if self.previous_lines.is_empty() {
+        let frame_height = self
+            .previous_frame
+            .take()
+            .map_or(0, |frame| frame.height as usize);
+        let line_count = self.previous_lines.len().max(frame_height);
+        if line_count == 0 {
+            return Ok(());
+        }
+        if !self.control {
+            self.previous_lines.clear();
+            self.last_output.clear();
+            self.inline_lines_rendered = 0;
             return Ok(());
         }
 
-        let mut stdout = stdout();
-        let line_count = self.previous_lines.len();
+        let mut stdout = self.writer();
 
         if self.alternate_screen {
             execute!(stdout, MoveTo(0, 0))?;
@@ -559,6 +760,7 @@ impl Terminal {
     /// Force a full repaint on next render
     pub fn repaint(&mut self) {
         self.previous_lines.clear();
+        self.previous_frame = None;
         self.last_output.clear();
     }
 
@@ -567,6 +769,50 @@ impl Terminal {
         crossterm::terminal::size()
     }
 
+    /// Query the cursor position as zero-based `(column, row)`
+    ///
+    /// Sends a Device Status Report (`ESC [ 6 n`) through this terminal's
+    /// output and waits at most 150 ms for the `ESC [ row ; col R` reply,
+    /// returning `TimedOut` from terminals that never answer. Input typed
+    /// while waiting is discarded. Returns `Unsupported` when stdin is not a
+    /// terminal or terminal control is off.
+    pub fn query_cursor_position(&self) -> std::io::Result<(u16, u16)> {
+        if !self.control || !std::io::stdin().is_terminal() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "stdin is not a terminal",
+            ));
+        }
+
+        #[cfg(unix)]
+        {
+            let mut output = self.writer();
+            write!(output, "\x1b[6n")?;
+            output.flush()?;
+            read_cursor_position_reply(CURSOR_QUERY_TIMEOUT)
+        }
+        // The Windows console answers from its own API without a round trip
+        #[cfg(not(unix))]
+        {
+            crossterm::cursor::position()
+        }
+    }
+
+    /// Ask the terminal for its background color (OSC 11)
+    ///
+    /// Only sends the query; the reply arrives later as input and is picked
+    /// up by the event loop. Does nothing without terminal control or when
+    /// stdio is not a terminal.
+    pub fn request_background_color(&mut self) -> std::io::Result<bool> {
+        if !self.control || !std::io::stdin().is_terminal() || !stdout().is_terminal() {
+            return Ok(false);
+        }
+        let mut stdout = self.writer();
+        write!(stdout, "{}", ansi::request_background_color())?;
+        stdout.flush()?;
+        Ok(true)
+    }
+
     /// Poll for input event
     pub fn poll_event(timeout: Duration) -> std::io::Result<Option<Event>> {
         if event::poll(timeout)? {
@@ -595,8 +841,8 @@ impl Terminal {
 
     /// Enable mouse capture
     pub fn enable_mouse(&mut self) -> std::io::Result<()> {
-        if !self.mouse_enabled {
-            execute!(stdout(), EnableMouseCapture)?;
+        if !self.mouse_enabled && self.control {
+            execute!(self.writer(), EnableMouseCapture)?;
             self.mouse_enabled = true;
         }
         Ok(())
@@ -605,12 +851,79 @@ impl Terminal {
     /// Disable mouse capture
     pub fn disable_mouse(&mut self) -> std::io::Result<()> {
         if self.mouse_enabled {
-            execute!(stdout(), DisableMouseCapture)?;
+            execute!(self.writer(), DisableMouseCapture)?;
             self.mouse_enabled = false;
         }
         Ok(())
     }
 
+    /// Enable focus in/out reporting when the terminal supports it
+    ///
+    /// Focus changes arrive as `Event::FocusGained` / `Event::FocusLost` and
+    /// drive [`use_terminal_focus`](crate::hooks::use_terminal_focus).
+    pub fn enable_focus_reporting(&mut self) -> std::io::Result<()> {
+        if !self.focus_reporting && self.control && crate::runtime::supports_focus_reporting() {
+            execute!(self.writer(), EnableFocusChange)?;
+            self.focus_reporting = true;
+        }
+        Ok(())
+    }
+
+    /// Disable focus in/out reporting
+    ///
+    /// The terminal is considered focused again once reporting stops.
+    pub fn disable_focus_reporting(&mut self) -> std::io::Result<()> {
+        if self.focus_reporting {
+            execute!(self.writer(), DisableFocusChange)?;
+            self.focus_reporting = false;
+            crate::hooks::set_terminal_focused(true);
+        }
+        Ok(())
+    }
+
+    /// Check if focus reporting is enabled
+    pub fn is_focus_reporting_enabled(&self) -> bool {
+        self.focus_reporting
+    }
+
+    /// Push keyboard enhancement flags when the terminal supports them
+    ///
+    /// Terminals speaking the kitty keyboard protocol then report key
+    /// repeats and lock state, filling in [`Key::repeat`] and
+    /// [`Key::caps_lock`](crate::hooks::Key::caps_lock). Support is
+    /// detected with a query to the terminal, so this only happens when
+    /// stdin is a terminal.
+    ///
+    /// [`Key::repeat`]: crate::hooks::Key::repeat
+    pub fn enable_keyboard_enhancement(&mut self) -> std::io::Result<()> {
+        if !self.keyboard_enhancement
+            && self.control
+            && std::io::stdin().is_terminal()
+            && crossterm::terminal::supports_keyboard_enhancement().unwrap_or(false)
+        {
+            execute!(
+                self.writer(),
+                PushKeyboardEnhancementFlags(keyboard_enhancement_flags())
+            )?;
+            self.keyboard_enhancement = true;
+        }
+        Ok(())
+    }
+
+    /// Pop the keyboard enhancement flags pushed on enter
+    pub fn disable_keyboard_enhancement(&mut self) -> std::io::Result<()> {
+        if self.keyboard_enhancement {
+            execute!(self.writer(), PopKeyboardEnhancementFlags)?;
+            self.keyboard_enhancement = false;
+        }
+        Ok(())
+    }
+
+    /// Check if keyboard enhancement flags are pushed
+    pub fn is_keyboard_enhancement_enabled(&self) -> bool {
+        self.keyboard_enhancement
+    }
+
     /// Check if mouse is enabled
     pub fn is_mouse_enabled(&self) -> bool {
         self.mouse_enabled
@@ -623,13 +936,22 @@ impl Terminal {
     ///
     /// Call `resume()` after the external process exits to restore TUI state.
     pub fn suspend(&mut self) -> std::io::Result<()> {
-        let mut stdout = stdout();
+        if !self.control {
+            return Ok(());
+        }
+        let mut stdout = self.writer();
 
         // Disable mouse capture
         if self.mouse_enabled {
             execute!(stdout, DisableMouseCapture)?;
             // Note: we keep mouse_enabled = true so resume() knows to re-enable it
         }
+        if self.focus_reporting {
+            execute!(stdout, DisableFocusChange)?;
+        }
+        if self.keyboard_enhancement {
+            execute!(stdout, PopKeyboardEnhancementFlags)?;
+        }
 
         // Show cursor
         if self.cursor_hidden {
@@ -655,7 +977,11 @@ impl Terminal {
     /// This restores the TUI state after an external process has finished.
     /// Should be called after `suspend()` and the external process has exited.
     pub fn resume(&mut self) -> std::io::Result<()> {
-        let mut stdout = stdout();
+        if !self.control {
+            self.repaint();
+            return Ok(());
+        }
+        let mut stdout = self.writer();
 
         // Re-enable raw mode
         if self.raw_mode {
@@ -678,6 +1004,15 @@ impl Terminal {
         if self.mouse_enabled {
             execute!(stdout, EnableMouseCapture)?;
         }
+        if self.focus_reporting {
+            execute!(stdout, EnableFocusChange)?;
+        }
+        if self.keyboard_enhancement {
+            execute!(
+                stdout,
+                PushKeyboardEnhancementFlags(keyboard_enhancement_flags())
+            )?;
+        }
 
         stdout.flush()?;
 
@@ -688,6 +1023,72 @@ impl Terminal {
     }
 }
 
+/// How long [`Terminal::query_cursor_position`] waits for a reply
+const CURSOR_QUERY_TIMEOUT: Duration = Duration::from_millis(150);
+
+/// Read stdin until a cursor position reply arrives or `timeout` passes
+#[cfg(unix)]
+fn read_cursor_position_reply(timeout: Duration) -> std::io::Result<(u16, u16)> {
+    use std::os::fd::AsRawFd;
+
+    let fd = std::io::stdin().as_raw_fd();
+    let deadline = std::time::Instant::now() + timeout;
+    let mut reply = Vec::new();
+    loop {
+        let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+        let mut pollfd = libc::pollfd {
+            fd,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        // SAFETY: `pollfd` is a valid, initialized struct for the call
+        let ready = unsafe { libc::poll(&mut pollfd, 1, remaining.as_millis() as libc::c_int) };
+        if ready < 0 {
+            let err = std::io::Error::last_os_error();
+            if err.kind() == std::io::ErrorKind::Interrupted {
+                continue;
+            }
+            return Err(err);
+        }
+        if ready == 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                "terminal did not report the cursor position",
+            ));
+        }
+
+        let mut byte = 0u8;
+        // SAFETY: reads at most one byte into `byte`
+        let read = unsafe { libc::read(fd, (&mut byte as *mut u8).cast(), 1) };
+        if read <= 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        reply.push(byte);
+        if byte == b'R' {
+            if let Some(position) = parse_cursor_position_reply(&reply) {
+                return Ok(position);
+            }
+            reply.clear();
+        }
+    }
+}
+
+/// Parse the last `ESC [ row ; col R` in `bytes` as zero-based `(column, row)`
+fn parse_cursor_position_reply(bytes: &[u8]) -> Option<(u16, u16)> {
+    let start = bytes.windows(2).rposition(|pair| pair == b"\x1b[")?;
+    let body = std::str::from_utf8(&bytes[start + 2..]).ok()?;
+    let (row, col) = body.strip_suffix('R')?.split_once(';')?;
+    let row: u16 = row.parse().ok()?;
+    let col: u16 = col.parse().ok()?;
+    Some((col.saturating_sub(1), row.saturating_sub(1)))
+}
+
+/// Flags pushed by [`Terminal::enable_keyboard_enhancement`]
+fn keyboard_enhancement_flags() -> KeyboardEnhancementFlags {
+    KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+        | KeyboardEnhancementFlags::REPORT_EVENT_TYPES
+}
+
 impl Default for Terminal {
     fn default() -> Self {
         Self::new()
@@ -724,6 +1125,16 @@ mod tests {
         assert_eq!(ansi::erase_screen(), "\x1b[2J");
         assert_eq!(ansi::enter_alt_screen(), "\x1b[?1049h");
         assert_eq!(ansi::leave_alt_screen(), "\x1b[?1049l");
+        assert_eq!(ansi::request_background_color(), "\x1b]11;?\x07");
+    }
+
+    #[test]
+    fn test_parse_cursor_position_reply() {
+        assert_eq!(parse_cursor_position_reply(b"\x1b[5;12R"), Some((11, 4)));
+        // Keys typed before the reply are skipped
+        assert_eq!(parse_cursor_position_reply(b"ab\x1b[1;1R"), Some((0, 0)));
+        assert_eq!(parse_cursor_position_reply(b"\x1b[1;R"), None);
+        assert_eq!(parse_cursor_position_reply(b"R"), None);
     }
 
     #[test]
@@ -788,4 +1199,36 @@ mod tests {
             vec![LineDiffOp::Clear { row: 1 }, LineDiffOp::Clear { row: 2 }]
         );
     }
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_plain_output_writes_whole_frames_without_control() {
+        let buffer = SharedBuffer::default();
+        let mut terminal = Terminal::with_output(buffer.clone());
+        terminal.set_terminal_control(false);
+
+        terminal.enter().unwrap();
+        assert!(terminal.is_alt_screen());
+        terminal.render("one\r\ntwo").unwrap();
+        terminal.render("one\r\ntwo").unwrap();
+        terminal.render("three").unwrap();
+        terminal.enable_mouse().unwrap();
+        assert!(!terminal.is_mouse_enabled());
+        terminal.exit().unwrap();
+
+        let written = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(written, "one\ntwo\nthree\n");
+    }
 }
@@ -64,28 +64,27 @@ impl TerminalController {
     ) -> std::io::Result<()> {
         use crate::cmd::TerminalCmd;
         use crossterm::{cursor, execute, terminal as ct};
-        use std::io::stdout;
+
+        // Escape sequences are dropped when the output is not a terminal
+        let control = terminal.has_terminal_control();
+        let mut out = terminal.writer();
 
         match cmd {
             TerminalCmd::ClearScreen => {
-                if terminal.is_alt_screen() {
-                    execute!(
-                        stdout(),
-                        ct::Clear(ct::ClearType::All),
-                        cursor::MoveTo(0, 0)
-                    )?;
+                if terminal.is_alt_screen() && control {
+                    execute!(out, ct::Clear(ct::ClearType::All), cursor::MoveTo(0, 0))?;
                 } else {
                     terminal.clear()?;
                 }
             }
-            TerminalCmd::HideCursor => {
-                execute!(stdout(), cursor::Hide)?;
+            TerminalCmd::HideCursor if control => {
+                execute!(out, cursor::Hide)?;
             }
-            TerminalCmd::ShowCursor => {
-                execute!(stdout(), cursor::Show)?;
+            TerminalCmd::ShowCursor if control => {
+                execute!(out, cursor::Show)?;
             }
-            TerminalCmd::SetWindowTitle(title) => {
-                execute!(stdout(), ct::SetTitle(&title))?;
+            TerminalCmd::SetWindowTitle(title) if control => {
+                execute!(out, ct::SetTitle(&title))?;
             }
             TerminalCmd::WindowSize => {
                 // This triggers a resize check on next render.
@@ -108,18 +107,27 @@ impl TerminalController {
             }
             TerminalCmd::EnableMouse => {
                 crate::hooks::use_mouse::set_mouse_enabled(true);
-                execute!(stdout(), crossterm::event::EnableMouseCapture)?;
+                if control {
+                    execute!(out, crossterm::event::EnableMouseCapture)?;
+                }
             }
             TerminalCmd::DisableMouse => {
                 crate::hooks::use_mouse::set_mouse_enabled(false);
-                execute!(stdout(), crossterm::event::DisableMouseCapture)?;
+                if control {
+                    execute!(out, crossterm::event::DisableMouseCapture)?;
+                }
             }
-            TerminalCmd::EnableBracketedPaste => {
-                execute!(stdout(), crossterm::event::EnableBracketedPaste)?;
+            TerminalCmd::EnableBracketedPaste if control => {
+                execute!(out, crossterm::event::EnableBracketedPaste)?;
             }
-            TerminalCmd::DisableBracketedPaste => {
-                execute!(stdout(), crossterm::event::DisableBracketedPaste)?;
+            TerminalCmd::DisableBracketedPaste if control => {
+                execute!(out, crossterm::event::DisableBracketedPaste)?;
             }
+            TerminalCmd::HideCursor
+            | TerminalCmd::ShowCursor
+            | TerminalCmd::SetWindowTitle(_)
+            | TerminalCmd::EnableBracketedPaste
+            | TerminalCmd::DisableBracketedPaste => {}
         }
         Ok(())
     }
@@ -162,7 +170,6 @@ impl TerminalController {
         use crossterm::cursor::MoveTo;
         use crossterm::execute;
         use crossterm::terminal::{Clear, ClearType};
-        use std::io::stdout;
 
         let action = resize_action(
             *last_width,
@@ -173,8 +180,8 @@ impl TerminalController {
         );
 
         if action.repaint {
-            if action.clear_screen {
-                let _ = execute!(stdout(), MoveTo(0, 0), Clear(ClearType::All));
+            if action.clear_screen && terminal.has_terminal_control() {
+                let _ = execute!(terminal.writer(), MoveTo(0, 0), Clear(ClearType::All));
             }
             // Inline mode: repaint only to avoid clearing scrollback.
             terminal.repaint();
@@ -3,9 +3,13 @@
 //! This module centralizes recursive element rendering so all call sites
 //! (runtime, render_to_string, static content, tests) use one code path.
 
-use crate::components::text::Line;
-use crate::core::{Display, Element, Overflow};
+use crate::components::text::{Line, Span};
+use crate::core::{
+    Anchor, AnchorPlacement, Display, Element, ElementId, Overflow, ShadowStyle, Style,
+    TextOverflow,
+};
 use crate::layout::LayoutEngine;
+use crate::layout::measure::{measure_text_width, truncate_text};
 use crate::renderer::Output;
 use crate::renderer::output::ClipRegion;
 
@@ -36,13 +40,84 @@ fn clamp_extent(v: f32) -> u16 {
     }
 }
 
+/// An anchored element waiting to be painted, with its in-flow offset
+type Overlay<'a> = (&'a Element, f32, f32);
+
 /// Render an element tree into the provided output buffer.
+///
+/// Elements with an [`Anchor`] are painted last, next to their target's
+/// rect, so overlays sit on top of the content they point at.
 pub(crate) fn render_element_tree(
     element: &Element,
     layout_engine: &LayoutEngine,
     output: &mut Output,
     offset_x: f32,
     offset_y: f32,
+) {
+    let mut overlays = Vec::new();
+    render_node(
+        element,
+        layout_engine,
+        output,
+        offset_x,
+        offset_y,
+        &mut overlays,
+    );
+
+    // Overlays may contain anchored elements of their own
+    let mut next = 0;
+    while let Some(&(overlay, flow_x, flow_y)) = overlays.get(next) {
+        next += 1;
+        let layout = layout_engine.get_layout(overlay.id).unwrap_or_default();
+        let target = overlay.style.anchor.and_then(|anchor| {
+            find_rect(element, anchor.target, layout_engine, offset_x, offset_y)
+                .map(|rect| (anchor, rect))
+        });
+
+        let (x, y) = match target {
+            Some((anchor, rect)) => place_overlay(
+                &anchor,
+                rect,
+                (layout.width, layout.height),
+                (output.width as f32, output.height as f32),
+            ),
+            // A missing target leaves the overlay where layout put it
+            None => (flow_x + layout.x, flow_y + layout.y),
+        };
+        paint_node(
+            overlay,
+            layout_engine,
+            output,
+            x - layout.x,
+            y - layout.y,
+            &mut overlays,
+        );
+    }
+}
+
+/// Render an element unless it is anchored, in which case defer it
+fn render_node<'a>(
+    element: &'a Element,
+    layout_engine: &LayoutEngine,
+    output: &mut Output,
+    offset_x: f32,
+    offset_y: f32,
+    overlays: &mut Vec<Overlay<'a>>,
+) {
+    if element.style.anchor.is_some() {
+        overlays.push((element, offset_x, offset_y));
+        return;
+    }
+    paint_node(element, layout_engine, output, offset_x, offset_y, overlays);
+}
+
+fn paint_node<'a>(
+    element: &'a Element,
+    layout_engine: &LayoutEngine,
+    output: &mut Output,
+    offset_x: f32,
+    offset_y: f32,
+    overlays: &mut Vec<Overlay<'a>>,
 ) {
     if element.style.display == Display::None {
         return;
@@ -57,11 +132,23 @@ pub(crate) fn render_element_tree(
     let width = clamp_extent(layout.width);
     let height = clamp_extent(layout.height);
 
-    if let (Some(x), Some(y)) = (x, y) {
-        if element.style.background_color.is_some() {
-            output.fill_rect(x, y, width, height, ' ', &element.style);
-        }
+    if let (Some(x), Some(y), Some(shadow)) = (x, y, element.style.shadow) {
+        render_shadow(output, x, y, width, height, &shadow);
+    }
 
+    let rect = (raw_x, raw_y, layout.width, layout.height);
+    if let Some(backdrop) = &element.style.backdrop {
+        shade_visible(output, rect, backdrop);
+    }
+
+    // Paint the whole rect, padding and empty space included, before any
+    // content; this also covers the visible part of a box scrolled partly
+    // off screen
+    if let Some(background) = element.style.background_color {
+        fill_background(output, rect, background);
+    }
+
+    if let (Some(x), Some(y)) = (x, y) {
         if element.style.has_border() {
             render_border(element, output, x, y, width, height);
         }
@@ -71,7 +158,24 @@ pub(crate) fn render_element_tree(
         let text_y =
             y + if element.style.has_border() { 1 } else { 0 } + element.style.padding.top as u16;
 
-        if let Some(spans) = &element.spans {
+        if element.style.text_overflow == TextOverflow::Ellipsis {
+            let inset = if element.style.has_border() { 2.0 } else { 0.0 };
+            let max_width = clamp_extent(
+                layout.width - inset - element.style.padding.left - element.style.padding.right,
+            ) as usize;
+            if let Some(spans) = &element.spans {
+                let lines: Vec<Line> = spans
+                    .iter()
+                    .map(|line| truncate_line(line, max_width))
+                    .collect();
+                render_spans(&lines, output, text_x, text_y);
+            } else if let Some(text) = &element.text_content {
+                for (i, line) in text.lines().enumerate() {
+                    let line = truncate_text(line, max_width, ELLIPSIS);
+                    output.write(text_x, text_y + i as u16, &line, &element.style);
+                }
+            }
+        } else if let Some(spans) = &element.spans {
             render_spans(spans, output, text_x, text_y);
         } else if let Some(text) = &element.text_content {
             output.write(text_x, text_y, text, &element.style);
@@ -110,12 +214,195 @@ pub(crate) fn render_element_tree(
     let child_offset_y = offset_y + layout.y - scroll_offset_y;
 
     for child in &element.children {
-        render_element_tree(child, layout_engine, output, child_offset_x, child_offset_y);
+        render_node(
+            child,
+            layout_engine,
+            output,
+            child_offset_x,
+            child_offset_y,
+            overlays,
+        );
     }
 
     if clip_pushed {
         output.unclip();
     }
+
+    if (element.style.mirror_horizontal || element.style.flip_vertical)
+        && let Some((x, y, width, height)) = visible_rect(rect)
+    {
+        if element.style.mirror_horizontal {
+            output.mirror_rect(x, y, width, height);
+        }
+        if element.style.flip_vertical {
+            output.flip_rect(x, y, width, height);
+        }
+    }
+
+    // Filter last so it sees the border and every child; anchored overlays
+    // are painted later and stay unfiltered
+    if let Some(filter) = &element.style.color_filter
+        && let Some((x, y, width, height)) = visible_rect(rect)
+    {
+        output.filter_rect(x, y, width, height, filter);
+    }
+    if let Some(url) = &element.style.hyperlink
+        && let Some((x, y, width, height)) = visible_rect(rect)
+    {
+        output.link_rect(x, y, width, height, url);
+    }
+}
+
+/// On-screen part of a rect as `(x, y, width, height)`, if any
+fn visible_rect((x, y, width, height): (f32, f32, f32, f32)) -> Option<(u16, u16, u16, u16)> {
+    let (left, top) = (x.max(0.0), y.max(0.0));
+    let (width, height) = (
+        clamp_extent(x + width - left),
+        clamp_extent(y + height - top),
+    );
+    (width > 0 && height > 0).then(|| (clamp_extent(left), clamp_extent(top), width, height))
+}
+
+/// Fill the on-screen part of a rect with a background color
+fn fill_background(
+    output: &mut Output,
+    rect: (f32, f32, f32, f32),
+    background: crate::core::Color,
+) {
+    if let Some((x, y, width, height)) = visible_rect(rect) {
+        let style = crate::core::Style::new().bg(background);
+        output.fill_rect(x, y, width, height, ' ', &style);
+    }
+}
+
+/// Shade the on-screen part of a rect, dimming what is already drawn
+fn shade_visible(
+    output: &mut Output,
+    rect: (f32, f32, f32, f32),
+    backdrop: &crate::core::Backdrop,
+) {
+    if let Some((x, y, width, height)) = visible_rect(rect) {
+        output.shade_rect(x, y, width, height, backdrop.color, backdrop.strength);
+    }
+}
+
+/// Screen rect `(x, y, width, height)` of `target` within the tree
+fn find_rect(
+    element: &Element,
+    target: ElementId,
+    layout_engine: &LayoutEngine,
+    offset_x: f32,
+    offset_y: f32,
+) -> Option<(f32, f32, f32, f32)> {
+    if element.style.display == Display::None {
+        return None;
+    }
+
+    let layout = layout_engine.get_layout(element.id).unwrap_or_default();
+    let x = offset_x + layout.x;
+    let y = offset_y + layout.y;
+    if element.id == target {
+        return Some((x, y, layout.width, layout.height));
+    }
+
+    let child_x = x - element.scroll_offset_x.unwrap_or(0) as f32;
+    let child_y = y - element.scroll_offset_y.unwrap_or(0) as f32;
+    element
+        .children
+        .iter()
+        .find_map(|child| find_rect(child, target, layout_engine, child_x, child_y))
+}
+
+/// Top-left corner for an overlay of `size` anchored to `target`
+///
+/// Flips to the opposite side when the preferred one would leave `bounds`
+/// and the other fits, then clamps the overlay on screen.
+fn place_overlay(
+    anchor: &Anchor,
+    (tx, ty, tw, th): (f32, f32, f32, f32),
+    (width, height): (f32, f32),
+    (bounds_w, bounds_h): (f32, f32),
+) -> (f32, f32) {
+    let gap = anchor.gap as f32;
+    let below = ty + th + gap;
+    let above = ty - gap - height;
+    let right = tx + tw + gap;
+    let left = tx - gap - width;
+
+    let (x, y) = match anchor.placement {
+        AnchorPlacement::Bottom if below + height > bounds_h && above >= 0.0 => (tx, above),
+        AnchorPlacement::Bottom => (tx, below),
+        AnchorPlacement::Top if above < 0.0 && below + height <= bounds_h => (tx, below),
+        AnchorPlacement::Top => (tx, above),
+        AnchorPlacement::Right if right + width > bounds_w && left >= 0.0 => (left, ty),
+        AnchorPlacement::Right => (right, ty),
+        AnchorPlacement::Left if left < 0.0 && right + width <= bounds_w => (right, ty),
+        AnchorPlacement::Left => (left, ty),
+    };
+
+    (
+        x.min(bounds_w - width).max(0.0),
+        y.min(bounds_h - height).max(0.0),
+    )
+}
+
+/// How strongly a shadow tints the cells it covers
+const SHADOW_STRENGTH: f32 = 0.6;
+/// Tint of the fringe around a blurred shadow
+const SHADOW_BLUR_STRENGTH: f32 = 0.3;
+
+/// Shade the cells under a box's drop shadow, leaving the box itself alone
+fn render_shadow(
+    output: &mut Output,
+    x: u16,
+    y: u16,
+    width: u16,
+    height: u16,
+    shadow: &ShadowStyle,
+) {
+    let (dx, dy) = shadow.offset;
+    if width == 0 || height == 0 || (dx == 0 && dy == 0) {
+        return;
+    }
+
+    let box_right = x.saturating_add(width);
+    let box_bottom = y.saturating_add(height);
+    let mut shade = |left: u16, top: u16, right: u16, bottom: u16, strength: f32| {
+        for row in top..bottom {
+            // Rows beside the box only shade past its right edge
+            let start = if row < box_bottom {
+                left.max(box_right)
+            } else {
+                left
+            };
+            if start < right {
+                output.shade_rect(start, row, right - start, 1, shadow.color, strength);
+            }
+        }
+    };
+
+    let left = x.saturating_add(dx);
+    let top = y.saturating_add(dy);
+    let right = box_right.saturating_add(dx);
+    let bottom = box_bottom.saturating_add(dy);
+    if shadow.blur {
+        // One-cell fringe along the shadow's right and bottom edges
+        shade(
+            right,
+            top.saturating_add(1),
+            right.saturating_add(1),
+            bottom,
+            SHADOW_BLUR_STRENGTH,
+        );
+        shade(
+            left.saturating_add(1),
+            bottom,
+            right.saturating_add(1),
+            bottom.saturating_add(1),
+            SHADOW_BLUR_STRENGTH,
+        );
+    }
+    shade(left, top, right, bottom, SHADOW_STRENGTH);
 }
 
 fn render_border(element: &Element, output: &mut Output, x: u16, y: u16, width: u16, height: u16) {
@@ -130,44 +417,52 @@ fn render_border(element: &Element, output: &mut Output, x: u16, y: u16, width:
     let mut style = element.style.clone();
     style.dim = element.style.border_dim;
 
-    if element.style.border_top && height > 0 && width > 0 {
-        style.color = element.style.get_border_top_color();
-        output.write_char(x, y, tl, &style);
-        if width > 2 {
-            for col in (x + 1)..(x + width - 1) {
-                output.write_char(col, y, h, &style);
-            }
-        }
-        if width > 1 {
-            output.write_char(x + width - 1, y, tr, &style);
+    // Corners only where two sides meet, so one-sided borders draw as
+    // straight lines across the whole edge
+    let (top, bottom) = (element.style.border_top, element.style.border_bottom);
+    let (left, right) = (element.style.border_left, element.style.border_right);
+    if width == 0 || height == 0 {
+        return;
+    }
+    let last_col = x + width - 1;
+    let last_row = y + height - 1;
+    let mut draw_row = |row: u16, first: char, last: char, style: &Style| {
+        for col in x..=last_col {
+            let ch = if col == x && left {
+                first
+            } else if col == last_col && right && width > 1 {
+                last
+            } else {
+                h
+            };
+            output.write_char(col, row, ch, style);
         }
+    };
+
+    if top {
+        style.color = element.style.get_border_top_color();
+        draw_row(y, tl, tr, &style);
     }
 
-    if element.style.border_bottom && height > 1 && width > 0 {
+    if bottom && (height > 1 || !top) {
         style.color = element.style.get_border_bottom_color();
-        let bottom_y = y + height - 1;
-        output.write_char(x, bottom_y, bl, &style);
-        if width > 2 {
-            for col in (x + 1)..(x + width - 1) {
-                output.write_char(col, bottom_y, h, &style);
-            }
-        }
-        if width > 1 {
-            output.write_char(x + width - 1, bottom_y, br, &style);
-        }
+        draw_row(last_row, bl, br, &style);
     }
 
-    if element.style.border_left && height > 1 {
+    let first_side_row = if top { y + 1 } else { y };
+    let end_side_row = if bottom { last_row } else { last_row + 1 };
+
+    if left {
         style.color = element.style.get_border_left_color();
-        for row in (y + 1)..(y + height - 1) {
+        for row in first_side_row..end_side_row {
             output.write_char(x, row, v, &style);
         }
     }
 
-    if element.style.border_right && width > 1 && height > 1 {
+    if right && (width > 1 || !left) {
         style.color = element.style.get_border_right_color();
-        for row in (y + 1)..(y + height - 1) {
-            output.write_char(x + width - 1, row, v, &style);
+        for row in first_side_row..end_side_row {
+            output.write_char(last_col, row, v, &style);
         }
     }
 }
@@ -184,6 +479,42 @@ fn render_spans(lines: &[Line], output: &mut Output, start_x: u16, start_y: u16)
     }
 }
 
+const ELLIPSIS: &str = "…";
+
+/// Cut a styled line to `max_width` columns, ending it with an ellipsis that
+/// takes the style of the span it replaces.
+fn truncate_line(line: &Line, max_width: usize) -> Line {
+    let total: usize = line.spans.iter().map(Span::width).sum();
+    if total <= max_width {
+        return line.clone();
+    }
+
+    let mut spans = Vec::new();
+    let mut remaining = max_width.saturating_sub(measure_text_width(ELLIPSIS));
+    let mut ellipsis_style = line.spans.last().map(|s| s.style.clone());
+    for span in &line.spans {
+        let width = span.width();
+        if width <= remaining {
+            remaining -= width;
+            spans.push(span.clone());
+            continue;
+        }
+        let mut cut = span.clone();
+        cut.content = truncate_text(&span.content, remaining, "");
+        spans.push(cut);
+        ellipsis_style = Some(span.style.clone());
+        break;
+    }
+    if max_width > 0 {
+        let mut ellipsis = Span::new(ELLIPSIS);
+        if let Some(style) = ellipsis_style {
+            ellipsis.style = style;
+        }
+        spans.push(ellipsis);
+    }
+    Line { spans }
+}
+
 fn border_char(raw: &str) -> char {
     raw.chars().next().unwrap_or(' ')
 }
@@ -192,7 +523,7 @@ fn border_char(raw: &str) -> char {
 mod tests {
     use super::*;
     use crate::components::{Box, Text};
-    use crate::core::Overflow;
+    use crate::core::{Overflow, Style};
 
     #[test]
     fn scrolled_out_negative_rows_do_not_paint_at_top() {
@@ -226,4 +557,198 @@ mod tests {
 
         assert_eq!(output.render(), "ok");
     }
+
+    #[test]
+    fn shadow_shades_cells_beside_and_below_box() {
+        let element = Box::new()
+            .width(10)
+            .height(4)
+            .child(
+                Box::new()
+                    .width(4)
+                    .height(2)
+                    .border_style(crate::core::BorderStyle::Single)
+                    .shadow(ShadowStyle {
+                        offset: (2, 1),
+                        ..Default::default()
+                    })
+                    .into_element(),
+            )
+            .into_element();
+
+        let mut engine = LayoutEngine::new();
+        engine.compute(&element, 10, 4);
+
+        let mut output = Output::new(10, 4);
+        output.write(0, 2, "xxxxxxxxxx", &Style::default());
+        render_element_tree(&element, &engine, &mut output, 0.0, 0.0);
+
+        let shaded = |col: usize, row: usize| output.cell_at(col, row).unwrap().dim;
+        // Box cells are untouched
+        assert!(!shaded(0, 0) && !shaded(3, 1));
+        // Right strip, starting one row down
+        assert!(!shaded(4, 0));
+        assert!(shaded(4, 1) && shaded(5, 1) && !shaded(6, 1));
+        // Bottom strip keeps the text beneath
+        assert!(!shaded(1, 2));
+        assert!(shaded(2, 2) && shaded(5, 2));
+        assert_eq!(output.cell_at(2, 2).unwrap().ch, 'x');
+        assert!(!shaded(6, 2) && !shaded(2, 3));
+    }
+
+    #[test]
+    fn blurred_shadow_adds_fringe() {
+        let mut output = Output::new(8, 4);
+        let shadow = ShadowStyle {
+            offset: (1, 1),
+            blur: true,
+            ..Default::default()
+        };
+        render_shadow(&mut output, 0, 0, 3, 2, &shadow);
+
+        assert!(output.cell_at(3, 1).unwrap().dim);
+        assert!(output.cell_at(4, 2).unwrap().dim);
+        assert!(output.cell_at(2, 3).unwrap().dim);
+        assert!(!output.cell_at(4, 1).unwrap().dim);
+        assert!(!output.cell_at(1, 3).unwrap().dim);
+        assert_ne!(
+            output.cell_at(3, 1).unwrap().bg,
+            output.cell_at(4, 2).unwrap().bg
+        );
+    }
+
+    fn render_plain(element: &Element, width: u16) -> String {
+        let mut engine = LayoutEngine::new();
+        engine.compute(element, width, 1);
+        let mut output = Output::new(width, 1);
+        render_element_tree(element, &engine, &mut output, 0.0, 0.0);
+        output.render()
+    }
+
+    #[test]
+    fn ellipsis_truncates_at_final_layout_width() {
+        let row = |width: u16| {
+            Box::new()
+                .width(width)
+                .child(
+                    Text::new("src/renderer/tree_renderer.rs")
+                        .text_overflow(TextOverflow::Ellipsis)
+                        .into_element(),
+                )
+                .child(Text::new(" [M]").into_element())
+                .into_element()
+        };
+
+        assert_eq!(render_plain(&row(14), 14), "src/rende… [M]");
+        assert_eq!(render_plain(&row(20), 20), "src/renderer/tr… [M]");
+        assert_eq!(
+            render_plain(&row(40), 40),
+            "src/renderer/tree_renderer.rs [M]"
+        );
+    }
+
+    #[test]
+    fn ellipsis_keeps_span_styles() {
+        let text = Text::spans(vec![
+            Span::new("abc").color(crate::core::Color::Red),
+            Span::new("defgh").color(crate::core::Color::Blue),
+        ])
+        .text_overflow(TextOverflow::Ellipsis);
+        let element = Box::new()
+            .width(6)
+            .child(text.into_element())
+            .into_element();
+
+        let mut engine = LayoutEngine::new();
+        engine.compute(&element, 6, 1);
+        let mut output = Output::new(6, 1);
+        render_element_tree(&element, &engine, &mut output, 0.0, 0.0);
+
+        let text: String = (0..6)
+            .map(|col| output.cell_at(col, 0).unwrap().ch)
+            .collect();
+        assert_eq!(text, "abcde…");
+        assert_eq!(
+            output.cell_at(5, 0).unwrap().fg,
+            Some(crate::core::Color::Blue)
+        );
+    }
+
+    #[test]
+    fn background_fills_padding_and_empty_space() {
+        use crate::core::{BorderStyle, Color};
+
+        let element = Box::new()
+            .width(10)
+            .height(5)
+            .padding(1)
+            .background(Color::Blue)
+            .child(Text::new("hi").into_element())
+            .into_element();
+
+        let mut engine = LayoutEngine::new();
+        engine.compute(&element, 12, 6);
+        let mut output = Output::new(12, 6);
+        render_element_tree(&element, &engine, &mut output, 0.0, 0.0);
+
+        for row in 0..6 {
+            for col in 0..12 {
+                let expected = (row < 5 && col < 10).then_some(Color::Blue);
+                assert_eq!(
+                    output.cell_at(col, row).unwrap().bg,
+                    expected,
+                    "({col}, {row})"
+                );
+            }
+        }
+        // Text keeps the box color behind it
+        assert_eq!(output.cell_at(1, 1).unwrap().ch, 'h');
+
+        let bordered = Box::new()
+            .width(10)
+            .height(5)
+            .border_style(BorderStyle::Single)
+            .background(Color::Green)
+            .into_element();
+        let mut engine = LayoutEngine::new();
+        engine.compute(&bordered, 10, 5);
+        let mut output = Output::new(10, 5);
+        render_element_tree(&bordered, &engine, &mut output, 0.0, 0.0);
+        for row in 1..4 {
+            for col in 1..9 {
+                assert_eq!(output.cell_at(col, row).unwrap().bg, Some(Color::Green));
+            }
+        }
+        assert_ne!(output.cell_at(0, 0).unwrap().ch, ' ');
+    }
+
+    #[test]
+    fn background_of_partly_scrolled_box_fills_visible_rows() {
+        use crate::core::Color;
+
+        let element = Box::new()
+            .flex_direction(crate::core::FlexDirection::Column)
+            .width(6)
+            .height(2)
+            .overflow_y(Overflow::Hidden)
+            .scroll_offset_y(1)
+            .child(
+                Box::new()
+                    .height(2)
+                    .flex_shrink(0.0)
+                    .background(Color::Red)
+                    .into_element(),
+            )
+            .child(Box::new().height(1).flex_shrink(0.0).into_element())
+            .into_element();
+
+        let mut engine = LayoutEngine::new();
+        engine.compute(&element, 6, 2);
+        let mut output = Output::new(6, 2);
+        render_element_tree(&element, &engine, &mut output, 0.0, 0.0);
+
+        assert_eq!(output.cell_at(0, 0).unwrap().bg, Some(Color::Red));
+        assert_eq!(output.cell_at(5, 0).unwrap().bg, Some(Color::Red));
+        assert_eq!(output.cell_at(0, 1).unwrap().bg, None);
+    }
 }
@@ -0,0 +1,236 @@
+//! Time source for timers and animations
+//!
+//! Timer hooks (`use_debounce`, `use_interval`, `use_timeout`), animation
+//! handles and throttled input read the time through the [`Clock`] of the
+//! runtime they render in. Apps run on the system clock. A manual clock, as
+//! installed by [`HookHarness`](crate::testing::HookHarness), only moves
+//! when [`Clock::advance`] is called and fires due timers synchronously, so
+//! tests of time-driven hooks are deterministic.
+
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+type TimerCallback = Arc<dyn Fn() + Send + Sync>;
+
+/// Identifies a timer scheduled on a manual [`Clock`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TimerId(u64);
+
+/// Source of the current time for a runtime
+///
+/// Cloning shares the same clock.
+#[derive(Clone, Default)]
+pub struct Clock {
+    manual: Option<Arc<ManualClock>>,
+}
+
+struct ManualClock {
+    origin: Instant,
+    state: Mutex<ManualState>,
+}
+
+#[derive(Default)]
+struct ManualState {
+    elapsed: Duration,
+    next_id: u64,
+    timers: Vec<ManualTimer>,
+}
+
+struct ManualTimer {
+    id: TimerId,
+    due: Duration,
+    period: Option<Duration>,
+    callback: TimerCallback,
+}
+
+impl Clock {
+    /// The system clock
+    pub fn system() -> Self {
+        Self::default()
+    }
+
+    /// A clock that starts now and only moves on [`advance`](Self::advance)
+    pub fn manual() -> Self {
+        Self {
+            manual: Some(Arc::new(ManualClock {
+                origin: Instant::now(),
+                state: Mutex::new(ManualState::default()),
+            })),
+        }
+    }
+
+    /// Check whether this is a manual clock
+    pub fn is_manual(&self) -> bool {
+        self.manual.is_some()
+    }
+
+    /// The current time
+    pub fn now(&self) -> Instant {
+        match &self.manual {
+            Some(manual) => manual.origin + manual.lock().elapsed,
+            None => Instant::now(),
+        }
+    }
+
+    /// Move a manual clock forward by `duration`
+    ///
+    /// Timers that fall due run in order of their due time, each seeing
+    /// [`now`](Self::now) at the moment it was due. Does nothing on the
+    /// system clock.
+    pub fn advance(&self, duration: Duration) {
+        let Some(manual) = &self.manual else {
+            return;
+        };
+        let target = manual.lock().elapsed + duration;
+        loop {
+            let callback = {
+                let mut state = manual.lock();
+                let next = state
+                    .timers
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, timer)| timer.due <= target)
+                    .min_by_key(|(_, timer)| (timer.due, timer.id.0))
+                    .map(|(index, _)| index);
+                let Some(index) = next else {
+                    state.elapsed = target;
+                    break;
+                };
+                state.elapsed = state.timers[index].due;
+                let timer = &mut state.timers[index];
+                let callback = timer.callback.clone();
+                match timer.period {
+                    Some(period) => timer.due += period,
+                    None => {
+                        state.timers.remove(index);
+                    }
+                }
+                callback
+            };
+            // Run outside the lock so callbacks can schedule or cancel timers
+            callback();
+        }
+    }
+
+    /// Run `callback` after `delay`, and every `period` after that if set
+    ///
+    /// Returns `None` on the system clock, where callers keep their own
+    /// threads. A zero `period` is treated as one-shot.
+    pub(crate) fn schedule(
+        &self,
+        delay: Duration,
+        period: Option<Duration>,
+        callback: impl Fn() + Send + Sync + 'static,
+    ) -> Option<TimerId> {
+        let manual = self.manual.as_ref()?;
+        let mut state = manual.lock();
+        state.next_id += 1;
+        let id = TimerId(state.next_id);
+        let due = state.elapsed + delay;
+        state.timers.push(ManualTimer {
+            id,
+            due,
+            period: period.filter(|period| !period.is_zero()),
+            callback: Arc::new(callback),
+        });
+        Some(id)
+    }
+
+    /// Cancel a timer scheduled with [`schedule`](Self::schedule)
+    pub(crate) fn cancel(&self, id: TimerId) {
+        if let Some(manual) = &self.manual {
+            manual.lock().timers.retain(|timer| timer.id != id);
+        }
+    }
+}
+
+impl ManualClock {
+    fn lock(&self) -> std::sync::MutexGuard<'_, ManualState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl fmt::Debug for Clock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.manual {
+            Some(manual) => f
+                .debug_struct("Clock")
+                .field("elapsed", &manual.lock().elapsed)
+                .finish(),
+            None => f.write_str("Clock::System"),
+        }
+    }
+}
+
+/// The clock of the current runtime, or the system clock outside one
+pub fn current_clock() -> Clock {
+    super::current_runtime()
+        .map(|ctx| ctx.borrow().clock())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_manual_clock_only_moves_on_advance() {
+        let clock = Clock::manual();
+        let start = clock.now();
+        assert_eq!(clock.now(), start);
+
+        clock.advance(Duration::from_millis(250));
+        assert_eq!(clock.now() - start, Duration::from_millis(250));
+    }
+
+    #[test]
+    fn test_manual_clock_fires_timers_in_order() {
+        let clock = Clock::manual();
+        let start = clock.now();
+        let fired = Arc::new(Mutex::new(Vec::new()));
+
+        let log = fired.clone();
+        let at = clock.clone();
+        clock.schedule(Duration::from_millis(30), None, move || {
+            log.lock().unwrap().push(("once", at.now() - start));
+        });
+        let log = fired.clone();
+        let at = clock.clone();
+        let ticker = clock
+            .schedule(
+                Duration::from_millis(20),
+                Some(Duration::from_millis(20)),
+                move || log.lock().unwrap().push(("tick", at.now() - start)),
+            )
+            .unwrap();
+
+        clock.advance(Duration::from_millis(50));
+        let ms = Duration::from_millis;
+        assert_eq!(
+            *fired.lock().unwrap(),
+            vec![("tick", ms(20)), ("once", ms(30)), ("tick", ms(40))]
+        );
+
+        clock.cancel(ticker);
+        clock.advance(Duration::from_millis(100));
+        assert_eq!(fired.lock().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_system_clock_does_not_schedule() {
+        let clock = Clock::system();
+        let count = Arc::new(AtomicUsize::new(0));
+        let counter = count.clone();
+        assert!(
+            clock
+                .schedule(Duration::ZERO, None, move || {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                })
+                .is_none()
+        );
+        clock.advance(Duration::from_secs(1));
+        assert_eq!(count.load(Ordering::SeqCst), 0);
+    }
+}
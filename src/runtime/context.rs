@@ -25,8 +25,9 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
+use super::clock::Clock;
 use crate::cmd::Cmd;
-use crate::components::Theme;
+use crate::components::{Theme, ThemeStack};
 use crate::core::NodeKey;
 use crate::hooks::context::{HookContext, HookStorage};
 use crate::hooks::paste::PasteEvent;
@@ -89,15 +90,20 @@ pub struct RuntimeContext {
     measurements_by_key: std::collections::HashMap<String, (u16, u16)>,
     /// Alias map from user-provided string keys to stable node identities.
     measurement_key_aliases: std::collections::HashMap<String, NodeKey>,
+    /// Widths each `Responsive` container can have without changing views
+    responsive_ranges: std::collections::HashMap<String, std::ops::Range<u16>>,
 
     /// Shared frame rate statistics
     frame_rate_stats: Option<Arc<SharedFrameRateStats>>,
 
     /// Current theme for this runtime (isolated per app/runtime context)
-    theme: Theme,
+    theme: ThemeStack,
 
     /// Provider-backed context values scoped to the active runtime.
     context_values: std::collections::HashMap<usize, Vec<Box<dyn Any>>>,
+
+    /// Time source for timers and animations
+    clock: Clock,
 }
 
 impl RuntimeContext {
@@ -119,9 +125,11 @@ impl RuntimeContext {
             measurements_by_node_key: std::collections::HashMap::new(),
             measurements_by_key: std::collections::HashMap::new(),
             measurement_key_aliases: std::collections::HashMap::new(),
+            responsive_ranges: std::collections::HashMap::new(),
             frame_rate_stats: None,
-            theme: Theme::dark(),
+            theme: ThemeStack::new(Theme::dark()),
             context_values: std::collections::HashMap::new(),
+            clock: Clock::system(),
         }
     }
 
@@ -143,9 +151,11 @@ impl RuntimeContext {
             measurements_by_node_key: std::collections::HashMap::new(),
             measurements_by_key: std::collections::HashMap::new(),
             measurement_key_aliases: std::collections::HashMap::new(),
+            responsive_ranges: std::collections::HashMap::new(),
             frame_rate_stats: None,
-            theme: Theme::dark(),
+            theme: ThemeStack::new(Theme::dark()),
             context_values: std::collections::HashMap::new(),
+            clock: Clock::system(),
         }
     }
 
@@ -157,6 +167,7 @@ impl RuntimeContext {
         self.mouse_handlers.clear();
         self.paste_handlers.clear();
         self.mouse_enabled = false;
+        self.responsive_ranges.clear();
     }
 
     /// Begin a render cycle
@@ -295,6 +306,19 @@ impl RuntimeContext {
         self.last_activity.elapsed()
     }
 
+    // === Clock Methods ===
+
+    /// Replace the clock timers and animations read, e.g. with
+    /// [`Clock::manual`] in tests
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.clock = clock;
+    }
+
+    /// Get the clock timers and animations read
+    pub fn clock(&self) -> Clock {
+        self.clock.clone()
+    }
+
     // === Focus Manager Methods ===
 
     /// Get mutable access to the focus manager
@@ -480,6 +504,20 @@ impl RuntimeContext {
             .map(|&(w, h)| (w as f32, h as f32))
     }
 
+    /// Record the widths a `Responsive` container rendered this frame for
+    pub(crate) fn record_responsive_range(&mut self, key: String, range: std::ops::Range<u16>) {
+        self.responsive_ranges.insert(key, range);
+    }
+
+    /// Whether a `Responsive` container was laid out with a width that
+    /// would pick a different view than the one it rendered
+    pub(crate) fn has_stale_responsive_layout(&self) -> bool {
+        self.responsive_ranges.iter().any(|(key, range)| {
+            self.get_measurement_by_key_dims(key)
+                .is_some_and(|(width, _)| !range.contains(&(width as u16)))
+        })
+    }
+
     // === Frame Rate Stats Methods ===
 
     /// Set the shared frame rate stats
@@ -496,12 +534,17 @@ impl RuntimeContext {
 
     /// Set the current theme for this runtime.
     pub fn set_theme(&mut self, theme: Theme) {
-        self.theme = theme;
+        self.theme.set_base(theme);
     }
 
     /// Get the current theme for this runtime.
     pub fn theme(&self) -> Theme {
-        self.theme.clone()
+        self.theme.current().clone()
+    }
+
+    /// Base theme and overrides for this runtime
+    pub(crate) fn theme_stack_mut(&mut self) -> &mut ThemeStack {
+        &mut self.theme
     }
 
     // === Context Provider Methods ===
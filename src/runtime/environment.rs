@@ -6,6 +6,10 @@
 //! - Terminal capabilities
 
 use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static DOUBLE_WIDTH_LINES_SUPPORTED: AtomicBool = AtomicBool::new(false);
+static DOUBLE_WIDTH_LINES_CHECKED: AtomicBool = AtomicBool::new(false);
 
 /// Environment information
 #[derive(Debug, Clone)]
@@ -127,10 +131,104 @@ pub fn is_tty() -> bool {
     std::io::stdout().is_terminal()
 }
 
+/// Check if the terminal is expected to understand focus reporting
+///
+/// Focus reporting (`CSI ? 1004 h`) needs an interactive terminal on both
+/// ends. The `dumb` terminal and the Linux virtual console ignore the mode,
+/// so it is never enabled there.
+pub fn supports_focus_reporting() -> bool {
+    if !is_tty() || !std::io::stdin().is_terminal() {
+        return false;
+    }
+    term_supports_focus_reporting(std::env::var("TERM").ok().as_deref())
+}
+
+fn term_supports_focus_reporting(term: Option<&str>) -> bool {
+    !matches!(term, Some("dumb") | Some("linux"))
+}
+
+/// Check if the terminal draws double-width lines (DECDWL)
+///
+/// Only xterm, Konsole and Windows Terminal are assumed to; elsewhere
+/// [`Style::double_width`](crate::core::Style::double_width) text is drawn
+/// at normal size. Override the detection with
+/// [`set_double_width_lines_supported`].
+pub fn supports_double_width_lines() -> bool {
+    if !DOUBLE_WIDTH_LINES_CHECKED.load(Ordering::SeqCst) {
+        let supported = term_supports_double_width_lines(
+            std::env::var("XTERM_VERSION").is_ok(),
+            std::env::var("KONSOLE_VERSION").is_ok(),
+            std::env::var("WT_SESSION").is_ok(),
+        );
+        DOUBLE_WIDTH_LINES_SUPPORTED.store(supported, Ordering::SeqCst);
+        DOUBLE_WIDTH_LINES_CHECKED.store(true, Ordering::SeqCst);
+    }
+    DOUBLE_WIDTH_LINES_SUPPORTED.load(Ordering::SeqCst)
+}
+
+/// Force enable/disable double-width line support
+pub fn set_double_width_lines_supported(supported: bool) {
+    DOUBLE_WIDTH_LINES_SUPPORTED.store(supported, Ordering::SeqCst);
+    DOUBLE_WIDTH_LINES_CHECKED.store(true, Ordering::SeqCst);
+}
+
+fn term_supports_double_width_lines(xterm: bool, konsole: bool, windows_terminal: bool) -> bool {
+    xterm || konsole || windows_terminal
+}
+
+/// Forces double-width line support for a test and restores the previous
+/// detection state on drop
+///
+/// Holds a lock so tests that override the flag do not interleave.
+#[cfg(test)]
+pub(crate) struct DoubleWidthLinesOverride {
+    supported: bool,
+    checked: bool,
+    _lock: std::sync::MutexGuard<'static, ()>,
+}
+
+#[cfg(test)]
+impl DoubleWidthLinesOverride {
+    pub(crate) fn new(supported: bool) -> Self {
+        static LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+        let lock = LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let guard = Self {
+            supported: DOUBLE_WIDTH_LINES_SUPPORTED.load(Ordering::SeqCst),
+            checked: DOUBLE_WIDTH_LINES_CHECKED.load(Ordering::SeqCst),
+            _lock: lock,
+        };
+        set_double_width_lines_supported(supported);
+        guard
+    }
+}
+
+#[cfg(test)]
+impl Drop for DoubleWidthLinesOverride {
+    fn drop(&mut self) {
+        DOUBLE_WIDTH_LINES_SUPPORTED.store(self.supported, Ordering::SeqCst);
+        DOUBLE_WIDTH_LINES_CHECKED.store(self.checked, Ordering::SeqCst);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_term_supports_focus_reporting() {
+        assert!(term_supports_focus_reporting(Some("xterm-256color")));
+        assert!(term_supports_focus_reporting(None));
+        assert!(!term_supports_focus_reporting(Some("dumb")));
+        assert!(!term_supports_focus_reporting(Some("linux")));
+    }
+
+    #[test]
+    fn test_term_supports_double_width_lines() {
+        assert!(term_supports_double_width_lines(true, false, false));
+        assert!(term_supports_double_width_lines(false, false, true));
+        assert!(!term_supports_double_width_lines(false, false, false));
+    }
+
     #[test]
     fn test_environment_detect() {
         let env = Environment::detect();
@@ -6,11 +6,13 @@
 //!
 //! This module provides:
 //! - Unified runtime context for app state
+//! - A replaceable clock for timers and animations
 //! - Panic hook for terminal restoration
 //! - Signal handling (SIGINT, SIGTERM, SIGHUP)
 //! - Suspend/resume support (Ctrl+Z / fg)
 //! - Environment detection (CI, TTY)
 
+mod clock;
 mod context;
 #[cfg(test)]
 mod context_tests;
@@ -19,10 +21,18 @@ mod panic_handler;
 mod signal_handler;
 mod suspend;
 
+pub(crate) use clock::TimerId;
+pub use clock::{Clock, current_clock};
 pub use context::{
     RuntimeContext, current_runtime, set_current_runtime, with_current_runtime, with_runtime,
 };
-pub use environment::{Environment, is_ci, is_tty};
+#[cfg(test)]
+pub(crate) use environment::DoubleWidthLinesOverride;
+pub use environment::{
+    Environment, is_ci, is_tty, set_double_width_lines_supported, supports_double_width_lines,
+    supports_focus_reporting,
+};
 pub use panic_handler::{install_panic_hook, restore_terminal};
-pub use signal_handler::{SignalHandler, install_signal_handler};
+pub use signal_handler::{OsSignal, SignalCallback, SignalHandler, install_signal_handler};
+pub(crate) use signal_handler::{SignalCallbacks, take_pending_signal};
 pub use suspend::{SuspendHandler, install_suspend_handlers, suspend_self};
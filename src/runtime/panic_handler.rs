@@ -20,7 +20,15 @@ static PANIC_HOOK_INSTALLED: Once = Once::new();
 /// 2. Leaves alternate screen (if active)
 /// 3. Shows cursor
 /// 4. Disables mouse capture
+/// 5. Pops keyboard enhancement flags
+///
+/// Does nothing while the running app has terminal control disabled, since
+/// its output may not be a terminal.
 pub fn restore_terminal() {
+    if !crate::renderer::registry::current_app_controls_terminal() {
+        return;
+    }
+
     let mut stdout = std::io::stdout();
 
     // Disable raw mode first
@@ -34,6 +42,7 @@ pub fn restore_terminal() {
         crossterm::event::DisableMouseCapture,
         crossterm::event::DisableBracketedPaste,
         crossterm::event::DisableFocusChange,
+        crossterm::event::PopKeyboardEnhancementFlags,
     );
 
     // Flush to ensure all escape sequences are sent
@@ -2,7 +2,11 @@
 //!
 //! Handles SIGINT (Ctrl+C), SIGTERM, and SIGHUP signals to ensure
 //! terminal state is restored before exit.
+//!
+//! Apps run through `AppBuilder` can instead register per-signal callbacks
+//! with `AppBuilder::on_signal`; see [`OsSignal`].
 
+use std::ops::ControlFlow;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 
@@ -89,6 +93,165 @@ pub fn install_signal_handler() -> Result<SignalHandler, ctrlc::Error> {
     Ok(handler)
 }
 
+/// OS signals an app can react to with `AppBuilder::on_signal`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OsSignal {
+    /// SIGINT, or Ctrl+C while the terminal is in raw mode
+    Interrupt,
+    /// SIGTERM
+    Terminate,
+    /// SIGHUP (terminal closed)
+    Hangup,
+    /// SIGWINCH (terminal resized), delivered through resize events
+    WindowChange,
+}
+
+impl OsSignal {
+    const ALL: [OsSignal; 4] = [
+        OsSignal::Interrupt,
+        OsSignal::Terminate,
+        OsSignal::Hangup,
+        OsSignal::WindowChange,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Whether the app exits on this signal when no callback is registered
+    fn exits_by_default(self) -> bool {
+        !matches!(self, OsSignal::WindowChange)
+    }
+}
+
+/// Callback for an OS signal
+///
+/// Return `ControlFlow::Break(())` to let the app exit or
+/// `ControlFlow::Continue(())` to keep running.
+pub type SignalCallback = Box<dyn Fn() -> ControlFlow<()>>;
+
+/// Signal callbacks registered on an app
+#[derive(Default)]
+pub(crate) struct SignalCallbacks {
+    callbacks: Vec<(OsSignal, SignalCallback)>,
+}
+
+impl SignalCallbacks {
+    pub(crate) fn register(&mut self, signal: OsSignal, callback: SignalCallback) {
+        self.callbacks.push((signal, callback));
+    }
+
+    pub(crate) fn handles(&self, signal: OsSignal) -> bool {
+        self.callbacks.iter().any(|(s, _)| *s == signal)
+    }
+
+    /// Run the callbacks for `signal` and report whether the app should exit
+    ///
+    /// Without callbacks the default applies: exit on everything except
+    /// `WindowChange`. With callbacks, any `Break` exits.
+    pub(crate) fn dispatch(&self, signal: OsSignal) -> bool {
+        let mut handled = false;
+        let mut exit = false;
+        for (_, callback) in self.callbacks.iter().filter(|(s, _)| *s == signal) {
+            handled = true;
+            exit |= callback().is_break();
+        }
+        if handled {
+            exit
+        } else {
+            signal.exits_by_default()
+        }
+    }
+
+    /// Catch the OS signals that have callbacks until the guard is dropped
+    ///
+    /// Interrupt, terminate and hangup are caught with `sigaction` and
+    /// queued for [`take_pending_signal`]. `WindowChange` is left to the
+    /// terminal backend, which reports it as a resize event. On non-Unix
+    /// platforms nothing is installed; Ctrl+C still arrives as a key press.
+    pub(crate) fn install(&self) -> OsSignalGuard {
+        let signals: Vec<OsSignal> = OsSignal::ALL
+            .into_iter()
+            .filter(|&s| s != OsSignal::WindowChange && self.handles(s))
+            .collect();
+        OsSignalGuard::install(&signals)
+    }
+}
+
+/// Signals received since the last check, indexed by [`OsSignal::index`]
+static PENDING_SIGNALS: [AtomicBool; 4] = [const { AtomicBool::new(false) }; 4];
+
+/// Take the next caught OS signal, if any
+pub(crate) fn take_pending_signal() -> Option<OsSignal> {
+    OsSignal::ALL
+        .into_iter()
+        .find(|s| PENDING_SIGNALS[s.index()].swap(false, Ordering::SeqCst))
+}
+
+/// Restores the previous signal dispositions on drop
+pub(crate) struct OsSignalGuard {
+    #[cfg(unix)]
+    previous: Vec<(libc::c_int, libc::sigaction)>,
+}
+
+#[cfg(unix)]
+impl OsSignalGuard {
+    fn install(signals: &[OsSignal]) -> Self {
+        extern "C" fn record(signum: libc::c_int) {
+            let signal = match signum {
+                libc::SIGINT => OsSignal::Interrupt,
+                libc::SIGTERM => OsSignal::Terminate,
+                libc::SIGHUP => OsSignal::Hangup,
+                _ => return,
+            };
+            // Only an atomic store: async-signal-safe
+            PENDING_SIGNALS[signal.index()].store(true, Ordering::SeqCst);
+        }
+
+        let mut previous = Vec::new();
+        for signal in signals {
+            let signum = match signal {
+                OsSignal::Interrupt => libc::SIGINT,
+                OsSignal::Terminate => libc::SIGTERM,
+                OsSignal::Hangup => libc::SIGHUP,
+                OsSignal::WindowChange => continue,
+            };
+            // SAFETY: the action is fully initialized and the handler only
+            // performs an atomic store.
+            unsafe {
+                let mut action: libc::sigaction = std::mem::zeroed();
+                action.sa_sigaction = record as extern "C" fn(libc::c_int) as libc::sighandler_t;
+                action.sa_flags = libc::SA_RESTART;
+                libc::sigemptyset(&mut action.sa_mask);
+                let mut old: libc::sigaction = std::mem::zeroed();
+                if libc::sigaction(signum, &action, &mut old) == 0 {
+                    previous.push((signum, old));
+                }
+            }
+        }
+        Self { previous }
+    }
+}
+
+#[cfg(unix)]
+impl Drop for OsSignalGuard {
+    fn drop(&mut self) {
+        for (signum, old) in self.previous.drain(..) {
+            // SAFETY: restoring a disposition previously returned by sigaction
+            unsafe {
+                libc::sigaction(signum, &old, std::ptr::null_mut());
+            }
+        }
+    }
+}
+
+#[cfg(not(unix))]
+impl OsSignalGuard {
+    fn install(_signals: &[OsSignal]) -> Self {
+        Self {}
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,4 +271,33 @@ mod tests {
         flag.store(true, Ordering::SeqCst);
         assert!(handler.should_exit());
     }
+
+    #[test]
+    fn test_signal_callbacks_default_and_override() {
+        let mut callbacks = SignalCallbacks::default();
+        assert!(callbacks.dispatch(OsSignal::Interrupt));
+        assert!(!callbacks.dispatch(OsSignal::WindowChange));
+
+        callbacks.register(OsSignal::Interrupt, Box::new(|| ControlFlow::Continue(())));
+        assert!(!callbacks.dispatch(OsSignal::Interrupt));
+
+        callbacks.register(OsSignal::Interrupt, Box::new(|| ControlFlow::Break(())));
+        assert!(callbacks.dispatch(OsSignal::Interrupt));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_installed_signal_is_queued() {
+        let mut callbacks = SignalCallbacks::default();
+        callbacks.register(OsSignal::Hangup, Box::new(|| ControlFlow::Continue(())));
+
+        let guard = callbacks.install();
+        // SAFETY: SIGHUP is caught by the guard's handler
+        unsafe {
+            libc::raise(libc::SIGHUP);
+        }
+        assert_eq!(take_pending_signal(), Some(OsSignal::Hangup));
+        assert_eq!(take_pending_signal(), None);
+        drop(guard);
+    }
 }
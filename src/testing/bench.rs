@@ -0,0 +1,306 @@
+//! Render throughput measurement
+//!
+//! Times the layout and render stages on large element trees so the cost of
+//! a change can be quantified, e.g. how much the reconciler's incremental
+//! layout saves over a full rebuild. Works from tests, examples, or
+//! divan/criterion benches; the timings are plain wall-clock measurements.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rnk::testing::{RenderBench, gen_bench_tree};
+//!
+//! let bench = RenderBench::new(80, 24).iterations(5);
+//! let comparison = bench.compare_layout(|frame| gen_bench_tree(20, 4, frame));
+//!
+//! assert_eq!(comparison.incremental.full_rebuilds, 0);
+//! println!("{}\n{}", comparison.full, comparison.incremental);
+//! ```
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::components::{Box as RnkBox, Text};
+use crate::core::{Element, FlexDirection};
+use crate::layout::LayoutEngine;
+use crate::renderer::Output;
+use crate::renderer::tree_renderer::render_element_tree;
+
+/// Build a `rows` × `cols` grid of text cells for throughput measurement
+///
+/// One cell changes with `frame`, so consecutive frames differ by a single
+/// text patch, the common case for interactive apps.
+pub fn gen_bench_tree(rows: usize, cols: usize, frame: usize) -> Element {
+    let changed_row = if rows == 0 { 0 } else { frame % rows };
+    let mut column = RnkBox::new().flex_direction(FlexDirection::Column);
+    for row in 0..rows {
+        let mut line = RnkBox::new().flex_direction(FlexDirection::Row).gap(1.0);
+        for col in 0..cols {
+            let text = if row == changed_row && col == 0 {
+                format!("frame {frame}")
+            } else {
+                format!("cell {row}:{col}")
+            };
+            line = line.child(Text::new(text).into_element());
+        }
+        column = column.child(line.into_element());
+    }
+    column.into_element()
+}
+
+/// Count the elements in a tree, including the root
+pub fn count_nodes(element: &Element) -> usize {
+    1 + element.children.iter().map(count_nodes).sum::<usize>()
+}
+
+/// Timing for one measured stage
+#[derive(Debug, Clone, Default)]
+pub struct BenchReport {
+    /// Stage name
+    pub label: &'static str,
+    /// Number of measured iterations
+    pub iterations: usize,
+    /// Elements processed across all iterations
+    pub nodes: usize,
+    /// Total measured time
+    pub elapsed: Duration,
+    /// Reconciler patches generated across all iterations
+    pub patch_count: usize,
+    /// Iterations where incremental layout fell back to a full rebuild
+    pub full_rebuilds: usize,
+}
+
+impl BenchReport {
+    fn new(label: &'static str) -> Self {
+        Self {
+            label,
+            ..Default::default()
+        }
+    }
+
+    /// Average time per iteration
+    pub fn per_iteration(&self) -> Duration {
+        if self.iterations == 0 {
+            Duration::ZERO
+        } else {
+            self.elapsed / self.iterations as u32
+        }
+    }
+
+    /// Elements processed per second
+    pub fn nodes_per_sec(&self) -> f64 {
+        let secs = self.elapsed.as_secs_f64();
+        if secs == 0.0 {
+            0.0
+        } else {
+            self.nodes as f64 / secs
+        }
+    }
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} iters, {:?}/iter, {:.0} nodes/s, {} patches",
+            self.label,
+            self.iterations,
+            self.per_iteration(),
+            self.nodes_per_sec(),
+            self.patch_count
+        )?;
+        if self.full_rebuilds > 0 {
+            write!(f, ", {} full rebuilds", self.full_rebuilds)?;
+        }
+        Ok(())
+    }
+}
+
+/// Full-rebuild and incremental layout timings for the same frames
+#[derive(Debug, Clone)]
+pub struct BenchComparison {
+    /// Layout rebuilt from scratch every frame
+    pub full: BenchReport,
+    /// Layout updated through reconciler patches
+    pub incremental: BenchReport,
+}
+
+impl BenchComparison {
+    /// How many times faster the incremental path is (`> 1.0` is a win)
+    pub fn speedup(&self) -> f64 {
+        let incremental = self.incremental.elapsed.as_secs_f64();
+        if incremental == 0.0 {
+            0.0
+        } else {
+            self.full.elapsed.as_secs_f64() / incremental
+        }
+    }
+}
+
+/// Measures layout and render throughput at a fixed terminal size
+///
+/// Frame builders receive the frame index and return the tree to render,
+/// so they can vary content between frames to exercise the reconciler.
+/// Frame 0 is a warm-up and is never measured.
+#[derive(Debug, Clone)]
+pub struct RenderBench {
+    width: u16,
+    height: u16,
+    iterations: usize,
+}
+
+impl RenderBench {
+    /// Create a bench for a `width` × `height` terminal (100 iterations)
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width,
+            height,
+            iterations: 100,
+        }
+    }
+
+    /// Set the number of measured iterations
+    pub fn iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations.max(1);
+        self
+    }
+
+    /// Time a full layout rebuild per frame (`LayoutEngine::compute`)
+    pub fn layout_full(&self, mut build: impl FnMut(usize) -> Element) -> BenchReport {
+        let mut report = BenchReport::new("layout (full)");
+        let mut engine = LayoutEngine::new();
+        engine.compute(&build(0), self.width, self.height);
+
+        for frame in 1..=self.iterations {
+            let element = build(frame);
+            let start = Instant::now();
+            engine.compute(&element, self.width, self.height);
+            report.elapsed += start.elapsed();
+            report.nodes += count_nodes(&element);
+            report.iterations += 1;
+        }
+        report
+    }
+
+    /// Time reconciler-driven layout per frame (`compute_element_incremental`)
+    pub fn layout_incremental(&self, mut build: impl FnMut(usize) -> Element) -> BenchReport {
+        let mut report = BenchReport::new("layout (incremental)");
+        let mut engine = LayoutEngine::new();
+        let (mut previous, _) =
+            engine.compute_element_incremental(&build(0), None, self.width, self.height);
+
+        for frame in 1..=self.iterations {
+            let element = build(frame);
+            let start = Instant::now();
+            let (vnode, outcome) = engine.compute_element_incremental(
+                &element,
+                Some(&previous),
+                self.width,
+                self.height,
+            );
+            report.elapsed += start.elapsed();
+            report.nodes += count_nodes(&element);
+            report.iterations += 1;
+            report.patch_count += outcome.patch_count;
+            report.full_rebuilds += usize::from(outcome.fallback_full_rebuild);
+            previous = vnode;
+        }
+        report
+    }
+
+    /// Run both layout paths over the same frames
+    pub fn compare_layout(&self, mut build: impl FnMut(usize) -> Element) -> BenchComparison {
+        BenchComparison {
+            full: self.layout_full(&mut build),
+            incremental: self.layout_incremental(&mut build),
+        }
+    }
+
+    /// Time `Output::render` alone on an already drawn frame
+    pub fn output_render(&self, element: &Element) -> BenchReport {
+        let mut report = BenchReport::new("output render");
+        let output = self.draw(element, &mut LayoutEngine::new());
+        let nodes = count_nodes(element);
+
+        for _ in 0..self.iterations {
+            let start = Instant::now();
+            std::hint::black_box(output.render());
+            report.elapsed += start.elapsed();
+            report.nodes += nodes;
+            report.iterations += 1;
+        }
+        report
+    }
+
+    /// Time whole frames: incremental layout, drawing, and `Output::render`
+    pub fn frame(&self, mut build: impl FnMut(usize) -> Element) -> BenchReport {
+        let mut report = BenchReport::new("frame");
+        let mut engine = LayoutEngine::new();
+        let first = build(0);
+        let (mut previous, _) =
+            engine.compute_element_incremental(&first, None, self.width, self.height);
+
+        for frame in 1..=self.iterations {
+            let element = build(frame);
+            let start = Instant::now();
+            let (vnode, outcome) = engine.compute_element_incremental(
+                &element,
+                Some(&previous),
+                self.width,
+                self.height,
+            );
+            let mut output = Output::new(self.width, self.height);
+            render_element_tree(&element, &engine, &mut output, 0.0, 0.0);
+            std::hint::black_box(output.render());
+            report.elapsed += start.elapsed();
+            report.nodes += count_nodes(&element);
+            report.iterations += 1;
+            report.patch_count += outcome.patch_count;
+            report.full_rebuilds += usize::from(outcome.fallback_full_rebuild);
+            previous = vnode;
+        }
+        report
+    }
+
+    fn draw(&self, element: &Element, engine: &mut LayoutEngine) -> Output {
+        engine.compute(element, self.width, self.height);
+        let mut output = Output::new(self.width, self.height);
+        render_element_tree(element, engine, &mut output, 0.0, 0.0);
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gen_bench_tree_changes_one_cell_per_frame() {
+        let tree = gen_bench_tree(3, 2, 0);
+        // root + 3 rows + 6 cells
+        assert_eq!(count_nodes(&tree), 10);
+
+        let bench = RenderBench::new(40, 10).iterations(3);
+        let report = bench.layout_incremental(|frame| gen_bench_tree(3, 2, frame));
+        assert_eq!(report.iterations, 3);
+        assert_eq!(report.nodes, 30);
+        assert!(report.patch_count >= 3);
+        assert_eq!(report.full_rebuilds, 0);
+    }
+
+    #[test]
+    fn test_compare_and_frame_reports() {
+        let bench = RenderBench::new(40, 10).iterations(2);
+        let comparison = bench.compare_layout(|frame| gen_bench_tree(4, 2, frame));
+        assert_eq!(comparison.full.iterations, 2);
+        assert_eq!(comparison.full.patch_count, 0);
+        assert_eq!(comparison.incremental.iterations, 2);
+
+        let frame = bench.frame(|frame| gen_bench_tree(4, 2, frame));
+        assert_eq!(frame.iterations, 2);
+        assert!(frame.to_string().starts_with("frame: 2 iters"));
+
+        let render = bench.output_render(&gen_bench_tree(4, 2, 0));
+        assert_eq!(render.nodes, 2 * 13);
+    }
+}
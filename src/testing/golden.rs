@@ -5,7 +5,7 @@
 #![allow(dead_code)]
 
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use super::renderer::TestRenderer;
 use crate::core::Element;
@@ -13,6 +13,9 @@ use crate::core::Element;
 /// Directory for golden files
 const GOLDEN_DIR: &str = "tests/golden";
 
+/// Directory for `assert_element_snapshot!` files
+const SNAPSHOT_DIR: &str = "tests/snapshots";
+
 /// Result of a golden file comparison
 #[derive(Debug)]
 pub enum GoldenResult {
@@ -200,6 +203,128 @@ fn normalize_golden_output(output: &str) -> String {
         .join("\n")
 }
 
+/// Render `element` and compare it against its stored snapshot
+///
+/// ANSI snapshots live in `tests/snapshots/<name>.snap`, plain-text ones in
+/// `tests/snapshots/<name>.plain.snap`. On a mismatch (or a missing
+/// snapshot) the actual output is written next to the stored one with a
+/// `.new` suffix and the test panics with a colored line diff. Set
+/// `UPDATE_SNAPSHOTS=1` to accept the new output instead. Usually called
+/// through [`assert_element_snapshot!`](crate::assert_element_snapshot).
+pub fn assert_element_snapshot(
+    name: &str,
+    element: &Element,
+    width: u16,
+    height: u16,
+    format: GoldenFormat,
+) {
+    let renderer = TestRenderer::new(width, height);
+    let (name, output) = match format {
+        GoldenFormat::Ansi => (name.to_string(), renderer.render_to_ansi(element)),
+        GoldenFormat::Plain => (format!("{}.plain", name), renderer.render_to_plain(element)),
+    };
+    let actual = normalize_golden_output(&output);
+    if let Err(message) = check_snapshot(
+        Path::new(SNAPSHOT_DIR),
+        &name,
+        &actual,
+        should_update_snapshots(),
+    ) {
+        panic!("\n\n{}", message);
+    }
+}
+
+fn should_update_snapshots() -> bool {
+    std::env::var("UPDATE_SNAPSHOTS").is_ok_and(|value| !value.is_empty() && value != "0")
+}
+
+/// Compare `actual` against the stored snapshot, writing or cleaning up files
+fn check_snapshot(dir: &Path, name: &str, actual: &str, update: bool) -> Result<(), String> {
+    let path = dir.join(format!("{}.snap", name));
+    let new_path = dir.join(format!("{}.snap.new", name));
+    let write = |path: &Path| -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| format!("cannot create {}: {}", parent.display(), err))?;
+        }
+        fs::write(path, actual).map_err(|err| format!("cannot write {}: {}", path.display(), err))
+    };
+
+    if update {
+        write(&path)?;
+        let _ = fs::remove_file(&new_path);
+        return Ok(());
+    }
+
+    let expected = match fs::read_to_string(&path) {
+        Ok(content) => normalize_golden_output(&content),
+        Err(_) => {
+            write(&new_path)?;
+            return Err(format!(
+                "Snapshot '{}' missing: {}\nActual output written to {}\n\n{}\n\nRun with UPDATE_SNAPSHOTS=1 to accept it.",
+                name,
+                path.display(),
+                new_path.display(),
+                actual
+            ));
+        }
+    };
+
+    if expected == actual {
+        let _ = fs::remove_file(&new_path);
+        return Ok(());
+    }
+
+    write(&new_path)?;
+    Err(format!(
+        "Snapshot '{}' mismatch ({}):\n\n{}\nActual output written to {}\nRun with UPDATE_SNAPSHOTS=1 to accept it.",
+        name,
+        path.display(),
+        colored_diff(&expected, actual),
+        new_path.display()
+    ))
+}
+
+/// Line diff with `-` (red) for expected and `+` (green) for actual lines
+fn colored_diff(expected: &str, actual: &str) -> String {
+    const RED: &str = "\x1b[31m";
+    const GREEN: &str = "\x1b[32m";
+    const DIM: &str = "\x1b[2m";
+    const RESET: &str = "\x1b[0m";
+
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // Longest common subsequence table, filled from the end
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            diff.push_str(&format!("{}  {}{}\n", DIM, old[i], RESET));
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            diff.push_str(&format!("{}+ {}{}\n", GREEN, new[j], RESET));
+            j += 1;
+        } else {
+            diff.push_str(&format!("{}- {}{}\n", RED, old[i], RESET));
+            i += 1;
+        }
+    }
+    diff
+}
+
 /// Generate a simple diff between two strings
 fn simple_diff(expected: &str, actual: &str) -> String {
     let expected_lines: Vec<&str> = expected.lines().collect();
@@ -251,6 +376,37 @@ macro_rules! golden_test {
     };
 }
 
+/// Assert that an element renders like its stored snapshot
+///
+/// Snapshots live in `tests/snapshots/` and include ANSI colors unless a
+/// [`GoldenFormat`](crate::testing::GoldenFormat) is given (80x24 unless a
+/// size is given). Run with `UPDATE_SNAPSHOTS=1` to create or accept
+/// snapshots.
+///
+/// ```ignore
+/// assert_element_snapshot!("greeting", Text::new("Hello").into_element());
+/// assert_element_snapshot!("greeting_narrow", element, 20, 5);
+/// assert_element_snapshot!("greeting_plain", element, 20, 5, GoldenFormat::Plain);
+/// ```
+#[macro_export]
+macro_rules! assert_element_snapshot {
+    ($name:expr, $element:expr) => {
+        $crate::assert_element_snapshot!($name, $element, 80, 24)
+    };
+    ($name:expr, $element:expr, $width:expr, $height:expr) => {
+        $crate::assert_element_snapshot!(
+            $name,
+            $element,
+            $width,
+            $height,
+            $crate::testing::GoldenFormat::Ansi
+        )
+    };
+    ($name:expr, $element:expr, $width:expr, $height:expr, $format:expr) => {
+        $crate::testing::assert_element_snapshot($name, &$element, $width, $height, $format)
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -275,6 +431,65 @@ mod tests {
         assert!(golden.golden_path().ends_with("my_test.ansi.txt"));
     }
 
+    fn snapshot_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("rnk-snapshots-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_check_snapshot_missing_writes_new_file() {
+        let dir = snapshot_dir("missing");
+        let err = check_snapshot(&dir, "widget", "hello", false).unwrap_err();
+
+        assert!(err.contains("UPDATE_SNAPSHOTS=1"));
+        assert_eq!(
+            fs::read_to_string(dir.join("widget.snap.new")).unwrap(),
+            "hello"
+        );
+        assert!(!dir.join("widget.snap").exists());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_check_snapshot_update_accepts_and_matches() {
+        let dir = snapshot_dir("update");
+        check_snapshot(&dir, "widget", "hello", false).unwrap_err();
+        check_snapshot(&dir, "widget", "hello", true).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(dir.join("widget.snap")).unwrap(),
+            "hello"
+        );
+        assert!(!dir.join("widget.snap.new").exists());
+        assert!(check_snapshot(&dir, "widget", "hello", false).is_ok());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_check_snapshot_mismatch_reports_diff() {
+        let dir = snapshot_dir("mismatch");
+        check_snapshot(&dir, "widget", "a\nb\nc", true).unwrap();
+        let err = check_snapshot(&dir, "widget", "a\nx\nc", false).unwrap_err();
+
+        assert!(err.contains("\x1b[31m- b"));
+        assert!(err.contains("\x1b[32m+ x"));
+        assert_eq!(
+            fs::read_to_string(dir.join("widget.snap.new")).unwrap(),
+            "a\nx\nc"
+        );
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_colored_diff_keeps_common_lines() {
+        let diff = colored_diff("one\ntwo", "one\ntwo\nthree");
+        assert!(diff.contains("  one"));
+        assert!(diff.contains("+ three"));
+        assert!(!diff.contains("- "));
+    }
+
     #[test]
     fn test_normalize_golden_output_removes_terminal_padding() {
         assert_eq!(
@@ -21,13 +21,14 @@
 //! ```
 
 use crate::core::Element;
-use crate::hooks::use_input::{Key, KeyCodeKind, MediaKeyKind};
+use crate::hooks::use_input::{Key, KeyCodeKind};
 use crate::hooks::use_mouse::Mouse;
-use crate::runtime::{RuntimeContext, current_runtime, set_current_runtime, with_runtime};
-use crate::testing::TestRenderer;
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MediaKeyCode};
+use crate::runtime::{Clock, RuntimeContext, current_runtime, set_current_runtime, with_runtime};
+use crate::testing::{InteractionScript, ScriptTarget, TestRenderer};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::time::Duration;
 
 /// Test harness for interactive component testing
 pub struct TestHarness<F>
@@ -55,7 +56,9 @@ where
 
     /// Create a test harness with custom terminal size
     pub fn with_size(component: F, width: u16, height: u16) -> Self {
-        let runtime = Rc::new(RefCell::new(RuntimeContext::new()));
+        let mut context = RuntimeContext::new();
+        context.set_clock(Clock::manual());
+        let runtime = Rc::new(RefCell::new(context));
         let renderer = TestRenderer::new(width, height);
 
         let mut harness = Self {
@@ -106,7 +109,7 @@ where
     /// Dispatch a typed key with modifiers through registered `use_input`
     /// handlers and render.
     pub fn send_key_with_modifiers(&mut self, code: KeyCodeKind, modifiers: KeyModifiers) -> &str {
-        let event = KeyEvent::new(key_code_to_event_code(code), modifiers);
+        let event = KeyEvent::new(code.to_event_code(), modifiers);
         self.send_key_event(event)
     }
 
@@ -161,6 +164,26 @@ where
         self.render()
     }
 
+    /// Get the manual clock hooks in this harness read.
+    pub fn clock(&self) -> Clock {
+        self.runtime.borrow().clock()
+    }
+
+    /// Move the harness clock forward by `duration` and render.
+    ///
+    /// Timers that fall due fire in order inside the runtime.
+    pub fn advance(&mut self, duration: Duration) -> &str {
+        let clock = self.clock();
+        self.with_current_runtime(|| clock.advance(duration));
+        self.render()
+    }
+
+    /// Run every step of a script in order, rendering after each one.
+    pub fn run_script(&mut self, script: &InteractionScript) -> &str {
+        script.run_on(self);
+        self.render()
+    }
+
     /// Resize the test renderer and render at the new dimensions.
     pub fn resize(&mut self, width: u16, height: u16) -> &str {
         self.renderer = TestRenderer::new(width, height);
@@ -192,23 +215,7 @@ where
     }
 
     fn with_current_runtime<R>(&self, f: impl FnOnce() -> R) -> R {
-        let previous = current_runtime();
-        set_current_runtime(Some(self.runtime.clone()));
-
-        struct RuntimeGuard {
-            previous: Option<Rc<RefCell<RuntimeContext>>>,
-        }
-
-        impl Drop for RuntimeGuard {
-            fn drop(&mut self) {
-                set_current_runtime(self.previous.take());
-            }
-        }
-
-        let guard = RuntimeGuard { previous };
-        let result = f();
-        drop(guard);
-        result
+        enter_runtime(&self.runtime, f)
     }
 
     // ========== Assertions ==========
@@ -293,38 +300,50 @@ where
     }
 }
 
-fn key_code_to_event_code(code: KeyCodeKind) -> KeyCode {
-    match code {
-        KeyCodeKind::Up => KeyCode::Up,
-        KeyCodeKind::Down => KeyCode::Down,
-        KeyCodeKind::Left => KeyCode::Left,
-        KeyCodeKind::Right => KeyCode::Right,
-        KeyCodeKind::PageUp => KeyCode::PageUp,
-        KeyCodeKind::PageDown => KeyCode::PageDown,
-        KeyCodeKind::Home => KeyCode::Home,
-        KeyCodeKind::End => KeyCode::End,
-        KeyCodeKind::Insert => KeyCode::Insert,
-        KeyCodeKind::Enter => KeyCode::Enter,
-        KeyCodeKind::Escape => KeyCode::Esc,
-        KeyCodeKind::Tab => KeyCode::Tab,
-        KeyCodeKind::BackTab => KeyCode::BackTab,
-        KeyCodeKind::Backspace => KeyCode::Backspace,
-        KeyCodeKind::Delete => KeyCode::Delete,
-        KeyCodeKind::Char(ch) => KeyCode::Char(ch),
-        KeyCodeKind::Function(n) => KeyCode::F(n),
-        KeyCodeKind::Media(media) => KeyCode::Media(match media {
-            MediaKeyKind::Play => MediaKeyCode::Play,
-            MediaKeyKind::Pause => MediaKeyCode::Pause,
-            MediaKeyKind::PlayPause => MediaKeyCode::PlayPause,
-            MediaKeyKind::Stop => MediaKeyCode::Stop,
-            MediaKeyKind::Next => MediaKeyCode::TrackNext,
-            MediaKeyKind::Previous => MediaKeyCode::TrackPrevious,
-            MediaKeyKind::VolumeUp => MediaKeyCode::RaiseVolume,
-            MediaKeyKind::VolumeDown => MediaKeyCode::LowerVolume,
-            MediaKeyKind::VolumeMute => MediaKeyCode::MuteVolume,
-        }),
-        KeyCodeKind::Unknown => KeyCode::Null,
+impl<F> ScriptTarget for TestHarness<F>
+where
+    F: Fn() -> Element,
+{
+    fn script_key(&mut self, input: &str, key: &Key) {
+        self.dispatch_key(input, key);
     }
+
+    fn script_text(&mut self, text: &str) {
+        self.send_text(text);
+    }
+
+    fn script_paste(&mut self, content: &str) {
+        self.send_paste(content);
+    }
+
+    fn script_mouse(&mut self, mouse: Mouse) {
+        self.send_mouse(mouse);
+    }
+
+    fn script_advance(&mut self, duration: Duration) {
+        self.advance(duration);
+    }
+}
+
+/// Run `f` with `runtime` installed as the current runtime, without rendering
+pub(super) fn enter_runtime<R>(runtime: &Rc<RefCell<RuntimeContext>>, f: impl FnOnce() -> R) -> R {
+    let previous = current_runtime();
+    set_current_runtime(Some(runtime.clone()));
+
+    struct RuntimeGuard {
+        previous: Option<Rc<RefCell<RuntimeContext>>>,
+    }
+
+    impl Drop for RuntimeGuard {
+        fn drop(&mut self) {
+            set_current_runtime(self.previous.take());
+        }
+    }
+
+    let guard = RuntimeGuard { previous };
+    let result = f();
+    drop(guard);
+    result
 }
 
 /// Snapshot testing support
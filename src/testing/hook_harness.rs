@@ -0,0 +1,343 @@
+//! Hook harness for testing hooks without a running app
+//!
+//! Wraps a hook-using closure in a fresh [`RuntimeContext`] so it can be
+//! rendered repeatedly, fed synthetic input, and inspected between renders.
+//!
+//! # Example
+//!
+//! ```rust
+//! use rnk::hooks::use_counter;
+//! use rnk::testing::hook_harness;
+//!
+//! let mut harness = hook_harness(|| use_counter(0));
+//! assert_eq!(harness.result().get(), 0);
+//!
+//! harness.act(|counter| counter.increment());
+//! assert_eq!(harness.result().get(), 1);
+//! ```
+//!
+//! The harness runtime uses a manual [`Clock`]: timer-driven hooks
+//! (`use_debounce`, `use_interval`, `use_timeout`) and animations only see
+//! time pass through [`HookHarness::advance`], which fires due timers
+//! synchronously. [`HookHarness::wait_for`] polls in real time and is meant
+//! for work that finishes on other threads, such as async commands.
+
+use crate::hooks::use_input::{Key, KeyCodeKind};
+use crate::hooks::use_mouse::Mouse;
+use crate::runtime::{Clock, RuntimeContext, with_runtime};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use super::harness::enter_runtime;
+use super::{InteractionScript, ScriptTarget};
+
+/// How often [`HookHarness::wait_for`] re-renders while polling
+const WAIT_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Drives a hook-using closure across simulated renders
+pub struct HookHarness<T, P = ()> {
+    /// Hook closure, re-run on every render
+    hook: Box<dyn FnMut(&P) -> T>,
+    /// Props passed to the hook closure
+    props: P,
+    /// Runtime context holding hook state and event handlers
+    runtime: Rc<RefCell<RuntimeContext>>,
+    /// Value returned by the latest render
+    result: T,
+    /// Number of renders so far
+    renders: usize,
+}
+
+/// Create a harness for a hook closure and run its first render
+pub fn hook_harness<T, F>(mut hook: F) -> HookHarness<T>
+where
+    F: FnMut() -> T + 'static,
+{
+    HookHarness::with_props((), move |_| hook())
+}
+
+impl<T, P> HookHarness<T, P> {
+    /// Create a harness whose hook closure receives props
+    ///
+    /// Use [`rerender`](Self::rerender) to render again with new props, e.g.
+    /// to feed a new value into `use_debounce`.
+    pub fn with_props<F>(props: P, hook: F) -> Self
+    where
+        F: FnMut(&P) -> T + 'static,
+    {
+        let mut context = RuntimeContext::new();
+        context.set_clock(Clock::manual());
+        let runtime = Rc::new(RefCell::new(context));
+        let mut hook: Box<dyn FnMut(&P) -> T> = Box::new(hook);
+        let result = with_runtime(runtime.clone(), || hook(&props));
+
+        Self {
+            hook,
+            props,
+            runtime,
+            result,
+            renders: 1,
+        }
+    }
+
+    /// Render again with the current props and return the new value
+    pub fn render(&mut self) -> &T {
+        let hook = &mut self.hook;
+        let props = &self.props;
+        self.result = with_runtime(self.runtime.clone(), || hook(props));
+        self.renders += 1;
+        &self.result
+    }
+
+    /// Replace the props and render
+    pub fn rerender(&mut self, props: P) -> &T {
+        self.props = props;
+        self.render()
+    }
+
+    /// Value returned by the latest render
+    pub fn result(&self) -> &T {
+        &self.result
+    }
+
+    /// Number of renders performed, including the initial one
+    pub fn render_count(&self) -> usize {
+        self.renders
+    }
+
+    /// Current props
+    pub fn props(&self) -> &P {
+        &self.props
+    }
+
+    /// Get the runtime context used by this harness
+    pub fn runtime_context(&self) -> Rc<RefCell<RuntimeContext>> {
+        self.runtime.clone()
+    }
+
+    /// Run `f` against the latest value inside the runtime, then render
+    ///
+    /// This is where state setters and handle methods should be called.
+    pub fn act(&mut self, f: impl FnOnce(&T)) -> &T {
+        let result = &self.result;
+        enter_runtime(&self.runtime, || f(result));
+        self.render()
+    }
+
+    /// Get the manual clock hooks in this harness read
+    pub fn clock(&self) -> Clock {
+        self.runtime.borrow().clock()
+    }
+
+    /// Move the harness clock forward by `duration`, then render
+    ///
+    /// Timers that fall due fire in order inside the runtime; nothing waits
+    /// for real time.
+    pub fn advance(&mut self, duration: Duration) -> &T {
+        let clock = self.clock();
+        enter_runtime(&self.runtime, || clock.advance(duration));
+        self.render()
+    }
+
+    /// Re-render until `predicate` holds or `timeout` elapses
+    ///
+    /// Returns whether the predicate was satisfied.
+    pub fn wait_for(&mut self, timeout: Duration, predicate: impl Fn(&T) -> bool) -> bool {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if predicate(self.render()) {
+                return true;
+            }
+            if Instant::now() >= deadline {
+                return false;
+            }
+            std::thread::sleep(WAIT_POLL_INTERVAL);
+        }
+    }
+
+    /// Dispatch a typed key through registered `use_input` handlers and render
+    pub fn send_key(&mut self, code: KeyCodeKind) -> &T {
+        self.send_key_with_modifiers(code, KeyModifiers::NONE)
+    }
+
+    /// Dispatch a typed key with modifiers through registered `use_input`
+    /// handlers and render
+    pub fn send_key_with_modifiers(&mut self, code: KeyCodeKind, modifiers: KeyModifiers) -> &T {
+        let event = KeyEvent::new(code.to_event_code(), modifiers);
+        self.send_key_event(event)
+    }
+
+    /// Dispatch a raw crossterm key event through registered `use_input`
+    /// handlers and render
+    pub fn send_key_event(&mut self, event: KeyEvent) -> &T {
+        let key = Key::from_event(&event);
+        let input = Key::char_from_event(&event);
+        enter_runtime(&self.runtime, || {
+            crate::hooks::use_input::dispatch_input(&input, &key);
+        });
+        self.render()
+    }
+
+    /// Dispatch an already-built key plus input string and render
+    pub fn dispatch_key(&mut self, input: &str, key: &Key) -> &T {
+        enter_runtime(&self.runtime, || {
+            crate::hooks::use_input::dispatch_input(input, key);
+        });
+        self.render()
+    }
+
+    /// Run every step of a script in order, rendering after each one
+    pub fn run_script(&mut self, script: &InteractionScript) -> &T {
+        script.run_on(self);
+        &self.result
+    }
+
+    /// Dispatch text as a sequence of character key events and render once
+    pub fn send_text(&mut self, text: &str) -> &T {
+        enter_runtime(&self.runtime, || {
+            for ch in text.chars() {
+                let event = KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE);
+                let key = Key::from_event(&event);
+                let input = Key::char_from_event(&event);
+                crate::hooks::use_input::dispatch_input(&input, &key);
+            }
+        });
+        self.render()
+    }
+
+    /// Dispatch a mouse event through registered `use_mouse` handlers and render
+    pub fn send_mouse(&mut self, mouse: Mouse) -> &T {
+        let handlers = self.runtime.borrow().mouse_handlers.clone();
+        enter_runtime(&self.runtime, || {
+            for handler in handlers {
+                handler(&mouse);
+            }
+        });
+        self.render()
+    }
+
+    /// Dispatch paste content through registered `use_paste` handlers and render
+    pub fn send_paste(&mut self, content: &str) -> &T {
+        enter_runtime(&self.runtime, || {
+            crate::hooks::paste::dispatch_paste(content);
+        });
+        self.render()
+    }
+}
+
+impl<T, P> ScriptTarget for HookHarness<T, P> {
+    fn script_key(&mut self, input: &str, key: &Key) {
+        self.dispatch_key(input, key);
+    }
+
+    fn script_text(&mut self, text: &str) {
+        self.send_text(text);
+    }
+
+    fn script_paste(&mut self, content: &str) {
+        self.send_paste(content);
+    }
+
+    fn script_mouse(&mut self, mouse: Mouse) {
+        self.send_mouse(mouse);
+    }
+
+    fn script_advance(&mut self, duration: Duration) {
+        self.advance(duration);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hooks::{
+        use_counter, use_debounce, use_input, use_interval, use_signal, use_timeout, use_transition,
+    };
+
+    #[test]
+    fn test_hook_harness_counter_across_renders() {
+        let mut harness = hook_harness(|| use_counter(0));
+        assert_eq!(harness.result().get(), 0);
+
+        harness.act(|counter| counter.increment());
+        harness.act(|counter| counter.increment_by(5));
+
+        assert_eq!(harness.result().get(), 6);
+        assert_eq!(harness.render_count(), 3);
+    }
+
+    #[test]
+    fn test_hook_harness_debounce_fires_after_delay() {
+        let delay = Duration::from_millis(30);
+        let mut harness = HookHarness::with_props("a".to_string(), move |value| {
+            use_debounce(value.clone(), delay)
+        });
+        assert_eq!(harness.result(), "a");
+
+        assert_eq!(harness.rerender("b".to_string()), "a");
+        assert_eq!(harness.advance(Duration::from_millis(29)), "a");
+        assert_eq!(harness.advance(Duration::from_millis(1)), "b");
+    }
+
+    #[test]
+    fn test_hook_harness_debounce_restarts_on_new_value() {
+        let delay = Duration::from_millis(30);
+        let mut harness = HookHarness::with_props("a".to_string(), move |value| {
+            use_debounce(value.clone(), delay)
+        });
+
+        harness.rerender("b".to_string());
+        harness.advance(Duration::from_millis(20));
+        harness.rerender("c".to_string());
+        assert_eq!(harness.advance(Duration::from_millis(20)), "a");
+        assert_eq!(harness.advance(Duration::from_millis(10)), "c");
+    }
+
+    #[test]
+    fn test_hook_harness_interval_and_timeout_follow_clock() {
+        let mut harness = hook_harness(|| {
+            let ticks = use_signal(|| 0usize);
+            let done = use_signal(|| false);
+            let tick_signal = ticks.clone();
+            use_interval(Duration::from_millis(10), move || {
+                tick_signal.update(|t| *t += 1);
+            });
+            let done_signal = done.clone();
+            use_timeout(Duration::from_millis(25), move || done_signal.set(true));
+            (ticks.get(), done.get())
+        });
+
+        assert_eq!(*harness.advance(Duration::from_millis(9)), (0, false));
+        assert_eq!(*harness.advance(Duration::from_millis(16)), (2, true));
+        assert_eq!(*harness.advance(Duration::from_millis(100)), (12, true));
+    }
+
+    #[test]
+    fn test_hook_harness_transition_follows_clock() {
+        let mut harness = hook_harness(|| use_transition(0.0, Duration::from_millis(100)));
+
+        harness.act(|position| position.set(100.0));
+        assert!(harness.result().is_transitioning());
+
+        harness.advance(Duration::from_millis(100));
+        assert!(!harness.result().is_transitioning());
+        assert_eq!(harness.result().get(), 100.0);
+    }
+
+    #[test]
+    fn test_hook_harness_dispatches_input() {
+        let mut harness = hook_harness(|| {
+            let typed = use_signal(String::new);
+            let handler_signal = typed.clone();
+            use_input(move |input, _key| {
+                handler_signal.update(|s| s.push_str(input));
+            });
+            typed.get()
+        });
+
+        harness.send_text("hi");
+        assert_eq!(harness.send_key(KeyCodeKind::Char('!')), "hi!");
+    }
+}
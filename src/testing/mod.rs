@@ -20,13 +20,20 @@
 //! ```
 
 mod assertions;
+mod bench;
 mod generators;
 mod golden;
 mod harness;
+mod hook_harness;
 mod renderer;
+mod script;
 
 pub use assertions::*;
+pub use bench::{BenchComparison, BenchReport, RenderBench, count_nodes, gen_bench_tree};
 pub use generators::*;
 pub use golden::*;
 pub use harness::{Snapshot, StringSnapshot, TestHarness};
+pub use hook_harness::{HookHarness, hook_harness};
 pub use renderer::{LayoutError, TestRenderer, display_width, strip_ansi_codes};
+pub(crate) use script::ScriptTarget;
+pub use script::{InteractionScript, ScriptStep};
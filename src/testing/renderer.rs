@@ -7,6 +7,7 @@ use std::collections::HashMap;
 use unicode_width::UnicodeWidthChar;
 
 use crate::core::{Element, ElementId};
+use crate::layout::measure::strip_ansi;
 use crate::layout::{Layout, LayoutEngine};
 use crate::renderer::Output;
 use crate::renderer::tree_renderer::render_element_tree;
@@ -238,29 +239,14 @@ impl std::fmt::Display for LayoutError {
 impl std::error::Error for LayoutError {}
 
 /// Strip ANSI escape codes from a string
+///
+/// Removes CSI sequences (colors, cursor movement), OSC strings such as
+/// hyperlinks, window titles and clipboard writes (terminated by BEL or
+/// ST), DCS/SOS/PM/APC strings and two-byte escapes, leaving only the
+/// visible text. An unterminated sequence swallows the rest of the input,
+/// as a terminal would.
 pub fn strip_ansi_codes(s: &str) -> String {
-    let mut result = String::new();
-    let mut chars = s.chars().peekable();
-
-    while let Some(ch) = chars.next() {
-        if ch == '\x1b' {
-            // Skip escape sequence
-            if chars.peek() == Some(&'[') {
-                chars.next(); // consume '['
-                // Skip until we hit a letter
-                while let Some(&c) = chars.peek() {
-                    chars.next();
-                    if c.is_ascii_alphabetic() {
-                        break;
-                    }
-                }
-            }
-        } else {
-            result.push(ch);
-        }
-    }
-
-    result
+    strip_ansi(s)
 }
 
 /// Calculate display width of text accounting for Unicode
@@ -283,6 +269,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_strip_ansi_codes_handles_non_sgr_sequences() {
+        // OSC 8 hyperlink, BEL and ST terminated
+        assert_eq!(
+            strip_ansi_codes("see \x1b]8;;https://example.com\x07docs\x1b]8;;\x07 here"),
+            "see docs here"
+        );
+        assert_eq!(
+            strip_ansi_codes("\x1b]8;id=1;https://a.b\x1b\\link\x1b]8;;\x1b\\"),
+            "link"
+        );
+        // Window title and OSC 52 clipboard writes
+        assert_eq!(strip_ansi_codes("\x1b]0;title\x07ok"), "ok");
+        assert_eq!(strip_ansi_codes("\x1b]52;c;aGk=\x07ok"), "ok");
+        // DCS string and single-character escapes
+        assert_eq!(strip_ansi_codes("a\x1bP1$r0m\x1b\\b"), "ab");
+        assert_eq!(strip_ansi_codes("\x1b7saved\x1b8"), "saved");
+        // Cursor movement CSI
+        assert_eq!(strip_ansi_codes("\x1b[2Kx\x1b[?25l"), "x");
+        // Unterminated sequences swallow the remainder
+        assert_eq!(strip_ansi_codes("ok\x1b]8;;https://"), "ok");
+    }
+
+    #[test]
+    fn test_strip_ansi_codes_on_rendered_hyperlink() {
+        let link = crate::components::Hyperlink::new("https://example.com", "Docs");
+        assert_eq!(strip_ansi_codes(&link.render_osc8()), "Docs");
+    }
+
     #[test]
     fn test_display_width() {
         assert_eq!(display_width("hello"), 5);
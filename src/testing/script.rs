@@ -0,0 +1,289 @@
+//! Scripted interactions for the test harnesses
+//!
+//! [`InteractionScript`] describes a user session as a list of steps built
+//! from readable strings: typed text, keybinding names such as `Ctrl+A`,
+//! pastes and pauses. Run it with [`TestHarness::run_script`] or
+//! [`HookHarness::run_script`]; each step is dispatched and re-rendered in
+//! order.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use rnk::testing::{InteractionScript, TestHarness};
+//! use std::time::Duration;
+//!
+//! let mut harness = TestHarness::new(form);
+//! let script = InteractionScript::new()
+//!     .type_text("hello")
+//!     .key("Enter")
+//!     .key("Ctrl+A")
+//!     .sleep(Duration::from_millis(100));
+//! harness.run_script(&script);
+//! harness.assert_text_contains("hello");
+//! ```
+//!
+//! Both harnesses run on a manual clock, so [`InteractionScript::sleep`]
+//! advances simulated time: due timers fire at once and the test never
+//! blocks.
+//!
+//! [`TestHarness::run_script`]: super::TestHarness::run_script
+//! [`HookHarness::run_script`]: super::HookHarness::run_script
+
+use crate::hooks::use_input::{Key, KeyCodeKind};
+use crate::hooks::use_mouse::Mouse;
+use std::time::Duration;
+
+/// One step of an [`InteractionScript`]
+#[derive(Debug, Clone)]
+pub enum ScriptStep {
+    /// A single key press
+    Key(Key),
+    /// Text typed as one character key event per character
+    Text(String),
+    /// Bracketed paste content
+    Paste(String),
+    /// A mouse event
+    Mouse(Mouse),
+    /// Advance the harness clock before the next step
+    Sleep(Duration),
+}
+
+impl ScriptStep {
+    /// Input string dispatched alongside a key (the character, if any)
+    pub(crate) fn key_input(key: &Key) -> String {
+        match key.code {
+            KeyCodeKind::Char(ch) => ch.to_string(),
+            _ => String::new(),
+        }
+    }
+}
+
+/// A reusable, fluent sequence of synthetic input events
+#[derive(Debug, Clone, Default)]
+pub struct InteractionScript {
+    steps: Vec<ScriptStep>,
+}
+
+impl InteractionScript {
+    /// Create an empty script
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Type text, one character key event per character
+    pub fn type_text(mut self, text: impl Into<String>) -> Self {
+        self.steps.push(ScriptStep::Text(text.into()));
+        self
+    }
+
+    /// Press a key given as a keybinding string (`Enter`, `Ctrl+A`, `Shift+Tab`, ...)
+    ///
+    /// See [`Key`]'s `FromStr` impl for the accepted names. Letters held
+    /// with `Ctrl` or `Alt` but not `Shift` are sent lowercase, as terminals
+    /// report them, so `Ctrl+A` and `Ctrl+a` are the same key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string is not a valid keybinding, so typos fail the test
+    /// where the script is written.
+    pub fn key(self, spec: &str) -> Self {
+        match spec.parse::<Key>() {
+            Ok(mut key) => {
+                if let KeyCodeKind::Char(ch) = key.code
+                    && (key.ctrl || key.alt)
+                    && !key.shift
+                {
+                    let ch = ch.to_ascii_lowercase();
+                    key.code = KeyCodeKind::Char(ch);
+                    key.character = Some(ch);
+                }
+                self.key_event(key)
+            }
+            Err(err) => panic!("invalid key in interaction script {:?}: {}", spec, err),
+        }
+    }
+
+    /// Press several keys in order
+    pub fn keys(self, specs: &[&str]) -> Self {
+        specs.iter().fold(self, |script, spec| script.key(spec))
+    }
+
+    /// Press an already-built key
+    pub fn key_event(mut self, key: Key) -> Self {
+        self.steps.push(ScriptStep::Key(key));
+        self
+    }
+
+    /// Paste content through `use_paste` handlers
+    pub fn paste(mut self, content: impl Into<String>) -> Self {
+        self.steps.push(ScriptStep::Paste(content.into()));
+        self
+    }
+
+    /// Send a mouse event
+    pub fn mouse(mut self, mouse: Mouse) -> Self {
+        self.steps.push(ScriptStep::Mouse(mouse));
+        self
+    }
+
+    /// Advance the harness clock by `duration` before the next step
+    pub fn sleep(mut self, duration: Duration) -> Self {
+        self.steps.push(ScriptStep::Sleep(duration));
+        self
+    }
+
+    /// Append every step of another script
+    pub fn then(mut self, other: &InteractionScript) -> Self {
+        self.steps.extend(other.steps.iter().cloned());
+        self
+    }
+
+    /// The recorded steps
+    pub fn steps(&self) -> &[ScriptStep] {
+        &self.steps
+    }
+
+    /// Number of steps
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Check whether the script has no steps
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Dispatch every step to a harness, which renders after each one
+    pub(crate) fn run_on(&self, target: &mut impl ScriptTarget) {
+        for step in &self.steps {
+            match step {
+                ScriptStep::Key(key) => target.script_key(&ScriptStep::key_input(key), key),
+                ScriptStep::Text(text) => target.script_text(text),
+                ScriptStep::Paste(content) => target.script_paste(content),
+                ScriptStep::Mouse(mouse) => target.script_mouse(mouse.clone()),
+                ScriptStep::Sleep(duration) => target.script_advance(*duration),
+            }
+        }
+    }
+}
+
+/// A harness that [`InteractionScript`] steps can be dispatched to
+pub(crate) trait ScriptTarget {
+    fn script_key(&mut self, input: &str, key: &Key);
+    fn script_text(&mut self, text: &str);
+    fn script_paste(&mut self, content: &str);
+    fn script_mouse(&mut self, mouse: Mouse);
+    fn script_advance(&mut self, duration: Duration);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::Text;
+    use crate::core::Element;
+    use crate::hooks::{use_debounce, use_input, use_paste, use_signal};
+    use crate::testing::{TestHarness, hook_harness};
+
+    #[test]
+    fn test_script_parses_keys() {
+        let script = InteractionScript::new()
+            .type_text("hi")
+            .keys(&["Enter", "Ctrl+A"])
+            .sleep(Duration::from_millis(1));
+        assert_eq!(script.len(), 4);
+
+        let ScriptStep::Key(ctrl_a) = &script.steps()[2] else {
+            panic!("expected a key step");
+        };
+        assert!(ctrl_a.ctrl);
+        assert_eq!(ctrl_a.code, KeyCodeKind::Char('a'));
+        assert_eq!(ScriptStep::key_input(ctrl_a), "a");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid key")]
+    fn test_script_rejects_unknown_key() {
+        let _ = InteractionScript::new().key("Hyper+Q");
+    }
+
+    fn editor() -> Element {
+        let text = use_signal(String::new);
+        let submitted = use_signal(String::new);
+
+        let handle = text.clone();
+        let done = submitted.clone();
+        use_input(move |input, key| {
+            if key.return_key {
+                done.set(handle.get());
+            } else if key.ctrl && input == "u" {
+                handle.set(String::new());
+            } else if key.backspace {
+                handle.update(|t| {
+                    t.pop();
+                });
+            } else if !input.is_empty() && !key.ctrl {
+                handle.update(|t| t.push_str(input));
+            }
+        });
+
+        let handle = text.clone();
+        use_paste(move |event| handle.update(|t| t.push_str(event.content())));
+
+        Text::new(format!("[{}] -> {}", text.get(), submitted.get())).into_element()
+    }
+
+    #[test]
+    fn test_run_script_on_test_harness() {
+        let mut harness = TestHarness::with_size(editor, 40, 1);
+        let script = InteractionScript::new()
+            .type_text("helo")
+            .key("Backspace")
+            .type_text("lo")
+            .paste(" world")
+            .key("Enter")
+            .key("Ctrl+U");
+
+        let output = harness.run_script(&script).to_string();
+        assert_eq!(output.trim_end(), "[] -> hello world");
+
+        harness.run_script(&InteractionScript::new().type_text("again"));
+        harness.assert_text_contains("[again] -> hello world");
+    }
+
+    #[test]
+    fn test_run_script_on_hook_harness() {
+        let mut harness = hook_harness(|| {
+            let count = use_signal(|| 0);
+            let handle = count.clone();
+            use_input(move |_, key| {
+                if key.up_arrow {
+                    handle.update(|c| *c += 1);
+                }
+            });
+            count.get()
+        });
+
+        let script = InteractionScript::new()
+            .keys(&["Up", "Up", "Down"])
+            .sleep(Duration::from_millis(1));
+        assert_eq!(*harness.run_script(&script), 2);
+    }
+
+    #[test]
+    fn test_script_sleep_advances_the_clock() {
+        fn delayed() -> Element {
+            let text = use_signal(String::new);
+            let handle = text.clone();
+            use_input(move |input, _| handle.update(|t| t.push_str(input)));
+            let shown = use_debounce(text.get(), Duration::from_secs(60));
+            Text::new(format!("[{}]", shown)).into_element()
+        }
+
+        let mut harness = TestHarness::with_size(delayed, 20, 1);
+        let script = InteractionScript::new().type_text("hi");
+        assert_eq!(harness.run_script(&script).trim_end(), "[]");
+
+        let script = InteractionScript::new().sleep(Duration::from_secs(60));
+        assert_eq!(harness.run_script(&script).trim_end(), "[hi]");
+    }
+}
@@ -41,6 +41,11 @@ fn testing_prelude_exports_snapshot_helpers() {
     let renderer = TestRenderer::new(30, 5);
     let output = renderer.render_to_plain(&element);
     inline_snapshot!(output.trim(), "testing prelude");
+    let colored = Text::new("testing prelude")
+        .color(rnk::core::Color::Red)
+        .into_element();
+    assert_element_snapshot!("testing_prelude", colored, 30, 3);
+    assert_element_snapshot!("testing_prelude", element, 30, 3, GoldenFormat::Plain);
 
     assert_eq!(display_width("abc"), 3);
     assert!(strip_ansi_codes("\u{1b}[31mred\u{1b}[0m").contains("red"));